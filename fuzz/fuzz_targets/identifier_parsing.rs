@@ -0,0 +1,19 @@
+#![no_main]
+
+use core::str::FromStr;
+
+use ibc::apps::transfer::types::PrefixedDenom;
+use ibc::core::host::types::identifiers::{
+    ChainId, ChannelId, ClientId, ClientType, ConnectionId, PortId,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|s: &str| {
+    let _ = ChainId::from_str(s);
+    let _ = ChannelId::from_str(s);
+    let _ = ClientId::from_str(s);
+    let _ = ClientType::from_str(s);
+    let _ = ConnectionId::from_str(s);
+    let _ = PortId::from_str(s);
+    let _ = PrefixedDenom::from_str(s);
+});
@@ -0,0 +1,27 @@
+#![no_main]
+
+use core::str::FromStr;
+
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(
+    |ids: (ClientId, ConnectionId, ChannelId, PortId, Sequence)| {
+        let (client_id, connection_id, channel_id, port_id, sequence) = ids;
+
+        assert_eq!(ClientId::from_str(client_id.as_str()).ok(), Some(client_id));
+        assert_eq!(
+            ConnectionId::from_str(connection_id.as_str()).ok(),
+            Some(connection_id)
+        );
+        assert_eq!(
+            ChannelId::from_str(channel_id.as_str()).ok(),
+            Some(channel_id)
+        );
+        assert_eq!(PortId::from_str(port_id.as_str()).ok(), Some(port_id));
+        assert_eq!(
+            Sequence::from_str(&sequence.to_string()).ok(),
+            Some(sequence)
+        );
+    }
+);
@@ -0,0 +1,11 @@
+#![no_main]
+
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::primitives::proto::Any;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (String, Vec<u8>)| {
+    let (type_url, value) = input;
+
+    let _ = MsgEnvelope::try_from(Any { type_url, value });
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use ibc::apps::nft_transfer::types::packet::PacketData;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(packet_data) = serde_json::from_slice::<PacketData>(data) {
+        let _ = packet_data.validate_basic();
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::commitment_types::merkle::MerkleProof;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: Vec<u8>| {
+    if let Ok(proof_bytes) = CommitmentProofBytes::try_from(data) {
+        let _ = MerkleProof::try_from(&proof_bytes);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use ibc::apps::transfer::types::packet::PacketData;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(packet_data) = serde_json::from_slice::<PacketData>(data) {
+        let json = serde_json::to_vec(&packet_data).expect("packet data serializes");
+        let decoded: PacketData = serde_json::from_slice(&json).expect("packet data roundtrips");
+
+        assert_eq!(packet_data, decoded);
+    }
+});
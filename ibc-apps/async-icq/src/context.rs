@@ -0,0 +1,25 @@
+//! Defines the context trait the host implements to serve the async
+//! interchain queries
+
+use ibc_app_async_icq_types::error::AsyncIcqError;
+use ibc_app_async_icq_types::packet::{AbciQueryRequest, AbciQueryResponse};
+use ibc_app_async_icq_types::IcqHostParams;
+use ibc_core::host::types::identifiers::PortId;
+
+/// Methods required by the async ICQ host application, to be implemented by
+/// the host
+pub trait AbciQueryContext {
+    /// get_port returns the portID for the async ICQ host module.
+    fn get_port(&self) -> Result<PortId, AsyncIcqError>;
+
+    /// Returns the parameters of the host, including the allowlist of the
+    /// query paths counterparties may query.
+    fn icq_host_params(&self) -> Result<IcqHostParams, AsyncIcqError>;
+
+    /// Executes the ABCI query against the latest committed state of the
+    /// host.
+    ///
+    /// Only queries on allowed paths, without height nor proof, are passed to
+    /// this method.
+    fn abci_query(&self, request: &AbciQueryRequest) -> Result<AbciQueryResponse, AsyncIcqError>;
+}
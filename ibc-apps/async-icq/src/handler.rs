@@ -0,0 +1,73 @@
+//! Implements the execution of the queries received by the async ICQ host.
+use ibc_app_async_icq_types::error::AsyncIcqError;
+use ibc_app_async_icq_types::packet::{AbciQueryRequest, CosmosQuery, CosmosResponse};
+use ibc_app_async_icq_types::IcqHostParams;
+use ibc_core::primitives::prelude::*;
+
+use crate::context::AbciQueryContext;
+
+/// Executes the queries of a packet, returning their results in order.
+///
+/// All the queries are checked against the host parameters before any of them
+/// is executed, and the packet fails as a whole if any query fails.
+pub fn execute_queries(
+    ctx: &impl AbciQueryContext,
+    query: &CosmosQuery,
+) -> Result<CosmosResponse, AsyncIcqError> {
+    let params = ctx.icq_host_params()?;
+
+    if !params.host_enabled {
+        return Err(AsyncIcqError::HostDisabled);
+    }
+
+    for request in &query.requests {
+        validate_request(&params, request)?;
+    }
+
+    let responses = query
+        .requests
+        .iter()
+        .map(|request| {
+            let response = ctx.abci_query(request)?;
+
+            if response.code.is_err() {
+                return Err(AsyncIcqError::QueryFailed {
+                    path: request.path.clone(),
+                    code: response.code.value(),
+                    log: response.log,
+                });
+            }
+
+            Ok(response)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(CosmosResponse { responses })
+}
+
+/// Checks that the query targets an allowed path of the latest state, without
+/// proof, as the results are not verifiable by the controller chain.
+fn validate_request(
+    params: &IcqHostParams,
+    request: &AbciQueryRequest,
+) -> Result<(), AsyncIcqError> {
+    if !params.is_query_allowed(&request.path) {
+        return Err(AsyncIcqError::QueryNotAllowed {
+            path: request.path.clone(),
+        });
+    }
+
+    if request.height.value() != 0 {
+        return Err(AsyncIcqError::QueryHeightNotAllowed {
+            path: request.path.clone(),
+        });
+    }
+
+    if request.prove {
+        return Err(AsyncIcqError::QueryProofNotAllowed {
+            path: request.path.clone(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+//! Implementation of the [async interchain
+//! queries](https://github.com/cosmos/ibc-apps/tree/main/modules/async-icq)
+//! (ICQ) host application logic, executing the ABCI queries received from
+//! counterparty chains and returning their results in the acknowledgements.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+/// Re-exports the async ICQ data structures.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_app_async_icq_types::*;
+}
+
+pub mod context;
+pub mod handler;
+#[cfg(feature = "serde")]
+pub mod module;
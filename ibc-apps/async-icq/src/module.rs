@@ -0,0 +1,383 @@
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_app_async_icq_types::error::AsyncIcqError;
+use ibc_app_async_icq_types::events::RecvEvent;
+use ibc_app_async_icq_types::packet::{
+    CosmosQuery, InterchainQueryPacketAck, InterchainQueryPacketData,
+};
+use ibc_app_async_icq_types::VERSION;
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, RecvPacketResult, StatusValue,
+};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::AbciQueryContext;
+use crate::handler::execute_queries;
+
+/// The host never initiates the channel handshake, which is opened by the
+/// controller chain querying it.
+pub fn on_chan_open_init_validate(
+    _ctx: &impl AbciQueryContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(), AsyncIcqError> {
+    Err(AsyncIcqError::InvalidHostChannelInit)
+}
+
+pub fn on_chan_open_init_execute(
+    _ctx: &impl AbciQueryContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(ModuleExtras, Version), AsyncIcqError> {
+    Err(AsyncIcqError::InvalidHostChannelInit)
+}
+
+pub fn on_chan_open_try_validate(
+    ctx: &impl AbciQueryContext,
+    order: Order,
+    _connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(), AsyncIcqError> {
+    if order != Order::Unordered {
+        return Err(AsyncIcqError::ChannelNotUnordered {
+            expect_order: Order::Unordered,
+            got_order: order,
+        });
+    }
+    let bound_port = ctx.get_port()?;
+    if port_id != &bound_port {
+        return Err(AsyncIcqError::InvalidPort {
+            port_id: port_id.clone(),
+            exp_port_id: bound_port,
+        });
+    }
+
+    counterparty_version
+        .verify_is_expected(Version::new(VERSION.to_string()))
+        .map_err(ContextError::from)?;
+
+    Ok(())
+}
+
+pub fn on_chan_open_try_execute(
+    _ctx: &impl AbciQueryContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), AsyncIcqError> {
+    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+}
+
+pub fn on_chan_open_ack_validate(
+    _ctx: &impl AbciQueryContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<(), AsyncIcqError> {
+    Err(AsyncIcqError::InvalidHostChannelInit)
+}
+
+pub fn on_chan_open_ack_execute(
+    _ctx: &impl AbciQueryContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<ModuleExtras, AsyncIcqError> {
+    Err(AsyncIcqError::InvalidHostChannelInit)
+}
+
+pub fn on_chan_open_confirm_validate(
+    _ctx: &impl AbciQueryContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), AsyncIcqError> {
+    Ok(())
+}
+
+pub fn on_chan_open_confirm_execute(
+    _ctx: &impl AbciQueryContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, AsyncIcqError> {
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_chan_close_init_validate(
+    _ctx: &impl AbciQueryContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), AsyncIcqError> {
+    Err(AsyncIcqError::CantCloseChannel)
+}
+
+pub fn on_chan_close_init_execute(
+    _ctx: &impl AbciQueryContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, AsyncIcqError> {
+    Err(AsyncIcqError::CantCloseChannel)
+}
+
+pub fn on_chan_close_confirm_validate(
+    _ctx: &impl AbciQueryContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), AsyncIcqError> {
+    Ok(())
+}
+
+pub fn on_chan_close_confirm_execute(
+    _ctx: &impl AbciQueryContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, AsyncIcqError> {
+    Ok(ModuleExtras::empty())
+}
+
+/// Executes the queries of the packet. The results are returned in a
+/// successful acknowledgement, whose result is the base64-encoded JSON
+/// [`InterchainQueryPacketAck`], as expected by the controller chains.
+pub fn on_recv_packet_execute(
+    ctx_b: &impl AbciQueryContext,
+    packet: &Packet,
+) -> (ModuleExtras, RecvPacketResult) {
+    let Ok(data) = serde_json::from_slice::<InterchainQueryPacketData>(&packet.data) else {
+        let ack = AcknowledgementStatus::error(AsyncIcqError::PacketDataDeserialization.into());
+        return (ModuleExtras::empty(), ack.into());
+    };
+
+    let (query_count, result) = match CosmosQuery::decode_vec(&data.data) {
+        Ok(query) => (
+            query.requests.len() as u64,
+            execute_queries(ctx_b, &query).and_then(|response| {
+                let ack = InterchainQueryPacketAck {
+                    data: response.encode_vec(),
+                };
+                let ack = serde_json::to_vec(&ack).map_err(|_| AsyncIcqError::AckSerialization)?;
+                StatusValue::new(BASE64_STANDARD.encode(ack))
+                    .map_err(|_| AsyncIcqError::AckSerialization)
+            }),
+        ),
+        Err(e) => (0, Err(e)),
+    };
+
+    let ack = match result {
+        Ok(result) => AcknowledgementStatus::success(result),
+        Err(error) => AcknowledgementStatus::error(error.into()),
+    };
+
+    let recv_event = RecvEvent {
+        query_count,
+        success: ack.is_successful(),
+    };
+
+    let extras = ModuleExtras {
+        events: vec![recv_event.into()],
+        log: Vec::new(),
+    };
+
+    (extras, ack.into())
+}
+
+pub fn on_acknowledgement_packet_validate(
+    _ctx: &impl AbciQueryContext,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> Result<(), AsyncIcqError> {
+    Err(AsyncIcqError::UnexpectedAcknowledgement)
+}
+
+pub fn on_acknowledgement_packet_execute(
+    _ctx: &impl AbciQueryContext,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), AsyncIcqError>) {
+    (
+        ModuleExtras::empty(),
+        Err(AsyncIcqError::UnexpectedAcknowledgement),
+    )
+}
+
+pub fn on_timeout_packet_validate(
+    _ctx: &impl AbciQueryContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> Result<(), AsyncIcqError> {
+    Err(AsyncIcqError::UnexpectedAcknowledgement)
+}
+
+pub fn on_timeout_packet_execute(
+    _ctx: &impl AbciQueryContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), AsyncIcqError>) {
+    (
+        ModuleExtras::empty(),
+        Err(AsyncIcqError::UnexpectedAcknowledgement),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use ibc_app_async_icq_types::packet::{AbciQueryRequest, AbciQueryResponse, CosmosResponse};
+    use ibc_app_async_icq_types::{IcqHostParams, PORT_ID};
+    use ibc_core::channel::types::timeout::TimeoutHeight;
+    use ibc_core::host::types::identifiers::Sequence;
+    use ibc_core::primitives::Timestamp;
+    use tendermint::abci::Code;
+
+    use super::*;
+
+    const BALANCES_PATH: &str = "/cosmos.bank.v1beta1.Query/AllBalances";
+
+    struct MockQueryContext {
+        params: IcqHostParams,
+    }
+
+    impl AbciQueryContext for MockQueryContext {
+        fn get_port(&self) -> Result<PortId, AsyncIcqError> {
+            Ok(PORT_ID)
+        }
+
+        fn icq_host_params(&self) -> Result<IcqHostParams, AsyncIcqError> {
+            Ok(self.params.clone())
+        }
+
+        /// Echoes the query data, and fails on empty queries.
+        fn abci_query(
+            &self,
+            request: &AbciQueryRequest,
+        ) -> Result<AbciQueryResponse, AsyncIcqError> {
+            let code = if request.data.is_empty() {
+                Code::from(1)
+            } else {
+                Code::Ok
+            };
+
+            Ok(AbciQueryResponse {
+                code,
+                log: String::new(),
+                info: String::new(),
+                index: 0,
+                key: Default::default(),
+                value: request.data.clone(),
+                proof: None,
+                height: Default::default(),
+                codespace: String::new(),
+            })
+        }
+    }
+
+    fn ctx() -> MockQueryContext {
+        MockQueryContext {
+            params: IcqHostParams::new(true, vec![BALANCES_PATH.to_string()]),
+        }
+    }
+
+    fn query_packet(requests: Vec<AbciQueryRequest>) -> Packet {
+        let data = InterchainQueryPacketData {
+            data: CosmosQuery { requests }.encode_vec(),
+            memo: String::new(),
+        };
+
+        Packet {
+            seq_on_a: Sequence::from(1),
+            port_id_on_a: PortId::from_static("icqcontroller"),
+            chan_id_on_a: ChannelId::new(0),
+            port_id_on_b: PORT_ID,
+            chan_id_on_b: ChannelId::new(0),
+            data: serde_json::to_vec(&data).unwrap(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    fn request(path: &str, data: &[u8]) -> AbciQueryRequest {
+        AbciQueryRequest {
+            data: data.to_vec().into(),
+            path: path.to_string(),
+            height: Default::default(),
+            prove: false,
+        }
+    }
+
+    fn ack_status(ack: &Acknowledgement) -> AcknowledgementStatus {
+        serde_json::from_slice(ack.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_allowed_queries_are_answered() {
+        let packet = query_packet(vec![
+            request(BALANCES_PATH, b"first"),
+            request(BALANCES_PATH, b"second"),
+        ]);
+
+        let (_, result) = on_recv_packet_execute(&ctx(), &packet);
+        let ack = result.into_acknowledgement().unwrap();
+
+        let AcknowledgementStatus::Success(result) = ack_status(&ack) else {
+            panic!("queries must succeed");
+        };
+        let ack_bytes = BASE64_STANDARD.decode(result.to_string()).unwrap();
+        let ack: InterchainQueryPacketAck = serde_json::from_slice(&ack_bytes).unwrap();
+        let response = CosmosResponse::decode_vec(&ack.data).unwrap();
+
+        let values: Vec<_> = response
+            .responses
+            .iter()
+            .map(|response| response.value.to_vec())
+            .collect();
+        assert_eq!(values, vec![b"first".to_vec(), b"second".to_vec()]);
+    }
+
+    #[test]
+    fn test_rejected_queries() {
+        let disallowed = query_packet(vec![
+            request(BALANCES_PATH, b"first"),
+            request("/cosmos.bank.v1beta1.Query/Balance", b"second"),
+        ]);
+        let mut with_proof = request(BALANCES_PATH, b"first");
+        with_proof.prove = true;
+        let with_proof = query_packet(vec![with_proof]);
+        let failing = query_packet(vec![request(BALANCES_PATH, b"")]);
+
+        for packet in [disallowed, with_proof, failing] {
+            let (_, result) = on_recv_packet_execute(&ctx(), &packet);
+            let ack = result.into_acknowledgement().unwrap();
+            assert!(!ack_status(&ack).is_successful());
+        }
+
+        let disabled = MockQueryContext {
+            params: IcqHostParams::new(false, vec![BALANCES_PATH.to_string()]),
+        };
+        let (_, result) = on_recv_packet_execute(
+            &disabled,
+            &query_packet(vec![request(BALANCES_PATH, b"first")]),
+        );
+        let ack = result.into_acknowledgement().unwrap();
+        assert!(!ack_status(&ack).is_successful());
+    }
+}
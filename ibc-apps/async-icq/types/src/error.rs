@@ -0,0 +1,101 @@
+//! Defines the async ICQ error type
+use displaydoc::Display;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::channel::Order;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::PortId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::ErrorCode;
+
+#[derive(Display, Debug)]
+pub enum AsyncIcqError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// expected `{expect_order}` channel, got `{got_order}`
+    ChannelNotUnordered {
+        expect_order: Order,
+        got_order: Order,
+    },
+    /// channel handshake must be initiated by the controller chain
+    InvalidHostChannelInit,
+    /// channel cannot be closed
+    CantCloseChannel,
+    /// invalid port: `{port_id}`, expected `{exp_port_id}`
+    InvalidPort {
+        port_id: PortId,
+        exp_port_id: PortId,
+    },
+    /// failed to deserialize packet data
+    PacketDataDeserialization,
+    /// failed to serialize acknowledgement
+    AckSerialization,
+    /// the host does not expect acknowledgements nor timeouts
+    UnexpectedAcknowledgement,
+    /// decoding queries error: `{reason}`
+    DecodeQueries { reason: String },
+    /// the host is not enabled
+    HostDisabled,
+    /// query path `{path}` is not allowed
+    QueryNotAllowed { path: String },
+    /// query on path `{path}` must not specify a height
+    QueryHeightNotAllowed { path: String },
+    /// query on path `{path}` must not request a proof
+    QueryProofNotAllowed { path: String },
+    /// query on path `{path}` failed with code `{code}`: `{log}`
+    QueryFailed {
+        path: String,
+        code: u32,
+        log: String,
+    },
+    /// other error: `{0}`
+    Other(String),
+}
+
+impl AsyncIcqError {
+    /// Returns the stable error code of this error.
+    ///
+    /// Errors wrapping another IBC error report the code of the wrapped error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("interchainquery", code);
+
+        match self {
+            Self::ContextError(e) => e.code(),
+            Self::ChannelNotUnordered { .. } => code(1),
+            Self::InvalidHostChannelInit => code(2),
+            Self::CantCloseChannel => code(3),
+            Self::InvalidPort { .. } => code(4),
+            Self::PacketDataDeserialization => code(5),
+            Self::AckSerialization => code(6),
+            Self::UnexpectedAcknowledgement => code(7),
+            Self::DecodeQueries { .. } => code(8),
+            Self::HostDisabled => code(9),
+            Self::QueryNotAllowed { .. } => code(10),
+            Self::QueryHeightNotAllowed { .. } => code(11),
+            Self::QueryProofNotAllowed { .. } => code(12),
+            Self::QueryFailed { .. } => code(13),
+            Self::Other(_) => code(14),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AsyncIcqError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for AsyncIcqError {
+    fn from(err: ContextError) -> AsyncIcqError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<AsyncIcqError> for StatusValue {
+    fn from(err: AsyncIcqError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
@@ -0,0 +1,33 @@
+//! Defines all async ICQ event types
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::MODULE_ID_STR;
+
+const EVENT_TYPE_PACKET: &str = "icq_packet";
+
+/// Event emitted by the `onRecvPacket` module callback to indicate that the
+/// queries of a packet were processed
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RecvEvent {
+    pub query_count: u64,
+    pub success: bool,
+}
+
+impl From<RecvEvent> for ModuleEvent {
+    fn from(ev: RecvEvent) -> Self {
+        let RecvEvent {
+            query_count,
+            success,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("query_count", query_count).into(),
+                ("success", success).into(),
+            ],
+        }
+    }
+}
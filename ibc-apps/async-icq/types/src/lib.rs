@@ -0,0 +1,41 @@
+//! Implementation of the [async interchain
+//! queries](https://github.com/cosmos/ibc-apps/tree/main/modules/async-icq)
+//! (ICQ) data structures, allowing counterparty chains to query the state of
+//! the host chain over IBC.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod params;
+
+use ibc_core::host::types::identifiers::PortId;
+pub use params::*;
+pub mod error;
+pub mod events;
+pub mod packet;
+
+/// Module identifier for the async ICQ host application.
+pub const MODULE_ID_STR: &str = "interchainquery";
+
+/// The port identifier that the async ICQ host applications
+/// typically bind with.
+pub const PORT_ID_STR: &str = "icqhost";
+
+/// The port identifier that the async ICQ host applications typically bind with, as
+/// a [`PortId`] validated at compile time.
+pub const PORT_ID: PortId = PortId::from_static(PORT_ID_STR);
+
+/// Async ICQ application current version.
+pub const VERSION: &str = "icq-1";
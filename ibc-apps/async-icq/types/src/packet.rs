@@ -0,0 +1,195 @@
+//! Contains the packet types exchanged with the controller chain: the
+//! `InterchainQueryPacketData` carrying the queries, and the
+//! `InterchainQueryPacketAck` carrying their results.
+use ibc_core::primitives::prelude::*;
+use prost::Message;
+pub use tendermint::abci::request::Query as AbciQueryRequest;
+pub use tendermint::abci::response::Query as AbciQueryResponse;
+use tendermint_proto::v0_38::abci::{RequestQuery, ResponseQuery};
+
+use crate::error::AsyncIcqError;
+
+/// Raw `ibc.applications.interchain_query.v1.CosmosQuery` message, which is
+/// not part of `ibc-proto`.
+#[derive(Clone, PartialEq, Message)]
+pub struct RawCosmosQuery {
+    #[prost(message, repeated, tag = "1")]
+    pub requests: Vec<RequestQuery>,
+}
+
+/// Raw `ibc.applications.interchain_query.v1.CosmosResponse` message, which
+/// is not part of `ibc-proto`.
+#[derive(Clone, PartialEq, Message)]
+pub struct RawCosmosResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub responses: Vec<ResponseQuery>,
+}
+
+/// The ABCI queries requested by the controller chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CosmosQuery {
+    pub requests: Vec<AbciQueryRequest>,
+}
+
+impl CosmosQuery {
+    /// Decodes the Protobuf-encoded queries carried by a query packet.
+    pub fn decode_vec(bytes: &[u8]) -> Result<Self, AsyncIcqError> {
+        let raw = RawCosmosQuery::decode(bytes).map_err(|e| AsyncIcqError::DecodeQueries {
+            reason: e.to_string(),
+        })?;
+
+        raw.try_into()
+    }
+
+    pub fn encode_vec(self) -> Vec<u8> {
+        RawCosmosQuery::from(self).encode_to_vec()
+    }
+}
+
+impl TryFrom<RawCosmosQuery> for CosmosQuery {
+    type Error = AsyncIcqError;
+
+    fn try_from(raw: RawCosmosQuery) -> Result<Self, Self::Error> {
+        let requests = raw
+            .requests
+            .into_iter()
+            .map(AbciQueryRequest::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(|e| AsyncIcqError::DecodeQueries {
+                reason: e.to_string(),
+            })?;
+
+        Ok(Self { requests })
+    }
+}
+
+impl From<CosmosQuery> for RawCosmosQuery {
+    fn from(query: CosmosQuery) -> Self {
+        Self {
+            requests: query.requests.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The results of the ABCI queries, in the order of the requests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CosmosResponse {
+    pub responses: Vec<AbciQueryResponse>,
+}
+
+impl CosmosResponse {
+    /// Decodes the Protobuf-encoded query results carried by an
+    /// acknowledgement.
+    pub fn decode_vec(bytes: &[u8]) -> Result<Self, AsyncIcqError> {
+        let raw = RawCosmosResponse::decode(bytes).map_err(|e| AsyncIcqError::DecodeQueries {
+            reason: e.to_string(),
+        })?;
+
+        raw.try_into()
+    }
+
+    pub fn encode_vec(self) -> Vec<u8> {
+        RawCosmosResponse::from(self).encode_to_vec()
+    }
+}
+
+impl TryFrom<RawCosmosResponse> for CosmosResponse {
+    type Error = AsyncIcqError;
+
+    fn try_from(raw: RawCosmosResponse) -> Result<Self, Self::Error> {
+        let responses = raw
+            .responses
+            .into_iter()
+            .map(AbciQueryResponse::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(|e| AsyncIcqError::DecodeQueries {
+                reason: e.to_string(),
+            })?;
+
+        Ok(Self { responses })
+    }
+}
+
+impl From<CosmosResponse> for RawCosmosResponse {
+    fn from(response: CosmosResponse) -> Self {
+        Self {
+            responses: response.responses.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Defines the structure of the query packet bytes, as JSON-encoded by the
+/// controller chain.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterchainQueryPacketData {
+    /// The Protobuf-encoded [`CosmosQuery`].
+    #[cfg_attr(feature = "serde", serde(with = "base64_bytes"))]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    pub data: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub memo: String,
+}
+
+/// Defines the structure of the acknowledgement result bytes of a successful
+/// query packet.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterchainQueryPacketAck {
+    /// The Protobuf-encoded [`CosmosResponse`].
+    #[cfg_attr(feature = "serde", serde(with = "base64_bytes"))]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    pub data: Vec<u8>,
+}
+
+/// Serializes bytes as base64 strings, following the Protobuf JSON mapping.
+#[cfg(feature = "serde")]
+mod base64_bytes {
+    use base64::prelude::BASE64_STANDARD;
+    use base64::Engine;
+    use ibc_core::primitives::prelude::*;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64_STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        BASE64_STANDARD
+            .decode(String::deserialize(deserializer)?)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packet_data_json_roundtrip() {
+        let query = CosmosQuery {
+            requests: vec![AbciQueryRequest {
+                data: vec![1, 2, 3].into(),
+                path: "/cosmos.bank.v1beta1.Query/AllBalances".to_string(),
+                height: Default::default(),
+                prove: false,
+            }],
+        };
+        let packet_data = InterchainQueryPacketData {
+            data: query.clone().encode_vec(),
+            memo: String::new(),
+        };
+
+        let json = serde_json::to_string(&packet_data).unwrap();
+        let decoded: InterchainQueryPacketData = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, packet_data);
+        assert_eq!(CosmosQuery::decode_vec(&decoded.data).unwrap(), query);
+
+        // The memo may be omitted by the controller chain.
+        let decoded: InterchainQueryPacketData =
+            serde_json::from_str(r#"{"data":"AQI="}"#).unwrap();
+        assert_eq!(decoded.data, vec![1, 2]);
+    }
+}
@@ -0,0 +1,48 @@
+//! Defines the parameters of the async ICQ host application.
+use ibc_core::primitives::prelude::*;
+
+/// Parameters of the async ICQ host application.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IcqHostParams {
+    /// Whether the host executes the received queries.
+    pub host_enabled: bool,
+    /// The ABCI query paths counterparties are allowed to query, e.g.
+    /// `/cosmos.bank.v1beta1.Query/AllBalances`.
+    pub allow_queries: Vec<String>,
+}
+
+impl IcqHostParams {
+    pub fn new(host_enabled: bool, allow_queries: Vec<String>) -> Self {
+        Self {
+            host_enabled,
+            allow_queries,
+        }
+    }
+
+    /// Checks whether queries on the given ABCI path are allowed.
+    pub fn is_query_allowed(&self, path: &str) -> bool {
+        self.allow_queries.iter().any(|allowed| allowed == path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_allowlist() {
+        let params = IcqHostParams::new(
+            true,
+            vec!["/cosmos.bank.v1beta1.Query/AllBalances".to_string()],
+        );
+
+        assert!(params.is_query_allowed("/cosmos.bank.v1beta1.Query/AllBalances"));
+        assert!(!params.is_query_allowed("/cosmos.bank.v1beta1.Query/Balance"));
+        assert!(!params.is_query_allowed("/cosmos.bank.v1beta1.Query"));
+        assert!(
+            !IcqHostParams::default().is_query_allowed("/cosmos.bank.v1beta1.Query/AllBalances")
+        );
+    }
+}
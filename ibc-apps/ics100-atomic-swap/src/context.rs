@@ -0,0 +1,78 @@
+//! Defines the main context traits and IBC module callbacks
+
+use ibc_app_atomic_swap_types::error::AtomicSwapError;
+use ibc_app_atomic_swap_types::{AtomicSwapOrder, OrderId, PrefixedCoin};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::{Signer, Timestamp};
+
+/// Methods required in atomic swap validation, to be implemented by the host
+pub trait AtomicSwapValidationContext {
+    type AccountId: TryFrom<Signer>;
+
+    /// get_port returns the portID for the atomic swap module.
+    fn get_port(&self) -> Result<PortId, AtomicSwapError>;
+
+    /// Returns the current timestamp of the host chain, against which the
+    /// expiration of the orders is checked.
+    fn host_timestamp(&self) -> Result<Timestamp, AtomicSwapError>;
+
+    /// Returns the order with the given identifier, if any.
+    fn order(&self, order_id: &OrderId) -> Result<Option<AtomicSwapOrder>, AtomicSwapError>;
+
+    /// Validates that the tokens can be escrowed successfully in the escrow
+    /// account of the given channel.
+    fn escrow_coins_validate(
+        &self,
+        from_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), AtomicSwapError>;
+
+    /// Validates that the tokens can be unescrowed successfully from the
+    /// escrow account of the given channel.
+    fn unescrow_coins_validate(
+        &self,
+        to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), AtomicSwapError>;
+
+    /// Converts a maker or taker into an account of the host chain.
+    ///
+    /// Defaults to the `TryFrom<Signer>` conversion of `AccountId`.
+    fn convert_signer(&self, signer: &Signer) -> Result<Self::AccountId, AtomicSwapError> {
+        signer
+            .clone()
+            .try_into()
+            .map_err(|_| AtomicSwapError::ParseAccountFailure)
+    }
+}
+
+/// Methods required in atomic swap execution, to be implemented by the host.
+pub trait AtomicSwapExecutionContext: AtomicSwapValidationContext {
+    /// Stores the given order, replacing any order with the same identifier.
+    fn store_order(&mut self, order: AtomicSwapOrder) -> Result<(), AtomicSwapError>;
+
+    /// Executes the escrow of the tokens in the escrow account of the given
+    /// channel.
+    fn escrow_coins_execute(
+        &mut self,
+        from_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), AtomicSwapError>;
+
+    /// Executes the unescrow of the tokens from the escrow account of the
+    /// given channel.
+    fn unescrow_coins_execute(
+        &mut self,
+        to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), AtomicSwapError>;
+}
@@ -0,0 +1,125 @@
+use ibc_app_atomic_swap_types::error::AtomicSwapError;
+use ibc_app_atomic_swap_types::events::CancelSwapEvent;
+use ibc_app_atomic_swap_types::msgs::MsgCancelSwap;
+use ibc_app_atomic_swap_types::packet::{AtomicSwapPacketData, CancelSwapData};
+use ibc_app_atomic_swap_types::{AtomicSwapOrder, OrderSide, MODULE_ID_STR};
+use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use super::{check_open, get_order, swap_packet};
+use crate::context::{AtomicSwapExecutionContext, AtomicSwapValidationContext};
+
+/// Requests the cancellation of an order. Equivalent to calling [`cancel_swap_validate`], followed by [`cancel_swap_execute`].
+pub fn cancel_swap<SendPacketCtx, SwapCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    swap_ctx_a: &mut SwapCtx,
+    msg: MsgCancelSwap,
+) -> Result<(), AtomicSwapError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    SwapCtx: AtomicSwapExecutionContext,
+{
+    cancel_swap_validate(send_packet_ctx_a, swap_ctx_a, msg.clone())?;
+    cancel_swap_execute(send_packet_ctx_a, swap_ctx_a, msg)
+}
+
+/// Validates the cancellation. If this succeeds, then it is legal to request the cancellation with [`cancel_swap_execute`].
+pub fn cancel_swap_validate<SendPacketCtx, SwapCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    swap_ctx_a: &SwapCtx,
+    msg: MsgCancelSwap,
+) -> Result<(), AtomicSwapError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    SwapCtx: AtomicSwapValidationContext,
+{
+    let order = get_order(swap_ctx_a, &msg.data.order_id, OrderSide::Native)?;
+
+    check_cancel(&order, &msg.data)?;
+
+    let packet = swap_packet(
+        send_packet_ctx_a,
+        &order.port_id,
+        &order.channel_id,
+        &AtomicSwapPacketData::new(msg.data, msg.memo),
+        msg.timeout_height_on_b,
+        msg.timeout_timestamp_on_b,
+    )?;
+
+    send_packet_validate(send_packet_ctx_a, &packet)?;
+
+    Ok(())
+}
+
+/// Executes the cancellation request. A prior call to [`cancel_swap_validate`] MUST have succeeded.
+///
+/// The order is only cancelled, and the maker's tokens refunded, once the
+/// counterparty chain acknowledges the cancellation.
+pub fn cancel_swap_execute<SendPacketCtx, SwapCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    swap_ctx_a: &mut SwapCtx,
+    msg: MsgCancelSwap,
+) -> Result<(), AtomicSwapError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    SwapCtx: AtomicSwapExecutionContext,
+{
+    let order = get_order(swap_ctx_a, &msg.data.order_id, OrderSide::Native)?;
+
+    let packet = swap_packet(
+        send_packet_ctx_a,
+        &order.port_id,
+        &order.channel_id,
+        &AtomicSwapPacketData::new(msg.data.clone(), msg.memo),
+        msg.timeout_height_on_b,
+        msg.timeout_timestamp_on_b,
+    )?;
+
+    send_packet_execute(send_packet_ctx_a, packet)?;
+
+    {
+        send_packet_ctx_a.log_message(format!(
+            "IBC atomic swap: cancellation of order {} requested by {}",
+            msg.data.order_id, msg.data.maker_address
+        ))?;
+
+        let cancel_swap_event = CancelSwapEvent {
+            order_id: msg.data.order_id,
+            maker: msg.data.maker_address,
+        };
+        send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(cancel_swap_event).into())?;
+
+        send_packet_ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the order can be cancelled by the given maker. Both chains
+/// perform this check, respectively when the cancellation is requested and
+/// when the `CancelSwap` packet is received.
+pub(super) fn check_cancel(
+    order: &AtomicSwapOrder,
+    cancel: &CancelSwapData,
+) -> Result<(), AtomicSwapError> {
+    check_open(order)?;
+
+    if order.taker.is_some() {
+        return Err(AtomicSwapError::OrderAlreadyTaken {
+            order_id: order.id.clone(),
+        });
+    }
+
+    if order.maker.maker_address != cancel.maker_address {
+        return Err(AtomicSwapError::UnauthorizedCanceller {
+            order_id: order.id.clone(),
+            maker: order.maker.maker_address.clone(),
+            sender: cancel.maker_address.clone(),
+        });
+    }
+
+    Ok(())
+}
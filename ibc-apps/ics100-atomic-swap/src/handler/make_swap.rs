@@ -0,0 +1,161 @@
+use ibc_app_atomic_swap_types::error::AtomicSwapError;
+use ibc_app_atomic_swap_types::events::MakeSwapEvent;
+use ibc_app_atomic_swap_types::msgs::MsgMakeSwap;
+use ibc_app_atomic_swap_types::packet::{AtomicSwapPacketData, MakeSwapData};
+use ibc_app_atomic_swap_types::{
+    AtomicSwapOrder, OrderId, OrderSide, OrderStatus, PrefixedCoin, MODULE_ID_STR,
+};
+use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use super::swap_packet;
+use crate::context::{AtomicSwapExecutionContext, AtomicSwapValidationContext};
+
+/// Makes an atomic swap order. Equivalent to calling [`make_swap_validate`], followed by [`make_swap_execute`].
+pub fn make_swap<SendPacketCtx, SwapCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    swap_ctx_a: &mut SwapCtx,
+    msg: MsgMakeSwap,
+) -> Result<(), AtomicSwapError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    SwapCtx: AtomicSwapExecutionContext,
+{
+    make_swap_validate(send_packet_ctx_a, swap_ctx_a, msg.clone())?;
+    make_swap_execute(send_packet_ctx_a, swap_ctx_a, msg)
+}
+
+/// Validates the order. If this succeeds, then it is legal to make the order with [`make_swap_execute`].
+pub fn make_swap_validate<SendPacketCtx, SwapCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    swap_ctx_a: &SwapCtx,
+    msg: MsgMakeSwap,
+) -> Result<(), AtomicSwapError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    SwapCtx: AtomicSwapValidationContext,
+{
+    check_non_zero(&msg.data.sell_token)?;
+    check_non_zero(&msg.data.buy_token)?;
+
+    let maker = swap_ctx_a.convert_signer(&msg.data.maker_address)?;
+
+    let packet = swap_packet(
+        send_packet_ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &AtomicSwapPacketData::new(msg.data.clone(), msg.memo),
+        msg.timeout_height_on_b,
+        msg.timeout_timestamp_on_b,
+    )?;
+
+    let order_id = OrderId::from_packet(&packet);
+
+    if swap_ctx_a.order(&order_id)?.is_some() {
+        return Err(AtomicSwapError::OrderAlreadyExists { order_id });
+    }
+
+    check_not_expired(swap_ctx_a, &order_id, &msg.data)?;
+
+    swap_ctx_a.escrow_coins_validate(
+        &maker,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.data.sell_token,
+    )?;
+
+    send_packet_validate(send_packet_ctx_a, &packet)?;
+
+    Ok(())
+}
+
+/// Executes the order. A prior call to [`make_swap_validate`] MUST have succeeded.
+pub fn make_swap_execute<SendPacketCtx, SwapCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    swap_ctx_a: &mut SwapCtx,
+    msg: MsgMakeSwap,
+) -> Result<(), AtomicSwapError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    SwapCtx: AtomicSwapExecutionContext,
+{
+    let maker = swap_ctx_a.convert_signer(&msg.data.maker_address)?;
+
+    let packet = swap_packet(
+        send_packet_ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &AtomicSwapPacketData::new(msg.data.clone(), msg.memo),
+        msg.timeout_height_on_b,
+        msg.timeout_timestamp_on_b,
+    )?;
+
+    let order_id = OrderId::from_packet(&packet);
+
+    swap_ctx_a.escrow_coins_execute(
+        &maker,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.data.sell_token,
+    )?;
+
+    swap_ctx_a.store_order(AtomicSwapOrder::new(
+        order_id.clone(),
+        OrderSide::Native,
+        OrderStatus::Initial,
+        msg.port_id_on_a,
+        msg.chan_id_on_a,
+        msg.data.clone(),
+    ))?;
+
+    send_packet_execute(send_packet_ctx_a, packet)?;
+
+    {
+        send_packet_ctx_a.log_message(format!(
+            "IBC atomic swap: order {} made by {}, selling {} for {}",
+            order_id, msg.data.maker_address, msg.data.sell_token, msg.data.buy_token
+        ))?;
+
+        let make_swap_event = MakeSwapEvent {
+            order_id,
+            maker: msg.data.maker_address,
+            sell_token: msg.data.sell_token,
+            buy_token: msg.data.buy_token,
+        };
+        send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(make_swap_event).into())?;
+
+        send_packet_ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
+
+fn check_non_zero(token: &PrefixedCoin) -> Result<(), AtomicSwapError> {
+    if token.amount.as_ref().is_zero() {
+        return Err(AtomicSwapError::ZeroAmount {
+            token: token.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+fn check_not_expired(
+    swap_ctx_a: &impl AtomicSwapValidationContext,
+    order_id: &OrderId,
+    data: &MakeSwapData,
+) -> Result<(), AtomicSwapError> {
+    let now = swap_ctx_a.host_timestamp()?;
+
+    if now.is_after(&data.expiration_timestamp) {
+        return Err(AtomicSwapError::OrderExpired {
+            order_id: order_id.clone(),
+            expiration: data.expiration_timestamp,
+        });
+    }
+
+    Ok(())
+}
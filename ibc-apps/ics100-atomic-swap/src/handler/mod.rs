@@ -0,0 +1,144 @@
+//! Implements the processing logic for ICS100 (atomic swap) messages.
+mod cancel_swap;
+mod make_swap;
+mod on_acknowledgement_packet;
+mod on_recv_packet;
+mod take_swap;
+
+pub use cancel_swap::*;
+use ibc_app_atomic_swap_types::error::AtomicSwapError;
+use ibc_app_atomic_swap_types::packet::{AtomicSwapPacketData, TakeSwapData};
+use ibc_app_atomic_swap_types::{AtomicSwapOrder, OrderId, OrderSide};
+use ibc_core::channel::context::SendPacketValidationContext;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Timestamp;
+pub use make_swap::*;
+pub use on_acknowledgement_packet::*;
+pub use on_recv_packet::*;
+pub use take_swap::*;
+
+use crate::context::AtomicSwapValidationContext;
+
+/// Builds the packet carrying the given swap message over the given channel,
+/// using its next send sequence.
+fn swap_packet(
+    send_packet_ctx_a: &impl SendPacketValidationContext,
+    port_id_on_a: &PortId,
+    chan_id_on_a: &ChannelId,
+    data: &AtomicSwapPacketData,
+    timeout_height_on_b: TimeoutHeight,
+    timeout_timestamp_on_b: Timestamp,
+) -> Result<Packet, AtomicSwapError> {
+    let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
+    let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    let port_id_on_b = chan_end_on_a.counterparty().port_id().clone();
+    let chan_id_on_b = chan_end_on_a
+        .counterparty()
+        .channel_id()
+        .ok_or_else(|| AtomicSwapError::DestinationChannelNotFound {
+            port_id: port_id_on_a.clone(),
+            channel_id: chan_id_on_a.clone(),
+        })?
+        .clone();
+
+    let seq_send_path_on_a = SeqSendPath::new(port_id_on_a, chan_id_on_a);
+    let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    let data = serde_json::to_vec(data).map_err(|_| AtomicSwapError::PacketDataSerialization)?;
+
+    Ok(Packet {
+        seq_on_a: sequence,
+        port_id_on_a: port_id_on_a.clone(),
+        chan_id_on_a: chan_id_on_a.clone(),
+        port_id_on_b,
+        chan_id_on_b,
+        data,
+        timeout_height_on_b,
+        timeout_timestamp_on_b,
+    })
+}
+
+/// Returns the stored order with the given identifier, checking that it was
+/// made on the expected side.
+fn get_order(
+    ctx: &impl AtomicSwapValidationContext,
+    order_id: &OrderId,
+    expected_side: OrderSide,
+) -> Result<AtomicSwapOrder, AtomicSwapError> {
+    let order = ctx
+        .order(order_id)?
+        .ok_or_else(|| AtomicSwapError::OrderNotFound {
+            order_id: order_id.clone(),
+        })?;
+
+    if order.side != expected_side {
+        return Err(AtomicSwapError::InvalidOrderSide {
+            order_id: order.id,
+            side: order.side,
+            expected: expected_side,
+        });
+    }
+
+    Ok(order)
+}
+
+/// Checks that the order is still open, i.e. neither cancelled nor completed.
+fn check_open(order: &AtomicSwapOrder) -> Result<(), AtomicSwapError> {
+    if !order.is_open() {
+        return Err(AtomicSwapError::InvalidOrderStatus {
+            order_id: order.id.clone(),
+            status: order.status,
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that the order can be taken by the given taker at the given host
+/// time. Both chains perform this check, respectively when the order is taken
+/// and when the `TakeSwap` packet is received.
+fn check_take(
+    order: &AtomicSwapOrder,
+    take: &TakeSwapData,
+    now: &Timestamp,
+) -> Result<(), AtomicSwapError> {
+    check_open(order)?;
+
+    if order.taker.is_some() {
+        return Err(AtomicSwapError::OrderAlreadyTaken {
+            order_id: order.id.clone(),
+        });
+    }
+
+    if order.is_expired(now) {
+        return Err(AtomicSwapError::OrderExpired {
+            order_id: order.id.clone(),
+            expiration: order.maker.expiration_timestamp,
+        });
+    }
+
+    if let Some(desired_taker) = &order.maker.desired_taker {
+        if desired_taker != &take.taker_address {
+            return Err(AtomicSwapError::UnauthorizedTaker {
+                order_id: order.id.clone(),
+                desired_taker: desired_taker.clone(),
+                taker: take.taker_address.clone(),
+            });
+        }
+    }
+
+    if take.sell_token != order.maker.buy_token {
+        return Err(AtomicSwapError::TokenMismatch {
+            order_id: order.id.clone(),
+            offered: take.sell_token.clone(),
+            expected: order.maker.buy_token.clone(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use ibc_app_atomic_swap_types::error::AtomicSwapError;
+use ibc_app_atomic_swap_types::packet::{AtomicSwapPacketData, SwapMessage};
+use ibc_app_atomic_swap_types::{AtomicSwapOrder, OrderId, OrderSide, OrderStatus, PrefixedCoin};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::Signer;
+
+use super::get_order;
+use crate::context::{AtomicSwapExecutionContext, AtomicSwapValidationContext};
+
+/// Validates the settlement of a sent swap message, once it was either
+/// acknowledged or timed out. Timeouts are settled as failed
+/// acknowledgements.
+pub fn process_ack_packet_validate(
+    ctx: &impl AtomicSwapValidationContext,
+    packet: &Packet,
+    data: &AtomicSwapPacketData,
+    success: bool,
+) -> Result<(), AtomicSwapError> {
+    match (&data.message, success) {
+        // The order was rejected by the counterparty chain, or could not be
+        // relayed: the maker is refunded.
+        (SwapMessage::MakeSwap(_), false) => {
+            let order = get_order(ctx, &OrderId::from_packet(packet), OrderSide::Native)?;
+            validate_release(
+                ctx,
+                &order,
+                &order.maker.maker_address,
+                &order.maker.sell_token,
+            )
+        }
+        (SwapMessage::MakeSwap(_), true) => {
+            get_order(ctx, &OrderId::from_packet(packet), OrderSide::Native).map(|_| ())
+        }
+        // The maker chain released the maker's tokens to the taker: the
+        // taker's tokens are released to the maker.
+        (SwapMessage::TakeSwap(take), true) => {
+            let order = get_order(ctx, &take.order_id, OrderSide::Remote)?;
+            validate_release(
+                ctx,
+                &order,
+                &order.maker.maker_receiving_address,
+                &take.sell_token,
+            )
+        }
+        // The swap did not happen: the taker is refunded.
+        (SwapMessage::TakeSwap(take), false) => {
+            let order = get_order(ctx, &take.order_id, OrderSide::Remote)?;
+            validate_release(ctx, &order, &take.taker_address, &take.sell_token)
+        }
+        // The order was cancelled on the taker chain: the maker is refunded.
+        (SwapMessage::CancelSwap(cancel), true) => {
+            let order = get_order(ctx, &cancel.order_id, OrderSide::Native)?;
+            validate_release(
+                ctx,
+                &order,
+                &order.maker.maker_address,
+                &order.maker.sell_token,
+            )
+        }
+        // The order is left untouched when it could not be cancelled.
+        (SwapMessage::CancelSwap(_), false) => Ok(()),
+    }
+}
+
+/// Executes the settlement of a sent swap message. A prior call to
+/// [`process_ack_packet_validate`] MUST have succeeded.
+pub fn process_ack_packet_execute(
+    ctx: &mut impl AtomicSwapExecutionContext,
+    packet: &Packet,
+    data: &AtomicSwapPacketData,
+    success: bool,
+) -> Result<(), AtomicSwapError> {
+    match (&data.message, success) {
+        (SwapMessage::MakeSwap(_), false) => {
+            let mut order = get_order(ctx, &OrderId::from_packet(packet), OrderSide::Native)?;
+            let maker = order.maker.maker_address.clone();
+            let sell_token = order.maker.sell_token.clone();
+            execute_release(ctx, &order, &maker, &sell_token)?;
+
+            order.status = OrderStatus::Cancel;
+            order.cancel_timestamp = Some(ctx.host_timestamp()?);
+            ctx.store_order(order)
+        }
+        (SwapMessage::MakeSwap(_), true) => {
+            let mut order = get_order(ctx, &OrderId::from_packet(packet), OrderSide::Native)?;
+
+            // The order may already have been completed if the `TakeSwap`
+            // packet was relayed before this acknowledgement.
+            if order.status == OrderStatus::Initial {
+                order.status = OrderStatus::Sync;
+                ctx.store_order(order)?;
+            }
+
+            Ok(())
+        }
+        (SwapMessage::TakeSwap(take), true) => {
+            let mut order = get_order(ctx, &take.order_id, OrderSide::Remote)?;
+            let maker_receiver = order.maker.maker_receiving_address.clone();
+            execute_release(ctx, &order, &maker_receiver, &take.sell_token)?;
+
+            order.status = OrderStatus::Complete;
+            order.complete_timestamp = Some(ctx.host_timestamp()?);
+            ctx.store_order(order)
+        }
+        (SwapMessage::TakeSwap(take), false) => {
+            let mut order = get_order(ctx, &take.order_id, OrderSide::Remote)?;
+            execute_release(ctx, &order, &take.taker_address, &take.sell_token)?;
+
+            // The order can be taken again.
+            order.taker = None;
+            ctx.store_order(order)
+        }
+        (SwapMessage::CancelSwap(cancel), true) => {
+            let mut order = get_order(ctx, &cancel.order_id, OrderSide::Native)?;
+            let maker = order.maker.maker_address.clone();
+            let sell_token = order.maker.sell_token.clone();
+            execute_release(ctx, &order, &maker, &sell_token)?;
+
+            order.status = OrderStatus::Cancel;
+            order.cancel_timestamp = Some(ctx.host_timestamp()?);
+            ctx.store_order(order)
+        }
+        (SwapMessage::CancelSwap(_), false) => Ok(()),
+    }
+}
+
+/// Validates the release of escrowed tokens of the order to the given receiver.
+fn validate_release(
+    ctx: &impl AtomicSwapValidationContext,
+    order: &AtomicSwapOrder,
+    receiver: &Signer,
+    token: &PrefixedCoin,
+) -> Result<(), AtomicSwapError> {
+    let receiver = ctx.convert_signer(receiver)?;
+
+    ctx.unescrow_coins_validate(&receiver, &order.port_id, &order.channel_id, token)
+}
+
+/// Releases escrowed tokens of the order to the given receiver.
+fn execute_release(
+    ctx: &mut impl AtomicSwapExecutionContext,
+    order: &AtomicSwapOrder,
+    receiver: &Signer,
+    token: &PrefixedCoin,
+) -> Result<(), AtomicSwapError> {
+    let receiver = ctx.convert_signer(receiver)?;
+
+    ctx.unescrow_coins_execute(&receiver, &order.port_id, &order.channel_id, token)
+}
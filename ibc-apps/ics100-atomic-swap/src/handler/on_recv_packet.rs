@@ -0,0 +1,101 @@
+use ibc_app_atomic_swap_types::error::AtomicSwapError;
+use ibc_app_atomic_swap_types::packet::{
+    AtomicSwapPacketData, CancelSwapData, MakeSwapData, SwapMessage, TakeSwapData,
+};
+use ibc_app_atomic_swap_types::{AtomicSwapOrder, OrderId, OrderSide, OrderStatus};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::module::ModuleExtras;
+
+use super::cancel_swap::check_cancel;
+use super::{check_take, get_order};
+use crate::context::AtomicSwapExecutionContext;
+
+/// This function handles the swap messages received by the counterparty
+/// chain. Every check is performed before the state is modified, so that a
+/// failing message leaves no trace.
+pub fn process_recv_packet_execute<Ctx: AtomicSwapExecutionContext>(
+    ctx: &mut Ctx,
+    packet: &Packet,
+    data: AtomicSwapPacketData,
+) -> Result<ModuleExtras, AtomicSwapError> {
+    match data.message {
+        SwapMessage::MakeSwap(make) => recv_make_swap(ctx, packet, make),
+        SwapMessage::TakeSwap(take) => recv_take_swap(ctx, take),
+        SwapMessage::CancelSwap(cancel) => recv_cancel_swap(ctx, cancel),
+    }?;
+
+    Ok(ModuleExtras::empty())
+}
+
+/// Records an order made on the counterparty chain, which can now be taken.
+fn recv_make_swap<Ctx: AtomicSwapExecutionContext>(
+    ctx: &mut Ctx,
+    packet: &Packet,
+    make: MakeSwapData,
+) -> Result<(), AtomicSwapError> {
+    let order_id = OrderId::from_packet(packet);
+
+    if ctx.order(&order_id)?.is_some() {
+        return Err(AtomicSwapError::OrderAlreadyExists { order_id });
+    }
+
+    ctx.store_order(AtomicSwapOrder::new(
+        order_id,
+        OrderSide::Remote,
+        OrderStatus::Sync,
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        make,
+    ))
+}
+
+/// Completes the swap on the maker chain, releasing the maker's escrowed
+/// tokens to the taker.
+fn recv_take_swap<Ctx: AtomicSwapExecutionContext>(
+    ctx: &mut Ctx,
+    take: TakeSwapData,
+) -> Result<(), AtomicSwapError> {
+    let mut order = get_order(ctx, &take.order_id, OrderSide::Native)?;
+
+    let now = ctx.host_timestamp()?;
+    check_take(&order, &take, &now)?;
+
+    let taker_receiver = ctx.convert_signer(&take.taker_receiving_address)?;
+
+    ctx.unescrow_coins_validate(
+        &taker_receiver,
+        &order.port_id,
+        &order.channel_id,
+        &order.maker.sell_token,
+    )?;
+    ctx.unescrow_coins_execute(
+        &taker_receiver,
+        &order.port_id,
+        &order.channel_id,
+        &order.maker.sell_token,
+    )?;
+
+    order.taker = Some(take);
+    order.status = OrderStatus::Complete;
+    order.complete_timestamp = Some(now);
+
+    ctx.store_order(order)
+}
+
+/// Cancels an order that was not taken on the taker chain.
+fn recv_cancel_swap<Ctx: AtomicSwapExecutionContext>(
+    ctx: &mut Ctx,
+    cancel: CancelSwapData,
+) -> Result<(), AtomicSwapError> {
+    let mut order = get_order(ctx, &cancel.order_id, OrderSide::Remote)?;
+
+    check_cancel(&order, &cancel)?;
+
+    let now = ctx.host_timestamp()?;
+
+    order.status = OrderStatus::Cancel;
+    order.cancel_timestamp = Some(now);
+
+    ctx.store_order(order)
+}
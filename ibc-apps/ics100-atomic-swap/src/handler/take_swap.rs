@@ -0,0 +1,122 @@
+use ibc_app_atomic_swap_types::error::AtomicSwapError;
+use ibc_app_atomic_swap_types::events::TakeSwapEvent;
+use ibc_app_atomic_swap_types::msgs::MsgTakeSwap;
+use ibc_app_atomic_swap_types::packet::AtomicSwapPacketData;
+use ibc_app_atomic_swap_types::{OrderSide, MODULE_ID_STR};
+use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use super::{check_take, get_order, swap_packet};
+use crate::context::{AtomicSwapExecutionContext, AtomicSwapValidationContext};
+
+/// Takes an order made on the counterparty chain. Equivalent to calling [`take_swap_validate`], followed by [`take_swap_execute`].
+pub fn take_swap<SendPacketCtx, SwapCtx>(
+    send_packet_ctx_b: &mut SendPacketCtx,
+    swap_ctx_b: &mut SwapCtx,
+    msg: MsgTakeSwap,
+) -> Result<(), AtomicSwapError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    SwapCtx: AtomicSwapExecutionContext,
+{
+    take_swap_validate(send_packet_ctx_b, swap_ctx_b, msg.clone())?;
+    take_swap_execute(send_packet_ctx_b, swap_ctx_b, msg)
+}
+
+/// Validates taking the order. If this succeeds, then it is legal to take the order with [`take_swap_execute`].
+pub fn take_swap_validate<SendPacketCtx, SwapCtx>(
+    send_packet_ctx_b: &SendPacketCtx,
+    swap_ctx_b: &SwapCtx,
+    msg: MsgTakeSwap,
+) -> Result<(), AtomicSwapError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    SwapCtx: AtomicSwapValidationContext,
+{
+    let order = get_order(swap_ctx_b, &msg.data.order_id, OrderSide::Remote)?;
+
+    let now = swap_ctx_b.host_timestamp()?;
+    check_take(&order, &msg.data, &now)?;
+
+    let taker = swap_ctx_b.convert_signer(&msg.data.taker_address)?;
+    swap_ctx_b.convert_signer(&msg.data.taker_receiving_address)?;
+
+    swap_ctx_b.escrow_coins_validate(
+        &taker,
+        &order.port_id,
+        &order.channel_id,
+        &msg.data.sell_token,
+    )?;
+
+    let packet = swap_packet(
+        send_packet_ctx_b,
+        &order.port_id,
+        &order.channel_id,
+        &AtomicSwapPacketData::new(msg.data, msg.memo),
+        msg.timeout_height_on_b,
+        msg.timeout_timestamp_on_b,
+    )?;
+
+    send_packet_validate(send_packet_ctx_b, &packet)?;
+
+    Ok(())
+}
+
+/// Executes taking the order. A prior call to [`take_swap_validate`] MUST have succeeded.
+pub fn take_swap_execute<SendPacketCtx, SwapCtx>(
+    send_packet_ctx_b: &mut SendPacketCtx,
+    swap_ctx_b: &mut SwapCtx,
+    msg: MsgTakeSwap,
+) -> Result<(), AtomicSwapError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    SwapCtx: AtomicSwapExecutionContext,
+{
+    let mut order = get_order(swap_ctx_b, &msg.data.order_id, OrderSide::Remote)?;
+
+    let taker = swap_ctx_b.convert_signer(&msg.data.taker_address)?;
+
+    swap_ctx_b.escrow_coins_execute(
+        &taker,
+        &order.port_id,
+        &order.channel_id,
+        &msg.data.sell_token,
+    )?;
+
+    let packet = swap_packet(
+        send_packet_ctx_b,
+        &order.port_id,
+        &order.channel_id,
+        &AtomicSwapPacketData::new(msg.data.clone(), msg.memo),
+        msg.timeout_height_on_b,
+        msg.timeout_timestamp_on_b,
+    )?;
+
+    // The taker is recorded until the maker chain acknowledges the swap, so
+    // that the order can neither be taken twice nor cancelled meanwhile.
+    order.taker = Some(msg.data.clone());
+    swap_ctx_b.store_order(order)?;
+
+    send_packet_execute(send_packet_ctx_b, packet)?;
+
+    {
+        send_packet_ctx_b.log_message(format!(
+            "IBC atomic swap: order {} taken by {} for {}",
+            msg.data.order_id, msg.data.taker_address, msg.data.sell_token
+        ))?;
+
+        let take_swap_event = TakeSwapEvent {
+            order_id: msg.data.order_id,
+            taker: msg.data.taker_address,
+            sell_token: msg.data.sell_token,
+        };
+        send_packet_ctx_b.emit_ibc_event(ModuleEvent::from(take_swap_event).into())?;
+
+        send_packet_ctx_b.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+//! Implementation of the IBC [interchain atomic swap](https://github.com/cosmos/ibc/blob/main/spec/app/ics-100-atomic-swap/README.md) (ICS-100) application logic.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+/// Re-exports the implementation of the IBC [interchain atomic
+/// swap](https://github.com/cosmos/ibc/blob/main/spec/app/ics-100-atomic-swap/README.md)
+/// (ICS-100) data structures.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_app_atomic_swap_types::*;
+}
+
+pub mod context;
+#[cfg(feature = "serde")]
+pub mod handler;
+#[cfg(feature = "serde")]
+pub mod module;
@@ -0,0 +1,598 @@
+use ibc_app_atomic_swap_types::error::AtomicSwapError;
+use ibc_app_atomic_swap_types::events::{AckEvent, RecvEvent, TimeoutEvent};
+use ibc_app_atomic_swap_types::packet::{AtomicSwapPacketData, SwapMessage};
+use ibc_app_atomic_swap_types::{ack_success_b64, OrderId, VERSION};
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, RecvPacketResult,
+};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::{AtomicSwapExecutionContext, AtomicSwapValidationContext};
+use crate::handler::{
+    process_ack_packet_execute, process_ack_packet_validate, process_recv_packet_execute,
+};
+
+pub fn on_chan_open_init_validate(
+    ctx: &impl AtomicSwapValidationContext,
+    order: Order,
+    _connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    version: &Version,
+) -> Result<(), AtomicSwapError> {
+    if order != Order::Unordered {
+        return Err(AtomicSwapError::ChannelNotUnordered {
+            expect_order: Order::Unordered,
+            got_order: order,
+        });
+    }
+    let bound_port = ctx.get_port()?;
+    if port_id != &bound_port {
+        return Err(AtomicSwapError::InvalidPort {
+            port_id: port_id.clone(),
+            exp_port_id: bound_port,
+        });
+    }
+
+    if !version.is_empty() {
+        version
+            .verify_is_expected(Version::new(VERSION.to_string()))
+            .map_err(ContextError::from)?;
+    }
+
+    Ok(())
+}
+
+pub fn on_chan_open_init_execute(
+    _ctx: &mut impl AtomicSwapExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(ModuleExtras, Version), AtomicSwapError> {
+    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+}
+
+pub fn on_chan_open_try_validate(
+    _ctx: &impl AtomicSwapValidationContext,
+    order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(), AtomicSwapError> {
+    if order != Order::Unordered {
+        return Err(AtomicSwapError::ChannelNotUnordered {
+            expect_order: Order::Unordered,
+            got_order: order,
+        });
+    }
+
+    counterparty_version
+        .verify_is_expected(Version::new(VERSION.to_string()))
+        .map_err(ContextError::from)?;
+
+    Ok(())
+}
+
+pub fn on_chan_open_try_execute(
+    _ctx: &mut impl AtomicSwapExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), AtomicSwapError> {
+    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+}
+
+pub fn on_chan_open_ack_validate(
+    _ctx: &impl AtomicSwapValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<(), AtomicSwapError> {
+    counterparty_version
+        .verify_is_expected(Version::new(VERSION.to_string()))
+        .map_err(ContextError::from)?;
+
+    Ok(())
+}
+
+pub fn on_chan_open_ack_execute(
+    _ctx: &mut impl AtomicSwapExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<ModuleExtras, AtomicSwapError> {
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_chan_open_confirm_validate(
+    _ctx: &impl AtomicSwapValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), AtomicSwapError> {
+    Ok(())
+}
+
+pub fn on_chan_open_confirm_execute(
+    _ctx: &mut impl AtomicSwapExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, AtomicSwapError> {
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_chan_close_init_validate(
+    _ctx: &impl AtomicSwapValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), AtomicSwapError> {
+    Err(AtomicSwapError::CantCloseChannel)
+}
+
+pub fn on_chan_close_init_execute(
+    _ctx: &mut impl AtomicSwapExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, AtomicSwapError> {
+    Err(AtomicSwapError::CantCloseChannel)
+}
+
+pub fn on_chan_close_confirm_validate(
+    _ctx: &impl AtomicSwapValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), AtomicSwapError> {
+    Ok(())
+}
+
+pub fn on_chan_close_confirm_execute(
+    _ctx: &mut impl AtomicSwapExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, AtomicSwapError> {
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_recv_packet_execute(
+    ctx_b: &mut impl AtomicSwapExecutionContext,
+    packet: &Packet,
+) -> (ModuleExtras, RecvPacketResult) {
+    let Ok(data) = serde_json::from_slice::<AtomicSwapPacketData>(&packet.data) else {
+        let ack = AcknowledgementStatus::error(AtomicSwapError::PacketDataDeserialization.into());
+        return (ModuleExtras::empty(), ack.into());
+    };
+
+    let order_id = order_id_of(packet, &data.message);
+    let message_type = data.message.message_type().to_string();
+
+    let (mut extras, ack) = match process_recv_packet_execute(ctx_b, packet, data) {
+        Ok(extras) => (extras, AcknowledgementStatus::success(ack_success_b64())),
+        Err(error) => (
+            ModuleExtras::empty(),
+            AcknowledgementStatus::error(error.into()),
+        ),
+    };
+
+    let recv_event = RecvEvent {
+        order_id,
+        message_type,
+        success: ack.is_successful(),
+    };
+    extras.events.push(recv_event.into());
+
+    (extras, ack.into())
+}
+
+pub fn on_acknowledgement_packet_validate<Ctx>(
+    ctx: &Ctx,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> Result<(), AtomicSwapError>
+where
+    Ctx: AtomicSwapValidationContext,
+{
+    let data = serde_json::from_slice::<AtomicSwapPacketData>(&packet.data)
+        .map_err(|_| AtomicSwapError::PacketDataDeserialization)?;
+
+    let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+        .map_err(|_| AtomicSwapError::AckDeserialization)?;
+
+    process_ack_packet_validate(ctx, packet, &data, acknowledgement.is_successful())
+}
+
+pub fn on_acknowledgement_packet_execute(
+    ctx: &mut impl AtomicSwapExecutionContext,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), AtomicSwapError>) {
+    let Ok(data) = serde_json::from_slice::<AtomicSwapPacketData>(&packet.data) else {
+        return (
+            ModuleExtras::empty(),
+            Err(AtomicSwapError::PacketDataDeserialization),
+        );
+    };
+
+    let Ok(acknowledgement) =
+        serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+    else {
+        return (
+            ModuleExtras::empty(),
+            Err(AtomicSwapError::AckDeserialization),
+        );
+    };
+
+    if let Err(err) =
+        process_ack_packet_execute(ctx, packet, &data, acknowledgement.is_successful())
+    {
+        return (ModuleExtras::empty(), Err(err));
+    }
+
+    let ack_event = AckEvent {
+        order_id: order_id_of(packet, &data.message),
+        message_type: data.message.message_type().to_string(),
+        acknowledgement,
+    };
+
+    let extras = ModuleExtras {
+        events: vec![ack_event.into()],
+        log: Vec::new(),
+    };
+
+    (extras, Ok(()))
+}
+
+pub fn on_timeout_packet_validate<Ctx>(
+    ctx: &Ctx,
+    packet: &Packet,
+    _relayer: &Signer,
+) -> Result<(), AtomicSwapError>
+where
+    Ctx: AtomicSwapValidationContext,
+{
+    let data = serde_json::from_slice::<AtomicSwapPacketData>(&packet.data)
+        .map_err(|_| AtomicSwapError::PacketDataDeserialization)?;
+
+    process_ack_packet_validate(ctx, packet, &data, false)
+}
+
+pub fn on_timeout_packet_execute(
+    ctx: &mut impl AtomicSwapExecutionContext,
+    packet: &Packet,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), AtomicSwapError>) {
+    let Ok(data) = serde_json::from_slice::<AtomicSwapPacketData>(&packet.data) else {
+        return (
+            ModuleExtras::empty(),
+            Err(AtomicSwapError::PacketDataDeserialization),
+        );
+    };
+
+    if let Err(err) = process_ack_packet_execute(ctx, packet, &data, false) {
+        return (ModuleExtras::empty(), Err(err));
+    }
+
+    let timeout_event = TimeoutEvent {
+        order_id: order_id_of(packet, &data.message),
+        message_type: data.message.message_type().to_string(),
+    };
+
+    let extras = ModuleExtras {
+        events: vec![timeout_event.into()],
+        log: Vec::new(),
+    };
+
+    (extras, Ok(()))
+}
+
+/// Returns the identifier of the order the swap message relates to.
+fn order_id_of(packet: &Packet, message: &SwapMessage) -> OrderId {
+    match message {
+        SwapMessage::MakeSwap(_) => OrderId::from_packet(packet),
+        SwapMessage::TakeSwap(take) => take.order_id.clone(),
+        SwapMessage::CancelSwap(cancel) => cancel.order_id.clone(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use ibc_app_atomic_swap_types::packet::{MakeSwapData, TakeSwapData};
+    use ibc_app_atomic_swap_types::{
+        AtomicSwapOrder, OrderSide, OrderStatus, PrefixedCoin, PORT_ID,
+    };
+    use ibc_core::channel::types::timeout::TimeoutHeight;
+    use ibc_core::host::types::identifiers::Sequence;
+    use ibc_core::primitives::Timestamp;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MockSwapContext {
+        orders: BTreeMap<OrderId, AtomicSwapOrder>,
+        escrowed: Vec<(Signer, PrefixedCoin)>,
+        released: Vec<(Signer, PrefixedCoin)>,
+    }
+
+    impl AtomicSwapValidationContext for MockSwapContext {
+        type AccountId = Signer;
+
+        fn get_port(&self) -> Result<PortId, AtomicSwapError> {
+            Ok(PORT_ID)
+        }
+
+        fn host_timestamp(&self) -> Result<Timestamp, AtomicSwapError> {
+            Ok(Timestamp::from_nanoseconds(10).unwrap())
+        }
+
+        fn order(&self, order_id: &OrderId) -> Result<Option<AtomicSwapOrder>, AtomicSwapError> {
+            Ok(self.orders.get(order_id).cloned())
+        }
+
+        fn escrow_coins_validate(
+            &self,
+            _from_account: &Signer,
+            _port_id: &PortId,
+            _channel_id: &ChannelId,
+            _coin: &PrefixedCoin,
+        ) -> Result<(), AtomicSwapError> {
+            Ok(())
+        }
+
+        fn unescrow_coins_validate(
+            &self,
+            _to_account: &Signer,
+            _port_id: &PortId,
+            _channel_id: &ChannelId,
+            _coin: &PrefixedCoin,
+        ) -> Result<(), AtomicSwapError> {
+            Ok(())
+        }
+    }
+
+    impl AtomicSwapExecutionContext for MockSwapContext {
+        fn store_order(&mut self, order: AtomicSwapOrder) -> Result<(), AtomicSwapError> {
+            self.orders.insert(order.id.clone(), order);
+            Ok(())
+        }
+
+        fn escrow_coins_execute(
+            &mut self,
+            from_account: &Signer,
+            _port_id: &PortId,
+            _channel_id: &ChannelId,
+            coin: &PrefixedCoin,
+        ) -> Result<(), AtomicSwapError> {
+            self.escrowed.push((from_account.clone(), coin.clone()));
+            Ok(())
+        }
+
+        fn unescrow_coins_execute(
+            &mut self,
+            to_account: &Signer,
+            _port_id: &PortId,
+            _channel_id: &ChannelId,
+            coin: &PrefixedCoin,
+        ) -> Result<(), AtomicSwapError> {
+            self.released.push((to_account.clone(), coin.clone()));
+            Ok(())
+        }
+    }
+
+    fn signer(s: &str) -> Signer {
+        Signer::from(s.to_string())
+    }
+
+    fn swap_packet(chan_id_on_a: u64, chan_id_on_b: u64, message: SwapMessage) -> Packet {
+        let port_id = PORT_ID;
+        Packet {
+            seq_on_a: Sequence::from(1),
+            port_id_on_a: port_id.clone(),
+            chan_id_on_a: ChannelId::new(chan_id_on_a),
+            port_id_on_b: port_id,
+            chan_id_on_b: ChannelId::new(chan_id_on_b),
+            data: serde_json::to_vec(&AtomicSwapPacketData::new(message, "".into())).unwrap(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    fn make_data() -> MakeSwapData {
+        MakeSwapData {
+            sell_token: "100uatom".parse().unwrap(),
+            buy_token: "50uosmo".parse().unwrap(),
+            maker_address: signer("maker"),
+            maker_receiving_address: signer("maker-on-b"),
+            desired_taker: None,
+            expiration_timestamp: Timestamp::from_nanoseconds(100).unwrap(),
+        }
+    }
+
+    /// Sets up the maker chain `A` as `make_swap` would, and relays the
+    /// `MakeSwap` packet to the taker chain `B`.
+    fn make_order() -> (MockSwapContext, MockSwapContext, OrderId) {
+        let mut ctx_a = MockSwapContext::default();
+        let mut ctx_b = MockSwapContext::default();
+
+        let make_packet = swap_packet(0, 1, make_data().into());
+        let order_id = OrderId::from_packet(&make_packet);
+        ctx_a
+            .store_order(AtomicSwapOrder::new(
+                order_id.clone(),
+                OrderSide::Native,
+                OrderStatus::Initial,
+                make_packet.port_id_on_a.clone(),
+                make_packet.chan_id_on_a.clone(),
+                make_data(),
+            ))
+            .unwrap();
+
+        let (_, result) = on_recv_packet_execute(&mut ctx_b, &make_packet);
+        let ack = result.into_acknowledgement().unwrap();
+        let (_, res) =
+            on_acknowledgement_packet_execute(&mut ctx_a, &make_packet, &ack, &signer("relayer"));
+        res.unwrap();
+
+        (ctx_a, ctx_b, order_id)
+    }
+
+    fn take_data(order_id: &OrderId, sell_token: &str) -> TakeSwapData {
+        TakeSwapData {
+            order_id: order_id.clone(),
+            sell_token: sell_token.parse().unwrap(),
+            taker_address: signer("taker"),
+            taker_receiving_address: signer("taker-on-a"),
+        }
+    }
+
+    fn is_success(ack: &Acknowledgement) -> bool {
+        serde_json::from_slice::<AcknowledgementStatus>(ack.as_ref())
+            .unwrap()
+            .is_successful()
+    }
+
+    #[test]
+    fn test_swap_is_settled_on_both_chains() {
+        let (mut ctx_a, mut ctx_b, order_id) = make_order();
+
+        assert_eq!(ctx_a.orders[&order_id].status, OrderStatus::Sync);
+        assert_eq!(ctx_b.orders[&order_id].side, OrderSide::Remote);
+        assert_eq!(ctx_b.orders[&order_id].status, OrderStatus::Sync);
+
+        // The taker escrows the asked tokens on `B`, as `take_swap` would.
+        let take = take_data(&order_id, "50uosmo");
+        let mut order_b = ctx_b.orders[&order_id].clone();
+        order_b.taker = Some(take.clone());
+        ctx_b.store_order(order_b).unwrap();
+
+        let take_packet = swap_packet(1, 0, take.into());
+        let (_, result) = on_recv_packet_execute(&mut ctx_a, &take_packet);
+        let ack = result.into_acknowledgement().unwrap();
+        assert!(is_success(&ack));
+        assert_eq!(ctx_a.orders[&order_id].status, OrderStatus::Complete);
+        assert_eq!(
+            ctx_a.released,
+            vec![(signer("taker-on-a"), "100uatom".parse().unwrap())]
+        );
+
+        let (_, res) =
+            on_acknowledgement_packet_execute(&mut ctx_b, &take_packet, &ack, &signer("relayer"));
+        res.unwrap();
+        assert_eq!(ctx_b.orders[&order_id].status, OrderStatus::Complete);
+        assert_eq!(
+            ctx_b.released,
+            vec![(signer("maker-on-b"), "50uosmo".parse().unwrap())]
+        );
+    }
+
+    #[test]
+    fn test_mismatching_take_is_refunded() {
+        let (mut ctx_a, mut ctx_b, order_id) = make_order();
+
+        let take = take_data(&order_id, "10uosmo");
+        let mut order_b = ctx_b.orders[&order_id].clone();
+        order_b.taker = Some(take.clone());
+        ctx_b.store_order(order_b).unwrap();
+
+        let take_packet = swap_packet(1, 0, take.into());
+        let (_, result) = on_recv_packet_execute(&mut ctx_a, &take_packet);
+        let ack = result.into_acknowledgement().unwrap();
+        assert!(!is_success(&ack));
+        assert_eq!(ctx_a.orders[&order_id].status, OrderStatus::Sync);
+        assert!(ctx_a.released.is_empty());
+
+        let (_, res) =
+            on_acknowledgement_packet_execute(&mut ctx_b, &take_packet, &ack, &signer("relayer"));
+        res.unwrap();
+        assert_eq!(ctx_b.orders[&order_id].taker, None);
+        assert_eq!(
+            ctx_b.released,
+            vec![(signer("taker"), "10uosmo".parse().unwrap())]
+        );
+    }
+
+    #[test]
+    fn test_cancel_refunds_maker_unless_taken() {
+        use ibc_app_atomic_swap_types::packet::CancelSwapData;
+
+        let (mut ctx_a, mut ctx_b, order_id) = make_order();
+
+        let cancel_packet = swap_packet(
+            0,
+            1,
+            CancelSwapData {
+                order_id: order_id.clone(),
+                maker_address: signer("maker"),
+            }
+            .into(),
+        );
+
+        // A taken order cannot be cancelled.
+        let mut taken = ctx_b.orders[&order_id].clone();
+        taken.taker = Some(take_data(&order_id, "50uosmo"));
+        let mut ctx_b_taken = MockSwapContext::default();
+        ctx_b_taken.store_order(taken).unwrap();
+        let (_, result) = on_recv_packet_execute(&mut ctx_b_taken, &cancel_packet);
+        let ack = result.into_acknowledgement().unwrap();
+        assert!(!is_success(&ack));
+
+        let (_, result) = on_recv_packet_execute(&mut ctx_b, &cancel_packet);
+        let ack = result.into_acknowledgement().unwrap();
+        assert!(is_success(&ack));
+        assert_eq!(ctx_b.orders[&order_id].status, OrderStatus::Cancel);
+
+        let (_, res) =
+            on_acknowledgement_packet_execute(&mut ctx_a, &cancel_packet, &ack, &signer("relayer"));
+        res.unwrap();
+        assert_eq!(ctx_a.orders[&order_id].status, OrderStatus::Cancel);
+        assert_eq!(
+            ctx_a.released,
+            vec![(signer("maker"), "100uatom".parse().unwrap())]
+        );
+    }
+
+    #[test]
+    fn test_timed_out_make_refunds_maker() {
+        let mut ctx_a = MockSwapContext::default();
+
+        let make_packet = swap_packet(0, 1, make_data().into());
+        let order_id = OrderId::from_packet(&make_packet);
+        ctx_a
+            .store_order(AtomicSwapOrder::new(
+                order_id.clone(),
+                OrderSide::Native,
+                OrderStatus::Initial,
+                make_packet.port_id_on_a.clone(),
+                make_packet.chan_id_on_a.clone(),
+                make_data(),
+            ))
+            .unwrap();
+
+        on_timeout_packet_validate(&ctx_a, &make_packet, &signer("relayer")).unwrap();
+        let (_, res) = on_timeout_packet_execute(&mut ctx_a, &make_packet, &signer("relayer"));
+        res.unwrap();
+
+        assert_eq!(ctx_a.orders[&order_id].status, OrderStatus::Cancel);
+        assert_eq!(
+            ctx_a.released,
+            vec![(signer("maker"), "100uatom".parse().unwrap())]
+        );
+    }
+}
@@ -0,0 +1,161 @@
+//! Defines the atomic swap error type
+use displaydoc::Display;
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::channel::Order;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::{ErrorCode, Signer, SignerError, Timestamp};
+
+use crate::{OrderId, OrderSide, OrderStatus};
+
+#[derive(Display, Debug)]
+pub enum AtomicSwapError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// destination channel not found in the counterparty of port_id `{port_id}` and channel_id `{channel_id}`
+    DestinationChannelNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// expected `{expect_order}` channel, got `{got_order}`
+    ChannelNotUnordered {
+        expect_order: Order,
+        got_order: Order,
+    },
+    /// channel cannot be closed
+    CantCloseChannel,
+    /// invalid port: `{port_id}`, expected `{exp_port_id}`
+    InvalidPort {
+        port_id: PortId,
+        exp_port_id: PortId,
+    },
+    /// failed to serialize packet data
+    PacketDataSerialization,
+    /// failed to deserialize packet data
+    PacketDataDeserialization,
+    /// failed to deserialize acknowledgement
+    AckDeserialization,
+    /// failed to parse as AccountId
+    ParseAccountFailure,
+    /// invalid signer: `{0}`
+    InvalidSigner(SignerError),
+    /// swap tokens must have a non-zero amount, got `{token}`
+    ZeroAmount { token: PrefixedCoin },
+    /// order `{order_id}` not found
+    OrderNotFound { order_id: OrderId },
+    /// order `{order_id}` already exists
+    OrderAlreadyExists { order_id: OrderId },
+    /// order `{order_id}` is a `{side}` order, expected a `{expected}` order
+    InvalidOrderSide {
+        order_id: OrderId,
+        side: OrderSide,
+        expected: OrderSide,
+    },
+    /// order `{order_id}` has status `{status}`, which does not allow this operation
+    InvalidOrderStatus {
+        order_id: OrderId,
+        status: OrderStatus,
+    },
+    /// order `{order_id}` has already been taken
+    OrderAlreadyTaken { order_id: OrderId },
+    /// order `{order_id}` expired at `{expiration}`
+    OrderExpired {
+        order_id: OrderId,
+        expiration: Timestamp,
+    },
+    /// order `{order_id}` can only be taken by `{desired_taker}`, got `{taker}`
+    UnauthorizedTaker {
+        order_id: OrderId,
+        desired_taker: Signer,
+        taker: Signer,
+    },
+    /// order `{order_id}` can only be cancelled by its maker `{maker}`, got `{sender}`
+    UnauthorizedCanceller {
+        order_id: OrderId,
+        maker: Signer,
+        sender: Signer,
+    },
+    /// offered token `{offered}` does not match the token `{expected}` asked by order `{order_id}`
+    TokenMismatch {
+        order_id: OrderId,
+        offered: PrefixedCoin,
+        expected: PrefixedCoin,
+    },
+    /// other error: `{0}`
+    Other(String),
+}
+
+impl AtomicSwapError {
+    /// Returns the stable error code of this error.
+    ///
+    /// Errors wrapping another IBC error report the code of the wrapped error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("atomicswap", code);
+
+        match self {
+            Self::ContextError(e) => e.code(),
+            Self::InvalidIdentifier(_) => code(1),
+            Self::DestinationChannelNotFound { .. } => code(2),
+            Self::ChannelNotUnordered { .. } => code(3),
+            Self::CantCloseChannel => code(4),
+            Self::InvalidPort { .. } => code(5),
+            Self::PacketDataSerialization => code(6),
+            Self::PacketDataDeserialization => code(7),
+            Self::AckDeserialization => code(8),
+            Self::ParseAccountFailure => code(9),
+            Self::InvalidSigner(_) => code(10),
+            Self::ZeroAmount { .. } => code(11),
+            Self::OrderNotFound { .. } => code(12),
+            Self::OrderAlreadyExists { .. } => code(13),
+            Self::InvalidOrderSide { .. } => code(14),
+            Self::InvalidOrderStatus { .. } => code(15),
+            Self::OrderAlreadyTaken { .. } => code(16),
+            Self::OrderExpired { .. } => code(17),
+            Self::UnauthorizedTaker { .. } => code(18),
+            Self::UnauthorizedCanceller { .. } => code(19),
+            Self::TokenMismatch { .. } => code(20),
+            Self::Other(_) => code(21),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AtomicSwapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidSigner(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for AtomicSwapError {
+    fn from(err: ContextError) -> AtomicSwapError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for AtomicSwapError {
+    fn from(err: IdentifierError) -> AtomicSwapError {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<SignerError> for AtomicSwapError {
+    fn from(err: SignerError) -> AtomicSwapError {
+        Self::InvalidSigner(err)
+    }
+}
+
+impl From<AtomicSwapError> for StatusValue {
+    fn from(err: AtomicSwapError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
@@ -0,0 +1,202 @@
+//! Defines all atomic swap event types
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::{OrderId, MODULE_ID_STR};
+
+const EVENT_TYPE_PACKET: &str = "atomic_swap_packet";
+const EVENT_TYPE_TIMEOUT: &str = "timeout";
+const EVENT_TYPE_MAKE_SWAP: &str = "make_swap";
+const EVENT_TYPE_TAKE_SWAP: &str = "take_swap";
+const EVENT_TYPE_CANCEL_SWAP: &str = "cancel_swap";
+
+/// Contains all events variants that can be emitted from the atomic swap application
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Event {
+    MakeSwap(MakeSwapEvent),
+    TakeSwap(TakeSwapEvent),
+    CancelSwap(CancelSwapEvent),
+    Recv(RecvEvent),
+    Ack(AckEvent),
+    Timeout(TimeoutEvent),
+}
+
+/// Event emitted when an order is made
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MakeSwapEvent {
+    pub order_id: OrderId,
+    pub maker: Signer,
+    pub sell_token: PrefixedCoin,
+    pub buy_token: PrefixedCoin,
+}
+
+impl From<MakeSwapEvent> for ModuleEvent {
+    fn from(ev: MakeSwapEvent) -> Self {
+        let MakeSwapEvent {
+            order_id,
+            maker,
+            sell_token,
+            buy_token,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_MAKE_SWAP.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("order_id", order_id).into(),
+                ("maker", maker).into(),
+                ("sell_token", sell_token).into(),
+                ("buy_token", buy_token).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted when an order is taken
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TakeSwapEvent {
+    pub order_id: OrderId,
+    pub taker: Signer,
+    pub sell_token: PrefixedCoin,
+}
+
+impl From<TakeSwapEvent> for ModuleEvent {
+    fn from(ev: TakeSwapEvent) -> Self {
+        let TakeSwapEvent {
+            order_id,
+            taker,
+            sell_token,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_TAKE_SWAP.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("order_id", order_id).into(),
+                ("taker", taker).into(),
+                ("sell_token", sell_token).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted when the cancellation of an order is requested
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CancelSwapEvent {
+    pub order_id: OrderId,
+    pub maker: Signer,
+}
+
+impl From<CancelSwapEvent> for ModuleEvent {
+    fn from(ev: CancelSwapEvent) -> Self {
+        let CancelSwapEvent { order_id, maker } = ev;
+        Self {
+            kind: EVENT_TYPE_CANCEL_SWAP.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("order_id", order_id).into(),
+                ("maker", maker).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted by the `onRecvPacket` module callback to indicate the that the
+/// `RecvPacket` message was processed
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RecvEvent {
+    pub order_id: OrderId,
+    pub message_type: String,
+    pub success: bool,
+}
+
+impl From<RecvEvent> for ModuleEvent {
+    fn from(ev: RecvEvent) -> Self {
+        let RecvEvent {
+            order_id,
+            message_type,
+            success,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("order_id", order_id).into(),
+                ("message_type", message_type).into(),
+                ("success", success).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted in the `onAcknowledgePacket` module callback
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AckEvent {
+    pub order_id: OrderId,
+    pub message_type: String,
+    pub acknowledgement: AcknowledgementStatus,
+}
+
+impl From<AckEvent> for ModuleEvent {
+    fn from(ev: AckEvent) -> Self {
+        let AckEvent {
+            order_id,
+            message_type,
+            acknowledgement,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("order_id", order_id).into(),
+                ("message_type", message_type).into(),
+                ("acknowledgement", acknowledgement).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted in the `onTimeoutPacket` module callback
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TimeoutEvent {
+    pub order_id: OrderId,
+    pub message_type: String,
+}
+
+impl From<TimeoutEvent> for ModuleEvent {
+    fn from(ev: TimeoutEvent) -> Self {
+        let TimeoutEvent {
+            order_id,
+            message_type,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_TIMEOUT.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("order_id", order_id).into(),
+                ("message_type", message_type).into(),
+            ],
+        }
+    }
+}
+
+impl From<Event> for ModuleEvent {
+    fn from(ev: Event) -> Self {
+        match ev {
+            Event::MakeSwap(ev) => ev.into(),
+            Event::TakeSwap(ev) => ev.into(),
+            Event::CancelSwap(ev) => ev.into(),
+            Event::Recv(ev) => ev.into(),
+            Event::Ack(ev) => ev.into(),
+            Event::Timeout(ev) => ev.into(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Implementation of the IBC [interchain atomic swap](https://github.com/cosmos/ibc/blob/main/spec/app/ics-100-atomic-swap/README.md) (ICS-100) data structures.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod order;
+
+/// Re-exports the ICS-20 coin and memo types, shared by the swap messages.
+pub use ibc_app_transfer_types::{Amount, Memo, PrefixedCoin, PrefixedDenom};
+pub use order::*;
+pub mod error;
+pub mod events;
+pub mod msgs;
+pub mod packet;
+
+/// Module identifier for the ICS100 application.
+pub const MODULE_ID_STR: &str = "atomicswap";
+
+/// The port identifier that the ICS100 applications
+/// typically bind with.
+pub const PORT_ID_STR: &str = "swap";
+
+/// The port identifier that the ICS100 applications typically bind with, as
+/// a [`PortId`] validated at compile time.
+pub const PORT_ID: PortId = PortId::from_static(PORT_ID_STR);
+
+/// ICS100 application current version.
+pub const VERSION: &str = "ics100-1";
+
+/// The successful string used for creating an acknowledgement status,
+/// equivalent to `base64::encode(0x01)`.
+pub const ACK_SUCCESS_B64: &str = "AQ==";
+
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::host::types::identifiers::PortId;
+
+/// Returns a successful acknowledgement status for the atomic swap application.
+pub fn ack_success_b64() -> StatusValue {
+    StatusValue::new(ACK_SUCCESS_B64).expect("ack status value is never supposed to be empty")
+}
@@ -0,0 +1,53 @@
+//! Defines the messages initiating the atomic swap operations.
+//!
+//! Unlike `MakeSwap`, which opens an order on the given channel, `TakeSwap`
+//! and `CancelSwap` are sent over the channel the order was made on.
+use ibc_app_transfer_types::Memo;
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::Timestamp;
+
+use crate::packet::{CancelSwapData, MakeSwapData, TakeSwapData};
+
+/// Message making a new atomic swap order, escrowing the maker's tokens.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgMakeSwap {
+    /// the port on which the packet will be sent
+    pub port_id_on_a: PortId,
+    /// the channel by which the packet will be sent
+    pub chan_id_on_a: ChannelId,
+    pub data: MakeSwapData,
+    pub memo: Memo,
+    /// Timeout height relative to the current block height.
+    /// The timeout is disabled when set to None.
+    pub timeout_height_on_b: TimeoutHeight,
+    /// Timeout timestamp relative to the current block timestamp.
+    /// The timeout is disabled when set to 0.
+    pub timeout_timestamp_on_b: Timestamp,
+}
+
+/// Message taking an order made on the counterparty chain, escrowing the
+/// taker's tokens.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgTakeSwap {
+    pub data: TakeSwapData,
+    pub memo: Memo,
+    pub timeout_height_on_b: TimeoutHeight,
+    pub timeout_timestamp_on_b: Timestamp,
+}
+
+/// Message cancelling an order that was not taken yet. The maker's tokens are
+/// refunded once the counterparty chain acknowledges the cancellation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgCancelSwap {
+    pub data: CancelSwapData,
+    pub memo: Memo,
+    pub timeout_height_on_b: TimeoutHeight,
+    pub timeout_timestamp_on_b: Timestamp,
+}
@@ -0,0 +1,145 @@
+//! Defines the atomic swap order, tracking the lifecycle of a swap on each
+//! chain.
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Timestamp;
+
+use crate::packet::{MakeSwapData, TakeSwapData};
+
+/// Identifier of an atomic swap order.
+///
+/// Orders are identified by the port, channel and sequence of the packet
+/// that created them on the maker chain, which makes the identifier
+/// available on both chains without any coordination.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+pub struct OrderId(String);
+
+impl OrderId {
+    /// Builds the identifier of the order created by a `MakeSwap` packet sent
+    /// on the given port and channel of the maker chain.
+    pub fn new(port_id_on_a: &PortId, chan_id_on_a: &ChannelId, seq_on_a: Sequence) -> Self {
+        Self(format!("{port_id_on_a}/{chan_id_on_a}/{seq_on_a}"))
+    }
+
+    /// Builds the identifier of the order created by the given `MakeSwap`
+    /// packet.
+    pub fn from_packet(packet: &Packet) -> Self {
+        Self::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for OrderId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+/// The side of an order, relative to the chain storing it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderSide {
+    /// The order was made on this chain, which escrows the maker's tokens.
+    Native,
+    /// The order was made on the counterparty chain, where it may be taken.
+    Remote,
+}
+
+impl Display for OrderSide {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Native => write!(f, "native"),
+            Self::Remote => write!(f, "remote"),
+        }
+    }
+}
+
+/// The status of an order.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderStatus {
+    /// The order was made, but the counterparty chain did not acknowledge it
+    /// yet.
+    Initial,
+    /// Both chains are aware of the order, which can be taken or cancelled.
+    Sync,
+    /// The order was cancelled, or could not be relayed; the maker's tokens
+    /// were refunded.
+    Cancel,
+    /// The swap was executed.
+    Complete,
+}
+
+impl Display for OrderStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Initial => write!(f, "initial"),
+            Self::Sync => write!(f, "sync"),
+            Self::Cancel => write!(f, "cancel"),
+            Self::Complete => write!(f, "complete"),
+        }
+    }
+}
+
+/// An atomic swap order, as stored on either chain.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AtomicSwapOrder {
+    pub id: OrderId,
+    pub side: OrderSide,
+    pub status: OrderStatus,
+    /// The port of the swap channel on this chain.
+    pub port_id: PortId,
+    /// The swap channel on this chain.
+    pub channel_id: ChannelId,
+    pub maker: MakeSwapData,
+    pub taker: Option<TakeSwapData>,
+    pub cancel_timestamp: Option<Timestamp>,
+    pub complete_timestamp: Option<Timestamp>,
+}
+
+impl AtomicSwapOrder {
+    /// Creates a new order for the given maker data, without taker.
+    pub fn new(
+        id: OrderId,
+        side: OrderSide,
+        status: OrderStatus,
+        port_id: PortId,
+        channel_id: ChannelId,
+        maker: MakeSwapData,
+    ) -> Self {
+        Self {
+            id,
+            side,
+            status,
+            port_id,
+            channel_id,
+            maker,
+            taker: None,
+            cancel_timestamp: None,
+            complete_timestamp: None,
+        }
+    }
+
+    /// Returns true if the order was neither cancelled nor completed.
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, OrderStatus::Initial | OrderStatus::Sync)
+    }
+
+    /// Returns true if the order can no longer be taken at the given host
+    /// time.
+    pub fn is_expired(&self, now: &Timestamp) -> bool {
+        now.is_after(&self.maker.expiration_timestamp)
+    }
+}
@@ -0,0 +1,122 @@
+//! Contains the `AtomicSwapPacketData` type that defines the structure of the
+//! atomic swap packet bytes
+use ibc_app_transfer_types::{Memo, PrefixedCoin};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::{Signer, Timestamp};
+
+use crate::OrderId;
+
+/// Payload of a `MakeSwap` packet, announcing a new order to the counterparty
+/// chain.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MakeSwapData {
+    /// The tokens escrowed by the maker on the maker chain.
+    pub sell_token: PrefixedCoin,
+    /// The tokens the maker asks for on the taker chain.
+    pub buy_token: PrefixedCoin,
+    /// The maker account on the maker chain.
+    pub maker_address: Signer,
+    /// The maker account receiving `buy_token` on the taker chain.
+    pub maker_receiving_address: Signer,
+    /// The only taker allowed to take the order, if any.
+    pub desired_taker: Option<Signer>,
+    /// The time after which the order can no longer be taken. Unset
+    /// timestamps never expire.
+    pub expiration_timestamp: Timestamp,
+}
+
+/// Payload of a `TakeSwap` packet, taking an order made on the counterparty
+/// chain.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TakeSwapData {
+    pub order_id: OrderId,
+    /// The tokens escrowed by the taker on the taker chain, which must match
+    /// the `buy_token` of the order.
+    pub sell_token: PrefixedCoin,
+    /// The taker account on the taker chain.
+    pub taker_address: Signer,
+    /// The taker account receiving the `sell_token` of the order on the maker
+    /// chain.
+    pub taker_receiving_address: Signer,
+}
+
+/// Payload of a `CancelSwap` packet, cancelling an order that was not taken
+/// yet.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CancelSwapData {
+    pub order_id: OrderId,
+    /// The maker account on the maker chain.
+    pub maker_address: Signer,
+}
+
+/// The swap messages carried by the atomic swap packets.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
+pub enum SwapMessage {
+    MakeSwap(MakeSwapData),
+    TakeSwap(TakeSwapData),
+    CancelSwap(CancelSwapData),
+}
+
+impl SwapMessage {
+    /// Returns the name of the message, as used in the emitted events.
+    pub fn message_type(&self) -> &'static str {
+        match self {
+            Self::MakeSwap(_) => "make_swap",
+            Self::TakeSwap(_) => "take_swap",
+            Self::CancelSwap(_) => "cancel_swap",
+        }
+    }
+}
+
+/// Defines the structure of the atomic swap packet bytes
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AtomicSwapPacketData {
+    pub message: SwapMessage,
+    pub memo: Memo,
+}
+
+impl AtomicSwapPacketData {
+    pub fn new(message: impl Into<SwapMessage>, memo: Memo) -> Self {
+        Self {
+            message: message.into(),
+            memo,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packet_data_serde_roundtrip() {
+        let data = AtomicSwapPacketData::new(
+            MakeSwapData {
+                sell_token: "100uatom".parse().unwrap(),
+                buy_token: "50uosmo".parse().unwrap(),
+                maker_address: Signer::from("maker".to_string()),
+                maker_receiving_address: Signer::from("maker-on-b".to_string()),
+                desired_taker: None,
+                expiration_timestamp: Timestamp::from_nanoseconds(1).unwrap(),
+            },
+            "".into(),
+        );
+
+        let json = serde_json::to_string(&data).unwrap();
+        assert!(json.contains("\"make_swap\""));
+
+        let decoded: AtomicSwapPacketData = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, data);
+    }
+}
@@ -0,0 +1,50 @@
+//! Implements the [`PacketDataCodec`] of the token transfer packets.
+use ibc_app_transfer_types::packet::PacketData;
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::channel::types::packet_data::PacketDataCodec;
+use ibc_core::primitives::prelude::*;
+
+/// Encodes the token transfer packet data as JSON, as expected by the
+/// counterparty chains.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TransferPacketDataCodec;
+
+impl PacketDataCodec for TransferPacketDataCodec {
+    type Data = PacketData;
+
+    fn encode(&self, data: &PacketData) -> Result<Vec<u8>, PacketError> {
+        serde_json::to_vec(data).map_err(|e| PacketError::InvalidPacketData {
+            reason: e.to_string(),
+        })
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<PacketData, PacketError> {
+        serde_json::from_slice(bytes).map_err(|e| PacketError::InvalidPacketData {
+            reason: e.to_string(),
+        })
+    }
+
+    /// Checks that the packet transfers a positive amount between non-blank
+    /// addresses.
+    fn validate(&self, data: &PacketData) -> Result<(), PacketError> {
+        if data.token.amount.is_zero() {
+            return Err(PacketError::InvalidPacketData {
+                reason: "transfer amount must be positive".to_string(),
+            });
+        }
+
+        if data.sender.as_ref().trim().is_empty() {
+            return Err(PacketError::InvalidPacketData {
+                reason: "sender address cannot be blank".to_string(),
+            });
+        }
+
+        if data.receiver.as_ref().trim().is_empty() {
+            return Err(PacketError::InvalidPacketData {
+                reason: "receiver address cannot be blank".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
 //! Defines the main context traits and IBC module callbacks
 
+use ibc_app_transfer_types::authorization::TransferAuthorization;
 use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::events::RefundReason;
+use ibc_app_transfer_types::params::TransferParams;
 use ibc_app_transfer_types::{Memo, PrefixedCoin, PrefixedDenom};
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
@@ -19,6 +22,33 @@ pub trait TokenTransferValidationContext {
     /// Returns Ok() if the host chain supports receiving coins.
     fn can_receive_coins(&self) -> Result<(), TokenTransferError>;
 
+    /// Returns the current params of the transfer application, consulted
+    /// before sending or receiving tokens. Both directions are enabled by
+    /// default; implement along with `store_params` to support pausing them.
+    fn get_params(&self) -> Result<TransferParams, TokenTransferError> {
+        Ok(TransferParams::default())
+    }
+
+    /// Returns Ok() if the given signer is the authority allowed to update the
+    /// transfer params. No one is allowed by default.
+    fn validate_params_authority(&self, signer: &Signer) -> Result<(), TokenTransferError> {
+        Err(TokenTransferError::UnauthorizedParamsUpdate {
+            signer: signer.clone(),
+        })
+    }
+
+    /// Returns Ok() if the tokens of a failed transfer may be refunded to
+    /// their sender for the given reason. Refunds are always allowed by
+    /// default; hosts may override this to apply refund-specific policies.
+    fn can_refund_coins(
+        &self,
+        _to_account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+        _reason: RefundReason,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
     /// Validates that the tokens can be escrowed successfully.
     ///
     /// `memo` field allows to incorporate additional contextual details in the
@@ -64,6 +94,26 @@ pub trait TokenTransferValidationContext {
     fn denom_hash_string(&self, _denom: &PrefixedDenom) -> Option<String> {
         None
     }
+
+    /// Returns the transfer authorization restricting the transfers the
+    /// sender may initiate. Senders without an authorization are unrestricted.
+    /// Implement only if the host chain supports transfer authorizations.
+    fn transfer_authorization(&self, _sender: &Self::AccountId) -> Option<TransferAuthorization> {
+        None
+    }
+
+    /// Converts a sender or receiver into an account of the host chain.
+    ///
+    /// Defaults to the `TryFrom<Signer>` conversion of `AccountId`. Hosts may
+    /// override it to reject malformed signers during validation, e.g. by
+    /// delegating to a [`SignerConverter`](ibc_core::primitives::SignerConverter)
+    /// such as [`Bech32Validator`](ibc_core::primitives::Bech32Validator).
+    fn convert_signer(&self, signer: &Signer) -> Result<Self::AccountId, TokenTransferError> {
+        signer
+            .clone()
+            .try_into()
+            .map_err(|_| TokenTransferError::ParseAccountFailure)
+    }
 }
 
 /// Methods required in token transfer execution, to be implemented by the host.
@@ -107,4 +157,21 @@ pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
         coin: &PrefixedCoin,
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
+
+    /// Stores the transfer authorization of the sender, with its spend limits
+    /// reduced by an executed transfer.
+    /// Implement only if the host chain supports transfer authorizations.
+    fn store_transfer_authorization(
+        &mut self,
+        _sender: &Self::AccountId,
+        _authorization: TransferAuthorization,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Stores the params of the transfer application.
+    /// Implement only if the host chain supports updating the transfer params.
+    fn store_params(&mut self, _params: TransferParams) -> Result<(), TokenTransferError> {
+        Err(TokenTransferError::ParamsUpdateNotSupported)
+    }
 }
@@ -1,26 +1,31 @@
 //! Implements the processing logic for ICS20 (token transfer) message.
 mod on_recv_packet;
+mod params;
 mod send_transfer;
 
 use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::events::{RefundEvent, RefundReason};
 use ibc_app_transfer_types::is_sender_chain_source;
 use ibc_app_transfer_types::packet::PacketData;
 use ibc_core::channel::types::packet::Packet;
 pub use on_recv_packet::*;
+pub use params::*;
 pub use send_transfer::*;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
+/// Refunds the tokens of a failed transfer to its sender, by unescrowing them
+/// if the host is the source of the denomination or minting the vouchers back
+/// otherwise.
+///
+/// Returns the [`RefundEvent`] to be emitted by the caller.
 pub fn refund_packet_token_execute(
     ctx_a: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
     data: &PacketData,
-) -> Result<(), TokenTransferError> {
-    let sender = data
-        .sender
-        .clone()
-        .try_into()
-        .map_err(|_| TokenTransferError::ParseAccountFailure)?;
+    reason: RefundReason,
+) -> Result<RefundEvent, TokenTransferError> {
+    let sender = ctx_a.convert_signer(&data.sender)?;
 
     if is_sender_chain_source(
         packet.port_id_on_a.clone(),
@@ -32,24 +37,33 @@ pub fn refund_packet_token_execute(
             &packet.port_id_on_a,
             &packet.chan_id_on_a,
             &data.token,
-        )
+        )?;
     }
     // mint vouchers back to sender
     else {
-        ctx_a.mint_coins_execute(&sender, &data.token)
+        ctx_a.mint_coins_execute(&sender, &data.token)?;
     }
+
+    Ok(RefundEvent {
+        refund_receiver: data.sender.clone(),
+        refund_denom: data.token.denom.clone(),
+        refund_amount: data.token.amount,
+        channel_id: packet.chan_id_on_a.clone(),
+        reason,
+    })
 }
 
+/// Validates that the tokens of a failed transfer can be refunded to its
+/// sender, including the host's refund policy.
 pub fn refund_packet_token_validate(
     ctx_a: &impl TokenTransferValidationContext,
     packet: &Packet,
     data: &PacketData,
+    reason: RefundReason,
 ) -> Result<(), TokenTransferError> {
-    let sender = data
-        .sender
-        .clone()
-        .try_into()
-        .map_err(|_| TokenTransferError::ParseAccountFailure)?;
+    let sender = ctx_a.convert_signer(&data.sender)?;
+
+    ctx_a.can_refund_coins(&sender, &data.token, reason)?;
 
     if is_sender_chain_source(
         packet.port_id_on_a.clone(),
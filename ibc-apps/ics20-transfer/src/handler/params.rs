@@ -0,0 +1,49 @@
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::msgs::update_params::MsgUpdateParams;
+use ibc_app_transfer_types::proto::transfer::v1::QueryParamsResponse;
+
+use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+
+/// Validates the update of the transfer params, which must be signed by the
+/// authority of the host. If this succeeds, the update can be applied with
+/// [`update_params_execute`].
+pub fn update_params_validate<Ctx>(
+    ctx: &Ctx,
+    msg: &MsgUpdateParams,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferValidationContext,
+{
+    ctx.validate_params_authority(&msg.signer)
+}
+
+/// Stores the new transfer params, taking effect for the next transfers sent
+/// or received by the host.
+pub fn update_params_execute<Ctx>(
+    ctx: &mut Ctx,
+    msg: MsgUpdateParams,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferExecutionContext,
+{
+    ctx.store_params(msg.params)
+}
+
+/// Updates the transfer params after validating the message.
+pub fn update_params<Ctx>(ctx: &mut Ctx, msg: MsgUpdateParams) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferExecutionContext,
+{
+    update_params_validate(ctx, &msg)?;
+    update_params_execute(ctx, msg)
+}
+
+/// Queries the current params of the transfer application.
+pub fn query_params<Ctx>(ctx: &Ctx) -> Result<QueryParamsResponse, TokenTransferError>
+where
+    Ctx: TokenTransferValidationContext,
+{
+    Ok(QueryParamsResponse {
+        params: Some(ctx.get_params()?.into()),
+    })
+}
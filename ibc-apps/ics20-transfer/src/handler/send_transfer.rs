@@ -3,18 +3,25 @@ use ibc_app_transfer_types::events::TransferEvent;
 use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
 use ibc_app_transfer_types::{is_sender_chain_source, MODULE_ID_STR};
 use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
-use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
+use ibc_core::channel::handler::{
+    compute_packet_timeouts, send_packet_execute, send_packet_validate_with_owner,
+};
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout_policy::TimeoutPolicy;
+use ibc_core::handler::types::error::ContextError;
 use ibc_core::handler::types::events::MessageEvent;
 use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
+use ibc_core::router::port::PortKeeper;
 use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::module::ModuleId;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
 /// Initiate a token transfer. Equivalent to calling [`send_transfer_validate`], followed by [`send_transfer_execute`].
 pub fn send_transfer<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
+    port_keeper_a: &impl PortKeeper,
     token_ctx_a: &mut TokenCtx,
     msg: MsgTransfer,
 ) -> Result<(), TokenTransferError>
@@ -22,13 +29,40 @@ where
     SendPacketCtx: SendPacketExecutionContext,
     TokenCtx: TokenTransferExecutionContext,
 {
-    send_transfer_validate(send_packet_ctx_a, token_ctx_a, msg.clone())?;
+    send_transfer_validate(send_packet_ctx_a, port_keeper_a, token_ctx_a, msg.clone())?;
     send_transfer_execute(send_packet_ctx_a, token_ctx_a, msg)
 }
 
+/// Sets the timeouts of the transfer following the given policy, from the
+/// latest height and timestamp of the destination chain known to the client of
+/// the channel the tokens are sent on.
+pub fn apply_timeout_policy<SendPacketCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    msg: &mut MsgTransfer,
+    policy: &TimeoutPolicy,
+) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+{
+    let (timeout_height_on_b, timeout_timestamp_on_b) = compute_packet_timeouts(
+        send_packet_ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        policy,
+    )?;
+
+    msg.timeout_height_on_b = timeout_height_on_b;
+    msg.timeout_timestamp_on_b = timeout_timestamp_on_b;
+
+    Ok(())
+}
+
 /// Validates the token transfer. If this succeeds, then it is legal to initiate the transfer with [`send_transfer_execute`].
+///
+/// The transfer module must own the source port in the given [`PortKeeper`].
 pub fn send_transfer_validate<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &SendPacketCtx,
+    port_keeper_a: &impl PortKeeper,
     token_ctx_a: &TokenCtx,
     msg: MsgTransfer,
 ) -> Result<(), TokenTransferError>
@@ -38,6 +72,13 @@ where
 {
     token_ctx_a.can_send_coins()?;
 
+    token_ctx_a.get_params()?.verify_send_enabled()?;
+
+    send_packet_ctx_a
+        .packet_size_limits()
+        .verify_memo(msg.packet_data.memo.as_ref())
+        .map_err(ContextError::from)?;
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -56,12 +97,16 @@ where
 
     let token = &msg.packet_data.token;
 
-    let sender: TokenCtx::AccountId = msg
-        .packet_data
-        .sender
-        .clone()
-        .try_into()
-        .map_err(|_| TokenTransferError::ParseAccountFailure)?;
+    let sender = token_ctx_a.convert_signer(&msg.packet_data.sender)?;
+
+    if let Some(authorization) = token_ctx_a.transfer_authorization(&sender) {
+        authorization.validate(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+            token,
+            &msg.packet_data.receiver,
+        )?;
+    }
 
     if is_sender_chain_source(
         msg.port_id_on_a.clone(),
@@ -81,7 +126,7 @@ where
 
     let packet = {
         let data = serde_json::to_vec(&msg.packet_data)
-            .expect("PacketData's infallible Serialize impl failed");
+            .map_err(|_| TokenTransferError::PacketDataSerialization)?;
 
         Packet {
             seq_on_a: sequence,
@@ -95,7 +140,12 @@ where
         }
     };
 
-    send_packet_validate(send_packet_ctx_a, &packet)?;
+    send_packet_validate_with_owner(
+        send_packet_ctx_a,
+        port_keeper_a,
+        &ModuleId::new(MODULE_ID_STR.to_string()),
+        &packet,
+    )?;
 
     Ok(())
 }
@@ -129,12 +179,17 @@ where
 
     let token = &msg.packet_data.token;
 
-    let sender = msg
-        .packet_data
-        .sender
-        .clone()
-        .try_into()
-        .map_err(|_| TokenTransferError::ParseAccountFailure)?;
+    let sender = token_ctx_a.convert_signer(&msg.packet_data.sender)?;
+
+    if let Some(authorization) = token_ctx_a.transfer_authorization(&sender) {
+        let authorization = authorization.consume(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+            token,
+            &msg.packet_data.receiver,
+        )?;
+        token_ctx_a.store_transfer_authorization(&sender, authorization)?;
+    }
 
     if is_sender_chain_source(
         msg.port_id_on_a.clone(),
@@ -153,10 +208,8 @@ where
     }
 
     let packet = {
-        let data = {
-            serde_json::to_vec(&msg.packet_data)
-                .expect("PacketData's infallible Serialize impl failed")
-        };
+        let data = serde_json::to_vec(&msg.packet_data)
+            .map_err(|_| TokenTransferError::PacketDataSerialization)?;
 
         Packet {
             seq_on_a: sequence,
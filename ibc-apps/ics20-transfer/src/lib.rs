@@ -1,7 +1,7 @@
 //! Implementation of the IBC [fungible token transfer](https://github.com/cosmos/ibc/blob/main/spec/app/ics-020-fungible-token-transfer/README.md) (ICS-20) application logic.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
 #![deny(
     warnings,
@@ -24,8 +24,14 @@ pub mod types {
     pub use ibc_app_transfer_types::*;
 }
 
+#[cfg(feature = "serde")]
+pub mod codec;
 pub mod context;
 #[cfg(feature = "serde")]
 pub mod handler;
 #[cfg(feature = "serde")]
 pub mod module;
+#[cfg(feature = "serde")]
+pub mod rate_limit;
+#[cfg(feature = "serde")]
+pub mod receive_fee;
@@ -1,16 +1,21 @@
+use ibc_app_transfer_types::ack_success_b64;
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
+use ibc_app_transfer_types::events::{
+    AckEvent, AckStatusEvent, RecvEvent, RefundReason, TimeoutEvent,
+};
 use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{ack_success_b64, VERSION};
-use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_app_transfer_types::version::TransferVersion;
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, RecvPacketResult,
+};
 use ibc_core::channel::types::channel::{Counterparty, Order};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::channel::types::Version;
-use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_core::router::types::module::ModuleExtras;
+use ibc_core::router::version::AppVersion;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use crate::handler::{
@@ -40,11 +45,7 @@ pub fn on_chan_open_init_validate(
         });
     }
 
-    if !version.is_empty() {
-        version
-            .verify_is_expected(Version::new(VERSION.to_string()))
-            .map_err(ContextError::from)?;
-    }
+    TransferVersion::on_chan_open_init(version)?;
 
     Ok(())
 }
@@ -56,9 +57,11 @@ pub fn on_chan_open_init_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _version: &Version,
+    version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    let version = TransferVersion::on_chan_open_init(version)?;
+
+    Ok((ModuleExtras::empty(), version.to_version()))
 }
 
 pub fn on_chan_open_try_validate(
@@ -77,9 +80,7 @@ pub fn on_chan_open_try_validate(
         });
     }
 
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    TransferVersion::on_chan_open_try(counterparty_version)?;
 
     Ok(())
 }
@@ -91,9 +92,11 @@ pub fn on_chan_open_try_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _counterparty_version: &Version,
+    counterparty_version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    let version = TransferVersion::on_chan_open_try(counterparty_version)?;
+
+    Ok((ModuleExtras::empty(), version.to_version()))
 }
 
 pub fn on_chan_open_ack_validate(
@@ -102,9 +105,7 @@ pub fn on_chan_open_ack_validate(
     _channel_id: &ChannelId,
     counterparty_version: &Version,
 ) -> Result<(), TokenTransferError> {
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    TransferVersion::on_chan_open_ack(counterparty_version)?;
 
     Ok(())
 }
@@ -134,6 +135,17 @@ pub fn on_chan_open_confirm_execute(
     Ok(ModuleExtras::empty())
 }
 
+/// Users may never close transfer channels, as the vouchers minted over them
+/// could no longer be sent back.
+pub fn chan_close_init_allowed(
+    _ctx: &impl TokenTransferValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _signer: &Signer,
+) -> bool {
+    false
+}
+
 pub fn on_chan_close_init_validate(
     _ctx: &impl TokenTransferValidationContext,
     _port_id: &PortId,
@@ -169,7 +181,7 @@ pub fn on_chan_close_confirm_execute(
 pub fn on_recv_packet_execute(
     ctx_b: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
-) -> (ModuleExtras, Acknowledgement) {
+) -> (ModuleExtras, RecvPacketResult) {
     let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
         let ack =
             AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into());
@@ -210,7 +222,7 @@ where
         .map_err(|_| TokenTransferError::AckDeserialization)?;
 
     if !acknowledgement.is_successful() {
-        refund_packet_token_validate(ctx, packet, &data)?;
+        refund_packet_token_validate(ctx, packet, &data, RefundReason::ErrorAcknowledgement)?;
     }
 
     Ok(())
@@ -238,11 +250,14 @@ pub fn on_acknowledgement_packet_execute(
         );
     };
 
-    if !acknowledgement.is_successful() {
-        if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
-            return (ModuleExtras::empty(), Err(err));
+    let refund_event = if acknowledgement.is_successful() {
+        None
+    } else {
+        match refund_packet_token_execute(ctx, packet, &data, RefundReason::ErrorAcknowledgement) {
+            Ok(refund_event) => Some(refund_event),
+            Err(err) => return (ModuleExtras::empty(), Err(err)),
         }
-    }
+    };
 
     let ack_event = AckEvent {
         sender: data.sender,
@@ -253,10 +268,11 @@ pub fn on_acknowledgement_packet_execute(
         acknowledgement: acknowledgement.clone(),
     };
 
-    let extras = ModuleExtras {
+    let mut extras = ModuleExtras {
         events: vec![ack_event.into(), AckStatusEvent { acknowledgement }.into()],
         log: Vec::new(),
     };
+    extras.events.extend(refund_event.map(Into::into));
 
     (extras, Ok(()))
 }
@@ -272,7 +288,7 @@ where
     let data = serde_json::from_slice::<PacketData>(&packet.data)
         .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
 
-    refund_packet_token_validate(ctx, packet, &data)?;
+    refund_packet_token_validate(ctx, packet, &data, RefundReason::Timeout)?;
 
     Ok(())
 }
@@ -289,9 +305,11 @@ pub fn on_timeout_packet_execute(
         );
     };
 
-    if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
-        return (ModuleExtras::empty(), Err(err));
-    }
+    let refund_event = match refund_packet_token_execute(ctx, packet, &data, RefundReason::Timeout)
+    {
+        Ok(refund_event) => refund_event,
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
+    };
 
     let timeout_event = TimeoutEvent {
         refund_receiver: data.sender,
@@ -301,7 +319,7 @@ pub fn on_timeout_packet_execute(
     };
 
     let extras = ModuleExtras {
-        events: vec![timeout_event.into()],
+        events: vec![timeout_event.into(), refund_event.into()],
         log: Vec::new(),
     };
 
@@ -0,0 +1,368 @@
+//! Implements a middleware rate-limiting ICS-20 token transfers per
+//! (channel, denom) pair, which acts as a circuit breaker for bridges.
+//!
+//! Inflows are enforced by [`RateLimitMiddleware`] when receiving packets,
+//! while outflows must be checked and recorded by the host alongside
+//! [`send_transfer`](crate::handler::send_transfer), using
+//! [`rate_limit_outflow_validate`] and [`rate_limit_outflow_execute`].
+//! Outflows of packets that are refunded, either because of an error
+//! acknowledgement or a timeout, are reverted by the middleware, provided the
+//! epoch in which they were sent is still the current one.
+
+use core::fmt::Debug;
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::events::RateLimitExceededEvent;
+use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::rate_limit::{Flow, FlowDirection, Quota};
+use ibc_app_transfer_types::{is_receiver_chain_source, PrefixedCoin, PrefixedDenom, TracePrefix};
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, RecvPacketResult,
+};
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{ChannelId, Sequence};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::{Signer, Timestamp};
+use ibc_core::router::middleware::Middleware;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+/// Methods required by the rate limiter, to be implemented by the host.
+pub trait RateLimitContext {
+    /// Returns the quota applied to the given (channel, denom) pair, or `None`
+    /// if the pair is not rate-limited.
+    fn quota(&self, channel_id: &ChannelId, denom: &PrefixedDenom) -> Option<Quota>;
+
+    /// Returns the flow recorded for the given (channel, denom) pair, if any.
+    fn flow(&self, channel_id: &ChannelId, denom: &PrefixedDenom) -> Option<Flow>;
+
+    /// Stores the flow of the given (channel, denom) pair.
+    fn store_flow(
+        &mut self,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        flow: Flow,
+    ) -> Result<(), TokenTransferError>;
+
+    /// Returns the start of the epoch in which the packet of the given sequence
+    /// was sent over `channel_id`, if its outflow was recorded.
+    fn outflow_epoch(&self, channel_id: &ChannelId, sequence: Sequence) -> Option<Timestamp>;
+
+    /// Stores the start of the epoch in which the packet of the given sequence
+    /// was sent over `channel_id`.
+    fn store_outflow_epoch(
+        &mut self,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        epoch_start: Timestamp,
+    ) -> Result<(), TokenTransferError>;
+
+    /// Deletes the epoch recorded for the packet of the given sequence, once
+    /// the packet is acknowledged or timed out. Deleting a missing record is a
+    /// no-op.
+    fn delete_outflow_epoch(
+        &mut self,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), TokenTransferError>;
+
+    /// Returns the current timestamp of the host chain.
+    fn host_timestamp(&self) -> Result<Timestamp, TokenTransferError>;
+}
+
+/// Checks that adding `coin` to the flow of `channel_id` in the given direction
+/// stays within its quota, and returns the updated flow, if the pair is
+/// rate-limited.
+fn checked_flow(
+    ctx: &impl RateLimitContext,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+    direction: FlowDirection,
+) -> Result<Option<Flow>, TokenTransferError> {
+    let Some(quota) = ctx.quota(channel_id, &coin.denom) else {
+        return Ok(None);
+    };
+
+    let now = ctx.host_timestamp()?;
+
+    let flow = ctx
+        .flow(channel_id, &coin.denom)
+        .unwrap_or_else(|| Flow::new(now))
+        .current(&quota, now);
+
+    flow.checked_add(direction, coin.amount, &quota)
+        .map(Some)
+        .ok_or_else(|| TokenTransferError::RateLimitExceeded {
+            channel_id: channel_id.clone(),
+            denom: coin.denom.clone(),
+            direction,
+            amount: coin.amount,
+            quota: quota.max(direction),
+        })
+}
+
+/// Validates that sending `coin` over `channel_id` does not exceed its outflow
+/// quota.
+pub fn rate_limit_outflow_validate(
+    ctx: &impl RateLimitContext,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    checked_flow(ctx, channel_id, coin, FlowDirection::Outflow).map(|_| ())
+}
+
+/// Records the outflow of `coin` over `channel_id`, sent in the packet of the
+/// given sequence, along with the epoch it was sent in.
+///
+/// A prior call to [`rate_limit_outflow_validate`] MUST have succeeded.
+pub fn rate_limit_outflow_execute(
+    ctx: &mut impl RateLimitContext,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    if let Some(flow) = checked_flow(ctx, channel_id, coin, FlowDirection::Outflow)? {
+        ctx.store_flow(channel_id, &coin.denom, flow)?;
+        ctx.store_outflow_epoch(channel_id, sequence, flow.epoch_start)?;
+    }
+
+    Ok(())
+}
+
+/// Reverts the outflow of `coin` over `channel_id`, when the transfer sent in
+/// the packet of the given sequence was refunded.
+///
+/// Outflows sent in a past epoch are not reverted, as the quota they used
+/// was already released when the epoch was reset.
+fn revert_outflow(
+    ctx: &mut impl RateLimitContext,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    let Some(epoch_start) = ctx.outflow_epoch(channel_id, sequence) else {
+        return Ok(());
+    };
+
+    ctx.delete_outflow_epoch(channel_id, sequence)?;
+
+    let (Some(quota), Some(flow)) = (
+        ctx.quota(channel_id, &coin.denom),
+        ctx.flow(channel_id, &coin.denom),
+    ) else {
+        return Ok(());
+    };
+
+    let flow = flow.current(&quota, ctx.host_timestamp()?);
+
+    if flow.epoch_start != epoch_start {
+        return Ok(());
+    }
+
+    ctx.store_flow(
+        channel_id,
+        &coin.denom,
+        flow.revert(FlowDirection::Outflow, coin.amount),
+    )
+}
+
+/// Returns the coin received on the host chain through the given packet, as
+/// denominated on the host chain.
+pub(crate) fn received_coin(packet: &Packet, data: &PacketData) -> PrefixedCoin {
+    let mut coin = data.token.clone();
+
+    if is_receiver_chain_source(
+        packet.port_id_on_a.clone(),
+        packet.chan_id_on_a.clone(),
+        &coin.denom,
+    ) {
+        let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
+        coin.denom.remove_trace_prefix(&prefix);
+    } else {
+        let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
+        coin.denom.add_trace_prefix(prefix);
+    }
+
+    coin
+}
+
+/// A [`Middleware`] enforcing the inflow quotas of received transfers, and
+/// reverting the outflow of refunded transfers.
+///
+/// Transfers exceeding the quota are rejected with an error acknowledgement,
+/// and a [`RateLimitExceededEvent`] is emitted.
+#[derive(Debug)]
+pub struct RateLimitMiddleware<Ctx> {
+    ctx: Ctx,
+}
+
+impl<Ctx> RateLimitMiddleware<Ctx>
+where
+    Ctx: RateLimitContext,
+{
+    pub fn new(ctx: Ctx) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut Ctx {
+        &mut self.ctx
+    }
+}
+
+impl<Ctx> Middleware for RateLimitMiddleware<Ctx>
+where
+    Ctx: RateLimitContext + Debug,
+{
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, RecvPacketResult) {
+        // Packets that cannot be decoded are left to the wrapped application
+        let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+            return next.on_recv_packet_execute(packet, relayer);
+        };
+
+        let coin = received_coin(packet, &data);
+
+        let flow = match checked_flow(
+            &self.ctx,
+            &packet.chan_id_on_b,
+            &coin,
+            FlowDirection::Inflow,
+        ) {
+            Ok(flow) => flow,
+            Err(err) => {
+                let mut extras = ModuleExtras::empty();
+
+                if let TokenTransferError::RateLimitExceeded { quota, .. } = &err {
+                    extras.events.push(
+                        RateLimitExceededEvent {
+                            channel_id: packet.chan_id_on_b.clone(),
+                            denom: coin.denom.clone(),
+                            direction: FlowDirection::Inflow,
+                            amount: coin.amount,
+                            quota: *quota,
+                        }
+                        .into(),
+                    );
+                }
+
+                return (extras, AcknowledgementStatus::error(err.into()).into());
+            }
+        };
+
+        let (mut extras, result) = next.on_recv_packet_execute(packet, relayer);
+
+        if let (Some(flow), true) = (flow, result.is_successful()) {
+            if let Err(err) = self.ctx.store_flow(&packet.chan_id_on_b, &coin.denom, flow) {
+                extras
+                    .log
+                    .push(format!("failed to record rate limit inflow: {err}"));
+            }
+        }
+
+        (extras, result)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, result) =
+            next.on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+
+        let is_error_ack =
+            serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+                .map(|ack| !ack.is_successful())
+                .unwrap_or(false);
+
+        if result.is_ok() {
+            return if is_error_ack {
+                self.revert_packet_outflow(extras, packet)
+            } else {
+                self.forget_packet_outflow(extras, packet)
+            };
+        }
+
+        (extras, result)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, result) = next.on_timeout_packet_execute(packet, relayer);
+
+        if result.is_ok() {
+            return self.revert_packet_outflow(extras, packet);
+        }
+
+        (extras, result)
+    }
+}
+
+impl<Ctx> RateLimitMiddleware<Ctx>
+where
+    Ctx: RateLimitContext,
+{
+    /// Reverts the outflow of a refunded packet sent by the host chain.
+    ///
+    /// The wrapped application already refunded the tokens, so a failure to
+    /// revert the outflow is logged rather than failing the packet, as for
+    /// the recording of inflows.
+    fn revert_packet_outflow(
+        &mut self,
+        mut extras: ModuleExtras,
+        packet: &Packet,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let reverted = serde_json::from_slice::<PacketData>(&packet.data)
+            .map_err(|_| TokenTransferError::PacketDataDeserialization)
+            .and_then(|data| {
+                revert_outflow(
+                    &mut self.ctx,
+                    &packet.chan_id_on_a,
+                    packet.seq_on_a,
+                    &data.token,
+                )
+            });
+
+        if let Err(err) = reverted {
+            extras
+                .log
+                .push(format!("failed to revert rate limit outflow: {err}"));
+        }
+
+        (extras, Ok(()))
+    }
+
+    /// Deletes the epoch recorded for a successfully acknowledged packet sent
+    /// by the host chain, whose outflow stands.
+    fn forget_packet_outflow(
+        &mut self,
+        mut extras: ModuleExtras,
+        packet: &Packet,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        if let Err(err) = self
+            .ctx
+            .delete_outflow_epoch(&packet.chan_id_on_a, packet.seq_on_a)
+        {
+            extras
+                .log
+                .push(format!("failed to delete rate limit outflow epoch: {err}"));
+        }
+
+        (extras, Ok(()))
+    }
+}
@@ -0,0 +1,177 @@
+//! Implements a middleware deducting a fee from the tokens received by the
+//! host chain, e.g. to fund a community account, without forking the transfer
+//! application.
+//!
+//! The deduction of the fee is validated by [`ReceiveFeeMiddleware`] before
+//! the wrapped application credits the receiver, and the fee is then moved
+//! from the receiver to the fee account. Should the deduction fail after all,
+//! the credit of the receiver is reverted and the transfer rejected with an
+//! error acknowledgement, so that it is refunded as a whole on the sending
+//! chain. A [`ReceiveFeeEvent`] records each deduction.
+
+use core::fmt::Debug;
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::events::ReceiveFeeEvent;
+use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::receive_fee::ReceiveFee;
+use ibc_app_transfer_types::{PrefixedCoin, PrefixedDenom};
+use ibc_core::channel::types::acknowledgement::{AcknowledgementStatus, RecvPacketResult};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::Middleware;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::rate_limit::received_coin;
+
+/// Methods required by the receive fee middleware, to be implemented by the
+/// host.
+pub trait ReceiveFeeContext {
+    /// Returns the fee deducted from the given denom when received over
+    /// `channel_id`, or `None` if no fee applies.
+    fn receive_fee(&self, channel_id: &ChannelId, denom: &PrefixedDenom) -> Option<ReceiveFee>;
+
+    /// Validates that the deducted `fee` can be moved from the receiver of a
+    /// transfer to the fee account, once the receiver is credited the
+    /// received tokens.
+    ///
+    /// If this succeeds, [`Self::deduct_receive_fee_execute`] MUST succeed
+    /// too once the receiver is credited.
+    fn deduct_receive_fee_validate(
+        &self,
+        receiver: &Signer,
+        fee_account: &Signer,
+        fee: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError>;
+
+    /// Moves the deducted `fee` from the receiver of a transfer to the fee
+    /// account.
+    ///
+    /// A prior call to [`Self::deduct_receive_fee_validate`] MUST have
+    /// succeeded.
+    fn deduct_receive_fee_execute(
+        &mut self,
+        receiver: &Signer,
+        fee_account: &Signer,
+        fee: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError>;
+
+    /// Reverts the credit of the received `coin` to the receiver of a
+    /// transfer, i.e. burns the received vouchers or escrows back the
+    /// received native tokens, when its fee could not be deducted.
+    fn revert_receive_execute(
+        &mut self,
+        receiver: &Signer,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError>;
+}
+
+/// A [`Middleware`] deducting the configured [`ReceiveFee`] from successfully
+/// received transfers.
+///
+/// Transfers whose fee cannot be deducted are rejected with an error
+/// acknowledgement, without being handed over to the wrapped application, or
+/// by reverting the credit of the receiver if the deduction only fails once
+/// the wrapped application processed the transfer.
+#[derive(Debug)]
+pub struct ReceiveFeeMiddleware<Ctx> {
+    ctx: Ctx,
+}
+
+impl<Ctx> ReceiveFeeMiddleware<Ctx>
+where
+    Ctx: ReceiveFeeContext,
+{
+    pub fn new(ctx: Ctx) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut Ctx {
+        &mut self.ctx
+    }
+}
+
+impl<Ctx> Middleware for ReceiveFeeMiddleware<Ctx>
+where
+    Ctx: ReceiveFeeContext + Debug,
+{
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, RecvPacketResult) {
+        // Packets that cannot be decoded are left to the wrapped application
+        let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+            return next.on_recv_packet_execute(packet, relayer);
+        };
+
+        let coin = received_coin(packet, &data);
+
+        let Some(fee) = self.ctx.receive_fee(&packet.chan_id_on_b, &coin.denom) else {
+            return next.on_recv_packet_execute(packet, relayer);
+        };
+
+        let deducted = PrefixedCoin {
+            denom: coin.denom.clone(),
+            amount: fee.deduction(coin.amount),
+        };
+
+        if deducted.amount == 0u64.into() {
+            return next.on_recv_packet_execute(packet, relayer);
+        }
+
+        if let Err(err) =
+            self.ctx
+                .deduct_receive_fee_validate(&data.receiver, fee.fee_account(), &deducted)
+        {
+            return (
+                ModuleExtras::empty(),
+                AcknowledgementStatus::error(err.into()).into(),
+            );
+        }
+
+        let (mut extras, result) = next.on_recv_packet_execute(packet, relayer);
+
+        if !result.is_successful() {
+            return (extras, result);
+        }
+
+        if let Err(err) =
+            self.ctx
+                .deduct_receive_fee_execute(&data.receiver, fee.fee_account(), &deducted)
+        {
+            // The receiver is already credited: reverting the credit leaves
+            // the transfer to be refunded by the error acknowledgement
+            if let Err(revert_err) = self.ctx.revert_receive_execute(&data.receiver, &coin) {
+                extras
+                    .log
+                    .push(format!("failed to revert received tokens: {revert_err}"));
+            }
+
+            extras.events.clear();
+
+            return (extras, AcknowledgementStatus::error(err.into()).into());
+        }
+
+        extras.events.push(
+            ReceiveFeeEvent {
+                receiver: data.receiver,
+                fee_account: fee.fee_account().clone(),
+                denom: deducted.denom,
+                amount: deducted.amount,
+                channel_id: packet.chan_id_on_b.clone(),
+            }
+            .into(),
+        );
+
+        (extras, result)
+    }
+}
@@ -0,0 +1,5 @@
+fn main() {
+    // Declares the cfg selecting the host hash function, without the `[lints]`
+    // table which requires a more recent cargo than the MSRV.
+    println!("cargo:rustc-check-cfg=cfg(ibc_host_hasher, values(\"blake2s\"))");
+}
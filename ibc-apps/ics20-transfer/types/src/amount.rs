@@ -92,6 +92,14 @@ impl From<[u64; 4]> for Amount {
 }
 
 impl Amount {
+    pub fn zero() -> Self {
+        Self(U256::zero())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
     pub fn checked_add(self, rhs: Self) -> Option<Self> {
         self.0.checked_add(rhs.0).map(Self)
     }
@@ -99,6 +107,79 @@ impl Amount {
     pub fn checked_sub(self, rhs: Self) -> Option<Self> {
         self.0.checked_sub(rhs.0).map(Self)
     }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.0.checked_mul(rhs.0).map(Self)
+    }
+
+    /// Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.0.checked_div(rhs.0).map(Self)
+    }
+
+    /// Returns `None` if `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.0.checked_rem(rhs.0).map(Self)
+    }
+
+    /// Converts an amount of a token with `from_decimals` decimals into the
+    /// same amount of its representation with `to_decimals` decimals, e.g.
+    /// from 6 decimals on a Cosmos chain to 18 decimals on an EVM chain.
+    ///
+    /// Fails if the converted amount overflows, or if reducing the number of
+    /// decimals would drop a non-zero remainder; see
+    /// [`convert_decimals_lossy`](Self::convert_decimals_lossy) to keep it.
+    pub fn convert_decimals(
+        self,
+        from_decimals: u8,
+        to_decimals: u8,
+    ) -> Result<Self, TokenTransferError> {
+        let (converted, remainder) = self.convert_decimals_lossy(from_decimals, to_decimals)?;
+
+        if !remainder.is_zero() {
+            return Err(TokenTransferError::InexactDecimalConversion {
+                amount: self,
+                from_decimals,
+                to_decimals,
+            });
+        }
+
+        Ok(converted)
+    }
+
+    /// Converts an amount between numbers of decimals as
+    /// [`convert_decimals`](Self::convert_decimals), rounding down, and
+    /// returns the converted amount along with the remainder dropped by the
+    /// conversion, in units of `from_decimals`.
+    pub fn convert_decimals_lossy(
+        self,
+        from_decimals: u8,
+        to_decimals: u8,
+    ) -> Result<(Self, Self), TokenTransferError> {
+        let scale = |decimals: u8| {
+            U256::from(10u8)
+                .checked_pow(U256::from(decimals))
+                .ok_or(TokenTransferError::AmountOverflow)
+        };
+
+        if to_decimals >= from_decimals {
+            let factor = scale(to_decimals - from_decimals)?;
+            let converted = self
+                .0
+                .checked_mul(factor)
+                .ok_or(TokenTransferError::AmountOverflow)?;
+
+            Ok((Self(converted), Self::zero()))
+        } else {
+            // A factor overflowing U256 exceeds any amount, which is then
+            // entirely dropped
+            let Ok(factor) = scale(from_decimals - to_decimals) else {
+                return Ok((Self::zero(), self));
+            };
+
+            Ok((Self(self.0 / factor), Self(self.0 % factor)))
+        }
+    }
 }
 
 impl AsRef<U256> for Amount {
@@ -122,6 +203,28 @@ impl From<u64> for Amount {
     }
 }
 
+impl TryFrom<Amount> for u64 {
+    type Error = TokenTransferError;
+
+    fn try_from(amount: Amount) -> Result<Self, Self::Error> {
+        amount
+            .0
+            .try_into()
+            .map_err(|_| TokenTransferError::AmountOverflow)
+    }
+}
+
+impl TryFrom<Amount> for u128 {
+    type Error = TokenTransferError;
+
+    fn try_from(amount: Amount) -> Result<Self, Self::Error> {
+        amount
+            .0
+            .try_into()
+            .map_err(|_| TokenTransferError::AmountOverflow)
+    }
+}
+
 #[cfg(feature = "serde")]
 fn deserialize<'de, D>(deserializer: D) -> Result<U256, D::Error>
 where
@@ -134,8 +237,72 @@ where
 
 #[cfg(test)]
 mod tests {
+    use primitive_types::U256;
+
     use super::Amount;
 
+    #[test]
+    fn checked_arithmetic() {
+        let max = Amount::from(U256::MAX);
+
+        assert_eq!(
+            Amount::from(6u64).checked_mul(7u64.into()),
+            Some(42u64.into())
+        );
+        assert_eq!(max.checked_mul(2u64.into()), None);
+        assert_eq!(
+            Amount::from(42u64).checked_div(5u64.into()),
+            Some(8u64.into())
+        );
+        assert_eq!(
+            Amount::from(42u64).checked_rem(5u64.into()),
+            Some(2u64.into())
+        );
+        assert_eq!(Amount::from(42u64).checked_div(Amount::zero()), None);
+        assert_eq!(max.checked_add(1u64.into()), None);
+        assert_eq!(Amount::zero().checked_sub(1u64.into()), None);
+    }
+
+    #[test]
+    fn integer_conversions() {
+        assert_eq!(
+            u128::try_from(Amount::from(U256::from(u128::MAX))).ok(),
+            Some(u128::MAX)
+        );
+        assert_eq!(u64::try_from(Amount::from(u64::MAX)).ok(), Some(u64::MAX));
+
+        let above_u128 = Amount::from(U256::from(u128::MAX))
+            .checked_add(1u64.into())
+            .unwrap();
+        assert!(u128::try_from(above_u128).is_err());
+        assert!(u64::try_from(Amount::from(U256::from(u128::from(u64::MAX) + 1))).is_err());
+        assert_eq!(U256::from(Amount::from(U256::MAX)), U256::MAX);
+    }
+
+    #[test]
+    fn convert_decimals() {
+        let one_usdc = Amount::from(1_000_000u64);
+        let one_usdc_evm = Amount::from(1_000_000_000_000_000_000u64);
+
+        assert_eq!(one_usdc.convert_decimals(6, 18).unwrap(), one_usdc_evm);
+        assert_eq!(one_usdc_evm.convert_decimals(18, 6).unwrap(), one_usdc);
+        assert_eq!(one_usdc.convert_decimals(6, 6).unwrap(), one_usdc);
+
+        let with_dust = one_usdc_evm.checked_add(42u64.into()).unwrap();
+        assert!(with_dust.convert_decimals(18, 6).is_err());
+        assert_eq!(
+            with_dust.convert_decimals_lossy(18, 6).unwrap(),
+            (one_usdc, 42u64.into())
+        );
+
+        assert!(Amount::from(U256::MAX).convert_decimals(0, 1).is_err());
+        assert!(one_usdc.convert_decimals(0, 78).is_err());
+        assert_eq!(
+            one_usdc.convert_decimals_lossy(78, 0).unwrap(),
+            (Amount::zero(), one_usdc)
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_amount() {
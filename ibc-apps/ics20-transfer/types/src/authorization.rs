@@ -0,0 +1,191 @@
+//! Defines the transfer authorization, which restricts the token transfers a
+//! sender is allowed to initiate, similar to the transfer authorization of
+//! ibc-go.
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::error::TokenTransferError;
+use crate::{Amount, PrefixedCoin};
+
+/// Grants a sender the right to transfer tokens over a specific channel.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Allocation {
+    /// The port on which the transfers are sent.
+    pub port_id: PortId,
+    /// The channel on which the transfers are sent.
+    pub channel_id: ChannelId,
+    /// The remaining amounts that may be transferred, per denom. Denoms not
+    /// listed here may not be transferred.
+    pub spend_limit: Vec<PrefixedCoin>,
+    /// The receivers that the sender may transfer tokens to. An empty list
+    /// allows any receiver.
+    pub allow_list: Vec<Signer>,
+}
+
+impl Allocation {
+    fn matches(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        &self.port_id == port_id && &self.channel_id == channel_id
+    }
+
+    fn spend_limit(&self, coin: &PrefixedCoin) -> Amount {
+        self.spend_limit
+            .iter()
+            .find(|limit| limit.denom == coin.denom)
+            .map(|limit| limit.amount)
+            .unwrap_or_else(|| Amount::from(0))
+    }
+
+    fn validate(&self, coin: &PrefixedCoin, receiver: &Signer) -> Result<(), TokenTransferError> {
+        if !self.allow_list.is_empty() && !self.allow_list.contains(receiver) {
+            return Err(TokenTransferError::ReceiverNotAllowed {
+                receiver: receiver.clone(),
+            });
+        }
+
+        let spend_limit = self.spend_limit(coin);
+
+        if coin.amount > spend_limit {
+            return Err(TokenTransferError::SpendLimitExceeded {
+                denom: coin.denom.clone(),
+                amount: coin.amount,
+                spend_limit,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The set of channels, spend limits and receivers a sender is restricted to
+/// when initiating token transfers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransferAuthorization {
+    pub allocations: Vec<Allocation>,
+}
+
+impl TransferAuthorization {
+    pub fn new(allocations: Vec<Allocation>) -> Self {
+        Self { allocations }
+    }
+
+    /// Validates that the transfer of `coin` to `receiver` over the given
+    /// channel is authorized.
+    pub fn validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        receiver: &Signer,
+    ) -> Result<(), TokenTransferError> {
+        self.allocations
+            .iter()
+            .find(|allocation| allocation.matches(port_id, channel_id))
+            .ok_or_else(|| TokenTransferError::TransferNotAuthorized {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            })?
+            .validate(coin, receiver)
+    }
+
+    /// Validates the transfer, and returns the authorization with the spend
+    /// limit of the channel reduced by the transferred amount.
+    pub fn consume(
+        mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        receiver: &Signer,
+    ) -> Result<Self, TokenTransferError> {
+        self.validate(port_id, channel_id, coin, receiver)?;
+
+        let limit = self
+            .allocations
+            .iter_mut()
+            .find(|allocation| allocation.matches(port_id, channel_id))
+            .and_then(|allocation| {
+                allocation
+                    .spend_limit
+                    .iter_mut()
+                    .find(|limit| limit.denom == coin.denom)
+            });
+
+        if let Some(limit) = limit {
+            // cannot underflow, as the amount was validated against the limit
+            limit.amount = limit
+                .amount
+                .checked_sub(coin.amount)
+                .unwrap_or_else(|| Amount::from(0));
+        }
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authorization() -> TransferAuthorization {
+        TransferAuthorization::new(vec![Allocation {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+            spend_limit: vec!["100uatom".parse().expect("valid coin")],
+            allow_list: vec![Signer::from("cosmos1receiver".to_string())],
+        }])
+    }
+
+    #[test]
+    fn transfer_authorization_validation() {
+        let auth = authorization();
+        let port_id = PortId::transfer();
+        let receiver = Signer::from("cosmos1receiver".to_string());
+        let coin: PrefixedCoin = "60uatom".parse().expect("valid coin");
+
+        assert!(auth
+            .validate(&port_id, &ChannelId::new(0), &coin, &receiver)
+            .is_ok());
+
+        assert!(matches!(
+            auth.validate(&port_id, &ChannelId::new(1), &coin, &receiver),
+            Err(TokenTransferError::TransferNotAuthorized { .. })
+        ));
+
+        assert!(matches!(
+            auth.validate(
+                &port_id,
+                &ChannelId::new(0),
+                &coin,
+                &Signer::from("cosmos1other".to_string())
+            ),
+            Err(TokenTransferError::ReceiverNotAllowed { .. })
+        ));
+
+        let other_denom: PrefixedCoin = "1uosmo".parse().expect("valid coin");
+        assert!(matches!(
+            auth.validate(&port_id, &ChannelId::new(0), &other_denom, &receiver),
+            Err(TokenTransferError::SpendLimitExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn transfer_authorization_consumes_spend_limit() {
+        let port_id = PortId::transfer();
+        let receiver = Signer::from("cosmos1receiver".to_string());
+        let coin: PrefixedCoin = "60uatom".parse().expect("valid coin");
+
+        let auth = authorization()
+            .consume(&port_id, &ChannelId::new(0), &coin, &receiver)
+            .expect("first transfer is within the spend limit");
+
+        assert_eq!(auth.allocations[0].spend_limit[0].amount, Amount::from(40));
+
+        assert!(matches!(
+            auth.consume(&port_id, &ChannelId::new(0), &coin, &receiver),
+            Err(TokenTransferError::SpendLimitExceeded { .. })
+        ));
+    }
+}
@@ -7,10 +7,14 @@ use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
+use ibc_core::primitives::{DefaultHostHasher, HostHasher};
 use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
 
 use super::error::TokenTransferError;
 
+/// The prefix of hashed IBC denominations, i.e. `ibc/{hash}`.
+pub const IBC_DENOM_PREFIX: &str = "ibc";
+
 /// The "base" of a denomination.
 ///
 /// For example, given the token `my_port-1/my_channel-1/my_port-2/my_channel-2/base_denom`,
@@ -110,6 +114,14 @@ impl TracePrefix {
 
         Some((Self::new(port_id, channel_id), remaining))
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
 }
 
 impl Display for TracePrefix {
@@ -163,6 +175,17 @@ impl TracePath {
         self.0.is_empty()
     }
 
+    /// Returns the number of hops in the path.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an iterator over the hops of the path, starting from the most
+    /// recent one, i.e. in the order they appear in the string representation.
+    pub fn hops(&self) -> impl Iterator<Item = &TracePrefix> {
+        self.0.iter().rev()
+    }
+
     /// Return empty trace path
     pub fn empty() -> Self {
         Self(vec![])
@@ -271,6 +294,143 @@ impl PrefixedDenom {
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// Returns true if the denomination has no trace, i.e. it is native to
+    /// the chain holding it.
+    pub fn is_native(&self) -> bool {
+        self.trace_path.is_empty()
+    }
+
+    /// Returns true if sending the denomination through the given port and
+    /// channel returns it to the chain it came from, which then unescrows it
+    /// instead of minting a voucher.
+    pub fn is_returning_to_source(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        is_receiver_chain_source(port_id.clone(), channel_id.clone(), self)
+    }
+
+    /// Returns the denomination as it appears on the counterparty chain once a
+    /// packet sent through `source_port`/`source_channel` is received on
+    /// `dest_port`/`dest_channel`.
+    ///
+    /// If the token returns to its source, the counterparty strips the
+    /// matching trace prefix; otherwise it prefixes the denomination with its
+    /// own end of the channel.
+    pub fn counterparty_denom(
+        &self,
+        source_port: &PortId,
+        source_channel: &ChannelId,
+        dest_port: &PortId,
+        dest_channel: &ChannelId,
+    ) -> PrefixedDenom {
+        let mut denom = self.clone();
+
+        if self.is_returning_to_source(source_port, source_channel) {
+            denom.remove_trace_prefix(&TracePrefix::new(
+                source_port.clone(),
+                source_channel.clone(),
+            ));
+        } else {
+            denom.add_trace_prefix(TracePrefix::new(dest_port.clone(), dest_channel.clone()));
+        }
+
+        denom
+    }
+
+    /// Returns the hops needed to send the denomination back to the chain
+    /// that created it, starting from the chain currently holding it.
+    ///
+    /// Each hop carries the port and channel to send through, along with the
+    /// denomination as held by the sending chain at that point. The route is
+    /// empty for native denominations.
+    pub fn unwind_route(&self) -> Vec<UnwindHop> {
+        let mut denom = self.clone();
+
+        self.trace_path
+            .hops()
+            .map(|prefix| {
+                let hop = UnwindHop {
+                    prefix: prefix.clone(),
+                    denom: denom.clone(),
+                };
+                denom.remove_trace_prefix(prefix);
+                hop
+            })
+            .collect()
+    }
+
+    /// Returns the denomination as held by the chain that created it.
+    pub fn origin_denom(&self) -> PrefixedDenom {
+        PrefixedDenom::from(self.base_denom.clone())
+    }
+
+    /// Returns the hash of the full denomination path, computed with the
+    /// [`DefaultHostHasher`], which matches ibc-go unless configured
+    /// otherwise.
+    pub fn hash(&self) -> [u8; 32] {
+        DefaultHostHasher::hash(self.to_string().as_bytes())
+    }
+
+    /// Returns the denomination as represented on Cosmos SDK chains, that is
+    /// `ibc/{HASH}` where `HASH` is the upper-case hex encoding of
+    /// [`PrefixedDenom::hash`], or the base denomination if there is no trace.
+    pub fn ibc_hash(&self) -> String {
+        if self.is_native() {
+            return self.base_denom.to_string();
+        }
+
+        let hash: String = self
+            .hash()
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect();
+
+        format!("{IBC_DENOM_PREFIX}/{hash}")
+    }
+}
+
+/// A single hop of the route unwinding a denomination back to its origin, as
+/// returned by [`PrefixedDenom::unwind_route`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnwindHop {
+    /// The port and channel the token is sent through.
+    pub prefix: TracePrefix,
+    /// The denomination as held by the sending chain.
+    pub denom: PrefixedDenom,
+}
+
+/// Resolves hashed IBC denominations, i.e. `ibc/{HASH}`, back to their full
+/// [`PrefixedDenom`]s, to be implemented by hosts storing denomination traces.
+pub trait DenomTraceResolver {
+    /// Returns the denomination whose hash is `hash`, given in upper-case hex.
+    fn denom_trace(&self, hash: &str) -> Option<PrefixedDenom>;
+}
+
+/// Parses a denomination that is either a hashed IBC denomination, which is
+/// resolved with `resolver`, or a full denomination path.
+pub fn resolve_denom(
+    resolver: &impl DenomTraceResolver,
+    denom: &str,
+) -> Result<PrefixedDenom, TokenTransferError> {
+    let Some(hash) = denom
+        .strip_prefix(IBC_DENOM_PREFIX)
+        .and_then(|rest| rest.strip_prefix('/'))
+    else {
+        return PrefixedDenom::from_str(denom);
+    };
+
+    if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(TokenTransferError::InvalidDenomHash {
+            hash: hash.to_string(),
+        });
+    }
+
+    let hash = hash.to_ascii_uppercase();
+
+    resolver
+        .denom_trace(&hash)
+        .filter(|prefixed_denom| prefixed_denom.ibc_hash() == format!("{IBC_DENOM_PREFIX}/{hash}"))
+        .ok_or(TokenTransferError::UnknownDenomHash { hash })
 }
 
 /// Returns true if the denomination originally came from the sender chain and
@@ -616,6 +776,117 @@ mod tests {
         assert_eq!(parsed_remaining, remaining);
     }
 
+    // https://github.com/cosmos/ibc-go/blob/main/modules/apps/transfer/types/trace_test.go
+    #[cfg(not(ibc_host_hasher = "blake2s"))]
+    #[rstest]
+    #[case("uatom", "uatom")]
+    #[case("gamm/pool/1", "gamm/pool/1")]
+    #[case(
+        "transfer/channel-0/uatom",
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+    )]
+    #[case(
+        "transfer/channel-141/uosmo",
+        "ibc/14F9BC3E44B8A9C1BE1FB08980FAB87034C9905EF17CF2F5008FC085218811CC"
+    )]
+    fn test_ibc_hash(#[case] denom: &str, #[case] ibc_denom: &str) {
+        let denom = PrefixedDenom::from_str(denom).expect("valid denom");
+
+        assert_eq!(denom.ibc_hash(), ibc_denom);
+    }
+
+    // The same denominations, hashed with BLAKE2s-256.
+    #[cfg(ibc_host_hasher = "blake2s")]
+    #[rstest]
+    #[case("uatom", "uatom")]
+    #[case(
+        "transfer/channel-0/uatom",
+        "ibc/3249482F6C0C32B20A3029ED1EF0CDB3182A5C900F365B26010F0F823AE8F475"
+    )]
+    #[case(
+        "transfer/channel-141/uosmo",
+        "ibc/DC59D3995E5E98F58C8BFED3D23ECDD168364BD4ED9F111B39CA09EA076AFF16"
+    )]
+    fn test_blake2s_ibc_hash(#[case] denom: &str, #[case] ibc_denom: &str) {
+        let denom = PrefixedDenom::from_str(denom).expect("valid denom");
+
+        assert_eq!(denom.ibc_hash(), ibc_denom);
+    }
+
+    struct Resolver(Vec<PrefixedDenom>);
+
+    impl DenomTraceResolver for Resolver {
+        fn denom_trace(&self, hash: &str) -> Option<PrefixedDenom> {
+            self.0
+                .iter()
+                .find(|denom| denom.ibc_hash().ends_with(hash))
+                .cloned()
+        }
+    }
+
+    #[test]
+    fn test_resolve_denom() {
+        let atom = PrefixedDenom::from_str("transfer/channel-0/uatom").expect("valid denom");
+        let resolver = Resolver(vec![atom.clone()]);
+
+        assert_eq!(
+            resolve_denom(&resolver, &atom.ibc_hash()).ok(),
+            Some(atom.clone())
+        );
+        assert_eq!(
+            resolve_denom(&resolver, &atom.ibc_hash().to_lowercase()).ok(),
+            Some(atom.clone())
+        );
+        assert_eq!(
+            resolve_denom(&resolver, "transfer/channel-0/uatom").ok(),
+            Some(atom)
+        );
+        assert!(matches!(
+            resolve_denom(&resolver, "ibc/ABCD"),
+            Err(TokenTransferError::InvalidDenomHash { .. })
+        ));
+        assert!(matches!(
+            resolve_denom(
+                &resolver,
+                "ibc/14F9BC3E44B8A9C1BE1FB08980FAB87034C9905EF17CF2F5008FC085218811CC"
+            ),
+            Err(TokenTransferError::UnknownDenomHash { .. })
+        ));
+    }
+
+    #[test]
+    fn test_multi_hop_trace() {
+        let port_id = PortId::transfer();
+        let mut denom = PrefixedDenom::from_str("uatom").expect("valid denom");
+
+        assert!(denom.is_native());
+        assert!(!denom.is_returning_to_source(&port_id, &ChannelId::new(0)));
+
+        denom.add_trace_prefix(TracePrefix::new(port_id.clone(), ChannelId::new(0)));
+        denom.add_trace_prefix(TracePrefix::new(port_id.clone(), ChannelId::new(1)));
+
+        assert_eq!(
+            denom.to_string(),
+            "transfer/channel-1/transfer/channel-0/uatom"
+        );
+        assert_eq!(denom.trace_path.len(), 2);
+        assert_eq!(
+            denom
+                .trace_path
+                .hops()
+                .map(|hop| hop.channel_id().clone())
+                .collect::<Vec<_>>(),
+            vec![ChannelId::new(1), ChannelId::new(0)]
+        );
+        assert!(denom.is_returning_to_source(&port_id, &ChannelId::new(1)));
+        assert!(!denom.is_returning_to_source(&port_id, &ChannelId::new(0)));
+
+        denom.remove_trace_prefix(&TracePrefix::new(port_id.clone(), ChannelId::new(1)));
+
+        assert_eq!(denom.to_string(), "transfer/channel-0/uatom");
+        assert!(denom.is_returning_to_source(&port_id, &ChannelId::new(0)));
+    }
+
     #[test]
     fn test_trace_path() -> Result<(), TokenTransferError> {
         assert!(TracePath::from_str("").is_ok(), "empty trace path");
@@ -655,4 +926,54 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_counterparty_denom() {
+        let port_id = PortId::transfer();
+        let atom = PrefixedDenom::from_str("uatom").expect("valid denom");
+
+        let voucher =
+            atom.counterparty_denom(&port_id, &ChannelId::new(0), &port_id, &ChannelId::new(5));
+        assert_eq!(voucher.to_string(), "transfer/channel-5/uatom");
+
+        let returned =
+            voucher.counterparty_denom(&port_id, &ChannelId::new(5), &port_id, &ChannelId::new(0));
+        assert_eq!(returned, atom);
+    }
+
+    #[test]
+    fn test_unwind_route() {
+        let denom = PrefixedDenom::from_str("transfer/channel-1/transfer/channel-2/uatom")
+            .expect("valid denom");
+
+        let route = denom.unwind_route();
+        assert_eq!(
+            route
+                .iter()
+                .map(|hop| (hop.prefix.to_string(), hop.denom.to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "transfer/channel-1".to_string(),
+                    "transfer/channel-1/transfer/channel-2/uatom".to_string()
+                ),
+                (
+                    "transfer/channel-2".to_string(),
+                    "transfer/channel-2/uatom".to_string()
+                ),
+            ]
+        );
+
+        // Walking the route with the counterparty view ends at the origin.
+        let unwound = route.iter().fold(denom.clone(), |denom, hop| {
+            denom.counterparty_denom(
+                hop.prefix.port_id(),
+                hop.prefix.channel_id(),
+                &PortId::transfer(),
+                &ChannelId::new(99),
+            )
+        });
+        assert_eq!(unwound, denom.origin_denom());
+        assert!(denom.origin_denom().unwind_route().is_empty());
+    }
 }
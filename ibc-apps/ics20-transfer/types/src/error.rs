@@ -9,8 +9,12 @@ use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::error::IdentifierError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::{ErrorCode, Signer, SignerError};
 use uint::FromDecStrErr;
 
+use crate::rate_limit::FlowDirection;
+use crate::{Amount, PrefixedDenom};
+
 #[derive(Display, Debug)]
 pub enum TokenTransferError {
     /// context error: `{0}`
@@ -54,6 +58,8 @@ pub enum TokenTransferError {
     },
     /// channel cannot be closed
     CantCloseChannel,
+    /// failed to serialize packet data
+    PacketDataSerialization,
     /// failed to deserialize packet data
     PacketDataDeserialization,
     /// failed to deserialize acknowledgement
@@ -73,12 +79,102 @@ pub enum TokenTransferError {
     DecodeRawMsg { reason: String },
     /// unknown msg type: `{msg_type}`
     UnknownMsgType { msg_type: String },
+    /// invalid IBC denom hash: `{hash}`
+    InvalidDenomHash { hash: String },
+    /// unknown IBC denom hash: `{hash}`
+    UnknownDenomHash { hash: String },
     /// invalid coin string: `{coin}`
     InvalidCoin { coin: String },
+    /// rate limit exceeded: `{direction}` of `{amount}` `{denom}` on channel `{channel_id}` exceeds the quota of `{quota}`
+    RateLimitExceeded {
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+        direction: FlowDirection,
+        amount: Amount,
+        quota: Amount,
+    },
+    /// no transfer authorization for port `{port_id}` and channel `{channel_id}`
+    TransferNotAuthorized {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// receiver `{receiver}` is not in the allow list of the transfer authorization
+    ReceiverNotAllowed { receiver: Signer },
+    /// transfer of `{amount}` `{denom}` exceeds the spend limit of `{spend_limit}`
+    SpendLimitExceeded {
+        denom: PrefixedDenom,
+        amount: Amount,
+        spend_limit: Amount,
+    },
     /// decoding raw bytes as UTF8 string error: `{0}`
     Utf8Decode(Utf8Error),
     /// other error: `{0}`
     Other(String),
+    /// invalid signer: `{0}`
+    InvalidSigner(SignerError),
+    /// signer `{signer}` is not the authority allowed to update the transfer params
+    UnauthorizedParamsUpdate { signer: Signer },
+    /// updating the transfer params is not supported by the host
+    ParamsUpdateNotSupported,
+    /// invalid receive fee of `{basis_points}` basis points, which exceeds the maximum of 10000 basis points
+    InvalidReceiveFee { basis_points: u16 },
+    /// amount overflow
+    AmountOverflow,
+    /// converting `{amount}` from `{from_decimals}` to `{to_decimals}` decimals drops a non-zero remainder
+    InexactDecimalConversion {
+        amount: Amount,
+        from_decimals: u8,
+        to_decimals: u8,
+    },
+}
+
+impl TokenTransferError {
+    /// Returns the stable error code of this error.
+    ///
+    /// Errors wrapping another IBC error report the code of the wrapped error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("transfer", code);
+
+        match self {
+            Self::ContextError(e) => e.code(),
+            Self::InvalidIdentifier(_) => code(1),
+            Self::InsufficientFunds { .. } => code(2),
+            Self::DestinationChannelNotFound { .. } => code(3),
+            Self::EmptyBaseDenom => code(4),
+            Self::InvalidTracePortId { .. } => code(5),
+            Self::InvalidTraceChannelId { .. } => code(6),
+            Self::MalformedTrace(_) => code(7),
+            Self::InvalidTraceLength { .. } => code(8),
+            Self::InvalidAmount(_) => code(9),
+            Self::InvalidToken => code(10),
+            Self::ChannelNotUnordered { .. } => code(11),
+            Self::CantCloseChannel => code(12),
+            Self::PacketDataSerialization => code(13),
+            Self::PacketDataDeserialization => code(14),
+            Self::AckDeserialization => code(15),
+            Self::ReceiveDisabled { .. } => code(16),
+            Self::SendDisabled { .. } => code(17),
+            Self::ParseAccountFailure => code(18),
+            Self::InvalidPort { .. } => code(19),
+            Self::DecodeRawMsg { .. } => code(20),
+            Self::UnknownMsgType { .. } => code(21),
+            Self::InvalidDenomHash { .. } => code(22),
+            Self::UnknownDenomHash { .. } => code(23),
+            Self::InvalidCoin { .. } => code(24),
+            Self::RateLimitExceeded { .. } => code(25),
+            Self::TransferNotAuthorized { .. } => code(26),
+            Self::ReceiverNotAllowed { .. } => code(27),
+            Self::SpendLimitExceeded { .. } => code(28),
+            Self::Utf8Decode(_) => code(29),
+            Self::Other(_) => code(30),
+            Self::InvalidSigner(_) => code(31),
+            Self::UnauthorizedParamsUpdate { .. } => code(32),
+            Self::ParamsUpdateNotSupported => code(33),
+            Self::InvalidReceiveFee { .. } => code(34),
+            Self::AmountOverflow => code(35),
+            Self::InexactDecimalConversion { .. } => code(36),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -97,6 +193,7 @@ impl std::error::Error for TokenTransferError {
             } => Some(e),
             Self::InvalidAmount(e) => Some(e),
             Self::Utf8Decode(e) => Some(e),
+            Self::InvalidSigner(e) => Some(e),
             _ => None,
         }
     }
@@ -120,6 +217,12 @@ impl From<IdentifierError> for TokenTransferError {
     }
 }
 
+impl From<SignerError> for TokenTransferError {
+    fn from(err: SignerError) -> TokenTransferError {
+        Self::InvalidSigner(err)
+    }
+}
+
 impl From<TokenTransferError> for StatusValue {
     fn from(err: TokenTransferError) -> Self {
         StatusValue::new(err.to_string()).expect("error message must not be empty")
@@ -0,0 +1,49 @@
+//! Derivation of the escrow accounts of the token transfer application.
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::VERSION;
+
+/// Length in bytes of the escrow addresses derived by
+/// [`cosmos_escrow_address`].
+pub const ESCROW_ADDRESS_LEN: usize = 20;
+
+/// Returns the address of the account escrowing the tokens sent over the given
+/// channel, as derived by ibc-go.
+///
+/// This follows the `Version1` address derivation of the Cosmos SDK (ADR-028),
+/// where the address is the truncated SHA-256 hash of the ICS-20 version,
+/// followed by a `0` byte and `{port_id}/{channel_id}`.
+pub fn cosmos_escrow_address(port_id: &PortId, channel_id: &ChannelId) -> [u8; ESCROW_ADDRESS_LEN] {
+    let contents = format!("{port_id}/{channel_id}");
+
+    let mut hasher = Sha256::new();
+    hasher.update(VERSION.as_bytes());
+    hasher.update([0]);
+    hasher.update(contents.as_bytes());
+    let hash = hasher.finalize();
+
+    let mut address = [0; ESCROW_ADDRESS_LEN];
+    address.copy_from_slice(&hash[..ESCROW_ADDRESS_LEN]);
+    address
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escrow_address_matches_ibc_go() {
+        let address = cosmos_escrow_address(&PortId::transfer(), &ChannelId::new(0));
+
+        assert_eq!(
+            address,
+            [
+                0xed, 0x23, 0xc6, 0xf4, 0x44, 0x3f, 0x49, 0xc4, 0xb0, 0x8f, 0x85, 0x63, 0x50, 0xa5,
+                0xd2, 0xc6, 0x5a, 0x20, 0x32, 0x35,
+            ]
+        );
+    }
+}
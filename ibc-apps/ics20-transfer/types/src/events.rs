@@ -1,18 +1,27 @@
 //! Defines all token transfer event types
+use core::fmt::{Display, Error as FmtError, Formatter};
+
 use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc_core::host::types::identifiers::ChannelId;
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_core::router::types::event::ModuleEvent;
 
 use super::Memo;
+use crate::rate_limit::FlowDirection;
 use crate::{Amount, PrefixedDenom, MODULE_ID_STR};
 
 const EVENT_TYPE_PACKET: &str = "fungible_token_packet";
 const EVENT_TYPE_TIMEOUT: &str = "timeout";
 const EVENT_TYPE_DENOM_TRACE: &str = "denomination_trace";
 const EVENT_TYPE_TRANSFER: &str = "ibc_transfer";
+const EVENT_TYPE_RATE_LIMIT_EXCEEDED: &str = "rate_limit_exceeded";
+const EVENT_TYPE_REFUND: &str = "transfer_refund";
+const EVENT_TYPE_RECEIVE_FEE: &str = "receive_fee";
 
 /// Contains all events variants that can be emitted from the token transfer application
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Event {
     Recv(RecvEvent),
     Ack(AckEvent),
@@ -20,10 +29,15 @@ pub enum Event {
     Timeout(TimeoutEvent),
     DenomTrace(DenomTraceEvent),
     Transfer(TransferEvent),
+    RateLimitExceeded(RateLimitExceededEvent),
+    Refund(RefundEvent),
+    ReceiveFee(ReceiveFeeEvent),
 }
 
 /// Event emitted by the `onRecvPacket` module callback to indicate the that the
 /// `RecvPacket` message was processed
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RecvEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -59,6 +73,8 @@ impl From<RecvEvent> for ModuleEvent {
 }
 
 /// Event emitted in the `onAcknowledgePacket` module callback
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AckEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -95,6 +111,8 @@ impl From<AckEvent> for ModuleEvent {
 
 /// Event emitted in the `onAcknowledgePacket` module callback to indicate
 /// whether the acknowledgement is a success or a failure
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AckStatusEvent {
     pub acknowledgement: AcknowledgementStatus,
 }
@@ -115,6 +133,8 @@ impl From<AckStatusEvent> for ModuleEvent {
 }
 
 /// Event emitted in the `onTimeoutPacket` module callback
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TimeoutEvent {
     pub refund_receiver: Signer,
     pub refund_denom: PrefixedDenom,
@@ -144,6 +164,8 @@ impl From<TimeoutEvent> for ModuleEvent {
 }
 
 /// Event emitted in the `onRecvPacket` module callback when new tokens are minted
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct DenomTraceEvent {
     pub trace_hash: Option<String>,
     pub denom: PrefixedDenom,
@@ -164,6 +186,8 @@ impl From<DenomTraceEvent> for ModuleEvent {
 }
 
 /// Event emitted after a successful `sendTransfer`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TransferEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -195,6 +219,134 @@ impl From<TransferEvent> for ModuleEvent {
     }
 }
 
+/// Event emitted when a transfer is rejected because it would exceed the rate
+/// limit quota of its (channel, denom) pair
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RateLimitExceededEvent {
+    pub channel_id: ChannelId,
+    pub denom: PrefixedDenom,
+    pub direction: FlowDirection,
+    pub amount: Amount,
+    pub quota: Amount,
+}
+
+impl From<RateLimitExceededEvent> for ModuleEvent {
+    fn from(ev: RateLimitExceededEvent) -> Self {
+        let RateLimitExceededEvent {
+            channel_id,
+            denom,
+            direction,
+            amount,
+            quota,
+        } = ev;
+
+        Self {
+            kind: EVENT_TYPE_RATE_LIMIT_EXCEEDED.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("channel", channel_id).into(),
+                ("denom", denom).into(),
+                ("direction", direction).into(),
+                ("amount", amount).into(),
+                ("quota", quota).into(),
+            ],
+        }
+    }
+}
+
+/// Why the tokens of a sent transfer are refunded to the sender.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RefundReason {
+    /// The receiving chain replied with an error acknowledgement.
+    ErrorAcknowledgement,
+    /// The packet timed out before being received.
+    Timeout,
+}
+
+impl Display for RefundReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::ErrorAcknowledgement => write!(f, "error_acknowledgement"),
+            Self::Timeout => write!(f, "timeout"),
+        }
+    }
+}
+
+/// Event emitted whenever the tokens of a failed transfer are refunded to the
+/// sender, either on an error acknowledgement or on a timeout
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RefundEvent {
+    pub refund_receiver: Signer,
+    pub refund_denom: PrefixedDenom,
+    pub refund_amount: Amount,
+    pub channel_id: ChannelId,
+    pub reason: RefundReason,
+}
+
+impl From<RefundEvent> for ModuleEvent {
+    fn from(ev: RefundEvent) -> Self {
+        let RefundEvent {
+            refund_receiver,
+            refund_denom,
+            refund_amount,
+            channel_id,
+            reason,
+        } = ev;
+
+        Self {
+            kind: EVENT_TYPE_REFUND.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("refund_receiver", refund_receiver).into(),
+                ("refund_denom", refund_denom).into(),
+                ("refund_amount", refund_amount).into(),
+                ("channel", channel_id).into(),
+                ("reason", reason).into(),
+            ],
+        }
+    }
+}
+
+/// Event emitted whenever a fee is deducted from the tokens received by the
+/// host chain, before crediting the receiver
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ReceiveFeeEvent {
+    pub receiver: Signer,
+    pub fee_account: Signer,
+    pub denom: PrefixedDenom,
+    pub amount: Amount,
+    pub channel_id: ChannelId,
+}
+
+impl From<ReceiveFeeEvent> for ModuleEvent {
+    fn from(ev: ReceiveFeeEvent) -> Self {
+        let ReceiveFeeEvent {
+            receiver,
+            fee_account,
+            denom,
+            amount,
+            channel_id,
+        } = ev;
+
+        Self {
+            kind: EVENT_TYPE_RECEIVE_FEE.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("receiver", receiver).into(),
+                ("fee_account", fee_account).into(),
+                ("denom", denom).into(),
+                ("amount", amount).into(),
+                ("channel", channel_id).into(),
+            ],
+        }
+    }
+}
+
 impl From<Event> for ModuleEvent {
     fn from(ev: Event) -> Self {
         match ev {
@@ -204,6 +356,9 @@ impl From<Event> for ModuleEvent {
             Event::Timeout(ev) => ev.into(),
             Event::DenomTrace(ev) => ev.into(),
             Event::Transfer(ev) => ev.into(),
+            Event::RateLimitExceeded(ev) => ev.into(),
+            Event::Refund(ev) => ev.into(),
+            Event::ReceiveFee(ev) => ev.into(),
         }
     }
 }
@@ -18,15 +18,22 @@ extern crate std;
 mod amount;
 mod coin;
 mod denom;
+mod escrow;
 mod memo;
 
 pub use amount::*;
 pub use coin::*;
 pub use denom::*;
+pub use escrow::*;
+pub mod authorization;
 pub mod error;
 pub mod events;
 pub mod msgs;
 pub mod packet;
+pub mod params;
+pub mod rate_limit;
+pub mod receive_fee;
+pub mod version;
 pub use memo::*;
 /// Re-exports `U256` from `primitive-types` crate for convenience.
 pub use primitive_types::U256;
@@ -43,6 +50,10 @@ pub const MODULE_ID_STR: &str = "transfer";
 /// typically bind with.
 pub const PORT_ID_STR: &str = "transfer";
 
+/// The port identifier that the ICS20 applications typically bind with, as
+/// a [`PortId`] validated at compile time.
+pub const PORT_ID: PortId = PortId::transfer();
+
 /// ICS20 application current version.
 pub const VERSION: &str = "ics20-1";
 
@@ -51,6 +62,7 @@ pub const VERSION: &str = "ics20-1";
 pub const ACK_SUCCESS_B64: &str = "AQ==";
 
 use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::host::types::identifiers::PortId;
 
 /// Returns a successful acknowledgement status for the token transfer application.
 pub fn ack_success_b64() -> StatusValue {
@@ -2,7 +2,10 @@
 
 use ibc_core::channel::types::error::PacketError;
 use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::channel::types::timeout_policy::TimeoutPolicy;
+use ibc_core::client::types::Height;
 use ibc_core::handler::types::error::ContextError;
+use ibc_core::handler::types::msgs::Msg;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Timestamp;
@@ -48,6 +51,27 @@ pub struct MsgTransfer {
     pub timeout_timestamp_on_b: Timestamp,
 }
 
+impl MsgTransfer {
+    /// Sets the timeouts of the transfer following the given policy, from the
+    /// latest height and timestamp of the destination chain known to the
+    /// sender.
+    pub fn with_timeout_policy(
+        mut self,
+        policy: &TimeoutPolicy,
+        latest_height_on_b: Height,
+        latest_timestamp_on_b: Timestamp,
+    ) -> Result<Self, TokenTransferError> {
+        let (timeout_height_on_b, timeout_timestamp_on_b) = policy
+            .timeouts(latest_height_on_b, latest_timestamp_on_b)
+            .map_err(ContextError::from)?;
+
+        self.timeout_height_on_b = timeout_height_on_b;
+        self.timeout_timestamp_on_b = timeout_timestamp_on_b;
+
+        Ok(self)
+    }
+}
+
 impl TryFrom<RawMsgTransfer> for MsgTransfer {
     type Error = TokenTransferError;
 
@@ -102,6 +126,12 @@ impl From<MsgTransfer> for RawMsgTransfer {
 
 impl Protobuf<RawMsgTransfer> for MsgTransfer {}
 
+impl Msg for MsgTransfer {
+    type Raw = RawMsgTransfer;
+
+    const TYPE_URL: &'static str = TYPE_URL;
+}
+
 impl TryFrom<Any> for MsgTransfer {
     type Error = TokenTransferError;
 
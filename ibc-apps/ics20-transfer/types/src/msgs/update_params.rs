@@ -0,0 +1,115 @@
+//! Defines the message updating the token transfer params
+
+use ibc_core::handler::types::msgs::Msg;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::transfer::v1::MsgUpdateParams as RawMsgUpdateParams;
+use ibc_proto::Protobuf;
+
+use crate::error::TokenTransferError;
+use crate::params::TransferParams;
+
+pub(crate) const TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgUpdateParams";
+
+/// Message used to update the parameters of the token transfer application,
+/// e.g. to pause sending or receiving tokens.
+///
+/// Only the authority configured by the host may submit it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgUpdateParams {
+    /// the authority submitting the update
+    pub signer: Signer,
+    /// the new params of the transfer application
+    pub params: TransferParams,
+}
+
+impl TryFrom<RawMsgUpdateParams> for MsgUpdateParams {
+    type Error = TokenTransferError;
+
+    fn try_from(raw_msg: RawMsgUpdateParams) -> Result<Self, Self::Error> {
+        Ok(MsgUpdateParams {
+            signer: raw_msg.signer.into(),
+            params: raw_msg
+                .params
+                .ok_or(TokenTransferError::DecodeRawMsg {
+                    reason: "missing params".to_string(),
+                })?
+                .into(),
+        })
+    }
+}
+
+impl From<MsgUpdateParams> for RawMsgUpdateParams {
+    fn from(domain_msg: MsgUpdateParams) -> Self {
+        RawMsgUpdateParams {
+            signer: domain_msg.signer.to_string(),
+            params: Some(domain_msg.params.into()),
+        }
+    }
+}
+
+impl Protobuf<RawMsgUpdateParams> for MsgUpdateParams {}
+
+impl Msg for MsgUpdateParams {
+    type Raw = RawMsgUpdateParams;
+
+    const TYPE_URL: &'static str = TYPE_URL;
+}
+
+impl TryFrom<Any> for MsgUpdateParams {
+    type Error = TokenTransferError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TYPE_URL => MsgUpdateParams::decode_vec(&raw.value).map_err(|e| {
+                TokenTransferError::DecodeRawMsg {
+                    reason: e.to_string(),
+                }
+            }),
+            _ => Err(TokenTransferError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_msg_update_params_roundtrip() {
+        let msg = MsgUpdateParams {
+            signer: Signer::from("authority".to_string()),
+            params: TransferParams::new(false, true),
+        };
+
+        let any = msg.clone().to_any();
+        assert_eq!(any.type_url, TYPE_URL);
+        assert_eq!(MsgUpdateParams::try_from(any).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_msg_update_params_missing_params() {
+        let raw = RawMsgUpdateParams {
+            signer: "authority".to_string(),
+            params: None,
+        };
+
+        assert!(matches!(
+            MsgUpdateParams::try_from(raw),
+            Err(TokenTransferError::DecodeRawMsg { .. })
+        ));
+    }
+}
@@ -2,6 +2,7 @@
 
 use core::str::FromStr;
 
+use ibc_core::channel::types::packet_data::PacketDataInfo;
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
@@ -60,6 +61,20 @@ impl From<PacketData> for RawPacketData {
     }
 }
 
+impl PacketDataInfo for PacketData {
+    fn sender(&self) -> Option<&str> {
+        Some(self.sender.as_ref())
+    }
+
+    fn receiver(&self) -> Option<&str> {
+        Some(self.receiver.as_ref())
+    }
+
+    fn memo(&self) -> Option<&str> {
+        Some(self.memo.as_ref())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use primitive_types::U256;
@@ -118,4 +133,37 @@ mod tests {
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data());
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data_without_memo());
     }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_packet_data_borsh_roundtrip() {
+        let packet_data = PacketData::new_dummy();
+
+        let bytes = borsh::to_vec(&packet_data).unwrap();
+        let decoded = <PacketData as borsh::BorshDeserialize>::try_from_slice(&bytes).unwrap();
+
+        assert_eq!(decoded, packet_data);
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn test_packet_data_json_schema() {
+        let schema = schemars::schema_for!(PacketData);
+        let json = serde_json::to_string(&schema).unwrap();
+
+        for field in ["sender", "receiver", "memo"] {
+            assert!(json.contains(field), "schema is missing `{field}`");
+        }
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn test_event_json_schema() {
+        let schema = schemars::schema_for!(crate::events::AckEvent);
+        let json = serde_json::to_string(&schema).unwrap();
+
+        // The acknowledgement status schema follows its serde renaming.
+        assert!(json.contains("result"));
+        assert!(json.contains("error"));
+    }
 }
@@ -0,0 +1,113 @@
+//! Defines the parameters of the token transfer application.
+
+use ibc_core::primitives::prelude::*;
+use ibc_proto::ibc::applications::transfer::v1::Params as RawParams;
+use ibc_proto::Protobuf;
+
+use crate::error::TokenTransferError;
+
+/// The parameters of the token transfer application, allowing the host to
+/// pause outgoing and incoming transfers, e.g. during a bridge incident.
+///
+/// Both directions are enabled by default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TransferParams {
+    /// Whether tokens may be sent from this chain.
+    pub send_enabled: bool,
+    /// Whether tokens may be received by this chain.
+    pub receive_enabled: bool,
+}
+
+impl TransferParams {
+    pub fn new(send_enabled: bool, receive_enabled: bool) -> Self {
+        Self {
+            send_enabled,
+            receive_enabled,
+        }
+    }
+
+    /// Returns an error if sending tokens is disabled.
+    pub fn verify_send_enabled(&self) -> Result<(), TokenTransferError> {
+        if !self.send_enabled {
+            return Err(TokenTransferError::SendDisabled {
+                reason: "sending is disabled by the transfer params".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns an error if receiving tokens is disabled.
+    pub fn verify_receive_enabled(&self) -> Result<(), TokenTransferError> {
+        if !self.receive_enabled {
+            return Err(TokenTransferError::ReceiveDisabled {
+                reason: "receiving is disabled by the transfer params".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Default for TransferParams {
+    fn default() -> Self {
+        Self::new(true, true)
+    }
+}
+
+impl From<RawParams> for TransferParams {
+    fn from(raw: RawParams) -> Self {
+        Self::new(raw.send_enabled, raw.receive_enabled)
+    }
+}
+
+impl From<TransferParams> for RawParams {
+    fn from(params: TransferParams) -> Self {
+        RawParams {
+            send_enabled: params.send_enabled,
+            receive_enabled: params.receive_enabled,
+        }
+    }
+}
+
+impl Protobuf<RawParams> for TransferParams {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params_toggles() {
+        let params = TransferParams::default();
+        assert!(params.verify_send_enabled().is_ok());
+        assert!(params.verify_receive_enabled().is_ok());
+
+        let paused = TransferParams::new(false, true);
+        assert!(matches!(
+            paused.verify_send_enabled(),
+            Err(TokenTransferError::SendDisabled { .. })
+        ));
+        assert!(paused.verify_receive_enabled().is_ok());
+
+        let paused = TransferParams::new(true, false);
+        assert!(matches!(
+            paused.verify_receive_enabled(),
+            Err(TokenTransferError::ReceiveDisabled { .. })
+        ));
+    }
+
+    #[test]
+    fn test_params_raw_roundtrip() {
+        let params = TransferParams::new(false, true);
+        let raw = RawParams::from(params);
+        assert_eq!(TransferParams::from(raw), params);
+    }
+}
@@ -0,0 +1,186 @@
+//! Defines the data structures used to rate-limit token transfers per
+//! (channel, denom) pair.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::time::Duration;
+
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Timestamp;
+
+use crate::Amount;
+
+/// Direction of a token flow through a channel, as seen from the host chain.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FlowDirection {
+    /// Tokens received from the counterparty.
+    Inflow,
+    /// Tokens sent to the counterparty.
+    Outflow,
+}
+
+impl Display for FlowDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Inflow => write!(f, "inflow"),
+            Self::Outflow => write!(f, "outflow"),
+        }
+    }
+}
+
+/// The maximum amounts of a denom that may flow through a channel within a
+/// single epoch.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Quota {
+    pub max_inflow: Amount,
+    pub max_outflow: Amount,
+    pub epoch_duration: Duration,
+}
+
+impl Quota {
+    /// Returns the maximum amount allowed in the given direction.
+    pub fn max(&self, direction: FlowDirection) -> Amount {
+        match direction {
+            FlowDirection::Inflow => self.max_inflow,
+            FlowDirection::Outflow => self.max_outflow,
+        }
+    }
+}
+
+/// The amounts of a denom that flowed through a channel since the start of
+/// the current epoch.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Flow {
+    pub inflow: Amount,
+    pub outflow: Amount,
+    pub epoch_start: Timestamp,
+}
+
+impl Flow {
+    /// Creates an empty flow for an epoch starting at `epoch_start`.
+    pub fn new(epoch_start: Timestamp) -> Self {
+        Self {
+            inflow: Amount::from(0),
+            outflow: Amount::from(0),
+            epoch_start,
+        }
+    }
+
+    /// Returns the flow applicable at time `now`, which is a fresh one if the
+    /// epoch of `self` is over.
+    pub fn current(self, quota: &Quota, now: Timestamp) -> Self {
+        let epoch_over = !self.epoch_start.is_set()
+            || now
+                .duration_since(&self.epoch_start)
+                .is_some_and(|elapsed| elapsed >= quota.epoch_duration);
+
+        if epoch_over {
+            Self::new(now)
+        } else {
+            self
+        }
+    }
+
+    /// Returns the amount that flowed in the given direction.
+    pub fn amount(&self, direction: FlowDirection) -> Amount {
+        match direction {
+            FlowDirection::Inflow => self.inflow,
+            FlowDirection::Outflow => self.outflow,
+        }
+    }
+
+    /// Returns the flow obtained by adding `amount` in the given direction,
+    /// or `None` if the result would exceed the quota.
+    pub fn checked_add(
+        mut self,
+        direction: FlowDirection,
+        amount: Amount,
+        quota: &Quota,
+    ) -> Option<Self> {
+        let total = self.amount(direction).checked_add(amount)?;
+
+        if total > quota.max(direction) {
+            return None;
+        }
+
+        match direction {
+            FlowDirection::Inflow => self.inflow = total,
+            FlowDirection::Outflow => self.outflow = total,
+        }
+
+        Some(self)
+    }
+
+    /// Returns the flow obtained by reverting `amount` in the given direction,
+    /// saturating at zero.
+    pub fn revert(mut self, direction: FlowDirection, amount: Amount) -> Self {
+        let reverted = self
+            .amount(direction)
+            .checked_sub(amount)
+            .unwrap_or_else(|| Amount::from(0));
+
+        match direction {
+            FlowDirection::Inflow => self.inflow = reverted,
+            FlowDirection::Outflow => self.outflow = reverted,
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quota() -> Quota {
+        Quota {
+            max_inflow: Amount::from(100),
+            max_outflow: Amount::from(50),
+            epoch_duration: Duration::from_secs(3600),
+        }
+    }
+
+    #[test]
+    fn flow_enforces_quota() {
+        let quota = quota();
+        let flow = Flow::new(Timestamp::from_nanoseconds(1).unwrap());
+
+        let flow = flow
+            .checked_add(FlowDirection::Outflow, Amount::from(50), &quota)
+            .unwrap();
+        assert!(flow
+            .checked_add(FlowDirection::Outflow, Amount::from(1), &quota)
+            .is_none());
+
+        // inflow and outflow are tracked independently
+        let flow = flow
+            .checked_add(FlowDirection::Inflow, Amount::from(100), &quota)
+            .unwrap();
+
+        let flow = flow.revert(FlowDirection::Outflow, Amount::from(20));
+        assert_eq!(flow.outflow, Amount::from(30));
+        assert_eq!(
+            flow.revert(FlowDirection::Outflow, Amount::from(40))
+                .outflow,
+            Amount::from(0)
+        );
+    }
+
+    #[test]
+    fn flow_resets_after_epoch() {
+        let quota = quota();
+        let start = Timestamp::from_nanoseconds(1_000_000_000).unwrap();
+        let flow = Flow::new(start)
+            .checked_add(FlowDirection::Outflow, Amount::from(50), &quota)
+            .unwrap();
+
+        let within_epoch = (start + Duration::from_secs(3599)).unwrap();
+        assert_eq!(flow.current(&quota, within_epoch), flow);
+
+        let next_epoch = (start + Duration::from_secs(3600)).unwrap();
+        assert_eq!(flow.current(&quota, next_epoch), Flow::new(next_epoch));
+    }
+}
@@ -0,0 +1,88 @@
+//! Defines the fee deducted from the tokens received by the host chain.
+
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use primitive_types::U256;
+
+use crate::error::TokenTransferError;
+use crate::Amount;
+
+/// The number of basis points in a whole.
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+/// A fee, expressed in basis points of the received amount, deducted from the
+/// tokens credited to the receiver of a transfer into `fee_account`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReceiveFee {
+    basis_points: u16,
+    fee_account: Signer,
+}
+
+impl ReceiveFee {
+    /// Creates a fee of `basis_points` of the received amount, which must not
+    /// exceed [`MAX_BASIS_POINTS`].
+    pub fn new(basis_points: u16, fee_account: Signer) -> Result<Self, TokenTransferError> {
+        if basis_points > MAX_BASIS_POINTS {
+            return Err(TokenTransferError::InvalidReceiveFee { basis_points });
+        }
+
+        Ok(Self {
+            basis_points,
+            fee_account,
+        })
+    }
+
+    pub fn basis_points(&self) -> u16 {
+        self.basis_points
+    }
+
+    pub fn fee_account(&self) -> &Signer {
+        &self.fee_account
+    }
+
+    /// Returns the amount deducted from the given received amount, rounded
+    /// down.
+    pub fn deduction(&self, amount: Amount) -> Amount {
+        let amount = *amount.as_ref();
+        let basis_points = U256::from(self.basis_points);
+        let whole = U256::from(MAX_BASIS_POINTS);
+
+        // Splits the amount to avoid overflowing the multiplication
+        let deduction = (amount / whole) * basis_points + (amount % whole) * basis_points / whole;
+
+        deduction.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deduction() {
+        let fee_account = Signer::from("fee_account".to_string());
+        let fee = ReceiveFee::new(25, fee_account.clone()).unwrap();
+
+        assert_eq!(fee.deduction(100_000u64.into()), 250u64.into());
+        assert_eq!(fee.deduction(399u64.into()), 0u64.into());
+        assert_eq!(fee.deduction(400u64.into()), 1u64.into());
+
+        let max = Amount::from(U256::MAX);
+        let whole = ReceiveFee::new(MAX_BASIS_POINTS, fee_account.clone()).unwrap();
+        assert_eq!(whole.deduction(max), max);
+        assert_eq!(
+            ReceiveFee::new(0, fee_account.clone())
+                .unwrap()
+                .deduction(max),
+            0u64.into()
+        );
+
+        assert_eq!(
+            ReceiveFee::new(MAX_BASIS_POINTS + 1, fee_account)
+                .unwrap_err()
+                .to_string(),
+            "invalid receive fee of `10001` basis points, which exceeds the maximum of 10000 basis points"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+//! Defines the version of the token transfer application negotiated through
+//! the channel version string.
+
+use ibc_core::channel::types::Version;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::version::AppVersion;
+
+use crate::error::TokenTransferError;
+use crate::VERSION;
+
+/// The version of the token transfer application. Only [`VERSION`] is
+/// supported.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransferVersion;
+
+impl AppVersion for TransferVersion {
+    type Error = TokenTransferError;
+
+    fn propose() -> Self {
+        Self
+    }
+
+    fn verify(version: &Version) -> Result<Self, Self::Error> {
+        version
+            .verify_is_expected(Self.to_version())
+            .map_err(ContextError::from)?;
+
+        Ok(Self)
+    }
+
+    fn to_version(&self) -> Version {
+        Version::new(VERSION.to_string())
+    }
+}
@@ -0,0 +1,47 @@
+//! Defines the context trait the host implements to execute the transactions
+//! of the interchain accounts
+
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::IcaHostParams;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Signer;
+
+/// Methods required by the interchain accounts host application, bridging the
+/// messages received from the controller chains to the message execution of
+/// the host
+pub trait InterchainTxExecutor {
+    /// get_port returns the portID for the interchain accounts host module.
+    fn get_port(&self) -> Result<PortId, InterchainAccountError>;
+
+    /// Returns the parameters of the host, including the allowlist of the
+    /// message types interchain accounts may execute.
+    fn ica_host_params(&self) -> Result<IcaHostParams, InterchainAccountError>;
+
+    /// Returns the address of the interchain account the host registers for
+    /// the owner of the given controller port, over the given host connection,
+    /// creating the account if it does not exist yet.
+    ///
+    /// Called when the controller chain opens a channel to the host.
+    fn register_interchain_account(
+        &mut self,
+        host_connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        host_channel_id: &ChannelId,
+    ) -> Result<Signer, InterchainAccountError>;
+
+    /// Executes the messages on behalf of the interchain account bound to the
+    /// given host channel, returning the response of each message in order.
+    ///
+    /// Only allowlisted messages are passed to this method. The host must
+    /// check that each message is signed by the interchain account, and
+    /// execute the messages atomically, i.e. discard the state changes of all
+    /// the messages if any of them fails.
+    fn execute_tx(
+        &mut self,
+        host_port_id: &PortId,
+        host_channel_id: &ChannelId,
+        messages: Vec<Any>,
+    ) -> Result<Vec<Any>, InterchainAccountError>;
+}
@@ -0,0 +1,62 @@
+//! Implements the execution of the transactions received by the interchain
+//! accounts host.
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::packet::{
+    CosmosTx, InterchainAccountPacketData, PacketType, TxMsgData,
+};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+
+use crate::context::InterchainTxExecutor;
+
+/// Executes the transaction of a packet received on the given host channel,
+/// returning the message responses in order.
+///
+/// All the messages are checked against the host allowlist before any of them
+/// is executed, and the packet fails as a whole if any message fails.
+pub fn execute_interchain_tx(
+    ctx: &mut impl InterchainTxExecutor,
+    host_port_id: &PortId,
+    host_channel_id: &ChannelId,
+    data: &InterchainAccountPacketData,
+) -> Result<TxMsgData, InterchainAccountError> {
+    let params = ctx.ica_host_params()?;
+
+    if !params.host_enabled {
+        return Err(InterchainAccountError::HostDisabled);
+    }
+
+    if data.packet_type != PacketType::ExecuteTx {
+        return Err(InterchainAccountError::UnsupportedPacketType {
+            packet_type: data.packet_type.to_string(),
+        });
+    }
+
+    let tx = CosmosTx::decode_vec(&data.data)?;
+
+    if tx.messages.is_empty() {
+        return Err(InterchainAccountError::EmptyTx);
+    }
+
+    if let Some(message) = tx
+        .messages
+        .iter()
+        .find(|message| !params.is_message_allowed(&message.type_url))
+    {
+        return Err(InterchainAccountError::MessageNotAllowed {
+            type_url: message.type_url.clone(),
+        });
+    }
+
+    let expected = tx.messages.len();
+    let msg_responses = ctx.execute_tx(host_port_id, host_channel_id, tx.messages)?;
+
+    if msg_responses.len() != expected {
+        return Err(InterchainAccountError::MismatchedMessageResponses {
+            expected,
+            actual: msg_responses.len(),
+        });
+    }
+
+    Ok(TxMsgData { msg_responses })
+}
@@ -0,0 +1,32 @@
+//! Implementation of the IBC [Interchain
+//! Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+//! (ICS-27) host application logic, executing the allowlisted messages of the
+//! transactions received from controller chains on behalf of their interchain
+//! accounts, and returning the message responses in the acknowledgements.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+/// Re-exports the interchain accounts data structures.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_app_interchain_accounts_types::*;
+}
+
+pub mod context;
+pub mod handler;
+#[cfg(feature = "serde")]
+pub mod module;
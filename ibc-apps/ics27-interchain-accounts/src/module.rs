@@ -0,0 +1,480 @@
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_app_interchain_accounts_types::error::InterchainAccountError;
+use ibc_app_interchain_accounts_types::events::RecvEvent;
+use ibc_app_interchain_accounts_types::metadata::Metadata;
+use ibc_app_interchain_accounts_types::packet::InterchainAccountPacketData;
+use ibc_app_interchain_accounts_types::CONTROLLER_PORT_PREFIX;
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, RecvPacketResult, StatusValue,
+};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::InterchainTxExecutor;
+use crate::handler::execute_interchain_tx;
+
+/// The host never initiates the channel handshake, which is opened by the
+/// controller chain registering an interchain account.
+pub fn on_chan_open_init_validate(
+    _ctx: &impl InterchainTxExecutor,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(), InterchainAccountError> {
+    Err(InterchainAccountError::InvalidHostChannelInit)
+}
+
+pub fn on_chan_open_init_execute(
+    _ctx: &mut impl InterchainTxExecutor,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountError> {
+    Err(InterchainAccountError::InvalidHostChannelInit)
+}
+
+pub fn on_chan_open_try_validate(
+    ctx: &impl InterchainTxExecutor,
+    _order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(), InterchainAccountError> {
+    let bound_port = ctx.get_port()?;
+    if port_id != &bound_port {
+        return Err(InterchainAccountError::InvalidPort {
+            port_id: port_id.clone(),
+            exp_port_id: bound_port,
+        });
+    }
+
+    validate_controller_port(&counterparty.port_id)?;
+
+    parse_metadata(connection_hops, counterparty_version)?;
+
+    Ok(())
+}
+
+/// Registers the interchain account of the controller port owner, and returns
+/// the channel version with its address.
+pub fn on_chan_open_try_execute(
+    ctx: &mut impl InterchainTxExecutor,
+    _order: Order,
+    connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountError> {
+    let mut metadata = parse_metadata(connection_hops, counterparty_version)?;
+
+    let address = ctx.register_interchain_account(
+        &metadata.host_connection_id,
+        &counterparty.port_id,
+        channel_id,
+    )?;
+    metadata.address = address.to_string();
+
+    let version =
+        serde_json::to_string(&metadata).map_err(|e| InterchainAccountError::InvalidMetadata {
+            reason: e.to_string(),
+        })?;
+
+    Ok((ModuleExtras::empty(), Version::new(version)))
+}
+
+pub fn on_chan_open_ack_validate(
+    _ctx: &impl InterchainTxExecutor,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<(), InterchainAccountError> {
+    Err(InterchainAccountError::InvalidHostChannelInit)
+}
+
+pub fn on_chan_open_ack_execute(
+    _ctx: &mut impl InterchainTxExecutor,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Err(InterchainAccountError::InvalidHostChannelInit)
+}
+
+pub fn on_chan_open_confirm_validate(
+    _ctx: &impl InterchainTxExecutor,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Ok(())
+}
+
+pub fn on_chan_open_confirm_execute(
+    _ctx: &mut impl InterchainTxExecutor,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_chan_close_init_validate(
+    _ctx: &impl InterchainTxExecutor,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Err(InterchainAccountError::CantCloseChannel)
+}
+
+pub fn on_chan_close_init_execute(
+    _ctx: &mut impl InterchainTxExecutor,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Err(InterchainAccountError::CantCloseChannel)
+}
+
+pub fn on_chan_close_confirm_validate(
+    _ctx: &impl InterchainTxExecutor,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Ok(())
+}
+
+pub fn on_chan_close_confirm_execute(
+    _ctx: &mut impl InterchainTxExecutor,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Ok(ModuleExtras::empty())
+}
+
+/// Executes the transaction of the packet. The message responses are returned
+/// in a successful acknowledgement, whose result is the base64-encoded
+/// Protobuf `TxMsgData`, as expected by the controller chains.
+pub fn on_recv_packet_execute(
+    ctx_b: &mut impl InterchainTxExecutor,
+    packet: &Packet,
+) -> (ModuleExtras, RecvPacketResult) {
+    let Ok(data) = serde_json::from_slice::<InterchainAccountPacketData>(&packet.data) else {
+        let ack =
+            AcknowledgementStatus::error(InterchainAccountError::PacketDataDeserialization.into());
+        return (ModuleExtras::empty(), ack.into());
+    };
+
+    let (message_count, result) =
+        match execute_interchain_tx(ctx_b, &packet.port_id_on_b, &packet.chan_id_on_b, &data) {
+            Ok(tx_msg_data) => (
+                tx_msg_data.msg_responses.len() as u64,
+                StatusValue::new(BASE64_STANDARD.encode(tx_msg_data.encode_vec()))
+                    .map_err(|_| InterchainAccountError::AckSerialization),
+            ),
+            Err(e) => (0, Err(e)),
+        };
+
+    let ack = match result {
+        Ok(result) => AcknowledgementStatus::success(result),
+        Err(error) => AcknowledgementStatus::error(error.into()),
+    };
+
+    let recv_event = RecvEvent {
+        host_channel_id: packet.chan_id_on_b.clone(),
+        message_count,
+        success: ack.is_successful(),
+    };
+
+    let extras = ModuleExtras {
+        events: vec![recv_event.into()],
+        log: Vec::new(),
+    };
+
+    (extras, ack.into())
+}
+
+pub fn on_acknowledgement_packet_validate(
+    _ctx: &impl InterchainTxExecutor,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> Result<(), InterchainAccountError> {
+    Err(InterchainAccountError::UnexpectedAcknowledgement)
+}
+
+pub fn on_acknowledgement_packet_execute(
+    _ctx: &mut impl InterchainTxExecutor,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainAccountError>) {
+    (
+        ModuleExtras::empty(),
+        Err(InterchainAccountError::UnexpectedAcknowledgement),
+    )
+}
+
+pub fn on_timeout_packet_validate(
+    _ctx: &impl InterchainTxExecutor,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> Result<(), InterchainAccountError> {
+    Err(InterchainAccountError::UnexpectedAcknowledgement)
+}
+
+pub fn on_timeout_packet_execute(
+    _ctx: &mut impl InterchainTxExecutor,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainAccountError>) {
+    (
+        ModuleExtras::empty(),
+        Err(InterchainAccountError::UnexpectedAcknowledgement),
+    )
+}
+
+/// Checks that the controller port is bound by an interchain account owner.
+fn validate_controller_port(port_id: &PortId) -> Result<(), InterchainAccountError> {
+    match port_id.as_str().strip_prefix(CONTROLLER_PORT_PREFIX) {
+        Some(owner) if !owner.is_empty() => Ok(()),
+        _ => Err(InterchainAccountError::InvalidControllerPort {
+            port_id: port_id.clone(),
+        }),
+    }
+}
+
+/// Parses the version metadata proposed by the controller chain, and checks it
+/// against the single-hop connection of the channel.
+fn parse_metadata(
+    connection_hops: &[ConnectionId],
+    counterparty_version: &Version,
+) -> Result<Metadata, InterchainAccountError> {
+    let [host_connection_id] = connection_hops else {
+        return Err(InterchainAccountError::InvalidMetadata {
+            reason: format!(
+                "expected a single connection hop, got {}",
+                connection_hops.len()
+            ),
+        });
+    };
+
+    let metadata: Metadata = serde_json::from_str(counterparty_version.as_str()).map_err(|e| {
+        InterchainAccountError::InvalidMetadata {
+            reason: e.to_string(),
+        }
+    })?;
+
+    metadata.validate(host_connection_id)?;
+
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod test {
+    use ibc_app_interchain_accounts_types::packet::{CosmosTx, PacketType, TxMsgData};
+    use ibc_app_interchain_accounts_types::{
+        IcaHostParams, ENCODING_PROTOBUF, HOST_PORT_ID, TX_TYPE_SDK_MULTI_MSG, VERSION,
+    };
+    use ibc_core::channel::types::timeout::TimeoutHeight;
+    use ibc_core::host::types::identifiers::Sequence;
+    use ibc_core::primitives::proto::Any;
+    use ibc_core::primitives::Timestamp;
+
+    use super::*;
+
+    const MSG_SEND: &str = "/cosmos.bank.v1beta1.MsgSend";
+    const MSG_DELEGATE: &str = "/cosmos.staking.v1beta1.MsgDelegate";
+    const MSG_FAILING: &str = "/test.MsgFail";
+
+    struct MockTxExecutor {
+        params: IcaHostParams,
+        executed: Vec<Any>,
+    }
+
+    impl InterchainTxExecutor for MockTxExecutor {
+        fn get_port(&self) -> Result<PortId, InterchainAccountError> {
+            Ok(HOST_PORT_ID)
+        }
+
+        fn ica_host_params(&self) -> Result<IcaHostParams, InterchainAccountError> {
+            Ok(self.params.clone())
+        }
+
+        fn register_interchain_account(
+            &mut self,
+            _host_connection_id: &ConnectionId,
+            controller_port_id: &PortId,
+            _host_channel_id: &ChannelId,
+        ) -> Result<Signer, InterchainAccountError> {
+            Ok(format!("ica-{controller_port_id}").into())
+        }
+
+        /// Echoes the messages, and fails the whole transaction on
+        /// `MSG_FAILING` messages.
+        fn execute_tx(
+            &mut self,
+            _host_port_id: &PortId,
+            _host_channel_id: &ChannelId,
+            messages: Vec<Any>,
+        ) -> Result<Vec<Any>, InterchainAccountError> {
+            if messages
+                .iter()
+                .any(|message| message.type_url == MSG_FAILING)
+            {
+                return Err(InterchainAccountError::Other("message failed".to_string()));
+            }
+
+            self.executed.extend(messages.iter().cloned());
+
+            Ok(messages)
+        }
+    }
+
+    fn ctx(allow_messages: &[&str]) -> MockTxExecutor {
+        MockTxExecutor {
+            params: IcaHostParams::new(
+                true,
+                allow_messages.iter().map(ToString::to_string).collect(),
+            ),
+            executed: Vec::new(),
+        }
+    }
+
+    fn message(type_url: &str) -> Any {
+        Any {
+            type_url: type_url.to_string(),
+            value: type_url.as_bytes().to_vec(),
+        }
+    }
+
+    fn tx_packet(messages: Vec<Any>) -> Packet {
+        let data = InterchainAccountPacketData {
+            packet_type: PacketType::ExecuteTx,
+            data: CosmosTx { messages }.encode_vec(),
+            memo: String::new(),
+        };
+
+        Packet {
+            seq_on_a: Sequence::from(1),
+            port_id_on_a: PortId::new("icacontroller-owner".to_string()).unwrap(),
+            chan_id_on_a: ChannelId::new(0),
+            port_id_on_b: HOST_PORT_ID,
+            chan_id_on_b: ChannelId::new(0),
+            data: serde_json::to_vec(&data).unwrap(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    fn ack_status(result: RecvPacketResult) -> AcknowledgementStatus {
+        let ack = result.into_acknowledgement().unwrap();
+        serde_json::from_slice(ack.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_allowed_messages_are_executed() {
+        let mut ctx = ctx(&[MSG_SEND, MSG_DELEGATE]);
+        let messages = vec![message(MSG_SEND), message(MSG_DELEGATE)];
+
+        let (_, result) = on_recv_packet_execute(&mut ctx, &tx_packet(messages.clone()));
+
+        let AcknowledgementStatus::Success(result) = ack_status(result) else {
+            panic!("transaction must succeed");
+        };
+        let ack_bytes = BASE64_STANDARD.decode(result.to_string()).unwrap();
+        let tx_msg_data = TxMsgData::decode_vec(&ack_bytes).unwrap();
+
+        assert_eq!(tx_msg_data.msg_responses, messages);
+        assert_eq!(ctx.executed, messages);
+    }
+
+    #[test]
+    fn test_rejected_transactions() {
+        let disallowed = tx_packet(vec![message(MSG_SEND), message(MSG_DELEGATE)]);
+        let empty = tx_packet(vec![]);
+        let failing = tx_packet(vec![message(MSG_SEND), message(MSG_FAILING)]);
+
+        for packet in [disallowed, empty, failing] {
+            let mut ctx = ctx(&[MSG_SEND, MSG_FAILING]);
+            let (_, result) = on_recv_packet_execute(&mut ctx, &packet);
+
+            assert!(!ack_status(result).is_successful());
+            // No message is executed if any of them is rejected.
+            assert!(ctx.executed.is_empty());
+        }
+
+        let mut disabled = ctx(&[MSG_SEND]);
+        disabled.params.host_enabled = false;
+        let (_, result) =
+            on_recv_packet_execute(&mut disabled, &tx_packet(vec![message(MSG_SEND)]));
+        assert!(!ack_status(result).is_successful());
+    }
+
+    #[test]
+    fn test_open_try_registers_interchain_account() {
+        let mut ctx = ctx(&[MSG_SEND]);
+        let connection_hops = [ConnectionId::zero()];
+        let counterparty = Counterparty::new(
+            PortId::new("icacontroller-owner".to_string()).unwrap(),
+            Some(ChannelId::new(0)),
+        );
+        let metadata = Metadata {
+            version: VERSION.to_string(),
+            controller_connection_id: ConnectionId::zero(),
+            host_connection_id: ConnectionId::zero(),
+            address: String::new(),
+            encoding: ENCODING_PROTOBUF.to_string(),
+            tx_type: TX_TYPE_SDK_MULTI_MSG.to_string(),
+        };
+        let version = Version::new(serde_json::to_string(&metadata).unwrap());
+
+        on_chan_open_try_validate(
+            &ctx,
+            Order::Ordered,
+            &connection_hops,
+            &HOST_PORT_ID,
+            &ChannelId::new(0),
+            &counterparty,
+            &version,
+        )
+        .unwrap();
+
+        let (_, version) = on_chan_open_try_execute(
+            &mut ctx,
+            Order::Ordered,
+            &connection_hops,
+            &HOST_PORT_ID,
+            &ChannelId::new(0),
+            &counterparty,
+            &version,
+        )
+        .unwrap();
+
+        let negotiated: Metadata = serde_json::from_str(version.as_str()).unwrap();
+        assert_eq!(negotiated.address, "ica-icacontroller-owner");
+
+        let invalid_counterparty = Counterparty::new(PortId::transfer(), Some(ChannelId::new(0)));
+        assert!(on_chan_open_try_validate(
+            &ctx,
+            Order::Ordered,
+            &connection_hops,
+            &HOST_PORT_ID,
+            &ChannelId::new(0),
+            &invalid_counterparty,
+            &version,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! Defines the interchain accounts error type
+use displaydoc::Display;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::PortId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::ErrorCode;
+
+#[derive(Display, Debug)]
+pub enum InterchainAccountError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// channel handshake must be initiated by the controller chain
+    InvalidHostChannelInit,
+    /// channel cannot be closed
+    CantCloseChannel,
+    /// invalid port: `{port_id}`, expected `{exp_port_id}`
+    InvalidPort {
+        port_id: PortId,
+        exp_port_id: PortId,
+    },
+    /// invalid controller port: `{port_id}`
+    InvalidControllerPort { port_id: PortId },
+    /// invalid version metadata: `{reason}`
+    InvalidMetadata { reason: String },
+    /// failed to deserialize packet data
+    PacketDataDeserialization,
+    /// failed to serialize acknowledgement
+    AckSerialization,
+    /// the host does not expect acknowledgements nor timeouts
+    UnexpectedAcknowledgement,
+    /// unsupported packet type: `{packet_type}`
+    UnsupportedPacketType { packet_type: String },
+    /// decoding transaction error: `{reason}`
+    DecodeTx { reason: String },
+    /// the transaction contains no message
+    EmptyTx,
+    /// the host is not enabled
+    HostDisabled,
+    /// message type `{type_url}` is not allowed
+    MessageNotAllowed { type_url: String },
+    /// expected `{expected}` message responses, got `{actual}`
+    MismatchedMessageResponses { expected: usize, actual: usize },
+    /// other error: `{0}`
+    Other(String),
+}
+
+impl InterchainAccountError {
+    /// Returns the stable error code of this error.
+    ///
+    /// Errors wrapping another IBC error report the code of the wrapped error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("interchainaccounts", code);
+
+        match self {
+            Self::ContextError(e) => e.code(),
+            Self::InvalidHostChannelInit => code(1),
+            Self::CantCloseChannel => code(2),
+            Self::InvalidPort { .. } => code(3),
+            Self::InvalidControllerPort { .. } => code(4),
+            Self::InvalidMetadata { .. } => code(5),
+            Self::PacketDataDeserialization => code(6),
+            Self::AckSerialization => code(7),
+            Self::UnexpectedAcknowledgement => code(8),
+            Self::UnsupportedPacketType { .. } => code(9),
+            Self::DecodeTx { .. } => code(10),
+            Self::EmptyTx => code(11),
+            Self::HostDisabled => code(12),
+            Self::MessageNotAllowed { .. } => code(13),
+            Self::MismatchedMessageResponses { .. } => code(14),
+            Self::Other(_) => code(15),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InterchainAccountError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for InterchainAccountError {
+    fn from(err: ContextError) -> InterchainAccountError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<InterchainAccountError> for StatusValue {
+    fn from(err: InterchainAccountError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
@@ -0,0 +1,37 @@
+//! Defines all interchain accounts event types
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::MODULE_ID_STR;
+
+const EVENT_TYPE_PACKET: &str = "ics27_packet";
+
+/// Event emitted by the `onRecvPacket` module callback to indicate that the
+/// transaction of a packet was processed
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RecvEvent {
+    pub host_channel_id: ChannelId,
+    pub message_count: u64,
+    pub success: bool,
+}
+
+impl From<RecvEvent> for ModuleEvent {
+    fn from(ev: RecvEvent) -> Self {
+        let RecvEvent {
+            host_channel_id,
+            message_count,
+            success,
+        } = ev;
+        Self {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes: vec![
+                ("module", MODULE_ID_STR).into(),
+                ("host_channel_id", host_channel_id).into(),
+                ("message_count", message_count).into(),
+                ("success", success).into(),
+            ],
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Implementation of the IBC [Interchain
+//! Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+//! (ICS-27) data structures, allowing controller chains to execute
+//! transactions on the host chain through accounts the host registers for
+//! them.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod params;
+
+use ibc_core::host::types::identifiers::PortId;
+pub use params::*;
+pub mod error;
+pub mod events;
+pub mod metadata;
+pub mod packet;
+
+/// Module identifier for the interchain accounts host application.
+pub const MODULE_ID_STR: &str = "interchainaccounts";
+
+/// The port identifier that the interchain accounts host applications
+/// typically bind with.
+pub const HOST_PORT_ID_STR: &str = "icahost";
+
+/// The port identifier that the interchain accounts host applications
+/// typically bind with, as a [`PortId`] validated at compile time.
+pub const HOST_PORT_ID: PortId = PortId::from_static(HOST_PORT_ID_STR);
+
+/// The prefix of the ports the controller chains bind with, followed by the
+/// owner of the interchain account.
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+
+/// Interchain accounts application current version.
+pub const VERSION: &str = "ics27-1";
+
+/// The encoding of the messages carried by the packets, as negotiated in the
+/// channel version metadata.
+pub const ENCODING_PROTOBUF: &str = "proto3";
+
+/// The type of the transactions carried by the packets, as negotiated in the
+/// channel version metadata.
+pub const TX_TYPE_SDK_MULTI_MSG: &str = "sdk_multi_msg";
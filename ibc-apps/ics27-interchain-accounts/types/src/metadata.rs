@@ -0,0 +1,68 @@
+//! Defines the channel version metadata negotiated by the controller and host
+//! chains during the channel handshake.
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+
+use crate::error::InterchainAccountError;
+use crate::{ENCODING_PROTOBUF, TX_TYPE_SDK_MULTI_MSG, VERSION};
+
+/// The JSON-encoded channel version of the interchain accounts channels.
+///
+/// The controller chain proposes it with an empty `address`, which the host
+/// fills with the address of the interchain account it registers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    pub version: String,
+    pub controller_connection_id: ConnectionId,
+    pub host_connection_id: ConnectionId,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+impl Metadata {
+    /// Checks that the metadata negotiates the version, encoding and
+    /// transaction type supported by the host, over the given host connection.
+    pub fn validate(
+        &self,
+        host_connection_id: &ConnectionId,
+    ) -> Result<(), InterchainAccountError> {
+        if self.version != VERSION {
+            return Err(InterchainAccountError::InvalidMetadata {
+                reason: format!("expected version {VERSION}, got {}", self.version),
+            });
+        }
+
+        if self.encoding != ENCODING_PROTOBUF {
+            return Err(InterchainAccountError::InvalidMetadata {
+                reason: format!(
+                    "expected encoding {ENCODING_PROTOBUF}, got {}",
+                    self.encoding
+                ),
+            });
+        }
+
+        if self.tx_type != TX_TYPE_SDK_MULTI_MSG {
+            return Err(InterchainAccountError::InvalidMetadata {
+                reason: format!(
+                    "expected tx type {TX_TYPE_SDK_MULTI_MSG}, got {}",
+                    self.tx_type
+                ),
+            });
+        }
+
+        if &self.host_connection_id != host_connection_id {
+            return Err(InterchainAccountError::InvalidMetadata {
+                reason: format!(
+                    "expected host connection {host_connection_id}, got {}",
+                    self.host_connection_id
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+//! Contains the packet types exchanged with the controller chain: the
+//! `InterchainAccountPacketData` carrying the transaction to execute, and the
+//! `TxMsgData` carrying the responses of its messages.
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+use prost::Message;
+
+use crate::error::InterchainAccountError;
+
+/// Raw `ibc.applications.interchain_accounts.v1.CosmosTx` message.
+#[derive(Clone, PartialEq, Message)]
+pub struct RawCosmosTx {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: Vec<Any>,
+}
+
+/// Raw `cosmos.base.abci.v1beta1.TxMsgData` message, omitting its deprecated
+/// `data` field.
+#[derive(Clone, PartialEq, Message)]
+pub struct RawTxMsgData {
+    #[prost(message, repeated, tag = "2")]
+    pub msg_responses: Vec<Any>,
+}
+
+/// The messages the controller chain requests the interchain account to
+/// execute, in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CosmosTx {
+    pub messages: Vec<Any>,
+}
+
+impl CosmosTx {
+    /// Decodes the Protobuf-encoded transaction carried by a packet.
+    pub fn decode_vec(bytes: &[u8]) -> Result<Self, InterchainAccountError> {
+        let raw = RawCosmosTx::decode(bytes).map_err(|e| InterchainAccountError::DecodeTx {
+            reason: e.to_string(),
+        })?;
+
+        Ok(raw.into())
+    }
+
+    pub fn encode_vec(self) -> Vec<u8> {
+        RawCosmosTx::from(self).encode_to_vec()
+    }
+}
+
+impl From<RawCosmosTx> for CosmosTx {
+    fn from(raw: RawCosmosTx) -> Self {
+        Self {
+            messages: raw.messages,
+        }
+    }
+}
+
+impl From<CosmosTx> for RawCosmosTx {
+    fn from(tx: CosmosTx) -> Self {
+        Self {
+            messages: tx.messages,
+        }
+    }
+}
+
+/// The responses of the executed messages, in the order of the messages.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TxMsgData {
+    pub msg_responses: Vec<Any>,
+}
+
+impl TxMsgData {
+    /// Decodes the Protobuf-encoded message responses carried by an
+    /// acknowledgement.
+    pub fn decode_vec(bytes: &[u8]) -> Result<Self, InterchainAccountError> {
+        let raw = RawTxMsgData::decode(bytes).map_err(|e| InterchainAccountError::DecodeTx {
+            reason: e.to_string(),
+        })?;
+
+        Ok(raw.into())
+    }
+
+    pub fn encode_vec(self) -> Vec<u8> {
+        RawTxMsgData::from(self).encode_to_vec()
+    }
+}
+
+impl From<RawTxMsgData> for TxMsgData {
+    fn from(raw: RawTxMsgData) -> Self {
+        Self {
+            msg_responses: raw.msg_responses,
+        }
+    }
+}
+
+impl From<TxMsgData> for RawTxMsgData {
+    fn from(data: TxMsgData) -> Self {
+        Self {
+            msg_responses: data.msg_responses,
+        }
+    }
+}
+
+/// The type of an interchain accounts packet.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketType {
+    #[cfg_attr(feature = "serde", serde(rename = "TYPE_UNSPECIFIED"))]
+    Unspecified,
+    /// The packet carries a [`CosmosTx`] to execute.
+    #[cfg_attr(feature = "serde", serde(rename = "TYPE_EXECUTE_TX"))]
+    ExecuteTx,
+}
+
+impl Display for PacketType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Unspecified => write!(f, "TYPE_UNSPECIFIED"),
+            Self::ExecuteTx => write!(f, "TYPE_EXECUTE_TX"),
+        }
+    }
+}
+
+/// Defines the structure of the packet bytes, as JSON-encoded by the
+/// controller chain.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterchainAccountPacketData {
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub packet_type: PacketType,
+    /// The Protobuf-encoded [`CosmosTx`].
+    #[cfg_attr(feature = "serde", serde(with = "base64_bytes"))]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
+    pub data: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub memo: String,
+}
+
+/// Serializes bytes as base64 strings, following the Protobuf JSON mapping.
+#[cfg(feature = "serde")]
+mod base64_bytes {
+    use base64::prelude::BASE64_STANDARD;
+    use base64::Engine;
+    use ibc_core::primitives::prelude::*;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&BASE64_STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        BASE64_STANDARD
+            .decode(String::deserialize(deserializer)?)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packet_data_json_roundtrip() {
+        let tx = CosmosTx {
+            messages: vec![Any {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: vec![1, 2, 3],
+            }],
+        };
+        let packet_data = InterchainAccountPacketData {
+            packet_type: PacketType::ExecuteTx,
+            data: tx.clone().encode_vec(),
+            memo: String::new(),
+        };
+
+        let json = serde_json::to_string(&packet_data).unwrap();
+        assert!(json.contains(r#""type":"TYPE_EXECUTE_TX""#));
+
+        let decoded: InterchainAccountPacketData = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, packet_data);
+        assert_eq!(CosmosTx::decode_vec(&decoded.data).unwrap(), tx);
+
+        // The memo may be omitted by the controller chain.
+        let decoded: InterchainAccountPacketData =
+            serde_json::from_str(r#"{"type":"TYPE_EXECUTE_TX","data":"AQI="}"#).unwrap();
+        assert_eq!(decoded.data, vec![1, 2]);
+    }
+}
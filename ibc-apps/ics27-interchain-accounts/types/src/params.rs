@@ -0,0 +1,52 @@
+//! Defines the parameters of the interchain accounts host application.
+use ibc_core::primitives::prelude::*;
+
+/// The allowlist entry allowing all the message types.
+pub const ALLOW_ALL_MESSAGES: &str = "*";
+
+/// Parameters of the interchain accounts host application.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IcaHostParams {
+    /// Whether the host executes the received transactions.
+    pub host_enabled: bool,
+    /// The type URLs of the messages interchain accounts are allowed to
+    /// execute, e.g. `/cosmos.bank.v1beta1.MsgSend`, or
+    /// [`ALLOW_ALL_MESSAGES`] to allow any message.
+    pub allow_messages: Vec<String>,
+}
+
+impl IcaHostParams {
+    pub fn new(host_enabled: bool, allow_messages: Vec<String>) -> Self {
+        Self {
+            host_enabled,
+            allow_messages,
+        }
+    }
+
+    /// Checks whether messages of the given type URL are allowed.
+    pub fn is_message_allowed(&self, type_url: &str) -> bool {
+        self.allow_messages
+            .iter()
+            .any(|allowed| allowed == ALLOW_ALL_MESSAGES || allowed == type_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_allowlist() {
+        let params = IcaHostParams::new(true, vec!["/cosmos.bank.v1beta1.MsgSend".to_string()]);
+
+        assert!(params.is_message_allowed("/cosmos.bank.v1beta1.MsgSend"));
+        assert!(!params.is_message_allowed("/cosmos.bank.v1beta1.MsgMultiSend"));
+        assert!(!params.is_message_allowed("/cosmos.bank.v1beta1"));
+        assert!(!IcaHostParams::default().is_message_allowed("/cosmos.bank.v1beta1.MsgSend"));
+
+        let allow_all = IcaHostParams::new(true, vec![ALLOW_ALL_MESSAGES.to_string()]);
+        assert!(allow_all.is_message_allowed("/cosmos.staking.v1beta1.MsgDelegate"));
+    }
+}
@@ -0,0 +1,205 @@
+//! Implements an audit of the consistency of the NFTs escrowed and minted by
+//! the ICS-721 application, for hosts recovering from application bugs.
+//!
+//! [`audit_nft_escrow`] reports every escrowed NFT which is not owned by the
+//! escrow account of its channel, and every voucher minted without the trace
+//! of its class. [`repair_operations`] turns these violations into the
+//! operations restoring the consistency of the escrow, which are applied by
+//! [`apply_repair_operation`].
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+
+use crate::context::{NftTransferExecutionContext, NftTransferValidationContext};
+use crate::types::error::NftTransferError;
+use crate::types::{Memo, PrefixedClassId, TokenId};
+
+/// Methods required by the escrow audit, to be implemented by the host.
+pub trait NftEscrowAuditContext: NftTransferValidationContext {
+    /// Returns the account holding the NFTs escrowed on the given channel.
+    fn escrow_account(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Self::AccountId, NftTransferError>;
+
+    /// Returns the current owner of the given NFT.
+    fn nft_owner(
+        &self,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<Self::AccountId, NftTransferError>;
+
+    /// Returns the class and token IDs of all the vouchers minted upon
+    /// receiving NFTs from other chains.
+    fn minted_vouchers(&self) -> Result<Vec<(PrefixedClassId, TokenId)>, NftTransferError>;
+}
+
+/// An inconsistency of the NFT escrow found by [`audit_nft_escrow`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EscrowViolation<AccountId> {
+    /// An NFT recorded as escrowed on a channel is owned by another account
+    /// than the escrow account of the channel.
+    MisplacedEscrowedNft {
+        port_id: PortId,
+        channel_id: ChannelId,
+        class_id: PrefixedClassId,
+        token_id: TokenId,
+        owner: AccountId,
+    },
+    /// An NFT recorded as escrowed on a channel does not exist.
+    MissingEscrowedNft {
+        port_id: PortId,
+        channel_id: ChannelId,
+        class_id: PrefixedClassId,
+        token_id: TokenId,
+    },
+    /// A voucher was minted without the trace of its class.
+    MissingClassTrace {
+        class_id: PrefixedClassId,
+        token_id: TokenId,
+    },
+}
+
+/// An operation restoring the consistency of the NFT escrow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RepairOperation<AccountId> {
+    /// Moves the NFT from its current owner back to the escrow account of the
+    /// channel.
+    ReturnToEscrow {
+        owner: AccountId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        class_id: PrefixedClassId,
+        token_id: TokenId,
+    },
+    /// Restores the trace of the class of minted vouchers.
+    RestoreClassTrace { class_id: PrefixedClassId },
+}
+
+/// Audits the NFTs escrowed on the given channels and the vouchers minted by
+/// the host, returning every violation of the escrow consistency.
+pub fn audit_nft_escrow<'a, Ctx>(
+    ctx: &Ctx,
+    channels: impl IntoIterator<Item = (&'a PortId, &'a ChannelId)>,
+) -> Result<Vec<EscrowViolation<Ctx::AccountId>>, NftTransferError>
+where
+    Ctx: NftEscrowAuditContext,
+{
+    let mut violations = Vec::new();
+
+    for (port_id, channel_id) in channels {
+        let escrow_account = ctx.escrow_account(port_id, channel_id)?;
+
+        for (class_id, token_id) in ctx.escrowed_nfts(port_id, channel_id)? {
+            match ctx.nft_owner(&class_id, &token_id) {
+                Ok(owner) if owner == escrow_account => {}
+                Ok(owner) => violations.push(EscrowViolation::MisplacedEscrowedNft {
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                    class_id,
+                    token_id,
+                    owner,
+                }),
+                Err(NftTransferError::NftNotFound) => {
+                    violations.push(EscrowViolation::MissingEscrowedNft {
+                        port_id: port_id.clone(),
+                        channel_id: channel_id.clone(),
+                        class_id,
+                        token_id,
+                    })
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    let traced_classes = ctx.traced_nft_classes()?;
+
+    for (class_id, token_id) in ctx.minted_vouchers()? {
+        if class_id.trace_path.is_empty() || !traced_classes.contains(&class_id) {
+            violations.push(EscrowViolation::MissingClassTrace { class_id, token_id });
+        }
+    }
+
+    Ok(violations)
+}
+
+/// Returns the operations repairing the given violations.
+///
+/// Escrowed NFTs which no longer exist cannot be repaired automatically and
+/// are left to the host, as are vouchers minted with an empty trace, since
+/// there is no trace to restore. The trace of a class is restored once,
+/// whatever the number of its vouchers missing it.
+pub fn repair_operations<AccountId>(
+    violations: impl IntoIterator<Item = EscrowViolation<AccountId>>,
+) -> Vec<RepairOperation<AccountId>>
+where
+    AccountId: PartialEq,
+{
+    let mut operations = Vec::new();
+
+    for violation in violations {
+        let operation = match violation {
+            EscrowViolation::MisplacedEscrowedNft {
+                port_id,
+                channel_id,
+                class_id,
+                token_id,
+                owner,
+            } => RepairOperation::ReturnToEscrow {
+                owner,
+                port_id,
+                channel_id,
+                class_id,
+                token_id,
+            },
+            EscrowViolation::MissingEscrowedNft { .. } => continue,
+            EscrowViolation::MissingClassTrace { class_id, .. } => {
+                if class_id.trace_path.is_empty() {
+                    continue;
+                }
+                RepairOperation::RestoreClassTrace { class_id }
+            }
+        };
+
+        if !operations.contains(&operation) {
+            operations.push(operation);
+        }
+    }
+
+    operations
+}
+
+/// Applies the given repair operation through the NFT transfer execution
+/// context.
+///
+/// Restored classes are created without URI nor data, which the host may
+/// update afterwards.
+pub fn apply_repair_operation<Ctx>(
+    ctx: &mut Ctx,
+    operation: &RepairOperation<Ctx::AccountId>,
+) -> Result<(), NftTransferError>
+where
+    Ctx: NftTransferExecutionContext,
+{
+    match operation {
+        RepairOperation::ReturnToEscrow {
+            owner,
+            port_id,
+            channel_id,
+            class_id,
+            token_id,
+        } => ctx.escrow_nft_execute(
+            owner,
+            port_id,
+            channel_id,
+            class_id,
+            token_id,
+            &Memo::from(""),
+        ),
+        RepairOperation::RestoreClassTrace { class_id } => {
+            ctx.create_or_update_class_execute(class_id, None, None)
+        }
+    }
+}
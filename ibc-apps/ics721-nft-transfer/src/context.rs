@@ -1,12 +1,13 @@
 //! Defines the required context traits for ICS-721 to interact with host
 //! machine.
+use ibc_core::channel::types::Version;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 
 use crate::types::error::NftTransferError;
 use crate::types::{
-    ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenUri,
+    ClassData, ClassId, ClassUri, Memo, Pagination, PrefixedClassId, TokenData, TokenId, TokenUri,
 };
 
 pub trait NftContext {
@@ -49,6 +50,29 @@ pub trait NftTransferValidationContext {
     /// Returns Ok() if the host chain supports receiving NFTs.
     fn can_receive_nft(&self) -> Result<(), NftTransferError>;
 
+    /// Returns the version negotiated by the given channel of the host chain.
+    ///
+    /// Received packets carrying metadata updates are rejected unless the
+    /// channel was negotiated with a version supporting them.
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, NftTransferError>;
+
+    /// Converts a sender or receiver into an account of the host chain.
+    ///
+    /// Defaults to the `TryFrom<Signer>` conversion of `AccountId`. Hosts may
+    /// override it to reject malformed signers during validation, e.g. by
+    /// delegating to a [`SignerConverter`](ibc_core::primitives::SignerConverter)
+    /// such as [`Bech32Validator`](ibc_core::primitives::Bech32Validator).
+    fn convert_signer(&self, signer: &Signer) -> Result<Self::AccountId, NftTransferError> {
+        signer
+            .clone()
+            .try_into()
+            .map_err(|_| NftTransferError::ParseAccountFailure)
+    }
+
     /// Validates that the NFT can be created or updated successfully.
     ///
     /// Note: some existing ICS-721 implementations may not strictly adhere to
@@ -119,6 +143,19 @@ pub trait NftTransferValidationContext {
         memo: &Memo,
     ) -> Result<(), NftTransferError>;
 
+    /// Validates that the NFT may be transferred.
+    ///
+    /// Hosts supporting non-transferable (e.g. soulbound) classes or tokens
+    /// should return [`NftTransferError::NonTransferableNft`] for them. By
+    /// default, every NFT is transferable.
+    fn transfer_policy_validate(
+        &self,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
     /// Returns a hash of the prefixed class ID and the token ID.
     /// Implement only if the host chain supports hashed class ID and token ID.
     fn token_hash_string(
@@ -139,6 +176,43 @@ pub trait NftTransferValidationContext {
     /// Returns the NFT class
     fn get_nft_class(&self, class_id: &PrefixedClassId)
         -> Result<Self::NftClass, NftTransferError>;
+
+    /// Returns the class and token IDs of all the NFTs escrowed on the given
+    /// channel.
+    fn escrowed_nfts(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<(PrefixedClassId, TokenId)>, NftTransferError>;
+
+    /// Returns a page of the NFTs escrowed on the given channel.
+    ///
+    /// Defaults to paginating over [`escrowed_nfts`](Self::escrowed_nfts).
+    /// Hosts able to iterate over their escrow store from an offset should
+    /// override it to avoid loading every entry.
+    fn escrowed_nfts_paginated(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        pagination: &Pagination,
+    ) -> Result<Vec<(PrefixedClassId, TokenId)>, NftTransferError> {
+        Ok(pagination.paginate(self.escrowed_nfts(port_id, channel_id)?))
+    }
+
+    /// Returns the IDs of all the NFT classes created from a trace, i.e. the
+    /// classes of the NFTs received from other chains.
+    fn traced_nft_classes(&self) -> Result<Vec<PrefixedClassId>, NftTransferError>;
+
+    /// Returns a page of the NFT classes created from a trace.
+    ///
+    /// Defaults to paginating over
+    /// [`traced_nft_classes`](Self::traced_nft_classes).
+    fn traced_nft_classes_paginated(
+        &self,
+        pagination: &Pagination,
+    ) -> Result<Vec<PrefixedClassId>, NftTransferError> {
+        Ok(pagination.paginate(self.traced_nft_classes()?))
+    }
 }
 
 /// Read-write methods required in NFT transfer execution context.
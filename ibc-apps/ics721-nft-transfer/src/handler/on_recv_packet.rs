@@ -6,6 +6,7 @@ use crate::context::NftTransferExecutionContext;
 use crate::types::error::NftTransferError;
 use crate::types::events::TokenTraceEvent;
 use crate::types::packet::PacketData;
+use crate::types::version::NftTransferVersion;
 use crate::types::{is_receiver_chain_source, TracePrefix};
 
 /// This function handles the transfer receiving logic.
@@ -25,11 +26,25 @@ where
         .can_receive_nft()
         .map_err(|err| (ModuleExtras::empty(), err))?;
 
-    let receiver_account = data
-        .receiver
-        .clone()
-        .try_into()
-        .map_err(|_| (ModuleExtras::empty(), NftTransferError::ParseAccountFailure))?;
+    if data.metadata_update.is_some() {
+        let version = ctx_b
+            .channel_version(&packet.port_id_on_b, &packet.chan_id_on_b)
+            .map_err(|err| (ModuleExtras::empty(), err))?;
+        let supports_metadata_update = NftTransferVersion::try_from(&version)
+            .map_err(|err| (ModuleExtras::empty(), err))?
+            .supports_metadata_update();
+        if !supports_metadata_update {
+            return Err((
+                ModuleExtras::empty(),
+                NftTransferError::MetadataUpdateNotNegotiated { version },
+            )
+                .into());
+        }
+    }
+
+    let receiver_account = ctx_b
+        .convert_signer(&data.receiver)
+        .map_err(|err| (ModuleExtras::empty(), err))?;
 
     let extras = if is_receiver_chain_source(
         packet.port_id_on_a.clone(),
@@ -37,6 +52,20 @@ where
         &data.class_id,
     ) {
         // sender chain is not the source, unescrow the NFT
+
+        // the class metadata held by the receiver, i.e. the source chain, is
+        // authoritative and cannot be updated by a counterparty
+        if data
+            .metadata_update
+            .is_some_and(|update| update.class || update.tokens)
+        {
+            return Err((
+                ModuleExtras::empty(),
+                NftTransferError::MetadataUpdateNotAllowed,
+            )
+                .into());
+        }
+
         let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
         let class_id = {
             let mut c = data.class_id;
@@ -81,9 +110,18 @@ where
             events: vec![],
             log: Vec::new(),
         };
+        let update_class = data.updates_class_metadata();
+        let update_tokens = data.updates_token_metadata();
+
         for (i, token_id) in data.token_ids.0.iter().enumerate() {
-            let token_uri = data.token_uris.as_ref().and_then(|uris| uris.get(i));
-            let token_data = data.token_data.as_ref().and_then(|data| data.get(i));
+            let (token_uri, token_data) = if update_tokens {
+                (
+                    data.token_uris.as_ref().and_then(|uris| uris.get(i)),
+                    data.token_data.as_ref().and_then(|data| data.get(i)),
+                )
+            } else {
+                (None, None)
+            };
 
             let trace_event = TokenTraceEvent {
                 trace_hash: ctx_b.token_hash_string(&class_id, token_id),
@@ -95,20 +133,24 @@ where
             // Note: the validation is called before the execution.
             // Refer to ICS-20 `process_recv_packet_execute()`.
 
-            ctx_b
-                .create_or_update_class_validate(
-                    &class_id,
-                    data.class_uri.as_ref(),
-                    data.class_data.as_ref(),
-                )
-                .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
-            ctx_b
-                .create_or_update_class_execute(
-                    &class_id,
-                    data.class_uri.as_ref(),
-                    data.class_data.as_ref(),
-                )
-                .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
+            // the stored class metadata is only overwritten when requested,
+            // unless the class does not exist yet
+            if update_class || ctx_b.get_nft_class(&class_id).is_err() {
+                ctx_b
+                    .create_or_update_class_validate(
+                        &class_id,
+                        data.class_uri.as_ref(),
+                        data.class_data.as_ref(),
+                    )
+                    .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
+                ctx_b
+                    .create_or_update_class_execute(
+                        &class_id,
+                        data.class_uri.as_ref(),
+                        data.class_data.as_ref(),
+                    )
+                    .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
+            }
 
             ctx_b
                 .mint_nft_validate(
@@ -1,10 +1,12 @@
 use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
-use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
+use ibc_core::channel::handler::{send_packet_execute, send_packet_validate_with_owner};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::handler::types::events::MessageEvent;
 use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
+use ibc_core::router::port::PortKeeper;
 use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::module::ModuleId;
 
 use crate::context::{
     NftClassContext, NftContext, NftTransferExecutionContext, NftTransferValidationContext,
@@ -12,11 +14,13 @@ use crate::context::{
 use crate::types::error::NftTransferError;
 use crate::types::events::TransferEvent;
 use crate::types::msgs::transfer::MsgTransfer;
+use crate::types::version::NftTransferVersion;
 use crate::types::{is_sender_chain_source, MODULE_ID_STR};
 
 /// Initiate a token transfer. Equivalent to calling [`send_nft_transfer_validate`], followed by [`send_nft_transfer_execute`].
 pub fn send_nft_transfer<SendPacketCtx, TransferCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
+    port_keeper_a: &impl PortKeeper,
     transfer_ctx: &mut TransferCtx,
     msg: MsgTransfer,
 ) -> Result<(), NftTransferError>
@@ -24,13 +28,16 @@ where
     SendPacketCtx: SendPacketExecutionContext,
     TransferCtx: NftTransferExecutionContext,
 {
-    send_nft_transfer_validate(send_packet_ctx_a, transfer_ctx, msg.clone())?;
+    send_nft_transfer_validate(send_packet_ctx_a, port_keeper_a, transfer_ctx, msg.clone())?;
     send_nft_transfer_execute(send_packet_ctx_a, transfer_ctx, msg)
 }
 
 /// Validates the NFT transfer
+///
+/// The NFT transfer module must own the source port in the given [`PortKeeper`].
 pub fn send_nft_transfer_validate<SendPacketCtx, TransferCtx>(
     send_packet_ctx_a: &SendPacketCtx,
+    port_keeper_a: &impl PortKeeper,
     transfer_ctx: &TransferCtx,
     msg: MsgTransfer,
 ) -> Result<(), NftTransferError>
@@ -43,6 +50,15 @@ where
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
+    if msg.packet_data.metadata_update.is_some() {
+        let version = chan_end_on_a.version();
+        if !NftTransferVersion::try_from(version)?.supports_metadata_update() {
+            return Err(NftTransferError::MetadataUpdateNotNegotiated {
+                version: version.clone(),
+            });
+        }
+    }
+
     let port_id_on_b = chan_end_on_a.counterparty().port_id().clone();
     let chan_id_on_b = chan_end_on_a
         .counterparty()
@@ -56,12 +72,7 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let sender: TransferCtx::AccountId = msg
-        .packet_data
-        .sender
-        .clone()
-        .try_into()
-        .map_err(|_| NftTransferError::ParseAccountFailure)?;
+    let sender = transfer_ctx.convert_signer(&msg.packet_data.sender)?;
 
     let mut packet_data = msg.packet_data;
     let class_id = &packet_data.class_id;
@@ -74,6 +85,8 @@ where
         data.clear();
     }
     for token_id in token_ids.as_ref() {
+        transfer_ctx.transfer_policy_validate(class_id, token_id)?;
+
         if is_sender_chain_source(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), class_id) {
             transfer_ctx.escrow_nft_validate(
                 &sender,
@@ -113,7 +126,7 @@ where
 
     let packet = {
         let data = serde_json::to_vec(&packet_data)
-            .expect("PacketData's infallible Serialize impl failed");
+            .map_err(|_| NftTransferError::PacketDataSerialization)?;
 
         Packet {
             seq_on_a: sequence,
@@ -127,7 +140,12 @@ where
         }
     };
 
-    send_packet_validate(send_packet_ctx_a, &packet)?;
+    send_packet_validate_with_owner(
+        send_packet_ctx_a,
+        port_keeper_a,
+        &ModuleId::new(MODULE_ID_STR.to_string()),
+        &packet,
+    )?;
 
     Ok(())
 }
@@ -159,12 +177,7 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let sender = msg
-        .packet_data
-        .sender
-        .clone()
-        .try_into()
-        .map_err(|_| NftTransferError::ParseAccountFailure)?;
+    let sender = transfer_ctx.convert_signer(&msg.packet_data.sender)?;
 
     let mut packet_data = msg.packet_data;
     let class_id = &packet_data.class_id;
@@ -213,9 +226,8 @@ where
     packet_data.class_data = nft_class.get_data().cloned();
 
     let packet = {
-        let data = {
-            serde_json::to_vec(&packet_data).expect("PacketData's infallible Serialize impl failed")
-        };
+        let data = serde_json::to_vec(&packet_data)
+            .map_err(|_| NftTransferError::PacketDataSerialization)?;
 
         Packet {
             seq_on_a: sequence,
@@ -3,7 +3,7 @@
 //! (ICS-721) application logic.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
 #![deny(
     warnings,
@@ -17,6 +17,8 @@
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+#[cfg(feature = "serde")]
+pub mod audit;
 #[cfg(feature = "serde")]
 pub mod context;
 #[cfg(feature = "serde")]
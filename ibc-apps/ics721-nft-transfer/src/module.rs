@@ -1,22 +1,25 @@
 //! Provides IBC module callbacks implementation for the ICS-721 transfer.
-use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, RecvPacketResult,
+};
 use ibc_core::channel::types::channel::{Counterparty, Order};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::channel::types::Version;
-use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_core::router::types::module::ModuleExtras;
+use ibc_core::router::version::AppVersion;
 
 use crate::context::{NftTransferExecutionContext, NftTransferValidationContext};
 use crate::handler::{
     process_recv_packet_execute, refund_packet_nft_execute, refund_packet_nft_validate,
 };
+use crate::types::ack_success_b64;
 use crate::types::error::NftTransferError;
 use crate::types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
 use crate::types::packet::PacketData;
-use crate::types::{ack_success_b64, VERSION};
+use crate::types::version::NftTransferVersion;
 
 pub fn on_chan_open_init_validate(
     ctx: &impl NftTransferValidationContext,
@@ -41,11 +44,7 @@ pub fn on_chan_open_init_validate(
         });
     }
 
-    if !version.is_empty() {
-        version
-            .verify_is_expected(Version::new(VERSION.to_string()))
-            .map_err(ContextError::from)?;
-    }
+    NftTransferVersion::on_chan_open_init(version)?;
 
     Ok(())
 }
@@ -57,9 +56,11 @@ pub fn on_chan_open_init_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _version: &Version,
+    version: &Version,
 ) -> Result<(ModuleExtras, Version), NftTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    let version = NftTransferVersion::on_chan_open_init(version)?;
+
+    Ok((ModuleExtras::empty(), version.to_version()))
 }
 
 pub fn on_chan_open_try_validate(
@@ -78,9 +79,7 @@ pub fn on_chan_open_try_validate(
         });
     }
 
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    NftTransferVersion::on_chan_open_try(counterparty_version)?;
 
     Ok(())
 }
@@ -92,9 +91,11 @@ pub fn on_chan_open_try_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _counterparty_version: &Version,
+    counterparty_version: &Version,
 ) -> Result<(ModuleExtras, Version), NftTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    let version = NftTransferVersion::on_chan_open_try(counterparty_version)?;
+
+    Ok((ModuleExtras::empty(), version.to_version()))
 }
 
 pub fn on_chan_open_ack_validate(
@@ -103,9 +104,7 @@ pub fn on_chan_open_ack_validate(
     _channel_id: &ChannelId,
     counterparty_version: &Version,
 ) -> Result<(), NftTransferError> {
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    NftTransferVersion::on_chan_open_ack(counterparty_version)?;
 
     Ok(())
 }
@@ -135,6 +134,17 @@ pub fn on_chan_open_confirm_execute(
     Ok(ModuleExtras::empty())
 }
 
+/// Users may never close transfer channels, as the vouchers minted over them
+/// could no longer be sent back.
+pub fn chan_close_init_allowed(
+    _ctx: &impl NftTransferValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _signer: &Signer,
+) -> bool {
+    false
+}
+
 pub fn on_chan_close_init_validate(
     _ctx: &impl NftTransferValidationContext,
     _port_id: &PortId,
@@ -170,7 +180,7 @@ pub fn on_chan_close_confirm_execute(
 pub fn on_recv_packet_execute(
     ctx_b: &mut impl NftTransferExecutionContext,
     packet: &Packet,
-) -> (ModuleExtras, Acknowledgement) {
+) -> (ModuleExtras, RecvPacketResult) {
     let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
         let ack = AcknowledgementStatus::error(NftTransferError::PacketDataDeserialization.into());
         return (ModuleExtras::empty(), ack.into());
@@ -5,10 +5,15 @@ use core::str::Utf8Error;
 use displaydoc::Display;
 use ibc_core::channel::types::acknowledgement::StatusValue;
 use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::Version;
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::error::IdentifierError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::{ErrorCode, SignerError};
+
+use crate::class::PrefixedClassId;
+use crate::token::TokenId;
 
 #[derive(Display, Debug)]
 pub enum NftTransferError {
@@ -65,8 +70,15 @@ pub enum NftTransferError {
     OwnerNotFound,
     /// nft is not found
     NftNotFound,
+    /// nft `{token_id}` of class `{class_id}` is not transferable
+    NonTransferableNft {
+        class_id: PrefixedClassId,
+        token_id: TokenId,
+    },
     /// nft class is not found
     NftClassNotFound,
+    /// failed to serialize packet data
+    PacketDataSerialization,
     /// failed to deserialize packet data
     PacketDataDeserialization,
     /// failed to deserialize acknowledgement
@@ -82,6 +94,12 @@ pub enum NftTransferError {
         port_id: PortId,
         exp_port_id: PortId,
     },
+    /// unsupported NFT transfer version: `{version}`
+    UnsupportedVersion { version: Version },
+    /// metadata updates are not supported by channel version `{version}`
+    MetadataUpdateNotNegotiated { version: Version },
+    /// metadata updates are only accepted from the source chain of the class
+    MetadataUpdateNotAllowed,
     /// decoding raw msg error: `{reason}`
     DecodeRawMsg { reason: String },
     /// unknown msg type: `{msg_type}`
@@ -90,6 +108,56 @@ pub enum NftTransferError {
     Utf8Decode(Utf8Error),
     /// other error: `{0}`
     Other(String),
+    /// invalid signer: `{0}`
+    InvalidSigner(SignerError),
+}
+
+impl NftTransferError {
+    /// Returns the stable error code of this error.
+    ///
+    /// Errors wrapping another IBC error report the code of the wrapped error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("nft-transfer", code);
+
+        match self {
+            Self::ContextError(e) => e.code(),
+            Self::InvalidIdentifier(_) => code(1),
+            Self::InvalidUri { .. } => code(2),
+            Self::DestinationChannelNotFound { .. } => code(3),
+            Self::EmptyBaseClassId => code(4),
+            Self::InvalidTracePortId { .. } => code(5),
+            Self::InvalidTraceChannelId { .. } => code(6),
+            Self::InvalidTraceLength { .. } => code(7),
+            Self::NoTokenId => code(8),
+            Self::InvalidTokenId => code(9),
+            Self::DuplicatedTokenIds => code(10),
+            Self::TokenMismatched => code(11),
+            Self::InvalidJsonData => code(12),
+            Self::InvalidIcs721Data => code(13),
+            Self::ChannelNotUnordered { .. } => code(14),
+            Self::CantCloseChannel => code(15),
+            Self::InvalidOwner { .. } => code(16),
+            Self::OwnerNotFound => code(17),
+            Self::NftNotFound => code(18),
+            Self::NonTransferableNft { .. } => code(19),
+            Self::NftClassNotFound => code(20),
+            Self::PacketDataSerialization => code(21),
+            Self::PacketDataDeserialization => code(22),
+            Self::AckDeserialization => code(23),
+            Self::ReceiveDisabled { .. } => code(24),
+            Self::SendDisabled { .. } => code(25),
+            Self::ParseAccountFailure => code(26),
+            Self::InvalidPort { .. } => code(27),
+            Self::UnsupportedVersion { .. } => code(28),
+            Self::MetadataUpdateNotNegotiated { .. } => code(29),
+            Self::MetadataUpdateNotAllowed => code(30),
+            Self::DecodeRawMsg { .. } => code(31),
+            Self::UnknownMsgType { .. } => code(32),
+            Self::Utf8Decode(_) => code(33),
+            Self::Other(_) => code(34),
+            Self::InvalidSigner(_) => code(35),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -110,6 +178,8 @@ impl std::error::Error for NftTransferError {
                 validation_error: e,
                 ..
             } => Some(e),
+            Self::Utf8Decode(e) => Some(e),
+            Self::InvalidSigner(e) => Some(e),
             _ => None,
         }
     }
@@ -133,6 +203,12 @@ impl From<IdentifierError> for NftTransferError {
     }
 }
 
+impl From<SignerError> for NftTransferError {
+    fn from(err: SignerError) -> NftTransferError {
+        Self::InvalidSigner(err)
+    }
+}
+
 impl From<NftTransferError> for StatusValue {
     fn from(err: NftTransferError) -> Self {
         StatusValue::new(err.to_string()).expect("error message must not be empty")
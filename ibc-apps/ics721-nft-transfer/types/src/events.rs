@@ -13,6 +13,8 @@ const EVENT_TYPE_TOKEN_TRACE: &str = "token_trace";
 const EVENT_TYPE_TRANSFER: &str = "ibc_nft_transfer";
 
 /// Contains all events variants that can be emitted from the NFT transfer application
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Event {
     Recv(RecvEvent),
     Ack(AckEvent),
@@ -24,6 +26,8 @@ pub enum Event {
 
 /// Event emitted by the `onRecvPacket` module callback to indicate the that the
 /// `RecvPacket` message was processed
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RecvEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -59,6 +63,8 @@ impl From<RecvEvent> for ModuleEvent {
 }
 
 /// Event emitted in the `onAcknowledgePacket` module callback
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AckEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -95,6 +101,8 @@ impl From<AckEvent> for ModuleEvent {
 
 /// Event emitted in the `onAcknowledgePacket` module callback to indicate
 /// whether the acknowledgement is a success or a failure
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AckStatusEvent {
     pub acknowledgement: AcknowledgementStatus,
 }
@@ -115,6 +123,8 @@ impl From<AckStatusEvent> for ModuleEvent {
 }
 
 /// Event emitted in the `onTimeoutPacket` module callback
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TimeoutEvent {
     pub refund_receiver: Signer,
     pub refund_class: PrefixedClassId,
@@ -144,6 +154,8 @@ impl From<TimeoutEvent> for ModuleEvent {
 }
 
 /// Event emitted in the `onRecvPacket` module callback when new tokens are minted
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TokenTraceEvent {
     pub trace_hash: Option<String>,
     pub class: PrefixedClassId,
@@ -169,6 +181,8 @@ impl From<TokenTraceEvent> for ModuleEvent {
 }
 
 /// Event emitted after a successful `sendTransfer`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TransferEvent {
     pub sender: Signer,
     pub receiver: Signer,
@@ -20,6 +20,7 @@ extern crate std;
 mod class;
 mod data;
 mod memo;
+mod pagination;
 mod token;
 
 pub mod events;
@@ -28,8 +29,10 @@ pub use class::*;
 pub use data::*;
 pub mod packet;
 pub use memo::*;
+pub use pagination::*;
 pub use token::*;
 pub mod error;
+pub mod version;
 
 /// Re-exports ICS-721 NFT transfer proto types from the `ibc-proto` crate.
 pub mod proto {
@@ -42,14 +45,22 @@ pub const MODULE_ID_STR: &str = "nft_transfer";
 /// The port identifier that the ICS-721 applications typically bind with.
 pub const PORT_ID_STR: &str = "nft-transfer";
 
+/// The port identifier that the ICS-721 applications typically bind with, as
+/// a [`PortId`] validated at compile time.
+pub const PORT_ID: PortId = PortId::from_static(PORT_ID_STR);
+
 /// ICS-721 application current version.
 pub const VERSION: &str = "ics721-1";
 
+/// ICS-721 application version supporting metadata updates.
+pub const VERSION_V2: &str = "ics721-2";
+
 /// The successful string used for creating an acknowledgement status,
 /// equivalent to `base64::encode(0x01)`.
 pub const ACK_SUCCESS_B64: &str = "AQ==";
 
 use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::host::types::identifiers::PortId;
 
 /// Returns a successful acknowledgement status for the NFT transfer application.
 pub fn ack_success_b64() -> StatusValue {
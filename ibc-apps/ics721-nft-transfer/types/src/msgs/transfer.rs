@@ -3,6 +3,7 @@
 use ibc_core::channel::types::error::PacketError;
 use ibc_core::channel::types::timeout::TimeoutHeight;
 use ibc_core::handler::types::error::ContextError;
+use ibc_core::handler::types::msgs::Msg;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Timestamp;
@@ -85,6 +86,7 @@ impl TryFrom<RawMsgTransfer> for MsgTransfer {
                 sender: raw_msg.sender.into(),
                 receiver: raw_msg.receiver.into(),
                 memo,
+                metadata_update: None,
             },
             timeout_height_on_b,
             timeout_timestamp_on_b,
@@ -120,6 +122,12 @@ impl From<MsgTransfer> for RawMsgTransfer {
 
 impl Protobuf<RawMsgTransfer> for MsgTransfer {}
 
+impl Msg for MsgTransfer {
+    type Raw = RawMsgTransfer;
+
+    const TYPE_URL: &'static str = TYPE_URL;
+}
+
 impl TryFrom<Any> for MsgTransfer {
     type Error = NftTransferError;
 
@@ -12,6 +12,7 @@ use crate::class::{ClassData, ClassUri, PrefixedClassId};
 use crate::error::NftTransferError;
 use crate::memo::Memo;
 use crate::token::{TokenData, TokenIds, TokenUri};
+use crate::version::NftTransferVersion;
 
 /// Defines the structure of token transfers' packet bytes
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -40,6 +41,46 @@ pub struct PacketData {
     pub sender: Signer,
     pub receiver: Signer,
     pub memo: Option<Memo>,
+    /// Metadata updates requested by the sender, only allowed over channels
+    /// negotiated with [`NftTransferVersion::V2`](crate::version::NftTransferVersion::V2).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub metadata_update: Option<MetadataUpdate>,
+}
+
+/// Describes how the receiver must handle the metadata carried by a packet.
+///
+/// Packets without metadata updates follow the original ICS-721 rules: the
+/// receiver creates or updates the class metadata, and mints vouchers with the
+/// token metadata. Packets with metadata updates follow these rules instead:
+///
+/// - The stored class metadata is only overwritten if `class` is set, and is
+///   otherwise only written when the class is created.
+/// - Vouchers are only minted with the token metadata if `tokens` is set.
+/// - Metadata updates are rejected when the receiver is the source of the
+///   class, as the source chain holds the authoritative metadata.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MetadataUpdate {
+    /// Whether the receiver must overwrite its stored class metadata.
+    pub class: bool,
+    /// Whether the receiver must mint the vouchers with the token metadata.
+    pub tokens: bool,
 }
 
 impl PacketData {
@@ -81,6 +122,7 @@ impl PacketData {
             sender,
             receiver,
             memo,
+            metadata_update: None,
         };
 
         packet_data.validate_basic()?;
@@ -88,6 +130,35 @@ impl PacketData {
         Ok(packet_data)
     }
 
+    /// Attaches metadata updates to the packet data.
+    pub fn with_metadata_update(self, metadata_update: MetadataUpdate) -> Self {
+        Self {
+            metadata_update: Some(metadata_update),
+            ..self
+        }
+    }
+
+    /// Returns the minimal version a channel must have been negotiated with
+    /// to carry this packet data.
+    pub fn required_version(&self) -> NftTransferVersion {
+        if self.metadata_update.is_some() {
+            NftTransferVersion::V2
+        } else {
+            NftTransferVersion::V1
+        }
+    }
+
+    /// Returns true if the receiver must overwrite its stored class metadata.
+    pub fn updates_class_metadata(&self) -> bool {
+        self.metadata_update.map_or(true, |update| update.class)
+    }
+
+    /// Returns true if the receiver must mint the vouchers with the token
+    /// metadata.
+    pub fn updates_token_metadata(&self) -> bool {
+        self.metadata_update.map_or(true, |update| update.tokens)
+    }
+
     /// Performs the basic validation of the packet data fields.
     pub fn validate_basic(&self) -> Result<(), NftTransferError> {
         if self.token_ids.0.is_empty() {
@@ -230,6 +301,7 @@ mod tests {
                 sender: address.clone(),
                 receiver: address,
                 memo: memo.map(|m| m.to_string().into()),
+                metadata_update: None,
             }
         }
 
@@ -246,6 +318,7 @@ mod tests {
                 sender: address.clone(),
                 receiver: address,
                 memo: None,
+                metadata_update: None,
             }
         }
 
@@ -304,6 +377,29 @@ mod tests {
         PacketData::new_min_dummy().deser_json_assert_eq(dummy_min_json_packet_data_with_null());
     }
 
+    #[test]
+    fn test_packet_data_metadata_update() {
+        let packet_data = PacketData::new_min_dummy().with_metadata_update(MetadataUpdate {
+            class: true,
+            tokens: false,
+        });
+
+        let json = r#"{"classId":"class","tokenIds":["token_0"],"sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","metadataUpdate":{"class":true,"tokens":false}}"#;
+
+        packet_data.ser_json_assert_eq(json);
+        packet_data.deser_json_assert_eq(json);
+
+        assert_eq!(packet_data.required_version(), NftTransferVersion::V2);
+        assert!(packet_data.updates_class_metadata());
+        assert!(!packet_data.updates_token_metadata());
+
+        let legacy = PacketData::new_min_dummy();
+
+        assert_eq!(legacy.required_version(), NftTransferVersion::V1);
+        assert!(legacy.updates_class_metadata());
+        assert!(legacy.updates_token_metadata());
+    }
+
     #[test]
     fn test_invalid_packet_data() {
         // the number of tokens is mismatched
@@ -0,0 +1,57 @@
+//! Defines the pagination of the enumeration methods of the NFT transfer
+//! context.
+use ibc_core::primitives::prelude::*;
+
+/// Selects a page of the entries enumerated by the NFT transfer context.
+///
+/// Entries are skipped by `offset` and at most `limit` of them are returned,
+/// in the order of the underlying enumeration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pagination {
+    pub offset: u64,
+    pub limit: u64,
+}
+
+impl Pagination {
+    pub fn new(offset: u64, limit: u64) -> Self {
+        Self { offset, limit }
+    }
+
+    /// Returns the pagination selecting every entry.
+    pub fn all() -> Self {
+        Self {
+            offset: 0,
+            limit: u64::MAX,
+        }
+    }
+
+    /// Returns the page of the given entries selected by this pagination.
+    pub fn paginate<T>(&self, entries: impl IntoIterator<Item = T>) -> Vec<T> {
+        let offset = usize::try_from(self.offset).unwrap_or(usize::MAX);
+        let limit = usize::try_from(self.limit).unwrap_or(usize::MAX);
+
+        entries.into_iter().skip(offset).take(limit).collect()
+    }
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paginate() {
+        let entries = [0, 1, 2, 3, 4];
+
+        assert_eq!(Pagination::all().paginate(entries), vec![0, 1, 2, 3, 4]);
+        assert_eq!(Pagination::new(1, 2).paginate(entries), vec![1, 2]);
+        assert_eq!(Pagination::new(3, 10).paginate(entries), vec![3, 4]);
+        assert!(Pagination::new(5, 1).paginate(entries).is_empty());
+        assert!(Pagination::new(0, 0).paginate(entries).is_empty());
+    }
+}
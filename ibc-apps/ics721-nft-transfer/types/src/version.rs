@@ -0,0 +1,79 @@
+//! Defines the versions of the NFT transfer application negotiated through
+//! the channel version string.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core::channel::types::Version;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::version::AppVersion;
+
+use crate::error::NftTransferError;
+use crate::{VERSION, VERSION_V2};
+
+/// The versions of the NFT transfer application.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NftTransferVersion {
+    /// The original ICS-721 packet format.
+    V1,
+    /// Extends [`V1`](Self::V1) with metadata updates, allowing the sender to
+    /// request the receiver to overwrite its stored class metadata.
+    V2,
+}
+
+impl NftTransferVersion {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::V1 => VERSION,
+            Self::V2 => VERSION_V2,
+        }
+    }
+
+    /// Returns true if packets sent over channels of this version may carry
+    /// metadata updates.
+    pub fn supports_metadata_update(&self) -> bool {
+        matches!(self, Self::V2)
+    }
+}
+
+impl Display for NftTransferVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&Version> for NftTransferVersion {
+    type Error = NftTransferError;
+
+    fn try_from(version: &Version) -> Result<Self, Self::Error> {
+        match version.as_str() {
+            VERSION => Ok(Self::V1),
+            VERSION_V2 => Ok(Self::V2),
+            _ => Err(NftTransferError::UnsupportedVersion {
+                version: version.clone(),
+            }),
+        }
+    }
+}
+
+impl From<NftTransferVersion> for Version {
+    fn from(version: NftTransferVersion) -> Self {
+        Version::new(version.as_str().to_string())
+    }
+}
+
+impl AppVersion for NftTransferVersion {
+    type Error = NftTransferError;
+
+    /// Channels opened without version keep using the original packet format.
+    fn propose() -> Self {
+        Self::V1
+    }
+
+    fn verify(version: &Version) -> Result<Self, Self::Error> {
+        Self::try_from(version)
+    }
+
+    fn to_version(&self) -> Version {
+        (*self).into()
+    }
+}
@@ -27,3 +27,30 @@ pub mod nft_transfer {
     #[cfg(feature = "nft-transfer")]
     pub use ibc_app_nft_transfer::*;
 }
+
+/// Re-exports the implementation of the IBC [Interchain Atomic
+/// Swap](https://github.com/cosmos/ibc/blob/main/spec/app/ics-100-atomic-swap/README.md)
+/// (ICS-100) application logic.
+pub mod atomic_swap {
+    #[doc(inline)]
+    #[cfg(feature = "atomic-swap")]
+    pub use ibc_app_atomic_swap::*;
+}
+
+/// Re-exports the implementation of the [Async Interchain
+/// Queries](https://github.com/cosmos/ibc-apps/tree/main/modules/async-icq)
+/// (ICQ) host application logic.
+pub mod async_icq {
+    #[doc(inline)]
+    #[cfg(feature = "async-icq")]
+    pub use ibc_app_async_icq::*;
+}
+
+/// Re-exports the implementation of the IBC [Interchain
+/// Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+/// (ICS-27) host application logic.
+pub mod interchain_accounts {
+    #[doc(inline)]
+    #[cfg(feature = "interchain-accounts")]
+    pub use ibc_app_interchain_accounts::*;
+}
@@ -0,0 +1,86 @@
+//! Measures the `Any` encoding and decoding of the Tendermint client types and
+//! of the messages a host chain routes through [`MsgEnvelope`].
+
+use core::fmt::Display;
+
+use criterion::measurement::WallTime;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use ibc::clients::tendermint::types::{
+    client_type as tm_client_type, ConsensusState as TmConsensusState, Header as TmHeader,
+};
+use ibc::core::channel::types::msgs::MsgRecvPacket;
+use ibc::core::client::types::msgs::MsgUpdateClient;
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::primitives::ToProto;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc_testkit::context::TendermintContext;
+use ibc_testkit::fixtures::clients::tendermint::dummy_tm_client_state_from_header;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_recv_packet;
+use ibc_testkit::fixtures::core::context::TestContextConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::TestBlock;
+
+fn bench_roundtrip<T>(group: &mut BenchmarkGroup<'_, WallTime>, name: &str, value: T)
+where
+    T: Protobuf<Any>,
+    <T as TryFrom<Any>>::Error: Display,
+{
+    let bytes = Protobuf::<Any>::encode_vec(value.clone());
+
+    group.bench_function(format!("{name}/encode"), |b| {
+        b.iter(|| Protobuf::<Any>::encode_vec(black_box(value.clone())))
+    });
+
+    group.bench_function(format!("{name}/decode"), |b| {
+        b.iter(|| <T as Protobuf<Any>>::decode_vec(black_box(&bytes)).expect("valid encoding"))
+    });
+}
+
+fn bench_msg_envelope(group: &mut BenchmarkGroup<'_, WallTime>, name: &str, msg: Any) {
+    group.bench_function(format!("{name}/decode"), |b| {
+        b.iter(|| MsgEnvelope::try_from(black_box(msg.clone())).expect("valid message"))
+    });
+}
+
+fn bench_any_roundtrip(c: &mut Criterion) {
+    let height = Height::new(0, 10).expect("valid height");
+
+    let ctx = TestContextConfig::builder()
+        .latest_height(height)
+        .build::<TendermintContext>();
+
+    let header = TmHeader::from(ctx.host_block(&height).expect("block exists").into_header());
+    let client_state = dummy_tm_client_state_from_header(header.signed_header.header.clone());
+    let consensus_state = TmConsensusState::from(header.signed_header.header.clone());
+
+    let mut group = c.benchmark_group("any_roundtrip");
+
+    bench_roundtrip(
+        &mut group,
+        "tendermint/client_state",
+        client_state.inner().clone(),
+    );
+    bench_roundtrip(&mut group, "tendermint/consensus_state", consensus_state);
+    bench_roundtrip(&mut group, "tendermint/header", header.clone());
+
+    let update_client = MsgUpdateClient {
+        client_id: tm_client_type().build_client_id(0),
+        client_message: header.into(),
+        signer: dummy_account_id(),
+    };
+    bench_msg_envelope(
+        &mut group,
+        "msg_envelope/update_client",
+        update_client.to_any(),
+    );
+
+    let recv_packet =
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(10)).expect("valid message");
+    bench_msg_envelope(&mut group, "msg_envelope/recv_packet", recv_packet.to_any());
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_any_roundtrip);
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+//! Measures the construction of the events emitted along the packet lifecycle
+//! and their conversion into the ABCI events handed over to the host.
+
+use criterion::measurement::WallTime;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use ibc::apps::transfer::types::ack_success_b64;
+use ibc::apps::transfer::types::events::RecvEvent;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc::core::channel::types::channel::Order;
+use ibc::core::channel::types::events::{ReceivePacket, SendPacket, WriteAcknowledgement};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::primitives::Timestamp;
+use ibc::core::router::types::event::ModuleEvent;
+use ibc_testkit::fixtures::applications::transfer::PacketDataConfig;
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use tendermint::abci;
+
+fn bench_event(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    name: &str,
+    construct: impl Fn() -> IbcEvent,
+) {
+    group.bench_function(format!("{name}/construct"), |b| b.iter(&construct));
+
+    let event = construct();
+
+    group.bench_function(format!("{name}/to_abci"), |b| {
+        b.iter(|| abci::Event::try_from(black_box(event.clone())).expect("valid event"))
+    });
+}
+
+fn bench_packet_events(c: &mut Criterion) {
+    let packet_data: PacketData = PacketDataConfig::builder()
+        .token("1000uatom".parse().expect("valid coin"))
+        .build();
+
+    let packet: Packet = PacketConfig::builder()
+        .data(serde_json::to_vec(&packet_data).expect("valid packet data"))
+        .timeout_height_on_b(TimeoutHeight::At(
+            Height::new(0, 100).expect("valid height"),
+        ))
+        .timeout_timestamp_on_b(
+            Timestamp::from_nanoseconds(1_700_000_000_000_000_000).expect("valid timestamp"),
+        )
+        .build();
+    let ack = Acknowledgement::from(AcknowledgementStatus::success(ack_success_b64()));
+    let conn_id = ConnectionId::zero();
+
+    let mut group = c.benchmark_group("events");
+
+    bench_event(&mut group, "send_packet", || {
+        IbcEvent::SendPacket(SendPacket::new(
+            black_box(packet.clone()),
+            Order::Unordered,
+            conn_id.clone(),
+        ))
+    });
+
+    bench_event(&mut group, "receive_packet", || {
+        IbcEvent::ReceivePacket(ReceivePacket::new(
+            black_box(packet.clone()),
+            Order::Unordered,
+            conn_id.clone(),
+        ))
+    });
+
+    bench_event(&mut group, "write_acknowledgement", || {
+        IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+            black_box(packet.clone()),
+            ack.clone(),
+            conn_id.clone(),
+        ))
+    });
+
+    bench_event(&mut group, "ics20_recv", || {
+        let data = black_box(packet_data.clone());
+        IbcEvent::Module(ModuleEvent::from(RecvEvent {
+            sender: data.sender,
+            receiver: data.receiver,
+            denom: data.token.denom,
+            amount: data.token.amount,
+            memo: data.memo,
+            success: true,
+        }))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_packet_events);
+criterion_main!(benches);
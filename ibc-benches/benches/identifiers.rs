@@ -0,0 +1,53 @@
+//! Measures the creation, parsing and cloning of the identifiers that handlers
+//! build their store paths and events from.
+
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+
+fn bench_identifiers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("identifiers");
+
+    group.bench_function("client_id/new", |b| {
+        b.iter(|| ClientId::new(black_box("07-tendermint"), black_box(42)).expect("valid id"))
+    });
+    group.bench_function("connection_id/new", |b| {
+        b.iter(|| ConnectionId::new(black_box(42)))
+    });
+    group.bench_function("channel_id/new", |b| {
+        b.iter(|| ChannelId::new(black_box(42)))
+    });
+
+    group.bench_function("channel_id/from_str", |b| {
+        b.iter(|| ChannelId::from_str(black_box("channel-42")).expect("valid id"))
+    });
+    group.bench_function("port_id/from_str", |b| {
+        b.iter(|| PortId::from_str(black_box("transfer")).expect("valid id"))
+    });
+
+    let client_id = ClientId::new("07-tendermint", 42).expect("valid id");
+    let channel_id = ChannelId::new(42);
+    let port_id = PortId::transfer();
+    // Longer identifiers, such as the ICA controller ports, are kept on the
+    // heap.
+    let long_port_id =
+        PortId::from_str("icacontroller-cosmos1m9l358xunhhwds0568za49mzhvuxx9uxre5tud")
+            .expect("valid id");
+
+    group.bench_function("client_id/clone", |b| {
+        b.iter(|| black_box(&client_id).clone())
+    });
+    group.bench_function("channel_id/clone", |b| {
+        b.iter(|| black_box(&channel_id).clone())
+    });
+    group.bench_function("port_id/clone", |b| b.iter(|| black_box(&port_id).clone()));
+    group.bench_function("port_id/clone_long", |b| {
+        b.iter(|| black_box(&long_port_id).clone())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_identifiers);
+criterion_main!(benches);
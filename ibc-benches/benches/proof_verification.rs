@@ -0,0 +1,80 @@
+//! Measures the ICS-23 membership proof verification of a Tendermint client,
+//! which every packet, acknowledgement and handshake message goes through.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{ClientConsensusStatePath, NextClientSequencePath, Path};
+use ibc::core::host::ValidationContext;
+use ibc_query::core::context::ProvableContext;
+use ibc_testkit::context::{MockContext, TendermintContext};
+use ibc_testkit::fixtures::core::context::TestContextConfig;
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+use ibc_testkit::testapp::ibc::core::types::LightClientBuilder;
+
+fn bench_tendermint_membership(c: &mut Criterion) {
+    let client_id = ClientId::new("07-tendermint", 0).expect("valid client id");
+    let client_height = Height::new(0, 10).expect("valid height");
+
+    let ctx_tm = TestContextConfig::builder()
+        .latest_height(client_height)
+        .build::<TendermintContext>();
+
+    let ctx_mk = MockContext::default().with_light_client(
+        &client_id,
+        LightClientBuilder::init().context(&ctx_tm).build(),
+    );
+
+    let client_ctx = ctx_mk.ibc_store().get_client_validation_context();
+
+    let AnyClientState::Tendermint(client_state) = client_ctx
+        .client_state(&client_id)
+        .expect("client state exists")
+    else {
+        panic!("not a tendermint client state")
+    };
+
+    let latest_height = client_state.latest_height();
+    let AnyConsensusState::Tendermint(consensus_state) = client_ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id,
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ))
+        .expect("consensus state exists")
+    else {
+        panic!("not a tendermint consensus state")
+    };
+
+    let prefix = ctx_tm.ibc_store().commitment_prefix();
+    let root = consensus_state.inner().root();
+    let path: Path = NextClientSequencePath.into();
+    let value = serde_json::to_vec(&0u64).expect("valid json serialization");
+
+    let proof: CommitmentProofBytes = ctx_tm
+        .ibc_store()
+        .get_proof(ctx_tm.latest_height(), &path)
+        .expect("proof exists")
+        .try_into()
+        .expect("valid merkle proof");
+
+    c.bench_function("proof_verification/tendermint/membership", |b| {
+        b.iter(|| {
+            client_state
+                .verify_membership(
+                    &prefix,
+                    black_box(&proof),
+                    &root,
+                    path.clone(),
+                    value.clone(),
+                )
+                .expect("valid proof")
+        })
+    });
+}
+
+criterion_group!(benches, bench_tendermint_membership);
+criterion_main!(benches);
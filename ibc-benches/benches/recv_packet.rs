@@ -0,0 +1,208 @@
+//! Measures the receiving side of an ICS-20 transfer: decoding the JSON packet
+//! data, the `onRecvPacket` callback of the transfer application, the core
+//! `RecvPacket` handler, and the throughput of dispatching batches of packets.
+
+use std::time::{Duration, Instant};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ibc::apps::transfer::module::on_recv_packet_execute;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::primitives::{Timestamp, ZERO_DURATION};
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::applications::transfer::PacketDataConfig;
+use ibc_testkit::fixtures::core::channel::{dummy_raw_msg_recv_packet, PacketConfig};
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+
+fn transfer_packet_data() -> Vec<u8> {
+    let packet_data: PacketData = PacketDataConfig::builder()
+        .token(
+            "1000transfer/channel-7/transfer/channel-2/uatom"
+                .parse()
+                .expect("valid coin"),
+        )
+        .memo(
+            r#"{"forward":{"receiver":"cosmos1forward","port":"transfer","channel":"channel-3"}}"#
+                .into(),
+        )
+        .build();
+
+    serde_json::to_vec(&packet_data).expect("valid packet data")
+}
+
+fn recv_packet_fixture(data: Vec<u8>) -> (MockContext, MsgRecvPacket) {
+    let client_id = ClientId::new("07-tendermint", 0).expect("valid client id");
+
+    let context = MockContext::default();
+    let host_height = context.latest_height().increment();
+    let client_height = host_height.increment();
+
+    let mut msg =
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(client_height.revision_height()))
+            .expect("valid message");
+    msg.packet.data = data;
+    // The timeout height is enough; a wall-clock timeout would expire while
+    // the benchmark runs.
+    msg.packet.timeout_timestamp_on_b = Timestamp::none();
+
+    let packet = &msg.packet;
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .expect("valid channel end");
+
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("valid prefix"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .expect("valid connection end");
+
+    let context = context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .advance_block_up_to_height(host_height);
+
+    (context, msg)
+}
+
+fn bench_ics20_recv_packet(c: &mut Criterion) {
+    let mut group = c.benchmark_group("recv_packet/ics20");
+
+    let data = transfer_packet_data();
+
+    group.bench_function("decode_packet_data", |b| {
+        b.iter(|| {
+            serde_json::from_slice::<PacketData>(black_box(&data)).expect("valid packet data")
+        })
+    });
+
+    let packet: Packet = PacketConfig::builder()
+        .port_id_on_a(PortId::transfer())
+        .chan_id_on_a(ChannelId::new(7))
+        .data(data)
+        .build();
+    let mut ctx = DummyTransferModule;
+
+    group.bench_function("on_recv_packet_execute", |b| {
+        b.iter(|| {
+            let (_, result) = on_recv_packet_execute(&mut ctx, black_box(&packet));
+            assert!(result.is_successful());
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_core_recv_packet(c: &mut Criterion) {
+    let mut group = c.benchmark_group("recv_packet/core");
+
+    let (mut ctx, msg) = recv_packet_fixture(transfer_packet_data());
+    let msg = MsgEnvelope::from(PacketMsg::from(msg));
+
+    group.bench_function("validate", |b| {
+        b.iter(|| ctx.validate(black_box(msg.clone())).expect("valid packet"))
+    });
+
+    // Execution stores the packet receipt and acknowledgement, so the context
+    // is reverted before every iteration, outside of the measured time.
+    let snapshot = ctx.snapshot();
+
+    group.bench_function("execute", |b| {
+        b.iter_custom(|iters| {
+            let mut elapsed = Duration::ZERO;
+            for _ in 0..iters {
+                ctx.restore(&snapshot);
+                let msg = msg.clone();
+                let start = Instant::now();
+                ctx.execute(black_box(msg)).expect("valid packet");
+                elapsed += start.elapsed();
+            }
+            elapsed
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_dispatch_throughput(c: &mut Criterion) {
+    const BATCH_SIZE: u64 = 64;
+
+    let mut group = c.benchmark_group("recv_packet/dispatch");
+    group.throughput(Throughput::Elements(BATCH_SIZE));
+
+    let (mut ctx, msg) = recv_packet_fixture(transfer_packet_data());
+
+    // Packets of distinct sequences, as a relayer would submit them in a
+    // single transaction.
+    let msgs: Vec<MsgEnvelope> = (1..=BATCH_SIZE)
+        .map(|seq| {
+            let mut msg = msg.clone();
+            msg.packet.seq_on_a = Sequence::from(seq);
+            MsgEnvelope::from(PacketMsg::from(msg))
+        })
+        .collect();
+
+    let snapshot = ctx.snapshot();
+
+    group.bench_function("batch", |b| {
+        b.iter_custom(|iters| {
+            let mut elapsed = Duration::ZERO;
+            for _ in 0..iters {
+                ctx.restore(&snapshot);
+                let msgs = msgs.clone();
+                let start = Instant::now();
+                for msg in msgs {
+                    ctx.dispatch(black_box(msg)).expect("valid packet");
+                }
+                elapsed += start.elapsed();
+            }
+            elapsed
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_ics20_recv_packet,
+    bench_core_recv_packet,
+    bench_dispatch_throughput
+);
+criterion_main!(benches);
@@ -0,0 +1,94 @@
+//! Measures the `UpdateClient` handler with an adjacent Tendermint header,
+//! which every relayed packet batch pays for on the receiving chain.
+
+use std::time::{Duration, Instant};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::ChainId;
+use ibc_testkit::context::{MockContext, TendermintContext};
+use ibc_testkit::fixtures::core::context::TestContextConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::{MockHost, TendermintHost, TestBlock, TestHeader};
+use ibc_testkit::testapp::ibc::core::types::LightClientBuilder;
+
+fn update_client_fixture() -> (MockContext, MsgEnvelope) {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).expect("valid height");
+    let update_height = Height::new(1, 21).expect("valid height");
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(ChainId::new("mockgaiaB-1").expect("valid chain id"))
+                .build(),
+        )
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").expect("valid chain id"))
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).expect("valid height"))
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let mut header = ctx_b
+        .host_block(&update_height)
+        .expect("block exists")
+        .into_header();
+    header.set_trusted_height(client_height);
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: header.into(),
+        signer: dummy_account_id(),
+    };
+
+    (ctx, MsgEnvelope::from(ClientMsg::from(msg)))
+}
+
+fn bench_update_client(c: &mut Criterion) {
+    let mut group = c.benchmark_group("update_client");
+
+    let (mut ctx, msg) = update_client_fixture();
+
+    group.bench_function("tendermint/validate", |b| {
+        b.iter(|| ctx.validate(black_box(msg.clone())).expect("valid update"))
+    });
+
+    // Execution stores the new consensus state, so the context is reverted
+    // before every iteration, outside of the measured time.
+    let snapshot = ctx.snapshot();
+
+    group.bench_function("tendermint/execute", |b| {
+        b.iter_custom(|iters| {
+            let mut elapsed = Duration::ZERO;
+            for _ in 0..iters {
+                ctx.restore(&snapshot);
+                let msg = msg.clone();
+                let start = Instant::now();
+                ctx.execute(black_box(msg)).expect("valid update");
+                elapsed += start.elapsed();
+            }
+            elapsed
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_update_client);
+criterion_main!(benches);
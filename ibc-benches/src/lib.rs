@@ -0,0 +1,6 @@
+//! Criterion benchmarks of the `ibc-rs` hot paths.
+//!
+//! The benchmarks live under the `benches` directory; see the crate's README
+//! for how to run them and to compare them against a saved baseline.
+#![no_std]
+#![forbid(unsafe_code)]
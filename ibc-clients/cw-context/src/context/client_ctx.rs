@@ -4,7 +4,7 @@ use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
 use ibc_client_wasm_types::consensus_state::ConsensusState as WasmConsensusState;
 use ibc_core::client::context::{ClientExecutionContext, ClientValidationContext};
 use ibc_core::client::types::error::ClientError;
-use ibc_core::client::types::Height;
+use ibc_core::client::types::{Height, UpdateMeta};
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::ClientId;
 use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
@@ -55,7 +55,7 @@ where
         &self,
         _client_id: &ClientId,
         height: &Height,
-    ) -> Result<(Timestamp, Height), ContextError> {
+    ) -> Result<UpdateMeta, ContextError> {
         let time_key = self.client_update_time_key(height);
 
         let time_vec = self.retrieve(time_key)?;
@@ -83,7 +83,7 @@ where
 
         let height = Height::new(0, revision_height)?;
 
-        Ok((timestamp, height))
+        Ok(UpdateMeta::new(timestamp, height))
     }
 }
 
@@ -144,14 +144,13 @@ where
         &mut self,
         _client_id: ClientId,
         height: Height,
-        host_timestamp: Timestamp,
-        host_height: Height,
+        update_meta: UpdateMeta,
     ) -> Result<(), ContextError> {
         let time_key = self.client_update_time_key(&height);
 
         let prefixed_time_key = self.prefixed_key(time_key);
 
-        let time_vec = host_timestamp.nanoseconds().to_be_bytes();
+        let time_vec = update_meta.processed_time.nanoseconds().to_be_bytes();
 
         self.insert(prefixed_time_key, time_vec);
 
@@ -159,7 +158,7 @@ where
 
         let prefixed_height_key = self.prefixed_key(height_key);
 
-        let revision_height_vec = host_height.revision_height().to_be_bytes();
+        let revision_height_vec = update_meta.processed_height.revision_height().to_be_bytes();
 
         self.insert(prefixed_height_key, revision_height_vec);
 
@@ -8,6 +8,7 @@
 //! `ibc-go` that supports the `08-wasm` proxy light client.
 
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
 #![deny(
     warnings,
     trivial_casts,
@@ -21,5 +22,6 @@
 pub mod api;
 pub mod context;
 pub mod handlers;
+mod macros;
 pub mod types;
 pub mod utils;
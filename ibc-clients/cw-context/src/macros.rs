@@ -0,0 +1,50 @@
+/// Generates the `instantiate`, `sudo` and `query` entry points of an 08-wasm
+/// light client contract, wiring them to the generic [`Context`] handlers for
+/// the given [`ClientType`].
+///
+/// The calling crate must depend on `cosmwasm-std`, as the generated functions
+/// are annotated with its `entry_point` attribute.
+///
+/// ```ignore
+/// ibc_client_cw::generate_entrypoints!(TendermintClient);
+/// ```
+///
+/// [`Context`]: crate::context::Context
+/// [`ClientType`]: crate::api::ClientType
+#[macro_export]
+macro_rules! generate_entrypoints {
+    ($client_type:ty) => {
+        #[::cosmwasm_std::entry_point]
+        pub fn instantiate(
+            deps: ::cosmwasm_std::DepsMut<'_>,
+            env: ::cosmwasm_std::Env,
+            _info: ::cosmwasm_std::MessageInfo,
+            msg: $crate::types::InstantiateMsg,
+        ) -> Result<::cosmwasm_std::Response, $crate::types::ContractError> {
+            let mut ctx = $crate::context::Context::<'_, $client_type>::new_mut(deps, env)?;
+            let data = ctx.instantiate(msg)?;
+            Ok(::cosmwasm_std::Response::default().set_data(data))
+        }
+
+        #[::cosmwasm_std::entry_point]
+        pub fn sudo(
+            deps: ::cosmwasm_std::DepsMut<'_>,
+            env: ::cosmwasm_std::Env,
+            msg: $crate::types::SudoMsg,
+        ) -> Result<::cosmwasm_std::Response, $crate::types::ContractError> {
+            let mut ctx = $crate::context::Context::<'_, $client_type>::new_mut(deps, env)?;
+            let data = ctx.sudo(msg)?;
+            Ok(::cosmwasm_std::Response::default().set_data(data))
+        }
+
+        #[::cosmwasm_std::entry_point]
+        pub fn query(
+            deps: ::cosmwasm_std::Deps<'_>,
+            env: ::cosmwasm_std::Env,
+            msg: $crate::types::QueryMsg,
+        ) -> Result<::cosmwasm_std::Binary, $crate::types::ContractError> {
+            let ctx = $crate::context::Context::<'_, $client_type>::new_ref(deps, env)?;
+            ctx.query(msg)
+        }
+    };
+}
@@ -0,0 +1,47 @@
+//! Measures how much the verified data cache saves when the same validator
+//! set is checked by several `UpdateClient` messages within a block.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ibc_client_tendermint::client_state::cached_validator_set_hash;
+use ibc_core_client::context::cache::LruVerifiedDataCache;
+use tendermint::crypto::default::Sha256;
+use tendermint::validator::Set as ValidatorSet;
+use tendermint_testgen::{Generator, Validator};
+
+fn validator_set(size: usize) -> ValidatorSet {
+    let validators = (0..size)
+        .map(|i| {
+            Validator::new(&format!("validator-{i}"))
+                .voting_power(50)
+                .generate()
+                .expect("valid validator")
+        })
+        .collect();
+
+    ValidatorSet::without_proposer(validators)
+}
+
+fn bench_validator_set_hash(c: &mut Criterion) {
+    let mut group = c.benchmark_group("validator_set_hash");
+
+    for size in [50, 150, 300] {
+        let set = validator_set(size);
+        let expected = set.hash_with::<Sha256>();
+
+        group.bench_with_input(BenchmarkId::new("uncached", size), &set, |b, set| {
+            b.iter(|| cached_validator_set_hash::<Sha256>(black_box(set), &expected, None))
+        });
+
+        let cache = LruVerifiedDataCache::new(16);
+        cached_validator_set_hash::<Sha256>(&set, &expected, Some(&cache));
+
+        group.bench_with_input(BenchmarkId::new("cached", size), &set, |b, set| {
+            b.iter(|| cached_validator_set_hash::<Sha256>(black_box(set), &expected, Some(&cache)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_validator_set_hash);
+criterion_main!(benches);
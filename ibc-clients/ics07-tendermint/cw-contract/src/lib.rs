@@ -1,6 +1,7 @@
 //! The CosmWasm contract implementation of the ICS-07 Tendermint light client
 //! built using `ibc-rs`.
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
 #![deny(
     warnings,
     trivial_casts,
@@ -1,4 +1,7 @@
-use ibc_client_tendermint_types::{client_type as tm_client_type, ClientState as ClientStateType};
+use ibc_client_tendermint_types::{
+    client_type as tm_client_type, ClientState as ClientStateType, Header as TmHeader,
+    Misbehaviour as TmMisbehaviour, TENDERMINT_HEADER_TYPE_URL, TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+};
 use ibc_core_client::context::client_state::ClientStateCommon;
 use ibc_core_client::context::consensus_state::ConsensusState;
 use ibc_core_client::types::error::{ClientError, UpgradeClientError};
@@ -6,14 +9,16 @@ use ibc_core_client::types::Height;
 use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
-use ibc_core_commitment_types::merkle::{apply_prefix, MerkleProof};
+use ibc_core_commitment_types::merkle::{try_apply_prefix, DecodedProof};
 use ibc_core_commitment_types::proto::ics23::{HostFunctionsManager, HostFunctionsProvider};
+use ibc_core_commitment_types::proto::v1::MerklePath;
 use ibc_core_commitment_types::specs::ProofSpecs;
 use ibc_core_host::types::identifiers::ClientType;
 use ibc_core_host::types::path::{Path, UpgradeClientPath};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
-use ibc_primitives::ToVec;
+use ibc_primitives::{CommitmentHasher, ToVec};
+use tendermint::block::CommitSig;
 
 use super::ClientState;
 use crate::consensus_state::ConsensusState as TmConsensusState;
@@ -35,6 +40,13 @@ impl ClientStateCommon for ClientState {
         validate_proof_height(self.inner(), proof_height)
     }
 
+    fn commitment_hasher(&self) -> Result<CommitmentHasher, ClientError> {
+        self.inner()
+            .proof_specs
+            .commitment_hasher()
+            .map_err(ClientError::Ics23Verification)
+    }
+
     fn verify_upgrade_client(
         &self,
         upgraded_client_state: Any,
@@ -61,6 +73,8 @@ impl ClientStateCommon for ClientState {
         path: Path,
         value: Vec<u8>,
     ) -> Result<(), ClientError> {
+        self.inner().frozen_height.verify_not_frozen()?;
+
         verify_membership::<HostFunctionsManager>(
             &self.inner().proof_specs,
             prefix,
@@ -78,6 +92,8 @@ impl ClientStateCommon for ClientState {
         root: &CommitmentRoot,
         path: Path,
     ) -> Result<(), ClientError> {
+        self.inner().frozen_height.verify_not_frozen()?;
+
         verify_non_membership::<HostFunctionsManager>(
             &self.inner().proof_specs,
             prefix,
@@ -86,6 +102,100 @@ impl ClientStateCommon for ClientState {
             path,
         )
     }
+
+    fn verify_merkle_membership(
+        &self,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        merkle_path: MerklePath,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.inner().frozen_height.verify_not_frozen()?;
+
+        verify_merkle_membership::<HostFunctionsManager>(
+            &self.inner().proof_specs,
+            proof,
+            root,
+            merkle_path,
+            value,
+        )
+    }
+
+    fn verify_merkle_non_membership(
+        &self,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        merkle_path: MerklePath,
+    ) -> Result<(), ClientError> {
+        self.inner().frozen_height.verify_not_frozen()?;
+
+        verify_merkle_non_membership::<HostFunctionsManager>(
+            &self.inner().proof_specs,
+            proof,
+            root,
+            merkle_path,
+        )
+    }
+
+    fn verify_membership_decoded(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &DecodedProof<'_>,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.inner().frozen_height.verify_not_frozen()?;
+
+        verify_membership_decoded::<HostFunctionsManager>(
+            &self.inner().proof_specs,
+            prefix,
+            proof,
+            root,
+            path,
+            value,
+        )
+    }
+
+    fn verify_non_membership_decoded(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &DecodedProof<'_>,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        self.inner().frozen_height.verify_not_frozen()?;
+
+        verify_non_membership_decoded::<HostFunctionsManager>(
+            &self.inner().proof_specs,
+            prefix,
+            proof,
+            root,
+            path,
+        )
+    }
+
+    fn verify_batch_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        items: Vec<(Path, Vec<u8>)>,
+    ) -> Result<(), ClientError> {
+        self.inner().frozen_height.verify_not_frozen()?;
+
+        verify_batch_membership::<HostFunctionsManager>(
+            &self.inner().proof_specs,
+            prefix,
+            proof,
+            root,
+            items,
+        )
+    }
+
+    fn client_message_signature_count(&self, client_message: &Any) -> Result<usize, ClientError> {
+        client_message_signature_count(client_message)
+    }
 }
 
 /// Verify an `Any` consensus state by attempting to convert it to a `TmConsensusState`.
@@ -106,6 +216,39 @@ pub fn verify_consensus_state(consensus_state: Any) -> Result<(), ClientError> {
     Ok(())
 }
 
+/// Counts the signatures of the commits carried by a header or a misbehaviour,
+/// i.e. the signatures checked when verifying it.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn client_message_signature_count(client_message: &Any) -> Result<usize, ClientError> {
+    let commit_signatures = |header: &TmHeader| {
+        header
+            .signed_header
+            .commit
+            .signatures
+            .iter()
+            .filter(|signature| !matches!(signature, CommitSig::BlockIdFlagAbsent))
+            .count()
+    };
+
+    match client_message.type_url.as_str() {
+        TENDERMINT_HEADER_TYPE_URL => {
+            let header = TmHeader::try_from(client_message.clone())?;
+
+            Ok(commit_signatures(&header))
+        }
+        TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = TmMisbehaviour::try_from(client_message.clone())?;
+
+            Ok(commit_signatures(misbehaviour.header1())
+                + commit_signatures(misbehaviour.header2()))
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
 /// Validate the given proof height against the client state's latest height, returning
 /// an error if the proof height is greater than the latest height of the client state.
 ///
@@ -147,6 +290,9 @@ pub fn verify_upgrade_client<H: HostFunctionsProvider>(
     proof_upgrade_consensus_state: CommitmentProofBytes,
     root: &CommitmentRoot,
 ) -> Result<(), ClientError> {
+    // Frozen clients must be recovered before they can be upgraded
+    client_state.frozen_height.verify_not_frozen()?;
+
     // Make sure that the client type is of Tendermint type `ClientState`
     let upgraded_tm_client_state = ClientState::try_from(upgraded_client_state.clone())?;
 
@@ -216,11 +362,77 @@ pub fn verify_membership<H: HostFunctionsProvider>(
     path: Path,
     value: Vec<u8>,
 ) -> Result<(), ClientError> {
-    let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
-    let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+    let proof = DecodedProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
 
-    merkle_proof
-        .verify_membership::<H>(proof_specs, root.clone().into(), merkle_path, value, 0)
+    verify_membership_decoded::<H>(proof_specs, prefix, &proof, root, path, value)
+}
+
+/// Verify membership of the given value at a raw merkle path, whose first key
+/// is the commitment prefix.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_merkle_membership<H: HostFunctionsProvider>(
+    proof_specs: &ProofSpecs,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    merkle_path: MerklePath,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    let proof = DecodedProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+
+    proof
+        .verify_membership::<H>(proof_specs, root.clone().into(), merkle_path, value)
+        .map_err(ClientError::Ics23Verification)
+}
+
+/// Verify membership of the given value against an already decoded merkle
+/// proof.
+///
+/// Allows verifying the same proof against several paths without decoding it
+/// each time, e.g. when verifying a batch of values.
+pub fn verify_membership_decoded<H: HostFunctionsProvider>(
+    proof_specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &DecodedProof<'_>,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    let merkle_path = try_apply_prefix(prefix, [path.to_string()])
+        .map_err(ClientError::InvalidCommitmentProof)?;
+
+    proof
+        .verify_membership::<H>(proof_specs, root.clone().into(), merkle_path, value)
+        .map_err(ClientError::Ics23Verification)
+}
+
+/// Verify membership of several values against a single merkle proof, whose
+/// innermost proof is an ICS-23 batch proof of all the values.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_batch_membership<H: HostFunctionsProvider>(
+    proof_specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    items: Vec<(Path, Vec<u8>)>,
+) -> Result<(), ClientError> {
+    let proof = DecodedProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+
+    let items = items
+        .into_iter()
+        .map(|(path, value)| {
+            try_apply_prefix(prefix, [path.to_string()]).map(|merkle_path| (merkle_path, value))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ClientError::InvalidCommitmentProof)?;
+
+    proof
+        .verify_batch_membership::<H>(proof_specs, root.clone().into(), items)
         .map_err(ClientError::Ics23Verification)
 }
 
@@ -236,10 +448,43 @@ pub fn verify_non_membership<H: HostFunctionsProvider>(
     root: &CommitmentRoot,
     path: Path,
 ) -> Result<(), ClientError> {
-    let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
-    let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+    let proof = DecodedProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+
+    verify_non_membership_decoded::<H>(proof_specs, prefix, &proof, root, path)
+}
+
+/// Verify that the given raw merkle path, whose first key is the commitment
+/// prefix, has no value.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_merkle_non_membership<H: HostFunctionsProvider>(
+    proof_specs: &ProofSpecs,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    merkle_path: MerklePath,
+) -> Result<(), ClientError> {
+    let proof = DecodedProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+
+    proof
+        .verify_non_membership::<H>(proof_specs, root.clone().into(), merkle_path)
+        .map_err(ClientError::Ics23Verification)
+}
+
+/// Verify that the given path has no value against an already decoded merkle
+/// proof.
+pub fn verify_non_membership_decoded<H: HostFunctionsProvider>(
+    proof_specs: &ProofSpecs,
+    prefix: &CommitmentPrefix,
+    proof: &DecodedProof<'_>,
+    root: &CommitmentRoot,
+    path: Path,
+) -> Result<(), ClientError> {
+    let merkle_path = try_apply_prefix(prefix, [path.to_string()])
+        .map_err(ClientError::InvalidCommitmentProof)?;
 
-    merkle_proof
+    proof
         .verify_non_membership::<H>(proof_specs, root.clone().into(), merkle_path)
         .map_err(ClientError::Ics23Verification)
 }
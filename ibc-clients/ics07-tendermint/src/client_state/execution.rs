@@ -1,15 +1,24 @@
+use core::time::Duration;
+
+use ibc_client_tendermint_types::error::{Error, IntoResult};
 use ibc_client_tendermint_types::{
     ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
+    TrustParamsUpdate,
 };
 use ibc_core_client::context::prelude::*;
 use ibc_core_client::types::error::ClientError;
-use ibc_core_client::types::Height;
+use ibc_core_client::types::{FrozenHeight, Height, UpdateMeta};
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
+use ibc_primitives::Signer;
+use tendermint::crypto::Sha256;
+use tendermint::merkle::MerkleHash;
+use tendermint_light_client_verifier::types::TrustedBlockState;
+use tendermint_light_client_verifier::Verifier;
 
-use super::ClientState;
+use super::{status, ClientState};
 
 impl<E> ClientStateExecution<E> for ClientState
 where
@@ -117,8 +126,7 @@ where
     ctx.store_update_meta(
         client_id.clone(),
         client_state.latest_height,
-        host_timestamp,
-        host_height,
+        UpdateMeta::new(host_timestamp, host_height),
     )?;
 
     Ok(())
@@ -143,7 +151,7 @@ where
     <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
 {
     let header = TmHeader::try_from(header)?;
-    let header_height = header.height();
+    let header_height = header.height()?;
 
     prune_oldest_consensus_state(client_state, ctx, client_id)?;
 
@@ -184,8 +192,7 @@ where
         ctx.store_update_meta(
             client_id.clone(),
             header_height,
-            host_timestamp,
-            host_height,
+            UpdateMeta::new(host_timestamp, host_height),
         )?;
     }
 
@@ -208,12 +215,13 @@ where
     E: ExtClientExecutionContext,
     E::ClientStateRef: From<ClientStateType>,
 {
-    // NOTE: frozen height is  set to `Height {revision_height: 0,
-    // revision_number: 1}` and it is the same for all misbehaviour. This
-    // aligns with the
+    // NOTE: frozen height is set to the `FrozenHeight::SENTINEL_HEIGHT`,
+    // i.e. `Height {revision_number: 0, revision_height: 1}`, and it is the
+    // same for all misbehaviour. This aligns with the
     // [`ibc-go`](https://github.com/cosmos/ibc-go/blob/0e3f428e66d6fc0fc6b10d2f3c658aaa5000daf7/modules/light-clients/07-tendermint/misbehaviour.go#L18-L19)
     // implementation.
-    let frozen_client_state = client_state.clone().with_frozen_height(Height::min(0));
+    let mut frozen_client_state = client_state.clone();
+    frozen_client_state.freeze(FrozenHeight::SENTINEL_HEIGHT);
 
     ctx.store_client_state(
         ClientStatePath::new(client_id.clone()),
@@ -301,8 +309,7 @@ where
     ctx.store_update_meta(
         client_id.clone(),
         latest_height,
-        host_timestamp,
-        host_height,
+        UpdateMeta::new(host_timestamp, host_height),
     )?;
 
     Ok(latest_height)
@@ -358,6 +365,7 @@ where
 
         ctx.delete_consensus_state(client_consensus_state_path)?;
         ctx.delete_update_meta(client_id.clone(), height)?;
+        ctx.unindex_consensus_state_processed_time(client_id, &height)?;
     }
 
     Ok(())
@@ -394,13 +402,13 @@ where
     let trusting_period = substitute_client_state.trusting_period;
     let latest_height = substitute_client_state.latest_height;
 
-    let new_client_state = ClientStateType {
+    let mut new_client_state = ClientStateType {
         chain_id,
         trusting_period,
         latest_height,
-        frozen_height: None,
         ..subject_client_state
     };
+    new_client_state.unfreeze();
 
     let host_timestamp = E::host_timestamp(ctx)?;
     let host_height = E::host_height(ctx)?;
@@ -424,9 +432,197 @@ where
     ctx.store_update_meta(
         subject_client_id.clone(),
         latest_height,
-        host_timestamp,
-        host_height,
+        UpdateMeta::new(host_timestamp, host_height),
+    )?;
+
+    Ok(())
+}
+
+/// Overrides the trust parameters of an active client, e.g. in response to a
+/// governance proposal. It is therefore only allowed to the recovery authority
+/// of the host, as checked by
+/// [`ExtClientValidationContext::validate_client_recovery_authority`].
+///
+/// Consensus states that already fell out of the current trusting period are
+/// pruned before the new parameters take effect, so that lengthening the
+/// trusting period cannot retroactively make stale consensus states usable as
+/// trusted states for new headers. Expired or frozen clients must be restored
+/// through client recovery instead.
+pub fn update_trust_params<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    params: TrustParamsUpdate,
+    signer: &Signer,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    ctx.validate_client_recovery_authority(signer)?;
+
+    status(client_state, ctx, client_id)?.verify_is_active()?;
+
+    if params.extends_trusting_period(client_state) {
+        prune_oldest_consensus_state(client_state, ctx, client_id)?;
+    }
+
+    let new_client_state = client_state.clone().with_trust_params(params)?;
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        new_client_state.into(),
+    )?;
+
+    Ok(())
+}
+
+/// Resets the client to the given header, installing its consensus state
+/// without verifying it against any trusted state, and unfreezing the client.
+///
+/// This is the last resort recovery path for clients that can no longer be
+/// updated, e.g. when more than two thirds of the voting power of the
+/// counterparty changed at once, and no substitute client is available. It is
+/// therefore only allowed to the recovery authority of the host, as checked
+/// by [`ExtClientValidationContext::validate_client_recovery_authority`].
+///
+/// The header must still be consistent with its validator set, belong to the
+/// chain of the client, be higher than its latest height, and be signed by
+/// more than two thirds of the voting power of its own validator set, within
+/// the trusting period of the client.
+pub fn reset_to_header<E, H>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    header: TmHeader,
+    signer: &Signer,
+    verifier: &impl Verifier,
+) -> Result<Height, ClientError>
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    ConsensusStateType: Convertible<E::ConsensusStateRef>,
+    H: MerkleHash + Sha256 + Default,
+{
+    ctx.validate_client_recovery_authority(signer)?;
+
+    let validators_hash = header.validator_set.hash_with::<H>();
+    if validators_hash != header.signed_header.header.validators_hash {
+        return Err(Error::MismatchValidatorsHashes {
+            signed_header_validators_hash: header.signed_header.header.validators_hash,
+            validators_hash,
+        }
+        .into());
+    }
+
+    if header.signed_header.header.chain_id.as_str() != client_state.chain_id.as_str() {
+        return Err(Error::MismatchHeaderChainId {
+            given: header.signed_header.header.chain_id.to_string(),
+            expected: client_state.chain_id.to_string(),
+        }
+        .into());
+    }
+    header.verify_chain_id_version_matches_height(&client_state.chain_id)?;
+
+    let header_height = header.height()?;
+    if header_height <= client_state.latest_height {
+        return Err(Error::InvalidHeaderHeight {
+            height: header_height.revision_height(),
+        }
+        .into());
+    }
+
+    verify_self_signed_header(client_state, ctx, &header, verifier)?;
+
+    let mut new_client_state = client_state.clone();
+    new_client_state.latest_height = header_height;
+    new_client_state.unfreeze();
+
+    let host_timestamp = E::host_timestamp(ctx)?;
+    let host_height = E::host_height(ctx)?;
+
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            header_height.revision_number(),
+            header_height.revision_height(),
+        ),
+        ConsensusStateType::from(header).into(),
+    )?;
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        new_client_state.into(),
     )?;
+    ctx.store_update_meta(
+        client_id.clone(),
+        header_height,
+        UpdateMeta::new(host_timestamp, host_height),
+    )?;
+
+    Ok(header_height)
+}
+
+/// Verifies that the header is signed by more than two thirds of the voting
+/// power of its own validator set, which is otherwise not trusted.
+///
+/// The header is verified as adjacent to a state trusting its validator set,
+/// for which the light client checks reduce to verifying the commit against
+/// that set, along with the header being neither from the future nor past
+/// the trusting period of the client.
+fn verify_self_signed_header<E>(
+    client_state: &ClientStateType,
+    ctx: &E,
+    header: &TmHeader,
+    verifier: &impl Verifier,
+) -> Result<(), ClientError>
+where
+    E: ExtClientValidationContext,
+{
+    let signed_header = &header.signed_header.header;
+
+    let header_time = signed_header
+        .time
+        .checked_sub(Duration::from_nanos(1))
+        .ok_or_else(|| ClientError::ClientSpecific {
+            description: "header time is not a valid TM timestamp".to_string(),
+        })?;
+    let height = signed_header
+        .height
+        .value()
+        .checked_sub(1)
+        .and_then(|height| height.try_into().ok())
+        .ok_or_else(|| ClientError::ClientSpecific {
+            description: Error::InvalidHeaderHeight {
+                height: signed_header.height.value(),
+            }
+            .to_string(),
+        })?;
+
+    let trusted_state = TrustedBlockState {
+        chain_id: &signed_header.chain_id,
+        header_time,
+        height,
+        next_validators: &header.validator_set,
+        next_validators_hash: signed_header.validators_hash,
+    };
+
+    let now = ctx
+        .host_timestamp()?
+        .into_tm_time()
+        .ok_or_else(|| ClientError::ClientSpecific {
+            description: "host timestamp is not a valid TM timestamp".to_string(),
+        })?;
+
+    verifier
+        .verify_update_header(
+            header.as_untrusted_block_state(),
+            trusted_state,
+            &client_state.as_light_client_options()?,
+            now,
+        )
+        .into_result()?;
 
     Ok(())
 }
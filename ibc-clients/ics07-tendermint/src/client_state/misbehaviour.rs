@@ -144,7 +144,7 @@ pub fn check_for_misbehaviour_on_misbehavior(
     header_1: &Header,
     header_2: &Header,
 ) -> Result<bool, ClientError> {
-    if header_1.height() == header_2.height() {
+    if header_1.height()? == header_2.height()? {
         // when the height of the 2 headers are equal, we only have evidence
         // of misbehaviour in the case where the headers are different
         // (otherwise, the same header was added twice in the message,
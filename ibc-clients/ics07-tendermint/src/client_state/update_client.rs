@@ -1,5 +1,9 @@
 use ibc_client_tendermint_types::error::{Error, IntoResult};
-use ibc_client_tendermint_types::{ConsensusState as ConsensusStateType, Header as TmHeader};
+use ibc_client_tendermint_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
+    TrustParamsUpdate, TrustThreshold,
+};
+use ibc_core_client::context::cache::VerifiedDataCache;
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::Height;
@@ -8,6 +12,8 @@ use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
 use tendermint::crypto::Sha256;
 use tendermint::merkle::MerkleHash;
+use tendermint::validator::Set as ValidatorSet;
+use tendermint::{Hash, Time};
 use tendermint_light_client_verifier::options::Options;
 use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
 use tendermint_light_client_verifier::Verifier;
@@ -25,9 +31,125 @@ where
     ConsensusStateType: Convertible<V::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
     H: MerkleHash + Sha256 + Default,
+{
+    let trusted_client_cons_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header.trusted_height.revision_number(),
+        header.trusted_height.revision_height(),
+    );
+    let trusted_consensus_state: ConsensusStateType = ctx
+        .consensus_state(&trusted_client_cons_state_path)?
+        .try_into()
+        .map_err(Into::into)?;
+
+    let now = ctx
+        .host_timestamp()?
+        .into_tm_time()
+        .ok_or_else(|| ClientError::ClientSpecific {
+            description: "host timestamp is not a valid TM timestamp".to_string(),
+        })?;
+
+    verify_header_against_trusted_state_with_cache::<H>(
+        header,
+        &trusted_consensus_state,
+        chain_id,
+        options,
+        verifier,
+        now,
+        ctx.verified_data_cache(),
+    )
+}
+
+/// Verifies the header as [`verify_header`] does, but requiring the given
+/// trust level instead of the one of the client.
+///
+/// Allows a host to get a client past a validator set handoff, e.g. a
+/// consumer chain rotating a large share of its voting power in a single
+/// block, without permanently weakening the client. The override applies to
+/// this update only; once verified, the header is applied as usual with
+/// `update_state`. Gating which updates may use an override, e.g. through
+/// governance, is left to the host.
+pub fn verify_header_with_trust_level<V, H>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    header: &TmHeader,
+    trust_level: TrustThreshold,
+    verifier: &impl Verifier,
+) -> Result<(), ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    H: MerkleHash + Sha256 + Default,
+{
+    let options = client_state
+        .clone()
+        .with_trust_params(TrustParamsUpdate {
+            trust_level: Some(trust_level),
+            ..Default::default()
+        })?
+        .as_light_client_options()?;
+
+    verify_header::<V, H>(
+        ctx,
+        header,
+        client_id,
+        client_state.chain_id(),
+        &options,
+        verifier,
+    )
+}
+
+/// Verifies the header against the consensus state it claims to be trusted
+/// by, as of the given time.
+///
+/// Unlike [`verify_header`], this function does not rely on a validation
+/// context, which makes it usable by standalone light client logic such as
+/// browser-based verifiers.
+pub fn verify_header_against_trusted_state<H>(
+    header: &TmHeader,
+    trusted_consensus_state: &ConsensusStateType,
+    chain_id: &ChainId,
+    options: &Options,
+    verifier: &impl Verifier,
+    now: Time,
+) -> Result<(), ClientError>
+where
+    H: MerkleHash + Sha256 + Default,
+{
+    verify_header_against_trusted_state_with_cache::<H>(
+        header,
+        trusted_consensus_state,
+        chain_id,
+        options,
+        verifier,
+        now,
+        None,
+    )
+}
+
+/// Same as [`verify_header_against_trusted_state`], but looks up validator
+/// sets in the given cache before hashing them, and records the ones that
+/// verify so that later updates in the same block can skip hashing them.
+pub fn verify_header_against_trusted_state_with_cache<H>(
+    header: &TmHeader,
+    trusted_consensus_state: &ConsensusStateType,
+    chain_id: &ChainId,
+    options: &Options,
+    verifier: &impl Verifier,
+    now: Time,
+    cache: Option<&dyn VerifiedDataCache>,
+) -> Result<(), ClientError>
+where
+    H: MerkleHash + Sha256 + Default,
 {
     // Checks that the header fields are valid.
-    header.validate_basic::<H>()?;
+    header.validate_basic_with_validators_hash(cached_validator_set_hash::<H>(
+        &header.validator_set,
+        &header.signed_header.header.validators_hash,
+        cache,
+    ))?;
 
     // The tendermint-light-client crate though works on heights that are assumed
     // to have the same revision number. We ensure this here.
@@ -35,70 +157,102 @@ where
 
     // Delegate to tendermint-light-client, which contains the required checks
     // of the new header against the trusted consensus state.
-    {
-        let trusted_state = {
-            let trusted_client_cons_state_path = ClientConsensusStatePath::new(
-                client_id.clone(),
-                header.trusted_height.revision_number(),
-                header.trusted_height.revision_height(),
-            );
-            let trusted_consensus_state: ConsensusStateType = ctx
-                .consensus_state(&trusted_client_cons_state_path)?
-                .try_into()
-                .map_err(Into::into)?;
-
-            header.check_trusted_next_validator_set::<H>(
-                &trusted_consensus_state.next_validators_hash,
-            )?;
-
-            TrustedBlockState {
-                chain_id: &chain_id
-                    .as_str()
-                    .try_into()
-                    .map_err(|e| ClientError::Other {
-                        description: format!("failed to parse chain id: {}", e),
-                    })?,
-                header_time: trusted_consensus_state.timestamp(),
-                height: header
-                    .trusted_height
-                    .revision_height()
-                    .try_into()
-                    .map_err(|_| ClientError::ClientSpecific {
-                        description: Error::InvalidHeaderHeight {
-                            height: header.trusted_height.revision_height(),
-                        }
-                        .to_string(),
-                    })?,
-                next_validators: &header.trusted_next_validator_set,
-                next_validators_hash: trusted_consensus_state.next_validators_hash,
-            }
-        };
-
-        let untrusted_state = UntrustedBlockState {
-            signed_header: &header.signed_header,
-            validators: &header.validator_set,
-            // NB: This will skip the
-            // VerificationPredicates::next_validators_match check for the
-            // untrusted state.
-            next_validators: None,
-        };
-
-        let now =
-            ctx.host_timestamp()?
-                .into_tm_time()
-                .ok_or_else(|| ClientError::ClientSpecific {
-                    description: "host timestamp is not a valid TM timestamp".to_string(),
-                })?;
-
-        // main header verification, delegated to the tendermint-light-client crate.
-        verifier
-            .verify_update_header(untrusted_state, trusted_state, options, now)
-            .into_result()?;
-    }
+    header.check_trusted_next_validator_set_hash(
+        &cached_validator_set_hash::<H>(
+            &header.trusted_next_validator_set,
+            &trusted_consensus_state.next_validators_hash,
+            cache,
+        ),
+        &trusted_consensus_state.next_validators_hash,
+    )?;
+
+    let trusted_state = TrustedBlockState {
+        chain_id: &chain_id
+            .as_str()
+            .try_into()
+            .map_err(|e| ClientError::Other {
+                description: format!("failed to parse chain id: {}", e),
+            })?,
+        header_time: trusted_consensus_state.timestamp(),
+        height: header
+            .trusted_height
+            .revision_height()
+            .try_into()
+            .map_err(|_| ClientError::ClientSpecific {
+                description: Error::InvalidHeaderHeight {
+                    height: header.trusted_height.revision_height(),
+                }
+                .to_string(),
+            })?,
+        next_validators: &header.trusted_next_validator_set,
+        next_validators_hash: trusted_consensus_state.next_validators_hash,
+    };
+
+    let untrusted_state = UntrustedBlockState {
+        signed_header: &header.signed_header,
+        validators: &header.validator_set,
+        // NB: This will skip the
+        // VerificationPredicates::next_validators_match check for the
+        // untrusted state.
+        next_validators: None,
+    };
+
+    // main header verification, delegated to the tendermint-light-client crate.
+    verifier
+        .verify_update_header(untrusted_state, trusted_state, options, now)
+        .into_result()?;
 
     Ok(())
 }
 
+/// Returns the hash of the given validator set.
+///
+/// If `cache` records the set as already verified against `expected_hash`,
+/// the hash is not recomputed. Otherwise the set is hashed, and recorded in
+/// the cache when it matches `expected_hash`.
+pub fn cached_validator_set_hash<H>(
+    validator_set: &ValidatorSet,
+    expected_hash: &Hash,
+    cache: Option<&dyn VerifiedDataCache>,
+) -> Hash
+where
+    H: MerkleHash + Sha256 + Default,
+{
+    let Some(cache) = cache else {
+        return validator_set.hash_with::<H>();
+    };
+
+    // The hasher is part of the key so that entries recorded with one hasher
+    // are never trusted by a client verifying with another.
+    let mut key = core::any::type_name::<H>().as_bytes().to_vec();
+    key.extend_from_slice(expected_hash.as_bytes());
+    let data = validator_set_fingerprint(validator_set);
+
+    if cache.is_verified(&key, &data) {
+        return *expected_hash;
+    }
+
+    let hash = validator_set.hash_with::<H>();
+    if &hash == expected_hash {
+        cache.record_verified(&key, &data);
+    }
+
+    hash
+}
+
+/// Serializes exactly the validator fields that feed into the validator set
+/// hash, i.e. the public key and voting power of each validator, in order.
+fn validator_set_fingerprint(validator_set: &ValidatorSet) -> Vec<u8> {
+    let mut data = Vec::new();
+    for validator in validator_set.validators() {
+        let pub_key = validator.pub_key.to_bytes();
+        data.extend_from_slice(&(pub_key.len() as u64).to_be_bytes());
+        data.extend_from_slice(&pub_key);
+        data.extend_from_slice(&validator.power().to_be_bytes());
+    }
+    data
+}
+
 /// Checks for misbehaviour upon receiving a new consensus state as part
 /// of a client update.
 pub fn check_for_misbehaviour_on_update<V>(
@@ -112,11 +266,13 @@ where
     ConsensusStateType: Convertible<V::ConsensusStateRef>,
     <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
 {
+    let header_height = header.height()?;
+
     let maybe_existing_consensus_state = {
         let path_at_header_height = ClientConsensusStatePath::new(
             client_id.clone(),
-            header.height().revision_number(),
-            header.height().revision_height(),
+            header_height.revision_number(),
+            header_height.revision_height(),
         );
 
         ctx.consensus_state(&path_at_header_height).ok()
@@ -137,7 +293,7 @@ where
         // 1. for all headers, the new header needs to have a larger timestamp than
         //    the “previous header”
         {
-            let maybe_prev_cs = ctx.prev_consensus_state(client_id, &header.height())?;
+            let maybe_prev_cs = ctx.prev_consensus_state(client_id, &header_height)?;
 
             if let Some(prev_cs) = maybe_prev_cs {
                 // New header timestamp cannot occur *before* the
@@ -152,8 +308,8 @@ where
 
         // 2. if a header comes in and is not the “last” header, then we also ensure
         //    that its timestamp is less than the “next header”
-        if &header.height() < client_latest_height {
-            let maybe_next_cs = ctx.next_consensus_state(client_id, &header.height())?;
+        if &header_height < client_latest_height {
+            let maybe_next_cs = ctx.next_consensus_state(client_id, &header_height)?;
 
             if let Some(next_cs) = maybe_next_cs {
                 // New (untrusted) header timestamp cannot occur *after* next
@@ -169,3 +325,117 @@ where
         Ok(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use ibc_core_client::context::cache::LruVerifiedDataCache;
+    use tendermint::crypto::default::Sha256 as DefaultSha256;
+    use tendermint_testgen::{Generator, Validator};
+
+    use super::*;
+
+    /// Counts the lookups of the wrapped cache that hit or miss a recorded
+    /// entry.
+    struct CountingCache {
+        inner: LruVerifiedDataCache,
+        hits: Cell<usize>,
+        misses: Cell<usize>,
+    }
+
+    impl CountingCache {
+        fn new(capacity: usize) -> Self {
+            Self {
+                inner: LruVerifiedDataCache::new(capacity),
+                hits: Cell::new(0),
+                misses: Cell::new(0),
+            }
+        }
+    }
+
+    impl VerifiedDataCache for CountingCache {
+        fn is_verified(&self, key: &[u8], data: &[u8]) -> bool {
+            let verified = self.inner.is_verified(key, data);
+
+            let counter = if verified { &self.hits } else { &self.misses };
+            counter.set(counter.get() + 1);
+
+            verified
+        }
+
+        fn record_verified(&self, key: &[u8], data: &[u8]) {
+            self.inner.record_verified(key, data)
+        }
+    }
+
+    fn validator_set(size: usize, voting_power: u64) -> ValidatorSet {
+        let validators = (0..size)
+            .map(|i| {
+                Validator::new(&format!("validator-{i}"))
+                    .voting_power(voting_power)
+                    .generate()
+                    .expect("valid validator")
+            })
+            .collect();
+
+        ValidatorSet::without_proposer(validators)
+    }
+
+    #[test]
+    fn test_cached_validator_set_hash_hit_and_miss() {
+        let cache = CountingCache::new(4);
+        let set = validator_set(4, 50);
+        let expected = set.hash_with::<DefaultSha256>();
+
+        // the first lookup misses and records the verified set
+        let hash = cached_validator_set_hash::<DefaultSha256>(&set, &expected, Some(&cache));
+        assert_eq!(hash, expected);
+        assert_eq!((cache.hits.get(), cache.misses.get()), (0, 1));
+        assert_eq!(cache.inner.len(), 1);
+
+        // the next ones hit without recording the set again
+        let hash = cached_validator_set_hash::<DefaultSha256>(&set, &expected, Some(&cache));
+        assert_eq!(hash, expected);
+        assert_eq!((cache.hits.get(), cache.misses.get()), (1, 1));
+        assert_eq!(cache.inner.len(), 1);
+
+        // another set misses and is recorded alongside the first one
+        let other_set = validator_set(4, 60);
+        let other_expected = other_set.hash_with::<DefaultSha256>();
+
+        let hash =
+            cached_validator_set_hash::<DefaultSha256>(&other_set, &other_expected, Some(&cache));
+        assert_eq!(hash, other_expected);
+        assert_eq!((cache.hits.get(), cache.misses.get()), (1, 2));
+        assert_eq!(cache.inner.len(), 2);
+    }
+
+    #[test]
+    fn test_cached_validator_set_hash_invalidation() {
+        let cache = CountingCache::new(4);
+        let set = validator_set(4, 50);
+        let expected = set.hash_with::<DefaultSha256>();
+
+        // a set not matching the expected hash is never recorded
+        let forged_set = validator_set(4, 60);
+        let hash = cached_validator_set_hash::<DefaultSha256>(&forged_set, &expected, Some(&cache));
+        assert_ne!(hash, expected);
+        assert!(cache.inner.is_empty());
+
+        cached_validator_set_hash::<DefaultSha256>(&set, &expected, Some(&cache));
+        assert_eq!(cache.inner.len(), 1);
+
+        // changing the set invalidates the recorded entry for that set
+        let hash = cached_validator_set_hash::<DefaultSha256>(&forged_set, &expected, Some(&cache));
+        assert_ne!(hash, expected);
+        assert_eq!(cache.hits.get(), 0);
+
+        // clearing the cache, e.g. between blocks, drops the verified sets
+        cache.inner.clear();
+        let hash = cached_validator_set_hash::<DefaultSha256>(&set, &expected, Some(&cache));
+        assert_eq!(hash, expected);
+        assert_eq!((cache.hits.get(), cache.misses.get()), (0, 4));
+        assert_eq!(cache.inner.len(), 1);
+    }
+}
@@ -98,6 +98,8 @@ where
     <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
     H: MerkleHash + Sha256Trait + Default,
 {
+    client_state.frozen_height.verify_not_frozen()?;
+
     match client_message.type_url.as_str() {
         TENDERMINT_HEADER_TYPE_URL => {
             let header = TmHeader::try_from(client_message)?;
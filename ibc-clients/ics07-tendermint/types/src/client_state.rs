@@ -5,8 +5,7 @@ use core::str::FromStr;
 use core::time::Duration;
 
 use ibc_core_client_types::error::ClientError;
-use ibc_core_client_types::proto::v1::Height as RawHeight;
-use ibc_core_client_types::Height;
+use ibc_core_client_types::{FrozenHeight, Height};
 use ibc_core_commitment_types::specs::ProofSpecs;
 use ibc_core_host_types::identifiers::ChainId;
 use ibc_primitives::prelude::*;
@@ -24,6 +23,19 @@ use crate::trust_threshold::TrustThreshold;
 
 pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        parity_scale_codec::MaxEncodedLen,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct AllowUpdate {
@@ -31,6 +43,26 @@ pub struct AllowUpdate {
     pub after_misbehaviour: bool,
 }
 
+/// Trust parameters of an existing Tendermint client that may be overridden
+/// after creation, typically by a governance-gated host flow. Fields left as
+/// `None` keep their current value.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TrustParamsUpdate {
+    pub trust_level: Option<TrustThreshold>,
+    pub trusting_period: Option<Duration>,
+    pub max_clock_drift: Option<Duration>,
+}
+
+impl TrustParamsUpdate {
+    /// Returns true if the update would lengthen the trusting period of the
+    /// given client state.
+    pub fn extends_trusting_period(&self, client_state: &ClientState) -> bool {
+        self.trusting_period
+            .is_some_and(|period| period > client_state.trusting_period)
+    }
+}
+
 /// Defines data structure for Tendermint client state.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
@@ -44,7 +76,7 @@ pub struct ClientState {
     pub proof_specs: ProofSpecs,
     pub upgrade_path: Vec<String>,
     pub allow_update: AllowUpdate,
-    pub frozen_height: Option<Height>,
+    pub frozen_height: FrozenHeight,
 }
 
 impl ClientState {
@@ -58,7 +90,7 @@ impl ClientState {
         latest_height: Height,
         proof_specs: ProofSpecs,
         upgrade_path: Vec<String>,
-        frozen_height: Option<Height>,
+        frozen_height: FrozenHeight,
         allow_update: AllowUpdate,
     ) -> Self {
         Self {
@@ -98,7 +130,7 @@ impl ClientState {
             latest_height,
             proof_specs,
             upgrade_path,
-            None, // New valid client must not be frozen.
+            FrozenHeight::UNFROZEN, // New valid client must not be frozen.
             allow_update,
         );
         client_state.validate()?;
@@ -107,16 +139,48 @@ impl ClientState {
 
     pub fn with_header(self, header: TmHeader) -> Result<Self, Error> {
         Ok(Self {
-            latest_height: max(header.height(), self.latest_height),
+            latest_height: max(header.height()?, self.latest_height),
             ..self
         })
     }
 
-    pub fn with_frozen_height(self, h: Height) -> Self {
-        Self {
-            frozen_height: Some(h),
-            ..self
+    pub fn with_frozen_height(mut self, h: Height) -> Self {
+        self.freeze(h);
+        self
+    }
+
+    /// Freezes the client at the given height, e.g. upon misbehaviour.
+    pub fn freeze(&mut self, height: Height) {
+        self.frozen_height.freeze(height);
+    }
+
+    /// Unfreezes the client, e.g. upon recovery.
+    pub fn unfreeze(&mut self) {
+        self.frozen_height.unfreeze();
+    }
+
+    /// Applies the given trust parameter overrides and validates the
+    /// resulting client state.
+    ///
+    /// Only the parameters themselves are checked here. Making sure that a
+    /// longer trusting period does not bring already expired consensus states
+    /// back into the trusting window is up to the caller.
+    pub fn with_trust_params(self, params: TrustParamsUpdate) -> Result<Self, Error> {
+        if self.is_frozen() {
+            return Err(Error::Validation {
+                reason: "cannot update the trust parameters of a frozen client".to_string(),
+            });
         }
+
+        let client_state = Self {
+            trust_level: params.trust_level.unwrap_or(self.trust_level),
+            trusting_period: params.trusting_period.unwrap_or(self.trusting_period),
+            max_clock_drift: params.max_clock_drift.unwrap_or(self.max_clock_drift),
+            ..self
+        };
+        client_state.validate()?;
+
+        Ok(client_state)
     }
 
     pub fn validate(&self) -> Result<(), Error> {
@@ -217,7 +281,7 @@ impl ClientState {
     }
 
     pub fn is_frozen(&self) -> bool {
-        self.frozen_height.is_some()
+        self.frozen_height.is_frozen()
     }
 
     // Resets custom fields to zero values (used in `update_client`)
@@ -226,13 +290,75 @@ impl ClientState {
         self.trust_level = TrustThreshold::ZERO;
         self.allow_update.after_expiry = false;
         self.allow_update.after_misbehaviour = false;
-        self.frozen_height = None;
+        self.frozen_height.unfreeze();
         self.max_clock_drift = ZERO_DURATION;
     }
 }
 
 impl Protobuf<RawTmClientState> for ClientState {}
 
+// The client state holds tendermint types without borsh support, hence it is
+// serialized with borsh as its protobuf encoding.
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for ClientState {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        let bytes = Protobuf::<RawTmClientState>::encode_vec(self.clone());
+        borsh::BorshSerialize::serialize(&bytes, writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for ClientState {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        let bytes = <Vec<u8> as borsh::BorshDeserialize>::deserialize_reader(reader)?;
+        Protobuf::<RawTmClientState>::decode_vec(&bytes).map_err(|e| {
+            borsh::maybestd::io::Error::new(
+                borsh::maybestd::io::ErrorKind::InvalidData,
+                e.to_string(),
+            )
+        })
+    }
+}
+
+// Likewise, the client state is SCALE-encoded as its protobuf encoding.
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Encode for ClientState {
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, writer: &mut T) {
+        let bytes = Protobuf::<RawTmClientState>::encode_vec(self.clone());
+        parity_scale_codec::Encode::encode_to(&bytes, writer);
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for ClientState {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let bytes = <Vec<u8> as parity_scale_codec::Decode>::decode(input)?;
+        Protobuf::<RawTmClientState>::decode_vec(&bytes)
+            .map_err(|_| parity_scale_codec::Error::from("invalid protobuf encoding"))
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl scale_info::TypeInfo for ClientState {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        scale_info::Type::builder()
+            .path(scale_info::Path::new("ClientState", module_path!()))
+            .composite(
+                scale_info::build::Fields::unnamed()
+                    .field(|f| f.ty::<Vec<u8>>().type_name("Vec<u8>")),
+            )
+    }
+}
+
 impl TryFrom<RawTmClientState> for ClientState {
     type Error = Error;
 
@@ -279,7 +405,7 @@ impl TryFrom<RawTmClientState> for ClientState {
         // frozen". See:
         // https://github.com/cosmos/ibc-go/blob/8422d0c4c35ef970539466c5bdec1cd27369bab3/modules/light-clients/07-tendermint/types/client_state.go#L74
         let frozen_height =
-            Height::try_from(raw.frozen_height.ok_or(Error::MissingFrozenHeight)?).ok();
+            FrozenHeight::from(raw.frozen_height.ok_or(Error::MissingFrozenHeight)?);
 
         // We use set this deprecated field just so that we can properly convert
         // it back in its raw form
@@ -320,10 +446,7 @@ impl From<ClientState> for RawTmClientState {
             // decode the `ClientState` value. In `RawClientState`, a
             // `frozen_height` of `0` means "not frozen". See:
             // https://github.com/cosmos/ibc-go/blob/8422d0c4c35ef970539466c5bdec1cd27369bab3/modules/light-clients/07-tendermint/types/client_state.go#L74
-            frozen_height: Some(value.frozen_height.map(Into::into).unwrap_or(RawHeight {
-                revision_number: 0,
-                revision_height: 0,
-            })),
+            frozen_height: Some(value.frozen_height.into()),
             latest_height: Some(value.latest_height.into()),
             proof_specs: value.proof_specs.into(),
             upgrade_path: value.upgrade_path,
@@ -583,4 +706,51 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn client_state_with_trust_params() {
+        let client_state = ClientState::new(
+            ChainId::new("ibc-0").unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128_000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 10).expect("Never fails"),
+            ProofSpecs::cosmos(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .expect("Never fails");
+
+        let params = TrustParamsUpdate {
+            trust_level: Some(TrustThreshold::TWO_THIRDS),
+            trusting_period: Some(Duration::new(100_000, 0)),
+            max_clock_drift: None,
+        };
+        assert!(params.extends_trusting_period(&client_state));
+
+        let updated = client_state
+            .clone()
+            .with_trust_params(params)
+            .expect("valid update");
+        assert_eq!(updated.trust_level, TrustThreshold::TWO_THIRDS);
+        assert_eq!(updated.trusting_period, Duration::new(100_000, 0));
+        assert_eq!(updated.max_clock_drift, client_state.max_clock_drift);
+
+        // The trusting period must stay below the unbonding period.
+        let too_long = TrustParamsUpdate {
+            trusting_period: Some(Duration::new(128_000, 0)),
+            ..Default::default()
+        };
+        assert!(client_state.clone().with_trust_params(too_long).is_err());
+
+        // Frozen clients must go through recovery instead.
+        let frozen = client_state.with_frozen_height(Height::new(0, 5).expect("Never fails"));
+        assert!(frozen
+            .with_trust_params(TrustParamsUpdate::default())
+            .is_err());
+    }
 }
@@ -46,6 +46,68 @@ impl ConsensusState {
 
 impl Protobuf<RawConsensusState> for ConsensusState {}
 
+// The consensus state holds tendermint types without borsh support, hence it is
+// serialized with borsh as its protobuf encoding.
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for ConsensusState {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        let bytes = Protobuf::<RawConsensusState>::encode_vec(self.clone());
+        borsh::BorshSerialize::serialize(&bytes, writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for ConsensusState {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        let bytes = <Vec<u8> as borsh::BorshDeserialize>::deserialize_reader(reader)?;
+        Protobuf::<RawConsensusState>::decode_vec(&bytes).map_err(|e| {
+            borsh::maybestd::io::Error::new(
+                borsh::maybestd::io::ErrorKind::InvalidData,
+                e.to_string(),
+            )
+        })
+    }
+}
+
+// Likewise, the consensus state is SCALE-encoded as its protobuf encoding.
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Encode for ConsensusState {
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, writer: &mut T) {
+        let bytes = Protobuf::<RawConsensusState>::encode_vec(self.clone());
+        parity_scale_codec::Encode::encode_to(&bytes, writer);
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for ConsensusState {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let bytes = <Vec<u8> as parity_scale_codec::Decode>::decode(input)?;
+        Protobuf::<RawConsensusState>::decode_vec(&bytes)
+            .map_err(|_| parity_scale_codec::Error::from("invalid protobuf encoding"))
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl scale_info::TypeInfo for ConsensusState {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        scale_info::Type::builder()
+            .path(scale_info::Path::new("ConsensusState", module_path!()))
+            .composite(
+                scale_info::build::Fields::unnamed()
+                    .field(|f| f.ty::<Vec<u8>>().type_name("Vec<u8>")),
+            )
+    }
+}
+
 impl TryFrom<RawConsensusState> for ConsensusState {
     type Error = Error;
 
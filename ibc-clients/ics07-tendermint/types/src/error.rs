@@ -103,6 +103,7 @@ impl std::error::Error for Error {
             Self::InvalidHeader { error: e, .. }
             | Self::InvalidTendermintTrustThreshold(e)
             | Self::InvalidRawHeader(e) => Some(e),
+            Self::InvalidProofSpec(e) => Some(e),
             _ => None,
         }
     }
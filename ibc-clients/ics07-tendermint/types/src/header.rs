@@ -51,14 +51,17 @@ impl Header {
         self.signed_header.header.time.into()
     }
 
-    pub fn height(&self) -> Height {
-        Height::new(
-            ChainId::from_str(self.signed_header.header.chain_id.as_str())
-                .expect("chain id")
-                .revision_number(),
-            u64::from(self.signed_header.header.height),
-        )
-        .expect("malformed tendermint header domain type has an illegal height of 0")
+    /// Returns the IBC height of the header, whose revision number is taken
+    /// from the chain identifier of the signed header.
+    pub fn height(&self) -> Result<Height, Error> {
+        let revision_number = ChainId::from_str(self.signed_header.header.chain_id.as_str())
+            .map_err(Error::InvalidIdentifier)?
+            .revision_number();
+        let revision_height = self.signed_header.header.height.value();
+
+        Height::new(revision_number, revision_height).map_err(|_| Error::InvalidHeaderHeight {
+            height: revision_height,
+        })
     }
 
     pub fn as_untrusted_block_state(&self) -> UntrustedBlockState<'_> {
@@ -91,7 +94,7 @@ impl Header {
     }
 
     pub fn verify_chain_id_version_matches_height(&self, chain_id: &ChainId) -> Result<(), Error> {
-        if self.height().revision_number() != chain_id.revision_number() {
+        if self.height()?.revision_number() != chain_id.revision_number() {
             return Err(Error::MismatchHeaderChainId {
                 given: self.signed_header.header.chain_id.to_string(),
                 expected: chain_id.to_string(),
@@ -107,7 +110,20 @@ impl Header {
         &self,
         trusted_next_validator_hash: &Hash,
     ) -> Result<(), ClientError> {
-        if &self.trusted_next_validator_set.hash_with::<H>() == trusted_next_validator_hash {
+        self.check_trusted_next_validator_set_hash(
+            &self.trusted_next_validator_set.hash_with::<H>(),
+            trusted_next_validator_hash,
+        )
+    }
+
+    /// Same as [`Self::check_trusted_next_validator_set`], but with the hash of
+    /// the trusted next validator set provided by the caller, e.g. from a cache.
+    pub fn check_trusted_next_validator_set_hash(
+        &self,
+        trusted_next_validator_set_hash: &Hash,
+        trusted_next_validator_hash: &Hash,
+    ) -> Result<(), ClientError> {
+        if trusted_next_validator_set_hash == trusted_next_validator_hash {
             Ok(())
         } else {
             Err(ClientError::HeaderVerificationFailure {
@@ -120,10 +136,18 @@ impl Header {
 
     /// Checks if the fields of a given header are consistent with the trusted fields of this header.
     pub fn validate_basic<H: MerkleHash + Sha256 + Default>(&self) -> Result<(), Error> {
-        if self.height().revision_number() != self.trusted_height.revision_number() {
+        self.validate_basic_with_validators_hash(self.validator_set.hash_with::<H>())
+    }
+
+    /// Same as [`Self::validate_basic`], but with the hash of the header's
+    /// validator set provided by the caller, e.g. from a cache.
+    pub fn validate_basic_with_validators_hash(&self, validators_hash: Hash) -> Result<(), Error> {
+        let height = self.height()?;
+
+        if height.revision_number() != self.trusted_height.revision_number() {
             return Err(Error::MismatchHeightRevisions {
                 trusted_revision: self.trusted_height.revision_number(),
-                header_revision: self.height().revision_number(),
+                header_revision: height.revision_number(),
             });
         }
 
@@ -131,14 +155,12 @@ impl Header {
         // height of the header already on chain for which this client update is
         // based on) must be smaller than height of the new header that we're
         // installing.
-        if self.trusted_height >= self.height() {
+        if self.trusted_height >= height {
             return Err(Error::InvalidHeaderHeight {
-                height: self.height().revision_height(),
+                height: height.revision_height(),
             });
         }
 
-        let validators_hash = self.validator_set.hash_with::<H>();
-
         if validators_hash != self.signed_header.header.validators_hash {
             return Err(Error::MismatchValidatorsHashes {
                 signed_header_validators_hash: self.signed_header.header.validators_hash,
@@ -181,6 +203,9 @@ impl TryFrom<RawHeader> for Header {
                 .map_err(Error::InvalidRawHeader)?,
         };
 
+        // Reject headers whose IBC height cannot be derived.
+        header.height()?;
+
         Ok(header)
     }
 }
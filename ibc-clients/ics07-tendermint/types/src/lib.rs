@@ -2,7 +2,7 @@
 //! the Tendermint consensus algorithm.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
 #![deny(
     warnings,
@@ -42,6 +42,7 @@ pub mod proto {
 pub const TENDERMINT_CLIENT_TYPE: &str = "07-tendermint";
 
 /// Returns the tendermint `ClientType`
+#[allow(clippy::expect_used)] // `TENDERMINT_CLIENT_TYPE` is a valid client type
 pub fn client_type() -> ClientType {
     ClientType::from_str(TENDERMINT_CLIENT_TYPE).expect("Never fails because it's valid")
 }
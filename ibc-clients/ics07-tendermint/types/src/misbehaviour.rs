@@ -55,12 +55,14 @@ impl Misbehaviour {
             });
         }
 
-        if self.header1.height() < self.header2.height() {
+        let header1_height = self.header1.height()?;
+        let header2_height = self.header2.height()?;
+
+        if header1_height < header2_height {
             return Err(Error::InvalidRawMisbehaviour {
                 reason: format!(
                     "header1 height is less than header2 height ({} < {})",
-                    self.header1.height(),
-                    self.header2.height()
+                    header1_height, header2_height
                 ),
             });
         }
@@ -143,9 +145,9 @@ impl core::fmt::Display for Misbehaviour {
             f,
             "{} h1: {}-{} h2: {}-{}",
             self.client_id,
-            self.header1.height(),
+            self.header1.signed_header.header.height,
             self.header1.trusted_height,
-            self.header2.height(),
+            self.header2.signed_header.header.height,
             self.header2.trusted_height,
         )
     }
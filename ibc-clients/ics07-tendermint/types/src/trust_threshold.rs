@@ -22,6 +22,7 @@ use tendermint::trust_threshold::TrustThresholdFraction;
     derive(
         parity_scale_codec::Encode,
         parity_scale_codec::Decode,
+        parity_scale_codec::MaxEncodedLen,
         scale_info::TypeInfo
     )
 )]
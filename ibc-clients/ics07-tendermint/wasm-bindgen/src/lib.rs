@@ -0,0 +1,169 @@
+//! `wasm-bindgen` bindings of the ICS-07 Tendermint light client verification
+//! functions, allowing light client logic built with `ibc-rs` to run in
+//! browsers.
+//!
+//! All client states, consensus states and headers are expected to be
+//! Protobuf-encoded `Any` messages, as returned by the gRPC queries of a Cosmos
+//! SDK chain.
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+use core::str::FromStr;
+
+use ibc_client_tendermint::client_state::{
+    verify_header_against_trusted_state, verify_membership as verify_tm_membership,
+    verify_non_membership as verify_tm_non_membership,
+};
+use ibc_client_tendermint::types::{ClientState, ConsensusState, Header};
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_commitment_types::proto::ics23::HostFunctionsManager;
+use ibc_core_host_types::path::Path;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+use tendermint::crypto::default::Sha256;
+use tendermint_light_client_verifier::ProdVerifier;
+use wasm_bindgen::prelude::*;
+
+/// Verifies a Tendermint header against the trusted consensus state it
+/// refers to, as of the given UNIX time in nanoseconds.
+#[wasm_bindgen(js_name = verifyTendermintHeader)]
+pub fn verify_tendermint_header(
+    client_state: &[u8],
+    trusted_consensus_state: &[u8],
+    header: &[u8],
+    now_unix_nanos: u64,
+) -> Result<(), JsError> {
+    verify_header(
+        client_state,
+        trusted_consensus_state,
+        header,
+        now_unix_nanos,
+    )
+    .map_err(|e| JsError::new(&e))
+}
+
+/// Verifies that `value` is committed under `path` in the state of the chain
+/// tracked by the given Tendermint client state.
+#[wasm_bindgen(js_name = verifyMembership)]
+pub fn verify_membership(
+    client_state: &[u8],
+    prefix: &[u8],
+    proof: &[u8],
+    root: &[u8],
+    path: &str,
+    value: &[u8],
+) -> Result<(), JsError> {
+    membership(client_state, prefix, proof, root, path, Some(value)).map_err(|e| JsError::new(&e))
+}
+
+/// Verifies that nothing is committed under `path` in the state of the chain
+/// tracked by the given Tendermint client state.
+#[wasm_bindgen(js_name = verifyNonMembership)]
+pub fn verify_non_membership(
+    client_state: &[u8],
+    prefix: &[u8],
+    proof: &[u8],
+    root: &[u8],
+    path: &str,
+) -> Result<(), JsError> {
+    membership(client_state, prefix, proof, root, path, None).map_err(|e| JsError::new(&e))
+}
+
+fn verify_header(
+    client_state: &[u8],
+    trusted_consensus_state: &[u8],
+    header: &[u8],
+    now_unix_nanos: u64,
+) -> Result<(), String> {
+    let client_state = decode_any::<ClientState>(client_state)?;
+    let trusted_consensus_state = decode_any::<ConsensusState>(trusted_consensus_state)?;
+    let header = decode_any::<Header>(header)?;
+
+    let now = Timestamp::from_nanoseconds(now_unix_nanos)
+        .map_err(|e| e.to_string())?
+        .into_tm_time()
+        .ok_or_else(|| "the current time is not a valid Tendermint time".to_string())?;
+
+    let options = client_state
+        .as_light_client_options()
+        .map_err(|e| e.to_string())?;
+
+    verify_header_against_trusted_state::<Sha256>(
+        &header,
+        &trusted_consensus_state,
+        client_state.chain_id(),
+        &options,
+        &ProdVerifier::default(),
+        now,
+    )
+    .map_err(|e| e.to_string())
+}
+
+fn membership(
+    client_state: &[u8],
+    prefix: &[u8],
+    proof: &[u8],
+    root: &[u8],
+    path: &str,
+    value: Option<&[u8]>,
+) -> Result<(), String> {
+    let client_state = decode_any::<ClientState>(client_state)?;
+    let prefix = CommitmentPrefix::try_from(prefix.to_vec()).map_err(|e| e.to_string())?;
+    let proof = CommitmentProofBytes::try_from(proof.to_vec()).map_err(|e| e.to_string())?;
+    let root = CommitmentRoot::from_bytes(root);
+    let path = Path::from_str(path).map_err(|e| e.to_string())?;
+
+    match value {
+        Some(value) => verify_tm_membership::<HostFunctionsManager>(
+            &client_state.proof_specs,
+            &prefix,
+            &proof,
+            &root,
+            path,
+            value.to_vec(),
+        ),
+        None => verify_tm_non_membership::<HostFunctionsManager>(
+            &client_state.proof_specs,
+            &prefix,
+            &proof,
+            &root,
+            path,
+        ),
+    }
+    .map_err(|e| e.to_string())
+}
+
+fn decode_any<T: Protobuf<Any>>(bytes: &[u8]) -> Result<T, String>
+where
+    <T as TryFrom<Any>>::Error: core::fmt::Display,
+{
+    T::decode_vec(bytes).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_inputs_are_rejected() {
+        assert!(verify_header(&[0xff], &[0xff], &[0xff], 0).is_err());
+        assert!(membership(
+            &[0xff],
+            b"ibc",
+            &[0xff],
+            &[0xff],
+            "nextClientSequence",
+            None
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,106 @@
+//! Defines a cache that light clients may use to avoid re-verifying the same
+//! data across the messages of a block.
+
+use core::cell::RefCell;
+
+use ibc_primitives::prelude::*;
+
+/// Memoizes data that a light client has already verified against a
+/// commitment, keyed by that commitment. For instance, the Tendermint client
+/// records validator sets under their hash so that sequential `UpdateClient`
+/// messages for adjacent heights do not hash the same set twice.
+///
+/// Client validation only has shared access to the context, so
+/// implementations record entries through interior mutability. Hosts are
+/// expected to clear the cache between blocks.
+pub trait VerifiedDataCache {
+    /// Returns true if `data` was previously recorded as verified under `key`.
+    fn is_verified(&self, key: &[u8], data: &[u8]) -> bool;
+
+    /// Records that `data` has been verified under `key`.
+    fn record_verified(&self, key: &[u8], data: &[u8]);
+}
+
+/// A fixed-capacity [`VerifiedDataCache`] evicting the least recently used
+/// entry once full.
+#[derive(Debug, Default)]
+pub struct LruVerifiedDataCache {
+    capacity: usize,
+    /// Entries ordered from least to most recently used.
+    entries: RefCell<Vec<(Vec<u8>, Vec<u8>)>>,
+}
+
+impl LruVerifiedDataCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: RefCell::new(Vec::with_capacity(capacity)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+
+    /// Drops all entries, typically at the end of a block.
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+}
+
+impl VerifiedDataCache for LruVerifiedDataCache {
+    fn is_verified(&self, key: &[u8], data: &[u8]) -> bool {
+        let mut entries = self.entries.borrow_mut();
+
+        let Some(idx) = entries.iter().position(|(k, d)| k == key && d == data) else {
+            return false;
+        };
+        let entry = entries.remove(idx);
+        entries.push(entry);
+
+        true
+    }
+
+    fn record_verified(&self, key: &[u8], data: &[u8]) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.borrow_mut();
+
+        entries.retain(|(k, _)| k != key);
+        if entries.len() >= self.capacity {
+            entries.remove(0);
+        }
+        entries.push((key.to_vec(), data.to_vec()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lru_verified_data_cache() {
+        let cache = LruVerifiedDataCache::new(2);
+
+        cache.record_verified(b"a", b"1");
+        cache.record_verified(b"b", b"2");
+        assert!(cache.is_verified(b"a", b"1"));
+        assert!(!cache.is_verified(b"a", b"2"));
+
+        // `b` is now the least recently used entry and gets evicted.
+        cache.record_verified(b"c", b"3");
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.is_verified(b"b", b"2"));
+        assert!(cache.is_verified(b"a", b"1"));
+        assert!(cache.is_verified(b"c", b"3"));
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
@@ -5,10 +5,14 @@ use ibc_core_client_types::{Height, Status};
 use ibc_core_commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
+use ibc_core_commitment_types::error::CommitmentError;
+use ibc_core_commitment_types::merkle::{merkle_path_to_string, split_prefix, DecodedProof};
+use ibc_core_commitment_types::proto::v1::MerklePath;
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_core_host_types::path::Path;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
+use ibc_primitives::CommitmentHasher;
 
 use crate::context::{ClientExecutionContext, ClientValidationContext};
 use crate::Convertible;
@@ -33,6 +37,17 @@ pub trait ClientStateCommon: Convertible<Any> {
     /// Validate that the client is at a sufficient height
     fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError>;
 
+    /// Returns the hash function the counterparty computes the packet and
+    /// acknowledgement commitments it stores with, which the host must use to
+    /// verify them.
+    ///
+    /// Defaults to SHA-256, as computed by ibc-go. Clients of counterparties
+    /// advertising their hash function, e.g. in their proof specs, should
+    /// override it.
+    fn commitment_hasher(&self) -> Result<CommitmentHasher, ClientError> {
+        Ok(CommitmentHasher::Sha256)
+    }
+
     /// Verify the upgraded client and consensus states and validate proofs
     /// against the given root.
     ///
@@ -72,6 +87,119 @@ pub trait ClientStateCommon: Convertible<Any> {
         root: &CommitmentRoot,
         path: Path,
     ) -> Result<(), ClientError>;
+
+    /// Verifies the existence of a value at the given merkle path, whose
+    /// first key is the commitment prefix, e.g. at a raw key of a store of the
+    /// counterparty other than its IBC store.
+    ///
+    /// Defaults to verifying merkle paths made of a prefix and an IBC path
+    /// with [`verify_membership`](Self::verify_membership), rejecting any other
+    /// path. Clients able to verify arbitrary keys should override it.
+    fn verify_merkle_membership(
+        &self,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        merkle_path: MerklePath,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let (prefix, path) = split_ibc_path(&merkle_path)?;
+
+        self.verify_membership(&prefix, proof, root, path, value)
+    }
+
+    /// Verifies the absence of a value at the given merkle path, whose first
+    /// key is the commitment prefix.
+    ///
+    /// Defaults to verifying merkle paths made of a prefix and an IBC path
+    /// with [`verify_non_membership`](Self::verify_non_membership), rejecting
+    /// any other path.
+    fn verify_merkle_non_membership(
+        &self,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        merkle_path: MerklePath,
+    ) -> Result<(), ClientError> {
+        let (prefix, path) = split_ibc_path(&merkle_path)?;
+
+        self.verify_non_membership(&prefix, proof, root, path)
+    }
+
+    /// Same as [`verify_membership`](Self::verify_membership), against a
+    /// proof already decoded as a merkle proof, e.g. by a handler which also
+    /// reports the value it proves.
+    ///
+    /// Defaults to verifying the bytes the proof was decoded from. Clients
+    /// verifying merkle proofs should override it to skip decoding them again.
+    fn verify_membership_decoded(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &DecodedProof<'_>,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.verify_membership(prefix, proof.bytes(), root, path, value)
+    }
+
+    /// Same as [`verify_non_membership`](Self::verify_non_membership),
+    /// against a proof already decoded as a merkle proof.
+    ///
+    /// Defaults to verifying the bytes the proof was decoded from.
+    fn verify_non_membership_decoded(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &DecodedProof<'_>,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        self.verify_non_membership(prefix, proof.bytes(), root, path)
+    }
+
+    /// Verifies the existence of several values at their respective paths
+    /// against a single proof, e.g. an ICS-23 batch proof or a zk aggregate.
+    ///
+    /// Defaults to verifying each value in turn with
+    /// [`verify_membership`](Self::verify_membership). Clients capable of
+    /// batched verification should override it.
+    fn verify_batch_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        items: Vec<(Path, Vec<u8>)>,
+    ) -> Result<(), ClientError> {
+        items
+            .into_iter()
+            .try_for_each(|(path, value)| self.verify_membership(prefix, proof, root, path, value))
+    }
+
+    /// Returns the number of signatures checked when verifying the given
+    /// client message, i.e. a header or a misbehaviour, so that hosts can
+    /// charge for them before the verification.
+    ///
+    /// Defaults to none, for clients whose messages carry no signatures.
+    fn client_message_signature_count(&self, _client_message: &Any) -> Result<usize, ClientError> {
+        Ok(0)
+    }
+}
+
+/// Splits a merkle path into its commitment prefix and the IBC path it is
+/// made of.
+fn split_ibc_path(merkle_path: &MerklePath) -> Result<(CommitmentPrefix, Path), ClientError> {
+    let (prefix, keys) = split_prefix(merkle_path).map_err(ClientError::InvalidCommitmentProof)?;
+
+    let path = match keys.as_slice() {
+        [key] => key.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        ClientError::InvalidCommitmentProof(CommitmentError::InvalidMerklePath {
+            path: merkle_path_to_string(merkle_path),
+            reason: "not an IBC path".to_string(),
+        })
+    })?;
+
+    Ok((prefix, path))
 }
 
 /// `ClientState` methods which require access to the client's validation
@@ -1,10 +1,12 @@
-use ibc_core_client_types::Height;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::{Height, UpdateMeta};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_core_host_types::path::{ClientConsensusStatePath, ClientStatePath};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::{Signer, Timestamp};
 
+use crate::cache::VerifiedDataCache;
 use crate::client_state::{ClientStateExecution, ClientStateValidation};
 use crate::consensus_state::ConsensusState;
 
@@ -34,11 +36,14 @@ pub trait ClientValidationContext: Sized {
 
     /// Returns the timestamp and height of the host when it processed a client
     /// update request at the specified height.
+    ///
+    /// Returns an error if no such metadata exists, e.g. for a consensus state
+    /// which was pruned.
     fn client_update_meta(
         &self,
         client_id: &ClientId,
         height: &Height,
-    ) -> Result<(Timestamp, Height), ContextError>;
+    ) -> Result<UpdateMeta, ContextError>;
 }
 
 /// Defines the methods that all client `ExecutionContext`s (precisely the
@@ -80,14 +85,14 @@ pub trait ClientExecutionContext:
 
     /// Called upon successful client update.
     ///
-    /// Implementations are expected to use this to record the specified time
-    /// and height as the time at which this update (or header) was processed.
+    /// Implementations are expected to use this to record the host time and
+    /// height of the specified `UpdateMeta` as the time at which this update
+    /// (or header) was processed.
     fn store_update_meta(
         &mut self,
         client_id: ClientId,
         height: Height,
-        host_timestamp: Timestamp,
-        host_height: Height,
+        update_meta: UpdateMeta,
     ) -> Result<(), ContextError>;
 
     /// Delete the update time and height associated with the client at the
@@ -102,6 +107,20 @@ pub trait ClientExecutionContext:
         client_id: ClientId,
         height: Height,
     ) -> Result<(), ContextError>;
+
+    /// Removes the consensus state of the client at the specified height from
+    /// the index of the consensus states by processed time, once the
+    /// consensus state is pruned.
+    ///
+    /// Implement only if the host maintains the index populated through
+    /// `ExecutionContext::index_consensus_state_processed_time`.
+    fn unindex_consensus_state_processed_time(
+        &mut self,
+        _client_id: &ClientId,
+        _height: &Height,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
 }
 
 /// An optional trait that extends the client validation context capabilities by
@@ -136,6 +155,24 @@ pub trait ExtClientValidationContext: ClientValidationContext {
         client_id: &ClientId,
         height: &Height,
     ) -> Result<Option<Self::ConsensusStateRef>, ContextError>;
+
+    /// Returns the cache light clients may use to skip re-verifying data,
+    /// such as validator sets, that was already verified earlier in the block.
+    ///
+    /// Caching is disabled by default.
+    fn verified_data_cache(&self) -> Option<&dyn VerifiedDataCache> {
+        None
+    }
+
+    /// Returns Ok() if the given signer is the authority, e.g. governance,
+    /// allowed to recover clients through client specific paths skipping
+    /// their usual verification, such as resetting a client to a header. No
+    /// one is allowed by default.
+    fn validate_client_recovery_authority(&self, signer: &Signer) -> Result<(), ContextError> {
+        Err(ClientError::UnauthorizedClientRecovery {
+            signer: signer.clone(),
+        })?
+    }
 }
 
 /// An optional trait that extends the client context required during execution.
@@ -5,7 +5,7 @@
 //! for pertinent client state transitions.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
 #![deny(
     warnings,
@@ -18,14 +18,17 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod cache;
 pub mod client_state;
 pub mod consensus_state;
+pub mod verify;
 
 mod context;
 pub use context::*;
 
 /// Trait preludes for the ICS-02 client implementation.
 pub mod prelude {
+    pub use crate::cache::*;
     pub use crate::client_state::*;
     pub use crate::consensus_state::*;
     pub use crate::context::*;
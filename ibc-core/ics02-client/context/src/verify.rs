@@ -0,0 +1,322 @@
+//! Standalone proof verification against the state committed by a
+//! counterparty, usable outside of the IBC handlers.
+//!
+//! These functions allow applications (e.g. interchain queries or bridges) to
+//! verify arbitrary values committed by the counterparty, such as values
+//! carried in packets, with the same light clients as the core handlers.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_commitment_types::merkle::DecodedProof;
+use ibc_core_commitment_types::proto::v1::MerklePath;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_host_types::path::{ClientConsensusStatePath, Path};
+use ibc_primitives::prelude::*;
+
+use crate::client_state::{ClientStateCommon, ClientStateValidation};
+use crate::consensus_state::ConsensusState;
+use crate::context::ClientValidationContext;
+
+/// A proof carried by a message, decoded once as a merkle proof when it is
+/// one, so that verifying it and, upon failure, reporting the value it proves
+/// do not decode it again. Proofs of other light clients are kept as is.
+#[derive(Clone, Debug)]
+pub enum MsgProof<'a> {
+    Merkle(DecodedProof<'a>),
+    Opaque(&'a CommitmentProofBytes),
+}
+
+impl<'a> From<&'a CommitmentProofBytes> for MsgProof<'a> {
+    fn from(bytes: &'a CommitmentProofBytes) -> Self {
+        DecodedProof::try_from(bytes).map_or(Self::Opaque(bytes), Self::Merkle)
+    }
+}
+
+impl MsgProof<'_> {
+    pub fn bytes(&self) -> &CommitmentProofBytes {
+        match self {
+            Self::Merkle(proof) => proof.bytes(),
+            Self::Opaque(bytes) => bytes,
+        }
+    }
+
+    /// Returns the value proved by the proof, if it is an ICS-23 existence
+    /// proof. See [`CommitmentProofBytes::proved_value`].
+    pub fn proved_value(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::Merkle(proof) => proof.proved_value().map(<[u8]>::to_vec),
+            Self::Opaque(_) => None,
+        }
+    }
+
+    /// Verifies that the given value is stored at the given path, under the
+    /// given prefix, against the given root.
+    pub fn verify_membership<C>(
+        &self,
+        client_state: &C,
+        prefix: &CommitmentPrefix,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError>
+    where
+        C: ClientStateCommon + ?Sized,
+    {
+        match self {
+            Self::Merkle(proof) => {
+                client_state.verify_membership_decoded(prefix, proof, root, path, value)
+            }
+            Self::Opaque(bytes) => client_state.verify_membership(prefix, bytes, root, path, value),
+        }
+    }
+
+    /// Verifies that no value is stored at the given path, under the given
+    /// prefix, against the given root.
+    pub fn verify_non_membership<C>(
+        &self,
+        client_state: &C,
+        prefix: &CommitmentPrefix,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError>
+    where
+        C: ClientStateCommon + ?Sized,
+    {
+        match self {
+            Self::Merkle(proof) => {
+                client_state.verify_non_membership_decoded(prefix, proof, root, path)
+            }
+            Self::Opaque(bytes) => client_state.verify_non_membership(prefix, bytes, root, path),
+        }
+    }
+}
+
+/// Verifies that the given value is stored at the given path, under the
+/// given prefix, against the given root.
+pub fn membership<C>(
+    client_state: &C,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: impl Into<Path>,
+    value: Vec<u8>,
+) -> Result<(), ClientError>
+where
+    C: ClientStateCommon + ?Sized,
+{
+    client_state.verify_membership(prefix, proof, root, path.into(), value)
+}
+
+/// Verifies that no value is stored at the given path, under the given
+/// prefix, against the given root.
+pub fn non_membership<C>(
+    client_state: &C,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: impl Into<Path>,
+) -> Result<(), ClientError>
+where
+    C: ClientStateCommon + ?Sized,
+{
+    client_state.verify_non_membership(prefix, proof, root, path.into())
+}
+
+/// Verifies that the given values are stored at their respective paths, under
+/// the given prefix, against a single proof and root.
+pub fn batch_membership<C>(
+    client_state: &C,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    items: Vec<(Path, Vec<u8>)>,
+) -> Result<(), ClientError>
+where
+    C: ClientStateCommon + ?Sized,
+{
+    client_state.verify_batch_membership(prefix, proof, root, items)
+}
+
+/// Verifies that the given value is stored at the given raw merkle path,
+/// whose first key is the commitment prefix, against the given root.
+///
+/// Unlike [`membership`], the key is not required to be an IBC path, e.g. to
+/// verify a value of a store of the counterparty other than its IBC store.
+pub fn merkle_membership<C>(
+    client_state: &C,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    merkle_path: MerklePath,
+    value: Vec<u8>,
+) -> Result<(), ClientError>
+where
+    C: ClientStateCommon + ?Sized,
+{
+    client_state.verify_merkle_membership(proof, root, merkle_path, value)
+}
+
+/// Verifies that no value is stored at the given raw merkle path, whose first
+/// key is the commitment prefix, against the given root.
+pub fn merkle_non_membership<C>(
+    client_state: &C,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    merkle_path: MerklePath,
+) -> Result<(), ClientError>
+where
+    C: ClientStateCommon + ?Sized,
+{
+    client_state.verify_merkle_non_membership(proof, root, merkle_path)
+}
+
+/// Verifies that the given value is stored at the given path on the
+/// counterparty tracked by the given client, at the given proof height.
+///
+/// As in the handlers, the client must be active and the root is taken from
+/// its consensus state at the proof height.
+pub fn membership_at_height<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    proof_height: Height,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    path: impl Into<Path>,
+    value: Vec<u8>,
+) -> Result<(), ContextError>
+where
+    V: ClientValidationContext,
+{
+    let (client_state, consensus_state) = verification_states(ctx, client_id, proof_height)?;
+
+    membership(
+        &client_state,
+        prefix,
+        proof,
+        consensus_state.root(),
+        path,
+        value,
+    )?;
+
+    Ok(())
+}
+
+/// Verifies that no value is stored at the given path on the counterparty
+/// tracked by the given client, at the given proof height.
+///
+/// As in the handlers, the client must be active and the root is taken from
+/// its consensus state at the proof height.
+pub fn non_membership_at_height<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    proof_height: Height,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    path: impl Into<Path>,
+) -> Result<(), ContextError>
+where
+    V: ClientValidationContext,
+{
+    let (client_state, consensus_state) = verification_states(ctx, client_id, proof_height)?;
+
+    non_membership(&client_state, prefix, proof, consensus_state.root(), path)?;
+
+    Ok(())
+}
+
+/// Verifies that the given values are stored at their respective paths on the
+/// counterparty tracked by the given client, at the given proof height,
+/// against a single proof.
+///
+/// As in the handlers, the client must be active and the root is taken from
+/// its consensus state at the proof height.
+pub fn batch_membership_at_height<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    proof_height: Height,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    items: Vec<(Path, Vec<u8>)>,
+) -> Result<(), ContextError>
+where
+    V: ClientValidationContext,
+{
+    let (client_state, consensus_state) = verification_states(ctx, client_id, proof_height)?;
+
+    batch_membership(&client_state, prefix, proof, consensus_state.root(), items)?;
+
+    Ok(())
+}
+
+/// Verifies that the given value is stored at the given raw merkle path on
+/// the counterparty tracked by the given client, at the given proof height.
+pub fn merkle_membership_at_height<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    proof_height: Height,
+    proof: &CommitmentProofBytes,
+    merkle_path: MerklePath,
+    value: Vec<u8>,
+) -> Result<(), ContextError>
+where
+    V: ClientValidationContext,
+{
+    let (client_state, consensus_state) = verification_states(ctx, client_id, proof_height)?;
+
+    merkle_membership(
+        &client_state,
+        proof,
+        consensus_state.root(),
+        merkle_path,
+        value,
+    )?;
+
+    Ok(())
+}
+
+/// Verifies that no value is stored at the given raw merkle path on the
+/// counterparty tracked by the given client, at the given proof height.
+pub fn merkle_non_membership_at_height<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    proof_height: Height,
+    proof: &CommitmentProofBytes,
+    merkle_path: MerklePath,
+) -> Result<(), ContextError>
+where
+    V: ClientValidationContext,
+{
+    let (client_state, consensus_state) = verification_states(ctx, client_id, proof_height)?;
+
+    merkle_non_membership(&client_state, proof, consensus_state.root(), merkle_path)?;
+
+    Ok(())
+}
+
+/// Returns the client state and the consensus state at the proof height to
+/// verify proofs against, checking that the client is active.
+fn verification_states<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    proof_height: Height,
+) -> Result<(V::ClientStateRef, V::ConsensusStateRef), ContextError>
+where
+    V: ClientValidationContext,
+{
+    let client_state = ctx.client_state(client_id)?;
+
+    client_state.status(ctx, client_id)?.verify_is_active()?;
+
+    client_state.validate_proof_height(proof_height)?;
+
+    let consensus_state = ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        proof_height.revision_number(),
+        proof_height.revision_height(),
+    ))?;
+
+    Ok((client_state, consensus_state))
+}
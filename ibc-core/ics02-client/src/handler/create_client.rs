@@ -3,9 +3,10 @@
 use ibc_core_client_context::prelude::*;
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::events::CreateClient;
-use ibc_core_client_types::msgs::MsgCreateClient;
+use ibc_core_client_types::msgs::{MsgCreateClient, MsgCreateClientResponse};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::log::HandlerLog;
 use ibc_core_host::{ClientStateMut, ClientStateRef, ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
@@ -30,7 +31,15 @@ where
 
     let client_state = ClientStateRef::<Ctx>::try_from(client_state).map_err(Into::into)?;
 
-    let client_id = client_state.client_type().build_client_id(id_counter);
+    let client_type = client_state.client_type();
+
+    ctx.client_params()?.verify_is_allowed(&client_type)?;
+
+    let client_id = client_type.build_client_id(id_counter);
+
+    ctx.identifier_validation_profile()
+        .validate_client_identifier(client_id.as_str())
+        .map_err(ClientError::InvalidClientIdentifier)?;
 
     let status = client_state.status(client_val_ctx, &client_id)?;
 
@@ -50,7 +59,10 @@ where
     Ok(())
 }
 
-pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgCreateClient) -> Result<(), ContextError>
+pub fn execute<Ctx>(
+    ctx: &mut Ctx,
+    msg: MsgCreateClient,
+) -> Result<MsgCreateClientResponse, ContextError>
 where
     Ctx: ExecutionContext,
     <ClientStateMut<Ctx> as TryFrom<Any>>::Error: Into<ClientError>,
@@ -75,6 +87,13 @@ where
 
     ctx.increase_client_counter()?;
 
+    let host_timestamp = ctx.host_timestamp()?;
+    ctx.index_consensus_state_processed_time(
+        &client_id,
+        &client_state.latest_height(),
+        &host_timestamp,
+    )?;
+
     let event = IbcEvent::CreateClient(CreateClient::new(
         client_id.clone(),
         client_type,
@@ -83,9 +102,9 @@ where
     ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
     ctx.emit_ibc_event(event)?;
 
-    ctx.log_message(format!(
-        "success: generated new client identifier: {client_id}"
-    ))?;
+    ctx.log(HandlerLog::ClientCreated {
+        client_id: client_id.clone(),
+    })?;
 
-    Ok(())
+    Ok(MsgCreateClientResponse { client_id })
 }
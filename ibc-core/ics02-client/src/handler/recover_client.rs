@@ -2,8 +2,10 @@
 
 use ibc_core_client_context::prelude::*;
 use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::events::RecoverClient;
 use ibc_core_client_types::msgs::MsgRecoverClient;
 use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 
@@ -80,6 +82,9 @@ where
             substitute_client_state.latest_height().revision_height(),
         ))?;
 
+    let client_type = subject_client_state.client_type();
+    let latest_height = substitute_client_state.latest_height();
+
     subject_client_state.update_on_recovery(
         ctx.get_client_execution_context(),
         &subject_client_id,
@@ -87,5 +92,12 @@ where
         substitute_consensus_state.into(),
     )?;
 
+    let host_timestamp = ctx.host_timestamp()?;
+    ctx.index_consensus_state_processed_time(&subject_client_id, &latest_height, &host_timestamp)?;
+
+    let event = IbcEvent::RecoverClient(RecoverClient::new(subject_client_id, client_type));
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+    ctx.emit_ibc_event(event)?;
+
     Ok(())
 }
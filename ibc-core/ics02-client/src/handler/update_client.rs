@@ -17,7 +17,7 @@ where
 {
     ctx.validate_message_signer(msg.signer())?;
 
-    let client_id = msg.client_id().clone();
+    let (client_id, client_message) = msg.into_parts();
 
     let client_val_ctx = ctx.get_client_validation_context();
 
@@ -28,7 +28,11 @@ where
         .status(client_val_ctx, &client_id)?
         .verify_is_active()?;
 
-    let client_message = msg.client_message();
+    let meter = ctx.resource_meter();
+    meter.charge_header_decoding(client_message.value.len())?;
+    meter.charge_signature_verification(
+        client_state.client_message_signature_count(&client_message)?,
+    )?;
 
     client_state.verify_client_message(client_val_ctx, &client_id, client_message)?;
 
@@ -39,12 +43,11 @@ pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpdateOrMisbehaviour) -> Result<(), C
 where
     Ctx: ExecutionContext,
 {
-    let client_id = msg.client_id().clone();
     let update_kind = match msg {
         MsgUpdateOrMisbehaviour::UpdateClient(_) => UpdateKind::UpdateClient,
         MsgUpdateOrMisbehaviour::Misbehaviour(_) => UpdateKind::SubmitMisbehaviour,
     };
-    let client_message = msg.client_message();
+    let (client_id, client_message) = msg.into_parts();
 
     let client_exec_ctx = ctx.get_client_execution_context();
 
@@ -75,6 +78,20 @@ where
         let consensus_heights =
             client_state.update_state(client_exec_ctx, &client_id, header.clone())?;
 
+        {
+            let host_height = ctx.host_height()?;
+            let host_timestamp = ctx.host_timestamp()?;
+
+            for consensus_height in &consensus_heights {
+                ctx.index_client_update(&client_id, consensus_height, &host_height)?;
+                ctx.index_consensus_state_processed_time(
+                    &client_id,
+                    consensus_height,
+                    &host_timestamp,
+                )?;
+            }
+        }
+
         {
             let event = {
                 let consensus_height = consensus_heights.first().ok_or(ClientError::Other {
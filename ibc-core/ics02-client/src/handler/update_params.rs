@@ -0,0 +1,32 @@
+//! Protocol logic for processing ICS02 messages of type `MsgUpdateClientParams`.
+
+use ibc_core_client_types::msgs::MsgUpdateClientParams;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+
+/// Validates the update of the client parameters, which must be signed by the
+/// authority of the host and well-formed.
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgUpdateClientParams) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.validate_client_params_authority(&msg.signer)?;
+
+    msg.params.validate()?;
+
+    Ok(())
+}
+
+/// Stores the new client parameters, taking effect for the next clients
+/// created on the host. Existing clients are not affected.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpdateClientParams) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx.store_client_params(msg.params)?;
+
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+
+    Ok(())
+}
@@ -43,6 +43,10 @@ where
             height: old_client_state.latest_height(),
         })?;
 
+    let meter = ctx.resource_meter();
+    meter.charge_proof_verification(msg.proof_upgrade_client.as_bytes().len())?;
+    meter.charge_proof_verification(msg.proof_upgrade_consensus_state.as_bytes().len())?;
+
     // Validate the upgraded client state and consensus state and verify proofs against the root
     old_client_state.verify_upgrade_client(
         msg.upgraded_client_state.clone(),
@@ -72,6 +76,9 @@ where
         msg.upgraded_consensus_state,
     )?;
 
+    let host_timestamp = ctx.host_timestamp()?;
+    ctx.index_consensus_state_processed_time(&client_id, &latest_height, &host_timestamp)?;
+
     let event = IbcEvent::UpgradeClient(UpgradeClient::new(
         client_id,
         old_client_state.client_type(),
@@ -7,7 +7,7 @@ use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::{ErrorCode, Signer, Timestamp};
 
 use super::status::Status;
 use crate::height::Height;
@@ -103,10 +103,79 @@ pub enum ClientError {
     CounterOverflow,
     /// update client message did not contain valid header or misbehaviour
     InvalidUpdateClientMessage,
+    /// client type `{client_type}` is not in the allowed clients of the host
+    ClientTypeNotAllowed { client_type: ClientType },
+    /// invalid client params: `{reason}`
+    InvalidClientParams { reason: String },
+    /// signer `{signer}` is not the authority allowed to update the client params
+    UnauthorizedParamsUpdate { signer: Signer },
+    /// updating the client params is not supported by the host
+    ParamsUpdateNotSupported,
+    /// signer `{signer}` is not the authority allowed to recover clients
+    UnauthorizedClientRecovery { signer: Signer },
     /// other error: `{description}`
     Other { description: String },
 }
 
+impl ClientError {
+    /// Returns the stable error code of this error.
+    ///
+    /// Errors wrapping another IBC error report the code of the wrapped error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("client", code);
+
+        match self {
+            Self::Upgrade(e) => e.code(),
+            Self::ClientFrozen { .. } => code(1),
+            Self::ClientNotActive { .. } => code(2),
+            Self::ClientNotInactive { .. } => code(3),
+            Self::ClientStateNotFound { .. } => code(4),
+            Self::ClientStateAlreadyExists { .. } => code(5),
+            Self::ClientRecoveryHeightMismatch { .. } => code(6),
+            Self::ClientRecoveryStateMismatch => code(7),
+            Self::ConsensusStateNotFound { .. } => code(8),
+            Self::UpdateMetaDataNotFound { .. } => code(9),
+            Self::HeaderVerificationFailure { .. } => code(10),
+            Self::InvalidTrustThreshold { .. } => code(11),
+            Self::FailedTrustThresholdConversion { .. } => code(12),
+            Self::UnknownClientStateType { .. } => code(13),
+            Self::EmptyPrefix => code(14),
+            Self::UnknownConsensusStateType { .. } => code(15),
+            Self::UnknownHeaderType { .. } => code(16),
+            Self::UnknownMisbehaviourType { .. } => code(17),
+            Self::MissingRawClientState => code(18),
+            Self::MissingRawConsensusState => code(19),
+            Self::InvalidMsgUpdateClientId(_) => code(20),
+            Self::InvalidMsgRecoverClientId(_) => code(21),
+            Self::InvalidClientIdentifier(_) => code(22),
+            Self::InvalidRawHeader { .. } => code(23),
+            Self::MissingClientMessage => code(24),
+            Self::InvalidRawMisbehaviour(_) => code(25),
+            Self::MissingRawMisbehaviour => code(26),
+            Self::InvalidHeight => code(27),
+            Self::InvalidHeightResult => code(28),
+            Self::InvalidProofHeight { .. } => code(29),
+            Self::InvalidCommitmentProof(_) => code(30),
+            Self::InvalidPacketTimestamp(_) => code(31),
+            Self::ClientArgsTypeMismatch { .. } => code(32),
+            Self::InvalidConsensusStateTimestamp { .. } => code(33),
+            Self::MissingLocalConsensusState { .. } => code(34),
+            Self::InvalidSigner { .. } => code(35),
+            Self::Ics23Verification(_) => code(36),
+            Self::MisbehaviourHandlingFailure { .. } => code(37),
+            Self::ClientSpecific { .. } => code(38),
+            Self::CounterOverflow => code(39),
+            Self::InvalidUpdateClientMessage => code(40),
+            Self::Other { .. } => code(41),
+            Self::ClientTypeNotAllowed { .. } => code(42),
+            Self::InvalidClientParams { .. } => code(43),
+            Self::UnauthorizedParamsUpdate { .. } => code(44),
+            Self::ParamsUpdateNotSupported => code(45),
+            Self::UnauthorizedClientRecovery { .. } => code(46),
+        }
+    }
+}
+
 impl From<&'static str> for ClientError {
     fn from(s: &'static str) -> Self {
         Self::Other {
@@ -125,7 +194,9 @@ impl From<Infallible> for ClientError {
 impl std::error::Error for ClientError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match &self {
+            Self::Upgrade(e) => Some(e),
             Self::InvalidMsgUpdateClientId(e)
+            | Self::InvalidMsgRecoverClientId(e)
             | Self::InvalidClientIdentifier(e)
             | Self::InvalidRawMisbehaviour(e) => Some(e),
             Self::InvalidCommitmentProof(e) | Self::Ics23Verification(e) => Some(e),
@@ -155,6 +226,22 @@ pub enum UpgradeClientError {
     Other { reason: String },
 }
 
+impl UpgradeClientError {
+    /// Returns the stable error code of this error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("upgrade", code);
+
+        match self {
+            Self::InvalidUpgradeClientProof(_) => code(1),
+            Self::InvalidUpgradeConsensusStateProof(_) => code(2),
+            Self::LowUpgradeHeight { .. } => code(3),
+            Self::InvalidUpgradeProposal { .. } => code(4),
+            Self::InvalidUpgradePlan { .. } => code(5),
+            Self::Other { .. } => code(6),
+        }
+    }
+}
+
 impl From<UpgradeClientError> for ClientError {
     fn from(e: UpgradeClientError) -> Self {
         ClientError::Upgrade(e)
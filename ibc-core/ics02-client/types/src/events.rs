@@ -1,21 +1,28 @@
 //! Types for the IBC events emitted from Tendermint Websocket by the client module.
+use core::fmt::Write;
+
 use derive_more::From;
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_primitives::prelude::*;
-use subtle_encoding::hex;
+use ibc_primitives::utils::{encode_hex, format_with_capacity};
 use tendermint::abci;
 
-use crate::height::Height;
+use crate::height::{Height, HEIGHT_STR_CAPACITY};
 
 /// Client event types
 pub const CREATE_CLIENT_EVENT: &str = "create_client";
 pub const UPDATE_CLIENT_EVENT: &str = "update_client";
 pub const CLIENT_MISBEHAVIOUR_EVENT: &str = "client_misbehaviour";
 pub const UPGRADE_CLIENT_EVENT: &str = "upgrade_client";
+pub const RECOVER_CLIENT_EVENT: &str = "recover_client";
+pub const CLIENT_EXPIRED_EVENT: &str = "client_expired";
 
 /// The content of the `key` field for the attribute containing the client identifier.
 pub const CLIENT_ID_ATTRIBUTE_KEY: &str = "client_id";
 
+/// The content of the `key` field for the attribute containing the identifier of a recovered client.
+pub const SUBJECT_CLIENT_ID_ATTRIBUTE_KEY: &str = "subject_client_id";
+
 /// The content of the `key` field for the attribute containing the client type.
 pub const CLIENT_TYPE_ATTRIBUTE_KEY: &str = "client_type";
 
@@ -52,6 +59,34 @@ impl From<ClientIdAttribute> for abci::EventAttribute {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct SubjectClientIdAttribute {
+    subject_client_id: ClientId,
+}
+
+impl From<SubjectClientIdAttribute> for abci::EventAttribute {
+    fn from(attr: SubjectClientIdAttribute) -> Self {
+        (
+            SUBJECT_CLIENT_ID_ATTRIBUTE_KEY,
+            attr.subject_client_id.as_str(),
+        )
+            .into()
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -96,7 +131,11 @@ struct ConsensusHeightAttribute {
 
 impl From<ConsensusHeightAttribute> for abci::EventAttribute {
     fn from(attr: ConsensusHeightAttribute) -> Self {
-        (CONSENSUS_HEIGHT_ATTRIBUTE_KEY, attr.consensus_height).into()
+        (
+            CONSENSUS_HEIGHT_ATTRIBUTE_KEY,
+            format_with_capacity(attr.consensus_height, HEIGHT_STR_CAPACITY),
+        )
+            .into()
     }
 }
 
@@ -120,12 +159,16 @@ struct ConsensusHeightsAttribute {
 
 impl From<ConsensusHeightsAttribute> for abci::EventAttribute {
     fn from(attr: ConsensusHeightsAttribute) -> Self {
-        let consensus_heights: Vec<String> = attr
-            .consensus_heights
-            .into_iter()
-            .map(|consensus_height| consensus_height.to_string())
-            .collect();
-        (CONSENSUS_HEIGHTS_ATTRIBUTE_KEY, consensus_heights.join(",")).into()
+        let mut consensus_heights =
+            String::with_capacity(attr.consensus_heights.len() * HEIGHT_STR_CAPACITY);
+        for (i, consensus_height) in attr.consensus_heights.iter().enumerate() {
+            if i > 0 {
+                consensus_heights.push(',');
+            }
+            // Writing into a `String` never fails.
+            let _ = write!(consensus_heights, "{consensus_height}");
+        }
+        (CONSENSUS_HEIGHTS_ATTRIBUTE_KEY, consensus_heights).into()
     }
 }
 
@@ -151,12 +194,7 @@ struct HeaderAttribute {
 
 impl From<HeaderAttribute> for abci::EventAttribute {
     fn from(attr: HeaderAttribute) -> Self {
-        (
-            HEADER_ATTRIBUTE_KEY,
-            str::from_utf8(&hex::encode(attr.header))
-                .expect("Never fails because hexadecimal is valid UTF-8"),
-        )
-            .into()
+        (HEADER_ATTRIBUTE_KEY, encode_hex(&attr.header)).into()
     }
 }
 
@@ -416,3 +454,104 @@ impl From<UpgradeClient> for abci::Event {
         }
     }
 }
+
+/// Signals a recent recovery of an on-chain client (IBC Client) from a substitute client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecoverClient {
+    subject_client_id: SubjectClientIdAttribute,
+    client_type: ClientTypeAttribute,
+}
+
+impl RecoverClient {
+    pub fn new(subject_client_id: ClientId, client_type: ClientType) -> Self {
+        Self {
+            subject_client_id: SubjectClientIdAttribute::from(subject_client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+        }
+    }
+
+    pub fn subject_client_id(&self) -> &ClientId {
+        &self.subject_client_id.subject_client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn event_type(&self) -> &str {
+        RECOVER_CLIENT_EVENT
+    }
+}
+
+impl From<RecoverClient> for abci::Event {
+    fn from(r: RecoverClient) -> Self {
+        Self {
+            kind: RECOVER_CLIENT_EVENT.to_owned(),
+            attributes: vec![r.subject_client_id.into(), r.client_type.into()],
+        }
+    }
+}
+
+/// Signals that an on-chain client (IBC Client) expired, and must be recovered
+/// from a substitute client to be used again.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientExpired {
+    client_id: ClientIdAttribute,
+    client_type: ClientTypeAttribute,
+}
+
+impl ClientExpired {
+    pub fn new(client_id: ClientId, client_type: ClientType) -> Self {
+        Self {
+            client_id: ClientIdAttribute::from(client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id.client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn event_type(&self) -> &str {
+        CLIENT_EXPIRED_EVENT
+    }
+}
+
+impl From<ClientExpired> for abci::Event {
+    fn from(c: ClientExpired) -> Self {
+        Self {
+            kind: CLIENT_EXPIRED_EVENT.to_owned(),
+            attributes: vec![c.client_id.into(), c.client_type.into()],
+        }
+    }
+}
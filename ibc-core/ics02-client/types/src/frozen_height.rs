@@ -0,0 +1,165 @@
+//! Defines the `FrozenHeight` type tracking whether a client is frozen
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+use crate::error::ClientError;
+use crate::height::Height;
+
+/// The height at which a client has been frozen, if any.
+///
+/// A frozen client can neither be updated nor used to verify proofs until it
+/// is recovered. When encoded, a height of zero means the client is not
+/// frozen, following `ibc-go`. Misbehaviour freezes clients at the
+/// [`SENTINEL`](Self::SENTINEL) height rather than at the height of the
+/// conflicting headers, as any non-zero height is equally frozen.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        parity_scale_codec::MaxEncodedLen,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FrozenHeight(Option<Height>);
+
+impl FrozenHeight {
+    /// The frozen height of an active client.
+    pub const UNFROZEN: Self = Self(None);
+
+    /// The height clients are frozen at upon misbehaviour, i.e. revision 0 at
+    /// height 1.
+    pub const SENTINEL_HEIGHT: Height = Height::min(0);
+
+    /// The frozen height set upon misbehaviour.
+    pub const SENTINEL: Self = Self(Some(Self::SENTINEL_HEIGHT));
+
+    /// Returns the frozen height of a client frozen at the given height.
+    pub fn at(height: Height) -> Self {
+        Self(Some(height))
+    }
+
+    /// Returns the height the client has been frozen at, or `None` if it is
+    /// not frozen.
+    pub fn height(&self) -> Option<Height> {
+        self.0
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Checks whether the client has been frozen at the sentinel height, as
+    /// done upon misbehaviour.
+    pub fn is_sentinel(&self) -> bool {
+        *self == Self::SENTINEL
+    }
+
+    /// Freezes the client at the given height.
+    pub fn freeze(&mut self, height: Height) {
+        self.0 = Some(height);
+    }
+
+    /// Unfreezes the client, as done upon recovery.
+    pub fn unfreeze(&mut self) {
+        self.0 = None;
+    }
+
+    /// Checks that the client is not frozen; returns `Err` otherwise.
+    pub fn verify_not_frozen(&self) -> Result<(), ClientError> {
+        match self.0 {
+            None => Ok(()),
+            Some(height) => Err(ClientError::ClientFrozen {
+                description: format!("the client is frozen at height {height}"),
+            }),
+        }
+    }
+}
+
+impl From<Option<Height>> for FrozenHeight {
+    fn from(height: Option<Height>) -> Self {
+        Self(height)
+    }
+}
+
+impl From<FrozenHeight> for Option<Height> {
+    fn from(frozen_height: FrozenHeight) -> Self {
+        frozen_height.0
+    }
+}
+
+/// A raw height with a zero revision height decodes to an unfrozen client.
+impl From<RawHeight> for FrozenHeight {
+    fn from(raw_height: RawHeight) -> Self {
+        Self(Height::try_from(raw_height).ok())
+    }
+}
+
+/// An unfrozen client encodes to a raw height of zero.
+impl From<FrozenHeight> for RawHeight {
+    fn from(frozen_height: FrozenHeight) -> Self {
+        frozen_height.0.map(Into::into).unwrap_or(RawHeight {
+            revision_number: 0,
+            revision_height: 0,
+        })
+    }
+}
+
+impl Display for FrozenHeight {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self.0 {
+            None => write!(f, "not frozen"),
+            Some(height) => write!(f, "frozen at {height}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frozen_height_raw_roundtrip() {
+        let raw_unfrozen = RawHeight::from(FrozenHeight::UNFROZEN);
+        assert_eq!(raw_unfrozen.revision_number, 0);
+        assert_eq!(raw_unfrozen.revision_height, 0);
+        assert_eq!(FrozenHeight::from(raw_unfrozen), FrozenHeight::UNFROZEN);
+
+        let raw_sentinel = RawHeight::from(FrozenHeight::SENTINEL);
+        assert_eq!(raw_sentinel.revision_number, 0);
+        assert_eq!(raw_sentinel.revision_height, 1);
+        assert!(FrozenHeight::from(raw_sentinel).is_sentinel());
+    }
+
+    #[test]
+    fn test_freeze_and_unfreeze() {
+        let mut frozen_height = FrozenHeight::default();
+        assert!(frozen_height.verify_not_frozen().is_ok());
+
+        let height = Height::new(1, 10).expect("valid height");
+        frozen_height.freeze(height);
+        assert_eq!(frozen_height.height(), Some(height));
+        assert!(!frozen_height.is_sentinel());
+        assert!(matches!(
+            frozen_height.verify_not_frozen(),
+            Err(ClientError::ClientFrozen { .. })
+        ));
+
+        frozen_height.unfreeze();
+        assert!(!frozen_height.is_frozen());
+    }
+}
@@ -11,6 +11,11 @@ use ibc_proto::Protobuf;
 
 use crate::error::ClientError;
 
+/// Fits the string representation of the usual heights, e.g. when formatting
+/// them into event attributes, so that formatting them takes a single
+/// allocation.
+pub const HEIGHT_STR_CAPACITY: usize = 24;
+
 /// The core IBC height type, which represents the height of a chain,
 /// which typically is the number of blocks since genesis
 /// (or more generally, since the last revision/hard upgrade).
@@ -19,6 +24,7 @@ use crate::error::ClientError;
     derive(
         parity_scale_codec::Encode,
         parity_scale_codec::Decode,
+        parity_scale_codec::MaxEncodedLen,
         scale_info::TypeInfo
     )
 )]
@@ -49,7 +55,7 @@ impl Height {
         })
     }
 
-    pub fn min(revision_number: u64) -> Self {
+    pub const fn min(revision_number: u64) -> Self {
         Self {
             revision_number,
             revision_height: 1,
@@ -64,10 +70,11 @@ impl Height {
         self.revision_height
     }
 
+    /// Adds `delta` to the revision height, saturating at `u64::MAX`.
     pub fn add(&self, delta: u64) -> Height {
         Height {
             revision_number: self.revision_number,
-            revision_height: self.revision_height + delta,
+            revision_height: self.revision_height.saturating_add(delta),
         }
     }
 
@@ -76,18 +83,43 @@ impl Height {
     }
 
     pub fn sub(&self, delta: u64) -> Result<Height, ClientError> {
-        if self.revision_height <= delta {
-            return Err(ClientError::InvalidHeightResult);
-        }
+        self.checked_sub(delta)
+            .ok_or(ClientError::InvalidHeightResult)
+    }
 
-        Ok(Height {
+    pub fn decrement(&self) -> Result<Height, ClientError> {
+        self.sub(1)
+    }
+
+    /// Adds `delta` to the revision height, returning `None` on overflow.
+    pub fn checked_add(&self, delta: u64) -> Option<Height> {
+        Some(Height {
             revision_number: self.revision_number,
-            revision_height: self.revision_height - delta,
+            revision_height: self.revision_height.checked_add(delta)?,
         })
     }
 
-    pub fn decrement(&self) -> Result<Height, ClientError> {
-        self.sub(1)
+    /// Subtracts `delta` from the revision height, returning `None` if the
+    /// result is not a valid (non-zero) height.
+    pub fn checked_sub(&self, delta: u64) -> Option<Height> {
+        let revision_height = self.revision_height.checked_sub(delta)?;
+
+        (revision_height != 0).then_some(Height {
+            revision_number: self.revision_number,
+            revision_height,
+        })
+    }
+
+    /// Checks whether both heights belong to the same revision.
+    pub fn is_same_revision(&self, other: &Height) -> bool {
+        self.revision_number == other.revision_number
+    }
+
+    /// Returns the number of blocks between the two heights, or `None` if
+    /// they belong to different revisions.
+    pub fn distance(&self, other: &Height) -> Option<u64> {
+        self.is_same_revision(other)
+            .then(|| self.revision_height.abs_diff(other.revision_height))
     }
 }
 
@@ -258,3 +290,43 @@ fn test_invalid_height() {
         })
     );
 }
+
+#[test]
+fn test_add_saturates() {
+    let height = Height::new(1, u64::MAX - 1).unwrap();
+
+    assert_eq!(height.increment().revision_height(), u64::MAX);
+    assert_eq!(height.add(u64::MAX).revision_height(), u64::MAX);
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let height = Height::new(1, 10).unwrap();
+
+    assert_eq!(height.checked_add(5), Some(Height::new(1, 15).unwrap()));
+    assert_eq!(height.checked_add(u64::MAX), None);
+    assert_eq!(height.checked_sub(9), Some(Height::new(1, 1).unwrap()));
+    assert_eq!(height.checked_sub(10), None);
+    assert_eq!(height.checked_sub(11), None);
+}
+
+#[test]
+fn test_distance() {
+    let height = Height::new(1, 10).unwrap();
+
+    assert_eq!(height.distance(&Height::new(1, 4).unwrap()), Some(6));
+    assert_eq!(Height::new(1, 4).unwrap().distance(&height), Some(6));
+    assert_eq!(height.distance(&Height::new(2, 10).unwrap()), None);
+}
+
+#[cfg(feature = "parity-scale-codec")]
+#[test]
+fn test_height_parity_scale_codec_roundtrip() {
+    use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+
+    let height = Height::new(u64::MAX, u64::MAX).unwrap();
+    let bytes = height.encode();
+
+    assert_eq!(bytes.len(), Height::max_encoded_len());
+    assert_eq!(Height::decode(&mut bytes.as_slice()).unwrap(), height);
+}
@@ -1,7 +1,7 @@
 //! Implementation of the Client Semantics (ICS-02) data structures.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
 #![deny(
     warnings,
@@ -16,12 +16,17 @@ extern crate std;
 
 pub mod error;
 pub mod events;
+mod frozen_height;
 mod height;
 pub mod msgs;
+pub mod params;
 mod status;
+mod update_meta;
 
+pub use frozen_height::*;
 pub use height::*;
 pub use status::*;
+pub use update_meta::*;
 
 /// Re-exports ICS-02 proto types from the `ibc-proto` crate for added convenience.
 pub mod proto {
@@ -1,5 +1,6 @@
 //! Definition of domain type message `MsgCreateClient`.
 
+use ibc_core_host_types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Signer;
 use ibc_proto::google::protobuf::Any;
@@ -23,6 +24,18 @@ pub struct MsgCreateClient {
     pub signer: Signer,
 }
 
+/// The response to a [`MsgCreateClient`], carrying the identifier the host
+/// allocated to the created client.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgCreateClientResponse {
+    pub client_id: ClientId,
+}
+
 impl MsgCreateClient {
     pub fn new(client_state: Any, consensus_state: Any, signer: Signer) -> Self {
         MsgCreateClient {
@@ -11,12 +11,14 @@ mod create_client;
 mod misbehaviour;
 mod recover_client;
 mod update_client;
+mod update_params;
 mod upgrade_client;
 
 pub use create_client::*;
 pub use misbehaviour::*;
 pub use recover_client::*;
 pub use update_client::*;
+pub use update_params::*;
 pub use upgrade_client::*;
 
 /// Encodes all the different client messages
@@ -55,6 +57,15 @@ impl MsgUpdateOrMisbehaviour {
         }
     }
 
+    /// Splits the message into its client identifier and client message,
+    /// sparing a clone of the identifier to callers that need both.
+    pub fn into_parts(self) -> (ClientId, Any) {
+        match self {
+            MsgUpdateOrMisbehaviour::UpdateClient(msg) => (msg.client_id, msg.client_message),
+            MsgUpdateOrMisbehaviour::Misbehaviour(msg) => (msg.client_id, msg.misbehaviour),
+        }
+    }
+
     pub fn signer(&self) -> &Signer {
         match self {
             MsgUpdateOrMisbehaviour::UpdateClient(msg) => &msg.signer,
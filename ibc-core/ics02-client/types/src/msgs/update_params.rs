@@ -0,0 +1,62 @@
+//! Definition of domain type message `MsgUpdateClientParams`.
+
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::client::v1::MsgUpdateParams as RawMsgUpdateParams;
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+use crate::params::ClientParams;
+
+pub const UPDATE_CLIENT_PARAMS_TYPE_URL: &str = "/ibc.core.client.v1.MsgUpdateParams";
+
+/// Defines the message used to update the parameters of the client module,
+/// e.g. the client types allowed to be created.
+///
+/// As with [`MsgRecoverClient`](crate::msgs::MsgRecoverClient), the message
+/// is meant to be submitted by the authority of the host, e.g. its governance
+/// module, hence it is not part of the messages dispatched by ibc-rs.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgUpdateClientParams {
+    /// The address of the signer who serves as the authority for the IBC
+    /// module.
+    pub signer: Signer,
+    /// The new parameters of the client module.
+    pub params: ClientParams,
+}
+
+impl Protobuf<RawMsgUpdateParams> for MsgUpdateClientParams {}
+
+impl TryFrom<RawMsgUpdateParams> for MsgUpdateClientParams {
+    type Error = ClientError;
+
+    fn try_from(raw: RawMsgUpdateParams) -> Result<Self, Self::Error> {
+        let params: ClientParams = raw
+            .params
+            .ok_or(ClientError::InvalidClientParams {
+                reason: "missing params".to_string(),
+            })?
+            .into();
+
+        params.validate()?;
+
+        Ok(MsgUpdateClientParams {
+            signer: raw.signer.into(),
+            params,
+        })
+    }
+}
+
+impl From<MsgUpdateClientParams> for RawMsgUpdateParams {
+    fn from(ics_msg: MsgUpdateClientParams) -> Self {
+        RawMsgUpdateParams {
+            signer: ics_msg.signer.to_string(),
+            params: Some(ics_msg.params.into()),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Defines the parameters of the client module.
+
+use ibc_core_host_types::identifiers::ClientType;
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Params as RawParams;
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+
+/// The entry of the allowed clients allowing any client type to be created.
+pub const ALLOW_ALL_CLIENTS: &str = "*";
+
+/// The parameters of the client module, restricting the types of the clients
+/// which can be created on the host, as in ibc-go.
+///
+/// Any client type is allowed by default.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientParams {
+    /// The client types allowed to be created, where [`ALLOW_ALL_CLIENTS`]
+    /// allows any client type.
+    pub allowed_clients: Vec<String>,
+}
+
+impl ClientParams {
+    pub fn new(allowed_clients: Vec<String>) -> Self {
+        Self { allowed_clients }
+    }
+
+    /// Returns true if clients of the given type may be created.
+    pub fn is_allowed(&self, client_type: &ClientType) -> bool {
+        self.allowed_clients
+            .iter()
+            .any(|allowed| allowed == ALLOW_ALL_CLIENTS || allowed == client_type.as_str())
+    }
+
+    /// Returns an error if clients of the given type may not be created.
+    pub fn verify_is_allowed(&self, client_type: &ClientType) -> Result<(), ClientError> {
+        if !self.is_allowed(client_type) {
+            return Err(ClientError::ClientTypeNotAllowed {
+                client_type: client_type.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that the allowed clients are neither blank nor repeated, and
+    /// that the wildcard, if present, is the only entry.
+    pub fn validate(&self) -> Result<(), ClientError> {
+        for (i, allowed) in self.allowed_clients.iter().enumerate() {
+            if allowed.trim().is_empty() {
+                return Err(ClientError::InvalidClientParams {
+                    reason: "allowed client type cannot be blank".to_string(),
+                });
+            }
+            if self.allowed_clients[..i].contains(allowed) {
+                return Err(ClientError::InvalidClientParams {
+                    reason: format!("duplicate allowed client type: {allowed}"),
+                });
+            }
+        }
+
+        if self.allowed_clients.len() > 1
+            && self
+                .allowed_clients
+                .iter()
+                .any(|allowed| allowed == ALLOW_ALL_CLIENTS)
+        {
+            return Err(ClientError::InvalidClientParams {
+                reason: format!("{ALLOW_ALL_CLIENTS} must be the only allowed client type"),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for ClientParams {
+    fn default() -> Self {
+        Self::new(vec![ALLOW_ALL_CLIENTS.to_string()])
+    }
+}
+
+impl From<RawParams> for ClientParams {
+    fn from(raw: RawParams) -> Self {
+        Self::new(raw.allowed_clients)
+    }
+}
+
+impl From<ClientParams> for RawParams {
+    fn from(params: ClientParams) -> Self {
+        RawParams {
+            allowed_clients: params.allowed_clients,
+        }
+    }
+}
+
+impl Protobuf<RawParams> for ClientParams {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(allowed_clients: &[&str]) -> ClientParams {
+        ClientParams::new(allowed_clients.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn test_allowed_clients() {
+        let tendermint = ClientType::new("07-tendermint").unwrap();
+        let mock = ClientType::new("9999-mock").unwrap();
+
+        assert!(ClientParams::default().is_allowed(&tendermint));
+        assert!(ClientParams::default().is_allowed(&mock));
+
+        let restricted = params(&["07-tendermint"]);
+        assert!(restricted.is_allowed(&tendermint));
+        assert!(matches!(
+            restricted.verify_is_allowed(&mock),
+            Err(ClientError::ClientTypeNotAllowed { .. })
+        ));
+
+        assert!(!params(&[]).is_allowed(&tendermint));
+    }
+
+    #[test]
+    fn test_validate_params() {
+        assert!(ClientParams::default().validate().is_ok());
+        assert!(params(&[]).validate().is_ok());
+        assert!(params(&["07-tendermint", "06-solomachine"])
+            .validate()
+            .is_ok());
+
+        assert!(params(&[" "]).validate().is_err());
+        assert!(params(&["07-tendermint", "07-tendermint"])
+            .validate()
+            .is_err());
+        assert!(params(&["*", "07-tendermint"]).validate().is_err());
+    }
+}
@@ -0,0 +1,26 @@
+use ibc_primitives::Timestamp;
+
+use crate::Height;
+
+/// The time and height of the host at which it processed the update of a
+/// client to a given consensus height.
+///
+/// The verification of the delay period of a connection, as well as the
+/// pruning of expired consensus states, rely on this metadata, which the
+/// clients record along with each consensus state they store.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UpdateMeta {
+    pub processed_time: Timestamp,
+    pub processed_height: Height,
+}
+
+impl UpdateMeta {
+    pub fn new(processed_time: Timestamp, processed_height: Height) -> Self {
+        Self {
+            processed_time,
+            processed_height,
+        }
+    }
+}
@@ -1,10 +1,25 @@
+//! Enforcement of the delay period of a connection on the proofs of the
+//! counterparty, as computed by [`ibc_core_host::delay`].
+
+use core::time::Duration;
+
 use ibc_core_client::context::ClientValidationContext;
 use ibc_core_client::types::Height;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::delay::{earliest_valid_height, earliest_valid_time};
 use ibc_core_host::ValidationContext;
+use ibc_primitives::Timestamp;
 
+/// Verifies that the delay period of the connection has elapsed on the host
+/// since its client was updated to the height of the packet proof.
+///
+/// Called by all the packet handlers verifying a proof of the counterparty,
+/// i.e. on receiving, acknowledging and timing out packets.
+///
+/// The `connection-localhost` sentinel has no delay period, and its client is
+/// never updated, so there is nothing to verify on it.
 pub fn verify_conn_delay_passed<Ctx>(
     ctx: &Ctx,
     packet_proof_height: Height,
@@ -13,13 +28,17 @@ pub fn verify_conn_delay_passed<Ctx>(
 where
     Ctx: ValidationContext,
 {
+    if connection_end.is_localhost() {
+        return Ok(());
+    }
+
     // Fetch the current host chain time and height.
     let current_host_time = ctx.host_timestamp()?;
     let current_host_height = ctx.host_height()?;
 
     // Fetch the latest time and height that the counterparty client was updated on the host chain.
     let client_id = connection_end.client_id();
-    let last_client_update = ctx
+    let update_meta = ctx
         .get_client_validation_context()
         .client_update_meta(client_id, &packet_proof_height)?;
 
@@ -27,28 +46,109 @@ where
     let conn_delay_time_period = connection_end.delay_period();
     let conn_delay_height_period = ctx.block_delay(&conn_delay_time_period);
 
+    verify_delay_passed(
+        current_host_time,
+        current_host_height,
+        update_meta.processed_time,
+        update_meta.processed_height,
+        conn_delay_time_period,
+        conn_delay_height_period,
+    )?;
+
+    Ok(())
+}
+
+/// Verifies that both the time and the number of blocks elapsed on the host
+/// since the given processed time and height exceed the delay period.
+pub fn verify_delay_passed(
+    current_host_time: Timestamp,
+    current_host_height: Height,
+    processed_time: Timestamp,
+    processed_height: Height,
+    delay_period_time: Duration,
+    delay_period_blocks: u64,
+) -> Result<(), ConnectionError> {
     // Verify that the current host chain time is later than the last client update time
-    let earliest_valid_time = (last_client_update.0 + conn_delay_time_period)
+    let earliest_valid_time = earliest_valid_time(processed_time, delay_period_time)
         .map_err(ConnectionError::TimestampOverflow)?;
     if current_host_time < earliest_valid_time {
-        return Err(ContextError::ConnectionError(
-            ConnectionError::NotEnoughTimeElapsed {
-                current_host_time,
-                earliest_valid_time,
-            },
-        ));
+        return Err(ConnectionError::NotEnoughTimeElapsed {
+            current_host_time,
+            earliest_valid_time,
+        });
     }
 
     // Verify that the current host chain height is later than the last client update height
-    let earliest_valid_height = last_client_update.1.add(conn_delay_height_period);
+    let earliest_valid_height = earliest_valid_height(processed_height, delay_period_blocks);
     if current_host_height < earliest_valid_height {
-        return Err(ContextError::ConnectionError(
-            ConnectionError::NotEnoughBlocksElapsed {
-                current_host_height,
-                earliest_valid_height,
-            },
-        ));
+        return Err(ConnectionError::NotEnoughBlocksElapsed {
+            current_host_height,
+            earliest_valid_height,
+        });
     };
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timestamp(secs: u64) -> Timestamp {
+        Timestamp::from_nanoseconds(secs * 1_000_000_000).unwrap()
+    }
+
+    fn height(revision_height: u64) -> Height {
+        Height::new(0, revision_height).unwrap()
+    }
+
+    #[test]
+    fn test_verify_delay_passed() {
+        let delay = Duration::from_secs(10);
+
+        // Both the time and the blocks elapsed, at the boundary.
+        assert!(verify_delay_passed(
+            timestamp(110),
+            height(15),
+            timestamp(100),
+            height(10),
+            delay,
+            5
+        )
+        .is_ok());
+
+        assert!(matches!(
+            verify_delay_passed(
+                timestamp(109),
+                height(15),
+                timestamp(100),
+                height(10),
+                delay,
+                5
+            ),
+            Err(ConnectionError::NotEnoughTimeElapsed { .. })
+        ));
+        assert!(matches!(
+            verify_delay_passed(
+                timestamp(110),
+                height(14),
+                timestamp(100),
+                height(10),
+                delay,
+                5
+            ),
+            Err(ConnectionError::NotEnoughBlocksElapsed { .. })
+        ));
+
+        // A zero delay period is always elapsed.
+        assert!(verify_delay_passed(
+            timestamp(100),
+            height(10),
+            timestamp(100),
+            height(10),
+            Duration::ZERO,
+            0
+        )
+        .is_ok());
+    }
+}
@@ -1,20 +1,25 @@
 //! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenAck`.
 
 use ibc_core_client::context::prelude::*;
+use ibc_core_client::context::verify::MsgProof;
 use ibc_core_client::types::error::ClientError;
+use ibc_core_connection_types::commitment::compute_connection_commitment;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenAck;
 use ibc_core_connection_types::msgs::MsgConnectionOpenAck;
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::log::HandlerLog;
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::proto::Any;
 use ibc_primitives::ToVec;
 
+use super::conn_end_verification_error;
+
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -92,17 +97,34 @@ where
                 vars.conn_end_on_a.delay_period(),
             )?;
 
-            client_state_of_b_on_a
+            ctx_a
+                .resource_meter()
+                .charge_proof_verification(msg.proof_conn_end_on_b.as_bytes().len())?;
+
+            let proof_conn_end_on_b = MsgProof::from(&msg.proof_conn_end_on_b);
+
+            proof_conn_end_on_b
                 .verify_membership(
+                    &client_state_of_b_on_a,
                     prefix_on_b,
-                    &msg.proof_conn_end_on_b,
                     consensus_state_of_b_on_a.root(),
                     Path::Connection(ConnectionPath::new(&msg.conn_id_on_b)),
-                    expected_conn_end_on_b.encode_vec(),
+                    compute_connection_commitment(&expected_conn_end_on_b),
                 )
-                .map_err(ConnectionError::VerifyConnectionState)?;
+                .map_err(|e| {
+                    conn_end_verification_error(
+                        &msg.conn_id_on_b,
+                        &expected_conn_end_on_b,
+                        proof_conn_end_on_b.proved_value(),
+                        e,
+                    )
+                })?;
         }
 
+        ctx_a
+            .resource_meter()
+            .charge_proof_verification(msg.proof_client_state_of_a_on_b.as_bytes().len())?;
+
         client_state_of_b_on_a
             .verify_membership(
                 prefix_on_b,
@@ -125,6 +147,10 @@ where
             msg.consensus_height_of_a_on_b.revision_height(),
         );
 
+        ctx_a
+            .resource_meter()
+            .charge_proof_verification(msg.proof_consensus_state_of_a_on_b.as_bytes().len())?;
+
         client_state_of_b_on_a
             .verify_membership(
                 prefix_on_b,
@@ -167,7 +193,9 @@ where
     ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
     ctx_a.emit_ibc_event(event)?;
 
-    ctx_a.log_message("success: conn_open_ack verification passed".to_string())?;
+    ctx_a.log(HandlerLog::ConnectionOpenAck {
+        conn_id_on_a: msg.conn_id_on_a.clone(),
+    })?;
 
     {
         let new_conn_end_on_a = {
@@ -1,17 +1,21 @@
 //! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenConfirm`.
 
 use ibc_core_client::context::prelude::*;
+use ibc_core_client::context::verify::MsgProof;
+use ibc_core_connection_types::commitment::compute_connection_commitment;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenConfirm;
 use ibc_core_connection_types::msgs::MsgConnectionOpenConfirm;
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::log::HandlerLog;
 use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
 use ibc_core_host::types::path::{ClientConsensusStatePath, ConnectionPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
+
+use super::conn_end_verification_error;
 
 pub fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgConnectionOpenConfirm) -> Result<(), ContextError>
 where
@@ -73,15 +77,28 @@ where
             conn_end_on_b.delay_period(),
         )?;
 
-        client_state_of_a_on_b
+        ctx_b
+            .resource_meter()
+            .charge_proof_verification(msg.proof_conn_end_on_a.as_bytes().len())?;
+
+        let proof_conn_end_on_a = MsgProof::from(&msg.proof_conn_end_on_a);
+
+        proof_conn_end_on_a
             .verify_membership(
+                &client_state_of_a_on_b,
                 prefix_on_a,
-                &msg.proof_conn_end_on_a,
                 consensus_state_of_a_on_b.root(),
                 Path::Connection(ConnectionPath::new(conn_id_on_a)),
-                expected_conn_end_on_a.encode_vec(),
+                compute_connection_commitment(&expected_conn_end_on_a),
             )
-            .map_err(ConnectionError::VerifyConnectionState)?;
+            .map_err(|e| {
+                conn_end_verification_error(
+                    conn_id_on_a,
+                    &expected_conn_end_on_a,
+                    proof_conn_end_on_a.proved_value(),
+                    e,
+                )
+            })?;
     }
 
     Ok(())
@@ -115,7 +132,9 @@ where
     ));
     ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
     ctx_b.emit_ibc_event(event)?;
-    ctx_b.log_message("success: conn_open_confirm verification passed".to_string())?;
+    ctx_b.log(HandlerLog::ConnectionOpenConfirm {
+        conn_id_on_b: msg.conn_id_on_b.clone(),
+    })?;
 
     {
         let new_conn_end_on_b = {
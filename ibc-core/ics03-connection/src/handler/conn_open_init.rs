@@ -1,21 +1,27 @@
 //! Protocol logic specific to ICS3 messages of type `MsgConnectionOpenInit`.
 use ibc_core_client::context::prelude::*;
+use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenInit;
-use ibc_core_connection_types::msgs::MsgConnectionOpenInit;
+use ibc_core_connection_types::msgs::{MsgConnectionOpenInit, MsgConnectionOpenInitResponse};
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::log::HandlerLog;
 use ibc_core_host::types::identifiers::ConnectionId;
 use ibc_core_host::types::path::{ClientConnectionPath, ConnectionPath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 
+use crate::localhost::verify_not_localhost;
+
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenInit) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
+    verify_not_localhost(&msg.client_id_on_a)?;
+
     let client_val_ctx_a = ctx_a.get_client_validation_context();
 
     // An IBC client running on the local (host) chain should exist.
@@ -29,10 +35,19 @@ where
         version.verify_is_supported(&ctx_a.get_compatible_versions())?;
     }
 
+    let conn_id_on_a = ConnectionId::new(ctx_a.connection_counter()?);
+    ctx_a
+        .identifier_validation_profile()
+        .validate_connection_identifier(conn_id_on_a.as_str())
+        .map_err(ConnectionError::InvalidIdentifier)?;
+
     Ok(())
 }
 
-pub fn execute<Ctx>(ctx_a: &mut Ctx, msg: MsgConnectionOpenInit) -> Result<(), ContextError>
+pub fn execute<Ctx>(
+    ctx_a: &mut Ctx,
+    msg: MsgConnectionOpenInit,
+) -> Result<MsgConnectionOpenInitResponse, ContextError>
 where
     Ctx: ExecutionContext,
 {
@@ -58,9 +73,9 @@ where
     // Construct the identifier for the new connection.
     let conn_id_on_a = ConnectionId::new(ctx_a.connection_counter()?);
 
-    ctx_a.log_message(format!(
-        "success: conn_open_init: generated new connection identifier: {conn_id_on_a}"
-    ))?;
+    ctx_a.log(HandlerLog::ConnectionOpenInit {
+        conn_id_on_a: conn_id_on_a.clone(),
+    })?;
 
     {
         let client_id_on_b = msg.counterparty.client_id().clone();
@@ -81,5 +96,5 @@ where
     )?;
     ctx_a.store_connection(&ConnectionPath::new(&conn_id_on_a), conn_end_on_a)?;
 
-    Ok(())
+    Ok(MsgConnectionOpenInitResponse { conn_id_on_a })
 }
@@ -1,21 +1,27 @@
 //! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenTry`.;
 use ibc_core_client::context::prelude::*;
+use ibc_core_client::context::verify::MsgProof;
 use ibc_core_client::types::error::ClientError;
+use ibc_core_connection_types::commitment::compute_connection_commitment;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenTry;
-use ibc_core_connection_types::msgs::MsgConnectionOpenTry;
+use ibc_core_connection_types::msgs::{MsgConnectionOpenTry, MsgConnectionOpenTryResponse};
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::log::HandlerLog;
 use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
 use ibc_core_host::types::path::{
     ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::proto::Any;
 use ibc_primitives::ToVec;
 
+use super::conn_end_verification_error;
+use crate::localhost::verify_not_localhost;
+
 pub fn validate<Ctx>(ctx_b: &Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -36,6 +42,8 @@ where
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
+    verify_not_localhost(&msg.client_id_on_b)?;
+
     let client_val_ctx_b = ctx_b.get_client_validation_context();
 
     let client_state_of_b_on_a =
@@ -55,6 +63,11 @@ where
         .into());
     }
 
+    ctx_b
+        .identifier_validation_profile()
+        .validate_connection_identifier(vars.conn_id_on_b.as_str())
+        .map_err(ConnectionError::InvalidIdentifier)?;
+
     let client_id_on_a = msg.counterparty.client_id();
 
     // Verify proofs
@@ -88,17 +101,34 @@ where
                 msg.delay_period,
             )?;
 
-            client_state_of_a_on_b
+            ctx_b
+                .resource_meter()
+                .charge_proof_verification(msg.proof_conn_end_on_a.as_bytes().len())?;
+
+            let proof_conn_end_on_a = MsgProof::from(&msg.proof_conn_end_on_a);
+
+            proof_conn_end_on_a
                 .verify_membership(
+                    &client_state_of_a_on_b,
                     prefix_on_a,
-                    &msg.proof_conn_end_on_a,
                     consensus_state_of_a_on_b.root(),
                     Path::Connection(ConnectionPath::new(&vars.conn_id_on_a)),
-                    expected_conn_end_on_a.encode_vec(),
+                    compute_connection_commitment(&expected_conn_end_on_a),
                 )
-                .map_err(ConnectionError::VerifyConnectionState)?;
+                .map_err(|e| {
+                    conn_end_verification_error(
+                        &vars.conn_id_on_a,
+                        &expected_conn_end_on_a,
+                        proof_conn_end_on_a.proved_value(),
+                        e,
+                    )
+                })?;
         }
 
+        ctx_b
+            .resource_meter()
+            .charge_proof_verification(msg.proof_client_state_of_b_on_a.as_bytes().len())?;
+
         client_state_of_a_on_b
             .verify_membership(
                 prefix_on_a,
@@ -121,6 +151,10 @@ where
             msg.consensus_height_of_b_on_a.revision_height(),
         );
 
+        ctx_b
+            .resource_meter()
+            .charge_proof_verification(msg.proof_consensus_state_of_b_on_a.as_bytes().len())?;
+
         client_state_of_a_on_b
             .verify_membership(
                 prefix_on_a,
@@ -138,7 +172,10 @@ where
     Ok(())
 }
 
-pub fn execute<Ctx>(ctx_b: &mut Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
+pub fn execute<Ctx>(
+    ctx_b: &mut Ctx,
+    msg: MsgConnectionOpenTry,
+) -> Result<MsgConnectionOpenTryResponse, ContextError>
 where
     Ctx: ExecutionContext,
 {
@@ -150,7 +187,7 @@ fn execute_impl<Ctx>(
     ctx_b: &mut Ctx,
     msg: MsgConnectionOpenTry,
     vars: LocalVars,
-) -> Result<(), ContextError>
+) -> Result<MsgConnectionOpenTryResponse, ContextError>
 where
     Ctx: ExecutionContext,
 {
@@ -167,7 +204,9 @@ where
     ));
     ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
     ctx_b.emit_ibc_event(event)?;
-    ctx_b.log_message("success: conn_open_try verification passed".to_string())?;
+    ctx_b.log(HandlerLog::ConnectionOpenTry {
+        conn_id_on_b: vars.conn_id_on_b.clone(),
+    })?;
 
     ctx_b.increase_connection_counter()?;
     ctx_b.store_connection_to_client(
@@ -176,7 +215,9 @@ where
     )?;
     ctx_b.store_connection(&ConnectionPath::new(&vars.conn_id_on_b), vars.conn_end_on_b)?;
 
-    Ok(())
+    Ok(MsgConnectionOpenTryResponse {
+        conn_id_on_b: vars.conn_id_on_b,
+    })
 }
 
 struct LocalVars {
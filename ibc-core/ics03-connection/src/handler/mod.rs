@@ -1,4 +1,46 @@
+use ibc_core_client::types::error::ClientError;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::proto::v1::ConnectionEnd as RawConnectionEnd;
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_host::types::identifiers::ConnectionId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Preserved;
+
 pub mod conn_open_ack;
 pub mod conn_open_confirm;
 pub mod conn_open_init;
 pub mod conn_open_try;
+
+/// Returns the error of a failed verification of the counterparty connection
+/// end, reporting the fields in which the end claimed by the proof differs
+/// from the expected one, if the proof carries a value. As the proof failed
+/// verification, the claimed end is unverified and only reported as a hint.
+pub(crate) fn conn_end_verification_error(
+    conn_id: &ConnectionId,
+    expected_conn_end: &ConnectionEnd,
+    claimed_value: Option<Vec<u8>>,
+    client_error: ClientError,
+) -> ConnectionError {
+    // The claimed end is kept along with its encoding, as an end decoding to
+    // the expected one still fails verification if the counterparty encoded
+    // it with fields unknown to ibc-rs.
+    let diff = claimed_value
+        .and_then(|value| Preserved::<ConnectionEnd>::decode::<RawConnectionEnd>(&value).ok())
+        .map(|claimed_conn_end| {
+            expected_conn_end.diff(claimed_conn_end.value()).compare(
+                "canonical_encoding",
+                &true,
+                &claimed_conn_end.is_canonical::<RawConnectionEnd>(),
+            )
+        })
+        .filter(|diff| !diff.is_empty());
+
+    match diff {
+        Some(diff) => ConnectionError::CounterpartyConnectionMismatch {
+            connection_id: conn_id.clone(),
+            diff,
+            client_error,
+        },
+        None => ConnectionError::VerifyConnectionState(client_error),
+    }
+}
@@ -3,7 +3,7 @@
 //! connection module.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
 #![deny(
     warnings,
@@ -18,6 +18,7 @@ extern crate std;
 
 pub mod delay;
 pub mod handler;
+pub mod localhost;
 
 /// Re-exports ICS-03 data structures from the `ibc-core-connection-types` crate
 pub mod types {
@@ -0,0 +1,40 @@
+//! The `connection-localhost` sentinel connection, through which the
+//! `09-localhost` client opens channels to the host itself without a
+//! connection handshake, as in ibc-go v7.1.
+
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
+use ibc_core_host::ValidationContext;
+
+/// Returns the end of the given connection as seen by the core handlers.
+///
+/// The `connection-localhost` sentinel is not stored by the host: it is built
+/// on the fly, open and bound to the `09-localhost` client, from the
+/// commitment prefix and the compatible versions of the host. The ends of all
+/// the other connections are read from the host.
+pub fn connection_end<Ctx>(ctx: &Ctx, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if conn_id.is_localhost() {
+        let version = ctx.pick_version(&ctx.get_compatible_versions())?;
+
+        return Ok(ConnectionEnd::localhost(ctx.commitment_prefix(), version));
+    }
+
+    ctx.connection_end(conn_id)
+}
+
+/// Rejects the connection handshakes of the localhost client, which is only
+/// ever bound to the sentinel connection.
+pub(crate) fn verify_not_localhost(client_id: &ClientId) -> Result<(), ConnectionError> {
+    if client_id.is_localhost() {
+        return Err(ConnectionError::LocalhostHandshake {
+            client_id: client_id.clone(),
+        });
+    }
+
+    Ok(())
+}
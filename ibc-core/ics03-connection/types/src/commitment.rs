@@ -0,0 +1,45 @@
+//! Computes the commitment stored under the provable path of a connection end.
+
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use crate::ConnectionEnd;
+
+/// Compute the commitment for a connection end, which is its Protobuf
+/// encoding, as in ibc-go.
+pub fn compute_connection_commitment(connection_end: &ConnectionEnd) -> Vec<u8> {
+    connection_end.clone().encode_vec()
+}
+
+#[cfg(test)]
+mod test {
+    use ibc_core_commitment_types::commitment::CommitmentPrefix;
+    use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
+
+    use super::*;
+    use crate::version::Version;
+    use crate::{Counterparty, State};
+
+    #[test]
+    fn test_compute_connection_commitment() {
+        // client id, versions, state, counterparty
+        let expected: &[u8] = b"\x0a\x0f07-tendermint-0\
+            \x12\x23\x0a\x011\x12\x0dORDER_ORDERED\x12\x0fORDER_UNORDERED\
+            \x18\x03\
+            \x22\x26\x0a\x0f07-tendermint-0\x12\x0cconnection-0\x1a\x05\x0a\x03ibc";
+        let client_id = ClientId::new("07-tendermint", 0).unwrap();
+        let connection_end = ConnectionEnd::new(
+            State::Open,
+            client_id.clone(),
+            Counterparty::new(
+                client_id,
+                Some(ConnectionId::new(0)),
+                CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+            ),
+            Version::compatibles(),
+            core::time::Duration::ZERO,
+        )
+        .unwrap();
+        assert_eq!(compute_connection_commitment(&connection_end), expected);
+    }
+}
@@ -8,6 +8,7 @@ use ibc_core_client_types::error::ClientError;
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
 use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_primitives::prelude::*;
+use ibc_primitives::StateDiff;
 use ibc_proto::ibc::core::connection::v1::{
     ConnectionEnd as RawConnectionEnd, Counterparty as RawCounterparty,
     IdentifiedConnection as RawIdentifiedConnection,
@@ -276,6 +277,29 @@ impl ConnectionEnd {
         })
     }
 
+    /// Builds the `connection-localhost` sentinel connection end of the
+    /// localhost client, which is open from the start and connects the host
+    /// to itself, with the given commitment prefix and negotiated version.
+    pub fn localhost(prefix: CommitmentPrefix, version: Version) -> Self {
+        Self {
+            state: State::Open,
+            client_id: ClientId::localhost(),
+            counterparty: Counterparty::new(
+                ClientId::localhost(),
+                Some(ConnectionId::localhost()),
+                prefix,
+            ),
+            versions: vec![version],
+            delay_period: Duration::ZERO,
+        }
+    }
+
+    /// Checks whether this is the sentinel connection end of the localhost
+    /// client.
+    pub fn is_localhost(&self) -> bool {
+        self.client_id.is_localhost()
+    }
+
     /// Getter for the state of this connection end.
     pub fn state(&self) -> &State {
         &self.state
@@ -347,6 +371,31 @@ impl ConnectionEnd {
     pub fn delay_period(&self) -> Duration {
         self.delay_period
     }
+
+    /// Returns the fields of the connection end claimed by the counterparty
+    /// which differ from this expected end.
+    pub fn diff(&self, claimed: &Self) -> StateDiff {
+        StateDiff::new()
+            .compare("state", &self.state, &claimed.state)
+            .compare("client_id", &self.client_id, &claimed.client_id)
+            .compare(
+                "counterparty.client_id",
+                &self.counterparty.client_id,
+                &claimed.counterparty.client_id,
+            )
+            .compare(
+                "counterparty.connection_id",
+                &self.counterparty.connection_id,
+                &claimed.counterparty.connection_id,
+            )
+            .compare(
+                "counterparty.prefix",
+                &self.counterparty.prefix,
+                &claimed.counterparty.prefix,
+            )
+            .compare("versions", &self.versions, &claimed.versions)
+            .compare("delay_period", &self.delay_period, &claimed.delay_period)
+    }
 }
 
 #[cfg_attr(
@@ -460,6 +509,7 @@ impl Counterparty {
     derive(
         parity_scale_codec::Encode,
         parity_scale_codec::Decode,
+        parity_scale_codec::MaxEncodedLen,
         scale_info::TypeInfo
     )
 )]
@@ -548,3 +598,45 @@ impl From<State> for i32 {
         value as i32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connection_end() -> ConnectionEnd {
+        ConnectionEnd::new(
+            State::Open,
+            ClientId::new("07-tendermint", 0).expect("valid client id"),
+            Counterparty::new(
+                ClientId::new("07-tendermint", 1).expect("valid client id"),
+                Some(ConnectionId::new(1)),
+                CommitmentPrefix::try_from(b"ibc".to_vec()).expect("non-empty prefix"),
+            ),
+            Version::compatibles(),
+            Duration::ZERO,
+        )
+        .expect("valid connection end")
+    }
+
+    #[test]
+    fn test_connection_end_diff() {
+        let expected = connection_end();
+
+        assert!(expected.diff(&connection_end()).is_empty());
+
+        let mut claimed = connection_end();
+        claimed.set_state(State::TryOpen);
+        claimed.set_counterparty(Counterparty::new(
+            ClientId::new("07-tendermint", 1).expect("valid client id"),
+            Some(ConnectionId::new(2)),
+            CommitmentPrefix::try_from(b"ibc".to_vec()).expect("non-empty prefix"),
+        ));
+
+        let diff = expected.diff(&claimed);
+        let fields: Vec<_> = diff.mismatches().iter().map(|m| m.field).collect();
+
+        assert_eq!(fields, vec!["state", "counterparty.connection_id"]);
+        assert_eq!(diff.mismatches()[0].expected, "Open");
+        assert_eq!(diff.mismatches()[0].claimed, "TryOpen");
+    }
+}
@@ -5,7 +5,7 @@ use ibc_core_client_types::{error as client_error, Height};
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_primitives::prelude::*;
-use ibc_primitives::{Timestamp, TimestampOverflowError};
+use ibc_primitives::{ErrorCode, StateDiff, Timestamp, TimestampOverflowError};
 
 use crate::version::Version;
 
@@ -85,6 +85,57 @@ pub enum ConnectionError {
     CounterOverflow,
     /// other error: `{description}`
     Other { description: String },
+    /// counterparty connection end `{connection_id}` claimed by the proof does not match the expected one: `{diff}`
+    CounterpartyConnectionMismatch {
+        connection_id: ConnectionId,
+        diff: StateDiff,
+        client_error: client_error::ClientError,
+    },
+    /// the localhost client `{client_id}` only takes part in the `connection-localhost` sentinel connection, not in connection handshakes
+    LocalhostHandshake { client_id: ClientId },
+}
+
+impl ConnectionError {
+    /// Returns the stable error code of this error.
+    ///
+    /// Errors wrapping another IBC error report the code of the wrapped error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("connection", code);
+
+        match self {
+            Self::Client(e) => e.code(),
+            Self::InvalidState { .. } => code(1),
+            Self::InvalidConsensusHeight { .. } => code(2),
+            Self::InvalidIdentifier(_) => code(3),
+            Self::EmptyProtoConnectionEnd => code(4),
+            Self::EmptyVersions => code(5),
+            Self::InvalidVersionLength => code(6),
+            Self::VersionNotSupported { .. } => code(7),
+            Self::NoCommonVersion => code(8),
+            Self::EmptyFeatures => code(9),
+            Self::FeatureNotSupported { .. } => code(10),
+            Self::NoCommonFeatures => code(11),
+            Self::MissingProofHeight => code(12),
+            Self::MissingConsensusHeight => code(13),
+            Self::InvalidProof => code(14),
+            Self::VerifyConnectionState(_) => code(15),
+            Self::InvalidSigner { .. } => code(16),
+            Self::ConnectionNotFound { .. } => code(17),
+            Self::InvalidCounterparty => code(18),
+            Self::MissingCounterparty => code(19),
+            Self::MissingClientState => code(20),
+            Self::ConsensusStateVerificationFailure { .. } => code(21),
+            Self::ClientStateVerificationFailure { .. } => code(22),
+            Self::InvalidClientState { .. } => code(23),
+            Self::NotEnoughBlocksElapsed { .. } => code(24),
+            Self::NotEnoughTimeElapsed { .. } => code(25),
+            Self::TimestampOverflow(_) => code(26),
+            Self::CounterOverflow => code(27),
+            Self::Other { .. } => code(28),
+            Self::CounterpartyConnectionMismatch { .. } => code(29),
+            Self::LocalhostHandshake { .. } => code(30),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -98,6 +149,9 @@ impl std::error::Error for ConnectionError {
             }
             | Self::ClientStateVerificationFailure {
                 client_error: e, ..
+            }
+            | Self::CounterpartyConnectionMismatch {
+                client_error: e, ..
             } => Some(e),
             Self::InvalidIdentifier(e) => Some(e),
             Self::TimestampOverflow(e) => Some(e),
@@ -1,7 +1,7 @@
 //! Implementation of the Connection Semantics (ICS-03) data structures.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
 #![deny(
     warnings,
@@ -17,6 +17,7 @@ extern crate std;
 mod connection;
 pub use connection::*;
 
+pub mod commitment;
 pub mod error;
 pub mod events;
 pub mod msgs;
@@ -1,6 +1,6 @@
 use core::time::Duration;
 
-use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_primitives::prelude::*;
 use ibc_primitives::Signer;
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenInit as RawMsgConnectionOpenInit;
@@ -25,6 +25,18 @@ pub struct MsgConnectionOpenInit {
     pub signer: Signer,
 }
 
+/// The response to a [`MsgConnectionOpenInit`], carrying the identifier the
+/// host allocated to the created connection.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgConnectionOpenInitResponse {
+    pub conn_id_on_a: ConnectionId,
+}
+
 /// This module encapsulates the workarounds we need to do to implement
 /// `BorshSerialize` and `BorshDeserialize` on `MsgConnectionOpenInit`
 #[cfg(feature = "borsh")]
@@ -2,7 +2,7 @@ use core::time::Duration;
 
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentProofBytes;
-use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_primitives::prelude::*;
 use ibc_primitives::Signer;
 use ibc_proto::google::protobuf::Any;
@@ -49,6 +49,18 @@ pub struct MsgConnectionOpenTry {
     pub previous_connection_id: String,
 }
 
+/// The response to a [`MsgConnectionOpenTry`], carrying the identifier the
+/// host allocated to the created connection.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgConnectionOpenTryResponse {
+    pub conn_id_on_b: ConnectionId,
+}
+
 #[allow(deprecated)]
 #[cfg(feature = "borsh")]
 mod borsh_impls {
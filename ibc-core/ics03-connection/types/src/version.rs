@@ -9,6 +9,13 @@ use ibc_proto::Protobuf;
 
 use crate::error::ConnectionError;
 
+/// Identifier of the default connection version.
+pub const DEFAULT_VERSION_IDENTIFIER: &str = "1";
+
+/// Features of the default connection version, i.e. the channel orderings it
+/// supports.
+pub const DEFAULT_VERSION_FEATURES: [&str; 2] = ["ORDER_ORDERED", "ORDER_UNORDERED"];
+
 /// Stores the identifier and the features supported by a version
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -62,8 +69,11 @@ impl Version {
     /// Returns the lists of supported versions
     pub fn compatibles() -> Vec<Self> {
         vec![Self {
-            identifier: "1".to_string(),
-            features: vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()],
+            identifier: DEFAULT_VERSION_IDENTIFIER.to_string(),
+            features: DEFAULT_VERSION_FEATURES
+                .iter()
+                .map(|feature| feature.to_string())
+                .collect(),
         }]
     }
 }
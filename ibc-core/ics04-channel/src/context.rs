@@ -2,14 +2,24 @@
 
 use ibc_core_channel_types::channel::ChannelEnd;
 use ibc_core_channel_types::commitment::PacketCommitment;
+use ibc_core_channel_types::limits::PacketSizeLimits;
+use ibc_core_channel_types::packet_data::DynPacketDataCodec;
+use ibc_core_channel_types::timeout::TimeoutHeight;
 use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::Height;
+use ibc_core_connection::localhost::connection_end;
 use ibc_core_connection::types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
-use ibc_core_host::types::identifiers::{ConnectionId, Sequence};
-use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath, SeqSendPath};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_handler_types::log::HandlerLog;
+use ibc_core_handler_types::mode::HostMode;
+use ibc_core_host::types::identifiers::{ConnectionId, PortId, Sequence};
+use ibc_core_host::types::path::{
+    ChannelEndPath, CommitmentPath, CounterpartyPayeePath, PayeePath, SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, NoopResourceMeter, ResourceMeter, ValidationContext};
 use ibc_primitives::prelude::*;
+use ibc_primitives::{Signer, Timestamp};
 
 /// Methods required in send packet validation, to be implemented by the host
 pub trait SendPacketValidationContext {
@@ -26,6 +36,36 @@ pub trait SendPacketValidationContext {
 
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
+
+    /// Returns the current height of the local chain, the latest height of
+    /// the `09-localhost` client.
+    fn host_height(&self) -> Result<Height, ContextError>;
+
+    /// Returns the current timestamp of the local chain, the latest timestamp
+    /// of the `09-localhost` client.
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
+    /// Returns the meter charged for the packet data being sent.
+    fn resource_meter(&self) -> &dyn ResourceMeter {
+        &NoopResourceMeter
+    }
+
+    /// Returns the mode in which the host operates IBC, which determines
+    /// whether packets may be sent.
+    fn host_mode(&self) -> HostMode {
+        HostMode::Full
+    }
+
+    /// Returns the upper bounds on the size of the packets being sent.
+    fn packet_size_limits(&self) -> PacketSizeLimits {
+        PacketSizeLimits::default()
+    }
+
+    /// Returns the codec of the packet data of the application bound to the
+    /// given port, if any.
+    fn packet_data_codec(&self, _port_id: &PortId) -> Option<&dyn DynPacketDataCodec> {
+        None
+    }
 }
 
 impl<T> SendPacketValidationContext for T
@@ -43,7 +83,7 @@ where
     }
 
     fn connection_end(&self, connection_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
-        self.connection_end(connection_id)
+        connection_end(self, connection_id)
     }
 
     fn get_next_sequence_send(
@@ -52,6 +92,30 @@ where
     ) -> Result<Sequence, ContextError> {
         self.get_next_sequence_send(seq_send_path)
     }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.host_timestamp()
+    }
+
+    fn resource_meter(&self) -> &dyn ResourceMeter {
+        self.resource_meter()
+    }
+
+    fn host_mode(&self) -> HostMode {
+        self.host_mode()
+    }
+
+    fn packet_size_limits(&self) -> PacketSizeLimits {
+        self.packet_size_limits()
+    }
+
+    fn packet_data_codec(&self, port_id: &PortId) -> Option<&dyn DynPacketDataCodec> {
+        self.packet_data_codec(port_id)
+    }
 }
 
 /// Methods required in send packet execution, to be implemented by the host
@@ -68,11 +132,28 @@ pub trait SendPacketExecutionContext: SendPacketValidationContext {
         commitment: PacketCommitment,
     ) -> Result<(), ContextError>;
 
+    /// Indexes the commitment of a sent packet by the timeout height and
+    /// timestamp of the packet.
+    /// Implement only if the host maintains such an index.
+    fn index_packet_timeout(
+        &mut self,
+        _commitment_path: &CommitmentPath,
+        _timeout_height: &TimeoutHeight,
+        _timeout_timestamp: &Timestamp,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
     /// Ibc events
     fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
 
     /// Logging facility
-    fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+    fn log(&mut self, log: HandlerLog) -> Result<(), ContextError>;
+
+    /// Logs the given free-form message, as a [`HandlerLog::Message`].
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.log(HandlerLog::Message(message))
+    }
 }
 
 impl<T> SendPacketExecutionContext for T
@@ -95,11 +176,37 @@ where
         self.store_packet_commitment(commitment_path, commitment)
     }
 
+    fn index_packet_timeout(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        timeout_height: &TimeoutHeight,
+        timeout_timestamp: &Timestamp,
+    ) -> Result<(), ContextError> {
+        self.index_packet_timeout(commitment_path, timeout_height, timeout_timestamp)
+    }
+
     fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
         self.emit_ibc_event(event)
     }
 
-    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
-        self.log_message(message)
+    fn log(&mut self, log: HandlerLog) -> Result<(), ContextError> {
+        self.log(log)
     }
 }
+
+/// Methods required to register the addresses of the relayers of a channel, to
+/// be implemented by the host along with the payee lookups of the
+/// [`ValidationContext`].
+pub trait RelayerPayeeExecutionContext: ExecutionContext {
+    /// Stores the address registered by the relayer to receive the fees it
+    /// earns on the channel.
+    fn store_payee(&mut self, payee_path: &PayeePath, payee: Signer) -> Result<(), ContextError>;
+
+    /// Stores the address registered by the relayer on the counterparty chain
+    /// of the channel.
+    fn store_counterparty_payee(
+        &mut self,
+        counterparty_payee_path: &CounterpartyPayeePath,
+        counterparty_payee: Signer,
+    ) -> Result<(), ContextError>;
+}
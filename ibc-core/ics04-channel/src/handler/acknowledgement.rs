@@ -1,20 +1,25 @@
-use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
-use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::channel::{Order, State as ChannelState};
+use ibc_core_channel_types::commitment::{
+    compute_counterparty_ack_commitment, compute_packet_commitment,
+};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::AcknowledgePacket;
 use ibc_core_channel_types::msgs::MsgAcknowledgement;
-use ibc_core_client::context::prelude::*;
+use ibc_core_client::context::verify::MsgProof;
 use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_connection::localhost::connection_end;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, SeqAckPath,
-};
+use ibc_core_handler_types::log::HandlerLog;
+use ibc_core_host::types::path::{AckPath, ChannelEndPath, CommitmentPath, Path, SeqAckPath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::localhost::CounterpartyState;
+use super::PacketLifecycleGuard;
+
 pub fn acknowledgement_packet_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -43,6 +48,9 @@ where
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
     let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
 
+    // The packet must not have been acknowledged or timed out already
+    PacketLifecycleGuard::check_pending(ctx_a, &msg.packet)?;
+
     // In all cases, this event is emitted
     let event = IbcEvent::AcknowledgePacket(AcknowledgePacket::new(
         msg.packet.clone(),
@@ -58,15 +66,6 @@ where
         msg.packet.seq_on_a,
     );
 
-    // check if we're in the NO-OP case
-    if ctx_a.get_packet_commitment(&commitment_path_on_a).is_err() {
-        // This error indicates that the timeout has already been relayed
-        // or there is a misconfigured relayer attempting to prove a timeout
-        // for a packet never sent. Core IBC will treat this error as a no-op in order to
-        // prevent an entire relay transaction from failing and consuming unnecessary fees.
-        return Ok(());
-    };
-
     let (extras, cb_result) =
         module.on_acknowledgement_packet_execute(&msg.packet, &msg.acknowledgement, &msg.signer);
 
@@ -75,6 +74,7 @@ where
     // apply state changes
     {
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
+        ctx_a.unindex_packet_timeout(&commitment_path_on_a)?;
 
         if let Order::Ordered = chan_end_on_a.ordering {
             // Note: in validation, we verified that `msg.packet.sequence == nextSeqRecv`
@@ -87,7 +87,11 @@ where
 
     // emit events and logs
     {
-        ctx_a.log_message("success: packet acknowledgement".to_string())?;
+        ctx_a.log(HandlerLog::PacketAcknowledged {
+            port_id_on_a: msg.packet.port_id_on_a.clone(),
+            chan_id_on_a: msg.packet.chan_id_on_a.clone(),
+            sequence: msg.packet.seq_on_a,
+        })?;
 
         // Note: Acknowledgement event was emitted at the beginning
 
@@ -96,7 +100,7 @@ where
         }
 
         for log_message in extras.log {
-            ctx_a.log_message(log_message)?;
+            ctx_a.log(log_message.into())?;
         }
     }
 
@@ -115,29 +119,19 @@ where
 
     chan_end_on_a.verify_state_matches(&ChannelState::Open)?;
 
-    let counterparty = Counterparty::new(
-        packet.port_id_on_b.clone(),
-        Some(packet.chan_id_on_b.clone()),
-    );
-
-    chan_end_on_a.verify_counterparty_matches(&counterparty)?;
+    chan_end_on_a.verify_counterparty_ids_match(&packet.port_id_on_b, &packet.chan_id_on_b)?;
 
     let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
-    let conn_end_on_a = ctx_a.connection_end(conn_id_on_a)?;
+    let conn_end_on_a = connection_end(ctx_a, conn_id_on_a)?;
 
     conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
 
-    let commitment_path_on_a =
-        CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+    // Verify packet commitment, which must still be pending
+    let commitment_on_a = PacketLifecycleGuard::check_pending(ctx_a, packet)?;
 
-    // Verify packet commitment
-    let Ok(commitment_on_a) = ctx_a.get_packet_commitment(&commitment_path_on_a) else {
-        // This error indicates that the timeout has already been relayed
-        // or there is a misconfigured relayer attempting to prove a timeout
-        // for a packet never sent. Core IBC will treat this error as a no-op in order to
-        // prevent an entire relay transaction from failing and consuming unnecessary fees.
-        return Ok(());
-    };
+    let meter = ctx_a.resource_meter();
+    meter.charge_packet_data(packet.data.len())?;
+    meter.charge_packet_data(msg.acknowledgement.as_bytes().len())?;
 
     if commitment_on_a
         != compute_packet_commitment(
@@ -147,6 +141,8 @@ where
         )
     {
         return Err(PacketError::IncorrectPacketCommitment {
+            port_id: packet.port_id_on_a.clone(),
+            channel_id: packet.chan_id_on_a.clone(),
             sequence: packet.seq_on_a,
         }
         .into());
@@ -166,36 +162,27 @@ where
 
     // Verify proofs
     {
-        let client_id_on_a = conn_end_on_a.client_id();
-
-        let client_val_ctx_a = ctx_a.get_client_validation_context();
-
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
-        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
-
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
+        let counterparty_state_on_a =
+            CounterpartyState::at_height(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
+        let ack_commitment = compute_counterparty_ack_commitment(
+            counterparty_state_on_a.commitment_hasher()?,
+            &msg.acknowledgement,
         );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let ack_commitment = compute_ack_commitment(&msg.acknowledgement);
         let ack_path_on_b =
             AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
 
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
+        ctx_a
+            .resource_meter()
+            .charge_proof_verification(msg.proof_acked_on_b.as_bytes().len())?;
+
         // Verify the proof for the packet against the chain store.
-        client_state_of_b_on_a
+        counterparty_state_on_a
             .verify_membership(
+                ctx_a,
                 conn_end_on_a.counterparty().prefix(),
-                &msg.proof_acked_on_b,
-                consensus_state_of_b_on_a.root(),
+                &MsgProof::from(&msg.proof_acked_on_b),
                 Path::Ack(ack_path_on_b),
                 ack_commitment.into_vec(),
             )
@@ -1,18 +1,23 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelCloseConfirm`.
 
 use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State, State as ChannelState};
+use ibc_core_channel_types::commitment::compute_channel_commitment;
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::CloseConfirm;
 use ibc_core_channel_types::msgs::MsgChannelCloseConfirm;
-use ibc_core_client::context::prelude::*;
+use ibc_core_client::context::verify::MsgProof;
+use ibc_core_connection::localhost::connection_end;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
+use ibc_core_handler_types::log::HandlerLog;
+use ibc_core_host::types::path::{ChannelEndPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
+
+use super::chan_end_verification_error;
+use super::localhost::CounterpartyState;
 
 pub fn chan_close_confirm_validate<ValCtx>(
     ctx_b: &ValCtx,
@@ -53,7 +58,10 @@ where
 
     // emit events and logs
     {
-        ctx_b.log_message("success: channel close confirm".to_string())?;
+        ctx_b.log(HandlerLog::ChannelCloseConfirm {
+            port_id_on_b: msg.port_id_on_b.clone(),
+            chan_id_on_b: msg.chan_id_on_b.clone(),
+        })?;
 
         let core_event = {
             let port_id_on_a = chan_end_on_b.counterparty().port_id.clone();
@@ -84,7 +92,7 @@ where
         }
 
         for log_message in extras.log {
-            ctx_b.log_message(log_message)?;
+            ctx_b.log(log_message.into())?;
         }
     }
 
@@ -104,30 +112,14 @@ where
     // Validate that the channel end is in a state where it can be closed.
     chan_end_on_b.verify_not_closed()?;
 
-    let conn_end_on_b = ctx_b.connection_end(&chan_end_on_b.connection_hops()[0])?;
+    let conn_end_on_b = connection_end(ctx_b, &chan_end_on_b.connection_hops()[0])?;
 
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
     // Verify proofs
     {
-        let client_id_on_b = conn_end_on_b.client_id();
-
-        let client_val_ctx_b = ctx_b.get_client_validation_context();
-
-        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
-
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
-            .verify_is_active()?;
-        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
-
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+        let counterparty_state_on_b =
+            CounterpartyState::at_height(ctx_b, &conn_end_on_b, msg.proof_height_on_a)?;
         let prefix_on_a = conn_end_on_b.counterparty().prefix();
         let port_id_on_a = &chan_end_on_b.counterparty().port_id;
         let chan_id_on_a = chan_end_on_b
@@ -149,17 +141,31 @@ where
         )?;
         let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
 
+        ctx_b
+            .resource_meter()
+            .charge_proof_verification(msg.proof_chan_end_on_a.as_bytes().len())?;
+
+        let proof_chan_end_on_a = MsgProof::from(&msg.proof_chan_end_on_a);
+
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_a_on_b
+        counterparty_state_on_b
             .verify_membership(
+                ctx_b,
                 prefix_on_a,
-                &msg.proof_chan_end_on_a,
-                consensus_state_of_a_on_b.root(),
+                &proof_chan_end_on_a,
                 Path::ChannelEnd(chan_end_path_on_a),
-                expected_chan_end_on_a.encode_vec(),
+                compute_channel_commitment(&expected_chan_end_on_a),
             )
-            .map_err(ChannelError::VerifyChannelFailed)?;
+            .map_err(|e| {
+                chan_end_verification_error(
+                    port_id_on_a,
+                    chan_id_on_a,
+                    &expected_chan_end_on_a,
+                    proof_chan_end_on_a.proved_value(),
+                    e,
+                )
+            })?;
     }
 
     Ok(())
@@ -3,15 +3,18 @@ use ibc_core_channel_types::channel::State;
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::CloseInit;
 use ibc_core_channel_types::msgs::MsgChannelCloseInit;
-use ibc_core_client::context::prelude::*;
+use ibc_core_connection::localhost::connection_end;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::log::HandlerLog;
 use ibc_core_host::types::path::ChannelEndPath;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::localhost::verify_client_active;
+
 pub fn chan_close_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -22,6 +25,8 @@ where
 {
     validate(ctx_a, &msg)?;
 
+    verify_close_allowed(module, &msg)?;
+
     module.on_chan_close_init_validate(&msg.port_id_on_a, &msg.chan_id_on_a)?;
 
     Ok(())
@@ -52,7 +57,10 @@ where
 
     // emit events and logs
     {
-        ctx_a.log_message("success: channel close init".to_string())?;
+        ctx_a.log(HandlerLog::ChannelCloseInit {
+            port_id_on_a: msg.port_id_on_a.clone(),
+            chan_id_on_a: msg.chan_id_on_a.clone(),
+        })?;
 
         let core_event = {
             let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
@@ -83,13 +91,29 @@ where
         }
 
         for log_message in extras.log {
-            ctx_a.log_message(log_message)?;
+            ctx_a.log(log_message.into())?;
         }
     }
 
     Ok(())
 }
 
+/// Consults the close policy of the module bound to the channel.
+fn verify_close_allowed(
+    module: &dyn Module,
+    msg: &MsgChannelCloseInit,
+) -> Result<(), ContextError> {
+    if !module.chan_close_init_allowed(&msg.port_id_on_a, &msg.chan_id_on_a, &msg.signer) {
+        return Err(ChannelError::ChannelCloseNotAllowed {
+            port_id: msg.port_id_on_a.clone(),
+            channel_id: msg.chan_id_on_a.clone(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
 fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelCloseInit) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -105,18 +129,11 @@ where
     // An OPEN IBC connection running on the local (host) chain should exist.
     chan_end_on_a.verify_connection_hops_length()?;
 
-    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+    let conn_end_on_a = connection_end(ctx_a, &chan_end_on_a.connection_hops()[0])?;
 
     conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
 
-    let client_id_on_a = conn_end_on_a.client_id();
-
-    let client_val_ctx_a = ctx_a.get_client_validation_context();
-
-    let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-    client_state_of_b_on_a
-        .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-        .verify_is_active()?;
+    verify_client_active(ctx_a, &conn_end_on_a)?;
 
     Ok(())
 }
@@ -1,17 +1,22 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelOpenAck`.
 use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State, State as ChannelState};
+use ibc_core_channel_types::commitment::compute_channel_commitment;
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenAck;
 use ibc_core_channel_types::msgs::MsgChannelOpenAck;
-use ibc_core_client::context::prelude::*;
+use ibc_core_client::context::verify::MsgProof;
+use ibc_core_connection::localhost::connection_end;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
+use ibc_core_handler_types::log::HandlerLog;
+use ibc_core_host::types::path::{ChannelEndPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
+
+use super::chan_end_verification_error;
+use super::localhost::CounterpartyState;
 
 pub fn chan_open_ack_validate<ValCtx>(
     ctx_a: &ValCtx,
@@ -57,7 +62,10 @@ where
 
     // emit events and logs
     {
-        ctx_a.log_message("success: channel open ack".to_string())?;
+        ctx_a.log(HandlerLog::ChannelOpenAck {
+            port_id_on_a: msg.port_id_on_a.clone(),
+            chan_id_on_a: msg.chan_id_on_a.clone(),
+        })?;
 
         let core_event = {
             let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
@@ -79,7 +87,7 @@ where
         }
 
         for log_message in extras.log {
-            ctx_a.log_message(log_message)?;
+            ctx_a.log(log_message.into())?;
         }
     }
 
@@ -101,28 +109,14 @@ where
     // An OPEN IBC connection running on the local (host) chain should exist.
     chan_end_on_a.verify_connection_hops_length()?;
 
-    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+    let conn_end_on_a = connection_end(ctx_a, &chan_end_on_a.connection_hops()[0])?;
 
     conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
 
     // Verify proofs
     {
-        let client_id_on_a = conn_end_on_a.client_id();
-        let client_val_ctx_a = ctx_a.get_client_validation_context();
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
-        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
-
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+        let counterparty_state_on_a =
+            CounterpartyState::at_height(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
         let prefix_on_b = conn_end_on_a.counterparty().prefix();
         let port_id_on_b = &chan_end_on_a.counterparty().port_id;
         let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
@@ -142,17 +136,31 @@ where
         )?;
         let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, &msg.chan_id_on_b);
 
+        ctx_a
+            .resource_meter()
+            .charge_proof_verification(msg.proof_chan_end_on_b.as_bytes().len())?;
+
+        let proof_chan_end_on_b = MsgProof::from(&msg.proof_chan_end_on_b);
+
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a
+        counterparty_state_on_a
             .verify_membership(
+                ctx_a,
                 prefix_on_b,
-                &msg.proof_chan_end_on_b,
-                consensus_state_of_b_on_a.root(),
+                &proof_chan_end_on_b,
                 Path::ChannelEnd(chan_end_path_on_b),
-                expected_chan_end_on_b.encode_vec(),
+                compute_channel_commitment(&expected_chan_end_on_b),
             )
-            .map_err(ChannelError::VerifyChannelFailed)?;
+            .map_err(|e| {
+                chan_end_verification_error(
+                    port_id_on_b,
+                    &msg.chan_id_on_b,
+                    &expected_chan_end_on_b,
+                    proof_chan_end_on_b.proved_value(),
+                    e,
+                )
+            })?;
     }
 
     Ok(())
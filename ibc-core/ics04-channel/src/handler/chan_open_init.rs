@@ -2,16 +2,20 @@
 
 use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State};
 use ibc_core_channel_types::events::OpenInit;
-use ibc_core_channel_types::msgs::MsgChannelOpenInit;
-use ibc_core_client::context::prelude::*;
+use ibc_core_channel_types::msgs::{MsgChannelOpenInit, MsgChannelOpenInitResponse};
+use ibc_core_connection::localhost::connection_end;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::log::HandlerLog;
 use ibc_core_host::types::identifiers::ChannelId;
 use ibc_core_host::types::path::{ChannelEndPath, SeqAckPath, SeqRecvPath, SeqSendPath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::localhost::verify_client_active;
+use super::validate_local_identifiers;
+
 pub fn chan_open_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -23,6 +27,8 @@ where
     validate(ctx_a, &msg)?;
     let chan_id_on_a = ChannelId::new(ctx_a.channel_counter()?);
 
+    validate_local_identifiers(ctx_a, &msg.port_id_on_a, &chan_id_on_a)?;
+
     module.on_chan_open_init_validate(
         msg.ordering,
         &msg.connection_hops_on_a,
@@ -39,7 +45,7 @@ pub fn chan_open_init_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
     msg: MsgChannelOpenInit,
-) -> Result<(), ContextError>
+) -> Result<MsgChannelOpenInitResponse, ContextError>
 where
     ExecCtx: ExecutionContext,
 {
@@ -82,15 +88,16 @@ where
 
     // emit events and logs
     {
-        ctx_a.log_message(format!(
-            "success: channel open init with channel identifier: {chan_id_on_a}"
-        ))?;
+        ctx_a.log(HandlerLog::ChannelOpenInit {
+            port_id_on_a: msg.port_id_on_a.clone(),
+            chan_id_on_a: chan_id_on_a.clone(),
+        })?;
         let core_event = IbcEvent::OpenInitChannel(OpenInit::new(
             msg.port_id_on_a.clone(),
             chan_id_on_a.clone(),
             msg.port_id_on_b,
             conn_id_on_a,
-            version,
+            version.clone(),
         ));
         ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_a.emit_ibc_event(core_event)?;
@@ -100,11 +107,14 @@ where
         }
 
         for log_message in extras.log {
-            ctx_a.log_message(log_message)?;
+            ctx_a.log(log_message.into())?;
         }
     }
 
-    Ok(())
+    Ok(MsgChannelOpenInitResponse {
+        chan_id_on_a,
+        version,
+    })
 }
 
 fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelOpenInit) -> Result<(), ContextError>
@@ -115,17 +125,11 @@ where
 
     msg.verify_connection_hops_length()?;
     // An IBC connection running on the local (host) chain should exist.
-    let conn_end_on_a = ctx_a.connection_end(&msg.connection_hops_on_a[0])?;
+    let conn_end_on_a = connection_end(ctx_a, &msg.connection_hops_on_a[0])?;
 
     // Note: Not needed check if the connection end is OPEN. Optimistic channel handshake is allowed.
 
-    let client_id_on_a = conn_end_on_a.client_id();
-    let client_val_ctx_a = ctx_a.get_client_validation_context();
-    let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-    client_state_of_b_on_a
-        .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-        .verify_is_active()?;
+    verify_client_active(ctx_a, &conn_end_on_a)?;
 
     let conn_version = conn_end_on_a.versions();
 
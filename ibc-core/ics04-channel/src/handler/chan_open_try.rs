@@ -1,21 +1,24 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelOpenTry`.
 
 use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State as ChannelState};
+use ibc_core_channel_types::commitment::compute_channel_commitment;
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenTry;
-use ibc_core_channel_types::msgs::MsgChannelOpenTry;
-use ibc_core_client::context::prelude::*;
+use ibc_core_channel_types::msgs::{MsgChannelOpenTry, MsgChannelOpenTryResponse};
+use ibc_core_client::context::verify::MsgProof;
+use ibc_core_connection::localhost::connection_end;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::log::HandlerLog;
 use ibc_core_host::types::identifiers::ChannelId;
-use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, Path, SeqAckPath, SeqRecvPath, SeqSendPath,
-};
+use ibc_core_host::types::path::{ChannelEndPath, Path, SeqAckPath, SeqRecvPath, SeqSendPath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
+
+use super::localhost::CounterpartyState;
+use super::{chan_end_verification_error, validate_local_identifiers};
 
 pub fn chan_open_try_validate<ValCtx>(
     ctx_b: &ValCtx,
@@ -29,6 +32,8 @@ where
 
     let chan_id_on_b = ChannelId::new(ctx_b.channel_counter()?);
 
+    validate_local_identifiers(ctx_b, &msg.port_id_on_b, &chan_id_on_b)?;
+
     module.on_chan_open_try_validate(
         msg.ordering,
         &msg.connection_hops_on_b,
@@ -45,7 +50,7 @@ pub fn chan_open_try_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module: &mut dyn Module,
     msg: MsgChannelOpenTry,
-) -> Result<(), ContextError>
+) -> Result<MsgChannelOpenTryResponse, ContextError>
 where
     ExecCtx: ExecutionContext,
 {
@@ -88,9 +93,10 @@ where
 
     // emit events and logs
     {
-        ctx_b.log_message(format!(
-            "success: channel open try with channel identifier: {chan_id_on_b}"
-        ))?;
+        ctx_b.log(HandlerLog::ChannelOpenTry {
+            port_id_on_b: msg.port_id_on_b.clone(),
+            chan_id_on_b: chan_id_on_b.clone(),
+        })?;
 
         let core_event = IbcEvent::OpenTryChannel(OpenTry::new(
             msg.port_id_on_b.clone(),
@@ -98,7 +104,7 @@ where
             msg.port_id_on_a.clone(),
             msg.chan_id_on_a.clone(),
             conn_id_on_b,
-            version,
+            version.clone(),
         ));
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(core_event)?;
@@ -108,11 +114,14 @@ where
         }
 
         for log_message in extras.log {
-            ctx_b.log_message(log_message)?;
+            ctx_b.log(log_message.into())?;
         }
     }
 
-    Ok(())
+    Ok(MsgChannelOpenTryResponse {
+        chan_id_on_b,
+        version,
+    })
 }
 
 fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelOpenTry) -> Result<(), ContextError>
@@ -123,7 +132,7 @@ where
 
     msg.verify_connection_hops_length()?;
 
-    let conn_end_on_b = ctx_b.connection_end(&msg.connection_hops_on_b[0])?;
+    let conn_end_on_b = connection_end(ctx_b, &msg.connection_hops_on_b[0])?;
 
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
@@ -133,23 +142,8 @@ where
 
     // Verify proofs
     {
-        let client_id_on_b = conn_end_on_b.client_id();
-        let client_val_ctx_b = ctx_b.get_client_validation_context();
-        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
-
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
-            .verify_is_active()?;
-
-        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
-
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+        let counterparty_state_on_b =
+            CounterpartyState::at_height(ctx_b, &conn_end_on_b, msg.proof_height_on_a)?;
         let prefix_on_a = conn_end_on_b.counterparty().prefix();
         let port_id_on_a = msg.port_id_on_a.clone();
         let chan_id_on_a = msg.chan_id_on_a.clone();
@@ -168,17 +162,31 @@ where
         )?;
         let chan_end_path_on_a = ChannelEndPath::new(&port_id_on_a, &chan_id_on_a);
 
+        ctx_b
+            .resource_meter()
+            .charge_proof_verification(msg.proof_chan_end_on_a.as_bytes().len())?;
+
+        let proof_chan_end_on_a = MsgProof::from(&msg.proof_chan_end_on_a);
+
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_a_on_b
+        counterparty_state_on_b
             .verify_membership(
+                ctx_b,
                 prefix_on_a,
-                &msg.proof_chan_end_on_a,
-                consensus_state_of_a_on_b.root(),
+                &proof_chan_end_on_a,
                 Path::ChannelEnd(chan_end_path_on_a),
-                expected_chan_end_on_a.encode_vec(),
+                compute_channel_commitment(&expected_chan_end_on_a),
             )
-            .map_err(ChannelError::VerifyChannelFailed)?;
+            .map_err(|e| {
+                chan_end_verification_error(
+                    &port_id_on_a,
+                    &chan_id_on_a,
+                    &expected_chan_end_on_a,
+                    proof_chan_end_on_a.proved_value(),
+                    e,
+                )
+            })?;
     }
 
     Ok(())
@@ -0,0 +1,244 @@
+//! Verification of the proofs of the counterparty, either through the client
+//! of the connection or, on the `connection-localhost` sentinel, against the
+//! store of the host itself as the `09-localhost` client does.
+
+use ibc_core_channel_types::commitment::{
+    compute_channel_commitment, compute_receipt_commitment, compute_sequence_commitment,
+};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::context::verify::MsgProof;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core_commitment_types::error::CommitmentError;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::path::{ClientConsensusStatePath, Path};
+use ibc_core_host::ValidationContext;
+use ibc_primitives::prelude::*;
+use ibc_primitives::{CommitmentHasher, Timestamp};
+
+type ClientStateOf<Ctx> =
+    <<Ctx as ValidationContext>::V as ClientValidationContext>::ClientStateRef;
+type ConsensusStateOf<Ctx> =
+    <<Ctx as ValidationContext>::V as ClientValidationContext>::ConsensusStateRef;
+
+/// The state of the counterparty that the proofs of a message are verified
+/// against, at the height of the proofs.
+pub(crate) enum CounterpartyState<Ctx>
+where
+    Ctx: ValidationContext,
+{
+    /// The counterparty as tracked by the client of the connection.
+    Client {
+        client_state: ClientStateOf<Ctx>,
+        consensus_state: ConsensusStateOf<Ctx>,
+    },
+    /// The host itself, tracked by the `09-localhost` client, whose proofs
+    /// are verified by reading the store of the host instead.
+    ///
+    /// The localhost client is always active, its latest height is the height
+    /// of the host and it has no consensus states: the timestamp of the
+    /// counterparty is the timestamp of the host.
+    Localhost,
+}
+
+impl<Ctx> CounterpartyState<Ctx>
+where
+    Ctx: ValidationContext,
+{
+    /// Looks up the state of the counterparty of the connection at the given
+    /// proof height, checking that its client is active and that the proof
+    /// height is not past its latest height.
+    pub(crate) fn at_height(
+        ctx: &Ctx,
+        conn_end: &ConnectionEnd,
+        proof_height: Height,
+    ) -> Result<Self, ContextError> {
+        if conn_end.is_localhost() {
+            let host_height = ctx.host_height()?;
+
+            if proof_height > host_height {
+                return Err(ClientError::InvalidProofHeight {
+                    latest_height: host_height,
+                    proof_height,
+                }
+                .into());
+            }
+
+            return Ok(Self::Localhost);
+        }
+
+        let client_id = conn_end.client_id();
+        let client_val_ctx = ctx.get_client_validation_context();
+        let client_state = client_val_ctx.client_state(client_id)?;
+
+        client_state
+            .status(client_val_ctx, client_id)?
+            .verify_is_active()?;
+
+        client_state.validate_proof_height(proof_height)?;
+
+        let consensus_state = client_val_ctx.consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            proof_height.revision_number(),
+            proof_height.revision_height(),
+        ))?;
+
+        Ok(Self::Client {
+            client_state,
+            consensus_state,
+        })
+    }
+
+    /// Returns the hash function the counterparty computes its packet and
+    /// acknowledgement commitments with, which is the one of the host on the
+    /// localhost connection.
+    pub(crate) fn commitment_hasher(&self) -> Result<CommitmentHasher, ClientError> {
+        match self {
+            Self::Client { client_state, .. } => client_state.commitment_hasher(),
+            Self::Localhost => Ok(CommitmentHasher::host()),
+        }
+    }
+
+    /// Returns the timestamp of the counterparty at the proof height.
+    pub(crate) fn timestamp(&self, ctx: &Ctx) -> Result<Timestamp, ContextError> {
+        match self {
+            Self::Client {
+                consensus_state, ..
+            } => Ok(consensus_state.timestamp()),
+            Self::Localhost => ctx.host_timestamp(),
+        }
+    }
+
+    /// Verifies that the given value is stored at the given path on the
+    /// counterparty.
+    pub(crate) fn verify_membership(
+        &self,
+        ctx: &Ctx,
+        prefix: &CommitmentPrefix,
+        proof: &MsgProof<'_>,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Client {
+                client_state,
+                consensus_state,
+            } => proof.verify_membership(client_state, prefix, consensus_state.root(), path, value),
+            Self::Localhost => match stored_value(ctx, &path) {
+                Some(stored) if stored == value => Ok(()),
+                _ => Err(ClientError::InvalidCommitmentProof(
+                    CommitmentError::VerificationFailure,
+                )),
+            },
+        }
+    }
+
+    /// Verifies that the given values are stored at their respective paths
+    /// on the counterparty, against a single proof of all of them.
+    pub(crate) fn verify_batch_membership(
+        &self,
+        ctx: &Ctx,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        items: Vec<(Path, Vec<u8>)>,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Client {
+                client_state,
+                consensus_state,
+            } => client_state.verify_batch_membership(prefix, proof, consensus_state.root(), items),
+            Self::Localhost => {
+                items
+                    .into_iter()
+                    .try_for_each(|(path, value)| match stored_value(ctx, &path) {
+                        Some(stored) if stored == value => Ok(()),
+                        _ => Err(ClientError::InvalidCommitmentProof(
+                            CommitmentError::VerificationFailure,
+                        )),
+                    })
+            }
+        }
+    }
+
+    /// Verifies that no value is stored at the given path on the
+    /// counterparty.
+    pub(crate) fn verify_non_membership(
+        &self,
+        ctx: &Ctx,
+        prefix: &CommitmentPrefix,
+        proof: &MsgProof<'_>,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Client {
+                client_state,
+                consensus_state,
+            } => proof.verify_non_membership(client_state, prefix, consensus_state.root(), path),
+            Self::Localhost => match stored_value(ctx, &path) {
+                None => Ok(()),
+                Some(_) => Err(ClientError::InvalidCommitmentProof(
+                    CommitmentError::VerificationFailure,
+                )),
+            },
+        }
+    }
+}
+
+/// Verifies that the client of the connection is active, which the
+/// `09-localhost` client always is.
+pub(crate) fn verify_client_active<Ctx>(
+    ctx: &Ctx,
+    conn_end: &ConnectionEnd,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if conn_end.is_localhost() {
+        return Ok(());
+    }
+
+    let client_id = conn_end.client_id();
+    let client_val_ctx = ctx.get_client_validation_context();
+
+    client_val_ctx
+        .client_state(client_id)?
+        .status(client_val_ctx, client_id)?
+        .verify_is_active()?;
+
+    Ok(())
+}
+
+/// Reads the value committed by the host at the given path, encoded as the
+/// counterparties of the host prove it, or `None` if there is none.
+///
+/// Only the paths that the channel handlers verify are served.
+fn stored_value<Ctx>(ctx: &Ctx, path: &Path) -> Option<Vec<u8>>
+where
+    Ctx: ValidationContext,
+{
+    match path {
+        Path::ChannelEnd(path) => ctx
+            .channel_end(path)
+            .ok()
+            .map(|chan_end| compute_channel_commitment(&chan_end)),
+        Path::Commitment(path) => ctx
+            .get_packet_commitment(path)
+            .ok()
+            .map(|commitment| commitment.into_vec()),
+        Path::Ack(path) => ctx
+            .get_packet_acknowledgement(path)
+            .ok()
+            .map(|ack_commitment| ack_commitment.into_vec()),
+        Path::Receipt(path) => ctx
+            .get_packet_receipt(path)
+            .ok()
+            .map(|receipt| compute_receipt_commitment(&receipt)),
+        Path::SeqRecv(path) => ctx
+            .get_next_sequence_recv(path)
+            .ok()
+            .map(|seq| compute_sequence_commitment(&seq)),
+        _ => None,
+    }
+}
@@ -6,10 +6,14 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod localhost;
+mod packet_lifecycle;
 mod recv_packet;
+mod register_payee;
 mod send_packet;
 mod timeout;
 mod timeout_on_close;
+mod write_acknowledgement;
 
 pub use acknowledgement::*;
 pub use chan_close_confirm::*;
@@ -18,7 +22,76 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::proto::v1::Channel as RawChannel;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::{ChannelId, PortId};
+use ibc_core_host::ValidationContext;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Preserved;
+pub use packet_lifecycle::*;
 pub use recv_packet::*;
+pub use register_payee::*;
 pub use send_packet::*;
 pub use timeout::*;
 pub use timeout_on_close::*;
+pub use write_acknowledgement::*;
+
+/// Checks the local port and the identifier of the channel being opened
+/// against the identifier validation profile of the host.
+pub(crate) fn validate_local_identifiers<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let profile = ctx.identifier_validation_profile();
+
+    profile
+        .validate_port_identifier(port_id.as_str())
+        .map_err(ChannelError::InvalidIdentifier)?;
+    profile
+        .validate_channel_identifier(chan_id.as_str())
+        .map_err(ChannelError::InvalidIdentifier)?;
+
+    Ok(())
+}
+
+/// Returns the error of a failed verification of the counterparty channel
+/// end, reporting the fields in which the end claimed by the proof differs
+/// from the expected one, if the proof carries a value. As the proof failed
+/// verification, the claimed end is unverified and only reported as a hint.
+pub(crate) fn chan_end_verification_error(
+    port_id: &PortId,
+    chan_id: &ChannelId,
+    expected_chan_end: &ChannelEnd,
+    claimed_value: Option<Vec<u8>>,
+    client_error: ClientError,
+) -> ChannelError {
+    // As for connection ends, an end decoding to the expected one still fails
+    // verification if the counterparty encoded it with unknown fields.
+    let diff = claimed_value
+        .and_then(|value| Preserved::<ChannelEnd>::decode::<RawChannel>(&value).ok())
+        .map(|claimed_chan_end| {
+            expected_chan_end.diff(claimed_chan_end.value()).compare(
+                "canonical_encoding",
+                &true,
+                &claimed_chan_end.is_canonical::<RawChannel>(),
+            )
+        })
+        .filter(|diff| !diff.is_empty());
+
+    match diff {
+        Some(diff) => ChannelError::CounterpartyChannelMismatch {
+            port_id: port_id.clone(),
+            channel_id: chan_id.clone(),
+            diff,
+            client_error,
+        },
+        None => ChannelError::VerifyChannelFailed(client_error),
+    }
+}
@@ -0,0 +1,107 @@
+use ibc_core_channel_types::channel::{ChannelEnd, Order};
+use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::path::{AckPath, CommitmentPath, ReceiptPath, SeqRecvPath};
+use ibc_core_host::ValidationContext;
+
+/// The status of a packet being received, as determined by the
+/// [`PacketLifecycleGuard`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecvPacketStatus {
+    /// The packet has not been received yet and must be processed.
+    New,
+    /// The packet has already been received. Receiving it again is a no-op,
+    /// leaving the stored acknowledgement, if any, untouched.
+    AlreadyReceived {
+        acknowledgement: Option<AcknowledgementCommitment>,
+    },
+}
+
+impl RecvPacketStatus {
+    pub fn is_new(&self) -> bool {
+        matches!(self, Self::New)
+    }
+}
+
+/// Guards the packet handlers against processing the same packet twice.
+///
+/// A packet goes through its lifecycle only once:
+/// - Receiving a packet that was already received is a no-op, whatever the
+///   application, so that competing relayers do not fail their transactions.
+/// - Acknowledging or timing out a packet requires its commitment to still be
+///   stored on the sending chain. As the commitment is deleted once the packet
+///   is acknowledged or timed out, acknowledging it twice or timing it out
+///   after its acknowledgement fails with [`PacketError::PacketNotPending`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PacketLifecycleGuard;
+
+impl PacketLifecycleGuard {
+    /// Checks whether the packet was already received on the given channel
+    /// of the receiving chain.
+    ///
+    /// Note that, as in ibc-go, no check is made for channels with an
+    /// [`Order::None`] ordering.
+    pub fn check_recv<Ctx>(
+        ctx_b: &Ctx,
+        chan_end_on_b: &ChannelEnd,
+        packet: &Packet,
+    ) -> Result<RecvPacketStatus, ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        let already_received = match chan_end_on_b.ordering {
+            Order::None => false,
+            Order::Unordered => {
+                let receipt_path_on_b =
+                    ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+                ctx_b.get_packet_receipt(&receipt_path_on_b).is_ok()
+            }
+            Order::Ordered => {
+                let seq_recv_path_on_b =
+                    SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+                let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
+
+                // the sequence number has already been incremented, so
+                // another relayer already relayed the packet
+                packet.seq_on_a < next_seq_recv
+            }
+        };
+
+        if !already_received {
+            return Ok(RecvPacketStatus::New);
+        }
+
+        let ack_path_on_b =
+            AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+        Ok(RecvPacketStatus::AlreadyReceived {
+            acknowledgement: ctx_b.get_packet_acknowledgement(&ack_path_on_b).ok(),
+        })
+    }
+
+    /// Checks that the packet is still pending on the sending chain, i.e.
+    /// neither acknowledged nor timed out, and returns its stored commitment.
+    pub fn check_pending<Ctx>(
+        ctx_a: &Ctx,
+        packet: &Packet,
+    ) -> Result<PacketCommitment, ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        let commitment_path_on_a =
+            CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+
+        ctx_a
+            .get_packet_commitment(&commitment_path_on_a)
+            .map_err(|_| {
+                PacketError::PacketNotPending {
+                    port_id: packet.port_id_on_a.clone(),
+                    channel_id: packet.chan_id_on_a.clone(),
+                    sequence: packet.seq_on_a,
+                }
+                .into()
+            })
+    }
+}
@@ -1,22 +1,31 @@
-use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
-use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::acknowledgement::{RecvPacketResult, StatusValue};
+use ibc_core_channel_types::channel::{Order, State as ChannelState};
+use ibc_core_channel_types::commitment::{
+    compute_ack_commitment, compute_counterparty_packet_commitment,
+};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ReceivePacket, WriteAcknowledgement};
 use ibc_core_channel_types::msgs::MsgRecvPacket;
-use ibc_core_channel_types::packet::Receipt;
-use ibc_core_client::context::prelude::*;
+use ibc_core_channel_types::packet::{Packet, Receipt};
+use ibc_core_client::context::verify::MsgProof;
 use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_connection::localhost::connection_end;
 use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::log::HandlerLog;
+use ibc_core_host::types::identifiers::ChannelId;
 use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
-    SeqRecvPath,
+    AckPath, ChannelEndPath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
+use ibc_core_router::types::module::ModuleExtras;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Expiry;
+use ibc_primitives::Signer;
+
+use super::localhost::CounterpartyState;
+use super::PacketLifecycleGuard;
 
 pub fn recv_packet_validate<ValCtx>(ctx_b: &ValCtx, msg: MsgRecvPacket) -> Result<(), ContextError>
 where
@@ -42,34 +51,30 @@ where
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
 
     // Check if another relayer already relayed the packet.
-    // We don't want to fail the transaction in this case.
-    {
-        let packet_already_received = match chan_end_on_b.ordering {
-            // Note: ibc-go doesn't make the check for `Order::None` channels
-            Order::None => false,
-            Order::Unordered => {
-                let packet = &msg.packet;
-                let receipt_path_on_b =
-                    ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
-                ctx_b.get_packet_receipt(&receipt_path_on_b).is_ok()
-            }
-            Order::Ordered => {
-                let seq_recv_path_on_b =
-                    SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
-                let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
-
-                // the sequence number has already been incremented, so
-                // another relayer already relayed the packet
-                msg.packet.seq_on_a < next_seq_recv
-            }
-        };
-
-        if packet_already_received {
-            return Ok(());
-        }
+    // We don't want to fail the transaction in this case, and the stored
+    // acknowledgement is left untouched.
+    if !PacketLifecycleGuard::check_recv(ctx_b, &chan_end_on_b, &msg.packet)?.is_new() {
+        return Ok(());
     }
 
-    let (extras, acknowledgement) = module.on_recv_packet_execute(&msg.packet, &msg.signer);
+    // Packets whose memo exceeds the limit of the host are rejected with an
+    // error acknowledgement, without being handed to the module.
+    let (extras, mut result) = match verify_memo(ctx_b, &msg.packet) {
+        Ok(()) => module.on_recv_packet_execute(&msg.packet, &msg.signer),
+        Err(e) => (ModuleExtras::empty(), error_result(e)?),
+    };
+
+    // Acknowledgements exceeding the limit of the host are replaced with an
+    // error acknowledgement, rather than failing the packet reception.
+    let ack_size_check = result.acknowledgement().map(|acknowledgement| {
+        ctx_b
+            .packet_size_limits()
+            .verify_acknowledgement(acknowledgement.as_bytes())
+    });
+
+    if let Some(Err(e)) = ack_size_check {
+        result = error_result(e)?;
+    }
 
     // state changes
     {
@@ -92,72 +97,139 @@ where
             }
             _ => {}
         }
-        let ack_path_on_b = AckPath::new(
-            &msg.packet.port_id_on_b,
-            &msg.packet.chan_id_on_b,
-            msg.packet.seq_on_a,
-        );
-        // `writeAcknowledgement` handler state changes
-        ctx_b.store_packet_acknowledgement(
-            &ack_path_on_b,
-            compute_ack_commitment(&acknowledgement),
-        )?;
+
+        // `writeAcknowledgement` handler state changes, unless the
+        // application writes the acknowledgement asynchronously
+        if let Some(acknowledgement) = result.acknowledgement() {
+            let ack_path_on_b = AckPath::new(
+                &msg.packet.port_id_on_b,
+                &msg.packet.chan_id_on_b,
+                msg.packet.seq_on_a,
+            );
+            ctx_b.store_packet_acknowledgement(
+                &ack_path_on_b,
+                compute_ack_commitment(acknowledgement),
+            )?;
+        }
     }
 
     // emit events and logs
     {
-        ctx_b.log_message("success: packet receive".to_string())?;
-        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
+        ctx_b.log(HandlerLog::PacketReceived {
+            port_id_on_b: msg.packet.port_id_on_b.clone(),
+            chan_id_on_b: msg.packet.chan_id_on_b.clone(),
+            sequence: msg.packet.seq_on_a,
+        })?;
 
         let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
-        let event = IbcEvent::ReceivePacket(ReceivePacket::new(
+        let payees = relayer_payees(ctx_b, &msg.signer, &msg.packet.chan_id_on_b)?;
+
+        let mut event = ReceivePacket::new(
             msg.packet.clone(),
             chan_end_on_b.ordering,
             conn_id_on_b.clone(),
-        ));
-        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
-        ctx_b.emit_ibc_event(event)?;
-        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
-            msg.packet,
-            acknowledgement,
-            conn_id_on_b.clone(),
-        ));
+        );
+        if let Some((fee_recipient, counterparty_payee)) = payees.clone() {
+            event = event.with_relayer(msg.signer.clone(), fee_recipient, counterparty_payee);
+        }
+        let event = IbcEvent::ReceivePacket(event);
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(event)?;
 
+        if let Some(acknowledgement) = result.into_acknowledgement() {
+            ctx_b.log(HandlerLog::AcknowledgementWritten {
+                port_id_on_b: msg.packet.port_id_on_b.clone(),
+                chan_id_on_b: msg.packet.chan_id_on_b.clone(),
+                sequence: msg.packet.seq_on_a,
+            })?;
+
+            let mut event =
+                WriteAcknowledgement::new(msg.packet, acknowledgement, conn_id_on_b.clone());
+            if let Some((fee_recipient, counterparty_payee)) = payees {
+                event = event.with_relayer(msg.signer, fee_recipient, counterparty_payee);
+            }
+            let event = IbcEvent::WriteAcknowledgement(event);
+            ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+            ctx_b.emit_ibc_event(event)?;
+        }
+
         for module_event in extras.events {
             ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
         }
 
         for log_message in extras.log {
-            ctx_b.log_message(log_message)?;
+            ctx_b.log(log_message.into())?;
         }
     }
 
     Ok(())
 }
 
+/// Returns the fee recipient and the counterparty payee of the relayer of a
+/// received packet, if the relayer registered any payee on the channel, in
+/// which case the packet is attributed to the relayer in the emitted events.
+fn relayer_payees<Ctx>(
+    ctx_b: &Ctx,
+    relayer: &Signer,
+    chan_id_on_b: &ChannelId,
+) -> Result<Option<(Signer, Option<Signer>)>, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let fee_recipient = ctx_b.fee_recipient(relayer, chan_id_on_b)?;
+    let counterparty_payee = ctx_b.recv_relayer_attribution(relayer, chan_id_on_b)?;
+
+    if &fee_recipient == relayer && counterparty_payee.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some((fee_recipient, counterparty_payee)))
+}
+
+/// Checks the memo of the packet against the limit of the host, if the
+/// packet data codec of the receiving port exposes one. Packet data that fails
+/// to decode is left to the module to reject.
+fn verify_memo<Ctx>(ctx_b: &Ctx, packet: &Packet) -> Result<(), PacketError>
+where
+    Ctx: ValidationContext,
+{
+    let Some(codec) = ctx_b.packet_data_codec(&packet.port_id_on_b) else {
+        return Ok(());
+    };
+
+    match codec.decode_info(&packet.data) {
+        Ok(data) => match data.memo() {
+            Some(memo) => ctx_b.packet_size_limits().verify_memo(memo),
+            None => Ok(()),
+        },
+        Err(_) => Ok(()),
+    }
+}
+
+/// Returns the error result acknowledging the packet with the given error.
+fn error_result(e: PacketError) -> Result<RecvPacketResult, PacketError> {
+    Ok(RecvPacketResult::error(StatusValue::new(e)?))
+}
+
 fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgRecvPacket) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
+    ctx_b.packet_size_limits().verify_data(&msg.packet.data)?;
+
     let chan_end_path_on_b =
         ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
 
     chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
 
-    let counterparty = Counterparty::new(
-        msg.packet.port_id_on_a.clone(),
-        Some(msg.packet.chan_id_on_a.clone()),
-    );
-
-    chan_end_on_b.verify_counterparty_matches(&counterparty)?;
+    chan_end_on_b
+        .verify_counterparty_ids_match(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a)?;
 
     let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
-    let conn_end_on_b = ctx_b.connection_end(conn_id_on_b)?;
+    let conn_end_on_b = connection_end(ctx_b, conn_id_on_b)?;
 
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
@@ -171,32 +243,25 @@ where
     }
 
     let latest_timestamp = ctx_b.host_timestamp()?;
-    if let Expiry::Expired = latest_timestamp.check_expiry(&msg.packet.timeout_timestamp_on_b) {
-        return Err(PacketError::LowPacketTimestamp.into());
+    if latest_timestamp.is_after(&msg.packet.timeout_timestamp_on_b) {
+        return Err(PacketError::LowPacketTimestamp {
+            chain_timestamp: latest_timestamp,
+            timeout_timestamp: msg.packet.timeout_timestamp_on_b,
+        }
+        .into());
     }
 
+    ctx_b
+        .resource_meter()
+        .charge_packet_data(msg.packet.data.len())?;
+
     // Verify proofs
     {
-        let client_id_on_b = conn_end_on_b.client_id();
-        let client_val_ctx_b = ctx_b.get_client_validation_context();
-        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
-
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
-            .verify_is_active()?;
-
-        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
+        let counterparty_state_on_b =
+            CounterpartyState::at_height(ctx_b, &conn_end_on_b, msg.proof_height_on_a)?;
 
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-
-        let expected_commitment_on_a = compute_packet_commitment(
+        let expected_commitment_on_a = compute_counterparty_packet_commitment(
+            counterparty_state_on_b.commitment_hasher()?,
             &msg.packet.data,
             &msg.packet.timeout_height_on_b,
             &msg.packet.timeout_timestamp_on_b,
@@ -209,12 +274,16 @@ where
 
         verify_conn_delay_passed(ctx_b, msg.proof_height_on_a, &conn_end_on_b)?;
 
+        ctx_b
+            .resource_meter()
+            .charge_proof_verification(msg.proof_commitment_on_a.as_bytes().len())?;
+
         // Verify the proof for the packet against the chain store.
-        client_state_of_a_on_b
+        counterparty_state_on_b
             .verify_membership(
+                ctx_b,
                 conn_end_on_b.counterparty().prefix(),
-                &msg.proof_commitment_on_a,
-                consensus_state_of_a_on_b.root(),
+                &MsgProof::from(&msg.proof_commitment_on_a),
                 Path::Commitment(commitment_path_on_a),
                 expected_commitment_on_a.into_vec(),
             )
@@ -237,30 +306,8 @@ where
                 }
                 .into());
             }
-
-            if msg.packet.seq_on_a == next_seq_recv {
-                // Case where the recvPacket is successful and an
-                // acknowledgement will be written (not a no-op)
-                validate_write_acknowledgement(ctx_b, msg)?;
-            }
-        }
-        Order::Unordered => {
-            let receipt_path_on_b = ReceiptPath::new(
-                &msg.packet.port_id_on_a,
-                &msg.packet.chan_id_on_a,
-                msg.packet.seq_on_a,
-            );
-            let packet_rec = ctx_b.get_packet_receipt(&receipt_path_on_b);
-            match packet_rec {
-                Ok(_receipt) => {}
-                Err(ContextError::PacketError(PacketError::PacketReceiptNotFound { sequence }))
-                    if sequence == msg.packet.seq_on_a => {}
-                Err(e) => return Err(e),
-            }
-            // Case where the recvPacket is successful and an
-            // acknowledgement will be written (not a no-op)
-            validate_write_acknowledgement(ctx_b, msg)?;
         }
+        Order::Unordered => {}
         Order::None => {
             return Err(ContextError::ChannelError(ChannelError::InvalidOrderType {
                 expected: "Channel ordering cannot be None".to_string(),
@@ -269,6 +316,12 @@ where
         }
     }
 
+    if PacketLifecycleGuard::check_recv(ctx_b, &chan_end_on_b, &msg.packet)?.is_new() {
+        // Case where the recvPacket is successful and an
+        // acknowledgement will be written (not a no-op)
+        validate_write_acknowledgement(ctx_b, msg)?;
+    }
+
     Ok(())
 }
 
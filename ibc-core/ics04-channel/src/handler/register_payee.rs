@@ -0,0 +1,108 @@
+//! Protocol logic for registering the addresses of the relayers of a channel,
+//! i.e. for messages of type `MsgRegisterPayee` and
+//! `MsgRegisterCounterpartyPayee`.
+use ibc_core_channel_types::msgs::{MsgRegisterCounterpartyPayee, MsgRegisterPayee};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::{ChannelId, PortId};
+use ibc_core_host::types::path::{ChannelEndPath, CounterpartyPayeePath, PayeePath};
+use ibc_core_host::ValidationContext;
+use ibc_core_router::types::event::ModuleEvent;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+use crate::context::RelayerPayeeExecutionContext;
+
+pub const REGISTER_PAYEE_EVENT: &str = "register_payee";
+pub const REGISTER_COUNTERPARTY_PAYEE_EVENT: &str = "register_counterparty_payee";
+
+pub fn register_payee_validate<ValCtx>(
+    ctx: &ValCtx,
+    msg: &MsgRegisterPayee,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx, &msg.port_id, &msg.channel_id, &msg.relayer)
+}
+
+pub fn register_payee_execute<ExecCtx>(
+    ctx: &mut ExecCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), ContextError>
+where
+    ExecCtx: RelayerPayeeExecutionContext,
+{
+    let payee_path = PayeePath::new(&msg.relayer, &msg.channel_id);
+    ctx.store_payee(&payee_path, msg.payee.clone())?;
+
+    ctx.log_message(format!(
+        "success: registered payee {} for relayer {} on channel {}",
+        msg.payee, msg.relayer, msg.channel_id
+    ))?;
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+    ctx.emit_ibc_event(IbcEvent::Module(ModuleEvent {
+        kind: REGISTER_PAYEE_EVENT.to_string(),
+        attributes: vec![
+            ("relayer", msg.relayer).into(),
+            ("payee", msg.payee).into(),
+            ("channel_id", msg.channel_id).into(),
+        ],
+    }))?;
+
+    Ok(())
+}
+
+pub fn register_counterparty_payee_validate<ValCtx>(
+    ctx: &ValCtx,
+    msg: &MsgRegisterCounterpartyPayee,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx, &msg.port_id, &msg.channel_id, &msg.relayer)
+}
+
+pub fn register_counterparty_payee_execute<ExecCtx>(
+    ctx: &mut ExecCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), ContextError>
+where
+    ExecCtx: RelayerPayeeExecutionContext,
+{
+    let counterparty_payee_path = CounterpartyPayeePath::new(&msg.relayer, &msg.channel_id);
+    ctx.store_counterparty_payee(&counterparty_payee_path, msg.counterparty_payee.clone())?;
+
+    ctx.log_message(format!(
+        "success: registered counterparty payee {} for relayer {} on channel {}",
+        msg.counterparty_payee, msg.relayer, msg.channel_id
+    ))?;
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+    ctx.emit_ibc_event(IbcEvent::Module(ModuleEvent {
+        kind: REGISTER_COUNTERPARTY_PAYEE_EVENT.to_string(),
+        attributes: vec![
+            ("relayer", msg.relayer).into(),
+            ("counterparty_payee", msg.counterparty_payee).into(),
+            ("channel_id", msg.channel_id).into(),
+        ],
+    }))?;
+
+    Ok(())
+}
+
+/// Checks that the channel exists and that the relayer signed the message.
+fn validate<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    relayer: &Signer,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx.validate_message_signer(relayer)?;
+
+    ctx.channel_end(&ChannelEndPath::new(port_id, channel_id))?;
+
+    Ok(())
+}
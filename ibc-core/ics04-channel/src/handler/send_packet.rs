@@ -1,16 +1,23 @@
-use ibc_core_channel_types::channel::Counterparty;
 use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::PacketError;
 use ibc_core_channel_types::events::SendPacket;
 use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_channel_types::timeout_policy::TimeoutPolicy;
 use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::Height;
+use ibc_core_connection::types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::log::HandlerLog;
+use ibc_core_host::types::identifiers::{ChannelId, PortId};
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, SeqSendPath,
 };
+use ibc_core_router::port::{authenticate_port_owner, PortKeeper};
+use ibc_core_router::types::module::ModuleId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Expiry;
+use ibc_primitives::Timestamp;
 
 use crate::context::{SendPacketExecutionContext, SendPacketValidationContext};
 
@@ -25,11 +32,68 @@ pub fn send_packet(
     send_packet_execute(ctx_a, packet)
 }
 
+/// Computes the timeouts of a packet about to be sent on the given channel,
+/// following the given policy, from the latest height and timestamp of the
+/// counterparty chain known to the client of the channel.
+pub fn compute_packet_timeouts(
+    ctx_a: &impl SendPacketValidationContext,
+    port_id_on_a: &PortId,
+    chan_id_on_a: &ChannelId,
+    policy: &TimeoutPolicy,
+) -> Result<(TimeoutHeight, Timestamp), ContextError> {
+    let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
+    let conn_end_on_a = ctx_a.connection_end(conn_id_on_a)?;
+
+    let (latest_height_on_a, latest_timestamp) = latest_counterparty_state(ctx_a, &conn_end_on_a)?;
+
+    Ok(policy.timeouts(latest_height_on_a, latest_timestamp)?)
+}
+
+/// Returns the latest height and timestamp of the counterparty known to the
+/// client of the connection, which are those of the host itself on the
+/// `connection-localhost` sentinel.
+fn latest_counterparty_state(
+    ctx_a: &impl SendPacketValidationContext,
+    conn_end_on_a: &ConnectionEnd,
+) -> Result<(Height, Timestamp), ContextError> {
+    if conn_end_on_a.is_localhost() {
+        return Ok((ctx_a.host_height()?, ctx_a.host_timestamp()?));
+    }
+
+    let client_id_on_a = conn_end_on_a.client_id();
+    let client_val_ctx_a = ctx_a.get_client_validation_context();
+    let latest_height_on_a = client_val_ctx_a
+        .client_state(client_id_on_a)?
+        .latest_height();
+
+    let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+        client_id_on_a.clone(),
+        latest_height_on_a.revision_number(),
+        latest_height_on_a.revision_height(),
+    );
+    let latest_timestamp = client_val_ctx_a
+        .consensus_state(&client_cons_state_path_on_a)?
+        .timestamp();
+
+    Ok((latest_height_on_a, latest_timestamp))
+}
+
 /// Validate that sending the given packet would succeed.
 pub fn send_packet_validate(
     ctx_a: &impl SendPacketValidationContext,
     packet: &Packet,
 ) -> Result<(), ContextError> {
+    ctx_a.host_mode().verify_allows_send_packet()?;
+
+    ctx_a.packet_size_limits().verify_data(&packet.data)?;
+
+    if let Some(codec) = ctx_a.packet_data_codec(&packet.port_id_on_a) {
+        codec.validate_bytes(&packet.data)?;
+    }
+
     if !packet.timeout_height_on_b.is_set() && !packet.timeout_timestamp_on_b.is_set() {
         return Err(ContextError::PacketError(PacketError::MissingTimeout));
     }
@@ -41,28 +105,23 @@ pub fn send_packet_validate(
     // This allows for optimistic packet processing before a channel opens
     chan_end_on_a.verify_not_closed()?;
 
-    let counterparty = Counterparty::new(
-        packet.port_id_on_b.clone(),
-        Some(packet.chan_id_on_b.clone()),
-    );
-
-    chan_end_on_a.verify_counterparty_matches(&counterparty)?;
+    chan_end_on_a.verify_counterparty_ids_match(&packet.port_id_on_b, &packet.chan_id_on_b)?;
 
     let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
 
     let conn_end_on_a = ctx_a.connection_end(conn_id_on_a)?;
 
-    let client_id_on_a = conn_end_on_a.client_id();
-
-    let client_val_ctx_a = ctx_a.get_client_validation_context();
-
-    let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+    if !conn_end_on_a.is_localhost() {
+        let client_id_on_a = conn_end_on_a.client_id();
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
 
-    client_state_of_b_on_a
-        .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-        .verify_is_active()?;
+        client_val_ctx_a
+            .client_state(client_id_on_a)?
+            .status(client_val_ctx_a, client_id_on_a)?
+            .verify_is_active()?;
+    }
 
-    let latest_height_on_a = client_state_of_b_on_a.latest_height();
+    let (latest_height_on_a, latest_timestamp) = latest_counterparty_state(ctx_a, &conn_end_on_a)?;
 
     if packet.timeout_height_on_b.has_expired(latest_height_on_a) {
         return Err(PacketError::LowPacketHeight {
@@ -72,17 +131,13 @@ pub fn send_packet_validate(
         .into());
     }
 
-    let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-        client_id_on_a.clone(),
-        latest_height_on_a.revision_number(),
-        latest_height_on_a.revision_height(),
-    );
-    let consensus_state_of_b_on_a =
-        client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-    let latest_timestamp = consensus_state_of_b_on_a.timestamp();
     let packet_timestamp = packet.timeout_timestamp_on_b;
-    if let Expiry::Expired = latest_timestamp.check_expiry(&packet_timestamp) {
-        return Err(PacketError::LowPacketTimestamp.into());
+    if latest_timestamp.is_after(&packet_timestamp) {
+        return Err(PacketError::LowPacketTimestamp {
+            chain_timestamp: latest_timestamp,
+            timeout_timestamp: packet_timestamp,
+        }
+        .into());
     }
 
     let seq_send_path_on_a = SeqSendPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
@@ -96,9 +151,32 @@ pub fn send_packet_validate(
         .into());
     }
 
+    ctx_a
+        .resource_meter()
+        .charge_packet_data(packet.data.len())?;
+
     Ok(())
 }
 
+/// Validate that the module identified by `module_id` owns the source port of
+/// the packet, and that sending the given packet would succeed.
+///
+/// Applications that are not routed through the host's [`Router`] should call
+/// this instead of [`send_packet_validate`] to enforce the ICS-05 port
+/// ownership semantics.
+///
+/// [`Router`]: ibc_core_router::router::Router
+pub fn send_packet_validate_with_owner(
+    ctx_a: &impl SendPacketValidationContext,
+    port_keeper: &impl PortKeeper,
+    module_id: &ModuleId,
+    packet: &Packet,
+) -> Result<(), ContextError> {
+    authenticate_port_owner(port_keeper, &packet.port_id_on_a, module_id)?;
+
+    send_packet_validate(ctx_a, packet)
+}
+
 /// Send the packet without any validation.
 ///
 /// A prior call to [`send_packet_validate`] MUST have succeeded.
@@ -113,14 +191,25 @@ pub fn send_packet_execute(
         ctx_a.store_next_sequence_send(&seq_send_path_on_a, next_seq_send_on_a.increment())?;
     }
 
-    ctx_a.store_packet_commitment(
-        &CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a),
-        compute_packet_commitment(
-            &packet.data,
+    {
+        let commitment_path_on_a =
+            CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+
+        ctx_a.store_packet_commitment(
+            &commitment_path_on_a,
+            compute_packet_commitment(
+                &packet.data,
+                &packet.timeout_height_on_b,
+                &packet.timeout_timestamp_on_b,
+            ),
+        )?;
+
+        ctx_a.index_packet_timeout(
+            &commitment_path_on_a,
             &packet.timeout_height_on_b,
             &packet.timeout_timestamp_on_b,
-        ),
-    )?;
+        )?;
+    }
 
     // emit events and logs
     {
@@ -128,7 +217,11 @@ pub fn send_packet_execute(
         let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
         let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
 
-        ctx_a.log_message("success: packet send".to_string())?;
+        ctx_a.log(HandlerLog::PacketSent {
+            port_id_on_a: packet.port_id_on_a.clone(),
+            chan_id_on_a: packet.chan_id_on_a.clone(),
+            sequence: packet.seq_on_a,
+        })?;
         let event = IbcEvent::SendPacket(SendPacket::new(
             packet,
             chan_end_on_a.ordering,
@@ -1,20 +1,21 @@
-use ibc_core_channel_types::channel::{Counterparty, Order, State};
-use ibc_core_channel_types::commitment::compute_packet_commitment;
+use ibc_core_channel_types::channel::{Order, State};
+use ibc_core_channel_types::commitment::{compute_packet_commitment, compute_sequence_commitment};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ChannelClosed, TimeoutPacket};
 use ibc_core_channel_types::msgs::{MsgTimeout, MsgTimeoutOnClose};
-use ibc_core_client::context::prelude::*;
+use ibc_core_client::context::verify::MsgProof;
 use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_connection::localhost::connection_end;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
-};
+use ibc_core_handler_types::log::HandlerLog;
+use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath, Path, ReceiptPath, SeqRecvPath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
-use super::timeout_on_close;
+use super::localhost::CounterpartyState;
+use super::{timeout_on_close, PacketLifecycleGuard};
 
 pub enum TimeoutMsgType {
     Timeout(MsgTimeout),
@@ -59,6 +60,9 @@ where
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
+    // The packet must not have been acknowledged or timed out already
+    PacketLifecycleGuard::check_pending(ctx_a, &packet)?;
+
     // In all cases, this event is emitted
     let event = IbcEvent::TimeoutPacket(TimeoutPacket::new(packet.clone(), chan_end_on_a.ordering));
     ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
@@ -67,15 +71,6 @@ where
     let commitment_path_on_a =
         CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
 
-    // check if we're in the NO-OP case
-    if ctx_a.get_packet_commitment(&commitment_path_on_a).is_err() {
-        // This error indicates that the timeout has already been relayed
-        // or there is a misconfigured relayer attempting to prove a timeout
-        // for a packet never sent. Core IBC will treat this error as a no-op in order to
-        // prevent an entire relay transaction from failing and consuming unnecessary fees.
-        return Ok(());
-    };
-
     let (extras, cb_result) = module.on_timeout_packet_execute(&packet, &signer);
 
     cb_result?;
@@ -83,6 +78,7 @@ where
     // apply state changes
     let chan_end_on_a = {
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
+        ctx_a.unindex_packet_timeout(&commitment_path_on_a)?;
 
         if let Order::Ordered = chan_end_on_a.ordering {
             let mut chan_end_on_a = chan_end_on_a;
@@ -97,7 +93,11 @@ where
 
     // emit events and logs
     {
-        ctx_a.log_message("success: packet timeout".to_string())?;
+        ctx_a.log(HandlerLog::PacketTimedOut {
+            port_id_on_a: packet.port_id_on_a.clone(),
+            chan_id_on_a: packet.chan_id_on_a.clone(),
+            sequence: packet.seq_on_a,
+        })?;
 
         if let Order::Ordered = chan_end_on_a.ordering {
             let conn_id_on_a = chan_end_on_a.connection_hops()[0].clone();
@@ -119,7 +119,7 @@ where
         }
 
         for log_message in extras.log {
-            ctx_a.log_message(log_message)?;
+            ctx_a.log(log_message.into())?;
         }
     }
 
@@ -139,29 +139,18 @@ where
 
     chan_end_on_a.verify_state_matches(&State::Open)?;
 
-    let counterparty = Counterparty::new(
-        msg.packet.port_id_on_b.clone(),
-        Some(msg.packet.chan_id_on_b.clone()),
-    );
+    chan_end_on_a
+        .verify_counterparty_ids_match(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b)?;
 
-    chan_end_on_a.verify_counterparty_matches(&counterparty)?;
+    let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
+    let conn_end_on_a = connection_end(ctx_a, conn_id_on_a)?;
 
-    let conn_id_on_a = chan_end_on_a.connection_hops()[0].clone();
-    let conn_end_on_a = ctx_a.connection_end(&conn_id_on_a)?;
+    //verify packet commitment, which must still be pending
+    let commitment_on_a = PacketLifecycleGuard::check_pending(ctx_a, &msg.packet)?;
 
-    //verify packet commitment
-    let commitment_path_on_a = CommitmentPath::new(
-        &msg.packet.port_id_on_a,
-        &msg.packet.chan_id_on_a,
-        msg.packet.seq_on_a,
-    );
-    let Ok(commitment_on_a) = ctx_a.get_packet_commitment(&commitment_path_on_a) else {
-        // This error indicates that the timeout has already been relayed
-        // or there is a misconfigured relayer attempting to prove a timeout
-        // for a packet never sent. Core IBC will treat this error as a no-op in order to
-        // prevent an entire relay transaction from failing and consuming unnecessary fees.
-        return Ok(());
-    };
+    ctx_a
+        .resource_meter()
+        .charge_packet_data(msg.packet.data.len())?;
 
     let expected_commitment_on_a = compute_packet_commitment(
         &msg.packet.data,
@@ -170,6 +159,8 @@ where
     );
     if commitment_on_a != expected_commitment_on_a {
         return Err(PacketError::IncorrectPacketCommitment {
+            port_id: msg.packet.port_id_on_a.clone(),
+            channel_id: msg.packet.chan_id_on_a.clone(),
             sequence: msg.packet.seq_on_a,
         }
         .into());
@@ -177,25 +168,9 @@ where
 
     // Verify proofs
     {
-        let client_id_on_a = conn_end_on_a.client_id();
-        let client_val_ctx_a = ctx_a.get_client_validation_context();
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
-
-        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
-
-        // check that timeout height or timeout timestamp has passed on the other end
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let timestamp_of_b = consensus_state_of_b_on_a.timestamp();
+        let counterparty_state_on_a =
+            CounterpartyState::at_height(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
+        let timestamp_of_b = counterparty_state_on_a.timestamp(ctx_a)?;
 
         if !msg.packet.timed_out(&timestamp_of_b, msg.proof_height_on_b) {
             return Err(PacketError::PacketTimeoutNotReached {
@@ -209,6 +184,10 @@ where
 
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
+        ctx_a
+            .resource_meter()
+            .charge_proof_verification(msg.proof_unreceived_on_b.as_bytes().len())?;
+
         let next_seq_recv_verification_result = match chan_end_on_a.ordering {
             Order::Ordered => {
                 if msg.packet.seq_on_a < msg.next_seq_recv_on_b {
@@ -221,12 +200,12 @@ where
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
 
-                client_state_of_b_on_a.verify_membership(
+                counterparty_state_on_a.verify_membership(
+                    ctx_a,
                     conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
+                    &MsgProof::from(&msg.proof_unreceived_on_b),
                     Path::SeqRecv(seq_recv_path_on_b),
-                    msg.packet.seq_on_a.to_vec(),
+                    compute_sequence_commitment(&msg.packet.seq_on_a),
                 )
             }
             Order::Unordered => {
@@ -236,10 +215,10 @@ where
                     msg.packet.seq_on_a,
                 );
 
-                client_state_of_b_on_a.verify_non_membership(
+                counterparty_state_on_a.verify_non_membership(
+                    ctx_a,
                     conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
+                    &MsgProof::from(&msg.proof_unreceived_on_b),
                     Path::Receipt(receipt_path_on_b),
                 )
             }
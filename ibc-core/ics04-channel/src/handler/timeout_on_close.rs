@@ -1,16 +1,19 @@
 use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State};
-use ibc_core_channel_types::commitment::compute_packet_commitment;
+use ibc_core_channel_types::commitment::{
+    compute_channel_commitment, compute_packet_commitment, compute_sequence_commitment,
+};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::msgs::MsgTimeoutOnClose;
-use ibc_core_client::context::prelude::*;
+use ibc_core_client::context::verify::MsgProof;
 use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_connection::localhost::connection_end;
 use ibc_core_handler_types::error::ContextError;
-use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
-};
+use ibc_core_host::types::path::{ChannelEndPath, Path, ReceiptPath, SeqRecvPath};
 use ibc_core_host::ValidationContext;
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
+
+use super::localhost::CounterpartyState;
+use super::{chan_end_verification_error, PacketLifecycleGuard};
 
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgTimeoutOnClose) -> Result<(), ContextError>
 where
@@ -22,27 +25,14 @@ where
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
-    let counterparty = Counterparty::new(
-        packet.port_id_on_b.clone(),
-        Some(packet.chan_id_on_b.clone()),
-    );
-
-    chan_end_on_a.verify_counterparty_matches(&counterparty)?;
+    chan_end_on_a.verify_counterparty_ids_match(&packet.port_id_on_b, &packet.chan_id_on_b)?;
 
-    let commitment_path_on_a = CommitmentPath::new(
-        &msg.packet.port_id_on_a,
-        &msg.packet.chan_id_on_a,
-        msg.packet.seq_on_a,
-    );
+    //verify the packet was sent and is still pending, check the store
+    let commitment_on_a = PacketLifecycleGuard::check_pending(ctx_a, packet)?;
 
-    //verify the packet was sent, check the store
-    let Ok(commitment_on_a) = ctx_a.get_packet_commitment(&commitment_path_on_a) else {
-        // This error indicates that the timeout has already been relayed
-        // or there is a misconfigured relayer attempting to prove a timeout
-        // for a packet never sent. Core IBC will treat this error as a no-op in order to
-        // prevent an entire relay transaction from failing and consuming unnecessary fees.
-        return Ok(());
-    };
+    ctx_a
+        .resource_meter()
+        .charge_packet_data(packet.data.len())?;
 
     let expected_commitment_on_a = compute_packet_commitment(
         &packet.data,
@@ -51,33 +41,20 @@ where
     );
     if commitment_on_a != expected_commitment_on_a {
         return Err(PacketError::IncorrectPacketCommitment {
+            port_id: packet.port_id_on_a.clone(),
+            channel_id: packet.chan_id_on_a.clone(),
             sequence: packet.seq_on_a,
         }
         .into());
     }
 
-    let conn_id_on_a = chan_end_on_a.connection_hops()[0].clone();
-    let conn_end_on_a = ctx_a.connection_end(&conn_id_on_a)?;
+    let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
+    let conn_end_on_a = connection_end(ctx_a, conn_id_on_a)?;
 
     // Verify proofs
     {
-        let client_id_on_a = conn_end_on_a.client_id();
-        let client_val_ctx_a = ctx_a.get_client_validation_context();
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
-
-        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
-
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+        let counterparty_state_on_a =
+            CounterpartyState::at_height(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
         let prefix_on_b = conn_end_on_a.counterparty().prefix();
         let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
         let chan_id_on_b = chan_end_on_a
@@ -102,23 +79,87 @@ where
             chan_end_on_a.version().clone(),
         )?;
 
-        let chan_end_path_on_b = ChannelEndPath(port_id_on_b, chan_id_on_b.clone());
+        let chan_end_path_on_b = ChannelEndPath(port_id_on_b.clone(), chan_id_on_b.clone());
+
+        // On ordered channels, the closed channel end and the next sequence to
+        // receive may be proven by a single batch proof, which is verified once.
+        if chan_end_on_a.ordering == Order::Ordered
+            && msg.proof_unreceived_on_b == msg.proof_close_on_b
+        {
+            if packet.seq_on_a < msg.next_seq_recv_on_b {
+                return Err(PacketError::InvalidPacketSequence {
+                    given_sequence: packet.seq_on_a,
+                    next_sequence: msg.next_seq_recv_on_b,
+                }
+                .into());
+            }
+
+            ctx_a
+                .resource_meter()
+                .charge_proof_verification(msg.proof_close_on_b.as_bytes().len())?;
+
+            let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+
+            counterparty_state_on_a
+                .verify_batch_membership(
+                    ctx_a,
+                    prefix_on_b,
+                    &msg.proof_close_on_b,
+                    vec![
+                        (
+                            Path::ChannelEnd(chan_end_path_on_b),
+                            compute_channel_commitment(&expected_chan_end_on_b),
+                        ),
+                        (
+                            Path::SeqRecv(seq_recv_path_on_b),
+                            compute_sequence_commitment(&packet.seq_on_a),
+                        ),
+                    ],
+                )
+                .map_err(|e| ChannelError::PacketVerificationFailed {
+                    sequence: msg.next_seq_recv_on_b,
+                    client_error: e,
+                })
+                .map_err(PacketError::Channel)?;
+
+            verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
+
+            return Ok(());
+        }
+
+        ctx_a
+            .resource_meter()
+            .charge_proof_verification(msg.proof_close_on_b.as_bytes().len())?;
+
+        let proof_close_on_b = MsgProof::from(&msg.proof_close_on_b);
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a
+        counterparty_state_on_a
             .verify_membership(
+                ctx_a,
                 prefix_on_b,
-                &msg.proof_close_on_b,
-                consensus_state_of_b_on_a.root(),
+                &proof_close_on_b,
                 Path::ChannelEnd(chan_end_path_on_b),
-                expected_chan_end_on_b.encode_vec(),
+                compute_channel_commitment(&expected_chan_end_on_b),
             )
-            .map_err(ChannelError::VerifyChannelFailed)
+            .map_err(|e| {
+                chan_end_verification_error(
+                    &port_id_on_b,
+                    &chan_id_on_b,
+                    &expected_chan_end_on_b,
+                    proof_close_on_b.proved_value(),
+                    e,
+                )
+            })
             .map_err(PacketError::Channel)?;
 
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
+        ctx_a
+            .resource_meter()
+            .charge_proof_verification(msg.proof_unreceived_on_b.as_bytes().len())?;
+
         let next_seq_recv_verification_result = match chan_end_on_a.ordering {
             Order::Ordered => {
                 if packet.seq_on_a < msg.next_seq_recv_on_b {
@@ -131,12 +172,12 @@ where
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
 
-                client_state_of_b_on_a.verify_membership(
+                counterparty_state_on_a.verify_membership(
+                    ctx_a,
                     conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
+                    &MsgProof::from(&msg.proof_unreceived_on_b),
                     Path::SeqRecv(seq_recv_path_on_b),
-                    packet.seq_on_a.to_vec(),
+                    compute_sequence_commitment(&packet.seq_on_a),
                 )
             }
             Order::Unordered => {
@@ -146,10 +187,10 @@ where
                     msg.packet.seq_on_a,
                 );
 
-                client_state_of_b_on_a.verify_non_membership(
+                counterparty_state_on_a.verify_non_membership(
+                    ctx_a,
                     conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
+                    &MsgProof::from(&msg.proof_unreceived_on_b),
                     Path::Receipt(receipt_path_on_b),
                 )
             }
@@ -0,0 +1,127 @@
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{Order, State as ChannelState};
+use ibc_core_channel_types::commitment::compute_ack_commitment;
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::events::WriteAcknowledgement;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_handler_types::log::HandlerLog;
+use ibc_core_host::types::path::{AckPath, ChannelEndPath, ReceiptPath, SeqRecvPath};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+/// Writes the acknowledgement of a packet that was received earlier, including
+/// all necessary validation.
+///
+/// Applications whose `on_recv_packet_execute` callback returned
+/// [`RecvPacketResult::Async`] call this once they are done processing the
+/// packet. The acknowledgement is written as is, so applications wrapped by
+/// middlewares go through the `write_acknowledgement` entrypoints of the
+/// handler instead, which wrap it in the acknowledgements of the middlewares.
+///
+/// Equivalent to calling [`write_acknowledgement_validate`], followed by
+/// [`write_acknowledgement_execute`].
+///
+/// [`RecvPacketResult::Async`]: ibc_core_channel_types::acknowledgement::RecvPacketResult::Async
+pub fn write_acknowledgement<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    write_acknowledgement_validate(ctx_b, &packet, &acknowledgement)?;
+    write_acknowledgement_execute(ctx_b, packet, acknowledgement)
+}
+
+/// Validate that writing the acknowledgement of the given packet would succeed.
+pub fn write_acknowledgement_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    ctx_b
+        .packet_size_limits()
+        .verify_acknowledgement(acknowledgement.as_bytes())?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
+
+    // The packet must have gone through `recvPacket` beforehand.
+    let packet_received = match chan_end_on_b.ordering {
+        Order::Unordered => {
+            let receipt_path_on_b =
+                ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+            ctx_b.get_packet_receipt(&receipt_path_on_b).is_ok()
+        }
+        Order::Ordered => {
+            let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+            let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
+
+            packet.seq_on_a < next_seq_recv
+        }
+        Order::None => false,
+    };
+
+    if !packet_received {
+        return Err(PacketError::PacketReceiptNotFound {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_acknowledgement(&ack_path_on_b).is_ok() {
+        return Err(PacketError::AcknowledgementExists {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Write the acknowledgement of the given packet without any validation.
+///
+/// A prior call to [`write_acknowledgement_validate`] MUST have succeeded.
+pub fn write_acknowledgement_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    ctx_b.store_packet_acknowledgement(&ack_path_on_b, compute_ack_commitment(&acknowledgement))?;
+
+    // emit events and logs
+    {
+        ctx_b.log(HandlerLog::AcknowledgementWritten {
+            port_id_on_b: packet.port_id_on_b.clone(),
+            chan_id_on_b: packet.chan_id_on_b.clone(),
+            sequence: packet.seq_on_a,
+        })?;
+
+        let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
+        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+            packet,
+            acknowledgement,
+            conn_id_on_b.clone(),
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(event)?;
+    }
+
+    Ok(())
+}
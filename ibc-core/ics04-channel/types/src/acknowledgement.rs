@@ -56,6 +56,7 @@ impl TryFrom<Vec<u8>> for Acknowledgement {
 /// [`Acknowledgement`] based on the
 /// success or failure of processing a received packet.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AcknowledgementStatus {
     /// Successful Acknowledgement
@@ -72,6 +73,7 @@ pub enum AcknowledgementStatus {
 /// [`AcknowledgementStatus`]
 /// against being constructed with an empty value.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StatusValue(String);
 
@@ -123,7 +125,8 @@ impl Display for AcknowledgementStatus {
 impl From<AcknowledgementStatus> for Vec<u8> {
     fn from(ack: AcknowledgementStatus) -> Self {
         // WARNING: Make sure all branches always return a non-empty vector.
-        // Otherwise, the conversion to `Acknowledgement` will panic.
+        // Otherwise, the conversion to `Acknowledgement` yields an invalid,
+        // empty acknowledgement.
         match ack {
             AcknowledgementStatus::Success(v) => alloc::format!(r#"{{"result":"{v}"}}"#).into(),
             AcknowledgementStatus::Error(v) => alloc::format!(r#"{{"error":"{v}"}}"#).into(),
@@ -133,9 +136,99 @@ impl From<AcknowledgementStatus> for Vec<u8> {
 
 impl From<AcknowledgementStatus> for Acknowledgement {
     fn from(ack_status: AcknowledgementStatus) -> Self {
-        let v: Vec<u8> = ack_status.into();
+        // The conversion into bytes always yields a non-empty JSON object.
+        Self(ack_status.into())
+    }
+}
+
+/// The outcome of an application's `onRecvPacket` callback.
+///
+/// Unlike a bare [`Acknowledgement`], it tells middleware and the core handler
+/// whether processing succeeded, and why it failed, without having to decode
+/// application-specific acknowledgement bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecvPacketResult {
+    /// The packet was processed; the acknowledgement is written right away.
+    Success(Acknowledgement),
+    /// The packet was rejected; the error acknowledgement is written right
+    /// away.
+    Error {
+        acknowledgement: Acknowledgement,
+        reason: StatusValue,
+    },
+    /// The application writes the acknowledgement later on, once it completes
+    /// processing the packet.
+    Async,
+}
 
-        v.try_into()
-            .expect("token transfer internal error: ack is never supposed to be empty")
+impl RecvPacketResult {
+    /// Creates an error result, whose acknowledgement is the JSON-encoded
+    /// [`AcknowledgementStatus::Error`] of the given reason.
+    pub fn error(reason: StatusValue) -> Self {
+        Self::Error {
+            acknowledgement: AcknowledgementStatus::error(reason.clone()).into(),
+            reason,
+        }
+    }
+
+    /// Returns the acknowledgement to write, or `None` if it is written
+    /// asynchronously.
+    pub fn acknowledgement(&self) -> Option<&Acknowledgement> {
+        match self {
+            Self::Success(ack)
+            | Self::Error {
+                acknowledgement: ack,
+                ..
+            } => Some(ack),
+            Self::Async => None,
+        }
+    }
+
+    /// Consumes the result and returns the acknowledgement to write, or `None`
+    /// if it is written asynchronously.
+    pub fn into_acknowledgement(self) -> Option<Acknowledgement> {
+        match self {
+            Self::Success(ack)
+            | Self::Error {
+                acknowledgement: ack,
+                ..
+            } => Some(ack),
+            Self::Async => None,
+        }
+    }
+
+    /// Returns true if the packet was processed successfully.
+    pub fn is_successful(&self) -> bool {
+        matches!(self, Self::Success(_))
+    }
+
+    /// Returns true if the acknowledgement is written asynchronously.
+    pub fn is_async(&self) -> bool {
+        matches!(self, Self::Async)
+    }
+
+    /// Transforms the acknowledgement, if any, keeping the outcome as is.
+    /// Typically used by middleware wrapping acknowledgements.
+    pub fn map_acknowledgement(self, f: impl FnOnce(Acknowledgement) -> Acknowledgement) -> Self {
+        match self {
+            Self::Success(ack) => Self::Success(f(ack)),
+            Self::Error {
+                acknowledgement,
+                reason,
+            } => Self::Error {
+                acknowledgement: f(acknowledgement),
+                reason,
+            },
+            Self::Async => Self::Async,
+        }
+    }
+}
+
+impl From<AcknowledgementStatus> for RecvPacketResult {
+    fn from(ack_status: AcknowledgementStatus) -> Self {
+        match ack_status {
+            AcknowledgementStatus::Success(_) => Self::Success(ack_status.into()),
+            AcknowledgementStatus::Error(reason) => Self::error(reason),
+        }
     }
 }
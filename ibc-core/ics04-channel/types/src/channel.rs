@@ -6,6 +6,7 @@ use core::str::FromStr;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc_primitives::prelude::*;
 use ibc_primitives::utils::PrettySlice;
+use ibc_primitives::StateDiff;
 use ibc_proto::ibc::core::channel::v1::{
     Channel as RawChannel, Counterparty as RawCounterparty,
     IdentifiedChannel as RawIdentifiedChannel,
@@ -134,6 +135,12 @@ impl TryFrom<RawChannel> for ChannelEnd {
     type Error = ChannelError;
 
     fn try_from(value: RawChannel) -> Result<Self, Self::Error> {
+        // Channel upgrades are not supported, and their sequence would be
+        // dropped by the conversion
+        if value.upgrade_sequence != 0 {
+            return Err(ChannelError::UnsupportedChannelUpgradeSequence);
+        }
+
         let chan_state: State = State::from_i32(value.state)?;
 
         let chan_ordering = Order::from_i32(value.ordering)?;
@@ -248,6 +255,30 @@ impl ChannelEnd {
         &self.version
     }
 
+    /// Returns the fields of the channel end claimed by the counterparty which
+    /// differ from this expected end.
+    pub fn diff(&self, claimed: &Self) -> StateDiff {
+        StateDiff::new()
+            .compare("state", &self.state, &claimed.state)
+            .compare("ordering", &self.ordering, &claimed.ordering)
+            .compare(
+                "counterparty.port_id",
+                &self.remote.port_id,
+                &claimed.remote.port_id,
+            )
+            .compare(
+                "counterparty.channel_id",
+                &self.remote.channel_id,
+                &claimed.remote.channel_id,
+            )
+            .compare(
+                "connection_hops",
+                &self.connection_hops,
+                &claimed.connection_hops,
+            )
+            .compare("version", &self.version, &claimed.version)
+    }
+
     pub fn validate_basic(&self) -> Result<(), ChannelError> {
         if self.state == State::Uninitialized {
             return Err(ChannelError::InvalidState {
@@ -312,6 +343,23 @@ impl ChannelEnd {
         Ok(())
     }
 
+    /// Checks if the counterparty of this channel end is the given port and
+    /// channel, without building the expected counterparty unless they differ.
+    pub fn verify_counterparty_ids_match(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        let counterparty = self.counterparty();
+        if counterparty.port_id() != port_id || counterparty.channel_id() != Some(channel_id) {
+            return Err(ChannelError::InvalidCounterparty {
+                expected: Counterparty::new(port_id.clone(), Some(channel_id.clone())),
+                actual: counterparty.clone(),
+            });
+        }
+        Ok(())
+    }
+
     /// Checks if the `connection_hops` has a length of `expected`.
     ///
     /// Note: Current IBC version only supports one connection hop.
@@ -440,6 +488,7 @@ impl From<Counterparty> for RawCounterparty {
     derive(
         parity_scale_codec::Encode,
         parity_scale_codec::Decode,
+        parity_scale_codec::MaxEncodedLen,
         scale_info::TypeInfo
     )
 )]
@@ -509,6 +558,7 @@ impl FromStr for Order {
     derive(
         parity_scale_codec::Encode,
         parity_scale_codec::Decode,
+        parity_scale_codec::MaxEncodedLen,
         scale_info::TypeInfo
     )
 )]
@@ -579,3 +629,35 @@ impl Display for State {
         write!(f, "{}", self.as_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel_end() -> ChannelEnd {
+        ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+            vec![ConnectionId::new(0)],
+            Version::new("ics20-1".to_string()),
+        )
+        .expect("valid channel end")
+    }
+
+    #[test]
+    fn test_channel_end_diff() {
+        let expected = channel_end();
+
+        assert!(expected.diff(&channel_end()).is_empty());
+
+        let mut claimed = channel_end();
+        claimed.set_counterparty_channel_id(ChannelId::new(2));
+        claimed.set_version(Version::new("ics20-2".to_string()));
+
+        let diff = expected.diff(&claimed);
+        let fields: Vec<_> = diff.mismatches().iter().map(|m| m.field).collect();
+
+        assert_eq!(fields, vec!["counterparty.channel_id", "version"]);
+    }
+}
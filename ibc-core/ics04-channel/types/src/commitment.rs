@@ -1,9 +1,27 @@
-//! Types and utilities related to packet commitments.
+//! Types and utilities related to the commitments stored by the IBC handlers.
+//!
+//! Centralizes the byte layouts committed under the provable paths of ICS-24,
+//! which must be identical to the ones of ibc-go for the proofs of a host to be
+//! verified by its counterparties:
+//!
+//! - the packet and acknowledgement commitments are hashes, computed with the
+//!   [`DefaultHostHasher`] (SHA-256 unless configured otherwise) for those of
+//!   the host, and with the [`CommitmentHasher`] of a counterparty, given by
+//!   the client state tracking it, for those of the counterparty,
+//! - the packet receipts and sequences are raw bytes,
+//! - the channel ends are their Protobuf encoding, as are the connection ends,
+//!   whose commitment lives in `ibc_core_connection_types::commitment`.
 
+use ibc_core_host_types::identifiers::Sequence;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::proto::Protobuf;
+use ibc_primitives::{
+    Blake2sHasher, CommitmentHasher, DefaultHostHasher, HostHasher, Sha256Hasher, Timestamp,
+};
 
 use super::acknowledgement::Acknowledgement;
+use crate::channel::ChannelEnd;
+use crate::packet::Receipt;
 use crate::timeout::TimeoutHeight;
 
 /// Packet commitment
@@ -78,7 +96,7 @@ impl From<Vec<u8>> for AcknowledgementCommitment {
     }
 }
 
-/// Compute the commitment for a packet.
+/// Compute the commitment for a packet with the [`DefaultHostHasher`].
 ///
 /// Note that the absence of `timeout_height` is treated as
 /// `{revision_number: 0, revision_height: 0}` to be consistent with ibc-go,
@@ -88,30 +106,92 @@ pub fn compute_packet_commitment(
     packet_data: &[u8],
     timeout_height: &TimeoutHeight,
     timeout_timestamp: &Timestamp,
+) -> PacketCommitment {
+    compute_packet_commitment_with::<DefaultHostHasher>(
+        packet_data,
+        timeout_height,
+        timeout_timestamp,
+    )
+}
+
+/// Compute the commitment a counterparty stores for a packet, with the hash
+/// function of the counterparty rather than the [`DefaultHostHasher`].
+pub fn compute_counterparty_packet_commitment(
+    hasher: CommitmentHasher,
+    packet_data: &[u8],
+    timeout_height: &TimeoutHeight,
+    timeout_timestamp: &Timestamp,
+) -> PacketCommitment {
+    match hasher {
+        CommitmentHasher::Sha256 => compute_packet_commitment_with::<Sha256Hasher>(
+            packet_data,
+            timeout_height,
+            timeout_timestamp,
+        ),
+        CommitmentHasher::Blake2s => compute_packet_commitment_with::<Blake2sHasher>(
+            packet_data,
+            timeout_height,
+            timeout_timestamp,
+        ),
+    }
+}
+
+/// Compute the commitment for a packet with the given hash function.
+pub fn compute_packet_commitment_with<H: HostHasher>(
+    packet_data: &[u8],
+    timeout_height: &TimeoutHeight,
+    timeout_timestamp: &Timestamp,
 ) -> PacketCommitment {
     let mut hash_input = [0; 8 * 3 + 32];
 
     hash_input[..8].copy_from_slice(&timeout_timestamp.nanoseconds().to_be_bytes());
     hash_input[8..16].copy_from_slice(&timeout_height.commitment_revision_number().to_be_bytes());
     hash_input[16..24].copy_from_slice(&timeout_height.commitment_revision_height().to_be_bytes());
-    hash_input[24..].copy_from_slice(&hash(packet_data));
+    hash_input[24..].copy_from_slice(&H::hash(packet_data));
 
-    hash(&hash_input).to_vec().into()
+    H::hash(&hash_input).to_vec().into()
 }
 
-/// Compute the commitment for an acknowledgement.
+/// Compute the commitment for an acknowledgement with the
+/// [`DefaultHostHasher`].
 pub fn compute_ack_commitment(ack: &Acknowledgement) -> AcknowledgementCommitment {
-    hash(ack.as_ref()).to_vec().into()
+    compute_ack_commitment_with::<DefaultHostHasher>(ack)
 }
 
-/// Helper function to hash a byte slice using SHA256.
-///
-/// Note that computing commitments with anything other than SHA256 will
-/// break the Merkle proofs of the IBC provable store.
-fn hash(data: &[u8]) -> [u8; 32] {
-    use sha2::Digest;
+/// Compute the commitment a counterparty stores for an acknowledgement, with
+/// the hash function of the counterparty rather than the
+/// [`DefaultHostHasher`].
+pub fn compute_counterparty_ack_commitment(
+    hasher: CommitmentHasher,
+    ack: &Acknowledgement,
+) -> AcknowledgementCommitment {
+    hasher.hash(ack.as_ref()).to_vec().into()
+}
 
-    sha2::Sha256::digest(data).into()
+/// Compute the commitment for an acknowledgement with the given hash
+/// function.
+pub fn compute_ack_commitment_with<H: HostHasher>(
+    ack: &Acknowledgement,
+) -> AcknowledgementCommitment {
+    H::hash(ack.as_ref()).to_vec().into()
+}
+
+/// Compute the commitment for a packet receipt, which is a single `0x01` byte.
+pub fn compute_receipt_commitment(receipt: &Receipt) -> Vec<u8> {
+    match receipt {
+        Receipt::Ok => vec![1],
+    }
+}
+
+/// Compute the commitment for the next send, receive or acknowledgement
+/// sequence of a channel, which is its big-endian encoding.
+pub fn compute_sequence_commitment(seq: &Sequence) -> Vec<u8> {
+    seq.to_vec()
+}
+
+/// Compute the commitment for a channel end, which is its Protobuf encoding.
+pub fn compute_channel_commitment(channel_end: &ChannelEnd) -> Vec<u8> {
+    channel_end.clone().encode_vec()
 }
 
 #[cfg(test)]
@@ -125,7 +205,7 @@ mod test {
             0x45, 0x79, 0x4f, 0x05, 0x9e, 0x65, 0x91, 0x08, 0x16, 0x86, 0x61, 0x26, 0xdc, 0x36,
             0x4f, 0x84, 0xcc, 0x15,
         ];
-        let actual = compute_packet_commitment(
+        let actual = compute_packet_commitment_with::<Sha256Hasher>(
             b"packet data",
             &TimeoutHeight::At(ibc_core_client_types::Height::new(42, 24).unwrap()),
             &Timestamp::from_nanoseconds(0x42).unwrap(),
@@ -141,7 +221,133 @@ mod test {
             0xf0, 0xc9, 0x90, 0xd8,
         ];
         let ack = Acknowledgement::try_from(vec![0, 1, 2, 3]).unwrap();
-        let actual = compute_ack_commitment(&ack);
+        let actual = compute_ack_commitment_with::<Sha256Hasher>(&ack);
         assert_eq!(&expected[..], actual.as_ref())
     }
+
+    #[test]
+    fn test_counterparty_commitments_follow_their_hasher() {
+        let timeout_height = TimeoutHeight::At(ibc_core_client_types::Height::new(42, 24).unwrap());
+        let timeout_timestamp = Timestamp::from_nanoseconds(0x42).unwrap();
+        let ack = Acknowledgement::try_from(vec![0, 1, 2, 3]).unwrap();
+
+        assert_eq!(
+            compute_counterparty_packet_commitment(
+                CommitmentHasher::Sha256,
+                b"packet data",
+                &timeout_height,
+                &timeout_timestamp
+            ),
+            compute_packet_commitment_with::<Sha256Hasher>(
+                b"packet data",
+                &timeout_height,
+                &timeout_timestamp
+            )
+        );
+        assert_eq!(
+            compute_counterparty_ack_commitment(CommitmentHasher::Sha256, &ack),
+            compute_ack_commitment_with::<Sha256Hasher>(&ack)
+        );
+
+        // A counterparty sharing the hash function of the host commits to
+        // packets and acknowledgements as the host does.
+        assert_eq!(
+            compute_counterparty_packet_commitment(
+                CommitmentHasher::host(),
+                b"packet data",
+                &timeout_height,
+                &timeout_timestamp
+            ),
+            compute_packet_commitment(b"packet data", &timeout_height, &timeout_timestamp)
+        );
+        assert_eq!(
+            compute_counterparty_ack_commitment(CommitmentHasher::host(), &ack),
+            compute_ack_commitment(&ack)
+        );
+    }
+
+    #[test]
+    fn test_blake2s_commitments() {
+        let expected: [u8; 32] = [
+            0xe4, 0x88, 0x48, 0xcf, 0xe8, 0xe2, 0xda, 0x4b, 0xaf, 0x1e, 0x22, 0x47, 0x94, 0xd4,
+            0x1f, 0x4d, 0xdb, 0x63, 0x86, 0x61, 0xbb, 0x33, 0xf3, 0x1f, 0x18, 0x0f, 0xa5, 0xd4,
+            0xdb, 0xac, 0xfd, 0xcf,
+        ];
+        let timeout_height = TimeoutHeight::At(ibc_core_client_types::Height::new(42, 24).unwrap());
+        let timeout_timestamp = Timestamp::from_nanoseconds(0x42).unwrap();
+
+        let commitment = compute_counterparty_packet_commitment(
+            CommitmentHasher::Blake2s,
+            b"packet data",
+            &timeout_height,
+            &timeout_timestamp,
+        );
+
+        assert_eq!(&expected[..], commitment.as_ref());
+
+        let expected: [u8; 32] = [
+            0x0c, 0xc7, 0x0e, 0x00, 0x34, 0x8b, 0x86, 0xba, 0x29, 0x44, 0xd0, 0xc3, 0x20, 0x38,
+            0xb2, 0x5c, 0x55, 0x58, 0x4f, 0x90, 0xdf, 0x23, 0x04, 0xf5, 0x5f, 0xa3, 0x32, 0xaf,
+            0x5f, 0xb0, 0x1e, 0x20,
+        ];
+        let ack = Acknowledgement::try_from(vec![0, 1, 2, 3]).unwrap();
+
+        assert_eq!(
+            &expected[..],
+            compute_counterparty_ack_commitment(CommitmentHasher::Blake2s, &ack).as_ref()
+        );
+    }
+
+    #[cfg(ibc_host_hasher = "blake2s")]
+    #[test]
+    fn test_blake2s_host_commitments() {
+        let timeout_height = TimeoutHeight::At(ibc_core_client_types::Height::new(42, 24).unwrap());
+        let timeout_timestamp = Timestamp::from_nanoseconds(0x42).unwrap();
+        let ack = Acknowledgement::try_from(vec![0, 1, 2, 3]).unwrap();
+
+        assert_eq!(
+            compute_packet_commitment(b"packet data", &timeout_height, &timeout_timestamp),
+            compute_packet_commitment_with::<Blake2sHasher>(
+                b"packet data",
+                &timeout_height,
+                &timeout_timestamp
+            )
+        );
+        assert_eq!(
+            compute_ack_commitment(&ack),
+            compute_ack_commitment_with::<Blake2sHasher>(&ack)
+        );
+    }
+
+    #[test]
+    fn test_compute_receipt_and_sequence_commitments() {
+        assert_eq!(compute_receipt_commitment(&Receipt::Ok), [0x01]);
+        assert_eq!(
+            compute_sequence_commitment(&Sequence::from(0x0102)),
+            [0, 0, 0, 0, 0, 0, 0x01, 0x02]
+        );
+    }
+
+    #[test]
+    fn test_compute_channel_commitment() {
+        use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+
+        use crate::channel::{Counterparty, Order, State};
+        use crate::Version;
+
+        // state, ordering, counterparty, connection hops, version
+        let expected: &[u8] = b"\x08\x03\x10\x01\
+            \x1a\x15\x0a\x08transfer\x12\x09channel-0\
+            \x22\x0cconnection-0\
+            \x2a\x07ics20-1";
+        let channel_end = ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::new(0))),
+            vec![ConnectionId::new(0)],
+            Version::new("ics20-1".to_string()),
+        )
+        .unwrap();
+        assert_eq!(compute_channel_commitment(&channel_end), expected);
+    }
 }
@@ -6,7 +6,7 @@ use ibc_core_connection_types::error as connection_error;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::{ParseTimestampError, Timestamp};
+use ibc_primitives::{ErrorCode, ParseTimestampError, StateDiff, Timestamp};
 
 use super::channel::Counterparty;
 use super::timeout::TimeoutHeight;
@@ -73,6 +73,53 @@ pub enum ChannelError {
     CounterOverflow,
     /// other error: `{description}`
     Other { description: String },
+    /// closing channel `{channel_id}` on port `{port_id}` is not allowed
+    ChannelCloseNotAllowed {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// counterparty channel end (`{port_id}`, `{channel_id}`) claimed by the proof does not match the expected one: `{diff}`
+    CounterpartyChannelMismatch {
+        port_id: PortId,
+        channel_id: ChannelId,
+        diff: StateDiff,
+        client_error: client_error::ClientError,
+    },
+}
+
+impl ChannelError {
+    /// Returns the stable error code of this error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("channel", code);
+
+        match self {
+            Self::InvalidChannelEnd { .. } => code(1),
+            Self::InvalidChannelId { .. } => code(2),
+            Self::InvalidState { .. } => code(3),
+            Self::InvalidOrderType { .. } => code(4),
+            Self::InvalidConnectionHopsLength { .. } => code(5),
+            Self::InvalidSigner { .. } => code(6),
+            Self::MissingHeight => code(7),
+            Self::NonUtf8PacketData => code(8),
+            Self::MissingCounterparty => code(9),
+            Self::UnsupportedChannelUpgradeSequence => code(10),
+            Self::VersionNotSupported { .. } => code(11),
+            Self::MissingChannel => code(12),
+            Self::ChannelNotFound { .. } => code(13),
+            Self::PacketVerificationFailed { .. } => code(14),
+            Self::VerifyChannelFailed(_) => code(15),
+            Self::InvalidStringAsSequence { .. } => code(16),
+            Self::InvalidCounterparty { .. } => code(17),
+            Self::AppModule { .. } => code(18),
+            Self::UndefinedConnectionCounterparty { .. } => code(19),
+            Self::InvalidProof => code(20),
+            Self::InvalidIdentifier(_) => code(21),
+            Self::CounterOverflow => code(22),
+            Self::Other { .. } => code(23),
+            Self::ChannelCloseNotAllowed { .. } => code(24),
+            Self::CounterpartyChannelMismatch { .. } => code(25),
+        }
+    }
 }
 
 #[derive(Debug, Display)]
@@ -86,8 +133,11 @@ pub enum PacketError {
         chain_height: Height,
         timeout_height: TimeoutHeight,
     },
-    /// Receiving chain block timestamp >= packet timeout timestamp
-    LowPacketTimestamp,
+    /// Receiving chain block timestamp `{chain_timestamp}` >= packet timeout timestamp `{timeout_timestamp}`
+    LowPacketTimestamp {
+        chain_timestamp: Timestamp,
+        timeout_timestamp: Timestamp,
+    },
     /// Invalid packet sequence `{given_sequence}` ≠ next send sequence `{next_sequence}`
     InvalidPacketSequence {
         given_sequence: Sequence,
@@ -99,8 +149,12 @@ pub enum PacketError {
     ConnectionNotOpen { connection_id: ConnectionId },
     /// Receipt for the packet `{sequence}` not found
     PacketReceiptNotFound { sequence: Sequence },
-    /// The stored commitment of the packet `{sequence}` is incorrect
-    IncorrectPacketCommitment { sequence: Sequence },
+    /// The stored commitment of the packet `{sequence}` on port `{port_id}` and channel `{channel_id}` is incorrect
+    IncorrectPacketCommitment {
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+    },
     /// implementation specific error
     ImplementationSpecific,
     /// Undefined counterparty connection for `{connection_id}`
@@ -168,6 +222,77 @@ pub enum PacketError {
     },
     /// other error: `{description}`
     Other { description: String },
+    /// packet data of `{length}` bytes exceeds the maximum length of `{max_length}` bytes
+    PacketDataTooLong { length: usize, max_length: usize },
+    /// memo of `{length}` bytes exceeds the maximum length of `{max_length}` bytes
+    MemoTooLong { length: usize, max_length: usize },
+    /// acknowledgement of `{length}` bytes exceeds the maximum length of `{max_length}` bytes
+    AcknowledgementTooLong { length: usize, max_length: usize },
+    /// invalid packet data: `{reason}`
+    InvalidPacketData { reason: String },
+    /// packet timeout overflows
+    TimeoutOverflow,
+    /// unsafe packet timeout: `{description}`
+    UnsafeTimeout { description: String },
+    /// packet `{sequence}` on port `{port_id}` and channel `{channel_id}` is not pending: it was already acknowledged or timed out, or was never sent
+    PacketNotPending {
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+    },
+}
+
+impl PacketError {
+    /// Returns the stable error code of this error.
+    ///
+    /// Errors wrapping another IBC error report the code of the wrapped error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("packet", code);
+
+        match self {
+            Self::Connection(e) => e.code(),
+            Self::Channel(e) => e.code(),
+            Self::LowPacketHeight { .. } => code(1),
+            Self::LowPacketTimestamp { .. } => code(2),
+            Self::InvalidPacketSequence { .. } => code(3),
+            Self::InvalidChannelState { .. } => code(4),
+            Self::ConnectionNotOpen { .. } => code(5),
+            Self::PacketReceiptNotFound { .. } => code(6),
+            Self::IncorrectPacketCommitment { .. } => code(7),
+            Self::ImplementationSpecific => code(8),
+            Self::UndefinedConnectionCounterparty { .. } => code(9),
+            Self::InvalidProof => code(10),
+            Self::PacketTimeoutNotReached { .. } => code(11),
+            Self::AcknowledgementExists { .. } => code(12),
+            Self::InvalidAcknowledgement => code(13),
+            Self::EmptyAcknowledgementStatus => code(14),
+            Self::PacketAcknowledgementNotFound { .. } => code(15),
+            Self::MissingHeight => code(16),
+            Self::MissingPacket => code(17),
+            Self::InvalidSigner { .. } => code(18),
+            Self::AppModule { .. } => code(19),
+            Self::RouteNotFound => code(20),
+            Self::ZeroPacketSequence => code(21),
+            Self::ZeroPacketData => code(22),
+            Self::InvalidTimeoutHeight => code(23),
+            Self::InvalidPacketTimestamp(_) => code(24),
+            Self::MissingTimeout => code(25),
+            Self::InvalidIdentifier(_) => code(26),
+            Self::MissingNextSendSeq { .. } => code(27),
+            Self::ChannelNotFound { .. } => code(28),
+            Self::PacketCommitmentNotFound { .. } => code(29),
+            Self::MissingNextRecvSeq { .. } => code(30),
+            Self::MissingNextAckSeq { .. } => code(31),
+            Self::Other { .. } => code(32),
+            Self::PacketDataTooLong { .. } => code(33),
+            Self::MemoTooLong { .. } => code(34),
+            Self::AcknowledgementTooLong { .. } => code(35),
+            Self::InvalidPacketData { .. } => code(36),
+            Self::TimeoutOverflow => code(37),
+            Self::UnsafeTimeout { .. } => code(38),
+            Self::PacketNotPending { .. } => code(39),
+        }
+    }
 }
 
 impl From<IdentifierError> for ChannelError {
@@ -188,6 +313,7 @@ impl std::error::Error for PacketError {
         match &self {
             Self::Connection(e) => Some(e),
             Self::Channel(e) => Some(e),
+            Self::InvalidPacketTimestamp(e) => Some(e),
             Self::InvalidIdentifier(e) => Some(e),
             _ => None,
         }
@@ -201,6 +327,10 @@ impl std::error::Error for ChannelError {
             Self::InvalidIdentifier(e) => Some(e),
             Self::PacketVerificationFailed {
                 client_error: e, ..
+            }
+            | Self::VerifyChannelFailed(e)
+            | Self::CounterpartyChannelMismatch {
+                client_error: e, ..
             } => Some(e),
             Self::InvalidStringAsSequence { error: e, .. } => Some(e),
             _ => None,
@@ -5,7 +5,7 @@ mod packet_attributes;
 
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::{Signer, Timestamp};
 use tendermint::abci;
 
 use self::channel_attributes::{
@@ -15,8 +15,8 @@ use self::channel_attributes::{
 };
 use self::packet_attributes::{
     AcknowledgementAttribute, ChannelOrderingAttribute, DstChannelIdAttribute, DstPortIdAttribute,
-    PacketConnectionIdAttribute, PacketDataAttribute, SequenceAttribute, SrcChannelIdAttribute,
-    SrcPortIdAttribute, TimeoutHeightAttribute, TimeoutTimestampAttribute,
+    PacketConnectionIdAttribute, PacketDataAttribute, PacketRelayerAttribute, SequenceAttribute,
+    SrcChannelIdAttribute, SrcPortIdAttribute, TimeoutHeightAttribute, TimeoutTimestampAttribute,
 };
 use super::acknowledgement::Acknowledgement;
 use super::channel::Order;
@@ -675,7 +675,7 @@ impl TryFrom<SendPacket> for abci::Event {
 
     fn try_from(v: SendPacket) -> Result<Self, Self::Error> {
         let mut attributes = Vec::with_capacity(11);
-        attributes.append(&mut v.packet_data_attr.try_into()?);
+        v.packet_data_attr.append_to(&mut attributes)?;
         attributes.push(v.timeout_height_attr_on_b.into());
         attributes.push(v.timeout_timestamp_attr_on_b.into());
         attributes.push(v.seq_attr_on_a.into());
@@ -718,6 +718,7 @@ pub struct ReceivePacket {
     chan_id_attr_on_b: DstChannelIdAttribute,
     channel_ordering_attr: ChannelOrderingAttribute,
     conn_id_attr_on_b: PacketConnectionIdAttribute,
+    relayer_attr: Option<PacketRelayerAttribute>,
 }
 
 impl ReceivePacket {
@@ -733,6 +734,25 @@ impl ReceivePacket {
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             channel_ordering_attr: channel_ordering.into(),
             conn_id_attr_on_b: dst_connection_id.into(),
+            relayer_attr: None,
+        }
+    }
+
+    /// Attributes the packet to the relayer which delivered it, along with
+    /// the payees registered by the relayer on the channel.
+    pub fn with_relayer(
+        self,
+        relayer: Signer,
+        fee_recipient: Signer,
+        counterparty_payee: Option<Signer>,
+    ) -> Self {
+        Self {
+            relayer_attr: Some(PacketRelayerAttribute {
+                relayer,
+                fee_recipient,
+                counterparty_payee,
+            }),
+            ..self
         }
     }
 
@@ -776,6 +796,25 @@ impl ReceivePacket {
         &self.conn_id_attr_on_b.connection_id
     }
 
+    /// Returns the relayer which delivered the packet, if attributed.
+    pub fn relayer(&self) -> Option<&Signer> {
+        self.relayer_attr.as_ref().map(|attr| &attr.relayer)
+    }
+
+    /// Returns the address to which the fees earned by the relayer are
+    /// distributed, if attributed.
+    pub fn fee_recipient(&self) -> Option<&Signer> {
+        self.relayer_attr.as_ref().map(|attr| &attr.fee_recipient)
+    }
+
+    /// Returns the counterparty address to which the packet is attributed, if
+    /// the relayer registered one.
+    pub fn counterparty_payee(&self) -> Option<&Signer> {
+        self.relayer_attr
+            .as_ref()
+            .and_then(|attr| attr.counterparty_payee.as_ref())
+    }
+
     pub fn event_type(&self) -> &str {
         RECEIVE_PACKET_EVENT
     }
@@ -786,7 +825,7 @@ impl TryFrom<ReceivePacket> for abci::Event {
 
     fn try_from(v: ReceivePacket) -> Result<Self, Self::Error> {
         let mut attributes = Vec::with_capacity(11);
-        attributes.append(&mut v.packet_data_attr.try_into()?);
+        v.packet_data_attr.append_to(&mut attributes)?;
         attributes.push(v.timeout_height_attr_on_b.into());
         attributes.push(v.timeout_timestamp_attr_on_b.into());
         attributes.push(v.seq_attr_on_a.into());
@@ -796,6 +835,9 @@ impl TryFrom<ReceivePacket> for abci::Event {
         attributes.push(v.chan_id_attr_on_b.into());
         attributes.push(v.channel_ordering_attr.into());
         attributes.push(v.conn_id_attr_on_b.into());
+        if let Some(relayer_attr) = v.relayer_attr {
+            relayer_attr.append_to(&mut attributes);
+        }
 
         Ok(abci::Event {
             kind: RECEIVE_PACKET_EVENT.to_string(),
@@ -829,6 +871,7 @@ pub struct WriteAcknowledgement {
     chan_id_attr_on_b: DstChannelIdAttribute,
     acknowledgement: AcknowledgementAttribute,
     conn_id_attr_on_b: PacketConnectionIdAttribute,
+    relayer_attr: Option<PacketRelayerAttribute>,
 }
 
 impl WriteAcknowledgement {
@@ -848,6 +891,25 @@ impl WriteAcknowledgement {
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             acknowledgement: acknowledgement.into(),
             conn_id_attr_on_b: conn_id_on_b.into(),
+            relayer_attr: None,
+        }
+    }
+
+    /// Attributes the acknowledged packet to the relayer which delivered it,
+    /// along with the payees registered by the relayer on the channel.
+    pub fn with_relayer(
+        self,
+        relayer: Signer,
+        fee_recipient: Signer,
+        counterparty_payee: Option<Signer>,
+    ) -> Self {
+        Self {
+            relayer_attr: Some(PacketRelayerAttribute {
+                relayer,
+                fee_recipient,
+                counterparty_payee,
+            }),
+            ..self
         }
     }
 
@@ -891,6 +953,25 @@ impl WriteAcknowledgement {
         &self.conn_id_attr_on_b.connection_id
     }
 
+    /// Returns the relayer which delivered the packet, if attributed.
+    pub fn relayer(&self) -> Option<&Signer> {
+        self.relayer_attr.as_ref().map(|attr| &attr.relayer)
+    }
+
+    /// Returns the address to which the fees earned by the relayer are
+    /// distributed, if attributed.
+    pub fn fee_recipient(&self) -> Option<&Signer> {
+        self.relayer_attr.as_ref().map(|attr| &attr.fee_recipient)
+    }
+
+    /// Returns the counterparty address to which the packet is attributed, if
+    /// the relayer registered one.
+    pub fn counterparty_payee(&self) -> Option<&Signer> {
+        self.relayer_attr
+            .as_ref()
+            .and_then(|attr| attr.counterparty_payee.as_ref())
+    }
+
     pub fn event_type(&self) -> &str {
         WRITE_ACK_EVENT
     }
@@ -900,8 +981,8 @@ impl TryFrom<WriteAcknowledgement> for abci::Event {
     type Error = ChannelError;
 
     fn try_from(v: WriteAcknowledgement) -> Result<Self, Self::Error> {
-        let mut attributes = Vec::with_capacity(11);
-        attributes.append(&mut v.packet_data.try_into()?);
+        let mut attributes = Vec::with_capacity(12);
+        v.packet_data.append_to(&mut attributes)?;
         attributes.push(v.timeout_height_attr_on_b.into());
         attributes.push(v.timeout_timestamp_attr_on_b.into());
         attributes.push(v.seq_attr_on_a.into());
@@ -909,8 +990,11 @@ impl TryFrom<WriteAcknowledgement> for abci::Event {
         attributes.push(v.chan_id_attr_on_a.into());
         attributes.push(v.port_id_attr_on_b.into());
         attributes.push(v.chan_id_attr_on_b.into());
-        attributes.append(&mut v.acknowledgement.try_into()?);
+        v.acknowledgement.append_to(&mut attributes)?;
         attributes.push(v.conn_id_attr_on_b.into());
+        if let Some(relayer_attr) = v.relayer_attr {
+            relayer_attr.append_to(&mut attributes);
+        }
 
         Ok(abci::Event {
             kind: WRITE_ACK_EVENT.to_string(),
@@ -1,13 +1,13 @@
 //! This module holds all the abci event attributes for IBC events emitted
 //! during packet-related datagrams.
 //!
-use core::str;
 
 use derive_more::From;
+use ibc_core_client_types::HEIGHT_STR_CAPACITY;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
-use subtle_encoding::hex;
+use ibc_primitives::utils::{encode_hex, format_with_capacity};
+use ibc_primitives::{Signer, Timestamp};
 use tendermint::abci;
 
 use crate::acknowledgement::Acknowledgement;
@@ -28,6 +28,9 @@ const PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_timeout_timestamp";
 const PKT_ACK_ATTRIBUTE_KEY: &str = "packet_ack";
 const PKT_ACK_HEX_ATTRIBUTE_KEY: &str = "packet_ack_hex";
 const PKT_CONNECTION_ID_ATTRIBUTE_KEY: &str = "packet_connection";
+const PKT_RELAYER_ATTRIBUTE_KEY: &str = "packet_relayer";
+const PKT_RELAYER_PAYEE_ATTRIBUTE_KEY: &str = "packet_relayer_payee";
+const PKT_FORWARD_RELAYER_ATTRIBUTE_KEY: &str = "packet_forward_relayer";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -47,23 +50,27 @@ pub struct PacketDataAttribute {
     pub packet_data: Vec<u8>,
 }
 
+impl PacketDataAttribute {
+    /// Appends the `packet_data` and `packet_data_hex` attributes to the given
+    /// ones, reusing the packet data buffer for the former.
+    pub fn append_to(self, attributes: &mut Vec<abci::EventAttribute>) -> Result<(), ChannelError> {
+        let data_hex = encode_hex(&self.packet_data);
+        let data =
+            String::from_utf8(self.packet_data).map_err(|_| ChannelError::NonUtf8PacketData)?;
+
+        attributes.push((PKT_DATA_ATTRIBUTE_KEY, data).into());
+        attributes.push((PKT_DATA_HEX_ATTRIBUTE_KEY, data_hex).into());
+
+        Ok(())
+    }
+}
+
 impl TryFrom<PacketDataAttribute> for Vec<abci::EventAttribute> {
     type Error = ChannelError;
 
     fn try_from(attr: PacketDataAttribute) -> Result<Self, Self::Error> {
-        let tags = vec![
-            (
-                PKT_DATA_ATTRIBUTE_KEY,
-                str::from_utf8(&attr.packet_data).map_err(|_| ChannelError::NonUtf8PacketData)?,
-            )
-                .into(),
-            (
-                PKT_DATA_HEX_ATTRIBUTE_KEY,
-                str::from_utf8(&hex::encode(attr.packet_data))
-                    .expect("Never fails because hexadecimal is valid UTF8"),
-            )
-                .into(),
-        ];
+        let mut tags = Vec::with_capacity(2);
+        attr.append_to(&mut tags)?;
 
         Ok(tags)
     }
@@ -91,9 +98,11 @@ impl From<TimeoutHeightAttribute> for abci::EventAttribute {
     fn from(attr: TimeoutHeightAttribute) -> Self {
         match attr.timeout_height {
             TimeoutHeight::Never => (PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY, "0-0").into(),
-            TimeoutHeight::At(height) => {
-                (PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY, height.to_string()).into()
-            }
+            TimeoutHeight::At(height) => (
+                PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY,
+                format_with_capacity(height, HEIGHT_STR_CAPACITY),
+            )
+                .into(),
         }
     }
 }
@@ -294,6 +303,46 @@ impl From<PacketConnectionIdAttribute> for abci::EventAttribute {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketRelayerAttribute {
+    pub relayer: Signer,
+    pub fee_recipient: Signer,
+    pub counterparty_payee: Option<Signer>,
+}
+
+impl PacketRelayerAttribute {
+    /// Appends the `packet_relayer` and `packet_relayer_payee` attributes to
+    /// the given ones, followed by `packet_forward_relayer` if the relayer
+    /// registered a counterparty payee.
+    pub fn append_to(self, attributes: &mut Vec<abci::EventAttribute>) {
+        attributes.push((PKT_RELAYER_ATTRIBUTE_KEY, self.relayer.as_ref()).into());
+        attributes.push((PKT_RELAYER_PAYEE_ATTRIBUTE_KEY, self.fee_recipient.as_ref()).into());
+
+        if let Some(counterparty_payee) = self.counterparty_payee {
+            attributes.push(
+                (
+                    PKT_FORWARD_RELAYER_ATTRIBUTE_KEY,
+                    counterparty_payee.as_ref(),
+                )
+                    .into(),
+            );
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -312,28 +361,31 @@ pub struct AcknowledgementAttribute {
     pub acknowledgement: Acknowledgement,
 }
 
+impl AcknowledgementAttribute {
+    /// Appends the `packet_ack` and `packet_ack_hex` attributes to the given
+    /// ones, reusing the acknowledgement buffer for the former.
+    pub fn append_to(self, attributes: &mut Vec<abci::EventAttribute>) -> Result<(), ChannelError> {
+        let ack_hex = encode_hex(self.acknowledgement.as_bytes());
+        // Note: this attribute forces us to assume that Packet data
+        // is valid UTF-8, even though the standard doesn't require
+        // it. It has been deprecated in ibc-go. It will be removed
+        // in the future.
+        let ack = String::from_utf8(self.acknowledgement.into())
+            .map_err(|_| ChannelError::NonUtf8PacketData)?;
+
+        attributes.push((PKT_ACK_ATTRIBUTE_KEY, ack).into());
+        attributes.push((PKT_ACK_HEX_ATTRIBUTE_KEY, ack_hex).into());
+
+        Ok(())
+    }
+}
+
 impl TryFrom<AcknowledgementAttribute> for Vec<abci::EventAttribute> {
     type Error = ChannelError;
 
     fn try_from(attr: AcknowledgementAttribute) -> Result<Self, Self::Error> {
-        let tags = vec![
-            (
-                PKT_ACK_ATTRIBUTE_KEY,
-                // Note: this attribute forces us to assume that Packet data
-                // is valid UTF-8, even though the standard doesn't require
-                // it. It has been deprecated in ibc-go. It will be removed
-                // in the future.
-                str::from_utf8(attr.acknowledgement.as_bytes())
-                    .map_err(|_| ChannelError::NonUtf8PacketData)?,
-            )
-                .into(),
-            (
-                PKT_ACK_HEX_ATTRIBUTE_KEY,
-                str::from_utf8(&hex::encode(attr.acknowledgement))
-                    .expect("Never fails because hexadecimal is always valid UTF-8"),
-            )
-                .into(),
-        ];
+        let mut tags = Vec::with_capacity(2);
+        attr.append_to(&mut tags)?;
 
         Ok(tags)
     }
@@ -1,7 +1,7 @@
 //! Implementation of the Channel and Packet Semantics (ICS-04) data structures.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
 #![deny(
     warnings,
@@ -19,10 +19,13 @@ extern crate std;
 pub mod channel;
 pub mod error;
 pub mod events;
+pub mod limits;
 
 pub mod msgs;
 pub mod packet;
+pub mod packet_data;
 pub mod timeout;
+pub mod timeout_policy;
 
 pub mod acknowledgement;
 pub mod commitment;
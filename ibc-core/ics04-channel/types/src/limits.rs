@@ -0,0 +1,108 @@
+//! Defines the upper bounds on the size of the packets and acknowledgements
+//! handled by a host.
+
+use crate::error::PacketError;
+
+/// The maximum length of the packet data accepted by default, in bytes, which
+/// matches the default maximum size of a CometBFT transaction.
+pub const DEFAULT_MAX_PACKET_DATA_LENGTH: usize = 1_048_576;
+
+/// The maximum length of a memo accepted by default, in bytes, which matches
+/// the limit of ibc-go.
+pub const DEFAULT_MAX_MEMO_LENGTH: usize = 32_768;
+
+/// The maximum length of an acknowledgement accepted by default, in bytes,
+/// which matches the default maximum size of a CometBFT transaction.
+pub const DEFAULT_MAX_ACKNOWLEDGEMENT_LENGTH: usize = 1_048_576;
+
+/// The upper bounds on the size of the packets handled by a host.
+///
+/// The packet data and acknowledgement limits are enforced by the core
+/// handlers when sending, receiving and acknowledging packets. On reception,
+/// the packets whose memo or acknowledgement exceeds its limit are
+/// acknowledged with an error instead. As the memo is part of the application
+/// data, it is only known to the core handlers through the packet data codec
+/// of the receiving port, and its limit is otherwise enforced by the
+/// applications carrying one, e.g. ICS-20 transfers on send.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PacketSizeLimits {
+    pub max_data_length: usize,
+    pub max_memo_length: usize,
+    pub max_ack_length: usize,
+}
+
+impl Default for PacketSizeLimits {
+    fn default() -> Self {
+        Self {
+            max_data_length: DEFAULT_MAX_PACKET_DATA_LENGTH,
+            max_memo_length: DEFAULT_MAX_MEMO_LENGTH,
+            max_ack_length: DEFAULT_MAX_ACKNOWLEDGEMENT_LENGTH,
+        }
+    }
+}
+
+impl PacketSizeLimits {
+    /// Checks that the packet data doesn't exceed the maximum length.
+    pub fn verify_data(&self, data: &[u8]) -> Result<(), PacketError> {
+        if data.len() > self.max_data_length {
+            return Err(PacketError::PacketDataTooLong {
+                length: data.len(),
+                max_length: self.max_data_length,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the memo doesn't exceed the maximum length.
+    pub fn verify_memo(&self, memo: &str) -> Result<(), PacketError> {
+        if memo.len() > self.max_memo_length {
+            return Err(PacketError::MemoTooLong {
+                length: memo.len(),
+                max_length: self.max_memo_length,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the acknowledgement doesn't exceed the maximum length.
+    pub fn verify_acknowledgement(&self, ack: &[u8]) -> Result<(), PacketError> {
+        if ack.len() > self.max_ack_length {
+            return Err(PacketError::AcknowledgementTooLong {
+                length: ack.len(),
+                max_length: self.max_ack_length,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packet_size_limits() {
+        let limits = PacketSizeLimits {
+            max_data_length: 2,
+            max_memo_length: 3,
+            max_ack_length: 4,
+        };
+
+        assert!(limits.verify_data(&[0; 2]).is_ok());
+        assert!(matches!(
+            limits.verify_data(&[0; 3]),
+            Err(PacketError::PacketDataTooLong {
+                length: 3,
+                max_length: 2
+            })
+        ));
+        assert!(limits.verify_memo("abc").is_ok());
+        assert!(limits.verify_memo("abcd").is_err());
+        assert!(limits.verify_acknowledgement(&[0; 4]).is_ok());
+        assert!(limits.verify_acknowledgement(&[0; 5]).is_err());
+    }
+}
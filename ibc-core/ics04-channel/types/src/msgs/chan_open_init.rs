@@ -1,4 +1,4 @@
-use ibc_core_host_types::identifiers::{ConnectionId, PortId};
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc_primitives::prelude::*;
 use ibc_primitives::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenInit as RawMsgChannelOpenInit;
@@ -30,6 +30,19 @@ pub struct MsgChannelOpenInit {
     pub version_proposal: Version,
 }
 
+/// The response to a [`MsgChannelOpenInit`], carrying the identifier the host
+/// allocated to the created channel and the version of the application.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelOpenInitResponse {
+    pub chan_id_on_a: ChannelId,
+    pub version: Version,
+}
+
 impl MsgChannelOpenInit {
     /// Checks if the `connection_hops` has a length of `expected`.
     ///
@@ -38,6 +38,19 @@ pub struct MsgChannelOpenTry {
     pub version_proposal: Version,
 }
 
+/// The response to a [`MsgChannelOpenTry`], carrying the identifier the host
+/// allocated to the created channel and the version of the application.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelOpenTryResponse {
+    pub chan_id_on_b: ChannelId,
+    pub version: Version,
+}
+
 impl MsgChannelOpenTry {
     /// Checks if the `connection_hops` has a length of `expected`.
     ///
@@ -9,6 +9,8 @@ mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
 mod recv_packet;
+mod register_counterparty_payee;
+mod register_payee;
 mod timeout;
 mod timeout_on_close;
 
@@ -25,6 +27,8 @@ pub use chan_open_try::*;
 use ibc_core_host_types::identifiers::*;
 use ibc_primitives::prelude::*;
 pub use recv_packet::*;
+pub use register_counterparty_payee::*;
+pub use register_payee::*;
 pub use timeout::*;
 pub use timeout_on_close::*;
 
@@ -69,6 +73,18 @@ pub fn channel_msg_to_port_id(msg: &ChannelMsg) -> &PortId {
     }
 }
 
+/// Returns the identifier of the local channel the message acts on, if the
+/// channel already exists, i.e. for all messages but `OpenInit` and `OpenTry`.
+pub fn channel_msg_to_channel_id(msg: &ChannelMsg) -> Option<&ChannelId> {
+    match msg {
+        ChannelMsg::OpenInit(_) | ChannelMsg::OpenTry(_) => None,
+        ChannelMsg::OpenAck(msg) => Some(&msg.chan_id_on_a),
+        ChannelMsg::OpenConfirm(msg) => Some(&msg.chan_id_on_b),
+        ChannelMsg::CloseInit(msg) => Some(&msg.chan_id_on_a),
+        ChannelMsg::CloseConfirm(msg) => Some(&msg.chan_id_on_b),
+    }
+}
+
 pub fn packet_msg_to_port_id(msg: &PacketMsg) -> &PortId {
     match msg {
         PacketMsg::Recv(msg) => &msg.packet.port_id_on_b,
@@ -77,3 +93,13 @@ pub fn packet_msg_to_port_id(msg: &PacketMsg) -> &PortId {
         PacketMsg::TimeoutOnClose(msg) => &msg.packet.port_id_on_a,
     }
 }
+
+/// Returns the identifier of the local channel the packet message acts on.
+pub fn packet_msg_to_channel_id(msg: &PacketMsg) -> &ChannelId {
+    match msg {
+        PacketMsg::Recv(msg) => &msg.packet.chan_id_on_b,
+        PacketMsg::Ack(msg) => &msg.packet.chan_id_on_a,
+        PacketMsg::Timeout(msg) => &msg.packet.chan_id_on_a,
+        PacketMsg::TimeoutOnClose(msg) => &msg.packet.chan_id_on_a,
+    }
+}
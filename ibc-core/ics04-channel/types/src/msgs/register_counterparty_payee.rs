@@ -0,0 +1,70 @@
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::applications::fee::v1::MsgRegisterCounterpartyPayee as RawMsgRegisterCounterpartyPayee;
+use ibc_proto::Protobuf;
+
+use crate::error::ChannelError;
+
+pub const REGISTER_COUNTERPARTY_PAYEE_TYPE_URL: &str =
+    "/ibc.applications.fee.v1.MsgRegisterCounterpartyPayee";
+
+///
+/// Message definition for registering the address of a relayer on the
+/// counterparty chain of a channel, to which the packets received by the
+/// relayer on this chain are attributed.
+///
+/// The message is signed by the relayer, and is compatible with the one of the
+/// ibc-go fee module, though the registration does not require the channel to
+/// be fee enabled.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRegisterCounterpartyPayee {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub relayer: Signer,
+    pub counterparty_payee: Signer,
+}
+
+impl Protobuf<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {}
+
+impl TryFrom<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {
+    type Error = ChannelError;
+
+    fn try_from(raw_msg: RawMsgRegisterCounterpartyPayee) -> Result<Self, Self::Error> {
+        if raw_msg.relayer.trim().is_empty() {
+            return Err(ChannelError::InvalidSigner {
+                reason: "empty relayer address".to_string(),
+            });
+        }
+
+        if raw_msg.counterparty_payee.trim().is_empty() {
+            return Err(ChannelError::InvalidSigner {
+                reason: "empty counterparty payee address".to_string(),
+            });
+        }
+
+        Ok(MsgRegisterCounterpartyPayee {
+            port_id: raw_msg.port_id.parse()?,
+            channel_id: raw_msg.channel_id.parse()?,
+            relayer: raw_msg.relayer.into(),
+            counterparty_payee: raw_msg.counterparty_payee.into(),
+        })
+    }
+}
+
+impl From<MsgRegisterCounterpartyPayee> for RawMsgRegisterCounterpartyPayee {
+    fn from(domain_msg: MsgRegisterCounterpartyPayee) -> Self {
+        RawMsgRegisterCounterpartyPayee {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.channel_id.to_string(),
+            relayer: domain_msg.relayer.to_string(),
+            counterparty_payee: domain_msg.counterparty_payee.to_string(),
+        }
+    }
+}
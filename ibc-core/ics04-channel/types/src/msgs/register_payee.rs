@@ -0,0 +1,68 @@
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::applications::fee::v1::MsgRegisterPayee as RawMsgRegisterPayee;
+use ibc_proto::Protobuf;
+
+use crate::error::ChannelError;
+
+pub const REGISTER_PAYEE_TYPE_URL: &str = "/ibc.applications.fee.v1.MsgRegisterPayee";
+
+///
+/// Message definition for registering the address to which the fees earned by
+/// a relayer on a channel are paid out.
+///
+/// The message is signed by the relayer, and is compatible with the one of the
+/// ibc-go fee module, though the registration does not require the channel to
+/// be fee enabled.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRegisterPayee {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub relayer: Signer,
+    pub payee: Signer,
+}
+
+impl Protobuf<RawMsgRegisterPayee> for MsgRegisterPayee {}
+
+impl TryFrom<RawMsgRegisterPayee> for MsgRegisterPayee {
+    type Error = ChannelError;
+
+    fn try_from(raw_msg: RawMsgRegisterPayee) -> Result<Self, Self::Error> {
+        if raw_msg.relayer.trim().is_empty() {
+            return Err(ChannelError::InvalidSigner {
+                reason: "empty relayer address".to_string(),
+            });
+        }
+
+        if raw_msg.payee.trim().is_empty() {
+            return Err(ChannelError::InvalidSigner {
+                reason: "empty payee address".to_string(),
+            });
+        }
+
+        Ok(MsgRegisterPayee {
+            port_id: raw_msg.port_id.parse()?,
+            channel_id: raw_msg.channel_id.parse()?,
+            relayer: raw_msg.relayer.into(),
+            payee: raw_msg.payee.into(),
+        })
+    }
+}
+
+impl From<MsgRegisterPayee> for RawMsgRegisterPayee {
+    fn from(domain_msg: MsgRegisterPayee) -> Self {
+        RawMsgRegisterPayee {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.channel_id.to_string(),
+            relayer: domain_msg.relayer.to_string(),
+            payee: domain_msg.payee.to_string(),
+        }
+    }
+}
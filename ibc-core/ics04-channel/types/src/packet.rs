@@ -2,7 +2,7 @@
 use ibc_core_client_types::Height;
 use ibc_core_host_types::identifiers::{ChannelId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Expiry::Expired;
+use ibc_primitives::proto::Protobuf;
 use ibc_primitives::Timestamp;
 use ibc_proto::ibc::core::channel::v1::{Packet as RawPacket, PacketState as RawPacketState};
 
@@ -25,6 +25,7 @@ pub enum PacketMsgType {
     derive(
         parity_scale_codec::Encode,
         parity_scale_codec::Decode,
+        parity_scale_codec::MaxEncodedLen,
         scale_info::TypeInfo
     )
 )]
@@ -140,8 +141,7 @@ impl Packet {
     pub fn timed_out(&self, dst_chain_ts: &Timestamp, dst_chain_height: Height) -> bool {
         let height_timed_out = self.timeout_height_on_b.has_expired(dst_chain_height);
 
-        let timestamp_timed_out = self.timeout_timestamp_on_b.is_set()
-            && dst_chain_ts.check_expiry(&self.timeout_timestamp_on_b) == Expired;
+        let timestamp_timed_out = dst_chain_ts.is_after(&self.timeout_timestamp_on_b);
 
         height_timed_out || timestamp_timed_out
     }
@@ -164,6 +164,8 @@ impl core::fmt::Display for Packet {
     }
 }
 
+impl Protobuf<RawPacket> for Packet {}
+
 impl TryFrom<RawPacket> for Packet {
     type Error = PacketError;
 
@@ -0,0 +1,133 @@
+//! Defines the [`PacketDataCodec`] trait, through which applications describe
+//! the encoding of their packet data, so that the channel layer and the
+//! middlewares can handle it without treating it as opaque bytes.
+use core::fmt::Debug;
+
+use ibc_primitives::prelude::*;
+
+use crate::error::PacketError;
+
+/// Read-only view of decoded packet data, letting middlewares introspect the
+/// packets of any application.
+///
+/// Every accessor defaults to `None`, for applications whose packets do not
+/// carry the corresponding field.
+pub trait PacketDataInfo: Debug {
+    /// Returns the address of the sender of the packet on the sending chain.
+    fn sender(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the address of the receiver of the packet on the receiving
+    /// chain.
+    fn receiver(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the memo of the packet.
+    fn memo(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Encodes and decodes the packet data of an application.
+pub trait PacketDataCodec: Debug {
+    /// The domain type of the packet data.
+    type Data: PacketDataInfo + 'static;
+
+    /// Encodes the packet data into the bytes carried by the packet.
+    fn encode(&self, data: &Self::Data) -> Result<Vec<u8>, PacketError>;
+
+    /// Decodes the bytes carried by a packet.
+    fn decode(&self, bytes: &[u8]) -> Result<Self::Data, PacketError>;
+
+    /// Checks the basic well-formedness of the packet data, i.e. the checks
+    /// that do not require access to the application state.
+    fn validate(&self, _data: &Self::Data) -> Result<(), PacketError> {
+        Ok(())
+    }
+}
+
+/// Object-safe counterpart of [`PacketDataCodec`], under which codecs are
+/// registered with the host and the router modules.
+///
+/// Implemented for every [`PacketDataCodec`].
+pub trait DynPacketDataCodec: Debug {
+    /// Decodes the bytes carried by a packet and checks the well-formedness of
+    /// the packet data.
+    fn validate_bytes(&self, bytes: &[u8]) -> Result<(), PacketError>;
+
+    /// Decodes the bytes carried by a packet into a generic view of the
+    /// packet data.
+    fn decode_info(&self, bytes: &[u8]) -> Result<Box<dyn PacketDataInfo>, PacketError>;
+}
+
+impl<C: PacketDataCodec> DynPacketDataCodec for C {
+    fn validate_bytes(&self, bytes: &[u8]) -> Result<(), PacketError> {
+        self.validate(&self.decode(bytes)?)
+    }
+
+    fn decode_info(&self, bytes: &[u8]) -> Result<Box<dyn PacketDataInfo>, PacketError> {
+        Ok(Box::new(self.decode(bytes)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Greeting {
+        sender: String,
+    }
+
+    impl PacketDataInfo for Greeting {
+        fn sender(&self) -> Option<&str> {
+            Some(&self.sender)
+        }
+    }
+
+    /// Encodes greetings as the UTF-8 bytes of the sender address.
+    #[derive(Debug)]
+    struct GreetingCodec;
+
+    impl PacketDataCodec for GreetingCodec {
+        type Data = Greeting;
+
+        fn encode(&self, data: &Greeting) -> Result<Vec<u8>, PacketError> {
+            Ok(data.sender.as_bytes().to_vec())
+        }
+
+        fn decode(&self, bytes: &[u8]) -> Result<Greeting, PacketError> {
+            let sender =
+                String::from_utf8(bytes.to_vec()).map_err(|e| PacketError::InvalidPacketData {
+                    reason: e.to_string(),
+                })?;
+
+            Ok(Greeting { sender })
+        }
+
+        fn validate(&self, data: &Greeting) -> Result<(), PacketError> {
+            if data.sender.is_empty() {
+                return Err(PacketError::InvalidPacketData {
+                    reason: "empty sender".to_string(),
+                });
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_dyn_packet_data_codec() {
+        let codec: &dyn DynPacketDataCodec = &GreetingCodec;
+
+        assert!(codec.validate_bytes(b"cosmos1sender").is_ok());
+        assert!(codec.validate_bytes(b"").is_err());
+        assert!(codec.validate_bytes(&[0xff]).is_err());
+
+        let info = codec.decode_info(b"cosmos1sender").unwrap();
+        assert_eq!(info.sender(), Some("cosmos1sender"));
+        assert_eq!(info.memo(), None);
+    }
+}
@@ -22,6 +22,7 @@ use ibc_proto::ibc::core::client::v1::Height as RawHeight;
     derive(
         parity_scale_codec::Encode,
         parity_scale_codec::Decode,
+        parity_scale_codec::MaxEncodedLen,
         scale_info::TypeInfo
     )
 )]
@@ -0,0 +1,263 @@
+//! Defines the [`TimeoutPolicy`], which computes safe timeouts for the packets
+//! about to be sent.
+use core::time::Duration;
+
+use ibc_core_client_types::Height;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+use crate::error::PacketError;
+use crate::timeout::TimeoutHeight;
+
+/// The number of blocks of the destination chain before a packet times out,
+/// by default, which matches the ICS-20 default of ibc-go.
+pub const DEFAULT_TIMEOUT_HEIGHT_OFFSET: u64 = 1000;
+
+/// The time before a packet times out, by default, which matches the ICS-20
+/// default of ibc-go.
+pub const DEFAULT_TIMEOUT_TIMESTAMP_OFFSET: Duration = Duration::from_secs(10 * 60);
+
+/// Computes the timeouts of a packet from the latest height and timestamp of
+/// the destination chain known to the sender, i.e. the ones of the latest
+/// consensus state of its client of the destination chain.
+///
+/// As this client lags behind the destination chain, and relaying the packet
+/// takes time, timeouts close to these values expire before the packet is
+/// received, and the tokens of a transfer are refunded. The offsets of the
+/// policy are the margins left to the packet to be relayed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeoutPolicy {
+    /// The number of blocks after the latest height of the destination chain
+    /// at which the packet times out. The height timeout is disabled when set
+    /// to `None`.
+    pub height_offset: Option<u64>,
+    /// The time after the latest timestamp of the destination chain at which
+    /// the packet times out. The timestamp timeout is disabled when set to
+    /// `None`.
+    pub timestamp_offset: Option<Duration>,
+}
+
+impl Default for TimeoutPolicy {
+    fn default() -> Self {
+        Self {
+            height_offset: Some(DEFAULT_TIMEOUT_HEIGHT_OFFSET),
+            timestamp_offset: Some(DEFAULT_TIMEOUT_TIMESTAMP_OFFSET),
+        }
+    }
+}
+
+impl TimeoutPolicy {
+    /// Builds a policy timing out packets on both height and timestamp.
+    pub fn new(height_offset: u64, timestamp_offset: Duration) -> Self {
+        Self {
+            height_offset: Some(height_offset),
+            timestamp_offset: Some(timestamp_offset),
+        }
+    }
+
+    /// Builds a policy timing out packets on height only.
+    pub fn height_only(height_offset: u64) -> Self {
+        Self {
+            height_offset: Some(height_offset),
+            timestamp_offset: None,
+        }
+    }
+
+    /// Builds a policy timing out packets on timestamp only.
+    pub fn timestamp_only(timestamp_offset: Duration) -> Self {
+        Self {
+            height_offset: None,
+            timestamp_offset: Some(timestamp_offset),
+        }
+    }
+
+    /// Returns the timeout height of a packet, given the latest height of the
+    /// destination chain known to the sender.
+    pub fn timeout_height(&self, latest_height_on_b: Height) -> Result<TimeoutHeight, PacketError> {
+        let Some(height_offset) = self.height_offset else {
+            return Ok(TimeoutHeight::Never);
+        };
+
+        let revision_height = latest_height_on_b
+            .revision_height()
+            .checked_add(height_offset)
+            .ok_or(PacketError::TimeoutOverflow)?;
+
+        let timeout_height = Height::new(latest_height_on_b.revision_number(), revision_height)
+            .map_err(|_| PacketError::TimeoutOverflow)?;
+
+        Ok(TimeoutHeight::At(timeout_height))
+    }
+
+    /// Returns the timeout timestamp of a packet, given the latest timestamp
+    /// of the destination chain known to the sender.
+    pub fn timeout_timestamp(
+        &self,
+        latest_timestamp_on_b: Timestamp,
+    ) -> Result<Timestamp, PacketError> {
+        let Some(timestamp_offset) = self.timestamp_offset else {
+            return Ok(Timestamp::none());
+        };
+
+        if !latest_timestamp_on_b.is_set() {
+            return Err(PacketError::UnsafeTimeout {
+                description: "the latest timestamp of the destination chain is unknown".to_string(),
+            });
+        }
+
+        (latest_timestamp_on_b + timestamp_offset).map_err(|_| PacketError::TimeoutOverflow)
+    }
+
+    /// Returns the timeout height and timestamp of a packet, given the latest
+    /// height and timestamp of the destination chain known to the sender.
+    pub fn timeouts(
+        &self,
+        latest_height_on_b: Height,
+        latest_timestamp_on_b: Timestamp,
+    ) -> Result<(TimeoutHeight, Timestamp), PacketError> {
+        if self.height_offset.is_none() && self.timestamp_offset.is_none() {
+            return Err(PacketError::MissingTimeout);
+        }
+
+        Ok((
+            self.timeout_height(latest_height_on_b)?,
+            self.timeout_timestamp(latest_timestamp_on_b)?,
+        ))
+    }
+
+    /// Checks that the given timeouts leave at least the margins of the policy
+    /// to the packet to be relayed.
+    pub fn verify_timeouts(
+        &self,
+        timeout_height_on_b: TimeoutHeight,
+        timeout_timestamp_on_b: Timestamp,
+        latest_height_on_b: Height,
+        latest_timestamp_on_b: Timestamp,
+    ) -> Result<(), PacketError> {
+        if !timeout_height_on_b.is_set() && !timeout_timestamp_on_b.is_set() {
+            return Err(PacketError::MissingTimeout);
+        }
+
+        if let (TimeoutHeight::At(timeout_height), TimeoutHeight::At(min_timeout_height)) = (
+            timeout_height_on_b,
+            self.timeout_height(latest_height_on_b)?,
+        ) {
+            if timeout_height < min_timeout_height {
+                return Err(PacketError::UnsafeTimeout {
+                    description: format!(
+                        "timeout height {timeout_height} is below the minimum of {min_timeout_height}"
+                    ),
+                });
+            }
+        }
+
+        if timeout_timestamp_on_b.is_set() {
+            let min_timeout_timestamp = self.timeout_timestamp(latest_timestamp_on_b)?;
+
+            if min_timeout_timestamp.is_after(&timeout_timestamp_on_b) {
+                return Err(PacketError::UnsafeTimeout {
+                    description: format!(
+                        "timeout timestamp {timeout_timestamp_on_b} is below the minimum of {min_timeout_timestamp}"
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn latest_height() -> Height {
+        Height::new(1, 100).unwrap()
+    }
+
+    fn latest_timestamp() -> Timestamp {
+        Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap()
+    }
+
+    #[test]
+    fn test_timeouts() {
+        let policy = TimeoutPolicy::new(10, Duration::from_secs(60));
+
+        let (timeout_height, timeout_timestamp) = policy
+            .timeouts(latest_height(), latest_timestamp())
+            .unwrap();
+
+        assert_eq!(
+            timeout_height,
+            TimeoutHeight::At(Height::new(1, 110).unwrap())
+        );
+        assert_eq!(
+            timeout_timestamp,
+            (latest_timestamp() + Duration::from_secs(60)).unwrap()
+        );
+
+        let (timeout_height, timeout_timestamp) = TimeoutPolicy::height_only(10)
+            .timeouts(latest_height(), latest_timestamp())
+            .unwrap();
+        assert!(timeout_height.is_set());
+        assert!(!timeout_timestamp.is_set());
+
+        let disabled = TimeoutPolicy {
+            height_offset: None,
+            timestamp_offset: None,
+        };
+        assert!(disabled
+            .timeouts(latest_height(), latest_timestamp())
+            .is_err());
+        assert!(TimeoutPolicy::height_only(u64::MAX)
+            .timeout_height(latest_height())
+            .is_err());
+    }
+
+    #[test]
+    fn test_verify_timeouts() {
+        let policy = TimeoutPolicy::new(10, Duration::from_secs(60));
+        let (timeout_height, timeout_timestamp) = policy
+            .timeouts(latest_height(), latest_timestamp())
+            .unwrap();
+
+        assert!(policy
+            .verify_timeouts(
+                timeout_height,
+                timeout_timestamp,
+                latest_height(),
+                latest_timestamp()
+            )
+            .is_ok());
+
+        // A timeout height within the margin is rejected.
+        assert!(policy
+            .verify_timeouts(
+                TimeoutHeight::At(Height::new(1, 105).unwrap()),
+                Timestamp::none(),
+                latest_height(),
+                latest_timestamp()
+            )
+            .is_err());
+
+        // A timeout timestamp within the margin is rejected.
+        assert!(policy
+            .verify_timeouts(
+                TimeoutHeight::Never,
+                (latest_timestamp() + Duration::from_secs(30)).unwrap(),
+                latest_height(),
+                latest_timestamp()
+            )
+            .is_err());
+
+        assert!(policy
+            .verify_timeouts(
+                TimeoutHeight::Never,
+                Timestamp::none(),
+                latest_height(),
+                latest_timestamp()
+            )
+            .is_err());
+    }
+}
@@ -117,6 +117,19 @@ impl TryFrom<MerkleProof> for CommitmentProofBytes {
     }
 }
 
+impl CommitmentProofBytes {
+    /// Returns the value proved by the proof, if it is an ICS-23 existence
+    /// proof, e.g. to report how the proved state differs from the expected
+    /// one when the verification fails. Proofs of other light clients yield
+    /// none.
+    pub fn proved_value(&self) -> Option<Vec<u8>> {
+        MerkleProof::try_from(self)
+            .ok()?
+            .proved_value()
+            .map(<[u8]>::to_vec)
+    }
+}
+
 impl<'a> TryFrom<&'a CommitmentProofBytes> for MerkleProof {
     type Error = CommitmentError;
 
@@ -160,8 +173,19 @@ impl CommitmentPrefix {
     pub fn empty() -> Self {
         Self { bytes: Vec::new() }
     }
+
+    /// Returns the prefix under which Cosmos-SDK chains commit IBC state,
+    /// i.e. the `ibc` multi-store key.
+    pub fn cosmos() -> Self {
+        Self {
+            bytes: COSMOS_IBC_STORE_KEY.as_bytes().to_vec(),
+        }
+    }
 }
 
+/// The key of the IBC store within the Cosmos-SDK multi-store.
+pub const COSMOS_IBC_STORE_KEY: &str = "ibc";
+
 impl TryFrom<Vec<u8>> for CommitmentPrefix {
     type Error = CommitmentError;
 
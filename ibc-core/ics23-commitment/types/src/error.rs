@@ -37,6 +37,22 @@ pub enum CommitmentError {
     InvalidHashOp(i32),
     /// invalid length operation: `{0}`
     InvalidLengthOp(i32),
+    /// proof spec is missing a leaf spec
+    MissingLeafSpec,
+    /// proof spec is missing an inner spec
+    MissingInnerSpec,
+    /// leaf spec prefix must not be empty
+    EmptyLeafPrefix,
+    /// key prehashing before comparison requires a prehash key operation
+    InvalidPrehashKey,
+    /// invalid child order: `{0:?}`
+    InvalidChildOrder(Vec<i32>),
+    /// invalid empty child length: expected `{expected}`, actual `{actual}`
+    InvalidEmptyChildLength { expected: i32, actual: usize },
+    /// commitment prefix is not valid UTF-8: `{0:?}`
+    NonUtf8CommitmentPrefix(Vec<u8>),
+    /// invalid merkle path `{path}`: {reason}
+    InvalidMerklePath { path: String, reason: String },
 }
 
 #[cfg(feature = "std")]
@@ -2,7 +2,7 @@
 //! state transitions between chains.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
 #![deny(
     warnings,
@@ -12,6 +12,8 @@
     rust_2018_idioms
 )]
 
+extern crate alloc;
+
 #[cfg(feature = "std")]
 extern crate std;
 
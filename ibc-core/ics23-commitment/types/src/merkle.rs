@@ -1,24 +1,133 @@
 //! Merkle proof utilities
 
+use alloc::borrow::Cow;
+
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 use ibc_proto::ibc::core::commitment::v1::{MerklePath, MerkleProof as RawMerkleProof, MerkleRoot};
 use ibc_proto::ics23::commitment_proof::Proof;
 use ibc_proto::ics23::{
-    calculate_existence_root, verify_membership, verify_non_membership, CommitmentProof,
-    HostFunctionsProvider, NonExistenceProof,
+    batch_entry, calculate_existence_root, decompress, verify_membership, verify_non_membership,
+    CommitmentProof, HostFunctionsProvider, NonExistenceProof,
 };
 
-use crate::commitment::{CommitmentPrefix, CommitmentRoot};
+use crate::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
 use crate::error::CommitmentError;
 use crate::specs::ProofSpecs;
 
+/// Prepends the commitment prefix to the given path, mirroring `ApplyPrefix`
+/// in ibc-go.
+///
+/// The prefix is rendered through its `Debug` representation, so a non UTF-8
+/// prefix yields a path that can never be proven. Prefer [`try_apply_prefix`]
+/// when the prefix is not known to be well-formed.
 pub fn apply_prefix(prefix: &CommitmentPrefix, mut path: Vec<String>) -> MerklePath {
     let mut key_path: Vec<String> = vec![format!("{prefix:?}")];
     key_path.append(&mut path);
     MerklePath { key_path }
 }
 
+/// Prepends the commitment prefix to the given path, rejecting empty and
+/// non UTF-8 prefixes.
+///
+/// For a Cosmos-SDK counterparty the prefix is the `ibc` store key (see
+/// [`CommitmentPrefix::cosmos`]), and the resulting path is the one proven by
+/// the two-level (store, then multi-store) proofs that chain produces.
+pub fn try_apply_prefix<S: ToString>(
+    prefix: &CommitmentPrefix,
+    path: impl IntoIterator<Item = S>,
+) -> Result<MerklePath, CommitmentError> {
+    if prefix.as_bytes().is_empty() {
+        return Err(CommitmentError::EmptyCommitmentPrefix);
+    }
+    let prefix = core::str::from_utf8(prefix.as_bytes())
+        .map_err(|_| CommitmentError::NonUtf8CommitmentPrefix(prefix.as_bytes().to_vec()))?;
+
+    let key_path = core::iter::once(prefix.to_string())
+        .chain(path.into_iter().map(|key| key.to_string()))
+        .collect();
+
+    Ok(MerklePath { key_path })
+}
+
+/// Splits a prefixed merkle path back into its commitment prefix and the
+/// remaining keys. This is the inverse of [`try_apply_prefix`].
+pub fn split_prefix(path: &MerklePath) -> Result<(CommitmentPrefix, Vec<String>), CommitmentError> {
+    let (prefix, keys) = path
+        .key_path
+        .split_first()
+        .ok_or(CommitmentError::EmptyCommitmentPrefix)?;
+    let prefix = CommitmentPrefix::try_from(prefix.as_bytes().to_vec())?;
+
+    Ok((prefix, keys.to_vec()))
+}
+
+/// Renders a merkle path the way ibc-go's `MerklePath.String()` does: every key
+/// is URL path-escaped and prefixed with a `/`.
+pub fn merkle_path_to_string(path: &MerklePath) -> String {
+    let mut rendered = String::new();
+    for key in &path.key_path {
+        rendered.push('/');
+        for byte in key.bytes() {
+            if is_path_segment_safe(byte) {
+                rendered.push(byte as char);
+            } else {
+                rendered.push_str(&format!("%{byte:02X}"));
+            }
+        }
+    }
+    rendered
+}
+
+/// Parses a merkle path from the representation produced by
+/// [`merkle_path_to_string`].
+pub fn parse_merkle_path(s: &str) -> Result<MerklePath, CommitmentError> {
+    let invalid = |reason: &str| CommitmentError::InvalidMerklePath {
+        path: s.to_string(),
+        reason: reason.to_string(),
+    };
+
+    if s.is_empty() {
+        return Ok(MerklePath { key_path: vec![] });
+    }
+
+    let segments = s
+        .strip_prefix('/')
+        .ok_or_else(|| invalid("path must start with `/`"))?;
+
+    let key_path = segments
+        .split('/')
+        .map(|segment| {
+            let mut bytes = Vec::with_capacity(segment.len());
+            let mut iter = segment.bytes();
+            while let Some(byte) = iter.next() {
+                if byte == b'%' {
+                    let hi = iter.next().and_then(|c| (c as char).to_digit(16));
+                    let lo = iter.next().and_then(|c| (c as char).to_digit(16));
+                    match (hi, lo) {
+                        (Some(hi), Some(lo)) => bytes.push((hi * 16 + lo) as u8),
+                        _ => return Err(invalid("malformed percent-encoding")),
+                    }
+                } else {
+                    bytes.push(byte);
+                }
+            }
+            String::from_utf8(bytes).map_err(|_| invalid("key is not valid UTF-8"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(MerklePath { key_path })
+}
+
+/// Bytes left unescaped by Go's `url.PathEscape`.
+fn is_path_segment_safe(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric()
+        || matches!(
+            byte,
+            b'-' | b'_' | b'.' | b'~' | b'$' | b'&' | b'+' | b':' | b'=' | b'@'
+        )
+}
+
 impl From<CommitmentRoot> for MerkleRoot {
     fn from(root: CommitmentRoot) -> Self {
         Self {
@@ -53,6 +162,16 @@ impl From<MerkleProof> for RawMerkleProof {
 }
 
 impl MerkleProof {
+    /// Returns the value proved by the innermost proof, i.e. the value stored
+    /// at the proved path of the counterparty's store, if that proof is an
+    /// existence proof.
+    pub fn proved_value(&self) -> Option<&[u8]> {
+        match self.proofs.first()?.proof.as_ref()? {
+            Proof::Exist(existence_proof) => Some(&existence_proof.value),
+            _ => None,
+        }
+    }
+
     pub fn verify_membership<H: HostFunctionsProvider>(
         &self,
         specs: &ProofSpecs,
@@ -88,11 +207,8 @@ impl MerkleProof {
             .iter()
             .zip(ics23_specs.iter())
             .zip(keys.key_path.iter().rev())
-            .skip(
-                start_index
-                    .try_into()
-                    .expect("safe because if u64 is more than usize it will skip all anyway"),
-            )
+            // if the index does not fit in `usize`, all the proofs are skipped anyway
+            .skip(start_index.try_into().unwrap_or(usize::MAX))
         {
             match &proof.proof {
                 Some(Proof::Exist(existence_proof)) => {
@@ -115,6 +231,74 @@ impl MerkleProof {
         Ok(())
     }
 
+    /// Verifies that each value is stored at its path against a single proof,
+    /// whose innermost proof is an ICS-23 batch proof of all the paths, which
+    /// may be compressed. The paths must only differ in their innermost key,
+    /// as they share the outer proofs.
+    pub fn verify_batch_membership<H: HostFunctionsProvider>(
+        &self,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        items: Vec<(MerklePath, Vec<u8>)>,
+    ) -> Result<(), CommitmentError> {
+        let Some((keys, _)) = items.first() else {
+            return Ok(());
+        };
+
+        // validate arguments
+        if self.proofs.is_empty() {
+            return Err(CommitmentError::EmptyMerkleProof);
+        }
+        if root.hash.is_empty() {
+            return Err(CommitmentError::EmptyMerkleRoot);
+        }
+        let num = self.proofs.len();
+        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
+        if ics23_specs.len() != num {
+            return Err(CommitmentError::NumberOfSpecsMismatch);
+        }
+        for (item_keys, value) in &items {
+            if item_keys.key_path.len() != num {
+                return Err(CommitmentError::NumberOfKeysMismatch);
+            }
+            if item_keys.key_path[..num - 1] != keys.key_path[..num - 1] {
+                return Err(CommitmentError::VerificationFailure);
+            }
+            if value.is_empty() {
+                return Err(CommitmentError::EmptyVerifiedValue);
+            }
+        }
+
+        // verify the values in the lowest subtree against the root it proves
+        let proof = decompress(&self.proofs[0]).map_err(|_| CommitmentError::InvalidMerkleProof)?;
+        let existence_proof = match &proof.proof {
+            Some(Proof::Exist(existence_proof)) => existence_proof,
+            Some(Proof::Batch(batch_proof)) => batch_proof
+                .entries
+                .iter()
+                .find_map(|entry| match &entry.proof {
+                    Some(batch_entry::Proof::Exist(existence_proof)) => Some(existence_proof),
+                    _ => None,
+                })
+                .ok_or(CommitmentError::InvalidMerkleProof)?,
+            _ => return Err(CommitmentError::InvalidMerkleProof),
+        };
+        let subroot = calculate_existence_root::<H>(existence_proof)
+            .map_err(|_| CommitmentError::InvalidMerkleProof)?;
+
+        for (item_keys, value) in &items {
+            let key = &item_keys.key_path[num - 1];
+
+            if !verify_membership::<H>(&proof, &ics23_specs[0], &subroot, key.as_bytes(), value) {
+                return Err(CommitmentError::VerificationFailure);
+            }
+        }
+
+        // verify the shared outer proofs once, starting from index 1 with
+        // value = subroot
+        self.verify_membership::<H>(specs, root, keys.clone(), subroot, 1)
+    }
+
     pub fn verify_non_membership<H: HostFunctionsProvider>(
         &self,
         specs: &ProofSpecs,
@@ -166,6 +350,107 @@ impl MerkleProof {
     }
 }
 
+/// A merkle proof decoded and structurally validated once, so that it can be
+/// verified against several paths, e.g. in a batch, without decoding the
+/// [`CommitmentProofBytes`] it originates from again.
+///
+/// Structural validation only ensures that the proof is not empty and that
+/// every commitment proof is set; whether the proof is an existence or a
+/// non-existence proof is checked upon verification.
+///
+/// Decoding borrowed proof bytes keeps borrowing them rather than copying
+/// them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedProof<'a> {
+    bytes: Cow<'a, CommitmentProofBytes>,
+    proof: MerkleProof,
+}
+
+impl<'a> DecodedProof<'a> {
+    fn new(bytes: Cow<'a, CommitmentProofBytes>) -> Result<Self, CommitmentError> {
+        let proof = MerkleProof::try_from(&*bytes)?;
+
+        if proof.proofs.is_empty() {
+            return Err(CommitmentError::EmptyMerkleProof);
+        }
+        if proof.proofs.iter().any(|proof| proof.proof.is_none()) {
+            return Err(CommitmentError::InvalidMerkleProof);
+        }
+
+        Ok(Self { bytes, proof })
+    }
+
+    /// Returns the proof bytes the proof was decoded from.
+    pub fn bytes(&self) -> &CommitmentProofBytes {
+        &self.bytes
+    }
+
+    pub fn merkle_proof(&self) -> &MerkleProof {
+        &self.proof
+    }
+
+    pub fn into_merkle_proof(self) -> MerkleProof {
+        self.proof
+    }
+
+    /// Returns the value proved by the proof, if it is an existence proof.
+    ///
+    /// See [`MerkleProof::proved_value`].
+    pub fn proved_value(&self) -> Option<&[u8]> {
+        self.proof.proved_value()
+    }
+
+    /// Verifies that the value is stored at the given path.
+    pub fn verify_membership<H: HostFunctionsProvider>(
+        &self,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        keys: MerklePath,
+        value: Vec<u8>,
+    ) -> Result<(), CommitmentError> {
+        self.proof
+            .verify_membership::<H>(specs, root, keys, value, 0)
+    }
+
+    /// Verifies that no value is stored at the given path.
+    pub fn verify_non_membership<H: HostFunctionsProvider>(
+        &self,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        keys: MerklePath,
+    ) -> Result<(), CommitmentError> {
+        self.proof.verify_non_membership::<H>(specs, root, keys)
+    }
+
+    /// Verifies that each value is stored at its path.
+    ///
+    /// See [`MerkleProof::verify_batch_membership`].
+    pub fn verify_batch_membership<H: HostFunctionsProvider>(
+        &self,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        items: Vec<(MerklePath, Vec<u8>)>,
+    ) -> Result<(), CommitmentError> {
+        self.proof.verify_batch_membership::<H>(specs, root, items)
+    }
+}
+
+impl TryFrom<CommitmentProofBytes> for DecodedProof<'static> {
+    type Error = CommitmentError;
+
+    fn try_from(bytes: CommitmentProofBytes) -> Result<Self, Self::Error> {
+        Self::new(Cow::Owned(bytes))
+    }
+}
+
+impl<'a> TryFrom<&'a CommitmentProofBytes> for DecodedProof<'a> {
+    type Error = CommitmentError;
+
+    fn try_from(bytes: &'a CommitmentProofBytes) -> Result<Self, Self::Error> {
+        Self::new(Cow::Borrowed(bytes))
+    }
+}
+
 // TODO move to ics23
 fn calculate_non_existence_root<H: HostFunctionsProvider>(
     proof: &NonExistenceProof,
@@ -178,3 +463,105 @@ fn calculate_non_existence_root<H: HostFunctionsProvider>(
         Err(CommitmentError::InvalidMerkleProof)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn prefix(bytes: &[u8]) -> CommitmentPrefix {
+        CommitmentPrefix::try_from(bytes.to_vec()).unwrap()
+    }
+
+    // Mirrors `TestApplyPrefix` in ibc-go's `modules/core/23-commitment/types`.
+    #[test]
+    fn test_apply_prefix_matches_ibc_go() {
+        let path_str = "pathone/pathtwo/paththree/key";
+        let prefixed = try_apply_prefix(&prefix(b"storePrefixKey"), [path_str]).unwrap();
+
+        assert_eq!(prefixed.key_path, vec!["storePrefixKey", path_str]);
+        assert_eq!(
+            merkle_path_to_string(&prefixed),
+            "/storePrefixKey/pathone%2Fpathtwo%2Fpaththree%2Fkey"
+        );
+        assert_eq!(
+            prefixed,
+            apply_prefix(&prefix(b"storePrefixKey"), vec![path_str.into()])
+        );
+    }
+
+    #[test]
+    fn test_cosmos_prefix() {
+        let prefixed =
+            try_apply_prefix(&CommitmentPrefix::cosmos(), ["connections/connection-0"]).unwrap();
+
+        assert_eq!(
+            merkle_path_to_string(&prefixed),
+            "/ibc/connections%2Fconnection-0"
+        );
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec!["rootKey", "storeKey", "path/to/leaf"])]
+    #[case(vec!["ibc", "commitments/ports/transfer/channels/channel-0/sequences/1"])]
+    #[case(vec!["", "with space", "100%", "a?b;c,d", "unicode-é", "$&+:=@-_.~"])]
+    fn test_merkle_path_string_round_trip(#[case] keys: Vec<&str>) {
+        let path = MerklePath {
+            key_path: keys.into_iter().map(Into::into).collect(),
+        };
+        let rendered = merkle_path_to_string(&path);
+
+        assert_eq!(parse_merkle_path(&rendered).unwrap(), path);
+    }
+
+    #[rstest]
+    #[case("no-leading-slash")]
+    #[case("/bad%2")]
+    #[case("/bad%zz")]
+    #[case("/%FF")]
+    fn test_parse_merkle_path_rejects_malformed(#[case] s: &str) {
+        assert!(parse_merkle_path(s).is_err());
+    }
+
+    #[test]
+    fn test_split_prefix_round_trip() {
+        let keys = vec!["clients/07-tendermint-0/clientState".to_string()];
+        let prefixed = try_apply_prefix(&CommitmentPrefix::cosmos(), keys.clone()).unwrap();
+
+        let (prefix, rest) = split_prefix(&prefixed).unwrap();
+        assert_eq!(prefix, CommitmentPrefix::cosmos());
+        assert_eq!(rest, keys);
+    }
+
+    #[test]
+    fn test_try_apply_prefix_rejects_malformed_prefix() {
+        assert!(matches!(
+            try_apply_prefix(&CommitmentPrefix::empty(), ["key"]),
+            Err(CommitmentError::EmptyCommitmentPrefix)
+        ));
+        assert!(matches!(
+            try_apply_prefix(&prefix(&[0xff, 0xfe]), ["key"]),
+            Err(CommitmentError::NonUtf8CommitmentPrefix(_))
+        ));
+    }
+
+    #[test]
+    fn test_decoded_proof_rejects_malformed_proofs() {
+        let unset = CommitmentProofBytes::try_from(RawMerkleProof {
+            proofs: vec![CommitmentProof { proof: None }],
+        })
+        .unwrap();
+        assert!(matches!(
+            DecodedProof::try_from(&unset),
+            Err(CommitmentError::InvalidMerkleProof)
+        ));
+
+        let garbage = CommitmentProofBytes::try_from(vec![0xff; 4]).unwrap();
+        assert!(matches!(
+            DecodedProof::try_from(garbage),
+            Err(CommitmentError::DecodingFailure(_))
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 //! Defines proof specs, which encode the structure of proofs
 
 use ibc_primitives::prelude::*;
+use ibc_primitives::{CommitmentHasher, DefaultHostHasher, HostHasher};
 use ibc_proto::ics23::{InnerSpec as RawInnerSpec, LeafOp as RawLeafOp, ProofSpec as RawProofSpec};
 use ics23::{HashOp, LengthOp};
 
@@ -16,38 +17,79 @@ pub struct ProofSpecs(Vec<ProofSpec>);
 impl ProofSpecs {
     /// Returns the specification for Cosmos-SDK proofs
     pub fn cosmos() -> Self {
-        vec![
-            ics23::iavl_spec(),       // Format of proofs-iavl (iavl merkle proofs)
-            ics23::tendermint_spec(), // Format of proofs-tendermint (crypto/ merkle SimpleProof)
-        ]
-        .try_into()
-        .expect("should convert successfully")
+        Self(vec![
+            ProofSpec(ics23::iavl_spec()), // Format of proofs-iavl (iavl merkle proofs)
+            ProofSpec(ics23::tendermint_spec()), // Format of proofs-tendermint (crypto/ merkle SimpleProof)
+        ])
+    }
+
+    /// Returns the specification of the proofs of the host, i.e. the
+    /// Cosmos-SDK one with its SHA-256 hash operations replaced by the one of
+    /// the [`DefaultHostHasher`].
+    ///
+    /// These are the specs a host advertises to the clients tracking it, from
+    /// which its counterparties learn the hash function of its commitments.
+    pub fn host() -> Self {
+        let host_hash_op = |hash_op: &mut i32| {
+            if *hash_op == HashOp::Sha256 as i32 {
+                *hash_op = DefaultHostHasher::HASH_OP;
+            }
+        };
+
+        Self(
+            Self::cosmos()
+                .0
+                .into_iter()
+                .map(|ProofSpec(mut spec)| {
+                    if let Some(leaf_spec) = spec.leaf_spec.as_mut() {
+                        host_hash_op(&mut leaf_spec.hash);
+                        host_hash_op(&mut leaf_spec.prehash_key);
+                        host_hash_op(&mut leaf_spec.prehash_value);
+                    }
+                    if let Some(inner_spec) = spec.inner_spec.as_mut() {
+                        host_hash_op(&mut inner_spec.hash);
+                    }
+                    ProofSpec(spec)
+                })
+                .collect(),
+        )
     }
 
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 
+    /// Returns the hash function of the commitments proven under these specs,
+    /// which is the leaf hash operation of the first spec, i.e. of the store
+    /// holding the IBC commitments.
+    pub fn commitment_hasher(&self) -> Result<CommitmentHasher, CommitmentError> {
+        let hash_op = self
+            .0
+            .first()
+            .ok_or(CommitmentError::EmptyProofSpecs)?
+            .0
+            .leaf_spec
+            .as_ref()
+            .ok_or(CommitmentError::MissingLeafSpec)?
+            .hash;
+
+        CommitmentHasher::from_hash_op(hash_op).ok_or(CommitmentError::InvalidHashOp(hash_op))
+    }
+
+    /// Performs structural checks on every spec, rejecting specs under which
+    /// proofs would be ambiguous or unverifiable.
+    ///
+    /// Besides the depth range, each spec must define both a leaf and an inner
+    /// spec, use real hash operations, have a non-empty leaf prefix, a
+    /// `child_order` that is a permutation of its indices, a bounded
+    /// `child_size` and an `empty_child` that is either empty or exactly
+    /// `child_size` bytes long.
     pub fn validate(&self) -> Result<(), CommitmentError> {
         if self.is_empty() {
             return Err(CommitmentError::EmptyProofSpecs);
         }
         for proof_spec in &self.0 {
-            // A non-positive `min_depth` or `max_depth` indicates no limit on the respective bound.
-            // For simplicity, negative values for `min_depth` and `max_depth` are not allowed
-            // and only `0` is used to indicate no limit. When `min_depth` and `max_depth` are both positive,
-            // `max_depth` must be greater than or equal to `min_depth` to ensure a valid range.
-            if proof_spec.0.max_depth < 0
-                || proof_spec.0.min_depth < 0
-                || (0 < proof_spec.0.min_depth
-                    && 0 < proof_spec.0.max_depth
-                    && proof_spec.0.max_depth < proof_spec.0.min_depth)
-            {
-                return Err(CommitmentError::InvalidDepthRange(
-                    proof_spec.0.min_depth,
-                    proof_spec.0.max_depth,
-                ));
-            }
+            proof_spec.validate()?;
         }
         Ok(())
     }
@@ -79,6 +121,96 @@ impl From<ProofSpecs> for Vec<RawProofSpec> {
 #[derive(Clone, Debug, PartialEq)]
 struct ProofSpec(RawProofSpec);
 
+/// Upper bound on the `max_depth` of a proof spec. Hash-keyed trees are at most
+/// 256 levels deep, so anything beyond that is a misconfiguration.
+pub const MAX_PROOF_DEPTH: i32 = 256;
+
+/// Upper bound on the `child_size` of an inner spec, in bytes.
+pub const MAX_CHILD_SIZE: i32 = 64;
+
+impl ProofSpec {
+    fn validate(&self) -> Result<(), CommitmentError> {
+        let spec = &self.0;
+
+        // A non-positive `min_depth` or `max_depth` indicates no limit on the respective bound.
+        // For simplicity, negative values for `min_depth` and `max_depth` are not allowed
+        // and only `0` is used to indicate no limit. When `min_depth` and `max_depth` are both positive,
+        // `max_depth` must be greater than or equal to `min_depth` to ensure a valid range.
+        if spec.max_depth < 0
+            || spec.min_depth < 0
+            || spec.max_depth > MAX_PROOF_DEPTH
+            || spec.min_depth > MAX_PROOF_DEPTH
+            || (0 < spec.min_depth && 0 < spec.max_depth && spec.max_depth < spec.min_depth)
+        {
+            return Err(CommitmentError::InvalidDepthRange(
+                spec.min_depth,
+                spec.max_depth,
+            ));
+        }
+
+        let leaf_spec = spec
+            .leaf_spec
+            .as_ref()
+            .ok_or(CommitmentError::MissingLeafSpec)?;
+        let inner_spec = spec
+            .inner_spec
+            .as_ref()
+            .ok_or(CommitmentError::MissingInnerSpec)?;
+
+        LeafOp::try_from(leaf_spec.clone())?;
+        InnerSpec::try_from(inner_spec.clone())?;
+
+        // Leaves must be hashed, and their prefix must keep them apart from
+        // inner nodes.
+        if leaf_spec.hash == HashOp::NoHash as i32 {
+            return Err(CommitmentError::InvalidHashOp(leaf_spec.hash));
+        }
+        if leaf_spec.prefix.is_empty() {
+            return Err(CommitmentError::EmptyLeafPrefix);
+        }
+        if spec.prehash_key_before_comparison && leaf_spec.prehash_key == HashOp::NoHash as i32 {
+            return Err(CommitmentError::InvalidPrehashKey);
+        }
+
+        match HashOp::try_from(inner_spec.hash) {
+            Ok(HashOp::NoHash) | Err(_) => {
+                return Err(CommitmentError::InvalidHashOp(inner_spec.hash))
+            }
+            Ok(_) => {}
+        }
+
+        if inner_spec.child_size > MAX_CHILD_SIZE {
+            return Err(CommitmentError::InvalidChildSize(inner_spec.child_size));
+        }
+
+        let mut seen = vec![false; inner_spec.child_order.len()];
+        let is_permutation = inner_spec.child_order.len() >= 2
+            && inner_spec.child_order.iter().all(|&i| {
+                usize::try_from(i)
+                    .ok()
+                    .and_then(|i| seen.get_mut(i))
+                    .map(|seen| !core::mem::replace(seen, true))
+                    .unwrap_or(false)
+            });
+        if !is_permutation {
+            return Err(CommitmentError::InvalidChildOrder(
+                inner_spec.child_order.clone(),
+            ));
+        }
+
+        if !inner_spec.empty_child.is_empty()
+            && inner_spec.empty_child.len() != inner_spec.child_size as usize
+        {
+            return Err(CommitmentError::InvalidEmptyChildLength {
+                expected: inner_spec.child_size,
+                actual: inner_spec.empty_child.len(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
 impl TryFrom<RawProofSpec> for ProofSpec {
     type Error = CommitmentError;
     fn try_from(spec: RawProofSpec) -> Result<Self, CommitmentError> {
@@ -196,6 +328,90 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_cosmos_proof_specs_are_valid() {
+        let raw_specs: Vec<RawProofSpec> = ProofSpecs::cosmos().into();
+
+        assert!(ProofSpecs::try_from(raw_specs).is_ok());
+    }
+
+    #[test]
+    fn test_cosmos_proof_specs_pass_validation() {
+        ProofSpecs::cosmos().validate().unwrap();
+    }
+
+    #[test]
+    fn test_host_proof_specs_follow_host_hasher() {
+        let host_specs = ProofSpecs::host();
+
+        host_specs.validate().unwrap();
+        assert_eq!(
+            host_specs.commitment_hasher().unwrap(),
+            CommitmentHasher::host()
+        );
+        assert_eq!(
+            ProofSpecs::cosmos().commitment_hasher().unwrap(),
+            CommitmentHasher::Sha256
+        );
+        if CommitmentHasher::host() == CommitmentHasher::Sha256 {
+            assert_eq!(host_specs, ProofSpecs::cosmos());
+        }
+    }
+
+    #[test]
+    fn test_commitment_hasher_of_unsupported_hash_op() {
+        let mut raw_spec = valid_raw_proof_spec();
+        if let Some(leaf_spec) = raw_spec.leaf_spec.as_mut() {
+            leaf_spec.hash = HashOp::Sha512 as i32;
+        }
+        let specs = ProofSpecs::try_from(vec![raw_spec]).unwrap();
+
+        assert!(matches!(
+            specs.commitment_hasher(),
+            Err(CommitmentError::InvalidHashOp(_))
+        ));
+    }
+
+    fn valid_raw_proof_spec() -> RawProofSpec {
+        ics23::iavl_spec()
+    }
+
+    #[rstest]
+    #[case::valid(|_: &mut RawProofSpec| {})]
+    #[should_panic(expected = "MissingLeafSpec")]
+    #[case::missing_leaf(|s: &mut RawProofSpec| s.leaf_spec = None)]
+    #[should_panic(expected = "MissingInnerSpec")]
+    #[case::missing_inner(|s: &mut RawProofSpec| s.inner_spec = None)]
+    #[should_panic(expected = "InvalidHashOp")]
+    #[case::unhashed_leaf(|s: &mut RawProofSpec| s.leaf_spec.as_mut().unwrap().hash = 0)]
+    #[should_panic(expected = "InvalidHashOp")]
+    #[case::unhashed_inner(|s: &mut RawProofSpec| s.inner_spec.as_mut().unwrap().hash = 0)]
+    #[should_panic(expected = "InvalidHashOp")]
+    #[case::unknown_inner_hash(|s: &mut RawProofSpec| s.inner_spec.as_mut().unwrap().hash = 42)]
+    #[should_panic(expected = "EmptyLeafPrefix")]
+    #[case::empty_leaf_prefix(|s: &mut RawProofSpec| s.leaf_spec.as_mut().unwrap().prefix = vec![])]
+    #[should_panic(expected = "InvalidPrehashKey")]
+    #[case::prehash_without_op(|s: &mut RawProofSpec| s.prehash_key_before_comparison = true)]
+    #[should_panic(expected = "InvalidDepthRange")]
+    #[case::too_deep(|s: &mut RawProofSpec| s.max_depth = MAX_PROOF_DEPTH + 1)]
+    #[should_panic(expected = "InvalidChildSize")]
+    #[case::child_too_large(|s: &mut RawProofSpec| s.inner_spec.as_mut().unwrap().child_size = MAX_CHILD_SIZE + 1)]
+    #[should_panic(expected = "InvalidChildOrder")]
+    #[case::single_child(|s: &mut RawProofSpec| s.inner_spec.as_mut().unwrap().child_order = vec![0])]
+    #[should_panic(expected = "InvalidChildOrder")]
+    #[case::duplicate_child(|s: &mut RawProofSpec| s.inner_spec.as_mut().unwrap().child_order = vec![0, 0])]
+    #[should_panic(expected = "InvalidChildOrder")]
+    #[case::out_of_range_child(|s: &mut RawProofSpec| s.inner_spec.as_mut().unwrap().child_order = vec![0, 2])]
+    #[should_panic(expected = "InvalidEmptyChildLength")]
+    #[case::short_empty_child(|s: &mut RawProofSpec| s.inner_spec.as_mut().unwrap().empty_child = vec![0; 4])]
+    fn test_proof_specs_validate(#[case] mutate: fn(&mut RawProofSpec)) {
+        let mut raw_proof_spec = valid_raw_proof_spec();
+        mutate(&mut raw_proof_spec);
+        ProofSpecs(vec![ProofSpec(raw_proof_spec)])
+            .validate()
+            .unwrap();
+    }
+
     #[rstest]
     #[case(0, 0)]
     #[case(2, 2)]
@@ -2,28 +2,43 @@ use core::time::Duration;
 
 use ibc_core_channel_types::channel::ChannelEnd;
 use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::limits::PacketSizeLimits;
 use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::packet_data::DynPacketDataCodec;
+use ibc_core_channel_types::timeout::TimeoutHeight;
 use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::params::ClientParams;
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
 use ibc_core_connection_types::version::{pick_version, Version as ConnectionVersion};
 use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
-use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_core_handler_types::log::HandlerLog;
+use ibc_core_handler_types::mode::HostMode;
+use ibc_core_host_types::identifiers::{
+    ChannelId, ClientId, ConnectionId, PortId, Sequence, ValidationProfile,
+};
 use ibc_core_host_types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath,
+    CounterpartyPayeePath, PayeePath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
+use ibc_core_router_types::module::ModuleId;
 use ibc_primitives::prelude::*;
 use ibc_primitives::{Signer, Timestamp};
 
-use crate::utils::calculate_block_delay;
+use crate::delay::calculate_block_delay;
+use crate::meter::{NoopResourceMeter, ResourceMeter};
 
 /// Context to be implemented by the host that provides all "read-only" methods.
 ///
 /// Trait used for the top-level `validate` entrypoint in the `ibc-core` crate.
 pub trait ValidationContext {
+    /// The mode in which the host operates IBC, when fixed at compile time.
+    /// Defaults to [`HostMode::Full`].
+    const HOST_MODE: HostMode = HostMode::Full;
+
     type V: ClientValidationContext;
     /// The client state type for the host chain.
     type HostClientState: ClientStateValidation<Self::V>;
@@ -138,6 +153,109 @@ pub trait ValidationContext {
     /// Validates the `signer` field of IBC messages, which represents the address
     /// of the user/relayer that signed the given message.
     fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+
+    /// Returns the rules that the identifiers generated by the host, and the
+    /// ports its applications bind to, must follow. Defaults to the ICS-24
+    /// rules.
+    fn identifier_validation_profile(&self) -> ValidationProfile {
+        ValidationProfile::default()
+    }
+
+    /// Returns the meter charged by the handlers for the work they perform.
+    /// Defaults to a meter that charges nothing.
+    fn resource_meter(&self) -> &dyn ResourceMeter {
+        &NoopResourceMeter
+    }
+
+    /// Returns the mode in which the host currently operates IBC. Defaults to
+    /// [`Self::HOST_MODE`], and is overridden by the hosts switching modes at
+    /// runtime, e.g. during a staged rollout.
+    fn host_mode(&self) -> HostMode {
+        Self::HOST_MODE
+    }
+
+    /// Returns the upper bounds on the size of the packets and
+    /// acknowledgements handled by the host. Defaults to the limits of
+    /// ibc-go.
+    fn packet_size_limits(&self) -> PacketSizeLimits {
+        PacketSizeLimits::default()
+    }
+
+    /// Returns the codec of the packet data of the application bound to the
+    /// given port, against which the packets sent on that port are checked
+    /// for well-formedness, and through which the memos of the packets
+    /// received on it are checked against the `packet_size_limits`. Defaults
+    /// to none, leaving the packet data opaque.
+    fn packet_data_codec(&self, _port_id: &PortId) -> Option<&dyn DynPacketDataCodec> {
+        None
+    }
+
+    /// Returns the parameters of the client module, consulted when creating a
+    /// client. Any client type is allowed by default; implement along with
+    /// [`ExecutionContext::store_client_params`] to restrict them.
+    fn client_params(&self) -> Result<ClientParams, ContextError> {
+        Ok(ClientParams::default())
+    }
+
+    /// Returns Ok() if the given signer is the authority allowed to update the
+    /// parameters of the client module. No one is allowed by default.
+    fn validate_client_params_authority(&self, signer: &Signer) -> Result<(), ContextError> {
+        Err(ClientError::UnauthorizedParamsUpdate {
+            signer: signer.clone(),
+        })?
+    }
+
+    /// Returns the module owning the given channel, as recorded by
+    /// [`ExecutionContext::store_channel_owner`] when the channel was opened.
+    /// Channels without a recorded owner are owned by the owner of their
+    /// port. Defaults to none.
+    fn channel_owner(
+        &self,
+        _chan_end_path: &ChannelEndPath,
+    ) -> Result<Option<ModuleId>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns the address registered by the relayer to receive the fees it
+    /// earns on the channel, if any. Defaults to none; implement if the host
+    /// tracks the payees of the relayers, with the same layout as the ibc-go
+    /// fee module, whether or not a fee middleware is enabled on the channel.
+    fn payee(&self, _payee_path: &PayeePath) -> Result<Option<Signer>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns the address registered by the relayer on the counterparty chain
+    /// of the channel, if any. Defaults to none; implement along with
+    /// [`payee`](Self::payee).
+    fn counterparty_payee(
+        &self,
+        _counterparty_payee_path: &CounterpartyPayeePath,
+    ) -> Result<Option<Signer>, ContextError> {
+        Ok(None)
+    }
+
+    /// Returns the address to which the fees earned by `relayer` on the
+    /// channel are distributed, i.e. its registered payee if any, or the
+    /// relayer itself otherwise.
+    fn fee_recipient(
+        &self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+    ) -> Result<Signer, ContextError> {
+        Ok(self
+            .payee(&PayeePath::new(relayer, channel_id))?
+            .unwrap_or_else(|| relayer.clone()))
+    }
+
+    /// Returns the counterparty address to which a packet received by
+    /// `relayer` on the channel is attributed, if the relayer registered one.
+    fn recv_relayer_attribution(
+        &self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+    ) -> Result<Option<Signer>, ContextError> {
+        self.counterparty_payee(&CounterpartyPayeePath::new(relayer, channel_id))
+    }
 }
 
 /// Context to be implemented by the host that provides all "write-only" methods.
@@ -236,8 +354,86 @@ pub trait ExecutionContext: ValidationContext {
     /// Emit the given IBC event
     fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
 
-    /// Log the given message.
-    fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+    /// Records the given log of a handler decision.
+    fn log(&mut self, log: HandlerLog) -> Result<(), ContextError>;
+
+    /// Logs the given free-form message, as a [`HandlerLog::Message`].
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.log(HandlerLog::Message(message))
+    }
+
+    /// Stores the parameters of the client module.
+    /// Implement only if the host supports updating the client parameters.
+    fn store_client_params(&mut self, _params: ClientParams) -> Result<(), ContextError> {
+        Err(ClientError::ParamsUpdateNotSupported)?
+    }
+
+    /// Records the module owning the given channel, once the channel is
+    /// opened by the `OpenInit` or `OpenTry` handshake step.
+    ///
+    /// The owner must be stored along with the channel end, so that it is
+    /// reverted with the rest of the transaction and routes the messages of
+    /// the channel to the module that opened it even after its port is bound
+    /// to another module. Implement along with
+    /// [`ValidationContext::channel_owner`].
+    fn store_channel_owner(
+        &mut self,
+        _chan_end_path: &ChannelEndPath,
+        _module_id: ModuleId,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Indexes the commitment of a sent packet by the timeout height and
+    /// timestamp of the packet, e.g. to look up the packets which timed out.
+    /// Implement only if the host maintains such an index.
+    fn index_packet_timeout(
+        &mut self,
+        _commitment_path: &CommitmentPath,
+        _timeout_height: &TimeoutHeight,
+        _timeout_timestamp: &Timestamp,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Removes the commitment of a packet from the timeout index, once the
+    /// packet is acknowledged or timed out.
+    /// Implement only if the host maintains such an index.
+    fn unindex_packet_timeout(
+        &mut self,
+        _commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Indexes the consensus state of a client at the given height by the
+    /// host time at which it was processed, e.g. to prune the consensus
+    /// states processed before a given time.
+    ///
+    /// Pruned consensus states are removed from the index through
+    /// `ClientExecutionContext::unindex_consensus_state_processed_time`.
+    /// Implement only if the host maintains such an index.
+    fn index_consensus_state_processed_time(
+        &mut self,
+        _client_id: &ClientId,
+        _height: &Height,
+        _processed_time: &Timestamp,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Indexes an update of a client to the given consensus height by the
+    /// host height at which it was processed, e.g. to look up the client
+    /// updates of a block.
+    /// Implement only if the host maintains such an index.
+    fn index_client_update(
+        &mut self,
+        _client_id: &ClientId,
+        _consensus_height: &Height,
+        _host_height: &Height,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
 }
 
 /// Convenient type alias for `ClientStateRef`, providing access to client
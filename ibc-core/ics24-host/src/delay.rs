@@ -0,0 +1,118 @@
+//! Provides the computation of the delay period of a connection, which the
+//! packet handlers enforce before accepting proofs from the counterparty, and
+//! which light clients verifying delayed proofs must reproduce exactly.
+//!
+//! A proof at some height of the counterparty can only be used once both the
+//! time and the number of blocks elapsed on the host since the client was
+//! updated to that height exceed the delay period of the connection.
+
+use core::time::Duration;
+
+use ibc_core_client_types::Height;
+use ibc_primitives::{Timestamp, TimestampOverflowError};
+
+/// Returns the number of blocks the host must produce for the given delay
+/// period to elapse, given the maximum expected time per block.
+///
+/// As in ibc-go, this is the delay period divided by the time per block,
+/// rounded up, computed over nanoseconds. A zero time per block yields no
+/// block delay.
+pub fn calculate_block_delay(
+    delay_period_time: &Duration,
+    max_expected_time_per_block: &Duration,
+) -> u64 {
+    let delay_period_time = delay_period_time.as_nanos();
+    let max_expected_time_per_block = max_expected_time_per_block.as_nanos();
+    if max_expected_time_per_block == 0 {
+        return 0;
+    }
+
+    // TODO: Use `u128::div_ceil` here instead once the MSRV allows it
+    let block_delay = delay_period_time / max_expected_time_per_block
+        + u128::from(delay_period_time % max_expected_time_per_block != 0);
+
+    u64::try_from(block_delay).unwrap_or(u64::MAX)
+}
+
+/// Returns the earliest host time at which a proof can be used, given the
+/// time the client was updated to its height.
+pub fn earliest_valid_time(
+    processed_time: Timestamp,
+    delay_period_time: Duration,
+) -> Result<Timestamp, TimestampOverflowError> {
+    processed_time + delay_period_time
+}
+
+/// Returns the earliest host height at which a proof can be used, given the
+/// height at which the client was updated to its height.
+pub fn earliest_valid_height(processed_height: Height, delay_period_blocks: u64) -> Height {
+    processed_height.add(delay_period_blocks)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::remainder_zero(10, 2, 5)]
+    #[case::remainder_not_zero(10, 3, 4)]
+    #[case::max_expected_zero(10, 0, 0)]
+    #[case::delay_period_zero(0, 2, 0)]
+    #[case::both_zero(0, 0, 0)]
+    #[case::delay_less_than_max(10, 11, 1)]
+    fn test_calculate_block_delay_zero(
+        #[case] delay_period_time: u64,
+        #[case] max_expected_time_per_block: u64,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(
+            calculate_block_delay(
+                &Duration::from_secs(delay_period_time),
+                &Duration::from_secs(max_expected_time_per_block)
+            ),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::sub_second_block_time(10_000, 500, 20)]
+    #[case::sub_second_delay_rounded_up(1_500, 1_000, 2)]
+    #[case::sub_second_both(300, 200, 2)]
+    #[case::sub_second_delay_less_than_max(1, 1_000, 1)]
+    fn test_calculate_block_delay_millis(
+        #[case] delay_period_time: u64,
+        #[case] max_expected_time_per_block: u64,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(
+            calculate_block_delay(
+                &Duration::from_millis(delay_period_time),
+                &Duration::from_millis(max_expected_time_per_block)
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_earliest_valid_time_and_height() {
+        let processed_time = Timestamp::from_nanoseconds(1_000_000_000).unwrap();
+        assert_eq!(
+            earliest_valid_time(processed_time, Duration::from_secs(2)).unwrap(),
+            Timestamp::from_nanoseconds(3_000_000_000).unwrap()
+        );
+        assert_eq!(
+            earliest_valid_time(processed_time, Duration::ZERO).unwrap(),
+            processed_time
+        );
+
+        let processed_height = Height::new(1, 10).unwrap();
+        assert_eq!(
+            earliest_valid_height(processed_height, 5),
+            Height::new(1, 15).unwrap()
+        );
+        assert_eq!(earliest_valid_height(processed_height, 0), processed_height);
+    }
+}
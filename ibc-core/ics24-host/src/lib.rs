@@ -4,7 +4,7 @@
 //! execution of IBC logics.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
 #![deny(
     warnings,
@@ -17,11 +17,23 @@
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
-pub(crate) mod utils;
+pub mod delay;
 
 mod context;
 pub use context::*;
 
+mod meter;
+pub use meter::*;
+
+mod per_block;
+pub use per_block::*;
+
+mod validate_self_client;
+pub use validate_self_client::*;
+
+mod vote_extension;
+pub use vote_extension::*;
+
 /// Re-exports ICS-24 data structures from `ibc-core-host-types` crate.
 pub mod types {
     #[doc(inline)]
@@ -0,0 +1,49 @@
+//! Defines the hooks through which hosts meter the work done by the handlers.
+
+use ibc_core_handler_types::error::ResourceError;
+
+/// Charge points invoked by the core handlers before performing work whose
+/// cost depends on the message, so that hosts can meter IBC work
+/// deterministically.
+///
+/// Returning [`ResourceError::OutOfGas`] from any charge point aborts the
+/// handling of the message. All the charge points default to no charge, so
+/// hosts only override the ones they meter.
+///
+/// Charges are made while validating messages, which take the context by
+/// shared reference, hence meters keep track of the consumed resources with
+/// interior mutability, e.g. in a `Cell`.
+pub trait ResourceMeter {
+    /// Charged before verifying a commitment proof of `proof_len` bytes
+    /// against the counterparty's state.
+    fn charge_proof_verification(&self, _proof_len: usize) -> Result<(), ResourceError> {
+        Ok(())
+    }
+
+    /// Charged before the light client verifies the `signatures` of a client
+    /// message, i.e. a header or a misbehaviour, as counted by
+    /// `ClientStateCommon::client_message_signature_count`.
+    fn charge_signature_verification(&self, _signatures: usize) -> Result<(), ResourceError> {
+        Ok(())
+    }
+
+    /// Charged before the light client decodes a client message of
+    /// `message_len` bytes.
+    fn charge_header_decoding(&self, _message_len: usize) -> Result<(), ResourceError> {
+        Ok(())
+    }
+
+    /// Charged before handling `data_len` bytes of packet data or
+    /// acknowledgement, which are hashed into commitments and passed to the
+    /// applications.
+    fn charge_packet_data(&self, _data_len: usize) -> Result<(), ResourceError> {
+        Ok(())
+    }
+}
+
+/// A [`ResourceMeter`] that charges nothing, used by the hosts that don't
+/// meter IBC work.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopResourceMeter;
+
+impl ResourceMeter for NoopResourceMeter {}
@@ -0,0 +1,172 @@
+//! Defines the hooks through which hosts run the per-block housekeeping of the
+//! IBC state, such as reporting expired clients, notifying about the packets
+//! whose timeout elapsed and pruning stale states.
+
+use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::events::ClientExpired;
+use ibc_core_client_types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_host_types::path::{ChannelEndPath, ClientConsensusStatePath, CommitmentPath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+use crate::context::{ExecutionContext, ValidationContext};
+
+/// Hooks invoked by the host at the beginning and the end of each block, e.g.
+/// from the ABCI `BeginBlock` and `EndBlock` calls.
+///
+/// The housekeeping itself is driven by [`Self::end_block`], while what is
+/// looked up and what is done with the findings is left to the host. All the
+/// hooks default to no-ops, so hosts only implement those they need.
+pub trait IbcPerBlockHooks: ExecutionContext {
+    /// Called at the beginning of each block, before the IBC messages of the
+    /// block are handled. Defaults to a no-op.
+    fn begin_block(&mut self) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Called at the end of each block, once the IBC messages of the block
+    /// are handled.
+    ///
+    /// Emits a [`ClientExpired`] event for, and reports to
+    /// [`Self::on_client_expired`], each expired client among
+    /// [`Self::clients_to_check_for_expiry`]. Then, for each channel of
+    /// [`Self::channels_with_pending_packets`], reports the packets returned
+    /// by [`Self::timed_out_packets`] as of the latest height and timestamp of
+    /// the counterparty known to the client of the channel to
+    /// [`Self::on_packet_timeout_elapsed`]. Finally calls [`Self::prune`].
+    fn end_block(&mut self) -> Result<(), ContextError> {
+        let host_height = self.host_height()?;
+        let host_timestamp = self.host_timestamp()?;
+
+        for client_id in self.clients_to_check_for_expiry()? {
+            let client_val_ctx = self.get_client_validation_context();
+            let client_state = client_val_ctx.client_state(&client_id)?;
+
+            if client_state
+                .status(client_val_ctx, &client_id)?
+                .is_expired()
+            {
+                let client_type = client_state.client_type();
+
+                self.emit_ibc_event(IbcEvent::ClientExpired(ClientExpired::new(
+                    client_id.clone(),
+                    client_type,
+                )))?;
+                self.on_client_expired(&client_id)?;
+            }
+        }
+
+        for chan_end_path in self.channels_with_pending_packets()? {
+            let (counterparty_height, counterparty_timestamp) =
+                latest_counterparty_state(self, &chan_end_path)?;
+
+            for commitment_path in self.timed_out_packets(
+                &chan_end_path,
+                &counterparty_height,
+                &counterparty_timestamp,
+            )? {
+                self.on_packet_timeout_elapsed(&commitment_path)?;
+            }
+        }
+
+        self.prune(&host_height, &host_timestamp)
+    }
+
+    /// Returns the clients whose expiry is checked at the end of each block.
+    /// Defaults to none.
+    ///
+    /// A client is reported at each block for as long as it is expired and
+    /// returned here.
+    fn clients_to_check_for_expiry(&self) -> Result<Vec<ClientId>, ContextError> {
+        Ok(Vec::new())
+    }
+
+    /// Called for each checked client found expired at the end of a block,
+    /// once its [`ClientExpired`] event is emitted, e.g. to schedule its
+    /// recovery. Defaults to a no-op.
+    fn on_client_expired(&mut self, _client_id: &ClientId) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Returns the channels whose pending packets are checked for timeouts at
+    /// the end of each block, e.g. those found in the index maintained
+    /// through [`ExecutionContext::index_packet_timeout`]. Defaults to none.
+    fn channels_with_pending_packets(&self) -> Result<Vec<ChannelEndPath>, ContextError> {
+        Ok(Vec::new())
+    }
+
+    /// Returns the commitments of the pending packets of the given channel
+    /// whose timeout elapsed as of the given height and timestamp of the
+    /// counterparty chain, e.g. looked up in the index maintained through
+    /// [`ExecutionContext::index_packet_timeout`]. Defaults to none.
+    ///
+    /// The timeouts of the packets are expressed in the height and time of
+    /// the counterparty, which are the latest ones known to the client of the
+    /// channel, not those of the host.
+    fn timed_out_packets(
+        &self,
+        _chan_end_path: &ChannelEndPath,
+        _counterparty_height: &Height,
+        _counterparty_timestamp: &Timestamp,
+    ) -> Result<Vec<CommitmentPath>, ContextError> {
+        Ok(Vec::new())
+    }
+
+    /// Called for each pending packet whose timeout elapsed, e.g. to notify
+    /// the relayers that the packet is ready to be timed out. Defaults to a
+    /// no-op.
+    fn on_packet_timeout_elapsed(
+        &mut self,
+        _commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Prunes the states the host no longer needs as of the given host height
+    /// and timestamp, following its own schedule, e.g. the consensus states
+    /// processed before the trusting period of their client. Defaults to a
+    /// no-op.
+    fn prune(
+        &mut self,
+        _host_height: &Height,
+        _host_timestamp: &Timestamp,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+}
+
+/// Returns the latest height and timestamp of the counterparty of the given
+/// channel, as known to the client of its connection, which are those of the
+/// host itself on the `connection-localhost` sentinel.
+fn latest_counterparty_state<Ctx>(
+    ctx: &Ctx,
+    chan_end_path: &ChannelEndPath,
+) -> Result<(Height, Timestamp), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let chan_end = ctx.channel_end(chan_end_path)?;
+    let conn_id = &chan_end.connection_hops()[0];
+
+    if conn_id.is_localhost() {
+        return Ok((ctx.host_height()?, ctx.host_timestamp()?));
+    }
+
+    let conn_end = ctx.connection_end(conn_id)?;
+    let client_id = conn_end.client_id();
+    let client_val_ctx = ctx.get_client_validation_context();
+    let latest_height = client_val_ctx.client_state(client_id)?.latest_height();
+
+    let latest_timestamp = client_val_ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ))?
+        .timestamp();
+
+    Ok((latest_height, latest_timestamp))
+}
@@ -0,0 +1,257 @@
+use core::time::Duration;
+
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::specs::ProofSpecs;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifiers::ChainId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::CommitmentHasher;
+
+/// Provides the building blocks of the `ValidationContext::validate_self_client`
+/// API, i.e. the checks of how a counterparty models the host in the client
+/// state it holds of the host.
+///
+/// Each check is client-agnostic and can be overridden, so that hosts whose
+/// counterparties model them with a non-Tendermint client can compose the
+/// checks relevant to their client state, and skip or tighten the others.
+pub trait ValidateSelfClientContext {
+    /// Returns the host chain id
+    fn chain_id(&self) -> &ChainId;
+
+    /// Returns the host current height
+    fn host_current_height(&self) -> Height;
+
+    /// Returns the host proof specs, e.g. [`ProofSpecs::host`] for hosts
+    /// with a Cosmos-SDK store.
+    fn proof_specs(&self) -> &ProofSpecs;
+
+    /// Returns the host unbonding period
+    fn unbonding_period(&self) -> Duration;
+
+    /// Returns the host upgrade path. May be empty.
+    fn upgrade_path(&self) -> &[String];
+
+    /// Checks that the counterparty tracks the host chain id.
+    fn validate_self_chain_id(&self, chain_id: &ChainId) -> Result<(), ContextError> {
+        let self_chain_id = self.chain_id();
+        if self_chain_id != chain_id {
+            return Err(invalid_client_state(format!(
+                "invalid chain-id. expected: {self_chain_id}, got: {chain_id}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the latest height of the client is in the current revision
+    /// of the host, and lower than the host current height.
+    fn validate_self_latest_height(&self, latest_height: Height) -> Result<(), ContextError> {
+        let self_revision_number = self.chain_id().revision_number();
+        if self_revision_number != latest_height.revision_number() {
+            return Err(invalid_client_state(format!(
+                "client is not in the same revision as the chain. expected: {}, got: {}",
+                self_revision_number,
+                latest_height.revision_number()
+            )));
+        }
+
+        let host_current_height = self.host_current_height();
+        if latest_height >= host_current_height {
+            return Err(invalid_client_state(format!(
+                "client has latest height {latest_height} greater than or equal to chain height {host_current_height}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the client proves the host state with well-formed proof
+    /// specs matching the host ones, under which the commitments of the host
+    /// are hashed with its hash function.
+    fn validate_self_proof_specs(&self, proof_specs: &ProofSpecs) -> Result<(), ContextError> {
+        proof_specs
+            .validate()
+            .map_err(|e| invalid_client_state(format!("client has malformed proof specs: {e}")))?;
+
+        let commitment_hasher = proof_specs.commitment_hasher().map_err(|e| {
+            invalid_client_state(format!("client has unsupported proof specs: {e}"))
+        })?;
+        if commitment_hasher != CommitmentHasher::host() {
+            return Err(invalid_client_state(format!(
+                "client has invalid commitment hasher. expected: {:?}, got: {:?}",
+                CommitmentHasher::host(),
+                commitment_hasher
+            )));
+        }
+
+        if self.proof_specs() != proof_specs {
+            return Err(invalid_client_state(format!(
+                "client has invalid proof specs. expected: {:?}, got: {:?}",
+                self.proof_specs(),
+                proof_specs
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the client uses the host unbonding period, and trusts the
+    /// host headers for less than that period.
+    fn validate_self_unbonding_period(
+        &self,
+        unbonding_period: Duration,
+        trusting_period: Duration,
+    ) -> Result<(), ContextError> {
+        if self.unbonding_period() != unbonding_period {
+            return Err(invalid_client_state(format!(
+                "invalid unbonding period. expected: {:?}, got: {:?}",
+                self.unbonding_period(),
+                unbonding_period,
+            )));
+        }
+
+        if unbonding_period < trusting_period {
+            return Err(invalid_client_state(format!(
+                "unbonding period must be greater than trusting period. unbonding period ({unbonding_period:?}) < trusting period ({trusting_period:?})"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the client, if upgradable, follows the host upgrade path.
+    fn validate_self_upgrade_path(&self, upgrade_path: &[String]) -> Result<(), ContextError> {
+        if !upgrade_path.is_empty() && self.upgrade_path() != upgrade_path {
+            return Err(invalid_client_state(format!(
+                "invalid upgrade path. expected: {:?}, got: {:?}",
+                self.upgrade_path(),
+                upgrade_path
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid_client_state(reason: String) -> ContextError {
+    ContextError::ConnectionError(ConnectionError::InvalidClientState { reason })
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_commitment_types::proto::ics23::ProofSpec as RawProofSpec;
+    use rstest::rstest;
+
+    use super::*;
+
+    struct DummyHost {
+        chain_id: ChainId,
+        proof_specs: ProofSpecs,
+    }
+
+    impl DummyHost {
+        fn new() -> Self {
+            Self {
+                chain_id: ChainId::new("ibc-1").expect("valid chain id"),
+                proof_specs: ProofSpecs::host(),
+            }
+        }
+    }
+
+    impl ValidateSelfClientContext for DummyHost {
+        fn chain_id(&self) -> &ChainId {
+            &self.chain_id
+        }
+
+        fn host_current_height(&self) -> Height {
+            Height::new(1, 10).expect("valid height")
+        }
+
+        fn proof_specs(&self) -> &ProofSpecs {
+            &self.proof_specs
+        }
+
+        fn unbonding_period(&self) -> Duration {
+            Duration::from_secs(100)
+        }
+
+        fn upgrade_path(&self) -> &[String] {
+            &[]
+        }
+    }
+
+    #[rstest]
+    #[case::lower_height(1, 9, true)]
+    #[case::current_height(1, 10, false)]
+    #[case::other_revision(2, 1, false)]
+    fn test_validate_self_latest_height(
+        #[case] revision_number: u64,
+        #[case] revision_height: u64,
+        #[case] expect_pass: bool,
+    ) {
+        let height = Height::new(revision_number, revision_height).expect("valid height");
+
+        let res = DummyHost::new().validate_self_latest_height(height);
+
+        assert_eq!(res.is_ok(), expect_pass);
+    }
+
+    #[rstest]
+    #[case::matching(100, 50, true)]
+    #[case::other_unbonding_period(200, 50, false)]
+    #[case::trusting_exceeds_unbonding(100, 150, false)]
+    fn test_validate_self_unbonding_period(
+        #[case] unbonding_secs: u64,
+        #[case] trusting_secs: u64,
+        #[case] expect_pass: bool,
+    ) {
+        let res = DummyHost::new().validate_self_unbonding_period(
+            Duration::from_secs(unbonding_secs),
+            Duration::from_secs(trusting_secs),
+        );
+
+        assert_eq!(res.is_ok(), expect_pass);
+    }
+
+    #[test]
+    fn test_validate_self_proof_specs() {
+        let host = DummyHost::new();
+
+        assert!(host.validate_self_proof_specs(&ProofSpecs::host()).is_ok());
+
+        // The specs of a counterparty with another hash function are
+        // rejected, even if the host advertises them.
+        let other_specs = if CommitmentHasher::host() == CommitmentHasher::Sha256 {
+            let mut raw_specs: Vec<RawProofSpec> = ProofSpecs::cosmos().into();
+            for raw_spec in &mut raw_specs {
+                if let Some(leaf_spec) = raw_spec.leaf_spec.as_mut() {
+                    leaf_spec.hash = CommitmentHasher::Blake2s.hash_op();
+                }
+            }
+            ProofSpecs::try_from(raw_specs).expect("valid proof specs")
+        } else {
+            ProofSpecs::cosmos()
+        };
+        let host = DummyHost {
+            proof_specs: other_specs.clone(),
+            ..host
+        };
+
+        assert!(host.validate_self_proof_specs(&other_specs).is_err());
+    }
+
+    #[test]
+    fn test_validate_self_chain_id_and_upgrade_path() {
+        let host = DummyHost::new();
+
+        assert!(host.validate_self_chain_id(&host.chain_id).is_ok());
+        assert!(host
+            .validate_self_chain_id(&ChainId::new("ibc-2").expect("valid chain id"))
+            .is_err());
+        assert!(host.validate_self_upgrade_path(&[]).is_ok());
+        assert!(host
+            .validate_self_upgrade_path(&["upgrade".to_string()])
+            .is_err());
+    }
+}
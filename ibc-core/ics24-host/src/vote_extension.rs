@@ -0,0 +1,163 @@
+//! Defines the hooks through which hosts built on ABCI++ carry IBC-related
+//! data in vote extensions, e.g. for validators to relay counterparty headers
+//! themselves instead of relying on external relayers.
+
+use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::msgs::MsgUpdateClient;
+use ibc_core_client_types::Height;
+use ibc_core_handler_types::error::{ContextError, VoteExtensionError};
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::Signer;
+
+use crate::context::ValidationContext;
+
+/// An item of IBC-related data a validator extends its vote with.
+///
+/// The encoding of the items into the vote extension bytes is left to the
+/// host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VoteExtensionItem {
+    /// A header of the counterparty tracked by the given client, fetched and
+    /// verified by the validator.
+    ClientHeader { client_id: ClientId, header: Any },
+    /// An attestation of a kind defined by the host, e.g. an oracle
+    /// observation, verified by [`VoteExtensionHooks::verify_attestation`].
+    Attestation { kind: String, data: Vec<u8> },
+}
+
+impl VoteExtensionItem {
+    /// Returns the message updating the client with the header carried by the
+    /// item, if any, for the proposer to include in the block once the header
+    /// was agreed on.
+    pub fn to_msg_update_client(&self, signer: Signer) -> Option<MsgUpdateClient> {
+        match self {
+            Self::ClientHeader { client_id, header } => Some(MsgUpdateClient {
+                client_id: client_id.clone(),
+                client_message: header.clone(),
+                signer,
+            }),
+            Self::Attestation { .. } => None,
+        }
+    }
+}
+
+/// Hooks invoked by the host from the ABCI++ `ExtendVote` and
+/// `VerifyVoteExtension` calls.
+///
+/// Unlike the handlers, `ExtendVote` runs outside of the state machine, so the
+/// items a validator extends its vote with may depend on data local to the
+/// validator, such as the headers of the counterparties it follows. The
+/// verification of these items must however be deterministic.
+pub trait VoteExtensionHooks: ValidationContext {
+    /// Returns the items the validator extends its vote with at the given
+    /// height. Defaults to none.
+    fn extend_vote(&self, _height: Height) -> Result<Vec<VoteExtensionItem>, ContextError> {
+        Ok(Vec::new())
+    }
+
+    /// Verifies an attestation of the given kind. Defaults to rejecting all
+    /// attestations.
+    fn verify_attestation(&self, kind: &str, _data: &[u8]) -> Result<(), ContextError> {
+        Err(VoteExtensionError::UnsupportedAttestation {
+            kind: kind.to_string(),
+        })?
+    }
+
+    /// Verifies the items of a vote extension.
+    ///
+    /// Headers are verified by their client, as when updating it, which must
+    /// be active; attestations by [`Self::verify_attestation`].
+    fn verify_vote_extension(&self, items: &[VoteExtensionItem]) -> Result<(), ContextError> {
+        for item in items {
+            match item {
+                VoteExtensionItem::ClientHeader { client_id, header } => {
+                    let client_val_ctx = self.get_client_validation_context();
+
+                    let client_state = client_val_ctx.client_state(client_id)?;
+
+                    client_state
+                        .status(client_val_ctx, client_id)?
+                        .verify_is_active()?;
+
+                    client_state.verify_client_message(
+                        client_val_ctx,
+                        client_id,
+                        header.clone(),
+                    )?;
+                }
+                VoteExtensionItem::Attestation { kind, data } => {
+                    self.verify_attestation(kind, data)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the items carried by the vote extensions of validators holding more
+/// than two thirds of the total voting power, in the order they were first
+/// seen.
+///
+/// Each vote extension is given along with the voting power of its validator.
+/// An item repeated in a vote extension is counted once.
+pub fn aggregate_vote_extensions<I>(extensions: I, total_power: u64) -> Vec<VoteExtensionItem>
+where
+    I: IntoIterator<Item = (u64, Vec<VoteExtensionItem>)>,
+{
+    let mut tallies: Vec<(VoteExtensionItem, u64)> = Vec::new();
+
+    for (power, items) in extensions {
+        let mut counted: Vec<&VoteExtensionItem> = Vec::new();
+
+        for item in &items {
+            if counted.contains(&item) {
+                continue;
+            }
+            counted.push(item);
+
+            match tallies.iter_mut().find(|(tallied, _)| tallied == item) {
+                Some((_, tally)) => *tally = tally.saturating_add(power),
+                None => tallies.push((item.clone(), power)),
+            }
+        }
+    }
+
+    tallies
+        .into_iter()
+        .filter(|(_, tally)| u128::from(*tally) * 3 > u128::from(total_power) * 2)
+        .map(|(item, _)| item)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attestation(data: u8) -> VoteExtensionItem {
+        VoteExtensionItem::Attestation {
+            kind: "price".to_string(),
+            data: vec![data],
+        }
+    }
+
+    #[test]
+    fn test_aggregate_vote_extensions() {
+        let extensions = vec![
+            (40, vec![attestation(1), attestation(2), attestation(2)]),
+            (30, vec![attestation(2), attestation(1)]),
+            (30, vec![attestation(3), attestation(2)]),
+        ];
+
+        assert_eq!(
+            aggregate_vote_extensions(extensions.clone(), 100),
+            vec![attestation(1), attestation(2)]
+        );
+        assert_eq!(
+            aggregate_vote_extensions(extensions, 105),
+            vec![attestation(2)]
+        );
+    }
+}
@@ -8,6 +8,8 @@ pub enum IdentifierError {
     InvalidLength { id: String, min: u64, max: u64 },
     /// identifier `{id}` must only contain alphanumeric characters or `.`, `_`, `+`, `-`, `#`, - `[`, `]`, `<`, `>`
     InvalidCharacter { id: String },
+    /// identifier `{id}` must only contain the characters allowed by the host: {allowed}
+    DisallowedCharacter { id: String, allowed: String },
     /// identifier prefix `{prefix}` is invalid
     InvalidPrefix { prefix: String },
     /// chain identifier is not formatted with revision number
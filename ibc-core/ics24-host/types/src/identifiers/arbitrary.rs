@@ -0,0 +1,57 @@
+//! Implements [`Arbitrary`] for the identifiers, generating valid identifiers
+//! only, so that structured fuzz targets exercise the code past their parsing.
+
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+use ibc_primitives::prelude::*;
+
+use super::{ChannelId, ClientId, ClientType, ConnectionId, PortId, Sequence};
+
+const IDENTIFIER_CHARS: &[u8] =
+    b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789._+-#[]<>";
+
+fn arbitrary_identifier(u: &mut Unstructured<'_>, min: usize, max: usize) -> Result<String> {
+    let len = u.int_in_range(min..=max)?;
+
+    (0..len)
+        .map(|_| u.choose(IDENTIFIER_CHARS).map(|c| char::from(*c)))
+        .collect()
+}
+
+impl<'a> Arbitrary<'a> for ClientType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // client types are prefixes of 9 to 64 characters long client identifiers
+        ClientType::new(&arbitrary_identifier(u, 7, 43)?).map_err(|_| Error::IncorrectFormat)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ClientId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let client_type = ClientType::arbitrary(u)?;
+
+        ClientId::new(client_type.as_str(), u.arbitrary()?).map_err(|_| Error::IncorrectFormat)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ConnectionId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ConnectionId::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ChannelId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(ChannelId::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for PortId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        PortId::new(arbitrary_identifier(u, 2, 128)?).map_err(|_| Error::IncorrectFormat)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Sequence {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Sequence::from(u64::arbitrary(u)?))
+    }
+}
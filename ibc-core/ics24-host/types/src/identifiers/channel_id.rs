@@ -1,11 +1,13 @@
 use core::fmt::{Debug, Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
-use derive_more::Into;
 use ibc_primitives::prelude::*;
 
+use super::inline_str::IdentifierStr;
 use crate::error::IdentifierError;
-use crate::validate::validate_channel_identifier;
+use crate::validate::{
+    is_valid_static_identifier, is_valid_static_named_u64_index, validate_channel_identifier,
+};
 
 const CHANNEL_ID_PREFIX: &str = "channel";
 
@@ -23,8 +25,8 @@ const CHANNEL_ID_PREFIX: &str = "channel";
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Into)]
-pub struct ChannelId(String);
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChannelId(IdentifierStr);
 
 impl ChannelId {
     /// Builds a new channel identifier. Like client and connection identifiers, channel ids are
@@ -39,8 +41,35 @@ impl ChannelId {
     /// assert_eq!(chan_id.to_string(), "channel-27");
     /// ```
     pub fn new(identifier: u64) -> Self {
-        let id = format!("{}-{}", Self::prefix(), identifier);
-        Self(id)
+        Self(IdentifierStr::format(format_args!(
+            "{}-{}",
+            Self::prefix(),
+            identifier
+        )))
+    }
+
+    /// Builds a channel identifier out of a `'static` string in a `const`
+    /// context, so that it is validated at compile time instead of being
+    /// parsed at runtime. See also the [`channel_id!`](crate::channel_id)
+    /// macro.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not a valid channel identifier, which fails the
+    /// compilation when evaluated in a `const` context.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::ChannelId;
+    /// const CHAN_ID: ChannelId = ChannelId::from_static("channel-27");
+    /// assert_eq!(CHAN_ID, ChannelId::new(27));
+    /// ```
+    pub const fn from_static(id: &'static str) -> Self {
+        assert!(
+            is_valid_static_identifier(id, 8, 64)
+                && is_valid_static_named_u64_index(id, CHANNEL_ID_PREFIX),
+            "invalid channel identifier"
+        );
+        Self(IdentifierStr::from_static(id))
     }
 
     /// Returns the static prefix to be used across all channel identifiers.
@@ -50,7 +79,7 @@ impl ChannelId {
 
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
-        &self.0
+        self.0.as_str()
     }
 
     /// Get this identifier as a borrowed byte slice
@@ -58,15 +87,15 @@ impl ChannelId {
         self.0.as_bytes()
     }
 
-    pub fn zero() -> Self {
-        Self::new(0)
+    pub const fn zero() -> Self {
+        Self::from_static("channel-0")
     }
 }
 
 /// This implementation provides a `to_string` method.
 impl Display for ChannelId {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
-        write!(f, "{}", self.0)
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -74,13 +103,13 @@ impl FromStr for ChannelId {
     type Err = IdentifierError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        validate_channel_identifier(s).map(|_| Self(s.to_string()))
+        validate_channel_identifier(s).map(|_| Self(IdentifierStr::new(s)))
     }
 }
 
 impl AsRef<str> for ChannelId {
     fn as_ref(&self) -> &str {
-        &self.0
+        self.as_str()
     }
 }
 
@@ -97,3 +126,9 @@ impl PartialEq<str> for ChannelId {
         self.as_str().eq(other)
     }
 }
+
+impl From<ChannelId> for String {
+    fn from(id: ChannelId) -> Self {
+        id.0.into()
+    }
+}
@@ -1,10 +1,16 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
-use derive_more::Into;
 use ibc_primitives::prelude::*;
 
+use super::inline_str::IdentifierStr;
 use crate::error::IdentifierError;
-use crate::validate::{validate_client_identifier, validate_client_type};
+use crate::validate::{
+    is_valid_static_identifier, validate_client_identifier, validate_client_type,
+};
+
+/// The identifier of the localhost client.
+const LOCALHOST_CLIENT_ID: &str = "09-localhost";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -20,8 +26,8 @@ use crate::validate::{validate_client_identifier, validate_client_type};
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Into, derive_more::Display)]
-pub struct ClientId(String);
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClientId(IdentifierStr);
 
 impl ClientId {
     /// Builds a new client identifier.
@@ -48,31 +54,72 @@ impl ClientId {
         validate_client_type(client_type).map(|()| Self::format(client_type, counter))
     }
 
+    /// Builds a client identifier out of a `'static` string in a `const`
+    /// context, so that the well-known identifiers of a host are validated at
+    /// compile time instead of being parsed at runtime.
+    ///
+    /// Only ASCII characters are accepted. See also the
+    /// [`client_id!`](crate::client_id) macro.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not a valid client identifier, which fails the
+    /// compilation when evaluated in a `const` context.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::ClientId;
+    /// const CLIENT_ID: ClientId = ClientId::from_static("07-tendermint-0");
+    /// assert_eq!(CLIENT_ID.as_str(), "07-tendermint-0");
+    /// ```
+    pub const fn from_static(id: &'static str) -> Self {
+        assert!(
+            is_valid_static_identifier(id, 9, 64),
+            "invalid client identifier"
+        );
+        Self(IdentifierStr::from_static(id))
+    }
+
     pub(super) fn format(client_type: &str, counter: u64) -> Self {
-        let client_id = format!("{client_type}-{counter}");
-        if cfg!(debug_assertions) {
-            validate_client_type(client_type).expect("valid client type");
-            validate_client_identifier(&client_id).expect("valid client id");
-        }
+        let client_id = IdentifierStr::format(format_args!("{client_type}-{counter}"));
+        debug_assert!(validate_client_type(client_type).is_ok());
+        debug_assert!(validate_client_identifier(client_id.as_str()).is_ok());
         Self(client_id)
     }
 
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
-        &self.0
+        self.0.as_str()
     }
 
     /// Get this identifier as a borrowed byte slice
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    /// Returns the identifier of the localhost client, `09-localhost`, which
+    /// the host tracks itself with.
+    pub const fn localhost() -> Self {
+        Self::from_static(LOCALHOST_CLIENT_ID)
+    }
+
+    /// Checks whether this is the identifier of the localhost client.
+    pub fn is_localhost(&self) -> bool {
+        self.as_str() == LOCALHOST_CLIENT_ID
+    }
+}
+
+/// This implementation provides a `to_string` method.
+impl Display for ClientId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        Display::fmt(&self.0, f)
+    }
 }
 
 impl FromStr for ClientId {
     type Err = IdentifierError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        validate_client_identifier(s).map(|_| Self(s.to_string()))
+        validate_client_identifier(s).map(|_| Self(IdentifierStr::new(s)))
     }
 }
 
@@ -89,3 +136,9 @@ impl PartialEq<str> for ClientId {
         self.as_str().eq(other)
     }
 }
+
+impl From<ClientId> for String {
+    fn from(id: ClientId) -> Self {
+        id.0.into()
+    }
+}
@@ -1,14 +1,20 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
-use derive_more::Into;
 use ibc_primitives::prelude::*;
 
+use super::inline_str::IdentifierStr;
 use crate::error::IdentifierError;
-use crate::validate::validate_connection_identifier;
+use crate::validate::{
+    is_static_str_eq, is_valid_static_identifier, is_valid_static_named_u64_index,
+    validate_connection_identifier,
+};
 
 const CONNECTION_ID_PREFIX: &str = "connection";
 
+/// The identifier of the sentinel connection of the localhost client.
+const LOCALHOST_CONNECTION_ID: &str = "connection-localhost";
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -23,8 +29,8 @@ const CONNECTION_ID_PREFIX: &str = "connection";
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Into)]
-pub struct ConnectionId(String);
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConnectionId(IdentifierStr);
 
 impl ConnectionId {
     /// Builds a new connection identifier. Connection identifiers are deterministically formed from
@@ -38,8 +44,36 @@ impl ConnectionId {
     /// assert_eq!(&conn_id, "connection-11");
     /// ```
     pub fn new(identifier: u64) -> Self {
-        let id = format!("{}-{}", Self::prefix(), identifier);
-        Self(id)
+        Self(IdentifierStr::format(format_args!(
+            "{}-{}",
+            Self::prefix(),
+            identifier
+        )))
+    }
+
+    /// Builds a connection identifier out of a `'static` string in a `const`
+    /// context, so that it is validated at compile time instead of being
+    /// parsed at runtime. See also the [`connection_id!`](crate::connection_id)
+    /// macro.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not a valid connection identifier, which fails the
+    /// compilation when evaluated in a `const` context.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::ConnectionId;
+    /// const CONN_ID: ConnectionId = ConnectionId::from_static("connection-11");
+    /// assert_eq!(CONN_ID, ConnectionId::new(11));
+    /// ```
+    pub const fn from_static(id: &'static str) -> Self {
+        assert!(
+            is_valid_static_identifier(id, 10, 64)
+                && (is_valid_static_named_u64_index(id, CONNECTION_ID_PREFIX)
+                    || is_static_str_eq(id, LOCALHOST_CONNECTION_ID)),
+            "invalid connection identifier"
+        );
+        Self(IdentifierStr::from_static(id))
     }
 
     /// Returns the static prefix to be used across all connection identifiers.
@@ -49,7 +83,7 @@ impl ConnectionId {
 
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
-        &self.0
+        self.0.as_str()
     }
 
     /// Get this identifier as a borrowed byte slice
@@ -58,15 +92,28 @@ impl ConnectionId {
     }
 
     /// Return ConnectionId with identifier 0
-    pub fn zero() -> Self {
-        Self::new(0)
+    pub const fn zero() -> Self {
+        Self::from_static("connection-0")
+    }
+
+    /// Returns the identifier of the sentinel connection of the localhost
+    /// client, `connection-localhost`, through which channels to the host
+    /// itself are opened without a connection handshake.
+    pub const fn localhost() -> Self {
+        Self::from_static(LOCALHOST_CONNECTION_ID)
+    }
+
+    /// Checks whether this is the identifier of the localhost sentinel
+    /// connection.
+    pub fn is_localhost(&self) -> bool {
+        self.as_str() == LOCALHOST_CONNECTION_ID
     }
 }
 
 /// This implementation provides a `to_string` method.
 impl Display for ConnectionId {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
-        write!(f, "{}", self.0)
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -74,7 +121,7 @@ impl FromStr for ConnectionId {
     type Err = IdentifierError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        validate_connection_identifier(s).map(|_| Self(s.to_string()))
+        validate_connection_identifier(s).map(|_| Self(IdentifierStr::new(s)))
     }
 }
 
@@ -91,3 +138,9 @@ impl PartialEq<str> for ConnectionId {
         self.as_str().eq(other)
     }
 }
+
+impl From<ConnectionId> for String {
+    fn from(id: ConnectionId) -> Self {
+        id.0.into()
+    }
+}
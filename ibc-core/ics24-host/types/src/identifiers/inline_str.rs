@@ -0,0 +1,335 @@
+//! Defines the string representation shared by the client, connection,
+//! channel and port identifiers.
+
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter, Write};
+use core::hash::{Hash, Hasher};
+
+use ibc_primitives::prelude::*;
+
+/// Length up to which an identifier is stored inline, chosen so that
+/// [`IdentifierStr`] fits in 32 bytes.
+pub(crate) const INLINE_CAPACITY: usize = 30;
+
+/// An immutable string that stores short identifiers inline, so that creating
+/// and cloning the identifiers of the common `07-tendermint-0`, `connection-0`,
+/// `channel-0` or `transfer` form doesn't allocate. Longer identifiers are kept
+/// on the heap, and identifiers known at compile time borrow their `'static`
+/// string.
+///
+/// All the trait implementations go through [`IdentifierStr::as_str`], so the
+/// identifiers compare, hash and encode exactly like the `String` they used to
+/// wrap.
+pub(crate) enum IdentifierStr {
+    Inline {
+        len: u8,
+        bytes: [u8; INLINE_CAPACITY],
+    },
+    Heap(String),
+    Static(&'static str),
+}
+
+impl IdentifierStr {
+    pub(crate) const fn empty() -> Self {
+        Self::Inline {
+            len: 0,
+            bytes: [0; INLINE_CAPACITY],
+        }
+    }
+
+    pub(crate) const fn from_static(s: &'static str) -> Self {
+        Self::Static(s)
+    }
+
+    pub(crate) fn new(s: &str) -> Self {
+        let mut id = Self::empty();
+        id.push_str(s);
+        id
+    }
+
+    /// Builds an identifier out of formatting arguments, without allocating
+    /// if the result fits inline.
+    pub(crate) fn format(args: fmt::Arguments<'_>) -> Self {
+        let mut id = Self::empty();
+        // Writing into an `IdentifierStr` never fails.
+        let _ = id.write_fmt(args);
+        id
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            // The inline bytes are always copied from whole `str`s, hence are
+            // valid UTF-8.
+            Self::Inline { len, bytes } => {
+                core::str::from_utf8(&bytes[..usize::from(*len)]).unwrap_or_default()
+            }
+            Self::Heap(s) => s,
+            Self::Static(s) => s,
+        }
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    fn push_str(&mut self, s: &str) {
+        match self {
+            Self::Inline { len, bytes } if usize::from(*len) + s.len() <= INLINE_CAPACITY => {
+                let start = usize::from(*len);
+                bytes[start..start + s.len()].copy_from_slice(s.as_bytes());
+                *len += s.len() as u8;
+            }
+            Self::Inline { .. } | Self::Static(_) => {
+                let mut heap = String::with_capacity(self.as_str().len() + s.len());
+                heap.push_str(self.as_str());
+                heap.push_str(s);
+                *self = Self::Heap(heap);
+            }
+            Self::Heap(heap) => heap.push_str(s),
+        }
+    }
+}
+
+impl Write for IdentifierStr {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+impl From<String> for IdentifierStr {
+    fn from(s: String) -> Self {
+        if s.len() <= INLINE_CAPACITY {
+            Self::new(&s)
+        } else {
+            Self::Heap(s)
+        }
+    }
+}
+
+impl From<IdentifierStr> for String {
+    fn from(id: IdentifierStr) -> Self {
+        match id {
+            IdentifierStr::Inline { .. } | IdentifierStr::Static(_) => id.as_str().to_string(),
+            IdentifierStr::Heap(s) => s,
+        }
+    }
+}
+
+impl Clone for IdentifierStr {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Inline { len, bytes } => Self::Inline {
+                len: *len,
+                bytes: *bytes,
+            },
+            Self::Heap(s) => Self::Heap(s.clone()),
+            Self::Static(s) => Self::Static(s),
+        }
+    }
+}
+
+impl Debug for IdentifierStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for IdentifierStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq for IdentifierStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for IdentifierStr {}
+
+impl PartialOrd for IdentifierStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IdentifierStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for IdentifierStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for IdentifierStr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IdentifierStr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <String as serde::Deserialize<'de>>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for IdentifierStr {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        <String as schemars::JsonSchema>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <String as schemars::JsonSchema>::json_schema(gen)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for IdentifierStr {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        borsh::BorshSerialize::serialize(self.as_str(), writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for IdentifierStr {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        <String as borsh::BorshDeserialize>::deserialize_reader(reader).map(Self::from)
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Encode for IdentifierStr {
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, writer: &mut T) {
+        parity_scale_codec::Encode::encode_to(self.as_str(), writer);
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for IdentifierStr {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        <String as parity_scale_codec::Decode>::decode(input).map(Self::from)
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl scale_info::TypeInfo for IdentifierStr {
+    type Identity = String;
+
+    fn type_info() -> scale_info::Type {
+        <String as scale_info::TypeInfo>::type_info()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::hash::BuildHasher;
+    use std::collections::hash_map::RandomState;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn hash_one(state: &RandomState, value: impl Hash) -> u64 {
+        let mut hasher = state.build_hasher();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[rstest]
+    #[case("", true)]
+    #[case("channel-0", true)]
+    #[case("07-tendermint-18446744073709551615", false)]
+    #[case(&"a".repeat(INLINE_CAPACITY), true)]
+    #[case(&"a".repeat(INLINE_CAPACITY + 1), false)]
+    fn test_identifier_str_representation(#[case] s: &str, #[case] inline: bool) {
+        let id = IdentifierStr::new(s);
+
+        assert_eq!(matches!(id, IdentifierStr::Inline { .. }), inline);
+        assert_eq!(id.as_str(), s);
+        assert_eq!(id.clone(), id);
+        assert_eq!(IdentifierStr::from(s.to_string()), id);
+        assert_eq!(String::from(id), s);
+    }
+
+    #[test]
+    fn test_identifier_str_from_static() {
+        const ID: IdentifierStr = IdentifierStr::from_static("channel-0");
+
+        assert_eq!(ID.as_str(), "channel-0");
+        assert_eq!(ID, IdentifierStr::new("channel-0"));
+        assert_eq!(String::from(ID.clone()), "channel-0");
+    }
+
+    #[test]
+    fn test_identifier_str_format_spills_to_heap() {
+        let short = IdentifierStr::format(format_args!("{}-{}", "connection", 7));
+        assert!(matches!(short, IdentifierStr::Inline { .. }));
+        assert_eq!(short.as_str(), "connection-7");
+
+        let long = IdentifierStr::format(format_args!("{}-{}", "a".repeat(25), u64::MAX));
+        assert!(matches!(long, IdentifierStr::Heap(_)));
+        assert_eq!(long.as_str(), format!("{}-{}", "a".repeat(25), u64::MAX));
+    }
+
+    #[test]
+    fn test_identifier_str_matches_string() {
+        let (long_a, long_b) = ("a".repeat(40), "b".repeat(40));
+        let strs: [&str; 4] = ["channel-10", "channel-9", &long_b, &long_a];
+        let state = RandomState::new();
+
+        for a in strs {
+            for b in strs {
+                let (id_a, id_b) = (IdentifierStr::new(a), IdentifierStr::new(b));
+                assert_eq!(id_a.cmp(&id_b), a.cmp(b));
+                assert_eq!(id_a == id_b, a == b);
+            }
+            assert_eq!(
+                hash_one(&state, IdentifierStr::new(a)),
+                hash_one(&state, a.to_string())
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_identifier_str_serde() {
+        let id = IdentifierStr::new("07-tendermint-0");
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, r#""07-tendermint-0""#);
+        assert_eq!(serde_json::from_str::<IdentifierStr>(&json).unwrap(), id);
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_identifier_str_borsh() {
+        let id = IdentifierStr::new("07-tendermint-0");
+        let bytes = borsh::to_vec(&id).unwrap();
+
+        assert_eq!(
+            bytes,
+            borsh::to_vec(&"07-tendermint-0".to_string()).unwrap()
+        );
+        assert_eq!(borsh::from_slice::<IdentifierStr>(&bytes).unwrap(), id);
+    }
+}
@@ -0,0 +1,94 @@
+//! Defines the macros building identifiers that are validated at compile time.
+
+/// Builds a [`ClientId`](crate::identifiers::ClientId) out of a string literal,
+/// failing the compilation if the identifier is invalid.
+///
+/// ```
+/// use ibc_core_host_types::client_id;
+///
+/// let client_id = client_id!("07-tendermint-0");
+/// assert_eq!(client_id.as_str(), "07-tendermint-0");
+/// ```
+///
+/// ```compile_fail
+/// use ibc_core_host_types::client_id;
+///
+/// let client_id = client_id!("07-tendermint/0");
+/// ```
+#[macro_export]
+macro_rules! client_id {
+    ($id:literal) => {{
+        const ID: $crate::identifiers::ClientId = $crate::identifiers::ClientId::from_static($id);
+        ID
+    }};
+}
+
+/// Builds a [`ConnectionId`](crate::identifiers::ConnectionId) out of a string
+/// literal, failing the compilation if the identifier is invalid.
+///
+/// ```
+/// use ibc_core_host_types::connection_id;
+/// use ibc_core_host_types::identifiers::ConnectionId;
+///
+/// assert_eq!(connection_id!("connection-1"), ConnectionId::new(1));
+/// ```
+///
+/// ```compile_fail
+/// use ibc_core_host_types::connection_id;
+///
+/// let conn_id = connection_id!("connection-01");
+/// ```
+#[macro_export]
+macro_rules! connection_id {
+    ($id:literal) => {{
+        const ID: $crate::identifiers::ConnectionId =
+            $crate::identifiers::ConnectionId::from_static($id);
+        ID
+    }};
+}
+
+/// Builds a [`ChannelId`](crate::identifiers::ChannelId) out of a string
+/// literal, failing the compilation if the identifier is invalid.
+///
+/// ```
+/// use ibc_core_host_types::channel_id;
+/// use ibc_core_host_types::identifiers::ChannelId;
+///
+/// assert_eq!(channel_id!("channel-7"), ChannelId::new(7));
+/// ```
+///
+/// ```compile_fail
+/// use ibc_core_host_types::channel_id;
+///
+/// let chan_id = channel_id!("chan-7");
+/// ```
+#[macro_export]
+macro_rules! channel_id {
+    ($id:literal) => {{
+        const ID: $crate::identifiers::ChannelId = $crate::identifiers::ChannelId::from_static($id);
+        ID
+    }};
+}
+
+/// Builds a [`PortId`](crate::identifiers::PortId) out of a string literal,
+/// failing the compilation if the identifier is invalid.
+///
+/// ```
+/// use ibc_core_host_types::identifiers::PortId;
+/// use ibc_core_host_types::port_id;
+///
+/// assert_eq!(port_id!("transfer"), PortId::transfer());
+/// ```
+///
+/// ```compile_fail
+/// use ibc_core_host_types::port_id;
+///
+/// let port_id = port_id!("p");
+/// ```
+#[macro_export]
+macro_rules! port_id {
+    ($id:literal) => {{
+        const ID: $crate::identifiers::PortId = $crate::identifiers::PortId::from_static($id);
+        ID
+    }};
+}
@@ -1,11 +1,16 @@
 //! Defines identifier types
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod chain_id;
 mod channel_id;
 mod client_id;
 mod client_type;
 mod connection_id;
+mod inline_str;
+mod macros;
 mod port_id;
+mod profile;
 mod sequence;
 
 pub use chain_id::ChainId;
@@ -14,4 +19,5 @@ pub use client_id::ClientId;
 pub use client_type::ClientType;
 pub use connection_id::ConnectionId;
 pub use port_id::PortId;
+pub use profile::ValidationProfile;
 pub use sequence::Sequence;
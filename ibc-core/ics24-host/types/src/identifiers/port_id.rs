@@ -1,11 +1,11 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
-use derive_more::Into;
 use ibc_primitives::prelude::*;
 
+use super::inline_str::IdentifierStr;
 use crate::error::IdentifierError;
-use crate::validate::validate_port_identifier;
+use crate::validate::{is_valid_static_identifier, validate_port_identifier};
 
 const TRANSFER_PORT_ID: &str = "transfer";
 
@@ -23,22 +23,47 @@ const TRANSFER_PORT_ID: &str = "transfer";
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Into)]
-pub struct PortId(String);
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PortId(IdentifierStr);
 
 impl PortId {
     pub fn new(id: String) -> Result<Self, IdentifierError> {
         Self::from_str(&id)
     }
 
+    /// Builds a port identifier out of a `'static` string in a `const`
+    /// context, so that the ports that applications bind to are validated at
+    /// compile time instead of being parsed at runtime.
+    ///
+    /// Only ASCII characters are accepted. See also the
+    /// [`port_id!`](crate::port_id) macro.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not a valid port identifier, which fails the
+    /// compilation when evaluated in a `const` context.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::PortId;
+    /// const PORT_ID: PortId = PortId::from_static("icqhost");
+    /// assert_eq!(PORT_ID.as_str(), "icqhost");
+    /// ```
+    pub const fn from_static(id: &'static str) -> Self {
+        assert!(
+            is_valid_static_identifier(id, 2, 128),
+            "invalid port identifier"
+        );
+        Self(IdentifierStr::from_static(id))
+    }
+
     /// Infallible creation of the well-known transfer port
-    pub fn transfer() -> Self {
-        Self(TRANSFER_PORT_ID.to_string())
+    pub const fn transfer() -> Self {
+        Self::from_static(TRANSFER_PORT_ID)
     }
 
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
-        &self.0
+        self.0.as_str()
     }
 
     /// Get this identifier as a borrowed byte slice
@@ -54,7 +79,7 @@ impl PortId {
 /// This implementation provides a `to_string` method.
 impl Display for PortId {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
-        write!(f, "{}", self.0)
+        Display::fmt(&self.0, f)
     }
 }
 
@@ -62,12 +87,18 @@ impl FromStr for PortId {
     type Err = IdentifierError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        validate_port_identifier(s).map(|_| Self(s.to_string()))
+        validate_port_identifier(s).map(|_| Self(IdentifierStr::new(s)))
     }
 }
 
 impl AsRef<str> for PortId {
     fn as_ref(&self) -> &str {
-        self.0.as_str()
+        self.as_str()
+    }
+}
+
+impl From<PortId> for String {
+    fn from(id: PortId) -> Self {
+        id.0.into()
     }
 }
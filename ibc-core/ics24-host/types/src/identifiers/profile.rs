@@ -0,0 +1,245 @@
+//! Defines the identifier validation rules that a host applies to its own
+//! identifiers.
+
+use ibc_primitives::prelude::*;
+
+use super::{ChannelId, ConnectionId};
+use crate::error::IdentifierError;
+use crate::validate::{
+    validate_identifier_length, validate_named_u64_index, validate_prefix_length,
+    VALID_SPECIAL_CHARS,
+};
+
+/// The rules the identifiers of a host must follow, i.e. their allowed lengths
+/// and characters.
+///
+/// Identifiers decoded from messages always follow the [`ICS-24`] rules. On
+/// top of those, hosts with narrower constraints, e.g. shorter channel
+/// identifiers or client types of a given charset, validate the identifiers
+/// they generate and the ports they bind against their own profile. The
+/// [default](ValidationProfile::ICS24) profile is the ICS-24 one.
+///
+/// ```
+/// # use ibc_core_host_types::identifiers::ValidationProfile;
+/// let profile = ValidationProfile::ICS24
+///     .with_channel_id_length(8, 16)
+///     .with_special_chars("-");
+///
+/// assert!(profile.validate_channel_identifier("channel-0").is_ok());
+/// assert!(profile.validate_channel_identifier("channel-18446744073709551615").is_err());
+/// assert!(profile.validate_port_identifier("transfer.v2").is_err());
+/// ```
+///
+/// [`ICS-24`]: https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#paths-identifiers-separators
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidationProfile {
+    client_id_length: (u64, u64),
+    connection_id_length: (u64, u64),
+    channel_id_length: (u64, u64),
+    port_id_length: (u64, u64),
+    special_chars: &'static str,
+    ascii_only: bool,
+}
+
+impl ValidationProfile {
+    /// The identifier rules of the ICS-24 specification.
+    pub const ICS24: Self = Self {
+        client_id_length: (9, 64),
+        connection_id_length: (10, 64),
+        channel_id_length: (8, 64),
+        port_id_length: (2, 128),
+        special_chars: VALID_SPECIAL_CHARS,
+        ascii_only: false,
+    };
+
+    /// Sets the allowed length of the client identifiers, which also bounds
+    /// the length of the client types.
+    pub const fn with_client_id_length(mut self, min: u64, max: u64) -> Self {
+        self.client_id_length = (min, max);
+        self
+    }
+
+    /// Sets the allowed length of the connection identifiers.
+    pub const fn with_connection_id_length(mut self, min: u64, max: u64) -> Self {
+        self.connection_id_length = (min, max);
+        self
+    }
+
+    /// Sets the allowed length of the channel identifiers.
+    pub const fn with_channel_id_length(mut self, min: u64, max: u64) -> Self {
+        self.channel_id_length = (min, max);
+        self
+    }
+
+    /// Sets the allowed length of the port identifiers.
+    pub const fn with_port_id_length(mut self, min: u64, max: u64) -> Self {
+        self.port_id_length = (min, max);
+        self
+    }
+
+    /// Sets the non-alphanumeric characters allowed in identifiers.
+    pub const fn with_special_chars(mut self, special_chars: &'static str) -> Self {
+        self.special_chars = special_chars;
+        self
+    }
+
+    /// Restricts the alphanumeric characters allowed in identifiers to ASCII.
+    pub const fn with_ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+
+    /// Checks that the identifier only contains characters allowed by this
+    /// profile.
+    pub fn validate_identifier_chars(&self, id: &str) -> Result<(), IdentifierError> {
+        let is_allowed = |c: char| {
+            let is_alphanumeric = if self.ascii_only {
+                c.is_ascii_alphanumeric()
+            } else {
+                c.is_alphanumeric()
+            };
+            is_alphanumeric || self.special_chars.contains(c)
+        };
+
+        if !id.chars().all(is_allowed) {
+            return Err(IdentifierError::DisallowedCharacter {
+                id: id.into(),
+                allowed: self.allowed_chars(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validates a client type, i.e. the prefix of a client identifier.
+    pub fn validate_client_type(&self, client_type: &str) -> Result<(), IdentifierError> {
+        self.validate_identifier_chars(client_type)?;
+        let (min, max) = self.client_id_length;
+        validate_prefix_length(client_type, min, max)
+    }
+
+    /// Validates a client identifier.
+    pub fn validate_client_identifier(&self, id: &str) -> Result<(), IdentifierError> {
+        self.validate_identifier_chars(id)?;
+        let (min, max) = self.client_id_length;
+        validate_identifier_length(id, min, max)
+    }
+
+    /// Validates a connection identifier, which must be of the
+    /// `connection-{N}` form.
+    pub fn validate_connection_identifier(&self, id: &str) -> Result<(), IdentifierError> {
+        self.validate_identifier_chars(id)?;
+        let (min, max) = self.connection_id_length;
+        validate_identifier_length(id, min, max)?;
+        validate_named_u64_index(id, ConnectionId::prefix())
+    }
+
+    /// Validates a channel identifier, which must be of the `channel-{N}`
+    /// form.
+    pub fn validate_channel_identifier(&self, id: &str) -> Result<(), IdentifierError> {
+        self.validate_identifier_chars(id)?;
+        let (min, max) = self.channel_id_length;
+        validate_identifier_length(id, min, max)?;
+        validate_named_u64_index(id, ChannelId::prefix())
+    }
+
+    /// Validates a port identifier.
+    pub fn validate_port_identifier(&self, id: &str) -> Result<(), IdentifierError> {
+        self.validate_identifier_chars(id)?;
+        let (min, max) = self.port_id_length;
+        validate_identifier_length(id, min, max)
+    }
+
+    fn allowed_chars(&self) -> String {
+        let alphanumeric = if self.ascii_only {
+            "ASCII alphanumeric characters"
+        } else {
+            "alphanumeric characters"
+        };
+
+        if self.special_chars.is_empty() {
+            alphanumeric.to_string()
+        } else {
+            format!("{alphanumeric} or any of `{}`", self.special_chars)
+        }
+    }
+}
+
+impl Default for ValidationProfile {
+    fn default() -> Self {
+        Self::ICS24
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::validate::{
+        validate_channel_identifier, validate_client_identifier, validate_client_type,
+        validate_connection_identifier, validate_port_identifier,
+    };
+
+    #[rstest]
+    #[case("07-tendermint")]
+    #[case("07-tendermint-0")]
+    #[case("connection-0")]
+    #[case("connection-01")]
+    #[case("channel-18446744073709551615")]
+    #[case("channel-18446744073709551616")]
+    #[case("transfer")]
+    #[case("p")]
+    #[case("id/1")]
+    #[case("chaîne-0")]
+    fn test_ics24_profile_matches_default_validation(#[case] id: &str) {
+        let profile = ValidationProfile::default();
+
+        assert_eq!(
+            profile.validate_client_type(id).is_ok(),
+            validate_client_type(id).is_ok()
+        );
+        assert_eq!(
+            profile.validate_client_identifier(id).is_ok(),
+            validate_client_identifier(id).is_ok()
+        );
+        assert_eq!(
+            profile.validate_connection_identifier(id).is_ok(),
+            validate_connection_identifier(id).is_ok()
+        );
+        assert_eq!(
+            profile.validate_channel_identifier(id).is_ok(),
+            validate_channel_identifier(id).is_ok()
+        );
+        assert_eq!(
+            profile.validate_port_identifier(id).is_ok(),
+            validate_port_identifier(id).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_custom_profile() {
+        let profile = ValidationProfile::ICS24
+            .with_client_id_length(9, 20)
+            .with_channel_id_length(8, 12)
+            .with_special_chars("-")
+            .with_ascii_only(true);
+
+        assert!(profile
+            .validate_client_identifier("07-tendermint-0")
+            .is_ok());
+        assert!(profile
+            .validate_client_identifier("07-tendermint-123456789")
+            .is_err());
+        assert!(profile.validate_channel_identifier("channel-9999").is_ok());
+        assert!(profile
+            .validate_channel_identifier("channel-10000")
+            .is_err());
+        assert!(profile.validate_port_identifier("transfer").is_ok());
+        assert!(matches!(
+            profile.validate_port_identifier("transfer.v2"),
+            Err(IdentifierError::DisallowedCharacter { .. })
+        ));
+        assert!(profile.validate_port_identifier("transfér").is_err());
+    }
+}
@@ -2,7 +2,7 @@
 //! hosting an IBC-enabled chain must implement.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
 #![deny(
     warnings,
@@ -18,4 +18,5 @@ extern crate std;
 pub mod error;
 pub mod identifiers;
 pub mod path;
+pub mod store_key;
 pub(crate) mod validate;
@@ -8,6 +8,7 @@ use core::str::FromStr;
 
 use derive_more::{Display, From};
 use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
 
 use crate::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 
@@ -42,7 +43,25 @@ pub const UPGRADED_CLIENT_STATE: &str = "upgradedClient";
 /// - The key identifying the upgraded consensus state
 pub const UPGRADED_CLIENT_CONSENSUS_STATE: &str = "upgradedConsState";
 
+/// Relayer payee keys, laid out as in the store of the ibc-go fee module
+/// - The prefix of the addresses receiving the fees of a relayer
+pub const PAYEE_PREFIX: &str = "payee";
+/// - The prefix of the addresses of a relayer on the counterparty chain
+pub const COUNTERPARTY_PAYEE_PREFIX: &str = "counterpartyPayee";
+
 /// The Path enum abstracts out the different sub-paths.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From, Display)]
 pub enum Path {
     NextClientSequence(NextClientSequencePath),
@@ -665,6 +684,74 @@ pub enum UpgradeClientPath {
     UpgradedClientConsensusState(u64),
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Path of the address to which the fees earned by a relayer on a channel are
+/// paid out.
+///
+/// Stored by the host outside of the provable IBC store, as the ibc-go fee
+/// module does.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{PAYEE_PREFIX}/{relayer}/{channel_id}")]
+pub struct PayeePath {
+    pub relayer: Signer,
+    pub channel_id: ChannelId,
+}
+
+impl PayeePath {
+    pub fn new(relayer: &Signer, channel_id: &ChannelId) -> PayeePath {
+        PayeePath {
+            relayer: relayer.clone(),
+            channel_id: channel_id.clone(),
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Path of the address of a relayer on the counterparty chain of a channel,
+/// to which the relayer is attributed the packets it receives on this chain.
+///
+/// Stored by the host outside of the provable IBC store, as the ibc-go fee
+/// module does.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{COUNTERPARTY_PAYEE_PREFIX}/{relayer}/{channel_id}")]
+pub struct CounterpartyPayeePath {
+    pub relayer: Signer,
+    pub channel_id: ChannelId,
+}
+
+impl CounterpartyPayeePath {
+    pub fn new(relayer: &Signer, channel_id: &ChannelId) -> CounterpartyPayeePath {
+        CounterpartyPayeePath {
+            relayer: relayer.clone(),
+            channel_id: channel_id.clone(),
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -696,12 +783,30 @@ impl Path {
     pub fn into_bytes(self) -> Vec<u8> {
         self.to_string().into_bytes()
     }
+
+    /// Parses the key under which a value is stored back into its `Path`, so
+    /// that hosts iterating over their raw store, e.g. for state sync,
+    /// migrations or genesis exports, can recover the typed paths.
+    ///
+    /// This is the inverse of [`Path::into_bytes`]: only the keys produced by
+    /// the latter are accepted, so numbers with a sign or leading zeros are
+    /// rejected.
+    pub fn try_from_key_bytes(key: &[u8]) -> Result<Self, PathError> {
+        let path =
+            core::str::from_utf8(key).map_err(|_| PathError::InvalidUtf8 { key: key.to_vec() })?;
+
+        path.parse()
+    }
 }
 
 #[derive(Debug, displaydoc::Display)]
 pub enum PathError {
     /// `{path}` could not be parsed into a Path
     ParseFailure { path: String },
+    /// key `{key:?}` is not valid UTF-8
+    InvalidUtf8 { key: Vec<u8> },
+    /// `{path}` is not a `{expected}`
+    MismatchedPath { path: String, expected: String },
 }
 
 #[cfg(feature = "std")]
@@ -730,6 +835,85 @@ impl FromStr for Path {
     }
 }
 
+/// Implements the parsing of the paths of a single kind, out of their string
+/// representation or key bytes.
+macro_rules! impl_sub_path_parsing {
+    ($($path:ident => $variant:ident),* $(,)?) => {
+        $(
+            impl TryFrom<Path> for $path {
+                type Error = PathError;
+
+                fn try_from(path: Path) -> Result<Self, Self::Error> {
+                    match path {
+                        Path::$variant(path) => Ok(path),
+                        path => Err(PathError::MismatchedPath {
+                            path: path.to_string(),
+                            expected: stringify!($path).to_string(),
+                        }),
+                    }
+                }
+            }
+
+            impl FromStr for $path {
+                type Err = PathError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Path::from_str(s)?.try_into()
+                }
+            }
+
+            impl $path {
+                #[doc = concat!(
+                    "Parses the key under which a value is stored into a `",
+                    stringify!($path),
+                    "`.\n\nSee [`Path::try_from_key_bytes`]."
+                )]
+                pub fn try_from_key_bytes(key: &[u8]) -> Result<Self, PathError> {
+                    Path::try_from_key_bytes(key)?.try_into()
+                }
+            }
+        )*
+    };
+}
+
+impl_sub_path_parsing! {
+    NextClientSequencePath => NextClientSequence,
+    NextConnectionSequencePath => NextConnectionSequence,
+    NextChannelSequencePath => NextChannelSequence,
+    ClientStatePath => ClientState,
+    ClientConsensusStatePath => ClientConsensusState,
+    ClientUpdateTimePath => ClientUpdateTime,
+    ClientUpdateHeightPath => ClientUpdateHeight,
+    ClientConnectionPath => ClientConnection,
+    ConnectionPath => Connection,
+    PortPath => Ports,
+    ChannelEndPath => ChannelEnd,
+    SeqSendPath => SeqSend,
+    SeqRecvPath => SeqRecv,
+    SeqAckPath => SeqAck,
+    CommitmentPath => Commitment,
+    AckPath => Ack,
+    ReceiptPath => Receipt,
+    UpgradeClientPath => UpgradeClient,
+}
+
+/// Parses a number embedded in a path, such as a sequence or a height, only
+/// accepting its canonical decimal form so that each key maps to exactly one
+/// path.
+fn parse_u64(s: &str) -> Option<u64> {
+    let is_canonical = match s.as_bytes() {
+        [b'0'] => true,
+        [b'1'..=b'9', rest @ ..] => rest.iter().all(u8::is_ascii_digit),
+        _ => false,
+    };
+
+    if !is_canonical {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
 fn parse_next_sequence(components: &[&str]) -> Option<Path> {
     if components.len() != 1 {
         return None;
@@ -750,7 +934,7 @@ fn parse_client_paths(components: &[&str]) -> Option<Path> {
         return None;
     }
 
-    let client_id = ClientId::from_str(components[1]).ok()?;
+    let client_id = ClientId::from_str(components.get(1)?).ok()?;
 
     if components.len() == 3 {
         match components[2] {
@@ -764,18 +948,11 @@ fn parse_client_paths(components: &[&str]) -> Option<Path> {
             _ => return None,
         }
 
-        let epoch_height: Vec<&str> = components[3].split('-').collect();
+        let (revision_number, revision_height) = components[3].split_once('-')?;
 
-        if epoch_height.len() != 2 {
-            return None;
-        }
-
-        let revision_number = epoch_height[0];
-        let revision_height = epoch_height[1];
+        let revision_number = parse_u64(revision_number)?;
 
-        let revision_number = revision_number.parse::<u64>().ok()?;
-
-        let revision_height = revision_height.parse::<u64>().ok()?;
+        let revision_height = parse_u64(revision_height)?;
 
         match components.len() {
             4 => Some(
@@ -879,10 +1056,9 @@ fn parse_sequences(components: &[&str]) -> Option<SubPath> {
 
     let sequence_number = *components.last()?;
 
-    match Sequence::from_str(sequence_number) {
-        Ok(seq) => Some(SubPath::Sequences(seq)),
-        Err(_) => None,
-    }
+    let sequence = parse_u64(sequence_number)?;
+
+    Some(SubPath::Sequences(Sequence::from(sequence)))
 }
 
 fn parse_channel_ends(components: &[&str]) -> Option<Path> {
@@ -1060,7 +1236,7 @@ fn parse_upgrades(components: &[&str]) -> Option<Path> {
 
     let last = *components.last()?;
 
-    let height = components[1].parse::<u64>().ok()?;
+    let height = parse_u64(components[1])?;
 
     match last {
         UPGRADED_CLIENT_STATE => Some(UpgradeClientPath::UpgradedClientState(height).into()),
@@ -1187,6 +1363,14 @@ mod tests {
     #[case("clients/clientType")]
     #[case("channels/channel-0")]
     #[case("sequences/0")]
+    #[case("clients")]
+    #[case("clients/07-tendermint-0/consensusStates/15-031")]
+    #[case("clients/07-tendermint-0/consensusStates/+15-31")]
+    #[case("clients/07-tendermint-0/consensusStates/15-31-1")]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/00")]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/+1")]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/18446744073709551616")]
+    #[case("upgradedIBCState/01/upgradedClient")]
     fn test_failure_parsing(#[case] path_str: &str) {
         // cannot be parsed into Path
         assert!(Path::from_str(path_str).is_err());
@@ -1403,4 +1587,198 @@ mod tests {
             )),
         )
     }
+
+    #[test]
+    fn test_try_from_key_bytes() {
+        let key = b"commitments/ports/transfer/channels/channel-0/sequences/18446744073709551615";
+        let path = CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            Sequence::from(u64::MAX),
+        );
+
+        assert_eq!(
+            Path::try_from_key_bytes(key).expect("no error"),
+            Path::Commitment(path.clone())
+        );
+        assert_eq!(
+            CommitmentPath::try_from_key_bytes(key).expect("no error"),
+            path
+        );
+        assert!(matches!(
+            AckPath::try_from_key_bytes(key),
+            Err(PathError::MismatchedPath { .. })
+        ));
+        assert!(matches!(
+            Path::try_from_key_bytes(b"ports/\xfftransfer"),
+            Err(PathError::InvalidUtf8 { .. })
+        ));
+    }
+
+    mod proptests {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        fn client_id() -> impl Strategy<Value = ClientId> {
+            ("[a-z0-9]{2,4}-[a-z]{4,16}", any::<u64>())
+                .prop_filter_map("valid client id", |(client_type, counter)| {
+                    ClientId::new(&client_type, counter).ok()
+                })
+        }
+
+        fn port_id() -> impl Strategy<Value = PortId> {
+            "[a-zA-Z0-9._+#<>\\[\\]-]{2,64}"
+                .prop_filter_map("valid port id", |port_id| PortId::from_str(&port_id).ok())
+        }
+
+        fn channel_id() -> impl Strategy<Value = ChannelId> {
+            any::<u64>().prop_map(ChannelId::new)
+        }
+
+        fn sequence() -> impl Strategy<Value = Sequence> {
+            any::<u64>().prop_map(Sequence::from)
+        }
+
+        fn path() -> impl Strategy<Value = Path> {
+            let height = (any::<u64>(), any::<u64>());
+
+            prop_oneof![
+                Just(NextClientSequencePath.into()),
+                Just(NextConnectionSequencePath.into()),
+                Just(NextChannelSequencePath.into()),
+                client_id().prop_map(|client_id| ClientStatePath(client_id).into()),
+                (client_id(), height.clone()).prop_map(|(client_id, (number, height))| {
+                    ClientConsensusStatePath::new(client_id, number, height).into()
+                }),
+                (client_id(), height.clone()).prop_map(|(client_id, (number, height))| {
+                    ClientUpdateTimePath::new(client_id, number, height).into()
+                }),
+                (client_id(), height).prop_map(|(client_id, (number, height))| {
+                    ClientUpdateHeightPath::new(client_id, number, height).into()
+                }),
+                client_id().prop_map(|client_id| ClientConnectionPath(client_id).into()),
+                any::<u64>().prop_map(|counter| ConnectionPath(ConnectionId::new(counter)).into()),
+                port_id().prop_map(|port_id| PortPath(port_id).into()),
+                (port_id(), channel_id())
+                    .prop_map(|(port_id, channel_id)| ChannelEndPath(port_id, channel_id).into()),
+                (port_id(), channel_id())
+                    .prop_map(|(port_id, channel_id)| SeqSendPath(port_id, channel_id).into()),
+                (port_id(), channel_id())
+                    .prop_map(|(port_id, channel_id)| SeqRecvPath(port_id, channel_id).into()),
+                (port_id(), channel_id())
+                    .prop_map(|(port_id, channel_id)| SeqAckPath(port_id, channel_id).into()),
+                (port_id(), channel_id(), sequence()).prop_map(
+                    |(port_id, channel_id, sequence)| {
+                        CommitmentPath::new(&port_id, &channel_id, sequence).into()
+                    }
+                ),
+                (port_id(), channel_id(), sequence()).prop_map(
+                    |(port_id, channel_id, sequence)| {
+                        AckPath::new(&port_id, &channel_id, sequence).into()
+                    }
+                ),
+                (port_id(), channel_id(), sequence()).prop_map(
+                    |(port_id, channel_id, sequence)| {
+                        ReceiptPath::new(&port_id, &channel_id, sequence).into()
+                    }
+                ),
+                any::<u64>()
+                    .prop_map(|height| UpgradeClientPath::UpgradedClientState(height).into()),
+                any::<u64>().prop_map(|height| {
+                    UpgradeClientPath::UpgradedClientConsensusState(height).into()
+                }),
+            ]
+        }
+
+        /// Keys made of path components, numbers and identifiers, which are
+        /// more likely than random bytes to look like a valid key.
+        fn key_like() -> impl Strategy<Value = String> {
+            "((clients|connections|ports|channels|channelEnds|sequences|commitments|acks|\
+              receipts|consensusStates|processedTime|upgradedIBCState|upgradedClient|\
+              nextSequenceSend|07-tendermint-[0-9]{1,2}|connection-[0-9]{1,2}|\
+              channel-[0-9]{1,2}|transfer|[0-9]{1,3}|[0-9]{1,2}-[0-9]{1,2}|\\+|-)/?){0,8}"
+        }
+
+        proptest! {
+            #[test]
+            fn test_path_key_bytes_roundtrip(path in path()) {
+                let key = path.clone().into_bytes();
+
+                prop_assert_eq!(Path::try_from_key_bytes(&key).expect("valid key"), path.clone());
+                prop_assert_eq!(Path::from_str(&path.to_string()).expect("valid path"), path);
+            }
+
+            #[test]
+            fn test_sub_path_key_bytes_roundtrip(
+                port_id in port_id(),
+                channel_id in channel_id(),
+                sequence in sequence(),
+            ) {
+                let path = ReceiptPath::new(&port_id, &channel_id, sequence);
+                let key = Path::from(path.clone()).into_bytes();
+
+                prop_assert_eq!(ReceiptPath::try_from_key_bytes(&key).expect("valid key"), path);
+                prop_assert!(CommitmentPath::try_from_key_bytes(&key).is_err());
+            }
+
+            #[test]
+            fn test_parsed_keys_are_canonical(key in key_like()) {
+                if let Ok(path) = Path::try_from_key_bytes(key.as_bytes()) {
+                    prop_assert_eq!(path.into_bytes(), key.into_bytes());
+                }
+            }
+
+            #[test]
+            fn test_arbitrary_key_bytes_dont_panic(key in any::<Vec<u8>>()) {
+                let _ = Path::try_from_key_bytes(&key);
+            }
+        }
+    }
+
+    #[cfg(any(feature = "borsh", feature = "parity-scale-codec"))]
+    fn dummy_paths() -> [Path; 4] {
+        [
+            Path::NextClientSequence(NextClientSequencePath),
+            Path::ClientConsensusState(ClientConsensusStatePath::new(
+                ClientId::new("07-tendermint", 0).expect("valid client id"),
+                1,
+                10,
+            )),
+            Path::Commitment(CommitmentPath::new(
+                &PortId::transfer(),
+                &ChannelId::new(0),
+                Sequence::from(5),
+            )),
+            Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(10)),
+        ]
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_path_borsh_roundtrip() {
+        use borsh::{BorshDeserialize, BorshSerialize};
+
+        for path in dummy_paths() {
+            let bytes = path.try_to_vec().expect("path serializes");
+            assert_eq!(
+                Path::try_from_slice(&bytes).expect("path deserializes"),
+                path
+            );
+        }
+    }
+
+    #[cfg(feature = "parity-scale-codec")]
+    #[test]
+    fn test_path_parity_scale_codec_roundtrip() {
+        use parity_scale_codec::{Decode, Encode};
+
+        for path in dummy_paths() {
+            let bytes = path.encode();
+            assert_eq!(
+                Path::decode(&mut bytes.as_slice()).expect("path decodes"),
+                path
+            );
+        }
+    }
 }
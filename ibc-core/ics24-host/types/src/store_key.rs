@@ -0,0 +1,115 @@
+//! Codec between ICS-24 [`Path`]s and the raw byte keys under which `ibc-go`
+//! lays out IBC state, allowing hosts built on a generic key-value store to
+//! share the exact key layout of Cosmos SDK chains.
+use ibc_primitives::prelude::*;
+
+use crate::path::{Path, ITERATE_CONSENSUS_STATE_PREFIX};
+
+/// Name of the store under which `ibc-go` commits the IBC state.
+pub const IBC_STORE_KEY: &str = "ibc";
+/// Name of the store under which the Cosmos SDK upgrade module commits the
+/// upgraded client and consensus states.
+pub const UPGRADE_STORE_KEY: &str = "upgrade";
+
+/// Returns the raw store key of the given path, i.e. the UTF-8 bytes of its
+/// ICS-24 representation.
+///
+/// Raw store keys are parsed back with [`Path::try_from_key_bytes`].
+pub fn path_to_key(path: &Path) -> Vec<u8> {
+    path.to_string().into_bytes()
+}
+
+/// Returns the name of the store that commits the given path.
+pub fn store_key(path: &Path) -> &'static str {
+    match path {
+        Path::UpgradeClient(_) => UPGRADE_STORE_KEY,
+        _ => IBC_STORE_KEY,
+    }
+}
+
+/// Returns the ICS-23 key path proving the given path on a Cosmos SDK chain,
+/// i.e. the name of the committing store followed by the path store key.
+pub fn proof_key_path(path: &Path) -> Vec<Vec<u8>> {
+    vec![store_key(path).as_bytes().to_vec(), path_to_key(path)]
+}
+
+/// Parses a key built by [`iteration_key`](crate::path::iteration_key) back
+/// into its revision number and revision height.
+pub fn parse_iteration_key(key: &[u8]) -> Option<(u64, u64)> {
+    let heights = key.strip_prefix(ITERATE_CONSENSUS_STATE_PREFIX.as_bytes())?;
+
+    if heights.len() != 16 {
+        return None;
+    }
+
+    let (revision_number, revision_height) = heights.split_at(8);
+
+    Some((
+        u64::from_be_bytes(revision_number.try_into().ok()?),
+        u64::from_be_bytes(revision_height.try_into().ok()?),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifiers::{ChannelId, ClientId, PortId, Sequence};
+    use crate::path::{
+        iteration_key, AckPath, ClientConsensusStatePath, NextClientSequencePath, UpgradeClientPath,
+    };
+
+    #[rstest::rstest]
+    #[case(Path::NextClientSequence(NextClientSequencePath), "nextClientSequence")]
+    #[case(
+        Path::ClientConsensusState(ClientConsensusStatePath::new(
+            ClientId::new("07-tendermint", 0).expect("valid client id"),
+            1,
+            10,
+        )),
+        "clients/07-tendermint-0/consensusStates/1-10"
+    )]
+    #[case(
+        Path::Ack(AckPath::new(
+            &PortId::transfer(),
+            &ChannelId::new(0),
+            Sequence::from(5),
+        )),
+        "acks/ports/transfer/channels/channel-0/sequences/5"
+    )]
+    fn test_path_key_roundtrip(#[case] path: Path, #[case] key: &str) {
+        assert_eq!(path_to_key(&path), key.as_bytes());
+        assert_eq!(
+            Path::try_from_key_bytes(key.as_bytes()).expect("valid key"),
+            path
+        );
+        assert_eq!(proof_key_path(&path)[0], IBC_STORE_KEY.as_bytes());
+    }
+
+    #[test]
+    fn test_upgrade_paths_are_in_upgrade_store() {
+        let path = Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(10));
+
+        assert_eq!(
+            proof_key_path(&path),
+            vec![
+                UPGRADE_STORE_KEY.as_bytes().to_vec(),
+                b"upgradedIBCState/10/upgradedClient".to_vec(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_keys_are_rejected() {
+        assert!(Path::try_from_key_bytes(b"clients/07-tendermint-0").is_err());
+        assert!(Path::try_from_key_bytes(&[0xff, 0xfe]).is_err());
+    }
+
+    #[test]
+    fn test_iteration_key_roundtrip() {
+        let key = iteration_key(3, u64::MAX);
+
+        assert_eq!(parse_iteration_key(&key), Some((3, u64::MAX)));
+        assert_eq!(parse_iteration_key(&key[..key.len() - 1]), None);
+        assert_eq!(parse_iteration_key(b"nextClientSequence"), None);
+    }
+}
@@ -3,7 +3,7 @@ use ibc_primitives::prelude::*;
 use crate::error::IdentifierError as Error;
 use crate::identifiers::{ChannelId, ConnectionId};
 
-const VALID_SPECIAL_CHARS: &str = "._+-#[]<>";
+pub(crate) const VALID_SPECIAL_CHARS: &str = "._+-#[]<>";
 
 /// Checks if the identifier only contains valid characters as specified in the
 /// [`ICS-24`](https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#paths-identifiers-separators)]
@@ -71,7 +71,9 @@ pub fn validate_named_u64_index(id: &str, name: &str) -> Result<(), Error> {
         .strip_prefix('-')
         .ok_or_else(|| Error::InvalidPrefix { prefix: id.into() })?;
 
-    if number_s.starts_with('0') && number_s.len() > 1 {
+    if !number_s.bytes().all(|b| b.is_ascii_digit())
+        || (number_s.starts_with('0') && number_s.len() > 1)
+    {
         return Err(Error::InvalidPrefix { prefix: id.into() });
     }
 
@@ -100,10 +102,14 @@ pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
 /// Default validator function for Connection identifiers.
 ///
 /// A valid connection identifier must be between 10-64 characters as specified
-/// in the ICS-24 spec.
+/// in the ICS-24 spec, and be either of the `connection-{N}` form or the
+/// `connection-localhost` sentinel.
 pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
     validate_identifier_length(id, 10, 64)?;
+    if id == ConnectionId::localhost().as_str() {
+        return Ok(());
+    }
     validate_named_u64_index(id, ConnectionId::prefix())?;
     Ok(())
 }
@@ -128,6 +134,100 @@ pub fn validate_channel_identifier(id: &str) -> Result<(), Error> {
     Ok(())
 }
 
+/// Compile-time counterpart of [`validate_identifier_chars`] and
+/// [`validate_identifier_length`], used by the `from_static` identifier
+/// constructors.
+///
+/// As `char` methods can't be called in `const` contexts, only ASCII
+/// alphanumeric characters are accepted, which makes this check stricter than
+/// its runtime counterpart.
+pub(crate) const fn is_valid_static_identifier(id: &str, min: usize, max: usize) -> bool {
+    let bytes = id.as_bytes();
+    if bytes.is_empty() || bytes.len() < min || bytes.len() > max {
+        return false;
+    }
+
+    let special = VALID_SPECIAL_CHARS.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if !c.is_ascii_alphanumeric() {
+            let mut j = 0;
+            while j < special.len() && special[j] != c {
+                j += 1;
+            }
+            if j == special.len() {
+                return false;
+            }
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Compile-time string equality, as `==` is not `const` on `str`.
+pub(crate) const fn is_static_str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Compile-time counterpart of [`validate_named_u64_index`].
+pub(crate) const fn is_valid_static_named_u64_index(id: &str, name: &str) -> bool {
+    let (bytes, name) = (id.as_bytes(), name.as_bytes());
+    // The name, a dash and at least one digit.
+    if bytes.len() < name.len() + 2 {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < name.len() {
+        if bytes[i] != name[i] {
+            return false;
+        }
+        i += 1;
+    }
+    if bytes[i] != b'-' {
+        return false;
+    }
+    i += 1;
+
+    // No leading zeros, except for the zero index itself.
+    if bytes[i] == b'0' && bytes.len() > i + 1 {
+        return false;
+    }
+
+    let mut index: u64 = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if !c.is_ascii_digit() {
+            return false;
+        }
+        index = match index.checked_mul(10) {
+            Some(index) => match index.checked_add((c - b'0') as u64) {
+                Some(index) => index,
+                None => return false,
+            },
+            None => return false,
+        };
+        i += 1;
+    }
+
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -174,6 +274,12 @@ mod tests {
         validate_connection_identifier("connection-18446744073709551615").expect("success");
     }
 
+    #[test]
+    fn parse_localhost_connection_id() {
+        validate_connection_identifier("connection-localhost").expect("success");
+        validate_connection_identifier("connection-localhost0").expect_err("failure");
+    }
+
     #[test]
     fn parse_invalid_connection_id_non_indexed() {
         // invalid indexing for connection id
@@ -312,8 +418,28 @@ mod tests {
     #[case::n1234("channel", "1234", true)]
     #[case::u64_max("chan", "18446744073709551615", true)]
     #[case::u64_max_plus_1("chan", "18446744073709551616", false)]
+    #[case::plus_sign("connection", "+1", false)]
     fn test_named_index_validation(#[case] name: &str, #[case] id: &str, #[case] success: bool) {
-        let result = validate_named_u64_index(format!("{name}-{id}").as_str(), name);
+        let id = format!("{name}-{id}");
+        let result = validate_named_u64_index(id.as_str(), name);
         assert_eq!(result.is_ok(), success, "{result:?}");
+        assert_eq!(is_valid_static_named_u64_index(&id, name), success);
+    }
+
+    #[rstest]
+    #[case::empty("", 0, 64, false)]
+    #[case::too_short("a", 2, 64, false)]
+    #[case::too_long("abc", 1, 2, false)]
+    #[case::special_chars("._+-#[]<>", 1, 64, true)]
+    #[case::path_separator("id/1", 1, 64, false)]
+    #[case::non_ascii("chaîne", 1, 64, false)]
+    #[case("07-tendermint-0", 9, 64, true)]
+    fn test_static_identifier_validation(
+        #[case] id: &str,
+        #[case] min: usize,
+        #[case] max: usize,
+        #[case] success: bool,
+    ) {
+        assert_eq!(is_valid_static_identifier(id, min, max), success);
     }
 }
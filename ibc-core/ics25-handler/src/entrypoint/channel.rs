@@ -0,0 +1,181 @@
+//! Entrypoints of the ICS-04 channel handshake messages.
+//!
+//! Unlike the client and connection messages, channel messages are routed to
+//! the module owning the channel, or the port of a channel being opened, hence
+//! their entrypoints take the [`Router`](ibc_core_router::router::Router) of
+//! the host.
+
+/// Entrypoints of the `MsgChannelOpenInit` message.
+pub mod chan_open_init {
+    use ibc_core_channel::handler::{chan_open_init_execute, chan_open_init_validate};
+    use ibc_core_channel::types::msgs::{MsgChannelOpenInit, MsgChannelOpenInitResponse};
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::types::identifiers::ChannelId;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+    use ibc_core_router::router::Router;
+
+    use crate::entrypoint::ownership::{claim_channel, verify_channel_unclaimed};
+    use crate::entrypoint::{route, route_mut};
+
+    pub fn validate<Ctx>(
+        ctx: &Ctx,
+        router: &impl Router,
+        msg: MsgChannelOpenInit,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        let module = route(ctx, router, &msg.port_id_on_a, None)?;
+
+        let chan_id_on_a = ChannelId::new(ctx.channel_counter()?);
+        verify_channel_unclaimed(ctx, &msg.port_id_on_a, &chan_id_on_a)?;
+
+        chan_open_init_validate(ctx, module, msg)
+    }
+
+    pub fn execute<Ctx>(
+        ctx: &mut Ctx,
+        router: &mut impl Router,
+        msg: MsgChannelOpenInit,
+    ) -> Result<MsgChannelOpenInitResponse, ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        let (module_id, module) = route_mut(ctx, router, &msg.port_id_on_a, None)?;
+
+        let chan_id_on_a = ChannelId::new(ctx.channel_counter()?);
+        claim_channel(ctx, &msg.port_id_on_a, &chan_id_on_a, module_id)?;
+
+        chan_open_init_execute(ctx, module, msg)
+    }
+}
+
+/// Entrypoints of the `MsgChannelOpenTry` message.
+pub mod chan_open_try {
+    use ibc_core_channel::handler::{chan_open_try_execute, chan_open_try_validate};
+    use ibc_core_channel::types::msgs::{MsgChannelOpenTry, MsgChannelOpenTryResponse};
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::types::identifiers::ChannelId;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+    use ibc_core_router::router::Router;
+
+    use crate::entrypoint::ownership::{claim_channel, verify_channel_unclaimed};
+    use crate::entrypoint::{route, route_mut};
+
+    pub fn validate<Ctx>(
+        ctx: &Ctx,
+        router: &impl Router,
+        msg: MsgChannelOpenTry,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        let module = route(ctx, router, &msg.port_id_on_b, None)?;
+
+        let chan_id_on_b = ChannelId::new(ctx.channel_counter()?);
+        verify_channel_unclaimed(ctx, &msg.port_id_on_b, &chan_id_on_b)?;
+
+        chan_open_try_validate(ctx, module, msg)
+    }
+
+    pub fn execute<Ctx>(
+        ctx: &mut Ctx,
+        router: &mut impl Router,
+        msg: MsgChannelOpenTry,
+    ) -> Result<MsgChannelOpenTryResponse, ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        let (module_id, module) = route_mut(ctx, router, &msg.port_id_on_b, None)?;
+
+        let chan_id_on_b = ChannelId::new(ctx.channel_counter()?);
+        claim_channel(ctx, &msg.port_id_on_b, &chan_id_on_b, module_id)?;
+
+        chan_open_try_execute(ctx, module, msg)
+    }
+}
+
+/// Generates the entrypoints of a message acting on an existing channel, which
+/// is routed to the module owning the channel.
+macro_rules! existing_channel_entrypoints {
+    ($(#[$doc:meta])* $name:ident, $msg:ident, $validate:ident, $execute:ident, |$m:ident| ($port_id:expr, $channel_id:expr)) => {
+        $(#[$doc])*
+        pub mod $name {
+            use ibc_core_channel::handler::{$execute, $validate};
+            use ibc_core_channel::types::msgs::$msg;
+            use ibc_core_handler_types::error::ContextError;
+            use ibc_core_host::{ExecutionContext, ValidationContext};
+            use ibc_core_router::router::Router;
+
+            use crate::entrypoint::{route, route_mut};
+
+            pub fn validate<Ctx>(
+                ctx: &Ctx,
+                router: &impl Router,
+                msg: $msg,
+            ) -> Result<(), ContextError>
+            where
+                Ctx: ValidationContext,
+            {
+                let module = {
+                    let $m = &msg;
+                    route(ctx, router, $port_id, Some($channel_id))?
+                };
+
+                $validate(ctx, module, msg)
+            }
+
+            pub fn execute<Ctx>(
+                ctx: &mut Ctx,
+                router: &mut impl Router,
+                msg: $msg,
+            ) -> Result<(), ContextError>
+            where
+                Ctx: ExecutionContext,
+            {
+                let (_, module) = {
+                    let $m = &msg;
+                    route_mut(ctx, router, $port_id, Some($channel_id))?
+                };
+
+                $execute(ctx, module, msg)
+            }
+        }
+    };
+}
+
+existing_channel_entrypoints!(
+    /// Entrypoints of the `MsgChannelOpenAck` message.
+    chan_open_ack,
+    MsgChannelOpenAck,
+    chan_open_ack_validate,
+    chan_open_ack_execute,
+    |msg| (&msg.port_id_on_a, &msg.chan_id_on_a)
+);
+
+existing_channel_entrypoints!(
+    /// Entrypoints of the `MsgChannelOpenConfirm` message.
+    chan_open_confirm,
+    MsgChannelOpenConfirm,
+    chan_open_confirm_validate,
+    chan_open_confirm_execute,
+    |msg| (&msg.port_id_on_b, &msg.chan_id_on_b)
+);
+
+existing_channel_entrypoints!(
+    /// Entrypoints of the `MsgChannelCloseInit` message.
+    chan_close_init,
+    MsgChannelCloseInit,
+    chan_close_init_validate,
+    chan_close_init_execute,
+    |msg| (&msg.port_id_on_a, &msg.chan_id_on_a)
+);
+
+existing_channel_entrypoints!(
+    /// Entrypoints of the `MsgChannelCloseConfirm` message.
+    chan_close_confirm,
+    MsgChannelCloseConfirm,
+    chan_close_confirm_validate,
+    chan_close_confirm_execute,
+    |msg| (&msg.port_id_on_b, &msg.chan_id_on_b)
+);
@@ -0,0 +1,100 @@
+//! Entrypoints of the ICS-02 client messages.
+
+/// Entrypoints of the `MsgCreateClient` message.
+pub mod create_client {
+    use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+    use ibc_core_client::handler::create_client;
+    use ibc_core_client::types::error::ClientError;
+    use ibc_core_client::types::msgs::{MsgCreateClient, MsgCreateClientResponse};
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+    use ibc_primitives::proto::Any;
+
+    pub fn validate<Ctx>(ctx: &Ctx, msg: MsgCreateClient) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+        <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error:
+            Into<ClientError>,
+    {
+        create_client::validate(ctx, msg)
+    }
+
+    pub fn execute<Ctx>(
+        ctx: &mut Ctx,
+        msg: MsgCreateClient,
+    ) -> Result<MsgCreateClientResponse, ContextError>
+    where
+        Ctx: ExecutionContext,
+        <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error:
+            Into<ClientError>,
+    {
+        create_client::execute(ctx, msg)
+    }
+}
+
+/// Entrypoints of the `MsgUpdateClient` message.
+pub mod update_client {
+    use ibc_core_client::handler::update_client;
+    use ibc_core_client::types::msgs::{MsgUpdateClient, MsgUpdateOrMisbehaviour};
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+
+    pub fn validate<Ctx>(ctx: &Ctx, msg: MsgUpdateClient) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        update_client::validate(ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg))
+    }
+
+    pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpdateClient) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        update_client::execute(ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg))
+    }
+}
+
+/// Entrypoints of the `MsgSubmitMisbehaviour` message.
+#[allow(deprecated)]
+pub mod submit_misbehaviour {
+    use ibc_core_client::handler::update_client;
+    use ibc_core_client::types::msgs::{MsgSubmitMisbehaviour, MsgUpdateOrMisbehaviour};
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+
+    pub fn validate<Ctx>(ctx: &Ctx, msg: MsgSubmitMisbehaviour) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        update_client::validate(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))
+    }
+
+    pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgSubmitMisbehaviour) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        update_client::execute(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))
+    }
+}
+
+/// Entrypoints of the `MsgUpgradeClient` message.
+pub mod upgrade_client {
+    use ibc_core_client::handler::upgrade_client;
+    use ibc_core_client::types::msgs::MsgUpgradeClient;
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+
+    pub fn validate<Ctx>(ctx: &Ctx, msg: MsgUpgradeClient) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        upgrade_client::validate(ctx, msg)
+    }
+
+    pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpgradeClient) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        upgrade_client::execute(ctx, msg)
+    }
+}
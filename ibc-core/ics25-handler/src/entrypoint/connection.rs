@@ -0,0 +1,101 @@
+//! Entrypoints of the ICS-03 connection handshake messages.
+
+/// Entrypoints of the `MsgConnectionOpenInit` message.
+pub mod conn_open_init {
+    use ibc_core_connection::handler::conn_open_init;
+    use ibc_core_connection::types::msgs::{MsgConnectionOpenInit, MsgConnectionOpenInitResponse};
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+
+    pub fn validate<Ctx>(ctx: &Ctx, msg: MsgConnectionOpenInit) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        conn_open_init::validate(ctx, msg)
+    }
+
+    pub fn execute<Ctx>(
+        ctx: &mut Ctx,
+        msg: MsgConnectionOpenInit,
+    ) -> Result<MsgConnectionOpenInitResponse, ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        conn_open_init::execute(ctx, msg)
+    }
+}
+
+/// Entrypoints of the `MsgConnectionOpenTry` message.
+pub mod conn_open_try {
+    use ibc_core_client::types::error::ClientError;
+    use ibc_core_connection::handler::conn_open_try;
+    use ibc_core_connection::types::msgs::{MsgConnectionOpenTry, MsgConnectionOpenTryResponse};
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+    use ibc_primitives::proto::Any;
+
+    pub fn validate<Ctx>(ctx: &Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+        <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+    {
+        conn_open_try::validate(ctx, msg)
+    }
+
+    pub fn execute<Ctx>(
+        ctx: &mut Ctx,
+        msg: MsgConnectionOpenTry,
+    ) -> Result<MsgConnectionOpenTryResponse, ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        conn_open_try::execute(ctx, msg)
+    }
+}
+
+/// Entrypoints of the `MsgConnectionOpenAck` message.
+pub mod conn_open_ack {
+    use ibc_core_client::types::error::ClientError;
+    use ibc_core_connection::handler::conn_open_ack;
+    use ibc_core_connection::types::msgs::MsgConnectionOpenAck;
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+    use ibc_primitives::proto::Any;
+
+    pub fn validate<Ctx>(ctx: &Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+        <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+    {
+        conn_open_ack::validate(ctx, msg)
+    }
+
+    pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        conn_open_ack::execute(ctx, msg)
+    }
+}
+
+/// Entrypoints of the `MsgConnectionOpenConfirm` message.
+pub mod conn_open_confirm {
+    use ibc_core_connection::handler::conn_open_confirm;
+    use ibc_core_connection::types::msgs::MsgConnectionOpenConfirm;
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+
+    pub fn validate<Ctx>(ctx: &Ctx, msg: MsgConnectionOpenConfirm) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        conn_open_confirm::validate(ctx, &msg)
+    }
+
+    pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgConnectionOpenConfirm) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        conn_open_confirm::execute(ctx, &msg)
+    }
+}
@@ -0,0 +1,302 @@
+//! Entrypoints of the IBC handlers.
+//!
+//! Besides [`dispatch`], [`validate`] and [`execute`], which process any
+//! message wrapped in a [`MsgEnvelope`], each message has its own entrypoints,
+//! e.g. [`recv_packet::validate`] and [`recv_packet::execute`], with the same
+//! semantics. Hosts supporting only a subset of the IBC messages, e.g. a
+//! receive-only bridge, can call the entrypoints of the messages they support
+//! and skip the envelope.
+//!
+//! [`simulate`] runs the same handling as [`dispatch`] over a branch of the
+//! state of the host, and reports what the message would have produced.
+//!
+//! The [`HostMode`](ibc_core_handler_types::mode::HostMode) of the host is
+//! only enforced, and the [`HandlerOutput`] of the message only returned, by
+//! the envelope based entrypoints.
+
+mod channel;
+mod client;
+mod connection;
+pub mod ownership;
+mod packet;
+mod recorder;
+mod simulate;
+
+pub use channel::*;
+pub use client::*;
+pub use connection::*;
+use ibc_core_channel::types::msgs::{ChannelMsg, PacketMsg};
+use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::msgs::ClientMsg;
+use ibc_core_connection::types::msgs::ConnectionMsg;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::msgs::MsgEnvelope;
+use ibc_core_handler_types::output::{HandlerOutput, HandlerResult};
+use ibc_core_host::types::identifiers::{ChannelId, PortId};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_core_router::router::Router;
+use ibc_core_router::types::error::RouterError;
+use ibc_core_router::types::module::ModuleId;
+use ibc_primitives::proto::Any;
+use ownership::channel_owner;
+pub use packet::*;
+use recorder::{Recording, RecordingContext};
+pub use simulate::*;
+
+/// Entrypoint which performs both validation and message execution, returning
+/// the [`HandlerOutput`] of the message.
+pub fn dispatch<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+) -> Result<HandlerOutput, ContextError>
+where
+    Ctx: ExecutionContext,
+    <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error: Into<ClientError>,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+    <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    validate(ctx, router, msg.clone())?;
+    execute(ctx, router, msg)
+}
+
+/// Entrypoint which only performs message validation
+///
+/// If a transaction contains `n` messages `m_1` ... `m_n`, then
+/// they MUST be processed as follows:
+///     validate(m_1), execute(m_1), ..., validate(m_n), execute(m_n)
+/// That is, the state transition of message `i` must be applied before
+/// message `i+1` is validated. This is equivalent to calling
+/// `dispatch()` on each successively.
+pub fn validate<Ctx>(ctx: &Ctx, router: &impl Router, msg: MsgEnvelope) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+    <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error: Into<ClientError>,
+    <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    ctx.host_mode().verify_allows(&msg)?;
+
+    match msg {
+        MsgEnvelope::Client(msg) => match msg {
+            ClientMsg::CreateClient(msg) => create_client::validate(ctx, msg),
+            ClientMsg::UpdateClient(msg) => update_client::validate(ctx, msg),
+            ClientMsg::Misbehaviour(msg) => submit_misbehaviour::validate(ctx, msg),
+            ClientMsg::UpgradeClient(msg) => upgrade_client::validate(ctx, msg),
+            ClientMsg::RecoverClient(_msg) => {
+                // Recover client messages are not dispatched by ibc-rs as they can only be
+                // authorized via a passing governance proposal
+                Ok(())
+            }
+        },
+        MsgEnvelope::Connection(msg) => match msg {
+            ConnectionMsg::OpenInit(msg) => conn_open_init::validate(ctx, msg),
+            ConnectionMsg::OpenTry(msg) => conn_open_try::validate(ctx, msg),
+            ConnectionMsg::OpenAck(msg) => conn_open_ack::validate(ctx, msg),
+            ConnectionMsg::OpenConfirm(msg) => conn_open_confirm::validate(ctx, msg),
+        },
+        MsgEnvelope::Channel(msg) => match msg {
+            ChannelMsg::OpenInit(msg) => chan_open_init::validate(ctx, router, msg),
+            ChannelMsg::OpenTry(msg) => chan_open_try::validate(ctx, router, msg),
+            ChannelMsg::OpenAck(msg) => chan_open_ack::validate(ctx, router, msg),
+            ChannelMsg::OpenConfirm(msg) => chan_open_confirm::validate(ctx, router, msg),
+            ChannelMsg::CloseInit(msg) => chan_close_init::validate(ctx, router, msg),
+            ChannelMsg::CloseConfirm(msg) => chan_close_confirm::validate(ctx, router, msg),
+        },
+        MsgEnvelope::Packet(msg) => match msg {
+            PacketMsg::Recv(msg) => recv_packet::validate(ctx, router, msg),
+            PacketMsg::Ack(msg) => acknowledgement::validate(ctx, router, msg),
+            PacketMsg::Timeout(msg) => timeout::validate(ctx, router, msg),
+            PacketMsg::TimeoutOnClose(msg) => timeout_on_close::validate(ctx, router, msg),
+        },
+    }
+}
+
+/// Entrypoint which only performs message execution, returning the
+/// [`HandlerOutput`] of the message.
+///
+/// The events and logs of the output are emitted on the context as well.
+pub fn execute<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+) -> Result<HandlerOutput, ContextError>
+where
+    Ctx: ExecutionContext,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    let mut recording_ctx = RecordingContext::new(ctx, Recording::Execution);
+
+    let result = execute_msg(&mut recording_ctx, router, msg)?;
+
+    Ok(HandlerOutput::new(
+        recording_ctx.events,
+        recording_ctx.logs,
+        result,
+    ))
+}
+
+/// Executes the message with the handler of its type.
+pub(crate) fn execute_msg<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+) -> Result<Option<HandlerResult>, ContextError>
+where
+    Ctx: ExecutionContext,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    ctx.host_mode().verify_allows(&msg)?;
+
+    match msg {
+        MsgEnvelope::Client(msg) => match msg {
+            ClientMsg::CreateClient(msg) => {
+                create_client::execute(ctx, msg).map(|res| Some(res.into()))
+            }
+            ClientMsg::UpdateClient(msg) => update_client::execute(ctx, msg).map(|()| None),
+            ClientMsg::Misbehaviour(msg) => submit_misbehaviour::execute(ctx, msg).map(|()| None),
+            ClientMsg::UpgradeClient(msg) => upgrade_client::execute(ctx, msg).map(|()| None),
+            ClientMsg::RecoverClient(_msg) => {
+                // Recover client messages are not dispatched by ibc-rs as they can only be
+                // authorized via a passing governance proposal
+                Ok(None)
+            }
+        },
+        MsgEnvelope::Connection(msg) => match msg {
+            ConnectionMsg::OpenInit(msg) => {
+                conn_open_init::execute(ctx, msg).map(|res| Some(res.into()))
+            }
+            ConnectionMsg::OpenTry(msg) => {
+                conn_open_try::execute(ctx, msg).map(|res| Some(res.into()))
+            }
+            ConnectionMsg::OpenAck(msg) => conn_open_ack::execute(ctx, msg).map(|()| None),
+            ConnectionMsg::OpenConfirm(msg) => conn_open_confirm::execute(ctx, msg).map(|()| None),
+        },
+        MsgEnvelope::Channel(msg) => match msg {
+            ChannelMsg::OpenInit(msg) => {
+                chan_open_init::execute(ctx, router, msg).map(|res| Some(res.into()))
+            }
+            ChannelMsg::OpenTry(msg) => {
+                chan_open_try::execute(ctx, router, msg).map(|res| Some(res.into()))
+            }
+            ChannelMsg::OpenAck(msg) => chan_open_ack::execute(ctx, router, msg).map(|()| None),
+            ChannelMsg::OpenConfirm(msg) => {
+                chan_open_confirm::execute(ctx, router, msg).map(|()| None)
+            }
+            ChannelMsg::CloseInit(msg) => chan_close_init::execute(ctx, router, msg).map(|()| None),
+            ChannelMsg::CloseConfirm(msg) => {
+                chan_close_confirm::execute(ctx, router, msg).map(|()| None)
+            }
+        },
+        MsgEnvelope::Packet(msg) => match msg {
+            PacketMsg::Recv(msg) => recv_packet::execute(ctx, router, msg).map(|()| None),
+            PacketMsg::Ack(msg) => acknowledgement::execute(ctx, router, msg).map(|()| None),
+            PacketMsg::Timeout(msg) => timeout::execute(ctx, router, msg).map(|()| None),
+            PacketMsg::TimeoutOnClose(msg) => {
+                timeout_on_close::execute(ctx, router, msg).map(|()| None)
+            }
+        },
+    }
+}
+
+/// Envelope based entrypoints with their signatures prior to returning the
+/// [`HandlerOutput`] of the messages, for the hosts which don't use it.
+pub mod compat {
+    use super::*;
+
+    /// Same as [`dispatch`](super::dispatch), discarding the output.
+    #[deprecated(note = "use `dispatch`, which returns the `HandlerOutput` of the message")]
+    pub fn dispatch<Ctx>(
+        ctx: &mut Ctx,
+        router: &mut impl Router,
+        msg: MsgEnvelope,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+        <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error:
+            Into<ClientError>,
+        <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error:
+            Into<ClientError>,
+        <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+    {
+        super::dispatch(ctx, router, msg).map(|_| ())
+    }
+
+    /// Same as [`execute`](super::execute), discarding the output.
+    #[deprecated(note = "use `execute`, which returns the `HandlerOutput` of the message")]
+    pub fn execute<Ctx>(
+        ctx: &mut Ctx,
+        router: &mut impl Router,
+        msg: MsgEnvelope,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+        <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error:
+            Into<ClientError>,
+    {
+        super::execute(ctx, router, msg).map(|_| ())
+    }
+}
+
+/// Looks up the module a message is routed to: the owner of the channel for
+/// messages acting on an existing channel, the owner of the port otherwise.
+fn lookup_route<Ctx>(
+    ctx: &Ctx,
+    router: &impl Router,
+    port_id: &PortId,
+    channel_id: Option<&ChannelId>,
+) -> Result<ModuleId, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    match channel_id {
+        Some(channel_id) => channel_owner(ctx, router, port_id, channel_id),
+        None => Ok(router
+            .lookup_module(port_id)
+            .ok_or_else(|| RouterError::UnknownPort {
+                port_id: port_id.clone(),
+            })?),
+    }
+}
+
+/// Returns the module a message is routed to, i.e. the owner of the channel
+/// for messages acting on an existing channel, and the owner of the port
+/// otherwise.
+pub(crate) fn route<'r, Ctx>(
+    ctx: &Ctx,
+    router: &'r impl Router,
+    port_id: &PortId,
+    channel_id: Option<&ChannelId>,
+) -> Result<&'r dyn Module, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let module_id = lookup_route(ctx, router, port_id, channel_id)?;
+
+    let module = router
+        .get_route(&module_id)
+        .ok_or(RouterError::ModuleNotFound)?;
+
+    Ok(module)
+}
+
+/// Same as [`route`], but returns a mutable reference to the module along with
+/// its identifier.
+pub(crate) fn route_mut<'r, Ctx>(
+    ctx: &Ctx,
+    router: &'r mut impl Router,
+    port_id: &PortId,
+    channel_id: Option<&ChannelId>,
+) -> Result<(ModuleId, &'r mut dyn Module), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let module_id = lookup_route(ctx, router, port_id, channel_id)?;
+
+    let module = router
+        .get_route_mut(&module_id)
+        .ok_or(RouterError::ModuleNotFound)?;
+
+    Ok((module_id, module))
+}
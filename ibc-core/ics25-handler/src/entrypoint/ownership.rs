@@ -0,0 +1,141 @@
+//! Resolution of the module owning each channel, so that the messages acting
+//! on a channel are only ever routed to the module that opened it.
+//!
+//! The owners are recorded by the host through
+//! [`ExecutionContext::store_channel_owner`] along with the channel ends, such
+//! that a claim is reverted with the transaction that made it. Channels
+//! without a recorded owner, e.g. opened before the host recorded owners and
+//! not migrated with [`migrate_channel_owners`], are owned by the owner of
+//! their port.
+
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::{ChannelId, PortId};
+use ibc_core_host::types::path::ChannelEndPath;
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_core_router::types::error::RouterError;
+use ibc_core_router::types::module::ModuleId;
+
+/// Checks that the given channel has no recorded owner yet, i.e. that the
+/// module opening it can claim it.
+pub fn verify_channel_unclaimed<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if let Some(owner) = ctx.channel_owner(&ChannelEndPath::new(port_id, channel_id))? {
+        return Err(RouterError::ChannelAlreadyOwned {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            module_id: owner,
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Records the module identified by `module_id` as the owner of the given
+/// channel. Called by the `OpenInit` and `OpenTry` entrypoints before the
+/// handshake step writes anything.
+///
+/// Fails if the channel is already owned, regardless of whether the owner is
+/// `module_id` itself.
+pub fn claim_channel<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    module_id: ModuleId,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    verify_channel_unclaimed(ctx, port_id, channel_id)?;
+
+    ctx.store_channel_owner(&ChannelEndPath::new(port_id, channel_id), module_id)
+}
+
+/// Returns the module owning the given channel, which the messages acting on
+/// the channel are routed to.
+pub fn channel_owner<Ctx>(
+    ctx: &Ctx,
+    router: &impl Router,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<ModuleId, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    match ctx.channel_owner(&ChannelEndPath::new(port_id, channel_id))? {
+        Some(owner) => Ok(owner),
+        None => Ok(router
+            .lookup_module(port_id)
+            .ok_or_else(|| RouterError::UnknownPort {
+                port_id: port_id.clone(),
+            })?),
+    }
+}
+
+/// Checks that the module identified by `module_id` owns the given channel.
+pub fn authenticate_channel_owner<Ctx>(
+    ctx: &Ctx,
+    router: &impl Router,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    module_id: &ModuleId,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let owner = channel_owner(ctx, router, port_id, channel_id)?;
+
+    if &owner != module_id {
+        return Err(RouterError::UnauthorizedChannelOwner {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            module_id: module_id.clone(),
+            owner,
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Records the current owner of the port of each of the given channels as the
+/// owner of the channel, skipping the channels whose ownership is already
+/// recorded. Returns the number of channels claimed.
+///
+/// Hosts starting to record channel owners should call it once with all their
+/// existing channels, e.g. as listed by `QueryContext::channel_ends` in
+/// `ibc-query`, before any port is rebound.
+pub fn migrate_channel_owners<Ctx, I>(
+    ctx: &mut Ctx,
+    router: &impl Router,
+    channels: I,
+) -> Result<usize, ContextError>
+where
+    Ctx: ExecutionContext,
+    I: IntoIterator<Item = (PortId, ChannelId)>,
+{
+    let mut claimed = 0;
+
+    for (port_id, channel_id) in channels {
+        let chan_end_path = ChannelEndPath::new(&port_id, &channel_id);
+
+        if ctx.channel_owner(&chan_end_path)?.is_some() {
+            continue;
+        }
+
+        let module_id = router
+            .lookup_module(&port_id)
+            .ok_or(RouterError::PortNotBound { port_id })?;
+
+        ctx.store_channel_owner(&chan_end_path, module_id)?;
+
+        claimed += 1;
+    }
+
+    Ok(claimed)
+}
@@ -0,0 +1,258 @@
+//! Entrypoints of the ICS-04 packet messages.
+//!
+//! Packet messages are routed to the module owning the channel of the packet,
+//! hence their entrypoints take the
+//! [`Router`](ibc_core_router::router::Router) of the host.
+
+/// Entrypoints of the `MsgRecvPacket` message.
+pub mod recv_packet {
+    use ibc_core_channel::handler::{recv_packet_execute, recv_packet_validate};
+    use ibc_core_channel::types::msgs::MsgRecvPacket;
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+    use ibc_core_router::router::Router;
+
+    use crate::entrypoint::{route, route_mut};
+
+    pub fn validate<Ctx>(
+        ctx: &Ctx,
+        router: &impl Router,
+        msg: MsgRecvPacket,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        route(
+            ctx,
+            router,
+            &msg.packet.port_id_on_b,
+            Some(&msg.packet.chan_id_on_b),
+        )?;
+
+        recv_packet_validate(ctx, msg)
+    }
+
+    pub fn execute<Ctx>(
+        ctx: &mut Ctx,
+        router: &mut impl Router,
+        msg: MsgRecvPacket,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        let (_, module) = route_mut(
+            ctx,
+            router,
+            &msg.packet.port_id_on_b,
+            Some(&msg.packet.chan_id_on_b),
+        )?;
+
+        recv_packet_execute(ctx, module, msg)
+    }
+}
+
+/// Entrypoints of the `MsgAcknowledgement` message.
+pub mod acknowledgement {
+    use ibc_core_channel::handler::{
+        acknowledgement_packet_execute, acknowledgement_packet_validate,
+    };
+    use ibc_core_channel::types::msgs::MsgAcknowledgement;
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+    use ibc_core_router::router::Router;
+
+    use crate::entrypoint::{route, route_mut};
+
+    pub fn validate<Ctx>(
+        ctx: &Ctx,
+        router: &impl Router,
+        msg: MsgAcknowledgement,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        let module = route(
+            ctx,
+            router,
+            &msg.packet.port_id_on_a,
+            Some(&msg.packet.chan_id_on_a),
+        )?;
+
+        acknowledgement_packet_validate(ctx, module, msg)
+    }
+
+    pub fn execute<Ctx>(
+        ctx: &mut Ctx,
+        router: &mut impl Router,
+        msg: MsgAcknowledgement,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        let (_, module) = route_mut(
+            ctx,
+            router,
+            &msg.packet.port_id_on_a,
+            Some(&msg.packet.chan_id_on_a),
+        )?;
+
+        acknowledgement_packet_execute(ctx, module, msg)
+    }
+}
+
+/// Entrypoints of the `MsgTimeout` message.
+pub mod timeout {
+    use ibc_core_channel::handler::{
+        timeout_packet_execute, timeout_packet_validate, TimeoutMsgType,
+    };
+    use ibc_core_channel::types::msgs::MsgTimeout;
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+    use ibc_core_router::router::Router;
+
+    use crate::entrypoint::{route, route_mut};
+
+    pub fn validate<Ctx>(
+        ctx: &Ctx,
+        router: &impl Router,
+        msg: MsgTimeout,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        let module = route(
+            ctx,
+            router,
+            &msg.packet.port_id_on_a,
+            Some(&msg.packet.chan_id_on_a),
+        )?;
+
+        timeout_packet_validate(ctx, module, TimeoutMsgType::Timeout(msg))
+    }
+
+    pub fn execute<Ctx>(
+        ctx: &mut Ctx,
+        router: &mut impl Router,
+        msg: MsgTimeout,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        let (_, module) = route_mut(
+            ctx,
+            router,
+            &msg.packet.port_id_on_a,
+            Some(&msg.packet.chan_id_on_a),
+        )?;
+
+        timeout_packet_execute(ctx, module, TimeoutMsgType::Timeout(msg))
+    }
+}
+
+/// Entrypoints of the `MsgTimeoutOnClose` message.
+pub mod timeout_on_close {
+    use ibc_core_channel::handler::{
+        timeout_packet_execute, timeout_packet_validate, TimeoutMsgType,
+    };
+    use ibc_core_channel::types::msgs::MsgTimeoutOnClose;
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+    use ibc_core_router::router::Router;
+
+    use crate::entrypoint::{route, route_mut};
+
+    pub fn validate<Ctx>(
+        ctx: &Ctx,
+        router: &impl Router,
+        msg: MsgTimeoutOnClose,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        let module = route(
+            ctx,
+            router,
+            &msg.packet.port_id_on_a,
+            Some(&msg.packet.chan_id_on_a),
+        )?;
+
+        timeout_packet_validate(ctx, module, TimeoutMsgType::TimeoutOnClose(msg))
+    }
+
+    pub fn execute<Ctx>(
+        ctx: &mut Ctx,
+        router: &mut impl Router,
+        msg: MsgTimeoutOnClose,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        let (_, module) = route_mut(
+            ctx,
+            router,
+            &msg.packet.port_id_on_a,
+            Some(&msg.packet.chan_id_on_a),
+        )?;
+
+        timeout_packet_execute(ctx, module, TimeoutMsgType::TimeoutOnClose(msg))
+    }
+}
+
+/// Entrypoints writing the acknowledgement of a packet an application
+/// acknowledges asynchronously.
+///
+/// The acknowledgement is wrapped by the middlewares of the module owning the
+/// channel, through
+/// [`Module::wrap_async_acknowledgement`](ibc_core_router::module::Module::wrap_async_acknowledgement),
+/// as synchronous acknowledgements are.
+pub mod write_acknowledgement {
+    use ibc_core_channel::handler::{
+        write_acknowledgement_execute, write_acknowledgement_validate,
+    };
+    use ibc_core_channel::types::acknowledgement::Acknowledgement;
+    use ibc_core_channel::types::packet::Packet;
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::{ExecutionContext, ValidationContext};
+    use ibc_core_router::router::Router;
+
+    use crate::entrypoint::route;
+
+    pub fn validate<Ctx>(
+        ctx: &Ctx,
+        router: &impl Router,
+        packet: &Packet,
+        app_ack: Acknowledgement,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        let module = route(
+            ctx,
+            router,
+            &packet.port_id_on_b,
+            Some(&packet.chan_id_on_b),
+        )?;
+
+        write_acknowledgement_validate(ctx, packet, &module.wrap_async_acknowledgement(app_ack))
+    }
+
+    pub fn execute<Ctx>(
+        ctx: &mut Ctx,
+        router: &impl Router,
+        packet: Packet,
+        app_ack: Acknowledgement,
+    ) -> Result<(), ContextError>
+    where
+        Ctx: ExecutionContext,
+    {
+        let module = route(
+            ctx,
+            router,
+            &packet.port_id_on_b,
+            Some(&packet.chan_id_on_b),
+        )?;
+        let acknowledgement = module.wrap_async_acknowledgement(app_ack);
+
+        write_acknowledgement_execute(ctx, packet, acknowledgement)
+    }
+}
@@ -0,0 +1,469 @@
+//! Defines the wrapper of the host context through which the envelope based
+//! entrypoints record the handling of a message.
+
+use core::cell::Cell;
+use core::time::Duration;
+
+use ibc_core_channel::types::channel::ChannelEnd;
+use ibc_core_channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel::types::limits::PacketSizeLimits;
+use ibc_core_channel::types::packet::Receipt;
+use ibc_core_channel::types::packet_data::DynPacketDataCodec;
+use ibc_core_channel::types::timeout::TimeoutHeight;
+use ibc_core_client::types::params::ClientParams;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection::types::version::Version as ConnectionVersion;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_handler_types::error::{ContextError, ResourceError};
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_handler_types::log::HandlerLog;
+use ibc_core_handler_types::mode::HostMode;
+use ibc_core_handler_types::simulation::{ResourceCharges, StateWrite};
+use ibc_core_host::types::identifiers::{
+    ClientId, ConnectionId, PortId, Sequence, ValidationProfile,
+};
+use ibc_core_host::types::path::{
+    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath,
+    CounterpartyPayeePath, NextChannelSequencePath, NextClientSequencePath,
+    NextConnectionSequencePath, PayeePath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, ResourceMeter, ValidationContext};
+use ibc_core_router::types::module::ModuleId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::{Signer, Timestamp};
+
+/// Whether the handling recorded by a [`RecordingContext`] is committed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Recording {
+    /// The events and logs are emitted on the host context as well as
+    /// recorded.
+    Execution,
+    /// The events and logs are recorded in place of being emitted on the host
+    /// context, and the writes are recorded as well.
+    Simulation,
+}
+
+/// Wraps the host context to record the events, logs, writes and resource
+/// charges of the handling of a message.
+pub(crate) struct RecordingContext<'a, Ctx> {
+    ctx: &'a mut Ctx,
+    recording: Recording,
+    pub(crate) events: Vec<IbcEvent>,
+    pub(crate) logs: Vec<HandlerLog>,
+    pub(crate) writes: Vec<StateWrite>,
+    pub(crate) charges: Cell<ResourceCharges>,
+}
+
+impl<'a, Ctx> RecordingContext<'a, Ctx> {
+    pub(crate) fn new(ctx: &'a mut Ctx, recording: Recording) -> Self {
+        Self {
+            ctx,
+            recording,
+            events: Vec::new(),
+            logs: Vec::new(),
+            writes: Vec::new(),
+            charges: Cell::new(ResourceCharges::default()),
+        }
+    }
+
+    fn record_charge(&self, charge: impl FnOnce(&mut ResourceCharges) -> &mut u64, len: usize) {
+        let mut charges = self.charges.get();
+        let charged = charge(&mut charges);
+        *charged = charged.saturating_add(len as u64);
+        self.charges.set(charges);
+    }
+
+    fn record_write(&mut self, write: StateWrite) {
+        if self.recording == Recording::Simulation {
+            self.writes.push(write);
+        }
+    }
+}
+
+impl<Ctx> ResourceMeter for RecordingContext<'_, Ctx>
+where
+    Ctx: ValidationContext,
+{
+    fn charge_proof_verification(&self, proof_len: usize) -> Result<(), ResourceError> {
+        self.record_charge(|charges| &mut charges.proof_verification, proof_len);
+        self.ctx
+            .resource_meter()
+            .charge_proof_verification(proof_len)
+    }
+
+    fn charge_signature_verification(&self, signatures: usize) -> Result<(), ResourceError> {
+        self.record_charge(|charges| &mut charges.signature_verification, signatures);
+        self.ctx
+            .resource_meter()
+            .charge_signature_verification(signatures)
+    }
+
+    fn charge_header_decoding(&self, message_len: usize) -> Result<(), ResourceError> {
+        self.record_charge(|charges| &mut charges.header_decoding, message_len);
+        self.ctx
+            .resource_meter()
+            .charge_header_decoding(message_len)
+    }
+
+    fn charge_packet_data(&self, data_len: usize) -> Result<(), ResourceError> {
+        self.record_charge(|charges| &mut charges.packet_data, data_len);
+        self.ctx.resource_meter().charge_packet_data(data_len)
+    }
+}
+
+impl<Ctx> ValidationContext for RecordingContext<'_, Ctx>
+where
+    Ctx: ValidationContext,
+{
+    const HOST_MODE: HostMode = Ctx::HOST_MODE;
+
+    type V = Ctx::V;
+    type HostClientState = Ctx::HostClientState;
+    type HostConsensusState = Ctx::HostConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self.ctx.get_client_validation_context()
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.ctx.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.ctx.host_timestamp()
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.ctx.host_consensus_state(height)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        self.ctx.client_counter()
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        self.ctx.connection_end(conn_id)
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.ctx.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        self.ctx.connection_counter()
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.ctx.get_compatible_versions()
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        self.ctx.pick_version(counterparty_candidate_versions)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        self.ctx.channel_end(channel_end_path)
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        self.ctx.get_next_sequence_send(seq_send_path)
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.ctx.get_next_sequence_recv(seq_recv_path)
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        self.ctx.get_next_sequence_ack(seq_ack_path)
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        self.ctx.get_packet_commitment(commitment_path)
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.ctx.get_packet_receipt(receipt_path)
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.ctx.get_packet_acknowledgement(ack_path)
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        self.ctx.channel_counter()
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.ctx.max_expected_time_per_block()
+    }
+
+    fn block_delay(&self, delay_period_time: &Duration) -> u64 {
+        self.ctx.block_delay(delay_period_time)
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.ctx.validate_message_signer(signer)
+    }
+
+    fn identifier_validation_profile(&self) -> ValidationProfile {
+        self.ctx.identifier_validation_profile()
+    }
+
+    fn resource_meter(&self) -> &dyn ResourceMeter {
+        self
+    }
+
+    fn host_mode(&self) -> HostMode {
+        self.ctx.host_mode()
+    }
+
+    fn packet_size_limits(&self) -> PacketSizeLimits {
+        self.ctx.packet_size_limits()
+    }
+
+    fn packet_data_codec(&self, port_id: &PortId) -> Option<&dyn DynPacketDataCodec> {
+        self.ctx.packet_data_codec(port_id)
+    }
+
+    fn client_params(&self) -> Result<ClientParams, ContextError> {
+        self.ctx.client_params()
+    }
+
+    fn validate_client_params_authority(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.ctx.validate_client_params_authority(signer)
+    }
+
+    fn channel_owner(
+        &self,
+        chan_end_path: &ChannelEndPath,
+    ) -> Result<Option<ModuleId>, ContextError> {
+        self.ctx.channel_owner(chan_end_path)
+    }
+
+    fn payee(&self, payee_path: &PayeePath) -> Result<Option<Signer>, ContextError> {
+        self.ctx.payee(payee_path)
+    }
+
+    fn counterparty_payee(
+        &self,
+        counterparty_payee_path: &CounterpartyPayeePath,
+    ) -> Result<Option<Signer>, ContextError> {
+        self.ctx.counterparty_payee(counterparty_payee_path)
+    }
+}
+
+impl<Ctx> ExecutionContext for RecordingContext<'_, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    type E = Ctx::E;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self.ctx.get_client_execution_context()
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(NextClientSequencePath.into()));
+        self.ctx.increase_client_counter()
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(connection_path.clone().into()));
+        self.ctx.store_connection(connection_path, connection_end)
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(client_connection_path.clone().into()));
+        self.ctx
+            .store_connection_to_client(client_connection_path, conn_id)
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(NextConnectionSequencePath.into()));
+        self.ctx.increase_connection_counter()
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(commitment_path.clone().into()));
+        self.ctx
+            .store_packet_commitment(commitment_path, commitment)
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Delete(commitment_path.clone().into()));
+        self.ctx.delete_packet_commitment(commitment_path)
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(receipt_path.clone().into()));
+        self.ctx.store_packet_receipt(receipt_path, receipt)
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(ack_path.clone().into()));
+        self.ctx
+            .store_packet_acknowledgement(ack_path, ack_commitment)
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Delete(ack_path.clone().into()));
+        self.ctx.delete_packet_acknowledgement(ack_path)
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(channel_end_path.clone().into()));
+        self.ctx.store_channel(channel_end_path, channel_end)
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(seq_send_path.clone().into()));
+        self.ctx.store_next_sequence_send(seq_send_path, seq)
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(seq_recv_path.clone().into()));
+        self.ctx.store_next_sequence_recv(seq_recv_path, seq)
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(seq_ack_path.clone().into()));
+        self.ctx.store_next_sequence_ack(seq_ack_path, seq)
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.record_write(StateWrite::Set(NextChannelSequencePath.into()));
+        self.ctx.increase_channel_counter()
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        match self.recording {
+            Recording::Execution => self.ctx.emit_ibc_event(event.clone())?,
+            Recording::Simulation => self.writes.extend(StateWrite::from_client_event(&event)),
+        }
+        self.events.push(event);
+        Ok(())
+    }
+
+    fn log(&mut self, log: HandlerLog) -> Result<(), ContextError> {
+        if self.recording == Recording::Execution {
+            self.ctx.log(log.clone())?;
+        }
+        self.logs.push(log);
+        Ok(())
+    }
+
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.ctx.store_client_params(params)
+    }
+
+    fn store_channel_owner(
+        &mut self,
+        chan_end_path: &ChannelEndPath,
+        module_id: ModuleId,
+    ) -> Result<(), ContextError> {
+        self.ctx.store_channel_owner(chan_end_path, module_id)
+    }
+
+    fn index_packet_timeout(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        timeout_height: &TimeoutHeight,
+        timeout_timestamp: &Timestamp,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .index_packet_timeout(commitment_path, timeout_height, timeout_timestamp)
+    }
+
+    fn unindex_packet_timeout(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.ctx.unindex_packet_timeout(commitment_path)
+    }
+
+    fn index_consensus_state_processed_time(
+        &mut self,
+        client_id: &ClientId,
+        height: &Height,
+        processed_time: &Timestamp,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .index_consensus_state_processed_time(client_id, height, processed_time)
+    }
+
+    fn index_client_update(
+        &mut self,
+        client_id: &ClientId,
+        consensus_height: &Height,
+        host_height: &Height,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .index_client_update(client_id, consensus_height, host_height)
+    }
+}
@@ -0,0 +1,87 @@
+//! Entrypoint simulating the handling of a message.
+
+use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::msgs::MsgEnvelope;
+use ibc_core_handler_types::simulation::{ResourceCharges, SimulationReport};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::entrypoint::recorder::{Recording, RecordingContext};
+use crate::entrypoint::{execute_msg, validate};
+
+/// Context to be implemented by the hosts simulating messages with
+/// [`simulate`], which branches off their state.
+///
+/// A branch buffers all the writes made through it, be they of the core
+/// handlers, of the light clients or of the applications, and is discarded
+/// once the simulation is over, e.g. a cache-wrapped store over the committed
+/// state of the host.
+pub trait SimulationContext {
+    /// The host context over a branch of the state of the host.
+    type Branch: ExecutionContext;
+    /// The router of the applications of the host, over the same branch.
+    type Router: Router;
+
+    /// Branches off the current state of the host, including the state of
+    /// its applications.
+    ///
+    /// The writes made through the returned context and router must never be
+    /// applied to the state of the host.
+    fn branch(&self) -> Result<(Self::Branch, Self::Router), ContextError>;
+}
+
+/// Entrypoint which validates and executes the message as
+/// [`dispatch`](crate::entrypoint::dispatch) does, over a branch of the state
+/// of the host, and reports the events, logs, writes and resource charges it
+/// produced, e.g. to serve transaction simulations over RPC.
+///
+/// The host is only borrowed immutably: all the writes, including those of
+/// the light clients and of the applications, land in the branch returned by
+/// [`SimulationContext::branch`], which is dropped before returning.
+///
+/// The events and logs are captured in the report instead of being emitted.
+/// The writes of the core handlers are captured as they are made, and those
+/// of the light clients from the events the handlers emit. The charges are
+/// forwarded to the meter of the branch, so that simulations run out of gas as
+/// the message would.
+pub fn simulate<Sim>(host: &Sim, msg: MsgEnvelope) -> SimulationReport
+where
+    Sim: SimulationContext,
+    <<<Sim::Branch as ValidationContext>::V as ClientValidationContext>::ClientStateRef as TryFrom<
+        Any,
+    >>::Error: Into<ClientError>,
+    <<<Sim::Branch as ExecutionContext>::E as ClientExecutionContext>::ClientStateMut as TryFrom<
+        Any,
+    >>::Error: Into<ClientError>,
+    <<Sim::Branch as ValidationContext>::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    let (mut branch, mut router) = match host.branch() {
+        Ok(branch) => branch,
+        Err(e) => {
+            return SimulationReport {
+                result: Err(e),
+                events: Vec::new(),
+                logs: Vec::new(),
+                writes: Vec::new(),
+                charges: ResourceCharges::default(),
+            }
+        }
+    };
+
+    let mut sim_ctx = RecordingContext::new(&mut branch, Recording::Simulation);
+
+    let result = validate(&sim_ctx, &router, msg.clone())
+        .and_then(|()| execute_msg(&mut sim_ctx, &mut router, msg).map(|_| ()));
+
+    SimulationReport {
+        result,
+        events: sim_ctx.events,
+        logs: sim_ctx.logs,
+        writes: sim_ctx.writes,
+        charges: sim_ctx.charges.get(),
+    }
+}
@@ -7,6 +7,9 @@ use ibc_core_client_types::error::ClientError;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_router_types::error::RouterError;
 use ibc_primitives::prelude::*;
+use ibc_primitives::ErrorCode;
+
+use crate::mode::HostMode;
 
 /// Top-level error
 #[derive(Debug, Display, From)]
@@ -21,6 +24,90 @@ pub enum ContextError {
     PacketError(PacketError),
     /// ICS26 Routing error: {0}
     RouterError(RouterError),
+    /// Resource metering error: {0}
+    ResourceError(ResourceError),
+    /// Host mode error: {0}
+    HostModeError(HostModeError),
+    /// Vote extension error: {0}
+    VoteExtensionError(VoteExtensionError),
+}
+
+/// Errors raised by the host's resource meter, which abort the handling of
+/// the message.
+#[derive(Debug, Display)]
+pub enum ResourceError {
+    /// out of gas: consumed `{consumed}` out of a limit of `{limit}`
+    OutOfGas { consumed: u64, limit: u64 },
+}
+
+impl ResourceError {
+    /// Returns the stable error code of the error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("resource", code);
+
+        match self {
+            Self::OutOfGas { .. } => code(1),
+        }
+    }
+}
+
+/// Errors raised when the host is asked to perform an IBC operation which its
+/// [`HostMode`] does not allow.
+#[derive(Debug, Display)]
+pub enum HostModeError {
+    /// message `{type_url}` is not allowed in `{mode}` host mode
+    MessageNotAllowed { type_url: String, mode: HostMode },
+    /// sending packets is not allowed in `{mode}` host mode
+    SendPacketNotAllowed { mode: HostMode },
+}
+
+impl HostModeError {
+    /// Returns the stable error code of the error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("host_mode", code);
+
+        match self {
+            Self::MessageNotAllowed { .. } => code(1),
+            Self::SendPacketNotAllowed { .. } => code(2),
+        }
+    }
+}
+
+/// Errors raised while verifying the IBC data carried in a vote extension.
+#[derive(Debug, Display)]
+pub enum VoteExtensionError {
+    /// unsupported attestation of kind `{kind}`
+    UnsupportedAttestation { kind: String },
+    /// invalid attestation of kind `{kind}`: `{description}`
+    InvalidAttestation { kind: String, description: String },
+}
+
+impl VoteExtensionError {
+    /// Returns the stable error code of the error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("vote_extension", code);
+
+        match self {
+            Self::UnsupportedAttestation { .. } => code(1),
+            Self::InvalidAttestation { .. } => code(2),
+        }
+    }
+}
+
+impl ContextError {
+    /// Returns the stable error code of the wrapped error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::ClientError(e) => e.code(),
+            Self::ConnectionError(e) => e.code(),
+            Self::ChannelError(e) => e.code(),
+            Self::PacketError(e) => e.code(),
+            Self::RouterError(e) => e.code(),
+            Self::ResourceError(e) => e.code(),
+            Self::HostModeError(e) => e.code(),
+            Self::VoteExtensionError(e) => e.code(),
+        }
+    }
 }
 
 impl From<ContextError> for ClientError {
@@ -43,6 +130,58 @@ impl std::error::Error for ContextError {
             Self::ChannelError(e) => Some(e),
             Self::PacketError(e) => Some(e),
             Self::RouterError(e) => Some(e),
+            Self::ResourceError(e) => Some(e),
+            Self::HostModeError(e) => Some(e),
+            Self::VoteExtensionError(e) => Some(e),
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for ResourceError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HostModeError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VoteExtensionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrapped_errors_report_inner_code() {
+        let client_error = ClientError::ClientFrozen {
+            description: "frozen".to_string(),
+        };
+        let error = ContextError::from(ConnectionError::Client(client_error));
+
+        assert_eq!(error.code(), ErrorCode::new("client", 1));
+
+        let error = ContextError::from(PacketError::Channel(ChannelError::MissingChannel));
+
+        assert_eq!(error.code(), ErrorCode::new("channel", 12));
+        assert_eq!(error.code().to_abci(), ("channel", 12));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_wrapped_errors_are_sources() {
+        use std::error::Error;
+
+        use ibc_core_client_types::error::UpgradeClientError;
+
+        let upgrade_error = UpgradeClientError::Other {
+            reason: "invalid plan".to_string(),
+        };
+        let error = ContextError::from(ClientError::Upgrade(upgrade_error));
+
+        let client_error = error.source().expect("client error is the source");
+        assert!(client_error.is::<ClientError>());
+
+        let upgrade_error = client_error.source().expect("upgrade error is the source");
+        assert!(upgrade_error.is::<UpgradeClientError>());
+        assert!(upgrade_error.source().is_none());
+    }
+}
@@ -68,6 +68,8 @@ pub enum IbcEvent {
     UpdateClient(ClientEvents::UpdateClient),
     UpgradeClient(ClientEvents::UpgradeClient),
     ClientMisbehaviour(ClientEvents::ClientMisbehaviour),
+    RecoverClient(ClientEvents::RecoverClient),
+    ClientExpired(ClientEvents::ClientExpired),
 
     OpenInitConnection(ConnectionEvents::OpenInit),
     OpenTryConnection(ConnectionEvents::OpenTry),
@@ -101,6 +103,8 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::UpdateClient(event) => event.into(),
             IbcEvent::UpgradeClient(event) => event.into(),
             IbcEvent::ClientMisbehaviour(event) => event.into(),
+            IbcEvent::RecoverClient(event) => event.into(),
+            IbcEvent::ClientExpired(event) => event.into(),
             IbcEvent::OpenInitConnection(event) => event.into(),
             IbcEvent::OpenTryConnection(event) => event.into(),
             IbcEvent::OpenAckConnection(event) => event.into(),
@@ -133,6 +137,8 @@ impl IbcEvent {
             IbcEvent::UpdateClient(event) => event.event_type(),
             IbcEvent::ClientMisbehaviour(event) => event.event_type(),
             IbcEvent::UpgradeClient(event) => event.event_type(),
+            IbcEvent::RecoverClient(event) => event.event_type(),
+            IbcEvent::ClientExpired(event) => event.event_type(),
             IbcEvent::OpenInitConnection(event) => event.event_type(),
             IbcEvent::OpenTryConnection(event) => event.event_type(),
             IbcEvent::OpenAckConnection(event) => event.event_type(),
@@ -155,6 +161,149 @@ impl IbcEvent {
     }
 }
 
+impl IbcEvent {
+    /// Converts the event into an ABCI event, whose attributes are indexed
+    /// according to the given policy.
+    pub fn try_into_abci_event(self, policy: &EventIndexPolicy) -> Result<abci::Event, Error> {
+        // the index flags explicitly set on module event attributes take
+        // precedence over the policy
+        let explicit_flags: Vec<Option<bool>> = match &self {
+            IbcEvent::Module(event) => event.attributes.iter().map(|attr| attr.index).collect(),
+            _ => vec![],
+        };
+
+        let mut event = abci::Event::try_from(self)?;
+
+        for (i, attr) in event.attributes.iter_mut().enumerate() {
+            match explicit_flags.get(i).copied().flatten() {
+                Some(index) => attr.set_index(index),
+                None => {
+                    let policy_index = attr
+                        .key_str()
+                        .ok()
+                        .and_then(|key| policy.index(&event.kind, key));
+
+                    if let Some(index) = policy_index {
+                        attr.set_index(index);
+                    }
+                }
+            }
+        }
+
+        Ok(event)
+    }
+
+    /// Converts the event into its JSON representation, whose attributes are
+    /// indexed according to the given policy.
+    #[cfg(feature = "serde")]
+    pub fn try_into_json_event(self, policy: &EventIndexPolicy) -> Result<JsonEvent, Error> {
+        self.try_into_abci_event(policy).map(JsonEvent::from)
+    }
+}
+
+/// The JSON representation of an IBC event, as found in the transaction
+/// results of ibc-go chains and consumed by relayers (e.g. hermes) and chain
+/// indexers.
+///
+/// Serializes to `{"type": ..., "attributes": [{"key": ..., "value": ...,
+/// "index": ...}]}`, whatever the implementation emitting the event.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct JsonEvent {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub attributes: Vec<JsonEventAttribute>,
+}
+
+/// An attribute of a [`JsonEvent`].
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct JsonEventAttribute {
+    pub key: String,
+    pub value: String,
+    pub index: bool,
+}
+
+#[cfg(feature = "serde")]
+impl From<abci::Event> for JsonEvent {
+    fn from(event: abci::Event) -> Self {
+        Self {
+            kind: event.kind,
+            attributes: event
+                .attributes
+                .iter()
+                .map(|attr| JsonEventAttribute {
+                    key: String::from_utf8_lossy(attr.key_bytes()).into_owned(),
+                    value: String::from_utf8_lossy(attr.value_bytes()).into_owned(),
+                    index: attr.index(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Controls which attributes of the IBC events are indexed by the host (e.g.
+/// by Tendermint), when converted with [`IbcEvent::try_into_abci_event`].
+///
+/// The flag of an attribute is resolved from, in order of precedence: the
+/// flag set on the attribute when constructing a [`ModuleEvent`], the
+/// override for the event type and attribute key, the override for the
+/// attribute key, and the policy default. If none of them applies, the
+/// attribute keeps the flag set by the event conversion.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventIndexPolicy {
+    default: Option<bool>,
+    key_overrides: BTreeMap<String, bool>,
+    event_key_overrides: BTreeMap<(String, String), bool>,
+}
+
+impl EventIndexPolicy {
+    /// Returns a policy indexing every attribute, unless overridden.
+    pub fn index_all() -> Self {
+        Self {
+            default: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Returns a policy indexing no attribute, unless overridden.
+    pub fn index_none() -> Self {
+        Self {
+            default: Some(false),
+            ..Default::default()
+        }
+    }
+
+    /// Overrides the flag of the attributes with the given key, in all events.
+    pub fn with_key(mut self, key: impl ToString, index: bool) -> Self {
+        self.key_overrides.insert(key.to_string(), index);
+        self
+    }
+
+    /// Overrides the flag of the attributes with the given key, in the events
+    /// of the given type.
+    pub fn with_event_key(
+        mut self,
+        event_type: impl ToString,
+        key: impl ToString,
+        index: bool,
+    ) -> Self {
+        self.event_key_overrides
+            .insert((event_type.to_string(), key.to_string()), index);
+        self
+    }
+
+    /// Returns whether the attribute with the given key, in an event of the
+    /// given type, must be indexed, if the policy has an opinion on it.
+    pub fn index(&self, event_type: &str, key: &str) -> Option<bool> {
+        self.event_key_overrides
+            .get(&(event_type.to_string(), key.to_string()))
+            .or_else(|| self.key_overrides.get(key))
+            .copied()
+            .or(self.default)
+    }
+}
+
 /// An event type that is emitted by the Cosmos SDK.
 ///
 /// We need to emit it as well, as currently [hermes] relies on it.
@@ -206,3 +355,52 @@ impl From<ModuleEvent> for IbcEvent {
         IbcEvent::Module(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_index_policy() {
+        let event = IbcEvent::Module(ModuleEvent {
+            kind: "transfer".to_string(),
+            attributes: vec![
+                ("sender", "alice").into(),
+                ("receiver", "bob").into(),
+                ("amount", "100", true).into(),
+                ("denom", "uatom").into(),
+            ],
+        });
+
+        let policy = EventIndexPolicy::index_none()
+            .with_key("sender", true)
+            .with_event_key("transfer", "receiver", true)
+            .with_event_key("recv_packet", "denom", true)
+            .with_key("amount", false);
+
+        let abci_event = event
+            .clone()
+            .try_into_abci_event(&policy)
+            .expect("valid event");
+
+        let flags: Vec<bool> = abci_event
+            .attributes
+            .iter()
+            .map(|attr| attr.index())
+            .collect();
+
+        assert_eq!(flags, vec![true, true, true, false]);
+
+        let abci_event = event
+            .try_into_abci_event(&EventIndexPolicy::default())
+            .expect("valid event");
+
+        let flags: Vec<bool> = abci_event
+            .attributes
+            .iter()
+            .map(|attr| attr.index())
+            .collect();
+
+        assert_eq!(flags, vec![false, false, true, false]);
+    }
+}
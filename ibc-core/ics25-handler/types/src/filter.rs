@@ -0,0 +1,244 @@
+//! Defines a filter over IBC events, for integrators subscribing to the events
+//! emitted by a host (e.g. over a WebSocket stream).
+
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, PortId, Sequence};
+use ibc_primitives::prelude::*;
+use tendermint::abci;
+
+use crate::events::IbcEvent;
+
+const CLIENT_ID_ATTRIBUTE_KEYS: [&str; 3] =
+    ["client_id", "counterparty_client_id", "subject_client_id"];
+
+const PORT_ID_ATTRIBUTE_KEYS: [&str; 4] = [
+    "port_id",
+    "counterparty_port_id",
+    "packet_src_port",
+    "packet_dst_port",
+];
+
+const CHANNEL_ID_ATTRIBUTE_KEYS: [&str; 4] = [
+    "channel_id",
+    "counterparty_channel_id",
+    "packet_src_channel",
+    "packet_dst_channel",
+];
+
+const SEQUENCE_ATTRIBUTE_KEY: &str = "packet_sequence";
+
+/// Selects IBC events by type, client, port, channel and packet sequence.
+///
+/// An event matches the filter if it matches all of its criteria. Unset
+/// criteria match any event. Identifiers match both the local and the
+/// counterparty attributes of an event, e.g. a channel filter matches packet
+/// events on both ends of the channel.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    event_types: Vec<String>,
+    client_id: Option<ClientId>,
+    port_id: Option<PortId>,
+    channel_id: Option<ChannelId>,
+    min_sequence: Option<Sequence>,
+    max_sequence: Option<Sequence>,
+}
+
+impl EventFilter {
+    /// Returns a filter matching every event.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the filter to events of the given type. Calling this method
+    /// multiple times matches events of any of the given types.
+    pub fn with_event_type(mut self, event_type: impl ToString) -> Self {
+        self.event_types.push(event_type.to_string());
+        self
+    }
+
+    /// Restricts the filter to events involving the given client.
+    pub fn with_client_id(mut self, client_id: ClientId) -> Self {
+        self.client_id = Some(client_id);
+        self
+    }
+
+    /// Restricts the filter to events involving the given port.
+    pub fn with_port_id(mut self, port_id: PortId) -> Self {
+        self.port_id = Some(port_id);
+        self
+    }
+
+    /// Restricts the filter to events involving the given channel.
+    pub fn with_channel_id(mut self, channel_id: ChannelId) -> Self {
+        self.channel_id = Some(channel_id);
+        self
+    }
+
+    /// Restricts the filter to packet events whose sequence lies within the
+    /// given inclusive bounds.
+    pub fn with_sequence_range(
+        mut self,
+        min_sequence: Option<Sequence>,
+        max_sequence: Option<Sequence>,
+    ) -> Self {
+        self.min_sequence = min_sequence;
+        self.max_sequence = max_sequence;
+        self
+    }
+
+    /// Returns true if the given typed event matches the filter.
+    pub fn matches(&self, event: &IbcEvent) -> bool {
+        if !self.matches_event_type(event.event_type()) {
+            return false;
+        }
+
+        if !self.has_attribute_criteria() {
+            return true;
+        }
+
+        abci::Event::try_from(event.clone())
+            .map(|abci_event| self.matches_attributes(&abci_event))
+            .unwrap_or(false)
+    }
+
+    /// Returns true if the given raw ABCI event matches the filter.
+    pub fn matches_abci(&self, event: &abci::Event) -> bool {
+        self.matches_event_type(&event.kind) && self.matches_attributes(event)
+    }
+
+    /// Returns the events matching the filter.
+    pub fn filter<'a>(
+        &'a self,
+        events: impl IntoIterator<Item = &'a IbcEvent>,
+    ) -> impl Iterator<Item = &'a IbcEvent> {
+        events.into_iter().filter(|event| self.matches(event))
+    }
+
+    fn has_attribute_criteria(&self) -> bool {
+        self.client_id.is_some()
+            || self.port_id.is_some()
+            || self.channel_id.is_some()
+            || self.min_sequence.is_some()
+            || self.max_sequence.is_some()
+    }
+
+    fn matches_event_type(&self, event_type: &str) -> bool {
+        self.event_types.is_empty() || self.event_types.iter().any(|t| t == event_type)
+    }
+
+    fn matches_attributes(&self, event: &abci::Event) -> bool {
+        let has_attribute = |keys: &[&str], value: &str| {
+            event.attributes.iter().any(|attr| {
+                attr.key_str().is_ok_and(|key| keys.contains(&key))
+                    && attr.value_str().is_ok_and(|attr_value| attr_value == value)
+            })
+        };
+
+        if let Some(client_id) = &self.client_id {
+            if !has_attribute(&CLIENT_ID_ATTRIBUTE_KEYS, client_id.as_str()) {
+                return false;
+            }
+        }
+
+        if let Some(port_id) = &self.port_id {
+            if !has_attribute(&PORT_ID_ATTRIBUTE_KEYS, port_id.as_str()) {
+                return false;
+            }
+        }
+
+        if let Some(channel_id) = &self.channel_id {
+            if !has_attribute(&CHANNEL_ID_ATTRIBUTE_KEYS, channel_id.as_str()) {
+                return false;
+            }
+        }
+
+        if self.min_sequence.is_some() || self.max_sequence.is_some() {
+            let sequence = event
+                .attributes
+                .iter()
+                .find(|attr| {
+                    attr.key_str()
+                        .is_ok_and(|key| key == SEQUENCE_ATTRIBUTE_KEY)
+                })
+                .and_then(|attr| attr.value_str().ok()?.parse::<Sequence>().ok());
+
+            let Some(sequence) = sequence else {
+                return false;
+            };
+
+            if self.min_sequence.is_some_and(|min| sequence < min)
+                || self.max_sequence.is_some_and(|max| sequence > max)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_channel_types::channel::Order;
+    use ibc_core_channel_types::events::{OpenInit, SendPacket};
+    use ibc_core_channel_types::packet::Packet;
+    use ibc_core_channel_types::timeout::TimeoutHeight;
+    use ibc_core_host_types::identifiers::ConnectionId;
+    use ibc_primitives::Timestamp;
+
+    use super::*;
+
+    fn send_packet(seq: u64) -> IbcEvent {
+        let packet = Packet {
+            seq_on_a: seq.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: vec![1],
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+
+        IbcEvent::SendPacket(SendPacket::new(
+            packet,
+            Order::Unordered,
+            ConnectionId::zero(),
+        ))
+    }
+
+    #[test]
+    fn event_filter_matches() {
+        let open_init = IbcEvent::OpenInitChannel(OpenInit::new(
+            PortId::transfer(),
+            ChannelId::new(0),
+            PortId::transfer(),
+            ConnectionId::zero(),
+            "ics20-1".to_string().into(),
+        ));
+        let events = vec![open_init, send_packet(1), send_packet(5), send_packet(10)];
+
+        assert_eq!(EventFilter::new().filter(&events).count(), 4);
+
+        let filter = EventFilter::new().with_event_type("send_packet");
+        assert_eq!(filter.filter(&events).count(), 3);
+
+        let filter = EventFilter::new().with_channel_id(ChannelId::new(1));
+        assert_eq!(filter.filter(&events).count(), 3);
+
+        let filter = EventFilter::new().with_channel_id(ChannelId::new(0));
+        assert_eq!(filter.filter(&events).count(), 4);
+
+        let filter = EventFilter::new().with_sequence_range(Some(2.into()), Some(10.into()));
+        assert_eq!(filter.filter(&events).count(), 2);
+
+        let filter = EventFilter::new().with_client_id(ClientId::new("07-tendermint", 0).unwrap());
+        assert_eq!(filter.filter(&events).count(), 0);
+
+        let abci_event = abci::Event::try_from(send_packet(5)).expect("valid event");
+        assert!(EventFilter::new()
+            .with_event_type("send_packet")
+            .with_port_id(PortId::transfer())
+            .with_sequence_range(Some(5.into()), None)
+            .matches_abci(&abci_event));
+    }
+}
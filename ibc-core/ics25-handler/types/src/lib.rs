@@ -3,7 +3,7 @@
 //! underlying host blockchain.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
 #![deny(
     warnings,
@@ -13,9 +13,18 @@
     rust_2018_idioms
 )]
 
+extern crate alloc;
+
 #[cfg(feature = "std")]
 extern crate std;
 
 pub mod error;
 pub mod events;
+pub mod filter;
+pub mod log;
+pub mod mode;
 pub mod msgs;
+pub mod output;
+pub mod replay;
+pub mod simulation;
+pub mod tracker;
@@ -0,0 +1,127 @@
+//! Defines the structured logs recording the decisions of the IBC handlers.
+
+use displaydoc::Display;
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc_primitives::prelude::*;
+
+/// A log recorded by a handler upon successfully processing a message, so
+/// that hosts can persist and inspect handler decisions programmatically.
+///
+/// Free-form messages, e.g. from applications, are recorded as
+/// [`HandlerLog::Message`]. The `Display` implementation renders every log
+/// as a human-readable line.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Display)]
+pub enum HandlerLog {
+    /// success: generated new client identifier: {client_id}
+    ClientCreated { client_id: ClientId },
+    /// success: conn_open_init: generated new connection identifier: {conn_id_on_a}
+    ConnectionOpenInit { conn_id_on_a: ConnectionId },
+    /// success: conn_open_try verification passed for connection {conn_id_on_b}
+    ConnectionOpenTry { conn_id_on_b: ConnectionId },
+    /// success: conn_open_ack verification passed for connection {conn_id_on_a}
+    ConnectionOpenAck { conn_id_on_a: ConnectionId },
+    /// success: conn_open_confirm verification passed for connection {conn_id_on_b}
+    ConnectionOpenConfirm { conn_id_on_b: ConnectionId },
+    /// success: channel open init with channel identifier: {chan_id_on_a}
+    ChannelOpenInit {
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+    },
+    /// success: channel open try with channel identifier: {chan_id_on_b}
+    ChannelOpenTry {
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+    },
+    /// success: channel open ack on {port_id_on_a}/{chan_id_on_a}
+    ChannelOpenAck {
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+    },
+    /// success: channel open confirm on {port_id_on_b}/{chan_id_on_b}
+    ChannelOpenConfirm {
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+    },
+    /// success: channel close init on {port_id_on_a}/{chan_id_on_a}
+    ChannelCloseInit {
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+    },
+    /// success: channel close confirm on {port_id_on_b}/{chan_id_on_b}
+    ChannelCloseConfirm {
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+    },
+    /// success: packet send on {port_id_on_a}/{chan_id_on_a} with sequence {sequence}
+    PacketSent {
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        sequence: Sequence,
+    },
+    /// success: packet receive on {port_id_on_b}/{chan_id_on_b} with sequence {sequence}
+    PacketReceived {
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        sequence: Sequence,
+    },
+    /// success: packet write acknowledgement on {port_id_on_b}/{chan_id_on_b} with sequence {sequence}
+    AcknowledgementWritten {
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+        sequence: Sequence,
+    },
+    /// success: packet acknowledgement on {port_id_on_a}/{chan_id_on_a} with sequence {sequence}
+    PacketAcknowledged {
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        sequence: Sequence,
+    },
+    /// success: packet timeout on {port_id_on_a}/{chan_id_on_a} with sequence {sequence}
+    PacketTimedOut {
+        port_id_on_a: PortId,
+        chan_id_on_a: ChannelId,
+        sequence: Sequence,
+    },
+    /// {0}
+    Message(String),
+}
+
+impl HandlerLog {
+    /// Returns the free-form message of the log, if it is not structured.
+    pub fn as_message(&self) -> Option<&str> {
+        match self {
+            Self::Message(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for HandlerLog {
+    fn from(message: String) -> Self {
+        Self::Message(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handler_log_display() {
+        let log = HandlerLog::PacketReceived {
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            sequence: 7u64.into(),
+        };
+        assert_eq!(
+            log.to_string(),
+            "success: packet receive on transfer/channel-1 with sequence 7"
+        );
+        assert_eq!(log.as_message(), None);
+
+        let log = HandlerLog::from("app decision".to_string());
+        assert_eq!(log.to_string(), "app decision");
+        assert_eq!(log.as_message(), Some("app decision"));
+    }
+}
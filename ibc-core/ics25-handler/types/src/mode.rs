@@ -0,0 +1,96 @@
+//! Defines the restricted modes in which a host may operate IBC.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core_channel_types::msgs::PacketMsg;
+
+use crate::error::HostModeError;
+use crate::msgs::MsgEnvelope;
+
+/// The IBC operations a host accepts to perform, e.g. for appchains rolling
+/// out IBC in stages, or for one-way bridges.
+///
+/// The mode is enforced by the `MsgEnvelope` based entrypoints, and by the
+/// `send_packet` handler for the packets originated by the applications.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HostMode {
+    /// All the IBC operations are allowed.
+    #[default]
+    Full,
+    /// Packets are received from the counterparties, but not sent to them,
+    /// e.g. to accept inbound transfers only.
+    ReceiveOnly,
+    /// Packets are sent to the counterparties, along with their
+    /// acknowledgements and timeouts, but not received from them.
+    SendOnly,
+    /// Only the light clients are operated, e.g. by hosts verifying the state
+    /// of other chains without exchanging packets with them.
+    ClientUpdatesOnly,
+}
+
+impl HostMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::ReceiveOnly => "receive-only",
+            Self::SendOnly => "send-only",
+            Self::ClientUpdatesOnly => "client-updates-only",
+        }
+    }
+
+    /// Checks whether the host processes the given message in this mode.
+    pub fn allows(&self, msg: &MsgEnvelope) -> bool {
+        match msg {
+            MsgEnvelope::Client(_) => true,
+            MsgEnvelope::Connection(_) | MsgEnvelope::Channel(_) => {
+                !matches!(self, Self::ClientUpdatesOnly)
+            }
+            MsgEnvelope::Packet(PacketMsg::Recv(_)) => {
+                matches!(self, Self::Full | Self::ReceiveOnly)
+            }
+            MsgEnvelope::Packet(PacketMsg::Ack(_))
+            | MsgEnvelope::Packet(PacketMsg::Timeout(_))
+            | MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(_)) => self.allows_send_packet(),
+        }
+    }
+
+    /// Checks whether the applications of the host may send packets in this
+    /// mode.
+    pub fn allows_send_packet(&self) -> bool {
+        matches!(self, Self::Full | Self::SendOnly)
+    }
+
+    /// Fails if the host does not process the given message in this mode.
+    pub fn verify_allows(&self, msg: &MsgEnvelope) -> Result<(), HostModeError> {
+        if self.allows(msg) {
+            Ok(())
+        } else {
+            Err(HostModeError::MessageNotAllowed {
+                type_url: msg.type_url().to_string(),
+                mode: *self,
+            })
+        }
+    }
+
+    /// Fails if the applications of the host may not send packets in this
+    /// mode.
+    pub fn verify_allows_send_packet(&self) -> Result<(), HostModeError> {
+        if self.allows_send_packet() {
+            Ok(())
+        } else {
+            Err(HostModeError::SendPacketNotAllowed { mode: *self })
+        }
+    }
+}
+
+impl Display for HostMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
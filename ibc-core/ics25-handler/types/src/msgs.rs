@@ -1,16 +1,19 @@
+use core::fmt::Display;
+
 use ibc_core_channel_types::msgs::{
     ChannelMsg, MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
-    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
-    MsgTimeoutOnClose, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL,
-    CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL,
-    CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL, RECV_PACKET_TYPE_URL,
-    TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket,
+    MsgRegisterCounterpartyPayee, MsgRegisterPayee, MsgTimeout, MsgTimeoutOnClose, PacketMsg,
+    ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL, CHAN_CLOSE_INIT_TYPE_URL,
+    CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL, CHAN_OPEN_INIT_TYPE_URL,
+    CHAN_OPEN_TRY_TYPE_URL, RECV_PACKET_TYPE_URL, REGISTER_COUNTERPARTY_PAYEE_TYPE_URL,
+    REGISTER_PAYEE_TYPE_URL, TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
 };
 #[allow(deprecated)]
 use ibc_core_client_types::msgs::{
-    ClientMsg, MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient, MsgUpgradeClient,
-    CREATE_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_TYPE_URL,
-    UPGRADE_CLIENT_TYPE_URL,
+    ClientMsg, MsgCreateClient, MsgRecoverClient, MsgSubmitMisbehaviour, MsgUpdateClient,
+    MsgUpgradeClient, CREATE_CLIENT_TYPE_URL, RECOVER_CLIENT_TYPE_URL,
+    SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_TYPE_URL, UPGRADE_CLIENT_TYPE_URL,
 };
 use ibc_core_connection_types::msgs::{
     ConnectionMsg, MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
@@ -20,7 +23,6 @@ use ibc_core_connection_types::msgs::{
 use ibc_core_router_types::error::RouterError;
 use ibc_primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
-use ibc_proto::Protobuf;
 
 /// Enumeration of all messages that the local ICS26 module is capable of routing.
 #[cfg_attr(
@@ -36,6 +38,70 @@ pub enum MsgEnvelope {
     Packet(PacketMsg),
 }
 
+impl MsgEnvelope {
+    /// Type URLs of all the messages that can be routed through a
+    /// [`MsgEnvelope`].
+    pub const TYPE_URLS: &'static [&'static str] = &[
+        CREATE_CLIENT_TYPE_URL,
+        UPDATE_CLIENT_TYPE_URL,
+        UPGRADE_CLIENT_TYPE_URL,
+        SUBMIT_MISBEHAVIOUR_TYPE_URL,
+        CONN_OPEN_INIT_TYPE_URL,
+        CONN_OPEN_TRY_TYPE_URL,
+        CONN_OPEN_ACK_TYPE_URL,
+        CONN_OPEN_CONFIRM_TYPE_URL,
+        CHAN_OPEN_INIT_TYPE_URL,
+        CHAN_OPEN_TRY_TYPE_URL,
+        CHAN_OPEN_ACK_TYPE_URL,
+        CHAN_OPEN_CONFIRM_TYPE_URL,
+        CHAN_CLOSE_INIT_TYPE_URL,
+        CHAN_CLOSE_CONFIRM_TYPE_URL,
+        RECV_PACKET_TYPE_URL,
+        ACKNOWLEDGEMENT_TYPE_URL,
+        TIMEOUT_TYPE_URL,
+        TIMEOUT_ON_CLOSE_TYPE_URL,
+    ];
+
+    /// Checks whether a message with the given type URL can be routed through
+    /// a [`MsgEnvelope`].
+    pub fn is_supported_type_url(type_url: &str) -> bool {
+        Self::TYPE_URLS.contains(&type_url)
+    }
+
+    /// Returns the type URL of the wrapped message.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            Self::Client(msg) => match msg {
+                ClientMsg::CreateClient(_) => CREATE_CLIENT_TYPE_URL,
+                ClientMsg::UpdateClient(_) => UPDATE_CLIENT_TYPE_URL,
+                ClientMsg::Misbehaviour(_) => SUBMIT_MISBEHAVIOUR_TYPE_URL,
+                ClientMsg::UpgradeClient(_) => UPGRADE_CLIENT_TYPE_URL,
+                ClientMsg::RecoverClient(_) => RECOVER_CLIENT_TYPE_URL,
+            },
+            Self::Connection(msg) => match msg {
+                ConnectionMsg::OpenInit(_) => CONN_OPEN_INIT_TYPE_URL,
+                ConnectionMsg::OpenTry(_) => CONN_OPEN_TRY_TYPE_URL,
+                ConnectionMsg::OpenAck(_) => CONN_OPEN_ACK_TYPE_URL,
+                ConnectionMsg::OpenConfirm(_) => CONN_OPEN_CONFIRM_TYPE_URL,
+            },
+            Self::Channel(msg) => match msg {
+                ChannelMsg::OpenInit(_) => CHAN_OPEN_INIT_TYPE_URL,
+                ChannelMsg::OpenTry(_) => CHAN_OPEN_TRY_TYPE_URL,
+                ChannelMsg::OpenAck(_) => CHAN_OPEN_ACK_TYPE_URL,
+                ChannelMsg::OpenConfirm(_) => CHAN_OPEN_CONFIRM_TYPE_URL,
+                ChannelMsg::CloseInit(_) => CHAN_CLOSE_INIT_TYPE_URL,
+                ChannelMsg::CloseConfirm(_) => CHAN_CLOSE_CONFIRM_TYPE_URL,
+            },
+            Self::Packet(msg) => match msg {
+                PacketMsg::Recv(_) => RECV_PACKET_TYPE_URL,
+                PacketMsg::Ack(_) => ACKNOWLEDGEMENT_TYPE_URL,
+                PacketMsg::Timeout(_) => TIMEOUT_TYPE_URL,
+                PacketMsg::TimeoutOnClose(_) => TIMEOUT_ON_CLOSE_TYPE_URL,
+            },
+        }
+    }
+}
+
 #[allow(deprecated)]
 impl TryFrom<Any> for MsgEnvelope {
     type Error = RouterError;
@@ -43,166 +109,223 @@ impl TryFrom<Any> for MsgEnvelope {
     fn try_from(any_msg: Any) -> Result<Self, Self::Error> {
         match any_msg.type_url.as_str() {
             // ICS2 messages
-            CREATE_CLIENT_TYPE_URL => {
-                // Pop out the message and then wrap it in the corresponding type.
-                let domain_msg = MsgCreateClient::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Client(ClientMsg::CreateClient(domain_msg)))
-            }
-            UPDATE_CLIENT_TYPE_URL => {
-                let domain_msg = MsgUpdateClient::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Client(ClientMsg::UpdateClient(domain_msg)))
-            }
-            UPGRADE_CLIENT_TYPE_URL => {
-                let domain_msg = MsgUpgradeClient::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Client(ClientMsg::UpgradeClient(domain_msg)))
-            }
-            SUBMIT_MISBEHAVIOUR_TYPE_URL => {
-                let domain_msg =
-                    MsgSubmitMisbehaviour::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
-                    })?;
-                Ok(MsgEnvelope::Client(ClientMsg::Misbehaviour(domain_msg)))
-            }
-
+            CREATE_CLIENT_TYPE_URL => MsgCreateClient::try_from_any(any_msg)
+                .map(|msg| ClientMsg::CreateClient(msg).into()),
+            UPDATE_CLIENT_TYPE_URL => MsgUpdateClient::try_from_any(any_msg)
+                .map(|msg| ClientMsg::UpdateClient(msg).into()),
+            UPGRADE_CLIENT_TYPE_URL => MsgUpgradeClient::try_from_any(any_msg)
+                .map(|msg| ClientMsg::UpgradeClient(msg).into()),
+            SUBMIT_MISBEHAVIOUR_TYPE_URL => MsgSubmitMisbehaviour::try_from_any(any_msg)
+                .map(|msg| ClientMsg::Misbehaviour(msg).into()),
             // ICS03
-            CONN_OPEN_INIT_TYPE_URL => {
-                let domain_msg =
-                    MsgConnectionOpenInit::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
-                    })?;
-                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenInit(domain_msg)))
-            }
-            CONN_OPEN_TRY_TYPE_URL => {
-                let domain_msg = MsgConnectionOpenTry::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenTry(domain_msg)))
-            }
-            CONN_OPEN_ACK_TYPE_URL => {
-                let domain_msg = MsgConnectionOpenAck::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenAck(domain_msg)))
-            }
-            CONN_OPEN_CONFIRM_TYPE_URL => {
-                let domain_msg =
-                    MsgConnectionOpenConfirm::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
-                    })?;
-                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenConfirm(
-                    domain_msg,
-                )))
-            }
-
+            CONN_OPEN_INIT_TYPE_URL => MsgConnectionOpenInit::try_from_any(any_msg)
+                .map(|msg| ConnectionMsg::OpenInit(msg).into()),
+            CONN_OPEN_TRY_TYPE_URL => MsgConnectionOpenTry::try_from_any(any_msg)
+                .map(|msg| ConnectionMsg::OpenTry(msg).into()),
+            CONN_OPEN_ACK_TYPE_URL => MsgConnectionOpenAck::try_from_any(any_msg)
+                .map(|msg| ConnectionMsg::OpenAck(msg).into()),
+            CONN_OPEN_CONFIRM_TYPE_URL => MsgConnectionOpenConfirm::try_from_any(any_msg)
+                .map(|msg| ConnectionMsg::OpenConfirm(msg).into()),
             // ICS04 channel messages
-            CHAN_OPEN_INIT_TYPE_URL => {
-                let domain_msg = MsgChannelOpenInit::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::OpenInit(domain_msg)))
-            }
+            CHAN_OPEN_INIT_TYPE_URL => MsgChannelOpenInit::try_from_any(any_msg)
+                .map(|msg| ChannelMsg::OpenInit(msg).into()),
             CHAN_OPEN_TRY_TYPE_URL => {
-                let domain_msg = MsgChannelOpenTry::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::OpenTry(domain_msg)))
+                MsgChannelOpenTry::try_from_any(any_msg).map(|msg| ChannelMsg::OpenTry(msg).into())
             }
             CHAN_OPEN_ACK_TYPE_URL => {
-                let domain_msg = MsgChannelOpenAck::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::OpenAck(domain_msg)))
-            }
-            CHAN_OPEN_CONFIRM_TYPE_URL => {
-                let domain_msg =
-                    MsgChannelOpenConfirm::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
-                    })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::OpenConfirm(domain_msg)))
-            }
-            CHAN_CLOSE_INIT_TYPE_URL => {
-                let domain_msg = MsgChannelCloseInit::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::CloseInit(domain_msg)))
-            }
-            CHAN_CLOSE_CONFIRM_TYPE_URL => {
-                let domain_msg =
-                    MsgChannelCloseConfirm::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
-                    })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::CloseConfirm(domain_msg)))
+                MsgChannelOpenAck::try_from_any(any_msg).map(|msg| ChannelMsg::OpenAck(msg).into())
             }
+            CHAN_OPEN_CONFIRM_TYPE_URL => MsgChannelOpenConfirm::try_from_any(any_msg)
+                .map(|msg| ChannelMsg::OpenConfirm(msg).into()),
+            CHAN_CLOSE_INIT_TYPE_URL => MsgChannelCloseInit::try_from_any(any_msg)
+                .map(|msg| ChannelMsg::CloseInit(msg).into()),
+            CHAN_CLOSE_CONFIRM_TYPE_URL => MsgChannelCloseConfirm::try_from_any(any_msg)
+                .map(|msg| ChannelMsg::CloseConfirm(msg).into()),
             // ICS04 packet messages
             RECV_PACKET_TYPE_URL => {
-                let domain_msg = MsgRecvPacket::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Packet(PacketMsg::Recv(domain_msg)))
+                MsgRecvPacket::try_from_any(any_msg).map(|msg| PacketMsg::Recv(msg).into())
             }
             ACKNOWLEDGEMENT_TYPE_URL => {
-                let domain_msg = MsgAcknowledgement::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Packet(PacketMsg::Ack(domain_msg)))
+                MsgAcknowledgement::try_from_any(any_msg).map(|msg| PacketMsg::Ack(msg).into())
             }
             TIMEOUT_TYPE_URL => {
-                let domain_msg = MsgTimeout::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Packet(PacketMsg::Timeout(domain_msg)))
-            }
-            TIMEOUT_ON_CLOSE_TYPE_URL => {
-                let domain_msg = MsgTimeoutOnClose::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(domain_msg)))
+                MsgTimeout::try_from_any(any_msg).map(|msg| PacketMsg::Timeout(msg).into())
             }
+            TIMEOUT_ON_CLOSE_TYPE_URL => MsgTimeoutOnClose::try_from_any(any_msg)
+                .map(|msg| PacketMsg::TimeoutOnClose(msg).into()),
             _ => Err(RouterError::UnknownMessageTypeUrl {
                 url: any_msg.type_url,
             }),
         }
     }
 }
+
+/// Common interface of the IBC messages, exposing their Protobuf type URL and
+/// their conversions from and into [`Any`].
+///
+/// This allows building transactions and routing messages generically over
+/// any IBC message, including application messages such as ICS-20 transfers.
+pub trait Msg: Sized {
+    /// The raw Protobuf message of this message.
+    type Raw: prost::Message + Default + From<Self>;
+
+    /// The Protobuf type URL of this message.
+    const TYPE_URL: &'static str;
+
+    /// Encodes the message into an [`Any`].
+    fn to_any(self) -> Any {
+        Any {
+            type_url: Self::TYPE_URL.to_string(),
+            value: prost::Message::encode_to_vec(&Self::Raw::from(self)),
+        }
+    }
+
+    /// Decodes and validates the message from an [`Any`], checking that its
+    /// type URL matches [`Self::TYPE_URL`].
+    fn try_from_any(any: Any) -> Result<Self, RouterError>
+    where
+        Self: TryFrom<Self::Raw>,
+        <Self as TryFrom<Self::Raw>>::Error: Display,
+    {
+        if any.type_url != Self::TYPE_URL {
+            return Err(RouterError::UnknownMessageTypeUrl { url: any.type_url });
+        }
+
+        let raw = <Self::Raw as prost::Message>::decode(any.value.as_slice()).map_err(|e| {
+            RouterError::MalformedMessageBytes {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Self::try_from(raw).map_err(|e| RouterError::MalformedMessageBytes {
+            reason: e.to_string(),
+        })
+    }
+}
+
+impl Msg for MsgCreateClient {
+    type Raw = ibc_proto::ibc::core::client::v1::MsgCreateClient;
+
+    const TYPE_URL: &'static str = CREATE_CLIENT_TYPE_URL;
+}
+
+impl Msg for MsgUpdateClient {
+    type Raw = ibc_proto::ibc::core::client::v1::MsgUpdateClient;
+
+    const TYPE_URL: &'static str = UPDATE_CLIENT_TYPE_URL;
+}
+
+impl Msg for MsgUpgradeClient {
+    type Raw = ibc_proto::ibc::core::client::v1::MsgUpgradeClient;
+
+    const TYPE_URL: &'static str = UPGRADE_CLIENT_TYPE_URL;
+}
+
+#[allow(deprecated)]
+impl Msg for MsgSubmitMisbehaviour {
+    type Raw = ibc_proto::ibc::core::client::v1::MsgSubmitMisbehaviour;
+
+    const TYPE_URL: &'static str = SUBMIT_MISBEHAVIOUR_TYPE_URL;
+}
+
+impl Msg for MsgRecoverClient {
+    type Raw = ibc_proto::ibc::core::client::v1::MsgRecoverClient;
+
+    const TYPE_URL: &'static str = RECOVER_CLIENT_TYPE_URL;
+}
+
+impl Msg for MsgConnectionOpenInit {
+    type Raw = ibc_proto::ibc::core::connection::v1::MsgConnectionOpenInit;
+
+    const TYPE_URL: &'static str = CONN_OPEN_INIT_TYPE_URL;
+}
+
+impl Msg for MsgConnectionOpenTry {
+    type Raw = ibc_proto::ibc::core::connection::v1::MsgConnectionOpenTry;
+
+    const TYPE_URL: &'static str = CONN_OPEN_TRY_TYPE_URL;
+}
+
+impl Msg for MsgConnectionOpenAck {
+    type Raw = ibc_proto::ibc::core::connection::v1::MsgConnectionOpenAck;
+
+    const TYPE_URL: &'static str = CONN_OPEN_ACK_TYPE_URL;
+}
+
+impl Msg for MsgConnectionOpenConfirm {
+    type Raw = ibc_proto::ibc::core::connection::v1::MsgConnectionOpenConfirm;
+
+    const TYPE_URL: &'static str = CONN_OPEN_CONFIRM_TYPE_URL;
+}
+
+impl Msg for MsgChannelOpenInit {
+    type Raw = ibc_proto::ibc::core::channel::v1::MsgChannelOpenInit;
+
+    const TYPE_URL: &'static str = CHAN_OPEN_INIT_TYPE_URL;
+}
+
+impl Msg for MsgChannelOpenTry {
+    type Raw = ibc_proto::ibc::core::channel::v1::MsgChannelOpenTry;
+
+    const TYPE_URL: &'static str = CHAN_OPEN_TRY_TYPE_URL;
+}
+
+impl Msg for MsgChannelOpenAck {
+    type Raw = ibc_proto::ibc::core::channel::v1::MsgChannelOpenAck;
+
+    const TYPE_URL: &'static str = CHAN_OPEN_ACK_TYPE_URL;
+}
+
+impl Msg for MsgChannelOpenConfirm {
+    type Raw = ibc_proto::ibc::core::channel::v1::MsgChannelOpenConfirm;
+
+    const TYPE_URL: &'static str = CHAN_OPEN_CONFIRM_TYPE_URL;
+}
+
+impl Msg for MsgChannelCloseInit {
+    type Raw = ibc_proto::ibc::core::channel::v1::MsgChannelCloseInit;
+
+    const TYPE_URL: &'static str = CHAN_CLOSE_INIT_TYPE_URL;
+}
+
+impl Msg for MsgChannelCloseConfirm {
+    type Raw = ibc_proto::ibc::core::channel::v1::MsgChannelCloseConfirm;
+
+    const TYPE_URL: &'static str = CHAN_CLOSE_CONFIRM_TYPE_URL;
+}
+
+impl Msg for MsgRecvPacket {
+    type Raw = ibc_proto::ibc::core::channel::v1::MsgRecvPacket;
+
+    const TYPE_URL: &'static str = RECV_PACKET_TYPE_URL;
+}
+
+impl Msg for MsgAcknowledgement {
+    type Raw = ibc_proto::ibc::core::channel::v1::MsgAcknowledgement;
+
+    const TYPE_URL: &'static str = ACKNOWLEDGEMENT_TYPE_URL;
+}
+
+impl Msg for MsgTimeout {
+    type Raw = ibc_proto::ibc::core::channel::v1::MsgTimeout;
+
+    const TYPE_URL: &'static str = TIMEOUT_TYPE_URL;
+}
+
+impl Msg for MsgTimeoutOnClose {
+    type Raw = ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose;
+
+    const TYPE_URL: &'static str = TIMEOUT_ON_CLOSE_TYPE_URL;
+}
+
+impl Msg for MsgRegisterPayee {
+    type Raw = ibc_proto::ibc::applications::fee::v1::MsgRegisterPayee;
+
+    const TYPE_URL: &'static str = REGISTER_PAYEE_TYPE_URL;
+}
+
+impl Msg for MsgRegisterCounterpartyPayee {
+    type Raw = ibc_proto::ibc::applications::fee::v1::MsgRegisterCounterpartyPayee;
+
+    const TYPE_URL: &'static str = REGISTER_COUNTERPARTY_PAYEE_TYPE_URL;
+}
@@ -0,0 +1,112 @@
+//! Defines the output of the execution of an IBC message.
+
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::msgs::{MsgChannelOpenInitResponse, MsgChannelOpenTryResponse};
+use ibc_core_client_types::msgs::MsgCreateClientResponse;
+use ibc_core_connection_types::msgs::{
+    MsgConnectionOpenInitResponse, MsgConnectionOpenTryResponse,
+};
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId};
+use ibc_primitives::prelude::*;
+
+use crate::events::IbcEvent;
+use crate::log::HandlerLog;
+
+/// The data resulting from the execution of a message, which hosts return to
+/// the sender of the message, as in the responses of the ibc-go messages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HandlerResult {
+    /// The identifier of the client created by a `MsgCreateClient`.
+    ClientCreated(ClientId),
+    /// The identifier of the connection created by a `MsgConnectionOpenInit`
+    /// or a `MsgConnectionOpenTry`.
+    ConnectionCreated(ConnectionId),
+    /// The identifier of the channel created by a `MsgChannelOpenInit` or a
+    /// `MsgChannelOpenTry`.
+    ChannelCreated(ChannelId),
+    /// The acknowledgement written upon receiving a packet.
+    Acknowledgement(Acknowledgement),
+}
+
+impl HandlerResult {
+    /// Returns the bytes of the result, i.e. the identifier as a string or the
+    /// acknowledgement, e.g. for the data of a transaction response.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::ClientCreated(client_id) => client_id.as_bytes().to_vec(),
+            Self::ConnectionCreated(conn_id) => conn_id.as_bytes().to_vec(),
+            Self::ChannelCreated(chan_id) => chan_id.as_bytes().to_vec(),
+            Self::Acknowledgement(ack) => ack.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl From<MsgCreateClientResponse> for HandlerResult {
+    fn from(response: MsgCreateClientResponse) -> Self {
+        Self::ClientCreated(response.client_id)
+    }
+}
+
+impl From<MsgConnectionOpenInitResponse> for HandlerResult {
+    fn from(response: MsgConnectionOpenInitResponse) -> Self {
+        Self::ConnectionCreated(response.conn_id_on_a)
+    }
+}
+
+impl From<MsgConnectionOpenTryResponse> for HandlerResult {
+    fn from(response: MsgConnectionOpenTryResponse) -> Self {
+        Self::ConnectionCreated(response.conn_id_on_b)
+    }
+}
+
+impl From<MsgChannelOpenInitResponse> for HandlerResult {
+    fn from(response: MsgChannelOpenInitResponse) -> Self {
+        Self::ChannelCreated(response.chan_id_on_a)
+    }
+}
+
+impl From<MsgChannelOpenTryResponse> for HandlerResult {
+    fn from(response: MsgChannelOpenTryResponse) -> Self {
+        Self::ChannelCreated(response.chan_id_on_b)
+    }
+}
+
+/// The output of the execution of a message: the events emitted and the logs
+/// recorded by the handlers, and the resulting data, if any.
+///
+/// The events and logs are also emitted on the host context, as by the
+/// handlers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HandlerOutput {
+    pub events: Vec<IbcEvent>,
+    pub logs: Vec<HandlerLog>,
+    pub result: Option<HandlerResult>,
+}
+
+impl HandlerOutput {
+    /// Builds the output from the events, logs and result of the execution.
+    ///
+    /// Absent a result returned by the handler, the acknowledgement written
+    /// by the application receiving a packet, if any, is taken from the
+    /// events, as the application only reports it through them.
+    pub fn new(
+        events: Vec<IbcEvent>,
+        logs: Vec<HandlerLog>,
+        result: Option<HandlerResult>,
+    ) -> Self {
+        let result = result.or_else(|| {
+            events.iter().find_map(|event| match event {
+                IbcEvent::WriteAcknowledgement(e) => {
+                    Some(HandlerResult::Acknowledgement(e.acknowledgement().clone()))
+                }
+                _ => None,
+            })
+        });
+
+        Self {
+            events,
+            logs,
+            result,
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! Defines stable identities for IBC events and a deduplicator of event
+//! streams, for indexers consuming the events emitted by a host from feeds
+//! that may replay, drop or reorder them (e.g. WebSocket subscriptions).
+
+use alloc::collections::BTreeSet;
+
+use ibc_core_client_types::Height;
+use ibc_primitives::prelude::*;
+use tendermint::Hash;
+
+use crate::events::IbcEvent;
+
+/// Identifies an event by the block and the transaction that emitted it, and
+/// by its index among the events of the transaction.
+///
+/// Identities are stable across replays of the same block, so that an event
+/// received twice from a feed has the same identity both times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventId {
+    pub height: Height,
+    pub tx_hash: Hash,
+    pub event_index: u32,
+}
+
+impl EventId {
+    pub fn new(height: Height, tx_hash: Hash, event_index: u32) -> Self {
+        Self {
+            height,
+            tx_hash,
+            event_index,
+        }
+    }
+}
+
+/// An event along with its identity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifiedEvent {
+    pub id: EventId,
+    pub event: IbcEvent,
+}
+
+impl IdentifiedEvent {
+    pub fn new(id: EventId, event: IbcEvent) -> Self {
+        Self { id, event }
+    }
+
+    /// Identifies the events emitted by a transaction, in the order they were
+    /// emitted.
+    pub fn from_tx(
+        height: Height,
+        tx_hash: Hash,
+        events: impl IntoIterator<Item = IbcEvent>,
+    ) -> impl Iterator<Item = Self> {
+        (0u32..)
+            .zip(events)
+            .map(move |(index, event)| Self::new(EventId::new(height, tx_hash, index), event))
+    }
+}
+
+/// Deduplicates and reorders a stream of identified events.
+///
+/// Events are buffered as they are pushed, dropping those already seen, and
+/// released per block with [`release_up_to`](Self::release_up_to). Released
+/// events are ordered by height, then by transaction, in the order the
+/// transactions of a block were first seen, and finally by event index.
+///
+/// The identities of released events are remembered so that late replays are
+/// still dropped, until [`prune_below`](Self::prune_below) is called.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventDeduplicator {
+    seen: BTreeSet<EventId>,
+    pending: BTreeMap<Height, Vec<IdentifiedEvent>>,
+}
+
+impl EventDeduplicator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Buffers the given event, unless it was already seen. Returns true if
+    /// the event is new.
+    pub fn push(&mut self, event: IdentifiedEvent) -> bool {
+        if !self.seen.insert(event.id) {
+            return false;
+        }
+
+        self.pending.entry(event.id.height).or_default().push(event);
+
+        true
+    }
+
+    /// Buffers the given events, dropping those already seen. Returns the
+    /// number of new events.
+    pub fn extend(&mut self, events: impl IntoIterator<Item = IdentifiedEvent>) -> usize {
+        events
+            .into_iter()
+            .filter(|event| self.push(event.clone()))
+            .count()
+    }
+
+    /// Returns true if an event with the given identity was already seen.
+    pub fn contains(&self, id: &EventId) -> bool {
+        self.seen.contains(id)
+    }
+
+    /// Returns the number of buffered events, not yet released.
+    pub fn pending_len(&self) -> usize {
+        self.pending.values().map(Vec::len).sum()
+    }
+
+    /// Releases the buffered events up to the given height, inclusive, in
+    /// order.
+    pub fn release_up_to(&mut self, height: Height) -> Vec<IdentifiedEvent> {
+        let mut released = Vec::new();
+
+        while let Some(entry) = self.pending.first_entry() {
+            if *entry.key() > height {
+                break;
+            }
+            released.extend(Self::sort_block(entry.remove()));
+        }
+
+        released
+    }
+
+    /// Releases all the buffered events, in order.
+    pub fn release_all(&mut self) -> Vec<IdentifiedEvent> {
+        core::mem::take(&mut self.pending)
+            .into_values()
+            .flat_map(Self::sort_block)
+            .collect()
+    }
+
+    /// Forgets the identities of the events below the given height, bounding
+    /// the memory of the deduplicator. Replays of these events are no longer
+    /// detected as duplicates afterwards.
+    pub fn prune_below(&mut self, height: Height) {
+        self.seen.retain(|id| id.height >= height);
+    }
+
+    /// Orders the events of a block by transaction, in the order the
+    /// transactions were first seen, then by event index.
+    fn sort_block(mut events: Vec<IdentifiedEvent>) -> Vec<IdentifiedEvent> {
+        let mut tx_order: Vec<Hash> = Vec::new();
+        for event in &events {
+            if !tx_order.contains(&event.id.tx_hash) {
+                tx_order.push(event.id.tx_hash);
+            }
+        }
+
+        events.sort_by_key(|event| {
+            let tx_position = tx_order
+                .iter()
+                .position(|tx_hash| *tx_hash == event.id.tx_hash);
+            (tx_position, event.id.event_index)
+        });
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::MessageEvent;
+
+    fn height(revision_height: u64) -> Height {
+        Height::new(0, revision_height).expect("valid height")
+    }
+
+    fn tx_hash(byte: u8) -> Hash {
+        Hash::Sha256([byte; 32])
+    }
+
+    fn event(revision_height: u64, tx: u8, index: u32) -> IdentifiedEvent {
+        IdentifiedEvent::new(
+            EventId::new(height(revision_height), tx_hash(tx), index),
+            MessageEvent::Channel.into(),
+        )
+    }
+
+    fn ids(events: &[IdentifiedEvent]) -> Vec<EventId> {
+        events.iter().map(|event| event.id).collect()
+    }
+
+    #[test]
+    fn test_identify_tx_events() {
+        let events = IdentifiedEvent::from_tx(
+            height(1),
+            tx_hash(1),
+            [MessageEvent::Client.into(), MessageEvent::Channel.into()],
+        )
+        .collect::<Vec<_>>();
+
+        assert_eq!(ids(&events), ids(&[event(1, 1, 0), event(1, 1, 1)]));
+    }
+
+    #[test]
+    fn test_deduplicate_replayed_events() {
+        let mut dedup = EventDeduplicator::new();
+
+        assert!(dedup.push(event(1, 1, 0)));
+        assert!(!dedup.push(event(1, 1, 0)));
+        assert_eq!(dedup.extend([event(1, 1, 0), event(1, 1, 1)]), 1);
+        assert_eq!(dedup.pending_len(), 2);
+
+        assert_eq!(dedup.release_up_to(height(1)).len(), 2);
+        assert!(!dedup.push(event(1, 1, 1)));
+
+        dedup.prune_below(height(2));
+        assert!(!dedup.contains(&event(1, 1, 1).id));
+        assert!(dedup.push(event(1, 1, 1)));
+    }
+
+    #[test]
+    fn test_reorder_events() {
+        let mut dedup = EventDeduplicator::new();
+
+        dedup.extend([
+            event(2, 3, 0),
+            event(1, 2, 1),
+            event(1, 1, 0),
+            event(1, 2, 0),
+            event(1, 1, 1),
+        ]);
+
+        assert_eq!(
+            ids(&dedup.release_up_to(height(1))),
+            ids(&[
+                event(1, 2, 0),
+                event(1, 2, 1),
+                event(1, 1, 0),
+                event(1, 1, 1)
+            ])
+        );
+        assert_eq!(dedup.pending_len(), 1);
+        assert_eq!(ids(&dedup.release_all()), ids(&[event(2, 3, 0)]));
+    }
+}
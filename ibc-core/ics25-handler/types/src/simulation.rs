@@ -0,0 +1,88 @@
+//! Defines the report of the simulation of an IBC message, i.e. of its
+//! validation and execution without committing its effects.
+
+use ibc_core_host_types::path::{
+    ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath, ClientUpdateTimePath, Path,
+};
+use ibc_primitives::prelude::*;
+
+use crate::error::ContextError;
+use crate::events::IbcEvent;
+use crate::log::HandlerLog;
+
+/// A write to the IBC store performed while handling a message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StateWrite {
+    /// A value was stored at the path.
+    Set(Path),
+    /// The value at the path was deleted.
+    Delete(Path),
+}
+
+impl StateWrite {
+    /// Returns the writes made by the light client which emitted the given
+    /// event, i.e. of its client state, and of its consensus states and their
+    /// update metadata. Returns none for the events not emitted by clients.
+    pub fn from_client_event(event: &IbcEvent) -> Vec<Self> {
+        let (client_id, consensus_heights) = match event {
+            IbcEvent::CreateClient(e) => (e.client_id(), vec![*e.consensus_height()]),
+            IbcEvent::UpdateClient(e) => (e.client_id(), e.consensus_heights().to_vec()),
+            IbcEvent::UpgradeClient(e) => (e.client_id(), vec![*e.consensus_height()]),
+            IbcEvent::ClientMisbehaviour(e) => (e.client_id(), Vec::new()),
+            _ => return Vec::new(),
+        };
+
+        let mut writes = vec![Self::Set(ClientStatePath::new(client_id.clone()).into())];
+
+        for height in consensus_heights {
+            let (revision_number, revision_height) =
+                (height.revision_number(), height.revision_height());
+
+            writes.push(Self::Set(
+                ClientConsensusStatePath::new(client_id.clone(), revision_number, revision_height)
+                    .into(),
+            ));
+            writes.push(Self::Set(
+                ClientUpdateTimePath::new(client_id.clone(), revision_number, revision_height)
+                    .into(),
+            ));
+            writes.push(Self::Set(
+                ClientUpdateHeightPath::new(client_id.clone(), revision_number, revision_height)
+                    .into(),
+            ));
+        }
+
+        writes
+    }
+}
+
+/// The resources charged to the host's `ResourceMeter` while handling a
+/// message, per charge point. Signature verification is charged per
+/// signature, and the other charge points per byte.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResourceCharges {
+    pub proof_verification: u64,
+    pub signature_verification: u64,
+    pub header_decoding: u64,
+    pub packet_data: u64,
+}
+
+/// The outcome of the simulation of a message, along with the events, logs,
+/// writes and resource charges it would have produced if committed.
+///
+/// If the message failed, these are the ones produced up to the failure.
+#[derive(Debug)]
+pub struct SimulationReport {
+    pub result: Result<(), ContextError>,
+    pub events: Vec<IbcEvent>,
+    pub logs: Vec<HandlerLog>,
+    pub writes: Vec<StateWrite>,
+    pub charges: ResourceCharges,
+}
+
+impl SimulationReport {
+    /// Returns true if the message would have been handled successfully.
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
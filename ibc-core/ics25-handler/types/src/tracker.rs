@@ -0,0 +1,261 @@
+//! Defines a tracker of the lifecycle of IBC packets, built from the events
+//! emitted on both ends of their channels.
+
+use ibc_core_host_types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_primitives::prelude::*;
+
+use crate::events::IbcEvent;
+
+/// Identifies a packet by the port, channel and sequence of its source end.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PacketKey {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub seq_on_a: Sequence,
+}
+
+impl PacketKey {
+    pub fn new(port_id_on_a: PortId, chan_id_on_a: ChannelId, seq_on_a: Sequence) -> Self {
+        Self {
+            port_id_on_a,
+            chan_id_on_a,
+            seq_on_a,
+        }
+    }
+}
+
+/// The stage of the lifecycle a packet reached.
+///
+/// Stages are ordered by progress. `Acknowledged` and `TimedOut` are terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PacketStatus {
+    /// The packet was sent on the source chain.
+    Sent,
+    /// The packet was received on the destination chain.
+    Received,
+    /// The acknowledgement of the packet was written on the destination chain.
+    AcknowledgementWritten,
+    /// The acknowledgement was processed on the source chain.
+    Acknowledged,
+    /// The packet timed out on the source chain.
+    TimedOut,
+}
+
+impl PacketStatus {
+    /// Returns true if the packet reached the end of its lifecycle.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Acknowledged | Self::TimedOut)
+    }
+}
+
+/// Tracks the lifecycle of packets from the `send_packet`, `recv_packet`,
+/// `write_acknowledgement`, `acknowledge_packet` and `timeout_packet` events.
+///
+/// Events can be fed in any order, e.g. as they are collected from both
+/// chains of a channel. A packet only ever moves forward in its lifecycle, and
+/// stays in its terminal status once reached. Other events are ignored.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PacketTracker {
+    packets: BTreeMap<PacketKey, PacketStatus>,
+}
+
+impl PacketTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the tracker with the given event. Returns the status of the
+    /// packet the event relates to, if any.
+    pub fn track(&mut self, event: &IbcEvent) -> Option<PacketStatus> {
+        let (key, status) = match event {
+            IbcEvent::SendPacket(e) => (
+                PacketKey::new(
+                    e.port_id_on_a().clone(),
+                    e.chan_id_on_a().clone(),
+                    *e.seq_on_a(),
+                ),
+                PacketStatus::Sent,
+            ),
+            IbcEvent::ReceivePacket(e) => (
+                PacketKey::new(
+                    e.port_id_on_a().clone(),
+                    e.chan_id_on_a().clone(),
+                    *e.seq_on_b(),
+                ),
+                PacketStatus::Received,
+            ),
+            IbcEvent::WriteAcknowledgement(e) => (
+                PacketKey::new(
+                    e.port_id_on_a().clone(),
+                    e.chan_id_on_a().clone(),
+                    *e.seq_on_a(),
+                ),
+                PacketStatus::AcknowledgementWritten,
+            ),
+            IbcEvent::AcknowledgePacket(e) => (
+                PacketKey::new(
+                    e.port_id_on_a().clone(),
+                    e.chan_id_on_a().clone(),
+                    *e.seq_on_a(),
+                ),
+                PacketStatus::Acknowledged,
+            ),
+            IbcEvent::TimeoutPacket(e) => (
+                PacketKey::new(
+                    e.port_id_on_a().clone(),
+                    e.chan_id_on_a().clone(),
+                    *e.seq_on_a(),
+                ),
+                PacketStatus::TimedOut,
+            ),
+            _ => return None,
+        };
+
+        let current = self.packets.entry(key).or_insert(status);
+
+        if !current.is_terminal() && status > *current {
+            *current = status;
+        }
+
+        Some(*current)
+    }
+
+    /// Updates the tracker with all the given events.
+    pub fn track_all<'a>(&mut self, events: impl IntoIterator<Item = &'a IbcEvent>) {
+        for event in events {
+            self.track(event);
+        }
+    }
+
+    /// Returns the status of the given packet, if any of its events was tracked.
+    pub fn status(&self, key: &PacketKey) -> Option<PacketStatus> {
+        self.packets.get(key).copied()
+    }
+
+    /// Returns all the tracked packets with their status.
+    pub fn packets(&self) -> impl Iterator<Item = (&PacketKey, &PacketStatus)> {
+        self.packets.iter()
+    }
+
+    /// Returns the packets that did not reach the end of their lifecycle.
+    pub fn pending(&self) -> impl Iterator<Item = &PacketKey> {
+        self.with_status(|status| !status.is_terminal())
+    }
+
+    /// Returns the packets whose acknowledgement was processed on the source chain.
+    pub fn completed(&self) -> impl Iterator<Item = &PacketKey> {
+        self.with_status(|status| status == PacketStatus::Acknowledged)
+    }
+
+    /// Returns the packets that timed out.
+    pub fn timed_out(&self) -> impl Iterator<Item = &PacketKey> {
+        self.with_status(|status| status == PacketStatus::TimedOut)
+    }
+
+    /// Stops tracking the packets that reached the end of their lifecycle.
+    pub fn prune_terminal(&mut self) {
+        self.packets.retain(|_, status| !status.is_terminal());
+    }
+
+    fn with_status(
+        &self,
+        predicate: impl Fn(PacketStatus) -> bool,
+    ) -> impl Iterator<Item = &PacketKey> {
+        self.packets
+            .iter()
+            .filter(move |(_, status)| predicate(**status))
+            .map(|(key, _)| key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_channel_types::acknowledgement::Acknowledgement;
+    use ibc_core_channel_types::channel::Order;
+    use ibc_core_channel_types::events::{
+        AcknowledgePacket, ReceivePacket, SendPacket, TimeoutPacket, WriteAcknowledgement,
+    };
+    use ibc_core_channel_types::packet::Packet;
+    use ibc_core_channel_types::timeout::TimeoutHeight;
+    use ibc_core_host_types::identifiers::ConnectionId;
+    use ibc_primitives::Timestamp;
+
+    use super::*;
+
+    fn packet(seq: u64) -> Packet {
+        Packet {
+            seq_on_a: seq.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: vec![1],
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    fn key(seq: u64) -> PacketKey {
+        PacketKey::new(PortId::transfer(), ChannelId::new(0), seq.into())
+    }
+
+    #[test]
+    fn packet_tracker_lifecycle() {
+        let ack = Acknowledgement::try_from(vec![1]).expect("non-empty ack");
+        let events = vec![
+            IbcEvent::SendPacket(SendPacket::new(
+                packet(1),
+                Order::Unordered,
+                ConnectionId::zero(),
+            )),
+            IbcEvent::SendPacket(SendPacket::new(
+                packet(2),
+                Order::Unordered,
+                ConnectionId::zero(),
+            )),
+            IbcEvent::SendPacket(SendPacket::new(
+                packet(3),
+                Order::Unordered,
+                ConnectionId::zero(),
+            )),
+            // acknowledgement observed before the receive on the counterparty
+            IbcEvent::AcknowledgePacket(AcknowledgePacket::new(
+                packet(1),
+                Order::Unordered,
+                ConnectionId::zero(),
+            )),
+            IbcEvent::ReceivePacket(ReceivePacket::new(
+                packet(1),
+                Order::Unordered,
+                ConnectionId::zero(),
+            )),
+            IbcEvent::ReceivePacket(ReceivePacket::new(
+                packet(2),
+                Order::Unordered,
+                ConnectionId::zero(),
+            )),
+            IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+                packet(2),
+                ack,
+                ConnectionId::zero(),
+            )),
+            IbcEvent::TimeoutPacket(TimeoutPacket::new(packet(3), Order::Unordered)),
+        ];
+
+        let mut tracker = PacketTracker::new();
+        tracker.track_all(&events);
+
+        assert_eq!(tracker.status(&key(1)), Some(PacketStatus::Acknowledged));
+        assert_eq!(
+            tracker.status(&key(2)),
+            Some(PacketStatus::AcknowledgementWritten)
+        );
+        assert_eq!(tracker.status(&key(3)), Some(PacketStatus::TimedOut));
+        assert_eq!(tracker.pending().collect::<Vec<_>>(), vec![&key(2)]);
+        assert_eq!(tracker.completed().collect::<Vec<_>>(), vec![&key(1)]);
+        assert_eq!(tracker.timed_out().collect::<Vec<_>>(), vec![&key(3)]);
+
+        tracker.prune_terminal();
+        assert_eq!(tracker.packets().count(), 1);
+    }
+}
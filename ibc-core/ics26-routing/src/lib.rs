@@ -1,7 +1,7 @@
 //! This library contains necessary traits to implement an IBC router module when integrating with `ibc-rs`.
 #![no_std]
 #![forbid(unsafe_code)]
-#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
 #![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types,))]
 #![deny(
     warnings,
@@ -14,8 +14,13 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "serde")]
+pub mod memo;
+pub mod middleware;
 pub mod module;
+pub mod port;
 pub mod router;
+pub mod version;
 
 /// Re-exports router data structures from the `ibc-core-router-types` crate
 pub mod types {
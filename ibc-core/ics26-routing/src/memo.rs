@@ -0,0 +1,354 @@
+//! Defines a shared parser of the JSON memos carried by packets, e.g. by
+//! ICS-20 transfers, with typed envelopes for the known memo schemas.
+//!
+//! A memo is parsed once into a [`ParsedMemo`], which maps the top-level keys
+//! of the memo to their raw JSON values. Middlewares then extract the payload
+//! of the schema they handle with [`ParsedMemo::get`], instead of parsing the
+//! memo string independently. Custom schemas are supported by implementing
+//! [`MemoSchema`], and a [`MemoRegistry`] validates all the schemas a host
+//! supports at once.
+
+use core::fmt::{Formatter, Result as FmtResult};
+
+use displaydoc::Display;
+pub use ibc_core_channel_types::limits::DEFAULT_MAX_MEMO_LENGTH;
+use ibc_primitives::prelude::*;
+use serde::de::{DeserializeOwned, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Errors raised when parsing a memo.
+#[derive(Debug, Display)]
+pub enum MemoError {
+    /// memo of `{length}` bytes exceeds the maximum length of `{max_length}` bytes
+    TooLong { length: usize, max_length: usize },
+    /// invalid JSON memo: `{reason}`
+    InvalidJson { reason: String },
+    /// invalid `{key}` memo payload: `{reason}`
+    InvalidPayload { key: String, reason: String },
+    /// memo schema `{key}` is registered more than once
+    DuplicateSchema { key: String },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MemoError {}
+
+/// The limits enforced when parsing a memo.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoLimits {
+    pub max_length: usize,
+}
+
+impl Default for MemoLimits {
+    fn default() -> Self {
+        Self {
+            max_length: DEFAULT_MAX_MEMO_LENGTH,
+        }
+    }
+}
+
+/// A JSON value kept in its serialized form, e.g. the payload of a memo key
+/// or the message of a wasm hook.
+///
+/// Numbers must be integers: floating point numbers are rejected, since
+/// re-encoding them would not reproduce the original memo deterministically.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawJson(String);
+
+impl RawJson {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Deserializes the raw value into the given type.
+    pub fn decode<T>(&self) -> Result<T, serde_json::de::Error>
+    where
+        T: DeserializeOwned,
+    {
+        serde_json::from_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for RawJson {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(RawJsonVisitor).map(RawJson)
+    }
+}
+
+/// Rebuilds the serialized form of any JSON value without floating point
+/// numbers.
+struct RawJsonVisitor;
+
+impl RawJsonVisitor {
+    fn encode<T, E>(value: &T) -> Result<String, E>
+    where
+        T: Serialize + ?Sized,
+        E: serde::de::Error,
+    {
+        serde_json::to_string(value).map_err(E::custom)
+    }
+}
+
+impl<'de> Visitor<'de> for RawJsonVisitor {
+    type Value = String;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        formatter.write_str("any JSON value without floating point numbers")
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<String, E> {
+        Self::encode(v)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<String, E> {
+        Ok("null".to_string())
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<String, E> {
+        Ok("null".to_string())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<String, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut elements = Vec::new();
+        while let Some(RawJson(element)) = seq.next_element()? {
+            elements.push(element);
+        }
+
+        Ok(format!("[{}]", elements.join(",")))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<String, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::new();
+        while let Some((key, RawJson(value))) = map.next_entry::<String, RawJson>()? {
+            entries.push(format!("{}:{value}", Self::encode::<_, A::Error>(&key)?));
+        }
+
+        Ok(format!("{{{}}}", entries.join(",")))
+    }
+}
+
+/// A schema of memo payload, stored under a top-level key of the memo.
+///
+/// Implemented by the known schemas of this module, and by the middlewares
+/// defining their own memo schemas.
+pub trait MemoSchema {
+    /// The top-level key of the memo under which the payload is stored.
+    const KEY: &'static str;
+
+    /// The typed payload of the schema.
+    type Payload: DeserializeOwned;
+}
+
+/// A memo parsed into its top-level keys.
+///
+/// Memos that are not JSON objects, e.g. plain text memos, are valid and
+/// carry no payload.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParsedMemo {
+    fields: BTreeMap<String, RawJson>,
+}
+
+impl ParsedMemo {
+    /// Parses the memo, failing if it exceeds the given limits, or if it looks
+    /// like a JSON object but is not valid JSON.
+    pub fn parse(memo: &str, limits: &MemoLimits) -> Result<Self, MemoError> {
+        if memo.len() > limits.max_length {
+            return Err(MemoError::TooLong {
+                length: memo.len(),
+                max_length: limits.max_length,
+            });
+        }
+
+        if !memo.trim_start().starts_with('{') {
+            return Ok(Self::default());
+        }
+
+        let fields = serde_json::from_str(memo).map_err(|e| MemoError::InvalidJson {
+            reason: e.to_string(),
+        })?;
+
+        Ok(Self { fields })
+    }
+
+    /// Returns the raw value stored under the given top-level key, if any.
+    pub fn raw(&self, key: &str) -> Option<&RawJson> {
+        self.fields.get(key)
+    }
+
+    /// Checks whether the memo has a payload under the given top-level key.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.fields.contains_key(key)
+    }
+
+    /// Returns the keys of the memo.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.fields.keys().map(String::as_str)
+    }
+
+    /// Decodes the payload of the given schema, if the memo has one.
+    pub fn get<S: MemoSchema>(&self) -> Result<Option<S::Payload>, MemoError> {
+        self.raw(S::KEY)
+            .map(|raw| {
+                raw.decode().map_err(|e| MemoError::InvalidPayload {
+                    key: S::KEY.to_string(),
+                    reason: e.to_string(),
+                })
+            })
+            .transpose()
+    }
+}
+
+/// The memo schemas supported by a host, validated together so that invalid
+/// payloads are rejected before any middleware acts on the memo.
+#[derive(Clone, Debug, Default)]
+pub struct MemoRegistry {
+    schemas: Vec<(&'static str, fn(&ParsedMemo) -> Result<(), MemoError>)>,
+}
+
+impl MemoRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a registry of all the schemas known by this module.
+    pub fn with_known_schemas() -> Self {
+        let mut registry = Self::new();
+        registry.insert::<Forward>();
+        registry.insert::<SrcCallback>();
+        registry.insert::<DestCallback>();
+        registry.insert::<WasmHook>();
+        registry
+    }
+
+    /// Registers a schema, failing if another schema is registered under the
+    /// same key.
+    pub fn register<S: MemoSchema>(&mut self) -> Result<(), MemoError> {
+        if self.schemas.iter().any(|(key, _)| *key == S::KEY) {
+            return Err(MemoError::DuplicateSchema {
+                key: S::KEY.to_string(),
+            });
+        }
+
+        self.insert::<S>();
+
+        Ok(())
+    }
+
+    fn insert<S: MemoSchema>(&mut self) {
+        self.schemas
+            .push((S::KEY, |memo| memo.get::<S>().map(|_| ())));
+    }
+
+    /// Parses the memo and validates the payloads of all the registered
+    /// schemas it carries.
+    pub fn parse(&self, memo: &str, limits: &MemoLimits) -> Result<ParsedMemo, MemoError> {
+        let parsed = ParsedMemo::parse(memo, limits)?;
+
+        for (_, validate) in &self.schemas {
+            validate(&parsed)?;
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// The packet-forward-middleware schema, stored under the `forward` key.
+#[derive(Clone, Copy, Debug)]
+pub struct Forward;
+
+impl MemoSchema for Forward {
+    const KEY: &'static str = "forward";
+
+    type Payload = ForwardMetadata;
+}
+
+/// The payload of the packet-forward-middleware schema, describing the next
+/// hop of the transfer.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct ForwardMetadata {
+    pub receiver: String,
+    pub port: String,
+    pub channel: String,
+    #[serde(default)]
+    pub timeout: Option<RawJson>,
+    #[serde(default)]
+    pub retries: Option<u8>,
+    /// The memo of the forwarded transfer.
+    #[serde(default)]
+    pub next: Option<RawJson>,
+}
+
+/// The callbacks middleware schema for the callbacks on the source chain,
+/// stored under the `src_callback` key.
+#[derive(Clone, Copy, Debug)]
+pub struct SrcCallback;
+
+impl MemoSchema for SrcCallback {
+    const KEY: &'static str = "src_callback";
+
+    type Payload = CallbackMetadata;
+}
+
+/// The callbacks middleware schema for the callbacks on the destination
+/// chain, stored under the `dest_callback` key.
+#[derive(Clone, Copy, Debug)]
+pub struct DestCallback;
+
+impl MemoSchema for DestCallback {
+    const KEY: &'static str = "dest_callback";
+
+    type Payload = CallbackMetadata;
+}
+
+/// The payload of the callbacks middleware schemas.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct CallbackMetadata {
+    pub address: String,
+    #[serde(default)]
+    pub gas_limit: Option<String>,
+}
+
+/// The wasm hooks schema, stored under the `wasm` key.
+#[derive(Clone, Copy, Debug)]
+pub struct WasmHook;
+
+impl MemoSchema for WasmHook {
+    const KEY: &'static str = "wasm";
+
+    type Payload = WasmHookMetadata;
+}
+
+/// The payload of the wasm hooks schema, describing the contract executed on
+/// receipt of the packet.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct WasmHookMetadata {
+    pub contract: String,
+    pub msg: RawJson,
+}
@@ -0,0 +1,622 @@
+//! Defines the `Middleware` trait and the `MiddlewareStack` combinator, which
+//! composes middlewares (e.g. fees, callbacks, rate-limiting) around a base
+//! IBC application.
+
+use core::fmt::Debug;
+
+use ibc_core_channel_types::acknowledgement::{Acknowledgement, RecvPacketResult};
+use ibc_core_channel_types::channel::{Counterparty, Order};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::packet_data::DynPacketDataCodec;
+use ibc_core_channel_types::Version;
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core_router_types::module::ModuleExtras;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+use crate::module::Module;
+
+/// A layer of logic wrapping an inner [`Module`], referred to as `next`.
+///
+/// Every callback receives the next module in the stack and, by default,
+/// simply delegates to it. Version strings are unwrapped with
+/// [`Middleware::unwrap_version`] before being handed to `next`, and the
+/// version returned by `next` is composed back with
+/// [`Middleware::wrap_version`]. Likewise, acknowledgements written by `next`
+/// are wrapped with [`Middleware::wrap_acknowledgement`] (asynchronous ones
+/// through [`Module::wrap_async_acknowledgement`]), and acknowledgements
+/// received from the counterparty are unwrapped with
+/// [`Middleware::unwrap_acknowledgement`] before reaching `next`.
+///
+/// Middlewares only need to override the callbacks they intercept.
+pub trait Middleware: Debug {
+    /// Composes the version negotiated by the wrapped application into the
+    /// version of this middleware.
+    fn wrap_version(&self, app_version: Version) -> Version {
+        app_version
+    }
+
+    /// Extracts the version of the wrapped application from `version`.
+    fn unwrap_version(&self, version: &Version) -> Result<Version, ChannelError> {
+        Ok(version.clone())
+    }
+
+    /// Composes the acknowledgement written by the wrapped application into
+    /// the acknowledgement of this middleware.
+    fn wrap_acknowledgement(&self, app_ack: Acknowledgement) -> Acknowledgement {
+        app_ack
+    }
+
+    /// Extracts the acknowledgement of the wrapped application from `ack`.
+    fn unwrap_acknowledgement(
+        &self,
+        ack: &Acknowledgement,
+    ) -> Result<Acknowledgement, PacketError> {
+        Ok(ack.clone())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_validate(
+        &self,
+        next: &dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let app_version = if version.is_empty() {
+            version.clone()
+        } else {
+            self.unwrap_version(version)?
+        };
+
+        let app_version = next.on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &app_version,
+        )?;
+
+        Ok(self.wrap_version(app_version))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_execute(
+        &mut self,
+        next: &mut dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let app_version = if version.is_empty() {
+            version.clone()
+        } else {
+            self.unwrap_version(version)?
+        };
+
+        let (extras, app_version) = next.on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &app_version,
+        )?;
+
+        Ok((extras, self.wrap_version(app_version)))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_validate(
+        &self,
+        next: &dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        let app_version = next.on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &self.unwrap_version(counterparty_version)?,
+        )?;
+
+        Ok(self.wrap_version(app_version))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_execute(
+        &mut self,
+        next: &mut dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        let (extras, app_version) = next.on_chan_open_try_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            &self.unwrap_version(counterparty_version)?,
+        )?;
+
+        Ok((extras, self.wrap_version(app_version)))
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_open_ack_validate(
+            port_id,
+            channel_id,
+            &self.unwrap_version(counterparty_version)?,
+        )
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_open_ack_execute(
+            port_id,
+            channel_id,
+            &self.unwrap_version(counterparty_version)?,
+        )
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_open_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_open_confirm_execute(port_id, channel_id)
+    }
+
+    /// Defaults to the packet data codec of `next`.
+    fn packet_data_codec<'a>(&'a self, next: &'a dyn Module) -> Option<&'a dyn DynPacketDataCodec> {
+        next.packet_data_codec()
+    }
+
+    /// Defaults to the close policy of `next`.
+    fn chan_close_init_allowed(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        signer: &Signer,
+    ) -> bool {
+        next.chan_close_init_allowed(port_id, channel_id, signer)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_close_init_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_close_init_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_close_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_close_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, RecvPacketResult) {
+        let (extras, result) = next.on_recv_packet_execute(packet, relayer);
+
+        (
+            extras,
+            result.map_acknowledgement(|app_ack| self.wrap_acknowledgement(app_ack)),
+        )
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        next: &dyn Module,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        next.on_acknowledgement_packet_validate(
+            packet,
+            &self.unwrap_acknowledgement(acknowledgement)?,
+            relayer,
+        )
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        match self.unwrap_acknowledgement(acknowledgement) {
+            Ok(app_ack) => next.on_acknowledgement_packet_execute(packet, &app_ack, relayer),
+            Err(e) => (ModuleExtras::empty(), Err(e)),
+        }
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        next: &dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        next.on_timeout_packet_validate(packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        next.on_timeout_packet_execute(packet, relayer)
+    }
+}
+
+/// A [`Module`] made of a [`Middleware`] wrapping an inner module, which may
+/// itself be a `MiddlewareStack`.
+///
+/// Stacks are built from the innermost layer outwards. For example, the
+/// following places the fee middleware at the top of the stack, in front of
+/// the callbacks middleware, which wraps the transfer application:
+///
+/// ```ignore
+/// let stack = MiddlewareStack::new(callbacks, transfer).wrap(fee);
+/// ```
+///
+/// Handshake versions and acknowledgements are therefore unwrapped from the
+/// outermost to the innermost layer on the way in, and wrapped in the reverse
+/// order on the way out.
+#[derive(Debug)]
+pub struct MiddlewareStack<M, A> {
+    middleware: M,
+    app: A,
+}
+
+impl<M, A> MiddlewareStack<M, A>
+where
+    M: Middleware,
+    A: Module,
+{
+    /// Wraps `app` with the given middleware.
+    pub fn new(middleware: M, app: A) -> Self {
+        Self { middleware, app }
+    }
+
+    /// Places another middleware on top of this stack.
+    pub fn wrap<N: Middleware>(self, middleware: N) -> MiddlewareStack<N, Self> {
+        MiddlewareStack::new(middleware, self)
+    }
+
+    /// Returns a reference to the outermost middleware.
+    pub fn middleware(&self) -> &M {
+        &self.middleware
+    }
+
+    /// Returns a mutable reference to the outermost middleware.
+    pub fn middleware_mut(&mut self) -> &mut M {
+        &mut self.middleware
+    }
+
+    /// Returns a reference to the wrapped module.
+    pub fn app(&self) -> &A {
+        &self.app
+    }
+
+    /// Returns a mutable reference to the wrapped module.
+    pub fn app_mut(&mut self) -> &mut A {
+        &mut self.app
+    }
+
+    /// Splits the stack into its outermost middleware and the wrapped module.
+    pub fn into_parts(self) -> (M, A) {
+        (self.middleware, self.app)
+    }
+}
+
+impl<M, A> Module for MiddlewareStack<M, A>
+where
+    M: Middleware,
+    A: Module,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.middleware.on_chan_open_init_validate(
+            &self.app,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.middleware.on_chan_open_init_execute(
+            &mut self.app,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.middleware.on_chan_open_try_validate(
+            &self.app,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.middleware.on_chan_open_try_execute(
+            &mut self.app,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.middleware.on_chan_open_ack_validate(
+            &self.app,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware.on_chan_open_ack_execute(
+            &mut self.app,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.middleware
+            .on_chan_open_confirm_validate(&self.app, port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware
+            .on_chan_open_confirm_execute(&mut self.app, port_id, channel_id)
+    }
+
+    fn packet_data_codec(&self) -> Option<&dyn DynPacketDataCodec> {
+        self.middleware.packet_data_codec(&self.app)
+    }
+
+    fn wrap_async_acknowledgement(&self, app_ack: Acknowledgement) -> Acknowledgement {
+        self.middleware
+            .wrap_acknowledgement(self.app.wrap_async_acknowledgement(app_ack))
+    }
+
+    fn chan_close_init_allowed(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        signer: &Signer,
+    ) -> bool {
+        self.middleware
+            .chan_close_init_allowed(&self.app, port_id, channel_id, signer)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.middleware
+            .on_chan_close_init_validate(&self.app, port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware
+            .on_chan_close_init_execute(&mut self.app, port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.middleware
+            .on_chan_close_confirm_validate(&self.app, port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware
+            .on_chan_close_confirm_execute(&mut self.app, port_id, channel_id)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, RecvPacketResult) {
+        self.middleware
+            .on_recv_packet_execute(&mut self.app, packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.middleware.on_acknowledgement_packet_validate(
+            &self.app,
+            packet,
+            acknowledgement,
+            relayer,
+        )
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.middleware.on_acknowledgement_packet_execute(
+            &mut self.app,
+            packet,
+            acknowledgement,
+            relayer,
+        )
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.middleware
+            .on_timeout_packet_validate(&self.app, packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.middleware
+            .on_timeout_packet_execute(&mut self.app, packet, relayer)
+    }
+}
@@ -1,10 +1,11 @@
 /// The trait that defines an IBC application
 use core::fmt::Debug;
 
-use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::acknowledgement::{Acknowledgement, RecvPacketResult};
 use ibc_core_channel_types::channel::{Counterparty, Order};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::packet_data::DynPacketDataCodec;
 use ibc_core_channel_types::Version;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc_core_router_types::module::ModuleExtras;
@@ -86,6 +87,20 @@ pub trait Module: Debug {
         Ok(ModuleExtras::empty())
     }
 
+    /// Returns whether `signer` may close the channel through a
+    /// `MsgChannelCloseInit`, which is consulted by the channel close init
+    /// handler before any other callback. Applications whose channels must
+    /// never be closed by users (e.g. token transfers) should return `false`.
+    /// By default, channel closure is allowed.
+    fn chan_close_init_allowed(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _signer: &Signer,
+    ) -> bool {
+        true
+    }
+
     fn on_chan_close_init_validate(
         &self,
         _port_id: &PortId,
@@ -118,16 +133,39 @@ pub trait Module: Debug {
         Ok(ModuleExtras::empty())
     }
 
+    /// Returns the codec of the packet data of the application, through which
+    /// middlewares introspect its packets. Defaults to none, leaving the
+    /// packet data opaque.
+    fn packet_data_codec(&self) -> Option<&dyn DynPacketDataCodec> {
+        None
+    }
+
+    /// Composes an acknowledgement the application writes asynchronously
+    /// through the `write_acknowledgement` entrypoint, as the
+    /// acknowledgements returned by [`Module::on_recv_packet_execute`] are.
+    /// Defaults to the acknowledgement of the application, which only
+    /// [`MiddlewareStack`](crate::middleware::MiddlewareStack)s wrap.
+    fn wrap_async_acknowledgement(&self, app_ack: Acknowledgement) -> Acknowledgement {
+        app_ack
+    }
+
     // Note: no `on_recv_packet_validate()`
     // the `onRecvPacket` callback always succeeds
     // if any error occurs, than an "error acknowledgement"
     // must be returned
 
+    /// Processes a received packet. The returned [`RecvPacketResult`] carries
+    /// the acknowledgement to write, or signals that the module writes it
+    /// asynchronously through the core `write_acknowledgement` handler.
+    ///
+    /// An acknowledgement exceeding the size limit of the host is replaced
+    /// with an error acknowledgement, while the effects of the callback are
+    /// kept, so modules must keep their acknowledgements within the limit.
     fn on_recv_packet_execute(
         &mut self,
         packet: &Packet,
         relayer: &Signer,
-    ) -> (ModuleExtras, Acknowledgement);
+    ) -> (ModuleExtras, RecvPacketResult);
 
     fn on_acknowledgement_packet_validate(
         &self,
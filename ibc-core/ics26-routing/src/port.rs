@@ -0,0 +1,67 @@
+//! Defines the port binding logic of ICS-05, which assigns the ownership of a
+//! port to exactly one IBC application module.
+
+use ibc_core_host_types::identifiers::PortId;
+use ibc_core_router_types::error::RouterError;
+use ibc_core_router_types::module::ModuleId;
+
+/// Keeps track of which module owns which port.
+///
+/// A port must be bound to a module before any channel can be opened on it,
+/// and only the owning module may act on behalf of the port (e.g. complete
+/// channel handshakes or send packets).
+pub trait PortKeeper {
+    /// Returns the `ModuleId` of the module that owns the given port, if the
+    /// port is bound.
+    fn port_owner(&self, port_id: &PortId) -> Option<ModuleId>;
+
+    /// Records `module_id` as the owner of `port_id`.
+    ///
+    /// Hosts should not call this method directly, but rather go through
+    /// [`bind_port`], which ensures that a port is never bound twice.
+    fn store_port_owner(&mut self, port_id: PortId, module_id: ModuleId)
+        -> Result<(), RouterError>;
+}
+
+/// Binds the given port to the module identified by `module_id`.
+///
+/// Fails if the port is already bound, regardless of whether the owner is
+/// `module_id` itself.
+pub fn bind_port(
+    keeper: &mut impl PortKeeper,
+    port_id: PortId,
+    module_id: ModuleId,
+) -> Result<(), RouterError> {
+    if let Some(owner) = keeper.port_owner(&port_id) {
+        return Err(RouterError::PortAlreadyBound {
+            port_id,
+            module_id: owner,
+        });
+    }
+
+    keeper.store_port_owner(port_id, module_id)
+}
+
+/// Checks that the module identified by `module_id` is the owner of the given
+/// port.
+pub fn authenticate_port_owner(
+    keeper: &impl PortKeeper,
+    port_id: &PortId,
+    module_id: &ModuleId,
+) -> Result<(), RouterError> {
+    let owner = keeper
+        .port_owner(port_id)
+        .ok_or_else(|| RouterError::PortNotBound {
+            port_id: port_id.clone(),
+        })?;
+
+    if &owner != module_id {
+        return Err(RouterError::UnauthorizedPortOwner {
+            port_id: port_id.clone(),
+            module_id: module_id.clone(),
+            owner,
+        });
+    }
+
+    Ok(())
+}
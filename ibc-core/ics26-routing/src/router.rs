@@ -1,18 +1,173 @@
 //! Defines the `Router`, which binds modules to ports
 
 use ibc_core_host_types::identifiers::PortId;
+use ibc_core_router_types::error::RouterError;
 use ibc_core_router_types::module::ModuleId;
+use ibc_primitives::prelude::*;
 
 use crate::module::Module;
+use crate::port::{bind_port, PortKeeper};
 
 /// Router as defined in ICS-26, which binds modules to ports.
-pub trait Router {
+///
+/// The port ownership is recorded through the [`PortKeeper`] supertrait, and
+/// ports are expected to be bound with [`bind_port`](crate::port::bind_port).
+/// The channel ownership is recorded by the host context instead, along with
+/// the channel ends.
+pub trait Router: PortKeeper {
     /// Returns a reference to a `Module` registered against the specified `ModuleId`
     fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module>;
 
     /// Returns a mutable reference to a `Module` registered against the specified `ModuleId`
     fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module>;
 
-    /// Return the module_id associated with a given port_id
-    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId>;
+    /// Return the module_id associated with a given port_id, i.e. the owner of the port.
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.port_owner(port_id)
+    }
+}
+
+/// A [`Router`] whose routing table can be modified at runtime.
+pub trait DynamicRouter: Router {
+    /// Registers the given module against `module_id`.
+    ///
+    /// Fails if a module is already registered against `module_id`.
+    fn add_route(
+        &mut self,
+        module_id: ModuleId,
+        module: Box<dyn Module>,
+    ) -> Result<(), RouterError>;
+
+    /// Removes the module registered against `module_id`, releasing all the
+    /// ports it owns, and returns it.
+    fn remove_route(&mut self, module_id: &ModuleId) -> Result<Box<dyn Module>, RouterError>;
+
+    /// Transfers the ownership of an already bound port to another registered
+    /// module. The channels already opened on the port remain owned by the
+    /// module that opened them, as recorded by the host context.
+    fn rebind_port(&mut self, port_id: &PortId, module_id: ModuleId) -> Result<(), RouterError>;
+
+    /// Returns the identifiers of all the registered modules.
+    fn module_ids(&self) -> Vec<ModuleId>;
+
+    /// Returns all the bound ports, along with the module owning each of them.
+    fn bound_ports(&self) -> Vec<(PortId, ModuleId)>;
+}
+
+/// A ready-to-use [`Router`] implementation that keeps its routing table in
+/// memory.
+///
+/// Ports can only be bound to modules that are registered in the router, so
+/// that a port lookup never yields a dangling `ModuleId`.
+#[derive(Debug, Default)]
+pub struct InMemoryRouter {
+    modules: BTreeMap<ModuleId, Box<dyn Module>>,
+    port_to_module: BTreeMap<PortId, ModuleId>,
+}
+
+impl InMemoryRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the given module against `module_id` and binds it to
+    /// `port_id`.
+    pub fn add_module(
+        &mut self,
+        module_id: ModuleId,
+        port_id: PortId,
+        module: impl Module + 'static,
+    ) -> Result<(), RouterError> {
+        if let Some(owner) = self.port_owner(&port_id) {
+            return Err(RouterError::PortAlreadyBound {
+                port_id,
+                module_id: owner,
+            });
+        }
+
+        self.add_route(module_id.clone(), Box::new(module))?;
+
+        bind_port(self, port_id, module_id)
+    }
+}
+
+impl PortKeeper for InMemoryRouter {
+    fn port_owner(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.port_to_module.get(port_id).cloned()
+    }
+
+    fn store_port_owner(
+        &mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+    ) -> Result<(), RouterError> {
+        if !self.modules.contains_key(&module_id) {
+            return Err(RouterError::ModuleNotFound);
+        }
+
+        self.port_to_module.insert(port_id, module_id);
+
+        Ok(())
+    }
+}
+
+impl Router for InMemoryRouter {
+    fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+        self.modules.get(module_id).map(Box::as_ref)
+    }
+
+    fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+        match self.modules.get_mut(module_id) {
+            Some(module) => Some(module.as_mut()),
+            None => None,
+        }
+    }
+}
+
+impl DynamicRouter for InMemoryRouter {
+    fn add_route(
+        &mut self,
+        module_id: ModuleId,
+        module: Box<dyn Module>,
+    ) -> Result<(), RouterError> {
+        if self.modules.contains_key(&module_id) {
+            return Err(RouterError::DuplicateModule { module_id });
+        }
+
+        self.modules.insert(module_id, module);
+
+        Ok(())
+    }
+
+    fn remove_route(&mut self, module_id: &ModuleId) -> Result<Box<dyn Module>, RouterError> {
+        let module = self
+            .modules
+            .remove(module_id)
+            .ok_or(RouterError::ModuleNotFound)?;
+
+        self.port_to_module.retain(|_, owner| owner != module_id);
+
+        Ok(module)
+    }
+
+    fn rebind_port(&mut self, port_id: &PortId, module_id: ModuleId) -> Result<(), RouterError> {
+        if self.port_owner(port_id).is_none() {
+            return Err(RouterError::PortNotBound {
+                port_id: port_id.clone(),
+            });
+        }
+
+        self.store_port_owner(port_id.clone(), module_id)
+    }
+
+    fn module_ids(&self) -> Vec<ModuleId> {
+        self.modules.keys().cloned().collect()
+    }
+
+    fn bound_ports(&self) -> Vec<(PortId, ModuleId)> {
+        self.port_to_module
+            .iter()
+            .map(|(port_id, module_id)| (port_id.clone(), module_id.clone()))
+            .collect()
+    }
 }
@@ -0,0 +1,85 @@
+//! Defines the `AppVersion` trait, which factors out the negotiation of the
+//! application version carried by the channel version string, along with
+//! helpers to encode the JSON version metadata of middlewares.
+
+#[cfg(feature = "serde")]
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::Version;
+#[cfg(feature = "serde")]
+use ibc_primitives::prelude::*;
+
+/// The version of an application, as negotiated through the version string of
+/// its channels.
+///
+/// Implementors only describe which versions they support; the provided
+/// `on_chan_open_*` methods implement the negotiation performed by the
+/// matching module callbacks:
+///
+/// - `OpenInit` proposes [`AppVersion::propose`] when the relayer leaves the
+///   version empty, and otherwise verifies the requested version.
+/// - `OpenTry` and `OpenAck` verify the version of the counterparty.
+pub trait AppVersion: Sized {
+    type Error;
+
+    /// Returns the version proposed when a channel handshake is initiated
+    /// without version.
+    fn propose() -> Self;
+
+    /// Parses the version, failing if it is not supported.
+    fn verify(version: &Version) -> Result<Self, Self::Error>;
+
+    /// Returns the version string of the version.
+    fn to_version(&self) -> Version;
+
+    /// Checks whether a channel of this version may be upgraded to the
+    /// proposed version, returning the upgraded version. Defaults to
+    /// accepting any supported version.
+    fn upgrade(&self, proposed: &Version) -> Result<Self, Self::Error> {
+        Self::verify(proposed)
+    }
+
+    /// Negotiates the version of a channel initiated with the given version.
+    fn on_chan_open_init(version: &Version) -> Result<Self, Self::Error> {
+        if version.is_empty() {
+            Ok(Self::propose())
+        } else {
+            Self::verify(version)
+        }
+    }
+
+    /// Negotiates the version of a channel whose handshake was initiated by
+    /// the counterparty with the given version.
+    fn on_chan_open_try(counterparty_version: &Version) -> Result<Self, Self::Error> {
+        Self::verify(counterparty_version)
+    }
+
+    /// Verifies the version selected by the counterparty.
+    fn on_chan_open_ack(counterparty_version: &Version) -> Result<Self, Self::Error> {
+        Self::verify(counterparty_version)
+    }
+}
+
+/// Decodes the JSON version metadata of a middleware, e.g.
+/// `{"fee_version":"ics29-1","app_version":"ics20-1"}`.
+#[cfg(feature = "serde")]
+pub fn decode_json_version<M>(version: &Version) -> Result<M, ChannelError>
+where
+    M: serde::de::DeserializeOwned,
+{
+    serde_json::from_str(version.as_str()).map_err(|e| ChannelError::AppModule {
+        description: format!("invalid version metadata `{version}`: {e}"),
+    })
+}
+
+/// Encodes the JSON version metadata of a middleware.
+#[cfg(feature = "serde")]
+pub fn encode_json_version<M>(metadata: &M) -> Result<Version, ChannelError>
+where
+    M: serde::Serialize,
+{
+    serde_json::to_string(metadata)
+        .map(Version::new)
+        .map_err(|e| ChannelError::AppModule {
+            description: format!("invalid version metadata: {e}"),
+        })
+}
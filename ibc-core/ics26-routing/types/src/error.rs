@@ -1,6 +1,9 @@
 use displaydoc::Display;
-use ibc_core_host_types::identifiers::PortId;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
 use ibc_primitives::prelude::*;
+use ibc_primitives::ErrorCode;
+
+use crate::module::ModuleId;
 
 /// Error type for the router module.
 #[derive(Debug, Display)]
@@ -13,6 +16,54 @@ pub enum RouterError {
     UnknownPort { port_id: PortId },
     /// module not found
     ModuleNotFound,
+    /// module `{module_id}` is already registered
+    DuplicateModule { module_id: ModuleId },
+    /// port `{port_id}` is already bound to module `{module_id}`
+    PortAlreadyBound {
+        port_id: PortId,
+        module_id: ModuleId,
+    },
+    /// port `{port_id}` is not bound to any module
+    PortNotBound { port_id: PortId },
+    /// module `{module_id}` does not own port `{port_id}`, which is bound to module `{owner}`
+    UnauthorizedPortOwner {
+        port_id: PortId,
+        module_id: ModuleId,
+        owner: ModuleId,
+    },
+    /// channel `{channel_id}` on port `{port_id}` is already owned by module `{module_id}`
+    ChannelAlreadyOwned {
+        port_id: PortId,
+        channel_id: ChannelId,
+        module_id: ModuleId,
+    },
+    /// module `{module_id}` does not own channel `{channel_id}` on port `{port_id}`, which is owned by module `{owner}`
+    UnauthorizedChannelOwner {
+        port_id: PortId,
+        channel_id: ChannelId,
+        module_id: ModuleId,
+        owner: ModuleId,
+    },
+}
+
+impl RouterError {
+    /// Returns the stable error code of this error.
+    pub fn code(&self) -> ErrorCode {
+        let code = |code| ErrorCode::new("router", code);
+
+        match self {
+            Self::UnknownMessageTypeUrl { .. } => code(1),
+            Self::MalformedMessageBytes { .. } => code(2),
+            Self::UnknownPort { .. } => code(3),
+            Self::ModuleNotFound => code(4),
+            Self::DuplicateModule { .. } => code(5),
+            Self::PortAlreadyBound { .. } => code(6),
+            Self::PortNotBound { .. } => code(7),
+            Self::UnauthorizedPortOwner { .. } => code(8),
+            Self::ChannelAlreadyOwned { .. } => code(9),
+            Self::UnauthorizedChannelOwner { .. } => code(10),
+        }
+    }
 }
 
 #[cfg(feature = "std")]
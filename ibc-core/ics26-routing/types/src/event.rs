@@ -49,6 +49,19 @@ impl From<ModuleEvent> for abci::Event {
 pub struct ModuleEventAttribute {
     pub key: String,
     pub value: String,
+    /// Whether the attribute must be indexed by the host. If unset, the
+    /// indexing policy of the host applies, which by default does not index it.
+    pub index: Option<bool>,
+}
+
+impl ModuleEventAttribute {
+    /// Overrides whether the attribute must be indexed by the host.
+    pub fn with_index(self, index: bool) -> Self {
+        Self {
+            index: Some(index),
+            ..self
+        }
+    }
 }
 
 impl<K: ToString, V: ToString> From<(K, V)> for ModuleEventAttribute {
@@ -56,12 +69,23 @@ impl<K: ToString, V: ToString> From<(K, V)> for ModuleEventAttribute {
         Self {
             key: k.to_string(),
             value: v.to_string(),
+            index: None,
+        }
+    }
+}
+
+impl<K: ToString, V: ToString> From<(K, V, bool)> for ModuleEventAttribute {
+    fn from((k, v, index): (K, V, bool)) -> Self {
+        Self {
+            key: k.to_string(),
+            value: v.to_string(),
+            index: Some(index),
         }
     }
 }
 
 impl From<ModuleEventAttribute> for abci::EventAttribute {
     fn from(attr: ModuleEventAttribute) -> Self {
-        (attr.key, attr.value).into()
+        (attr.key, attr.value, attr.index.unwrap_or(false)).into()
     }
 }
@@ -53,6 +53,48 @@ pub(crate) fn impl_ClientStateCommon(
         quote! {verify_non_membership(cs, prefix, proof, root, path)},
         imports,
     );
+    let verify_merkle_membership_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {verify_merkle_membership(cs, proof, root, merkle_path, value)},
+        imports,
+    );
+    let verify_merkle_non_membership_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {verify_merkle_non_membership(cs, proof, root, merkle_path)},
+        imports,
+    );
+    let verify_membership_decoded_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {verify_membership_decoded(cs, prefix, proof, root, path, value)},
+        imports,
+    );
+    let verify_non_membership_decoded_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {verify_non_membership_decoded(cs, prefix, proof, root, path)},
+        imports,
+    );
+    let verify_batch_membership_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {verify_batch_membership(cs, prefix, proof, root, items)},
+        imports,
+    );
+    let commitment_hasher_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {commitment_hasher(cs)},
+        imports,
+    );
+    let client_message_signature_count_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {client_message_signature_count(cs, client_message)},
+        imports,
+    );
 
     let HostClientState = client_state_enum_name;
 
@@ -60,6 +102,9 @@ pub(crate) fn impl_ClientStateCommon(
     let CommitmentRoot = imports.commitment_root();
     let CommitmentPrefix = imports.commitment_prefix();
     let CommitmentProofBytes = imports.commitment_proof_bytes();
+    let CommitmentHasher = imports.commitment_hasher();
+    let DecodedProof = imports.decoded_proof();
+    let MerklePath = imports.merkle_path();
     let ClientStateCommon = imports.client_state_common();
     let ClientType = imports.client_type();
     let ClientError = imports.client_error();
@@ -91,6 +136,12 @@ pub(crate) fn impl_ClientStateCommon(
                 }
             }
 
+            fn commitment_hasher(&self) -> core::result::Result<#CommitmentHasher, #ClientError> {
+                match self {
+                    #(#commitment_hasher_impl),*
+                }
+            }
+
             fn verify_upgrade_client(
                 &self,
                 upgraded_client_state: #Any,
@@ -128,6 +179,75 @@ pub(crate) fn impl_ClientStateCommon(
                     #(#verify_non_membership_impl),*
                 }
             }
+
+            fn verify_merkle_membership(
+                &self,
+                proof: &#CommitmentProofBytes,
+                root: &#CommitmentRoot,
+                merkle_path: #MerklePath,
+                value: Vec<u8>,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_merkle_membership_impl),*
+                }
+            }
+
+            fn verify_merkle_non_membership(
+                &self,
+                proof: &#CommitmentProofBytes,
+                root: &#CommitmentRoot,
+                merkle_path: #MerklePath,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_merkle_non_membership_impl),*
+                }
+            }
+
+            fn verify_membership_decoded(
+                &self,
+                prefix: &#CommitmentPrefix,
+                proof: &#DecodedProof<'_>,
+                root: &#CommitmentRoot,
+                path: #Path,
+                value: Vec<u8>,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_membership_decoded_impl),*
+                }
+            }
+
+            fn verify_non_membership_decoded(
+                &self,
+                prefix: &#CommitmentPrefix,
+                proof: &#DecodedProof<'_>,
+                root: &#CommitmentRoot,
+                path: #Path,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_non_membership_decoded_impl),*
+                }
+            }
+
+            fn verify_batch_membership(
+                &self,
+                prefix: &#CommitmentPrefix,
+                proof: &#CommitmentProofBytes,
+                root: &#CommitmentRoot,
+                items: Vec<(#Path, Vec<u8>)>,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_batch_membership_impl),*
+                }
+            }
+
+            fn client_message_signature_count(
+                &self,
+                client_message: &#Any,
+            ) -> core::result::Result<usize, #ClientError> {
+                match self {
+                    #(#client_message_signature_count_impl),*
+                }
+            }
         }
 
     }
@@ -45,6 +45,16 @@ impl Imports {
         quote! {#Prefix::commitment_types::commitment::CommitmentProofBytes}
     }
 
+    pub fn decoded_proof(&self) -> TokenStream {
+        let Prefix = self.prefix();
+        quote! {#Prefix::commitment_types::merkle::DecodedProof}
+    }
+
+    pub fn merkle_path(&self) -> TokenStream {
+        let Prefix = self.prefix();
+        quote! {#Prefix::commitment_types::proto::v1::MerklePath}
+    }
+
     pub fn path(&self) -> TokenStream {
         let Prefix = self.prefix();
         quote! {#Prefix::host::types::path::Path}
@@ -95,6 +105,11 @@ impl Imports {
         quote! {#prefix::primitives::proto::Any}
     }
 
+    pub fn commitment_hasher(&self) -> TokenStream {
+        let prefix = self.prefix();
+        quote! {#prefix::primitives::CommitmentHasher}
+    }
+
     pub fn timestamp(&self) -> TokenStream {
         let prefix = self.prefix();
         quote! {#prefix::primitives::Timestamp}
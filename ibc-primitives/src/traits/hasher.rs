@@ -0,0 +1,160 @@
+//! Defines the `HostHasher` trait, which abstracts the hash function used to
+//! compute the commitments of the host.
+//!
+//! Only the commitments stored by the host itself are computed with its hash
+//! function. The commitments stored by a counterparty, which the host verifies
+//! the proofs of, are computed with the [`CommitmentHasher`] of the
+//! counterparty, as given by the client state tracking it.
+
+use sha2::Digest;
+
+/// A hash function producing 32-byte digests.
+pub trait HostHasher {
+    /// The value of the `ics23::HashOp` identifying the hash function in the
+    /// proof specs of the host's commitment store.
+    const HASH_OP: i32;
+
+    /// Hashes the given bytes.
+    fn hash(data: &[u8]) -> [u8; 32];
+}
+
+/// The SHA-256 hash function, as used by ibc-go.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Sha256Hasher;
+
+impl HostHasher for Sha256Hasher {
+    const HASH_OP: i32 = 1;
+
+    fn hash(data: &[u8]) -> [u8; 32] {
+        sha2::Sha256::digest(data).into()
+    }
+}
+
+/// The BLAKE2s-256 hash function.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Blake2sHasher;
+
+impl HostHasher for Blake2sHasher {
+    const HASH_OP: i32 = 8;
+
+    fn hash(data: &[u8]) -> [u8; 32] {
+        blake2::Blake2s256::digest(data).into()
+    }
+}
+
+/// The hash function of the host, used to compute the packet and
+/// acknowledgement commitments it stores and to hash the denominations of
+/// tokens.
+///
+/// It is selected at compile time rather than configured at runtime, so that
+/// the nodes of a chain can never disagree on their commitments. Defaults to
+/// [`Sha256Hasher`], which building with `--cfg ibc_host_hasher="blake2s"`
+/// replaces with [`Blake2sHasher`]. Hosts relying on other hash functions
+/// (e.g. Poseidon) can implement [`HostHasher`] and use the `*_with` variants
+/// of the commitment functions.
+///
+/// Note that the counterparties of a host must support its hash function in
+/// order to verify its proofs, which they learn from the proof specs the host
+/// advertises.
+#[cfg(not(ibc_host_hasher = "blake2s"))]
+pub type DefaultHostHasher = Sha256Hasher;
+
+#[cfg(ibc_host_hasher = "blake2s")]
+pub type DefaultHostHasher = Blake2sHasher;
+
+/// The hash functions a counterparty may compute its commitments with, as
+/// identified by the `ics23::HashOp` of its proof specs.
+///
+/// Unlike the [`DefaultHostHasher`], it is selected at runtime, as the
+/// counterparties of a host need not agree on their hash function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommitmentHasher {
+    /// The [`Sha256Hasher`], used by ibc-go.
+    Sha256,
+    /// The [`Blake2sHasher`].
+    Blake2s,
+}
+
+impl CommitmentHasher {
+    /// Returns the hash function of the host, i.e. the [`DefaultHostHasher`].
+    pub fn host() -> Self {
+        if cfg!(ibc_host_hasher = "blake2s") {
+            Self::Blake2s
+        } else {
+            Self::Sha256
+        }
+    }
+
+    /// Returns the hash function identified by the given `ics23::HashOp`, if
+    /// supported.
+    pub fn from_hash_op(hash_op: i32) -> Option<Self> {
+        match hash_op {
+            Sha256Hasher::HASH_OP => Some(Self::Sha256),
+            Blake2sHasher::HASH_OP => Some(Self::Blake2s),
+            _ => None,
+        }
+    }
+
+    /// Returns the `ics23::HashOp` identifying the hash function.
+    pub fn hash_op(&self) -> i32 {
+        match self {
+            Self::Sha256 => Sha256Hasher::HASH_OP,
+            Self::Blake2s => Blake2sHasher::HASH_OP,
+        }
+    }
+
+    /// Hashes the given bytes.
+    pub fn hash(&self, data: &[u8]) -> [u8; 32] {
+        match self {
+            Self::Sha256 => Sha256Hasher::hash(data),
+            Self::Blake2s => Blake2sHasher::hash(data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_hasher() {
+        let expected: [u8; 32] = [
+            0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+            0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+            0xf2, 0x00, 0x15, 0xad,
+        ];
+        assert_eq!(Sha256Hasher::hash(b"abc"), expected);
+        assert_eq!(CommitmentHasher::Sha256.hash(b"abc"), expected);
+    }
+
+    #[test]
+    fn test_blake2s_hasher() {
+        let expected: [u8; 32] = [
+            0x50, 0x8c, 0x5e, 0x8c, 0x32, 0x7c, 0x14, 0xe2, 0xe1, 0xa7, 0x2b, 0xa3, 0x4e, 0xeb,
+            0x45, 0x2f, 0x37, 0x45, 0x8b, 0x20, 0x9e, 0xd6, 0x3a, 0x29, 0x4d, 0x99, 0x9b, 0x4c,
+            0x86, 0x67, 0x59, 0x82,
+        ];
+        assert_eq!(Blake2sHasher::hash(b"abc"), expected);
+        assert_eq!(CommitmentHasher::Blake2s.hash(b"abc"), expected);
+    }
+
+    #[test]
+    fn test_commitment_hasher_hash_ops() {
+        for hasher in [CommitmentHasher::Sha256, CommitmentHasher::Blake2s] {
+            assert_eq!(
+                CommitmentHasher::from_hash_op(hasher.hash_op()),
+                Some(hasher)
+            );
+        }
+        assert_eq!(CommitmentHasher::from_hash_op(0), None);
+
+        assert_eq!(
+            CommitmentHasher::host().hash_op(),
+            DefaultHostHasher::HASH_OP
+        );
+        assert_eq!(
+            CommitmentHasher::host().hash(b"abc"),
+            DefaultHostHasher::hash(b"abc")
+        );
+    }
+}
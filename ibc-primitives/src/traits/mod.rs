@@ -1,3 +1,11 @@
+mod hasher;
 mod proto;
 
+pub use hasher::*;
 pub use proto::*;
+
+#[cfg(feature = "serde")]
+mod proto_json;
+
+#[cfg(feature = "serde")]
+pub use proto_json::*;
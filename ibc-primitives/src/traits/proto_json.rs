@@ -0,0 +1,154 @@
+use core::fmt::Display;
+
+use displaydoc::Display as DisplayDoc;
+use ibc_proto::Protobuf;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::prelude::*;
+
+/// Errors that arise while converting a domain type to or from its proto3
+/// JSON representation.
+#[derive(Debug, DisplayDoc)]
+pub enum ProtoJsonError {
+    /// failed to encode proto3 JSON: `{0}`
+    Encode(String),
+    /// failed to decode proto3 JSON: `{0}`
+    Decode(String),
+    /// failed to convert the raw type into its domain type: `{0}`
+    Conversion(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProtoJsonError {}
+
+/// Legacy amino JSON envelope wrapping a message under its registered type
+/// name, as found in `SIGN_MODE_LEGACY_AMINO_JSON` sign docs.
+#[derive(Serialize)]
+struct AminoJson<'a, P> {
+    #[serde(rename = "type")]
+    type_name: &'a str,
+    value: P,
+}
+
+/// Types that implement this trait can be converted to and from the proto3
+/// JSON encoding of their raw Protobuf type.
+///
+/// The encoding is delegated to the `serde` implementations of the raw
+/// `ibc-proto` types, which follow the proto3 JSON mapping used by Cosmos SDK
+/// sign docs (base64 encoded `bytes`, stringified 64-bit integers). This
+/// allows signing flows to be built on top of the domain `Msg*` types directly.
+pub trait ToProtoJson<P>: Protobuf<P>
+where
+    P: From<Self> + prost::Message + Default + Serialize + DeserializeOwned,
+    <Self as TryFrom<P>>::Error: Display,
+{
+    /// Encodes `self` as proto3 JSON.
+    fn to_proto_json(&self) -> Result<String, ProtoJsonError> {
+        serde_json::to_string(&P::from(self.clone()))
+            .map_err(|e| ProtoJsonError::Encode(e.to_string()))
+    }
+
+    /// Encodes `self` as legacy amino JSON, i.e. wrapped in the
+    /// `{"type": .., "value": ..}` envelope under the given type name, e.g.
+    /// `cosmos-sdk/MsgTransfer`, with the snake_case field names of the proto
+    /// definitions instead of their proto3 JSON lowerCamelCase names.
+    ///
+    /// The fields keep the order of the proto definitions, as sign docs are
+    /// sorted as a whole by the signer. Messages with `map` fields are not
+    /// supported, as their keys would be renamed as well.
+    fn to_amino_json(&self, type_name: &str) -> Result<String, ProtoJsonError> {
+        let json = serde_json::to_string(&AminoJson {
+            type_name,
+            value: P::from(self.clone()),
+        })
+        .map_err(|e| ProtoJsonError::Encode(e.to_string()))?;
+
+        Ok(snake_case_keys(&json))
+    }
+
+    /// Decodes a domain type from its proto3 JSON encoding, running the same
+    /// validation as the Protobuf conversion.
+    fn from_proto_json(json: &str) -> Result<Self, ProtoJsonError> {
+        let raw: P =
+            serde_json::from_str(json).map_err(|e| ProtoJsonError::Decode(e.to_string()))?;
+
+        Self::try_from(raw).map_err(|e| ProtoJsonError::Conversion(e.to_string()))
+    }
+}
+
+impl<T, P> ToProtoJson<P> for T
+where
+    T: Protobuf<P>,
+    P: From<Self> + prost::Message + Default + Serialize + DeserializeOwned,
+    <Self as TryFrom<P>>::Error: Display,
+{
+}
+
+/// Renames the object keys of the given JSON from lowerCamelCase to
+/// snake_case, leaving the string values as they are.
+fn snake_case_keys(json: &str) -> String {
+    let mut snake = String::with_capacity(json.len() + json.len() / 8);
+    let mut rest = json;
+
+    while let Some(start) = rest.find('"') {
+        snake.push_str(&rest[..start]);
+
+        let (literal, tail) = rest[start..].split_at(string_literal_len(&rest[start..]));
+
+        if tail.trim_start().starts_with(':') {
+            for c in literal.chars() {
+                if c.is_ascii_uppercase() {
+                    snake.push('_');
+                    snake.push(c.to_ascii_lowercase());
+                } else {
+                    snake.push(c);
+                }
+            }
+        } else {
+            snake.push_str(literal);
+        }
+
+        rest = tail;
+    }
+
+    snake.push_str(rest);
+    snake
+}
+
+/// Returns the length of the JSON string literal at the start of `json`,
+/// quotes included, or the length of `json` if the literal is unterminated.
+fn string_literal_len(json: &str) -> usize {
+    let mut escaped = false;
+
+    for (i, c) in json.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return i + 1,
+            _ => {}
+        }
+    }
+
+    json.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snake_case_keys() {
+        assert_eq!(
+            snake_case_keys(r#"{"sourcePort":"transfer","timeoutHeight":{"revisionNumber":"1"}}"#),
+            r#"{"source_port":"transfer","timeout_height":{"revision_number":"1"}}"#
+        );
+
+        // Values, including escaped quotes and strings followed by colons,
+        // are left untouched
+        assert_eq!(
+            snake_case_keys(r#"{"memo":"{\"fooBar\":1}","list":["aB", "cD:eF"]}"#),
+            r#"{"memo":"{\"fooBar\":1}","list":["aB", "cD:eF"]}"#
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+/// A stable, machine-readable identifier of an IBC error.
+///
+/// An error code is made of a `namespace`, identifying the module the error
+/// originates from (e.g. `client` or `transfer`), and a non-zero `code` unique
+/// within that namespace. Codes are never reassigned once released, so hosts
+/// can return them to their clients instead of error messages, which are not
+/// part of the stable API.
+///
+/// The pair maps directly onto the `codespace` and `code` fields of ABCI
+/// responses, in which the code 0 is reserved for success.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ErrorCode {
+    namespace: &'static str,
+    code: u32,
+}
+
+impl ErrorCode {
+    /// Creates a new `ErrorCode` from its `namespace` and `code`, which must
+    /// not be 0.
+    pub const fn new(namespace: &'static str, code: u32) -> Self {
+        Self { namespace, code }
+    }
+
+    /// Returns the namespace of the error code.
+    pub const fn namespace(&self) -> &'static str {
+        self.namespace
+    }
+
+    /// Returns the code within the namespace.
+    pub const fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// Returns the `(codespace, code)` pair of the error in an ABCI response.
+    pub const fn to_abci(&self) -> (&'static str, u32) {
+        (self.namespace, self.code)
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}:{}", self.namespace, self.code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_error_code_display() {
+        let code = ErrorCode::new("client", 5);
+
+        assert_eq!(code.to_string(), "client:5");
+        assert_eq!(code.to_abci(), ("client", 5));
+    }
+}
@@ -1,5 +1,11 @@
+mod error_code;
+mod preserved;
 mod signer;
+mod state_diff;
 mod timestamp;
 
+pub use error_code::*;
+pub use preserved::*;
 pub use signer::*;
+pub use state_diff::*;
 pub use timestamp::*;
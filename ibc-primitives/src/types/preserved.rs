@@ -0,0 +1,158 @@
+use core::fmt::Display;
+
+use displaydoc::Display as DisplayDoc;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+use prost::Message;
+
+use crate::prelude::*;
+
+/// Errors that arise while decoding a [`Preserved`] value.
+#[derive(Debug, DisplayDoc)]
+pub enum PreservedDecodingError {
+    /// failed to decode protobuf: `{0}`
+    Decode(String),
+    /// failed to convert the raw type into its domain type: `{0}`
+    Conversion(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PreservedDecodingError {}
+
+/// A domain value decoded from its protobuf encoding, along with the bytes it
+/// was decoded from.
+///
+/// Decoding drops the fields unknown to the protos of ibc-rs, e.g. those added
+/// to a structure by a counterparty running newer protos, so that re-encoding
+/// the domain value would not reproduce the encoding committed to by the
+/// counterparty. `Preserved` re-encodes the original bytes instead, which
+/// keeps commitments over such structures verifiable.
+///
+/// Note that packet data needs no such wrapper, as packet commitments are
+/// computed over the `data` bytes of the packet, which are kept as received.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Preserved<T> {
+    value: T,
+    encoded: Vec<u8>,
+}
+
+impl<T> Preserved<T> {
+    /// Decodes a domain value from the protobuf encoding of its raw type,
+    /// e.g. a `ChannelEnd` or a `ConnectionEnd`.
+    pub fn decode<Raw>(encoded: &[u8]) -> Result<Self, PreservedDecodingError>
+    where
+        T: Protobuf<Raw>,
+        Raw: Message + Default + From<T>,
+        <T as TryFrom<Raw>>::Error: Display,
+    {
+        let raw =
+            Raw::decode(encoded).map_err(|e| PreservedDecodingError::Decode(e.to_string()))?;
+        let value =
+            T::try_from(raw).map_err(|e| PreservedDecodingError::Conversion(e.to_string()))?;
+
+        Ok(Self {
+            value,
+            encoded: encoded.to_vec(),
+        })
+    }
+
+    /// Decodes a domain value from the protobuf encoding of an `Any`, e.g. a
+    /// client or consensus state as stored by the counterparty.
+    pub fn decode_any(encoded: &[u8]) -> Result<Self, PreservedDecodingError>
+    where
+        T: TryFrom<Any>,
+        <T as TryFrom<Any>>::Error: Display,
+    {
+        let any =
+            Any::decode(encoded).map_err(|e| PreservedDecodingError::Decode(e.to_string()))?;
+        let value =
+            T::try_from(any).map_err(|e| PreservedDecodingError::Conversion(e.to_string()))?;
+
+        Ok(Self {
+            value,
+            encoded: encoded.to_vec(),
+        })
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Returns the bytes the value was decoded from.
+    pub fn encoded(&self) -> &[u8] {
+        &self.encoded
+    }
+
+    /// Re-encodes the value byte-identically to the encoding it was decoded
+    /// from.
+    pub fn encode_vec(self) -> Vec<u8> {
+        self.encoded
+    }
+
+    /// Returns `true` if re-encoding the raw type decoded from the original
+    /// bytes reproduces them, i.e. if decoding dropped no unknown fields and
+    /// the original encoding was canonical.
+    ///
+    /// A value whose encoding is not canonical decodes to the same domain
+    /// value as its canonical encoding, but commitments over the two differ.
+    pub fn is_canonical<Raw>(&self) -> bool
+    where
+        Raw: Message + Default,
+    {
+        Raw::decode(self.encoded.as_slice())
+            .map(|raw| raw.encode_to_vec() == self.encoded)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Height(u64, u64);
+
+    impl TryFrom<RawHeight> for Height {
+        type Error = String;
+
+        fn try_from(raw: RawHeight) -> Result<Self, Self::Error> {
+            Ok(Self(raw.revision_number, raw.revision_height))
+        }
+    }
+
+    impl From<Height> for RawHeight {
+        fn from(height: Height) -> Self {
+            Self {
+                revision_number: height.0,
+                revision_height: height.1,
+            }
+        }
+    }
+
+    impl Protobuf<RawHeight> for Height {}
+
+    #[test]
+    fn test_preserves_unknown_fields() {
+        let known = Height(1, 42).encode_vec();
+        // Appends an unknown varint field numbered 15 to the encoding
+        let extended = [known.as_slice(), &[0x78, 0x07]].concat();
+
+        let preserved = Preserved::<Height>::decode::<RawHeight>(&extended).unwrap();
+
+        assert_eq!(preserved.value(), &Height(1, 42));
+        assert!(!preserved.is_canonical::<RawHeight>());
+        assert_eq!(preserved.clone().encode_vec(), extended);
+        assert_eq!(preserved.into_value().encode_vec(), known);
+
+        let preserved = Preserved::<Height>::decode::<RawHeight>(&known).unwrap();
+
+        assert!(preserved.is_canonical::<RawHeight>());
+        assert_eq!(preserved.encode_vec(), known);
+    }
+}
@@ -1,4 +1,5 @@
 use derive_more::Display;
+use subtle_encoding::bech32;
 
 use crate::prelude::*;
 
@@ -31,3 +32,241 @@ impl AsRef<str> for Signer {
         self.0.as_str()
     }
 }
+
+impl Signer {
+    /// Validates the signer against the given validator.
+    pub fn validate_with(&self, validator: &impl SignerValidator) -> Result<(), SignerError> {
+        validator.validate_signer(self)
+    }
+}
+
+/// Errors that arise when validating a [`Signer`].
+#[derive(Debug, displaydoc::Display)]
+pub enum SignerError {
+    /// signer length `{length}` is outside of the range [`{min}`, `{max}`]
+    InvalidLength {
+        length: usize,
+        min: usize,
+        max: usize,
+    },
+    /// invalid bech32 signer `{signer}`: `{reason}`
+    InvalidBech32 { signer: Signer, reason: String },
+    /// signer `{signer}` has the human-readable part `{actual}`, expected `{expected}`
+    MismatchedHrp {
+        signer: Signer,
+        expected: String,
+        actual: String,
+    },
+    /// invalid hex signer `{signer}`
+    InvalidHex { signer: Signer },
+    /// failed to convert signer `{signer}` into an account
+    ConversionFailed { signer: Signer },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SignerError {}
+
+/// Validates the format of [`Signer`]s before they are converted into host
+/// accounts, so that malformed addresses are rejected early.
+pub trait SignerValidator {
+    fn validate_signer(&self, signer: &Signer) -> Result<(), SignerError>;
+}
+
+/// Accepts signers that are accepted by both validators.
+impl<A, B> SignerValidator for (A, B)
+where
+    A: SignerValidator,
+    B: SignerValidator,
+{
+    fn validate_signer(&self, signer: &Signer) -> Result<(), SignerError> {
+        self.0.validate_signer(signer)?;
+        self.1.validate_signer(signer)
+    }
+}
+
+/// Accepts bech32 signers with the configured human-readable part, e.g.
+/// `cosmos`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bech32Validator {
+    hrp: String,
+}
+
+impl Bech32Validator {
+    pub fn new(hrp: impl Into<String>) -> Self {
+        Self { hrp: hrp.into() }
+    }
+}
+
+impl SignerValidator for Bech32Validator {
+    fn validate_signer(&self, signer: &Signer) -> Result<(), SignerError> {
+        let (hrp, _) = bech32::decode(signer.as_ref()).map_err(|e| SignerError::InvalidBech32 {
+            signer: signer.clone(),
+            reason: e.to_string(),
+        })?;
+
+        if hrp != self.hrp {
+            return Err(SignerError::MismatchedHrp {
+                signer: signer.clone(),
+                expected: self.hrp.clone(),
+                actual: hrp,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Accepts `0x`-prefixed hex signers, optionally of a fixed byte length.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HexValidator {
+    byte_len: Option<usize>,
+}
+
+impl HexValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only accepts signers encoding exactly `byte_len` bytes, e.g. 20 for
+    /// Ethereum addresses.
+    pub fn with_byte_len(byte_len: usize) -> Self {
+        Self {
+            byte_len: Some(byte_len),
+        }
+    }
+}
+
+impl SignerValidator for HexValidator {
+    fn validate_signer(&self, signer: &Signer) -> Result<(), SignerError> {
+        let is_valid = signer.as_ref().strip_prefix("0x").is_some_and(|hex| {
+            !hex.is_empty()
+                && hex.len() % 2 == 0
+                && hex.bytes().all(|b| b.is_ascii_hexdigit())
+                && self.byte_len.map_or(true, |len| hex.len() == 2 * len)
+        });
+
+        if !is_valid {
+            return Err(SignerError::InvalidHex {
+                signer: signer.clone(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Accepts signers whose length, in bytes, lies within the inclusive bounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LengthValidator {
+    min: usize,
+    max: usize,
+}
+
+impl LengthValidator {
+    pub fn new(min: usize, max: usize) -> Self {
+        Self { min, max }
+    }
+}
+
+impl SignerValidator for LengthValidator {
+    fn validate_signer(&self, signer: &Signer) -> Result<(), SignerError> {
+        let length = signer.as_ref().len();
+
+        if length < self.min || length > self.max {
+            return Err(SignerError::InvalidLength {
+                length,
+                min: self.min,
+                max: self.max,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts [`Signer`]s into host account identifiers, rejecting malformed
+/// signers.
+///
+/// Every [`SignerValidator`] is a converter into any account type that can be
+/// built from a `Signer`, validating the signer before converting it.
+pub trait SignerConverter<AccountId> {
+    fn convert_signer(&self, signer: &Signer) -> Result<AccountId, SignerError>;
+}
+
+impl<V, AccountId> SignerConverter<AccountId> for V
+where
+    V: SignerValidator,
+    AccountId: TryFrom<Signer>,
+{
+    fn convert_signer(&self, signer: &Signer) -> Result<AccountId, SignerError> {
+        self.validate_signer(signer)?;
+
+        AccountId::try_from(signer.clone()).map_err(|_| SignerError::ConversionFailed {
+            signer: signer.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer(s: &str) -> Signer {
+        s.to_string().into()
+    }
+
+    #[test]
+    fn test_bech32_validator() {
+        let validator = Bech32Validator::new("cosmos");
+
+        assert!(validator
+            .validate_signer(&signer("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"))
+            .is_ok());
+        assert!(matches!(
+            Bech32Validator::new("osmo")
+                .validate_signer(&signer("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng")),
+            Err(SignerError::MismatchedHrp { .. })
+        ));
+        // Corrupted checksum.
+        assert!(matches!(
+            validator.validate_signer(&signer("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7nh")),
+            Err(SignerError::InvalidBech32 { .. })
+        ));
+        assert!(validator.validate_signer(&signer("")).is_err());
+    }
+
+    #[test]
+    fn test_hex_validator() {
+        let address = signer("0x0CDA3F47EF3C4906693B170EF650EB968C5F4B2C");
+
+        assert!(HexValidator::new().validate_signer(&address).is_ok());
+        assert!(HexValidator::with_byte_len(20)
+            .validate_signer(&address)
+            .is_ok());
+        assert!(HexValidator::with_byte_len(32)
+            .validate_signer(&address)
+            .is_err());
+        assert!(HexValidator::new()
+            .validate_signer(&signer("0CDA3F47EF3C4906693B170EF650EB968C5F4B2C"))
+            .is_err());
+        assert!(HexValidator::new()
+            .validate_signer(&signer("0x0g"))
+            .is_err());
+        assert!(HexValidator::new().validate_signer(&signer("0x")).is_err());
+    }
+
+    #[test]
+    fn test_combined_validators_and_conversion() {
+        let validator = (Bech32Validator::new("cosmos"), LengthValidator::new(1, 45));
+        let address = signer("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng");
+
+        let converted: Signer = validator.convert_signer(&address).unwrap();
+        assert_eq!(converted, address);
+
+        let too_short = (Bech32Validator::new("cosmos"), LengthValidator::new(1, 10));
+        assert!(matches!(
+            SignerConverter::<Signer>::convert_signer(&too_short, &address),
+            Err(SignerError::InvalidLength { .. })
+        ));
+    }
+}
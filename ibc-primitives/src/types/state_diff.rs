@@ -0,0 +1,97 @@
+use core::fmt::{Debug, Display, Error as FmtError, Formatter};
+
+use crate::prelude::*;
+
+/// A field whose value in the state claimed by a counterparty differs from the
+/// one expected by the host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldMismatch {
+    pub field: &'static str,
+    pub expected: String,
+    pub claimed: String,
+}
+
+/// The field by field differences between the state a handler expected a
+/// counterparty to have committed and the state the counterparty claimed, e.g.
+/// between the expected connection end of a handshake and the one carried by
+/// a proof which failed verification.
+///
+/// The claimed state is taken from a proof which did not verify, so it is
+/// only a hint of what the counterparty committed, not a verified value.
+///
+/// The values of the fields are rendered through their `Debug`
+/// representation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    mismatches: Vec<FieldMismatch>,
+}
+
+impl StateDiff {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a mismatch of the given field if the expected and the claimed
+    /// values differ.
+    pub fn compare<T>(mut self, field: &'static str, expected: &T, claimed: &T) -> Self
+    where
+        T: Debug + PartialEq + ?Sized,
+    {
+        if expected != claimed {
+            self.mismatches.push(FieldMismatch {
+                field,
+                expected: format!("{expected:?}"),
+                claimed: format!("{claimed:?}"),
+            });
+        }
+        self
+    }
+
+    /// Returns true if the claimed state matches the expected one.
+    pub fn is_empty(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    pub fn mismatches(&self) -> &[FieldMismatch] {
+        &self.mismatches
+    }
+}
+
+impl Display for StateDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        for (i, mismatch) in self.mismatches.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(
+                f,
+                "{}: expected {}, claimed {}",
+                mismatch.field, mismatch.expected, mismatch.claimed
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_diff() {
+        let diff = StateDiff::new()
+            .compare("state", &1, &1)
+            .compare("delay_period", &10, &20)
+            .compare("client_id", "07-tendermint-0", "07-tendermint-1");
+
+        assert!(!diff.is_empty());
+        assert_eq!(diff.mismatches().len(), 2);
+        assert_eq!(
+            diff.to_string(),
+            "delay_period: expected 10, claimed 20, \
+             client_id: expected \"07-tendermint-0\", claimed \"07-tendermint-1\""
+        );
+
+        assert!(StateDiff::new().compare("state", &1, &1).is_empty());
+    }
+}
@@ -3,7 +3,7 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 use core::hash::Hash;
 use core::num::ParseIntError;
-use core::ops::{Add, Sub};
+use core::ops::{Add, Bound, RangeBounds, Sub};
 use core::str::FromStr;
 use core::time::Duration;
 
@@ -70,6 +70,13 @@ impl parity_scale_codec::Decode for Timestamp {
     }
 }
 
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::MaxEncodedLen for Timestamp {
+    fn max_encoded_len() -> usize {
+        u64::max_encoded_len()
+    }
+}
+
 #[cfg(feature = "parity-scale-codec")]
 impl scale_info::TypeInfo for Timestamp {
     type Identity = Self;
@@ -127,7 +134,11 @@ impl Timestamp {
     }
 
     /// Returns a `Timestamp` representation of the current time.
+    ///
+    /// This reads the system time, which differs across nodes, hence must not
+    /// be used in execution paths, which rely on the host timestamp instead.
     #[cfg(feature = "std")]
+    #[allow(clippy::disallowed_methods)]
     pub fn now() -> Self {
         Time::now().into()
     }
@@ -158,6 +169,10 @@ impl Timestamp {
 
     /// Convert a `Timestamp` to `u64` value in nanoseconds. If no timestamp
     /// is set, the result is 0.
+    ///
+    /// Times which do not fit into the protocol representation saturate:
+    /// times before the Unix epoch map to 0, and times after about year 2554
+    /// map to `u64::MAX`.
     /// ```
     /// use ibc_primitives::Timestamp;
     ///
@@ -177,10 +192,11 @@ impl Timestamp {
         self.time.map_or(0, |time| {
             let t: OffsetDateTime = time.into();
             let s = t.unix_timestamp_nanos();
-            assert!(s >= 0, "time {time:?} has negative `.timestamp()`");
-            s.try_into().expect(
-                "Fails UNIX timestamp is negative, but we don't allow that to be constructed",
-            )
+            if s < 0 {
+                0
+            } else {
+                s.try_into().unwrap_or(u64::MAX)
+            }
         })
     }
 
@@ -213,6 +229,44 @@ impl Timestamp {
     pub fn is_set(&self) -> bool {
         self.time.is_some()
     }
+
+    /// Adds `duration` to the timestamp. Returns `None` if the timestamp is
+    /// not set or if the result overflows.
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        self.time
+            .and_then(|time| (time + duration).ok())
+            .map(Self::from)
+    }
+
+    /// Subtracts `duration` from the timestamp. Returns `None` if the
+    /// timestamp is not set or if the result overflows.
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.time
+            .and_then(|time| (time - duration).ok())
+            .map(Self::from)
+    }
+
+    /// Checks whether both timestamps are set and the current one is strictly
+    /// after `other`.
+    pub fn is_after(&self, other: &Self) -> bool {
+        self.check_expiry(other) == Expiry::Expired
+    }
+
+    /// Checks whether the timestamp is set and lies within the given range.
+    /// Unset bounds of the range are treated as unbounded.
+    pub fn is_within(&self, range: impl RangeBounds<Self>) -> bool {
+        fn set_bound(bound: Bound<&Timestamp>) -> Bound<&Timestamp> {
+            match bound {
+                Bound::Included(timestamp) | Bound::Excluded(timestamp) if !timestamp.is_set() => {
+                    Bound::Unbounded
+                }
+                bound => bound,
+            }
+        }
+
+        self.is_set()
+            && (set_bound(range.start_bound()), set_bound(range.end_bound())).contains(self)
+    }
 }
 
 impl Display for Timestamp {
@@ -302,7 +356,7 @@ mod tests {
 
     use time::OffsetDateTime;
 
-    use super::{Expiry, Timestamp, ZERO_DURATION};
+    use super::{Expiry, Time, Timestamp, ZERO_DURATION};
 
     #[test]
     fn test_timestamp_comparisons() {
@@ -357,6 +411,61 @@ mod tests {
     }
 
     #[test]
+    fn test_timestamp_checked_arithmetic() {
+        let time1 = Timestamp::from_nanoseconds(100).unwrap();
+        let duration = Duration::from_nanos(50);
+
+        assert_eq!(
+            time1.checked_add(duration),
+            Some(Timestamp::from_nanoseconds(150).unwrap())
+        );
+        assert_eq!(
+            time1.checked_sub(duration),
+            Some(Timestamp::from_nanoseconds(50).unwrap())
+        );
+        assert_eq!(Timestamp::none().checked_add(duration), None);
+        assert_eq!(Timestamp::none().checked_sub(duration), None);
+    }
+
+    #[test]
+    fn test_timestamp_ordering_helpers() {
+        let time1 = Timestamp::from_nanoseconds(100).unwrap();
+        let time2 = Timestamp::from_nanoseconds(150).unwrap();
+        let time3 = Timestamp::from_nanoseconds(200).unwrap();
+
+        assert!(time2.is_after(&time1));
+        assert!(!time1.is_after(&time2));
+        assert!(!time1.is_after(&time1));
+        assert!(!time1.is_after(&Timestamp::none()));
+
+        assert!(time2.is_within(time1..time3));
+        assert!(!time3.is_within(time1..time3));
+        assert!(time3.is_within(time1..=time3));
+        assert!(time3.is_within(time1..));
+        assert!(!Timestamp::none().is_within(..time3));
+
+        assert!(time2.is_within(time1..Timestamp::none()));
+        assert!(time2.is_within(time1..=Timestamp::none()));
+        assert!(time2.is_within(Timestamp::none()..time3));
+        assert!(time2.is_within(Timestamp::none()..Timestamp::none()));
+        assert!(!time1.is_within(time2..Timestamp::none()));
+    }
+
+    #[test]
+    fn test_nanoseconds_saturate() {
+        let before_epoch = Timestamp::from(Time::from_unix_timestamp(-1, 0).unwrap());
+        assert_eq!(before_epoch.nanoseconds(), 0);
+
+        let far_future = Timestamp::from(Time::parse_from_rfc3339("9999-12-31T23:59:59Z").unwrap());
+        assert_eq!(far_future.nanoseconds(), u64::MAX);
+
+        let before_epoch =
+            (Timestamp::from_nanoseconds(1).unwrap() - Duration::from_secs(1)).unwrap();
+        assert_eq!(before_epoch.nanoseconds(), 0);
+    }
+
+    #[test]
+    #[allow(clippy::disallowed_methods)]
     fn subtract_compare() {
         let sleep_duration = Duration::from_micros(100);
 
@@ -375,7 +484,7 @@ mod tests {
     #[cfg(feature = "borsh")]
     fn test_timestamp_borsh_ser_der() {
         use borsh::{BorshDeserialize, BorshSerialize};
-        let timestamp = Timestamp::now();
+        let timestamp = Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap();
         let encode_timestamp = timestamp.try_to_vec().unwrap();
         let _ = Timestamp::try_from_slice(&encode_timestamp).unwrap();
     }
@@ -384,8 +493,17 @@ mod tests {
     #[cfg(feature = "parity-scale-codec")]
     fn test_timestamp_parity_scale_codec_ser_der() {
         use parity_scale_codec::{Decode, Encode};
-        let timestamp = Timestamp::now();
+        let timestamp = Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap();
         let encode_timestamp = timestamp.encode();
         let _ = Timestamp::decode(&mut encode_timestamp.as_slice()).unwrap();
     }
+
+    #[cfg(feature = "parity-scale-codec")]
+    #[test]
+    fn test_timestamp_max_encoded_len() {
+        use parity_scale_codec::{Encode, MaxEncodedLen};
+
+        let timestamp = Timestamp::from_nanoseconds(u64::MAX).unwrap();
+        assert_eq!(timestamp.encode().len(), Timestamp::max_encoded_len());
+    }
 }
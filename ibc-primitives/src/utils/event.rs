@@ -0,0 +1,68 @@
+//! Helpers to build the values of ABCI event attributes.
+//!
+//! Packet-heavy blocks emit thousands of event attributes, so these helpers
+//! write straight into a buffer allocated once, rather than going through
+//! intermediate `String`s or byte vectors.
+
+use core::fmt::{Display, Write};
+
+use crate::prelude::*;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Appends the lowercase hex encoding of the given bytes to `buf`.
+pub fn write_hex(buf: &mut String, bytes: &[u8]) {
+    buf.reserve(bytes.len() * 2);
+    for byte in bytes {
+        buf.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+        buf.push(char::from(HEX_DIGITS[usize::from(byte & 0x0f)]));
+    }
+}
+
+/// Returns the lowercase hex encoding of the given bytes, allocating once.
+pub fn encode_hex(bytes: &[u8]) -> String {
+    let mut buf = String::new();
+    write_hex(&mut buf, bytes);
+    buf
+}
+
+/// Formats the given value into a `String` of the given initial capacity.
+///
+/// Unlike `to_string`, this doesn't reallocate when a value that is written
+/// in several pieces, e.g. a `Height`, fits in the capacity.
+pub fn format_with_capacity(value: impl Display, capacity: usize) -> String {
+    let mut buf = String::with_capacity(capacity);
+    // Writing into a `String` never fails.
+    let _ = write!(buf, "{value}");
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use subtle_encoding::hex;
+
+    use super::*;
+
+    #[test]
+    fn test_encode_hex_matches_subtle_encoding() {
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+        let expected = String::from_utf8(hex::encode(&bytes)).expect("hex is valid UTF-8");
+
+        assert_eq!(encode_hex(&bytes), expected);
+        assert_eq!(encode_hex(&[]), "");
+    }
+
+    #[test]
+    fn test_write_hex_appends() {
+        let mut buf = String::from("0x");
+        write_hex(&mut buf, &[0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(buf, "0xdeadbeef");
+    }
+
+    #[test]
+    fn test_format_with_capacity() {
+        let formatted = format_with_capacity(format_args!("{}-{}", 1, 42), 8);
+        assert_eq!(formatted, "1-42");
+        assert_eq!(formatted.capacity(), 8);
+    }
+}
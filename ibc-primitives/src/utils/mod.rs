@@ -1,4 +1,6 @@
 //! Contains various internally-used utilities.
+pub mod event;
 pub mod pretty;
 
+pub use event::*;
 pub use pretty::*;
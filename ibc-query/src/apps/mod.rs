@@ -0,0 +1,5 @@
+//! Provides the gRPC query services of the IBC applications, answering the
+//! queries from the contexts of the applications.
+
+pub mod nft_transfer;
+pub mod transfer;
@@ -0,0 +1,102 @@
+//! [`NftTransferQueryService`] answers the ICS-721 NFT transfer queries from
+//! an [`NftTransferValidationContext`].
+//!
+//! The context neither resolves class traces from their hash nor exposes the
+//! escrow addresses, so the queries over them are answered as unimplemented.
+
+use ibc::apps::nft_transfer::context::NftTransferValidationContext;
+use ibc::apps::nft_transfer::types::Pagination;
+use ibc::core::primitives::prelude::*;
+use ibc_proto::ibc::applications::nft_transfer::v1::query_server::Query as NftTransferQuery;
+use ibc_proto::ibc::applications::nft_transfer::v1::{
+    QueryClassHashRequest, QueryClassHashResponse, QueryClassTraceRequest, QueryClassTraceResponse,
+    QueryClassTracesRequest, QueryClassTracesResponse, QueryEscrowAddressRequest,
+    QueryEscrowAddressResponse,
+};
+use tonic::{Request, Response, Status};
+
+use crate::types::PageRequest;
+
+/// The generic `I` must be a type where writes from one thread are readable
+/// from another. This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most
+/// cases.
+pub struct NftTransferQueryService<I>
+where
+    I: NftTransferValidationContext + Send + Sync + 'static,
+{
+    nft_transfer_context: I,
+}
+
+impl<I> NftTransferQueryService<I>
+where
+    I: NftTransferValidationContext + Send + Sync + 'static,
+{
+    /// The parameter `nft_transfer_context` must be a type where writes from
+    /// one thread are readable from another. This means using `Arc<Mutex<_>>`
+    /// or `Arc<RwLock<_>>` in most cases.
+    pub fn new(nft_transfer_context: I) -> Self {
+        Self {
+            nft_transfer_context,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> NftTransferQuery for NftTransferQueryService<I>
+where
+    I: NftTransferValidationContext + Send + Sync + 'static,
+{
+    async fn class_trace(
+        &self,
+        _request: Request<QueryClassTraceRequest>,
+    ) -> Result<Response<QueryClassTraceResponse>, Status> {
+        Err(Status::unimplemented(
+            "the NFT transfer context does not resolve class traces",
+        ))
+    }
+
+    async fn class_traces(
+        &self,
+        request: Request<QueryClassTracesRequest>,
+    ) -> Result<Response<QueryClassTracesResponse>, Status> {
+        let page_request = request
+            .into_inner()
+            .pagination
+            .map_or_else(PageRequest::all, PageRequest::from);
+        let limit = match page_request.limit {
+            0 => u64::MAX,
+            limit => limit,
+        };
+
+        let class_traces = self
+            .nft_transfer_context
+            .traced_nft_classes_paginated(&Pagination::new(page_request.offset, limit))
+            .map_err(|e| Status::internal(e.to_string()))?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(Response::new(QueryClassTracesResponse {
+            class_traces,
+            pagination: None,
+        }))
+    }
+
+    async fn class_hash(
+        &self,
+        _request: Request<QueryClassHashRequest>,
+    ) -> Result<Response<QueryClassHashResponse>, Status> {
+        Err(Status::unimplemented(
+            "the NFT transfer context does not hash classes",
+        ))
+    }
+
+    async fn escrow_address(
+        &self,
+        _request: Request<QueryEscrowAddressRequest>,
+    ) -> Result<Response<QueryEscrowAddressResponse>, Status> {
+        Err(Status::unimplemented(
+            "the NFT transfer context does not expose the escrow addresses",
+        ))
+    }
+}
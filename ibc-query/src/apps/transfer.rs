@@ -0,0 +1,113 @@
+//! [`TransferQueryService`] answers the ICS-20 token transfer queries from a
+//! [`TokenTransferValidationContext`].
+//!
+//! The context neither resolves denomination traces from their hash nor
+//! tracks the escrowed amounts, so the queries over them are answered as
+//! unimplemented.
+
+use core::str::FromStr;
+
+use ibc::apps::transfer::context::TokenTransferValidationContext;
+use ibc::apps::transfer::types::PrefixedDenom;
+use ibc::core::primitives::prelude::*;
+use ibc_proto::ibc::applications::transfer::v1::query_server::Query as TransferQuery;
+use ibc_proto::ibc::applications::transfer::v1::{
+    QueryDenomHashRequest, QueryDenomHashResponse, QueryDenomTraceRequest, QueryDenomTraceResponse,
+    QueryDenomTracesRequest, QueryDenomTracesResponse, QueryEscrowAddressRequest,
+    QueryEscrowAddressResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryTotalEscrowForDenomRequest, QueryTotalEscrowForDenomResponse,
+};
+use tonic::{Request, Response, Status};
+
+/// The generic `I` must be a type where writes from one thread are readable
+/// from another. This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most
+/// cases.
+pub struct TransferQueryService<I>
+where
+    I: TokenTransferValidationContext + Send + Sync + 'static,
+{
+    transfer_context: I,
+}
+
+impl<I> TransferQueryService<I>
+where
+    I: TokenTransferValidationContext + Send + Sync + 'static,
+{
+    /// The parameter `transfer_context` must be a type where writes from one
+    /// thread are readable from another. This means using `Arc<Mutex<_>>` or
+    /// `Arc<RwLock<_>>` in most cases.
+    pub fn new(transfer_context: I) -> Self {
+        Self { transfer_context }
+    }
+}
+
+#[tonic::async_trait]
+impl<I> TransferQuery for TransferQueryService<I>
+where
+    I: TokenTransferValidationContext + Send + Sync + 'static,
+{
+    async fn denom_trace(
+        &self,
+        _request: Request<QueryDenomTraceRequest>,
+    ) -> Result<Response<QueryDenomTraceResponse>, Status> {
+        Err(Status::unimplemented(
+            "the transfer context does not resolve denomination traces",
+        ))
+    }
+
+    async fn denom_traces(
+        &self,
+        _request: Request<QueryDenomTracesRequest>,
+    ) -> Result<Response<QueryDenomTracesResponse>, Status> {
+        Err(Status::unimplemented(
+            "the transfer context does not enumerate denomination traces",
+        ))
+    }
+
+    async fn params(
+        &self,
+        _request: Request<QueryParamsRequest>,
+    ) -> Result<Response<QueryParamsResponse>, Status> {
+        let params = self
+            .transfer_context
+            .get_params()
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(QueryParamsResponse {
+            params: Some(params.into()),
+        }))
+    }
+
+    async fn denom_hash(
+        &self,
+        request: Request<QueryDenomHashRequest>,
+    ) -> Result<Response<QueryDenomHashResponse>, Status> {
+        let denom = PrefixedDenom::from_str(&request.get_ref().trace)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let hash = self
+            .transfer_context
+            .denom_hash_string(&denom)
+            .ok_or_else(|| Status::unimplemented("the host does not hash denominations"))?;
+
+        Ok(Response::new(QueryDenomHashResponse { hash }))
+    }
+
+    async fn escrow_address(
+        &self,
+        _request: Request<QueryEscrowAddressRequest>,
+    ) -> Result<Response<QueryEscrowAddressResponse>, Status> {
+        Err(Status::unimplemented(
+            "the transfer context does not expose the escrow addresses",
+        ))
+    }
+
+    async fn total_escrow_for_denom(
+        &self,
+        _request: Request<QueryTotalEscrowForDenomRequest>,
+    ) -> Result<Response<QueryTotalEscrowForDenomResponse>, Status> {
+        Err(Status::unimplemented(
+            "the transfer context does not track the escrowed amounts",
+        ))
+    }
+}
@@ -1,9 +1,8 @@
 //! Provides utility functions for querying IBC channel states.
 
-use ibc::core::client::context::ClientValidationContext;
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, Path,
-    ReceiptPath, SeqRecvPath, SeqSendPath,
+    ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::{ConsensusStateRef, ValidationContext};
 use ibc::primitives::prelude::format;
@@ -11,19 +10,22 @@ use ibc_proto::google::protobuf::Any;
 
 use super::{
     QueryChannelClientStateRequest, QueryChannelClientStateResponse,
-    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse, QueryChannelRequest,
+    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse,
+    QueryChannelPacketStatsRequest, QueryChannelPacketStatsResponse, QueryChannelRequest,
     QueryChannelResponse, QueryChannelsRequest, QueryChannelsResponse,
-    QueryConnectionChannelsRequest, QueryConnectionChannelsResponse,
-    QueryNextSequenceReceiveRequest, QueryNextSequenceReceiveResponse,
-    QueryNextSequenceSendRequest, QueryNextSequenceSendResponse, QueryPacketAcknowledgementRequest,
-    QueryPacketAcknowledgementResponse, QueryPacketAcknowledgementsRequest,
-    QueryPacketAcknowledgementsResponse, QueryPacketCommitmentRequest,
-    QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest, QueryPacketCommitmentsResponse,
-    QueryPacketReceiptRequest, QueryPacketReceiptResponse, QueryUnreceivedAcksRequest,
-    QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
+    QueryConnectionChannelsRequest, QueryConnectionChannelsResponse, QueryNextSequenceAckRequest,
+    QueryNextSequenceAckResponse, QueryNextSequenceReceiveRequest,
+    QueryNextSequenceReceiveResponse, QueryNextSequenceSendRequest, QueryNextSequenceSendResponse,
+    QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse,
+    QueryPacketAcknowledgementsRequest, QueryPacketAcknowledgementsResponse,
+    QueryPacketCommitmentRequest, QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest,
+    QueryPacketCommitmentsResponse, QueryPacketReceiptRequest, QueryPacketReceiptResponse,
+    QueryUnreceivedAcksRequest, QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest,
+    QueryUnreceivedPacketsResponse,
 };
 use crate::core::client::IdentifiedClientState;
 use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::height::{decode_sequence, QueryHeight};
 use crate::error::QueryError;
 
 /// Queries for a specific IBC channel by the given channel and port ids and
@@ -37,12 +39,11 @@ where
 {
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
-    let channel_end = ibc_ctx.channel_end(&channel_end_path)?;
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let channel_end = query_height.channel_end(ibc_ctx, &channel_end_path)?;
+
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(proof_height, &Path::ChannelEnd(channel_end_path.clone()))
@@ -112,12 +113,14 @@ where
 {
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
-    let channel_end = ibc_ctx.channel_end(&channel_end_path)?;
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
+
+    let channel_end = query_height.channel_end(ibc_ctx, &channel_end_path)?;
 
     let connection_end = channel_end
         .connection_hops()
         .first()
-        .map(|connection_id| ibc_ctx.connection_end(connection_id))
+        .map(|connection_id| query_height.connection_end(ibc_ctx, connection_id))
         .ok_or_else(|| {
             QueryError::proof_not_found(format!(
                 "Channel {} does not have a connection",
@@ -125,14 +128,9 @@ where
             ))
         })??;
 
-    let client_val_ctx = ibc_ctx.get_client_validation_context();
+    let client_state = query_height.client_state(ibc_ctx, connection_end.client_id())?;
 
-    let client_state = client_val_ctx.client_state(connection_end.client_id())?;
-
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(
@@ -147,7 +145,7 @@ where
         })?;
 
     Ok(QueryChannelClientStateResponse::new(
-        IdentifiedClientState::new(connection_end.client_id().clone(), client_state.into()),
+        IdentifiedClientState::new(connection_end.client_id().clone(), client_state),
         proof,
         proof_height,
     ))
@@ -165,12 +163,14 @@ where
 {
     let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
 
-    let channel_end = ibc_ctx.channel_end(&channel_end_path)?;
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
+
+    let channel_end = query_height.channel_end(ibc_ctx, &channel_end_path)?;
 
     let connection_end = channel_end
         .connection_hops()
         .first()
-        .map(|connection_id| ibc_ctx.connection_end(connection_id))
+        .map(|connection_id| query_height.connection_end(ibc_ctx, connection_id))
         .ok_or_else(|| {
             QueryError::proof_not_found(format!(
                 "Channel {} does not have a connection",
@@ -183,14 +183,9 @@ where
         request.consensus_height.revision_number(),
         request.consensus_height.revision_height(),
     );
-    let client_val_ctx = ibc_ctx.get_client_validation_context();
+    let consensus_state = query_height.consensus_state(ibc_ctx, &consensus_path)?;
 
-    let consensus_state = client_val_ctx.consensus_state(&consensus_path)?;
-
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(
@@ -204,7 +199,7 @@ where
         })?;
 
     Ok(QueryChannelConsensusStateResponse::new(
-        consensus_state.into(),
+        consensus_state,
         connection_end.client_id().clone(),
         proof,
         proof_height,
@@ -223,12 +218,16 @@ where
     let commitment_path =
         CommitmentPath::new(&request.port_id, &request.channel_id, request.sequence);
 
-    let packet_commitment_data = ibc_ctx.get_packet_commitment(&commitment_path)?;
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let packet_commitment_data = query_height.value(
+        ibc_ctx,
+        &Path::Commitment(commitment_path.clone()),
+        || Ok(ibc_ctx.get_packet_commitment(&commitment_path)?),
+        |bytes| Ok(bytes.into()),
+    )?;
+
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(proof_height, &Path::Commitment(commitment_path.clone()))
@@ -279,15 +278,19 @@ where
 {
     let receipt_path = ReceiptPath::new(&request.port_id, &request.channel_id, request.sequence);
 
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
+
     // Receipt only has one enum
     // Unreceived packets are not stored
-    let packet_receipt_data = ibc_ctx.get_packet_receipt(&receipt_path);
-
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
+    let packet_received = match query_height {
+        QueryHeight::Latest(_) => ibc_ctx.get_packet_receipt(&receipt_path).is_ok(),
+        QueryHeight::Historical(height) => ibc_ctx
+            .get_historical_value(height, &Path::Receipt(receipt_path.clone()))
+            .is_some(),
     };
 
+    let proof_height = query_height.height();
+
     let proof = ibc_ctx
         .get_proof(proof_height, &Path::Receipt(receipt_path.clone()))
         .ok_or_else(|| {
@@ -297,7 +300,7 @@ where
         })?;
 
     Ok(QueryPacketReceiptResponse::new(
-        packet_receipt_data.is_ok(),
+        packet_received,
         proof,
         proof_height,
     ))
@@ -315,12 +318,16 @@ where
     let acknowledgement_path =
         AckPath::new(&request.port_id, &request.channel_id, request.sequence);
 
-    let packet_acknowledgement_data = ibc_ctx.get_packet_acknowledgement(&acknowledgement_path)?;
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let packet_acknowledgement_data = query_height.value(
+        ibc_ctx,
+        &Path::Ack(acknowledgement_path.clone()),
+        || Ok(ibc_ctx.get_packet_acknowledgement(&acknowledgement_path)?),
+        |bytes| Ok(bytes.into()),
+    )?;
+
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(proof_height, &Path::Ack(acknowledgement_path.clone()))
@@ -421,12 +428,16 @@ where
 {
     let next_seq_send_path = SeqSendPath::new(&request.port_id, &request.channel_id);
 
-    let next_sequence_send = ibc_ctx.get_next_sequence_send(&next_seq_send_path)?;
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let next_sequence_send = query_height.value(
+        ibc_ctx,
+        &Path::SeqSend(next_seq_send_path.clone()),
+        || Ok(ibc_ctx.get_next_sequence_send(&next_seq_send_path)?),
+        decode_sequence,
+    )?;
+
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(proof_height, &Path::SeqSend(next_seq_send_path))
@@ -454,12 +465,16 @@ where
 {
     let next_seq_recv_path = SeqRecvPath::new(&request.port_id, &request.channel_id);
 
-    let next_sequence_recv = ibc_ctx.get_next_sequence_recv(&next_seq_recv_path)?;
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let next_sequence_recv = query_height.value(
+        ibc_ctx,
+        &Path::SeqRecv(next_seq_recv_path.clone()),
+        || Ok(ibc_ctx.get_next_sequence_recv(&next_seq_recv_path)?),
+        decode_sequence,
+    )?;
+
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(proof_height, &Path::SeqRecv(next_seq_recv_path))
@@ -476,3 +491,75 @@ where
         proof_height,
     ))
 }
+
+/// Queries for the next sequence to be acknowledged on the channel specified
+/// in the `request`.
+pub fn query_next_sequence_ack<I>(
+    ibc_ctx: &I,
+    request: &QueryNextSequenceAckRequest,
+) -> Result<QueryNextSequenceAckResponse, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let next_seq_ack_path = SeqAckPath::new(&request.port_id, &request.channel_id);
+
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
+
+    let next_sequence_ack = query_height.value(
+        ibc_ctx,
+        &Path::SeqAck(next_seq_ack_path.clone()),
+        || Ok(ibc_ctx.get_next_sequence_ack(&next_seq_ack_path)?),
+        decode_sequence,
+    )?;
+
+    let proof_height = query_height.height();
+
+    let proof = ibc_ctx
+        .get_proof(proof_height, &Path::SeqAck(next_seq_ack_path))
+        .ok_or_else(|| {
+            QueryError::proof_not_found(format!(
+                "Next sequence ack proof not found for channel {}",
+                request.channel_id
+            ))
+        })?;
+
+    Ok(QueryNextSequenceAckResponse::new(
+        next_sequence_ack,
+        proof,
+        proof_height,
+    ))
+}
+
+/// Queries for the packet statistics of the channel specified in the
+/// `request`, at the latest height of the host.
+pub fn query_channel_packet_stats<I>(
+    ibc_ctx: &I,
+    request: &QueryChannelPacketStatsRequest,
+) -> Result<QueryChannelPacketStatsResponse, QueryError>
+where
+    I: QueryContext,
+{
+    let channel_end_path = ChannelEndPath::new(&request.port_id, &request.channel_id);
+
+    // the channel must exist for its statistics to be meaningful
+    ibc_ctx.channel_end(&channel_end_path)?;
+
+    let count = |len: usize| u64::try_from(len).unwrap_or(u64::MAX);
+
+    Ok(QueryChannelPacketStatsResponse {
+        packet_commitments: count(ibc_ctx.packet_commitments(&channel_end_path)?.len()),
+        packet_receipts: count(ibc_ctx.packet_receipts(&channel_end_path)?.len()),
+        packet_acknowledgements: count(
+            ibc_ctx
+                .packet_acknowledgements(&channel_end_path, core::iter::empty())?
+                .len(),
+        ),
+        next_sequence_send: ibc_ctx
+            .get_next_sequence_send(&SeqSendPath::new(&request.port_id, &request.channel_id))?,
+        next_sequence_receive: ibc_ctx
+            .get_next_sequence_recv(&SeqRecvPath::new(&request.port_id, &request.channel_id))?,
+        next_sequence_ack: ibc_ctx
+            .get_next_sequence_ack(&SeqAckPath::new(&request.port_id, &request.channel_id))?,
+        height: ibc_ctx.host_height()?,
+    })
+}
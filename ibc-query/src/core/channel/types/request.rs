@@ -408,3 +408,28 @@ impl TryFrom<RawQueryNextSequenceSendRequest> for QueryNextSequenceSendRequest {
         })
     }
 }
+
+/// Defines the request type for querying the next sequence to be acknowledged
+/// on the specified channel.
+///
+/// The query has no gRPC counterpart in ibc-go, hence no proto conversion.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryNextSequenceAckRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub query_height: Option<Height>,
+}
+
+/// Defines the request type for querying the packet statistics of the
+/// specified channel, which relayers use to pace their work on the channel.
+///
+/// The query has no gRPC counterpart in ibc-go, hence no proto conversion.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryChannelPacketStatsRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
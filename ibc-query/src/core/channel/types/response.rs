@@ -736,3 +736,43 @@ impl From<QueryNextSequenceSendResponse> for RawQueryNextSequenceSendResponse {
         }
     }
 }
+
+/// Defines the response type when querying the next sequence to be
+/// acknowledged on a channel.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryNextSequenceAckResponse {
+    pub next_sequence_ack: Sequence,
+    pub proof: Proof,
+    pub proof_height: Height,
+}
+
+impl QueryNextSequenceAckResponse {
+    pub fn new(next_sequence_ack: Sequence, proof: Proof, proof_height: Height) -> Self {
+        Self {
+            next_sequence_ack,
+            proof,
+            proof_height,
+        }
+    }
+}
+
+/// Defines the response type when querying the packet statistics of a
+/// channel, i.e. the number of packets in flight on the channel and the next
+/// sequences of its packet flows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryChannelPacketStatsResponse {
+    /// Number of packets sent and not yet acknowledged or timed out.
+    pub packet_commitments: u64,
+    /// Number of packets received, as recorded by their receipts.
+    pub packet_receipts: u64,
+    /// Number of packets acknowledged on the channel.
+    pub packet_acknowledgements: u64,
+    pub next_sequence_send: Sequence,
+    pub next_sequence_receive: Sequence,
+    pub next_sequence_ack: Sequence,
+    pub height: Height,
+}
@@ -12,9 +12,11 @@ use ibc::primitives::prelude::format;
 use ibc::primitives::proto::Any;
 
 use super::{
-    ConsensusStateWithHeight, IdentifiedClientState, QueryClientStateResponse,
-    QueryClientStatesRequest, QueryClientStatesResponse, QueryClientStatusRequest,
-    QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
+    ConsensusStateWithHeight, IdentifiedClientState, QueryClientParamsRequest,
+    QueryClientParamsResponse, QueryClientStateResponse, QueryClientStatesRequest,
+    QueryClientStatesResponse, QueryClientStatusRequest, QueryClientStatusResponse,
+    QueryClientUpdateMetaRequest, QueryClientUpdateMetaResponse, QueryClientUpdateMetasRequest,
+    QueryClientUpdateMetasResponse, QueryConsensusStateHeightsRequest,
     QueryConsensusStateHeightsResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
     QueryConsensusStatesRequest, QueryConsensusStatesResponse, QueryUpgradedClientStateRequest,
     QueryUpgradedClientStateResponse, QueryUpgradedConsensusStateRequest,
@@ -22,6 +24,7 @@ use super::{
 };
 use crate::core::client::QueryClientStateRequest;
 use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::height::{decode_any, QueryHeight};
 use crate::error::QueryError;
 
 /// Queries for the client state of a given client id.
@@ -34,14 +37,11 @@ where
 {
     let client_id = request.client_id.clone();
 
-    let client_val_ctx = ibc_ctx.get_client_validation_context();
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
 
-    let client_state = client_val_ctx.client_state(&client_id)?;
+    let client_state = query_height.client_state(ibc_ctx, &client_id)?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(
@@ -55,7 +55,7 @@ where
         })?;
 
     Ok(QueryClientStateResponse::new(
-        client_state.into(),
+        client_state,
         proof,
         proof_height,
     ))
@@ -92,32 +92,31 @@ where
 {
     let client_id = request.client_id.clone();
 
-    let (height, consensus_state) = if let Some(height) = request.consensus_height {
-        let client_val_ctx = ibc_ctx.get_client_validation_context();
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
 
-        let consensus_state = client_val_ctx.consensus_state(&ClientConsensusStatePath::new(
-            client_id.clone(),
-            height.revision_number(),
-            height.revision_height(),
-        ))?;
-
-        (height, consensus_state)
-    } else {
-        ibc_ctx
-            .consensus_states(&client_id)?
+    let height = match request.consensus_height {
+        Some(height) => height,
+        None => ibc_ctx
+            .consensus_state_heights(&client_id)?
             .into_iter()
-            .max_by_key(|&(h, _)| h)
+            .max()
             .ok_or_else(|| {
                 QueryError::proof_not_found(format!(
                     "No consensus state found for client: {client_id:?}"
                 ))
-            })?
+            })?,
     };
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let consensus_state = query_height.consensus_state(
+        ibc_ctx,
+        &ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ),
+    )?;
+
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(
@@ -135,7 +134,7 @@ where
         })?;
 
     Ok(QueryConsensusStateResponse::new(
-        consensus_state.into(),
+        consensus_state,
         proof,
         proof_height,
     ))
@@ -195,6 +194,57 @@ where
     Ok(QueryClientStatusResponse::new(client_status))
 }
 
+/// Queries for the parameters of the client module.
+pub fn query_client_params<I>(
+    ibc_ctx: &I,
+    _request: &QueryClientParamsRequest,
+) -> Result<QueryClientParamsResponse, QueryError>
+where
+    I: QueryContext,
+{
+    Ok(QueryClientParamsResponse::new(ibc_ctx.allowed_clients()?))
+}
+
+/// Queries for the update metadata recorded for a client at a given consensus
+/// height.
+pub fn query_client_update_meta<I>(
+    ibc_ctx: &I,
+    request: &QueryClientUpdateMetaRequest,
+) -> Result<QueryClientUpdateMetaResponse, QueryError>
+where
+    I: ValidationContext,
+{
+    let update_meta = ibc_ctx
+        .get_client_validation_context()
+        .client_update_meta(&request.client_id, &request.consensus_height)?;
+
+    Ok(QueryClientUpdateMetaResponse::new(update_meta))
+}
+
+/// Queries for the update metadata recorded for each consensus state of a
+/// client.
+pub fn query_client_update_metas<I>(
+    ibc_ctx: &I,
+    request: &QueryClientUpdateMetasRequest,
+) -> Result<QueryClientUpdateMetasResponse, QueryError>
+where
+    I: QueryContext,
+{
+    let client_val_ctx = ibc_ctx.get_client_validation_context();
+
+    let update_metas = ibc_ctx
+        .consensus_state_heights(&request.client_id)?
+        .into_iter()
+        .map(|height| {
+            client_val_ctx
+                .client_update_meta(&request.client_id, &height)
+                .map(|update_meta| (height, update_meta))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(QueryClientUpdateMetasResponse::new(update_metas))
+}
+
 /// Queries for the upgraded client state.
 pub fn query_upgraded_client_state<I, U>(
     ibc_ctx: &I,
@@ -218,14 +268,22 @@ where
     let upgraded_client_state_path =
         UpgradeClientPath::UpgradedClientState(upgrade_revision_height);
 
-    let upgraded_client_state = upgrade_ctx
-        .upgraded_client_state(&upgraded_client_state_path)
-        .map_err(ClientError::from)?;
+    let query_height =
+        QueryHeight::resolve(upgrade_ctx, ibc_ctx.host_height()?, request.query_height)?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let upgraded_client_state = query_height.value(
+        upgrade_ctx,
+        &Path::UpgradeClient(upgraded_client_state_path.clone()),
+        || {
+            Ok(upgrade_ctx
+                .upgraded_client_state(&upgraded_client_state_path)
+                .map_err(ClientError::from)?
+                .into())
+        },
+        decode_any,
+    )?;
+
+    let proof_height = query_height.height();
 
     let proof = upgrade_ctx
         .get_proof(
@@ -239,7 +297,7 @@ where
         })?;
 
     Ok(QueryUpgradedClientStateResponse::new(
-        upgraded_client_state.into(),
+        upgraded_client_state,
         proof,
         proof_height,
     ))
@@ -269,14 +327,22 @@ where
     let upgraded_consensus_state_path =
         UpgradeClientPath::UpgradedClientConsensusState(upgrade_revision_height);
 
-    let upgraded_consensus_state = upgrade_ctx
-        .upgraded_consensus_state(&upgraded_consensus_state_path)
-        .map_err(ClientError::from)?;
+    let query_height =
+        QueryHeight::resolve(upgrade_ctx, ibc_ctx.host_height()?, request.query_height)?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let upgraded_consensus_state = query_height.value(
+        upgrade_ctx,
+        &Path::UpgradeClient(upgraded_consensus_state_path.clone()),
+        || {
+            Ok(upgrade_ctx
+                .upgraded_consensus_state(&upgraded_consensus_state_path)
+                .map_err(ClientError::from)?
+                .into())
+        },
+        decode_any,
+    )?;
+
+    let proof_height = query_height.height();
 
     let proof = upgrade_ctx
         .get_proof(
@@ -290,7 +356,7 @@ where
         })?;
 
     Ok(QueryUpgradedConsensusStateResponse::new(
-        upgraded_consensus_state.into(),
+        upgraded_consensus_state,
         proof,
         proof_height,
     ))
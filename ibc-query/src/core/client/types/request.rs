@@ -235,3 +235,26 @@ impl From<RawUpgradedConsensusStateRequest> for QueryUpgradedConsensusStateReque
         }
     }
 }
+
+/// Defines the request type for querying the update metadata (processed time
+/// and height) recorded for a client at the given consensus height.
+///
+/// The query has no gRPC counterpart in ibc-go, hence no proto conversion.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClientUpdateMetaRequest {
+    pub client_id: ClientId,
+    pub consensus_height: Height,
+}
+
+/// Defines the request type for querying the update metadata recorded for
+/// every consensus state of a client.
+///
+/// The query has no gRPC counterpart in ibc-go, hence no proto conversion.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClientUpdateMetasRequest {
+    pub client_id: ClientId,
+}
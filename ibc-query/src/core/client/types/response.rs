@@ -1,7 +1,7 @@
 //! Contains all the RPC method response domain types and their conversions to
 //! and from the corresponding gRPC proto types for the client module.
 
-use ibc::core::client::types::{Height, Status};
+use ibc::core::client::types::{Height, Status, UpdateMeta};
 use ibc::core::host::types::identifiers::ClientId;
 use ibc::core::primitives::proto::Any;
 use ibc::primitives::prelude::*;
@@ -390,11 +390,12 @@ impl From<QueryClientStatusResponse> for RawQueryClientStatusResponse {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct QueryClientParamsResponse {
-    pub allowed_clients: Vec<ClientId>,
+    /// The client types allowed to be created, where `*` allows any.
+    pub allowed_clients: Vec<String>,
 }
 
 impl QueryClientParamsResponse {
-    pub fn new(allowed_clients: Vec<ClientId>) -> Self {
+    pub fn new(allowed_clients: Vec<String>) -> Self {
         Self { allowed_clients }
     }
 }
@@ -409,10 +410,7 @@ impl TryFrom<RawQueryClientParamsResponse> for QueryClientParamsResponse {
             allowed_clients: value
                 .params
                 .ok_or_else(|| QueryError::missing_field("params"))?
-                .allowed_clients
-                .into_iter()
-                .map(|id| id.parse())
-                .collect::<Result<_, _>>()?,
+                .allowed_clients,
         })
     }
 }
@@ -421,11 +419,7 @@ impl From<QueryClientParamsResponse> for RawQueryClientParamsResponse {
     fn from(response: QueryClientParamsResponse) -> Self {
         Self {
             params: Some(RawParams {
-                allowed_clients: response
-                    .allowed_clients
-                    .into_iter()
-                    .map(|id| id.to_string())
-                    .collect(),
+                allowed_clients: response.allowed_clients,
             }),
         }
     }
@@ -492,3 +486,35 @@ impl From<QueryUpgradedConsensusStateResponse> for RawQueryUpgradedConsensusStat
         }
     }
 }
+
+/// Defines the response type when querying the update metadata of a client
+/// at a consensus height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClientUpdateMetaResponse {
+    /// The time and height at which the consensus state was processed.
+    pub update_meta: UpdateMeta,
+}
+
+impl QueryClientUpdateMetaResponse {
+    pub fn new(update_meta: UpdateMeta) -> Self {
+        Self { update_meta }
+    }
+}
+
+/// Defines the response type when querying the update metadata of all the
+/// consensus states of a client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryClientUpdateMetasResponse {
+    /// The update metadata, keyed by consensus height.
+    pub update_metas: Vec<(Height, UpdateMeta)>,
+}
+
+impl QueryClientUpdateMetasResponse {
+    pub fn new(update_metas: Vec<(Height, UpdateMeta)>) -> Self {
+        Self { update_metas }
+    }
+}
@@ -1,7 +1,9 @@
 mod query;
+#[cfg(feature = "grpc")]
 mod service;
 mod types;
 
 pub use query::*;
+#[cfg(feature = "grpc")]
 pub use service::*;
 pub use types::*;
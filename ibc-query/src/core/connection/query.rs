@@ -1,6 +1,5 @@
 //! Provides utility functions for querying IBC connection states.
 
-use ibc::core::client::context::ClientValidationContext;
 use ibc::core::host::types::path::{
     ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
 };
@@ -17,6 +16,7 @@ use super::{
 };
 use crate::core::client::IdentifiedClientState;
 use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::height::{decode_connection_ids, QueryHeight};
 use crate::error::QueryError;
 use crate::types::Proof;
 
@@ -28,12 +28,11 @@ pub fn query_connection<I>(
 where
     I: ValidationContext + ProvableContext,
 {
-    let connection_end = ibc_ctx.connection_end(&request.connection_id)?;
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let connection_end = query_height.connection_end(ibc_ctx, &request.connection_id)?;
+
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(
@@ -79,12 +78,16 @@ pub fn query_client_connections<I>(
 where
     I: QueryContext,
 {
-    let connections = ibc_ctx.client_connection_ends(&request.client_id)?;
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
+
+    let connections = query_height.value(
+        ibc_ctx,
+        &Path::ClientConnection(ClientConnectionPath::new(request.client_id.clone())),
+        || Ok(ibc_ctx.client_connection_ends(&request.client_id)?),
+        decode_connection_ids,
+    )?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let proof_height = query_height.height();
 
     let proof: Proof = ibc_ctx
         .get_proof(
@@ -113,16 +116,13 @@ pub fn query_connection_client_state<I>(
 where
     I: QueryContext,
 {
-    let connection_end = ibc_ctx.connection_end(&request.connection_id)?;
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
 
-    let client_val_ctx = ibc_ctx.get_client_validation_context();
+    let connection_end = query_height.connection_end(ibc_ctx, &request.connection_id)?;
 
-    let client_state = client_val_ctx.client_state(connection_end.client_id())?;
+    let client_state = query_height.client_state(ibc_ctx, connection_end.client_id())?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(
@@ -137,7 +137,7 @@ where
         })?;
 
     Ok(QueryConnectionClientStateResponse::new(
-        IdentifiedClientState::new(connection_end.client_id().clone(), client_state.into()),
+        IdentifiedClientState::new(connection_end.client_id().clone(), client_state),
         proof,
         proof_height,
     ))
@@ -152,7 +152,9 @@ where
     I: ValidationContext + ProvableContext,
     ConsensusStateRef<I>: Into<Any>,
 {
-    let connection_end = ibc_ctx.connection_end(&request.connection_id)?;
+    let query_height = QueryHeight::resolve(ibc_ctx, ibc_ctx.host_height()?, request.query_height)?;
+
+    let connection_end = query_height.connection_end(ibc_ctx, &request.connection_id)?;
 
     let consensus_path = ClientConsensusStatePath::new(
         connection_end.client_id().clone(),
@@ -160,14 +162,9 @@ where
         request.height.revision_height(),
     );
 
-    let client_val_ctx = ibc_ctx.get_client_validation_context();
-
-    let consensus_state = client_val_ctx.consensus_state(&consensus_path)?;
+    let consensus_state = query_height.consensus_state(ibc_ctx, &consensus_path)?;
 
-    let proof_height = match request.query_height {
-        Some(height) => height,
-        None => ibc_ctx.host_height()?,
-    };
+    let proof_height = query_height.height();
 
     let proof = ibc_ctx
         .get_proof(proof_height, &Path::ClientConsensusState(consensus_path))
@@ -179,7 +176,7 @@ where
         })?;
 
     Ok(QueryConnectionConsensusStateResponse::new(
-        consensus_state.into(),
+        consensus_state,
         connection_end.client_id().clone(),
         proof,
         proof_height,
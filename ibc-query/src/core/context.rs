@@ -14,7 +14,32 @@ use ibc::core::primitives::prelude::*;
 pub trait ProvableContext {
     /// Returns the proof for the given path at the given height.
     /// As this is in the context of IBC, the path is expected to be an [`IbcPath`](Path).
+    ///
+    /// Queries may request proofs at a past height, in which case the returned
+    /// proof must be against the state committed at that `height`, not the
+    /// latest one.
     fn get_proof(&self, height: Height, path: &Path) -> Option<Vec<u8>>;
+
+    /// Returns `true` if the host retains the committed state at the given
+    /// past height, i.e. it is able to serve both
+    /// [`get_historical_value`](Self::get_historical_value) and
+    /// [`get_proof`](Self::get_proof) at that height.
+    ///
+    /// Defaults to `false`, in which case queries are only served at the latest
+    /// height of the host.
+    fn is_height_retained(&self, _height: &Height) -> bool {
+        false
+    }
+
+    /// Returns the raw value stored under the given path at the given past
+    /// height, or `None` if nothing is stored there.
+    ///
+    /// Values are expected in their store encoding as specified by ICS-24,
+    /// e.g. protobuf for channel and connection ends, `Any` for client and
+    /// consensus states, and big-endian `u64` for sequences.
+    fn get_historical_value(&self, _height: Height, _path: &Path) -> Option<Vec<u8>> {
+        None
+    }
 }
 
 /// Context to be implemented by the host that provides gRPC query services.
@@ -33,6 +58,14 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     /// Returns the list of all heights at which consensus states for the given client are.
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
 
+    /// Returns the client types allowed to be created on the host, where `*`
+    /// allows any client type.
+    ///
+    /// Defaults to the allowed clients of [`ValidationContext::client_params`].
+    fn allowed_clients(&self) -> Result<Vec<String>, ContextError> {
+        Ok(self.client_params()?.allowed_clients)
+    }
+
     // Connection queries
 
     /// Returns the list of all connection ends.
@@ -65,6 +98,13 @@ pub trait QueryContext: ProvableContext + ValidationContext {
         sequences: impl ExactSizeIterator<Item = Sequence>,
     ) -> Result<Vec<PacketState>, ContextError>;
 
+    /// Returns the sequences of all the packets received on the given channel
+    /// end, as recorded by their receipts.
+    fn packet_receipts(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, ContextError>;
+
     /// Filters the packet sequences for the given channel end that are not received.
     fn unreceived_packets(
         &self,
@@ -0,0 +1,249 @@
+//! Resolves the height at which a query is served.
+
+use ibc::core::channel::types::channel::ChannelEnd;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::path::{
+    ChannelEndPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
+};
+use ibc::core::host::{ConsensusStateRef, ValidationContext};
+use ibc::primitives::prelude::*;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc_proto::ibc::core::connection::v1::ClientPaths as RawClientPaths;
+use prost::Message;
+
+use crate::core::context::ProvableContext;
+use crate::error::QueryError;
+
+/// The height at which a query is served, as resolved from the optional
+/// `query_height` field of a request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueryHeight {
+    /// The latest height of the host. Values are read from the current state.
+    Latest(Height),
+    /// A past height retained by the host. Values are read through
+    /// [`ProvableContext::get_historical_value`].
+    Historical(Height),
+}
+
+impl QueryHeight {
+    /// Resolves the `requested` height against the latest height of the host.
+    ///
+    /// Requests without a height are served at `host_height`. Heights ahead of
+    /// the host, or past heights the host does not retain, are rejected with
+    /// [`QueryError::HeightUnavailable`].
+    pub fn resolve<P>(
+        provable_ctx: &P,
+        host_height: Height,
+        requested: Option<Height>,
+    ) -> Result<Self, QueryError>
+    where
+        P: ProvableContext + ?Sized,
+    {
+        match requested {
+            None => Ok(Self::Latest(host_height)),
+            Some(height) if height == host_height => Ok(Self::Latest(height)),
+            Some(height) if height < host_height && provable_ctx.is_height_retained(&height) => {
+                Ok(Self::Historical(height))
+            }
+            Some(height) => Err(QueryError::HeightUnavailable {
+                requested: height,
+                latest: host_height,
+            }),
+        }
+    }
+
+    /// Returns the resolved height, at which the proofs are generated.
+    pub fn height(&self) -> Height {
+        match self {
+            Self::Latest(height) | Self::Historical(height) => *height,
+        }
+    }
+
+    /// Returns the value stored under `path` at the resolved height.
+    ///
+    /// At the latest height the value is read with `latest`; at a historical
+    /// height the raw bytes retained by the host are parsed with `decode`.
+    pub fn value<P, T>(
+        &self,
+        provable_ctx: &P,
+        path: &Path,
+        latest: impl FnOnce() -> Result<T, QueryError>,
+        decode: impl FnOnce(Vec<u8>) -> Result<T, QueryError>,
+    ) -> Result<T, QueryError>
+    where
+        P: ProvableContext + ?Sized,
+    {
+        match self {
+            Self::Latest(_) => latest(),
+            Self::Historical(height) => {
+                let bytes = provable_ctx
+                    .get_historical_value(*height, path)
+                    .ok_or_else(|| {
+                        QueryError::value_not_found(format!("{path} at height {height}"))
+                    })?;
+
+                decode(bytes)
+            }
+        }
+    }
+
+    /// Returns the channel end stored under `path` at the resolved height.
+    pub(crate) fn channel_end<I>(
+        &self,
+        ibc_ctx: &I,
+        path: &ChannelEndPath,
+    ) -> Result<ChannelEnd, QueryError>
+    where
+        I: ValidationContext + ProvableContext,
+    {
+        self.value(
+            ibc_ctx,
+            &Path::ChannelEnd(path.clone()),
+            || Ok(ibc_ctx.channel_end(path)?),
+            |bytes| ChannelEnd::decode_vec(&bytes).map_err(QueryError::invalid_value),
+        )
+    }
+
+    /// Returns the connection end of `connection_id` at the resolved height.
+    pub(crate) fn connection_end<I>(
+        &self,
+        ibc_ctx: &I,
+        connection_id: &ConnectionId,
+    ) -> Result<ConnectionEnd, QueryError>
+    where
+        I: ValidationContext + ProvableContext,
+    {
+        self.value(
+            ibc_ctx,
+            &Path::Connection(ConnectionPath::new(connection_id)),
+            || Ok(ibc_ctx.connection_end(connection_id)?),
+            |bytes| ConnectionEnd::decode_vec(&bytes).map_err(QueryError::invalid_value),
+        )
+    }
+
+    /// Returns the client state of `client_id` at the resolved height.
+    pub(crate) fn client_state<I>(
+        &self,
+        ibc_ctx: &I,
+        client_id: &ClientId,
+    ) -> Result<Any, QueryError>
+    where
+        I: ValidationContext + ProvableContext,
+    {
+        self.value(
+            ibc_ctx,
+            &Path::ClientState(ClientStatePath::new(client_id.clone())),
+            || {
+                Ok(ibc_ctx
+                    .get_client_validation_context()
+                    .client_state(client_id)?
+                    .into())
+            },
+            decode_any,
+        )
+    }
+
+    /// Returns the consensus state stored under `path` at the resolved height.
+    pub(crate) fn consensus_state<I>(
+        &self,
+        ibc_ctx: &I,
+        path: &ClientConsensusStatePath,
+    ) -> Result<Any, QueryError>
+    where
+        I: ValidationContext + ProvableContext,
+        ConsensusStateRef<I>: Into<Any>,
+    {
+        self.value(
+            ibc_ctx,
+            &Path::ClientConsensusState(path.clone()),
+            || {
+                Ok(ibc_ctx
+                    .get_client_validation_context()
+                    .consensus_state(path)?
+                    .into())
+            },
+            decode_any,
+        )
+    }
+}
+
+/// Decodes a client or consensus state stored as a protobuf `Any`.
+pub(crate) fn decode_any(bytes: Vec<u8>) -> Result<Any, QueryError> {
+    Any::decode(bytes.as_slice()).map_err(QueryError::invalid_value)
+}
+
+/// Decodes a sequence stored as a big-endian `u64`.
+pub(crate) fn decode_sequence(bytes: Vec<u8>) -> Result<Sequence, QueryError> {
+    let bytes: [u8; 8] = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| QueryError::invalid_value("sequence must be 8 bytes long"))?;
+
+    Ok(Sequence::from(u64::from_be_bytes(bytes)))
+}
+
+/// Decodes the list of connection ids stored for a client.
+pub(crate) fn decode_connection_ids(bytes: Vec<u8>) -> Result<Vec<ConnectionId>, QueryError> {
+    RawClientPaths::decode(bytes.as_slice())
+        .map_err(QueryError::invalid_value)?
+        .paths
+        .iter()
+        .map(|path| path.parse().map_err(QueryError::from))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Retaining(Height);
+
+    impl ProvableContext for Retaining {
+        fn get_proof(&self, _height: Height, _path: &Path) -> Option<Vec<u8>> {
+            None
+        }
+
+        fn is_height_retained(&self, height: &Height) -> bool {
+            height >= &self.0
+        }
+    }
+
+    #[test]
+    fn test_resolve_query_height() {
+        let height = |h| Height::new(0, h).expect("valid height");
+        let ctx = Retaining(height(5));
+
+        assert_eq!(
+            QueryHeight::resolve(&ctx, height(10), None).unwrap(),
+            QueryHeight::Latest(height(10))
+        );
+        assert_eq!(
+            QueryHeight::resolve(&ctx, height(10), Some(height(10))).unwrap(),
+            QueryHeight::Latest(height(10))
+        );
+        assert_eq!(
+            QueryHeight::resolve(&ctx, height(10), Some(height(7))).unwrap(),
+            QueryHeight::Historical(height(7))
+        );
+        assert!(matches!(
+            QueryHeight::resolve(&ctx, height(10), Some(height(4))),
+            Err(QueryError::HeightUnavailable { .. })
+        ));
+        assert!(matches!(
+            QueryHeight::resolve(&ctx, height(10), Some(height(11))),
+            Err(QueryError::HeightUnavailable { .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_sequence() {
+        assert_eq!(
+            decode_sequence(7u64.to_be_bytes().to_vec()).unwrap(),
+            Sequence::from(7)
+        );
+        assert!(decode_sequence(vec![7]).is_err());
+    }
+}
@@ -2,3 +2,6 @@ pub mod channel;
 pub mod client;
 pub mod connection;
 pub mod context;
+pub mod height;
+#[cfg(feature = "grpc")]
+pub mod services;
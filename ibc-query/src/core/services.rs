@@ -0,0 +1,130 @@
+//! Provides [`IbcQueryServices`], which assembles the client, connection and
+//! channel query services over one shared context into the routes of a gRPC
+//! server:
+//!
+//! ```rust,ignore
+//! IbcQueryServices::new(ibc_context)
+//!     .with_transfer_service(transfer_context)
+//!     .into_router()
+//!     .serve(addr)
+//!     .await?;
+//! ```
+//!
+//! The ICS-20 and ICS-721 query services are added over the contexts of the
+//! applications with [`IbcQueryServices::with_transfer_service`] and
+//! [`IbcQueryServices::with_nft_transfer_service`]. Other services are served
+//! by adding them to the routes returned by [`IbcQueryServices::into_routes`].
+
+use ibc::apps::nft_transfer::context::NftTransferValidationContext;
+use ibc::apps::transfer::context::TokenTransferValidationContext;
+use ibc::core::host::ConsensusStateRef;
+use ibc::cosmos_host::upgrade_proposal::{
+    UpgradeValidationContext, UpgradedClientStateRef, UpgradedConsensusStateRef,
+};
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::nft_transfer::v1::query_server::QueryServer as NftTransferQueryServer;
+use ibc_proto::ibc::applications::transfer::v1::query_server::QueryServer as TransferQueryServer;
+use ibc_proto::ibc::core::channel::v1::query_server::QueryServer as ChannelQueryServer;
+use ibc_proto::ibc::core::client::v1::query_server::QueryServer as ClientQueryServer;
+use ibc_proto::ibc::core::connection::v1::query_server::QueryServer as ConnectionQueryServer;
+use tonic::transport::server::{Router, Routes};
+use tonic::transport::Server;
+
+use crate::apps::nft_transfer::NftTransferQueryService;
+use crate::apps::transfer::TransferQueryService;
+use crate::core::channel::ChannelQueryService;
+use crate::core::client::ClientQueryService;
+use crate::core::connection::ConnectionQueryService;
+use crate::core::context::QueryContext;
+
+/// The routes serving every IBC query service over a shared context.
+#[derive(Debug)]
+pub struct IbcQueryServices {
+    routes: Routes,
+}
+
+impl IbcQueryServices {
+    /// Assembles the client, connection and channel query services over the
+    /// given context, which also answers the upgrade queries.
+    ///
+    /// The parameter `ibc_context` is cloned into each service and must be a
+    /// type where writes from one thread are readable from another. This
+    /// means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
+    pub fn new<I>(ibc_context: I) -> Self
+    where
+        I: QueryContext + UpgradeValidationContext + Clone + Send + Sync + 'static,
+        ConsensusStateRef<I>: Into<Any>,
+        UpgradedClientStateRef<I>: Into<Any>,
+        UpgradedConsensusStateRef<I>: Into<Any>,
+    {
+        let client_service = ClientQueryService::new(ibc_context.clone(), ibc_context.clone());
+        let connection_service = ConnectionQueryService::new(ibc_context.clone());
+        let channel_service = ChannelQueryService::new(ibc_context);
+
+        let routes = Routes::new(ClientQueryServer::new(client_service))
+            .add_service(ConnectionQueryServer::new(connection_service))
+            .add_service(ChannelQueryServer::new(channel_service));
+
+        Self { routes }
+    }
+
+    /// Adds the ICS-20 token transfer query service over the given context.
+    ///
+    /// The parameter `transfer_context` must be a type where writes from one
+    /// thread are readable from another, as the one of [`Self::new`].
+    pub fn with_transfer_service<T>(mut self, transfer_context: T) -> Self
+    where
+        T: TokenTransferValidationContext + Send + Sync + 'static,
+    {
+        let transfer_service = TransferQueryService::new(transfer_context);
+
+        self.routes = self
+            .routes
+            .add_service(TransferQueryServer::new(transfer_service));
+
+        self
+    }
+
+    /// Adds the ICS-721 NFT transfer query service over the given context.
+    ///
+    /// The parameter `nft_transfer_context` must be a type where writes from
+    /// one thread are readable from another, as the one of [`Self::new`].
+    pub fn with_nft_transfer_service<T>(mut self, nft_transfer_context: T) -> Self
+    where
+        T: NftTransferValidationContext + Send + Sync + 'static,
+    {
+        let nft_transfer_service = NftTransferQueryService::new(nft_transfer_context);
+
+        self.routes = self
+            .routes
+            .add_service(NftTransferQueryServer::new(nft_transfer_service));
+
+        self
+    }
+
+    /// Adds the gRPC reflection service, describing the services registered
+    /// in the given encoded `FileDescriptorSet`.
+    #[cfg(feature = "reflection")]
+    pub fn with_reflection(
+        mut self,
+        file_descriptor_set: &'static [u8],
+    ) -> Result<Self, tonic_reflection::server::Error> {
+        let reflection_service = tonic_reflection::server::Builder::configure()
+            .register_encoded_file_descriptor_set(file_descriptor_set)
+            .build()?;
+
+        self.routes = self.routes.add_service(reflection_service);
+
+        Ok(self)
+    }
+
+    /// Returns the routes of the services, to which the host may add its own.
+    pub fn into_routes(self) -> Routes {
+        self.routes
+    }
+
+    /// Returns a gRPC server router serving the services.
+    pub fn into_router(self) -> Router {
+        Server::builder().add_routes(self.routes)
+    }
+}
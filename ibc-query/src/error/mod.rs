@@ -3,10 +3,16 @@ use alloc::string::{String, ToString};
 use displaydoc::Display;
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
 use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::error::IdentifierError;
-use tonic::Status;
+
+#[cfg(feature = "grpc")]
+mod status;
+
+#[cfg(feature = "grpc")]
+pub use status::*;
 
 #[derive(Debug, Display)]
 pub enum QueryError {
@@ -18,6 +24,25 @@ pub enum QueryError {
     ProofNotFound(String),
     /// Missing field: {0}
     MissingField(String),
+    /// Value not found: {0}
+    ValueNotFound(String),
+    /// Invalid stored value: {0}
+    InvalidValue(String),
+    /// Query height `{requested}` is not available; latest height is `{latest}`
+    HeightUnavailable { requested: Height, latest: Height },
+    /// No migration is registered from store version `{version}`
+    MissingMigration { version: u64 },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for QueryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::IdentifierError(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 impl QueryError {
@@ -28,16 +53,13 @@ impl QueryError {
     pub fn missing_field<T: ToString>(description: T) -> Self {
         Self::MissingField(description.to_string())
     }
-}
 
-impl From<QueryError> for Status {
-    fn from(e: QueryError) -> Self {
-        match e {
-            QueryError::ContextError(ctx_err) => Self::internal(ctx_err.to_string()),
-            QueryError::IdentifierError(id_err) => Self::internal(id_err.to_string()),
-            QueryError::ProofNotFound(description) => Self::not_found(description),
-            QueryError::MissingField(description) => Self::invalid_argument(description),
-        }
+    pub fn value_not_found<T: ToString>(description: T) -> Self {
+        Self::ValueNotFound(description.to_string())
+    }
+
+    pub fn invalid_value<T: ToString>(description: T) -> Self {
+        Self::InvalidValue(description.to_string())
     }
 }
 
@@ -0,0 +1,163 @@
+//! Maps the [`QueryError`]s to the gRPC `Status` returned by the query
+//! services.
+
+use alloc::string::ToString;
+
+use ibc::core::channel::types::error::{ChannelError, PacketError};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::connection::types::error::ConnectionError;
+use ibc::core::handler::types::error::ContextError;
+use ibc::primitives::ErrorCode;
+use tonic::{Code, Status};
+
+use super::QueryError;
+
+/// The gRPC metadata key carrying the namespace of the [`ErrorCode`] of a
+/// failed query.
+pub const ERROR_CODESPACE_METADATA_KEY: &str = "ibc-error-codespace";
+
+/// The gRPC metadata key carrying the code of the [`ErrorCode`] of a failed
+/// query.
+pub const ERROR_CODE_METADATA_KEY: &str = "ibc-error-code";
+
+/// Attaches the given [`ErrorCode`] to the metadata of the `Status`.
+fn with_error_code(mut status: Status, error_code: ErrorCode) -> Status {
+    let metadata = status.metadata_mut();
+
+    if let Ok(codespace) = error_code.namespace().parse() {
+        metadata.insert(ERROR_CODESPACE_METADATA_KEY, codespace);
+    }
+    metadata.insert(ERROR_CODE_METADATA_KEY, error_code.code().into());
+
+    status
+}
+
+/// Maps the given context error to a `Status` carrying its message and its
+/// [`ErrorCode`] in the metadata.
+///
+/// Values missing from the store map to `NOT_FOUND`, malformed identifiers to
+/// `INVALID_ARGUMENT`, and any other error to `INTERNAL`.
+pub fn context_error_status(e: ContextError) -> Status {
+    let code = match &e {
+        ContextError::ClientError(e) => client_error_code(e),
+        ContextError::ConnectionError(e) => connection_error_code(e),
+        ContextError::ChannelError(e) => channel_error_code(e),
+        ContextError::PacketError(e) => packet_error_code(e),
+        _ => Code::Internal,
+    };
+
+    with_error_code(Status::new(code, e.to_string()), e.code())
+}
+
+fn client_error_code(e: &ClientError) -> Code {
+    match e {
+        ClientError::ClientStateNotFound { .. }
+        | ClientError::ConsensusStateNotFound { .. }
+        | ClientError::UpdateMetaDataNotFound { .. }
+        | ClientError::MissingLocalConsensusState { .. } => Code::NotFound,
+        ClientError::InvalidClientIdentifier(_)
+        | ClientError::InvalidMsgUpdateClientId(_)
+        | ClientError::InvalidMsgRecoverClientId(_)
+        | ClientError::InvalidHeight => Code::InvalidArgument,
+        _ => Code::Internal,
+    }
+}
+
+fn connection_error_code(e: &ConnectionError) -> Code {
+    match e {
+        ConnectionError::Client(e) => client_error_code(e),
+        ConnectionError::ConnectionNotFound { .. } => Code::NotFound,
+        ConnectionError::InvalidIdentifier(_) => Code::InvalidArgument,
+        _ => Code::Internal,
+    }
+}
+
+fn channel_error_code(e: &ChannelError) -> Code {
+    match e {
+        ChannelError::ChannelNotFound { .. } | ChannelError::MissingChannel => Code::NotFound,
+        ChannelError::InvalidIdentifier(_) | ChannelError::InvalidStringAsSequence { .. } => {
+            Code::InvalidArgument
+        }
+        _ => Code::Internal,
+    }
+}
+
+fn packet_error_code(e: &PacketError) -> Code {
+    match e {
+        PacketError::Connection(e) => connection_error_code(e),
+        PacketError::Channel(e) => channel_error_code(e),
+        PacketError::ChannelNotFound { .. }
+        | PacketError::PacketReceiptNotFound { .. }
+        | PacketError::PacketCommitmentNotFound { .. }
+        | PacketError::PacketAcknowledgementNotFound { .. }
+        | PacketError::MissingNextSendSeq { .. }
+        | PacketError::MissingNextRecvSeq { .. }
+        | PacketError::MissingNextAckSeq { .. } => Code::NotFound,
+        PacketError::InvalidIdentifier(_) | PacketError::ZeroPacketSequence => {
+            Code::InvalidArgument
+        }
+        _ => Code::Internal,
+    }
+}
+
+impl From<QueryError> for Status {
+    fn from(e: QueryError) -> Self {
+        match e {
+            QueryError::ContextError(ctx_err) => context_error_status(ctx_err),
+            QueryError::IdentifierError(id_err) => Self::invalid_argument(id_err.to_string()),
+            QueryError::ProofNotFound(description) => Self::not_found(description),
+            QueryError::MissingField(description) => Self::invalid_argument(description),
+            QueryError::ValueNotFound(description) => Self::not_found(description),
+            QueryError::InvalidValue(description) => Self::internal(description),
+            e @ QueryError::HeightUnavailable { .. } => Self::out_of_range(e.to_string()),
+            e @ QueryError::MissingMigration { .. } => Self::internal(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId};
+
+    use super::*;
+
+    #[test]
+    fn test_context_error_status() {
+        let client_id = ClientId::new("07-tendermint", 0).expect("valid client id");
+        let not_found = ContextError::ClientError(ClientError::ClientStateNotFound {
+            client_id: client_id.clone(),
+        });
+        let message = not_found.to_string();
+        let error_code = not_found.code();
+
+        let status = Status::from(QueryError::ContextError(not_found));
+
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(status.message(), message);
+        assert_eq!(
+            status.metadata().get(ERROR_CODE_METADATA_KEY),
+            Some(&error_code.code().into())
+        );
+
+        let nested =
+            ContextError::PacketError(PacketError::Channel(ChannelError::ChannelNotFound {
+                port_id: PortId::transfer(),
+                channel_id: ChannelId::new(0),
+            }));
+
+        assert_eq!(context_error_status(nested).code(), Code::NotFound);
+
+        let invalid = ContextError::ChannelError(ChannelError::InvalidStringAsSequence {
+            value: "one".to_string(),
+            error: "one".parse::<u64>().expect_err("not a number"),
+        });
+
+        assert_eq!(context_error_status(invalid).code(), Code::InvalidArgument);
+
+        let internal = ContextError::ClientError(ClientError::ClientFrozen {
+            description: "frozen".to_string(),
+        });
+
+        assert_eq!(context_error_status(internal).code(), Code::Internal);
+    }
+}
@@ -0,0 +1,28 @@
+//! Provides [`EventStreamServer`], a gRPC server streaming the IBC events
+//! emitted by a host to its subscribers.
+//!
+//! Hosts feed the events produced by the IBC handlers into the server with
+//! [`EventStreamServer::publish`], and serve it alongside the query services:
+//!
+//! ```rust,ignore
+//! let event_server = EventStreamServer::new(1024);
+//!
+//! let grpc_server = tonic::transport::Server::builder()
+//!       .add_service(event_server.clone())
+//!       .serve(addr);
+//!
+//! // after executing a message
+//! for event in ibc_ctx.events() {
+//!     event_server.publish(event)?;
+//! }
+//! ```
+//!
+//! Clients subscribe through the `ibc.services.events.v1.EventStream/Subscribe`
+//! server-streaming RPC, with a [`SubscribeRequest`] restricting the streamed
+//! events by type, client, port, channel and packet sequence range.
+
+mod server;
+mod types;
+
+pub use server::*;
+pub use types::*;
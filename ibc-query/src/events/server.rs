@@ -0,0 +1,139 @@
+//! Implements the gRPC server of the event stream service.
+
+use core::pin::Pin;
+
+use ibc::core::handler::types::events::{Error as EventError, IbcEvent};
+use ibc::core::handler::types::filter::EventFilter;
+use ibc::primitives::prelude::*;
+use tendermint::abci;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+use tonic::codegen::{empty_body, http, Body, BoxFuture, Context, Poll, StdError};
+use tonic::server::{Grpc, NamedService, ServerStreamingService};
+use tonic::{Code, Request, Response, Status};
+
+use super::{SubscribeRequest, SubscribeResponse};
+
+/// The path of the `Subscribe` RPC.
+pub const SUBSCRIBE_PATH: &str = "/ibc.services.events.v1.EventStream/Subscribe";
+
+/// The stream of events returned to a subscriber.
+pub type EventStream = Pin<Box<dyn Stream<Item = Result<SubscribeResponse, Status>> + Send>>;
+
+/// A gRPC server streaming the IBC events published by the host to its
+/// subscribers.
+///
+/// Cloning the server yields a handle to the same event feed, so that one
+/// clone can be handed to the gRPC server while the host publishes events
+/// through another.
+#[derive(Clone, Debug)]
+pub struct EventStreamServer {
+    sender: broadcast::Sender<abci::Event>,
+}
+
+impl EventStreamServer {
+    /// Creates a new server buffering up to `capacity` events per subscriber.
+    ///
+    /// Subscribers falling behind by more than `capacity` events have their
+    /// stream terminated with a `DATA_LOSS` status.
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+
+        Self { sender }
+    }
+
+    /// Publishes an IBC event to all current subscribers.
+    pub fn publish(&self, event: IbcEvent) -> Result<(), EventError> {
+        self.publish_abci(event.try_into()?);
+
+        Ok(())
+    }
+
+    /// Publishes an IBC event, already converted to its ABCI representation,
+    /// to all current subscribers.
+    pub fn publish_abci(&self, event: abci::Event) {
+        // sending only fails when there are no subscribers
+        let _ = self.sender.send(event);
+    }
+
+    /// Returns the number of active subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    /// Returns the stream of published events matching the given filter.
+    pub fn subscribe(&self, filter: EventFilter) -> EventStream {
+        let stream =
+            BroadcastStream::new(self.sender.subscribe()).filter_map(move |event| match event {
+                Ok(event) if filter.matches_abci(&event) => Some(Ok(event.into())),
+                Ok(_) => None,
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => Some(Err(Status::data_loss(
+                    format!("subscriber lagged behind, {skipped} events were dropped"),
+                ))),
+            });
+
+        Box::pin(stream)
+    }
+}
+
+impl NamedService for EventStreamServer {
+    const NAME: &'static str = "ibc.services.events.v1.EventStream";
+}
+
+struct SubscribeSvc(EventStreamServer);
+
+impl ServerStreamingService<SubscribeRequest> for SubscribeSvc {
+    type Response = SubscribeResponse;
+    type ResponseStream = EventStream;
+    type Future = BoxFuture<Response<Self::ResponseStream>, Status>;
+
+    fn call(&mut self, request: Request<SubscribeRequest>) -> Self::Future {
+        let server = self.0.clone();
+
+        Box::pin(async move {
+            let filter = EventFilter::try_from(request.into_inner())?;
+
+            Ok(Response::new(server.subscribe(filter)))
+        })
+    }
+}
+
+impl<B> tonic::codegen::Service<http::Request<B>> for EventStreamServer
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = core::convert::Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        if request.uri().path() != SUBSCRIBE_PATH {
+            return Box::pin(async move {
+                let mut response = http::Response::new(empty_body());
+                let headers = response.headers_mut();
+                headers.insert("grpc-status", (Code::Unimplemented as i32).into());
+                headers.insert(
+                    http::header::CONTENT_TYPE,
+                    tonic::metadata::GRPC_CONTENT_TYPE,
+                );
+
+                Ok(response)
+            });
+        }
+
+        let service = SubscribeSvc(self.clone());
+
+        Box::pin(async move {
+            let mut grpc = Grpc::new(tonic::codec::ProstCodec::default());
+
+            Ok(grpc.server_streaming(service, request).await)
+        })
+    }
+}
@@ -0,0 +1,132 @@
+//! Defines the protobuf messages of the event stream service.
+
+use ibc::core::handler::types::filter::EventFilter;
+use ibc::core::host::types::identifiers::Sequence;
+use ibc::primitives::prelude::*;
+use tendermint::abci;
+
+use crate::error::QueryError;
+
+/// Request to subscribe to the IBC events of a host.
+///
+/// Empty fields match any event.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeRequest {
+    /// The event types to stream, e.g. `send_packet`.
+    #[prost(string, repeated, tag = "1")]
+    pub event_types: Vec<String>,
+    #[prost(string, tag = "2")]
+    pub client_id: String,
+    #[prost(string, tag = "3")]
+    pub port_id: String,
+    #[prost(string, tag = "4")]
+    pub channel_id: String,
+    /// The lowest packet sequence to stream, inclusive.
+    #[prost(uint64, optional, tag = "5")]
+    pub min_sequence: Option<u64>,
+    /// The highest packet sequence to stream, inclusive.
+    #[prost(uint64, optional, tag = "6")]
+    pub max_sequence: Option<u64>,
+}
+
+impl TryFrom<SubscribeRequest> for EventFilter {
+    type Error = QueryError;
+
+    fn try_from(request: SubscribeRequest) -> Result<Self, Self::Error> {
+        let mut filter = request
+            .event_types
+            .into_iter()
+            .fold(EventFilter::new(), |filter, event_type| {
+                filter.with_event_type(event_type)
+            });
+
+        if !request.client_id.is_empty() {
+            filter = filter.with_client_id(request.client_id.parse()?);
+        }
+        if !request.port_id.is_empty() {
+            filter = filter.with_port_id(request.port_id.parse()?);
+        }
+        if !request.channel_id.is_empty() {
+            filter = filter.with_channel_id(request.channel_id.parse()?);
+        }
+
+        Ok(filter.with_sequence_range(
+            request.min_sequence.map(Sequence::from),
+            request.max_sequence.map(Sequence::from),
+        ))
+    }
+}
+
+/// An IBC event streamed to a subscriber, in its ABCI representation.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubscribeResponse {
+    #[prost(string, tag = "1")]
+    pub r#type: String,
+    #[prost(message, repeated, tag = "2")]
+    pub attributes: Vec<EventAttribute>,
+}
+
+impl From<abci::Event> for SubscribeResponse {
+    fn from(event: abci::Event) -> Self {
+        Self {
+            r#type: event.kind,
+            attributes: event.attributes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// An attribute of a streamed event.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct EventAttribute {
+    #[prost(string, tag = "1")]
+    pub key: String,
+    #[prost(string, tag = "2")]
+    pub value: String,
+    #[prost(bool, tag = "3")]
+    pub index: bool,
+}
+
+impl From<abci::EventAttribute> for EventAttribute {
+    fn from(attribute: abci::EventAttribute) -> Self {
+        Self {
+            key: String::from_utf8_lossy(attribute.key_bytes()).into_owned(),
+            value: String::from_utf8_lossy(attribute.value_bytes()).into_owned(),
+            index: attribute.index(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscribe_response_from_abci_event() {
+        let event = abci::Event::new(
+            "send_packet",
+            [
+                ("packet_sequence", "1", true),
+                ("packet_data_hex", "0a0b", false),
+            ],
+        );
+
+        let response = SubscribeResponse::from(event);
+
+        assert_eq!(response.r#type, "send_packet");
+        assert_eq!(
+            response.attributes,
+            vec![
+                EventAttribute {
+                    key: "packet_sequence".to_string(),
+                    value: "1".to_string(),
+                    index: true,
+                },
+                EventAttribute {
+                    key: "packet_data_hex".to_string(),
+                    value: "0a0b".to_string(),
+                    index: false,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! Provides functions auditing the IBC state of a host for consistency.
+//!
+//! The checks only read the state through the [`QueryContext`], and are meant
+//! to be run in tests and in the upgrade handlers of a chain, e.g. before and
+//! after migrating its IBC store.
+
+use displaydoc::Display;
+use ibc::apps::transfer::types::{Amount, PrefixedDenom};
+use ibc::core::channel::types::channel::State as ChannelState;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::Height;
+use ibc::core::connection::localhost::connection_end;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc::core::host::ValidationContext;
+use ibc::primitives::prelude::*;
+
+use crate::core::context::QueryContext;
+use crate::error::QueryError;
+
+/// A violation of an invariant of the IBC state.
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// channel `{port_id}/{channel_id}` references the missing connection `{connection_id}`
+    MissingConnection {
+        port_id: PortId,
+        channel_id: ChannelId,
+        connection_id: ConnectionId,
+    },
+    /// open channel `{port_id}/{channel_id}` references the connection `{connection_id}` which is not open
+    ConnectionNotOpen {
+        port_id: PortId,
+        channel_id: ChannelId,
+        connection_id: ConnectionId,
+    },
+    /// packet commitment `{sequence}` on channel `{port_id}/{channel_id}` is not below the next send sequence `{next_sequence_send}`
+    CommitmentAheadOfSendSequence {
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        next_sequence_send: Sequence,
+    },
+    /// consensus state of client `{client_id}` at height `{height}` has no update metadata
+    MissingUpdateMeta { client_id: ClientId, height: Height },
+    /// escrowed amount `{escrowed}` of `{denom}` does not match the tracked total `{tracked}`
+    EscrowMismatch {
+        denom: PrefixedDenom,
+        tracked: Amount,
+        escrowed: Amount,
+    },
+}
+
+/// Runs all the checks on the core IBC state of the host and returns the
+/// violations found.
+pub fn check_core_invariants<I>(ibc_ctx: &I) -> Result<Vec<InvariantViolation>, QueryError>
+where
+    I: QueryContext,
+{
+    let mut violations = check_channel_connections(ibc_ctx)?;
+    violations.extend(check_packet_commitment_sequences(ibc_ctx)?);
+    violations.extend(check_consensus_state_update_meta(ibc_ctx)?);
+
+    Ok(violations)
+}
+
+/// Checks that every channel references an existing connection, which is open
+/// if the channel is. The `connection-localhost` sentinel always exists.
+pub fn check_channel_connections<I>(ibc_ctx: &I) -> Result<Vec<InvariantViolation>, QueryError>
+where
+    I: QueryContext,
+{
+    let mut violations = Vec::new();
+
+    for channel in ibc_ctx.channel_ends()? {
+        for connection_id in channel.channel_end.connection_hops() {
+            match connection_end(ibc_ctx, connection_id) {
+                Ok(connection_end) => {
+                    if *channel.channel_end.state() == ChannelState::Open
+                        && !connection_end.is_open()
+                    {
+                        violations.push(InvariantViolation::ConnectionNotOpen {
+                            port_id: channel.port_id.clone(),
+                            channel_id: channel.channel_id.clone(),
+                            connection_id: connection_id.clone(),
+                        });
+                    }
+                }
+                Err(_) => violations.push(InvariantViolation::MissingConnection {
+                    port_id: channel.port_id.clone(),
+                    channel_id: channel.channel_id.clone(),
+                    connection_id: connection_id.clone(),
+                }),
+            }
+        }
+    }
+
+    Ok(violations)
+}
+
+/// Checks that the sequence of every packet commitment is below the next send
+/// sequence of its channel.
+pub fn check_packet_commitment_sequences<I>(
+    ibc_ctx: &I,
+) -> Result<Vec<InvariantViolation>, QueryError>
+where
+    I: QueryContext,
+{
+    let mut violations = Vec::new();
+
+    for channel in ibc_ctx.channel_ends()? {
+        let next_sequence_send = ibc_ctx
+            .get_next_sequence_send(&SeqSendPath::new(&channel.port_id, &channel.channel_id))?;
+
+        let commitments = ibc_ctx
+            .packet_commitments(&ChannelEndPath::new(&channel.port_id, &channel.channel_id))?;
+
+        violations.extend(
+            commitments
+                .into_iter()
+                .filter(|commitment| commitment.seq >= next_sequence_send)
+                .map(
+                    |commitment| InvariantViolation::CommitmentAheadOfSendSequence {
+                        port_id: channel.port_id.clone(),
+                        channel_id: channel.channel_id.clone(),
+                        sequence: commitment.seq,
+                        next_sequence_send,
+                    },
+                ),
+        );
+    }
+
+    Ok(violations)
+}
+
+/// Checks that the update metadata is recorded for every consensus state of
+/// every client.
+pub fn check_consensus_state_update_meta<I>(
+    ibc_ctx: &I,
+) -> Result<Vec<InvariantViolation>, QueryError>
+where
+    I: QueryContext,
+{
+    let client_val_ctx = ibc_ctx.get_client_validation_context();
+
+    let mut violations = Vec::new();
+
+    for (client_id, _) in ibc_ctx.client_states()? {
+        for height in ibc_ctx.consensus_state_heights(&client_id)? {
+            if client_val_ctx
+                .client_update_meta(&client_id, &height)
+                .is_err()
+            {
+                violations.push(InvariantViolation::MissingUpdateMeta {
+                    client_id: client_id.clone(),
+                    height,
+                });
+            }
+        }
+    }
+
+    Ok(violations)
+}
+
+/// Checks that the total escrowed amount tracked for each denomination matches
+/// the balance of the escrow accounts, as returned by `escrowed`.
+///
+/// The bank state is owned by the host, hence the escrowed balances are
+/// provided by the caller rather than read from the [`QueryContext`].
+pub fn check_escrow_totals(
+    tracked_totals: impl IntoIterator<Item = (PrefixedDenom, Amount)>,
+    escrowed: impl Fn(&PrefixedDenom) -> Amount,
+) -> Vec<InvariantViolation> {
+    tracked_totals
+        .into_iter()
+        .filter_map(|(denom, tracked)| {
+            let escrowed = escrowed(&denom);
+
+            (tracked != escrowed).then_some(InvariantViolation::EscrowMismatch {
+                denom,
+                tracked,
+                escrowed,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_escrow_totals() {
+        let atom: PrefixedDenom = "uatom".parse().expect("valid denom");
+        let osmo: PrefixedDenom = "uosmo".parse().expect("valid denom");
+
+        let tracked = vec![(atom.clone(), Amount::from(100u64)), (osmo, 50u64.into())];
+
+        let violations = check_escrow_totals(tracked, |denom| {
+            if denom == &atom {
+                100u64.into()
+            } else {
+                40u64.into()
+            }
+        });
+
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            &violations[0],
+            InvariantViolation::EscrowMismatch { tracked, escrowed, .. }
+                if *tracked == 50u64.into() && *escrowed == 40u64.into()
+        ));
+    }
+}
@@ -51,6 +51,17 @@
 //!       .serve(addr);
 //! ```
 //!
+//! The gRPC services are enabled by the `grpc` feature. Without it, the crate
+//! does not depend on `tonic`, and hosts exposing another interface, e.g.
+//! JSON-RPC or REST, call the query functions backing the services directly:
+//!
+//! ```rust,ignore
+//! use ibc_query::core::connection::{query_connection, QueryConnectionRequest};
+//!
+//! let request = QueryConnectionRequest::try_from(raw_request)?;
+//! let response = query_connection(&ibc, &request)?;
+//! ```
+//!
 
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
 #![no_std]
@@ -69,7 +80,14 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "grpc")]
+pub mod apps;
 pub mod core;
 pub mod error;
+#[cfg(feature = "event-stream")]
+pub mod events;
+pub mod invariants;
+pub mod migrations;
 pub mod types;
+#[cfg(feature = "grpc")]
 pub mod utils;
@@ -0,0 +1,356 @@
+//! Provides a framework for migrating the IBC state of a host across versions
+//! of its store layout, along with the migrations mirroring the ones of
+//! ibc-go.
+//!
+//! The version of the IBC store is owned by the host, e.g. the consensus
+//! version of the IBC module of a Cosmos SDK chain. On upgrade, the host runs
+//! the registered migrations from its current version with a [`Migrator`] and
+//! persists the returned version.
+
+use ibc::core::client::context::prelude::*;
+use ibc::core::client::types::params::ClientParams;
+use ibc::core::client::types::{Height, UpdateMeta};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::primitives::prelude::*;
+
+use crate::core::context::QueryContext;
+use crate::error::QueryError;
+
+/// The client type of the legacy localhost client, removed from ibc-go v7.
+pub const LEGACY_LOCALHOST_CLIENT_TYPE: &str = "09-localhost";
+
+/// Context required by the migrations provided by this module.
+///
+/// Extends the [`QueryContext`] and the [`ExecutionContext`] with the writes
+/// which only happen when migrating the store, and are therefore not part of
+/// the handlers' contexts.
+pub trait MigrationContext: QueryContext + ExecutionContext {
+    /// Deletes the client state of the given client from the store.
+    fn delete_client_state(&mut self, client_id: &ClientId) -> Result<(), ContextError>;
+
+    /// Stores the list of client types allowed to be created on the host,
+    /// as returned by [`QueryContext::allowed_clients`].
+    ///
+    /// Defaults to storing them as the client parameters of the host, with
+    /// [`ExecutionContext::store_client_params`].
+    fn store_allowed_clients(&mut self, allowed_clients: Vec<String>) -> Result<(), ContextError> {
+        self.store_client_params(ClientParams::new(allowed_clients))
+    }
+}
+
+/// A migration of the IBC state from one version of the store to the next.
+pub trait Migration<Ctx> {
+    /// Returns a human-readable name of the migration, used for logging.
+    fn name(&self) -> &'static str;
+
+    /// Returns the version of the store this migration applies to. Once
+    /// applied, the store is at version `from_version() + 1`.
+    fn from_version(&self) -> u64;
+
+    /// Transforms the state of the store.
+    fn migrate(&self, ctx: &mut Ctx) -> Result<(), QueryError>;
+}
+
+/// Applies registered [`Migration`]s in version order.
+pub struct Migrator<'a, Ctx> {
+    migrations: Vec<Box<dyn Migration<Ctx> + 'a>>,
+}
+
+impl<Ctx> Default for Migrator<'_, Ctx> {
+    fn default() -> Self {
+        Self {
+            migrations: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Ctx> Migrator<'a, Ctx> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a migration, replacing any migration previously registered
+    /// from the same version.
+    pub fn register(mut self, migration: impl Migration<Ctx> + 'a) -> Self {
+        self.migrations
+            .retain(|m| m.from_version() != migration.from_version());
+        self.migrations.push(Box::new(migration));
+        self.migrations.sort_by_key(|m| m.from_version());
+        self
+    }
+
+    /// Returns the version of the store once all the registered migrations
+    /// are applied, or `None` if no migration is registered.
+    pub fn latest_version(&self) -> Option<u64> {
+        self.migrations.last().map(|m| m.from_version() + 1)
+    }
+
+    /// Applies, in order, all the registered migrations from the
+    /// `current_version` of the store, and returns the new version to be
+    /// persisted by the host.
+    ///
+    /// Fails without touching the store if the registered migrations don't
+    /// cover every version from `current_version` on. Migrations are not
+    /// atomic: if one of them fails, the host is expected to discard the
+    /// changes made to its store, e.g. by aborting the upgrade.
+    pub fn run(&self, ctx: &mut Ctx, current_version: u64) -> Result<u64, QueryError> {
+        let pending: Vec<_> = self
+            .migrations
+            .iter()
+            .filter(|m| m.from_version() >= current_version)
+            .collect();
+
+        let mut version = current_version;
+        for migration in &pending {
+            if migration.from_version() != version {
+                return Err(QueryError::MissingMigration { version });
+            }
+            version += 1;
+        }
+
+        for migration in pending {
+            migration.migrate(ctx)?;
+        }
+
+        Ok(version)
+    }
+}
+
+/// Removes the legacy localhost clients from the store, along with their
+/// consensus states and update metadata.
+///
+/// Mirrors the pruning of the localhost clients in the migration to ibc-go v7.
+#[derive(Clone, Copy, Debug)]
+pub struct PruneLegacyLocalhostClients {
+    pub from_version: u64,
+}
+
+impl<Ctx> Migration<Ctx> for PruneLegacyLocalhostClients
+where
+    Ctx: MigrationContext,
+{
+    fn name(&self) -> &'static str {
+        "prune legacy localhost clients"
+    }
+
+    fn from_version(&self) -> u64 {
+        self.from_version
+    }
+
+    fn migrate(&self, ctx: &mut Ctx) -> Result<(), QueryError> {
+        let localhost_client_ids: Vec<ClientId> = ctx
+            .client_states()?
+            .into_iter()
+            .filter(|(_, client_state)| {
+                client_state.client_type().as_str() == LEGACY_LOCALHOST_CLIENT_TYPE
+            })
+            .map(|(client_id, _)| client_id)
+            .collect();
+
+        for client_id in localhost_client_ids {
+            let heights = ctx.consensus_state_heights(&client_id)?;
+
+            let client_exec_ctx = ctx.get_client_execution_context();
+            for height in heights {
+                client_exec_ctx.delete_consensus_state(ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    height.revision_number(),
+                    height.revision_height(),
+                ))?;
+                client_exec_ctx.delete_update_meta(client_id.clone(), height)?;
+                client_exec_ctx.unindex_consensus_state_processed_time(&client_id, &height)?;
+            }
+
+            ctx.delete_client_state(&client_id)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds the given client types to the allowed clients of the host, e.g. the
+/// localhost client type.
+///
+/// Mirrors the addition of the localhost client to the allowed clients in the
+/// migration to ibc-go v7.1.
+#[derive(Clone, Debug)]
+pub struct AddAllowedClients {
+    pub from_version: u64,
+    pub client_types: Vec<String>,
+}
+
+impl<Ctx> Migration<Ctx> for AddAllowedClients
+where
+    Ctx: MigrationContext,
+{
+    fn name(&self) -> &'static str {
+        "add allowed clients"
+    }
+
+    fn from_version(&self) -> u64 {
+        self.from_version
+    }
+
+    fn migrate(&self, ctx: &mut Ctx) -> Result<(), QueryError> {
+        let mut allowed_clients = ctx.allowed_clients()?;
+
+        for client_type in &self.client_types {
+            if !allowed_clients.contains(client_type) {
+                allowed_clients.push(client_type.clone());
+            }
+        }
+
+        ctx.store_allowed_clients(allowed_clients)?;
+
+        Ok(())
+    }
+}
+
+/// Moves the client parameters of the host into the IBC store.
+///
+/// The legacy parameters are owned by the host, e.g. by the `x/params` module
+/// of a Cosmos SDK chain, hence they are provided by the caller rather than
+/// read from the store. Mirrors the migration of the parameters to ibc-go v8.
+#[derive(Clone, Debug)]
+pub struct MigrateParams {
+    pub from_version: u64,
+    pub allowed_clients: Vec<String>,
+}
+
+impl<Ctx> Migration<Ctx> for MigrateParams
+where
+    Ctx: MigrationContext,
+{
+    fn name(&self) -> &'static str {
+        "migrate params"
+    }
+
+    fn from_version(&self) -> u64 {
+        self.from_version
+    }
+
+    fn migrate(&self, ctx: &mut Ctx) -> Result<(), QueryError> {
+        ctx.store_allowed_clients(self.allowed_clients.clone())?;
+
+        Ok(())
+    }
+}
+
+/// Stores the update metadata of the consensus states missing it, using the
+/// current height and timestamp of the host.
+///
+/// Mirrors the backfilling of the consensus metadata in the migration to
+/// ibc-go v4. Once applied,
+/// [`check_consensus_state_update_meta`](crate::invariants::check_consensus_state_update_meta)
+/// reports no violation.
+#[derive(Clone, Copy, Debug)]
+pub struct BackfillConsensusStateUpdateMeta {
+    pub from_version: u64,
+}
+
+impl<Ctx> Migration<Ctx> for BackfillConsensusStateUpdateMeta
+where
+    Ctx: MigrationContext,
+{
+    fn name(&self) -> &'static str {
+        "backfill consensus state update metadata"
+    }
+
+    fn from_version(&self) -> u64 {
+        self.from_version
+    }
+
+    fn migrate(&self, ctx: &mut Ctx) -> Result<(), QueryError> {
+        let host_timestamp = ValidationContext::host_timestamp(ctx)?;
+        let host_height = ValidationContext::host_height(ctx)?;
+
+        let mut missing: Vec<(ClientId, Height)> = Vec::new();
+        {
+            let client_val_ctx = ctx.get_client_validation_context();
+            for (client_id, _) in ctx.client_states()? {
+                for height in ctx.consensus_state_heights(&client_id)? {
+                    if client_val_ctx
+                        .client_update_meta(&client_id, &height)
+                        .is_err()
+                    {
+                        missing.push((client_id.clone(), height));
+                    }
+                }
+            }
+        }
+
+        let client_exec_ctx = ctx.get_client_execution_context();
+        for (client_id, height) in missing {
+            client_exec_ctx.store_update_meta(
+                client_id,
+                height,
+                UpdateMeta::new(host_timestamp, host_height),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct RecordVersion(u64);
+
+    impl Migration<Vec<u64>> for RecordVersion {
+        fn name(&self) -> &'static str {
+            "record version"
+        }
+
+        fn from_version(&self) -> u64 {
+            self.0
+        }
+
+        fn migrate(&self, ctx: &mut Vec<u64>) -> Result<(), QueryError> {
+            ctx.push(self.0);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_migrator_applies_in_version_order() {
+        let migrator = Migrator::new()
+            .register(RecordVersion(3))
+            .register(RecordVersion(1))
+            .register(RecordVersion(2));
+
+        assert_eq!(migrator.latest_version(), Some(4));
+
+        let mut applied = Vec::new();
+        let version = migrator.run(&mut applied, 2).expect("no error");
+
+        assert_eq!(version, 4);
+        assert_eq!(applied, vec![2, 3]);
+
+        let mut applied = Vec::new();
+        let version = migrator.run(&mut applied, 4).expect("no error");
+
+        assert_eq!(version, 4);
+        assert!(applied.is_empty());
+    }
+
+    #[test]
+    fn test_migrator_fails_on_missing_version() {
+        let migrator = Migrator::new()
+            .register(RecordVersion(1))
+            .register(RecordVersion(3));
+
+        let mut applied = Vec::new();
+        let result = migrator.run(&mut applied, 1);
+
+        assert!(matches!(
+            result,
+            Err(QueryError::MissingMigration { version: 2 })
+        ));
+        assert!(applied.is_empty());
+    }
+}
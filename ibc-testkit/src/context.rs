@@ -7,22 +7,24 @@ use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::PacketCommitment;
 use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::client::context::{ClientExecutionContext, ClientValidationContext};
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{Height, UpdateMeta};
 use ibc::core::connection::types::ConnectionEnd;
-use ibc::core::entrypoint::{dispatch, execute, validate};
+use ibc::core::entrypoint::{dispatch, execute, validate, SimulationContext};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::log::HandlerLog;
 use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::handler::types::output::HandlerOutput;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, ConnectionPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    Path, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::primitives::prelude::*;
 use ibc::primitives::Timestamp;
 
-use super::testapp::ibc::core::types::{LightClientState, MockIbcStore};
+use super::testapp::ibc::core::types::{LightClientState, MockIbcStore, MockIbcStoreSnapshot};
 use crate::fixtures::core::context::TestContextConfig;
 use crate::hosts::{HostClientState, MockHost, TendermintHost, TestBlock, TestHeader, TestHost};
 use crate::relayer::error::RelayerError;
@@ -52,6 +54,15 @@ where
     pub ibc_router: MockRouter,
 }
 
+/// A copy of the state of a [`StoreGenericTestContext`], taken with
+/// [`StoreGenericTestContext::snapshot`].
+#[derive(Clone, Debug)]
+pub struct TestContextSnapshot<S, H> {
+    multi_store: S,
+    host: H,
+    ibc_store: MockIbcStoreSnapshot<S>,
+}
+
 /// A mock store type using basecoin-storage implementations.
 pub type MockStore = InMemoryStore;
 /// A [`StoreGenericTestContext`] using [`MockStore`].
@@ -108,6 +119,12 @@ where
         self.host.get_block(target_height)
     }
 
+    /// Returns a proof of the IBC state of the host chain at the given height,
+    /// in the format verified by the light clients of the host.
+    pub fn commitment_proof(&self, height: &Height, path: &Path) -> Option<Vec<u8>> {
+        self.host.commitment_proof(&self.ibc_store, height, path)
+    }
+
     /// Returns the latest block from the host chain.
     pub fn query_latest_block(&self) -> Option<H::Block> {
         self.host.get_block(&self.latest_height())
@@ -150,13 +167,16 @@ where
 
         // commit multi store
         let multi_store_commitment = self.multi_store.commit().expect("no error");
+        let commitment_root = self
+            .host
+            .commitment_root(&self.ibc_store, multi_store_commitment);
 
         // generate a genesis block
         // this is basically self.host.produce_block() but with
         // block height 1 and block timestamp `genesis_time`.
-        let genesis_block =
-            self.host
-                .generate_block(multi_store_commitment, 1, genesis_time, params);
+        let genesis_block = self
+            .host
+            .generate_block(commitment_root, 1, genesis_time, params);
 
         // push the genesis block to the host
         self.host.push_block(genesis_block);
@@ -224,9 +244,11 @@ where
     pub fn commit_state_to_host(&mut self, block_time: Duration, params: &H::BlockParams) {
         // commit the multi store
         let multi_store_commitment = self.multi_store.commit().expect("no error");
+        let commitment_root = self
+            .host
+            .commitment_root(&self.ibc_store, multi_store_commitment);
         // generate a new block and add it to the block history
-        self.host
-            .commit_block(multi_store_commitment, block_time, params);
+        self.host.commit_block(commitment_root, block_time, params);
     }
 
     /// Advances the host chain height by ending the current block, producing a new block, and
@@ -249,6 +271,31 @@ where
         )
     }
 
+    /// Advances the host chain by the given number of blocks, using default
+    /// parameters.
+    pub fn advance_blocks(&mut self, count: u64) {
+        for _ in 0..count {
+            self.advance_block_height();
+        }
+    }
+
+    /// Produces the next block `elapsed` after the latest one, e.g. to
+    /// simulate a chain halt or a long downtime before client expiry.
+    pub fn advance_block_after(&mut self, elapsed: Duration) {
+        self.advance_block_height_with_params(elapsed, &Default::default())
+    }
+
+    /// Produces the next block at the given timestamp, which must be after
+    /// the latest one.
+    pub fn advance_time_up_to(&mut self, target_timestamp: Timestamp) {
+        let elapsed = target_timestamp
+            .duration_since(&self.latest_timestamp())
+            .filter(|elapsed| !elapsed.is_zero())
+            .expect("Cannot rewind the time of the chain!");
+
+        self.advance_block_after(elapsed)
+    }
+
     /// Returns the latest height of the host chain.
     pub fn latest_height(&self) -> Height {
         let latest_ibc_height = self.ibc_store.host_height().expect("Never fails");
@@ -357,8 +404,7 @@ where
                 .store_update_meta(
                     client_id.clone(),
                     height,
-                    self.latest_timestamp(),
-                    self.latest_height(),
+                    UpdateMeta::new(self.latest_timestamp(), self.latest_height()),
                 )
                 .expect("error writing to store");
         }
@@ -468,12 +514,12 @@ where
     }
 
     /// Calls [`execute`] function on [`MsgEnvelope`] using the context's IBC store and router.
-    pub fn execute(&mut self, msg: MsgEnvelope) -> Result<(), ContextError> {
+    pub fn execute(&mut self, msg: MsgEnvelope) -> Result<HandlerOutput, ContextError> {
         execute(&mut self.ibc_store, &mut self.ibc_router, msg)
     }
 
     /// Calls [`dispatch`] function on [`MsgEnvelope`] using the context's IBC store and router.
-    pub fn dispatch(&mut self, msg: MsgEnvelope) -> Result<(), ContextError> {
+    pub fn dispatch(&mut self, msg: MsgEnvelope) -> Result<HandlerOutput, ContextError> {
         dispatch(&mut self.ibc_store, &mut self.ibc_router, msg)
     }
 
@@ -488,20 +534,70 @@ where
         Ok(())
     }
 
+    /// Takes a snapshot of the host chain and its stores, which
+    /// [`restore`](Self::restore) reverts the context to. Tests may restore
+    /// the same snapshot several times to branch execution, e.g. to exercise
+    /// both the acknowledgement and the timeout of a packet.
+    ///
+    /// The state of the IBC applications in the router is not part of the
+    /// snapshot.
+    pub fn snapshot(&self) -> TestContextSnapshot<S, H>
+    where
+        S: Clone,
+        H: Clone,
+    {
+        TestContextSnapshot {
+            multi_store: self.multi_store.clone(),
+            host: self.host.clone(),
+            ibc_store: self.ibc_store.snapshot(),
+        }
+    }
+
+    /// Reverts the host chain and its stores to the given snapshot.
+    pub fn restore(&mut self, snapshot: &TestContextSnapshot<S, H>)
+    where
+        S: Clone,
+        H: Clone,
+    {
+        self.multi_store = snapshot.multi_store.clone();
+        self.host = snapshot.host.clone();
+        self.ibc_store.restore(&snapshot.ibc_store);
+    }
+
     /// Returns all the events that have been emitted by the context's IBC store.
     pub fn get_events(&self) -> Vec<IbcEvent> {
         self.ibc_store.events.lock().clone()
     }
 
     /// Returns all the logs that have been emitted by the context's IBC store.
-    pub fn get_logs(&self) -> Vec<String> {
+    pub fn get_logs(&self) -> Vec<HandlerLog> {
         self.ibc_store.logs.lock().clone()
     }
 }
 
+/// Simulates messages over a [branch](MockIbcStore::branch) of the IBC store.
+///
+/// The modules of the router of the context cannot be branched, so the
+/// applications of the branch are the stateless ones of
+/// [`MockRouter::new_with_transfer`].
+impl<S, H> SimulationContext for StoreGenericTestContext<S, H>
+where
+    S: ProvableStore + Debug + Clone,
+    H: TestHost,
+    HostClientState<H>: ClientStateValidation<MockIbcStore<S>>,
+{
+    type Branch = MockIbcStore<S>;
+    type Router = MockRouter;
+
+    fn branch(&self) -> Result<(Self::Branch, Self::Router), ContextError> {
+        Ok((self.ibc_store.branch(), MockRouter::new_with_transfer()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ibc::core::client::context::consensus_state::ConsensusState;
+    use ibc::core::client::types::Status;
 
     use super::*;
     use crate::hosts::{HostConsensusState, MockHost, TendermintHost};
@@ -609,4 +705,63 @@ mod tests {
         run_tests::<MockHost>("Mock Host");
         run_tests::<TendermintHost>("Synthetic TM Host");
     }
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let client_id = ClientId::new("9999-mock", 0).expect("no error");
+
+        let mut ctx = MockContext::default();
+        let height = ctx.latest_height();
+        let timestamp = ctx.latest_timestamp();
+        let snapshot = ctx.snapshot();
+
+        let light_client = ctx.generate_light_client(vec![], &());
+        ctx = ctx.with_light_client(&client_id, light_client);
+        ctx.advance_blocks(2);
+        assert_eq!(ctx.latest_height(), height.add(2));
+        assert!(ctx.ibc_store.client_state(&client_id).is_ok());
+
+        // The same snapshot can be restored several times.
+        for _ in 0..2 {
+            ctx.restore(&snapshot);
+            assert_eq!(ctx.latest_height(), height);
+            assert_eq!(ctx.latest_timestamp(), timestamp);
+            assert!(ctx.ibc_store.client_state(&client_id).is_err());
+
+            ctx.advance_block_height();
+            assert_eq!(ctx.latest_height(), height.increment());
+        }
+    }
+
+    #[test]
+    fn test_client_expires_after_downtime() {
+        let client_id = ClientId::new("9999-mock", 0).expect("no error");
+
+        let mut ctx = MockContext::default();
+        let light_client = ctx.generate_light_client(vec![], &());
+        let trusting_period = light_client.client_state.trusting_period;
+        ctx = ctx.with_light_client(&client_id, light_client);
+        let snapshot = ctx.snapshot();
+
+        let status = |ctx: &MockContext| {
+            ctx.ibc_store
+                .client_state(&client_id)
+                .expect("client exists")
+                .status(&ctx.ibc_store, &client_id)
+                .expect("no error")
+        };
+
+        ctx.advance_block_after(trusting_period / 2);
+        assert_eq!(status(&ctx), Status::Active);
+
+        ctx.restore(&snapshot);
+        ctx.advance_block_after(trusting_period + Duration::from_secs(1));
+        assert_eq!(status(&ctx), Status::Expired);
+
+        ctx.restore(&snapshot);
+        let target = (ctx.latest_timestamp() + trusting_period * 2).expect("no overflow");
+        ctx.advance_time_up_to(target);
+        assert_eq!(ctx.latest_timestamp(), target);
+        assert_eq!(status(&ctx), Status::Expired);
+    }
 }
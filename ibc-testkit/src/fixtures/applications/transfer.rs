@@ -75,3 +75,62 @@ impl From<PacketDataConfig> for PacketData {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use ibc::core::client::types::Height;
+    use ibc::core::primitives::ToProtoJson;
+
+    use super::*;
+
+    fn dummy_msg_transfer() -> MsgTransfer {
+        let packet_data = PacketDataConfig::builder()
+            .token("1000uatom".parse().expect("valid coin"))
+            .build();
+
+        MsgTransferConfig::builder()
+            .packet_data(packet_data)
+            .timeout_height_on_b(TimeoutHeight::At(Height::new(1, 10).expect("valid height")))
+            .timeout_timestamp_on_b(
+                Timestamp::from_nanoseconds(1_700_000_000_000_000_000).expect("valid timestamp"),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_msg_transfer_proto_json_roundtrip() {
+        let msg = dummy_msg_transfer();
+
+        let json = msg.to_proto_json().expect("encoding never fails");
+
+        let decoded = MsgTransfer::from_proto_json(&json).expect("valid proto3 JSON");
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn test_msg_transfer_amino_json_envelope() {
+        let msg = dummy_msg_transfer();
+
+        let json = msg
+            .to_amino_json("cosmos-sdk/MsgTransfer")
+            .expect("encoding never fails");
+
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"type":"cosmos-sdk/MsgTransfer","value":{"#,
+                r#""source_port":"transfer","source_channel":"channel-0","#,
+                r#""token":{"denom":"uatom","amount":"1000"},"#,
+                r#""sender":"0CDA3F47EF3C4906693B170EF650EB968C5F4B2C","#,
+                r#""receiver":"0CDA3F47EF3C4906693B170EF650EB968C5F4B2C","#,
+                r#""timeout_height":{"revision_number":"1","revision_height":"10"},"#,
+                r#""timeout_timestamp":"1700000000000000000"}}"#,
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_proto_json_is_rejected() {
+        assert!(MsgTransfer::from_proto_json("{").is_err());
+    }
+}
@@ -233,4 +233,70 @@ mod tests {
             tm_client_state_from_any.expect("Never fails").into()
         );
     }
+
+    #[test]
+    fn tm_header_with_invalid_chain_id_is_rejected() {
+        use ibc::clients::tendermint::types::proto::v1::Header as RawHeader;
+
+        let raw_header = RawHeader::from(dummy_ics07_header());
+        assert!(Header::try_from(raw_header.clone()).is_ok());
+
+        let mut raw_header = raw_header;
+        if let Some(header) = raw_header
+            .signed_header
+            .as_mut()
+            .and_then(|signed_header| signed_header.header.as_mut())
+        {
+            header.chain_id = "test chain 1".to_string();
+        }
+
+        assert!(Header::try_from(raw_header).is_err());
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn tm_states_borsh_roundtrip() {
+        use ibc::clients::tendermint::types::ConsensusState;
+
+        let header = dummy_tendermint_header();
+        let client_state: ClientStateType = dummy_tm_client_state_from_header(header.clone())
+            .inner()
+            .clone();
+
+        let bytes = borsh::to_vec(&client_state).unwrap();
+        let decoded = <ClientStateType as borsh::BorshDeserialize>::try_from_slice(&bytes).unwrap();
+        assert_eq!(decoded, client_state);
+
+        let consensus_state = ConsensusState::from(header);
+
+        let bytes = borsh::to_vec(&consensus_state).unwrap();
+        let decoded = <ConsensusState as borsh::BorshDeserialize>::try_from_slice(&bytes).unwrap();
+        assert_eq!(decoded, consensus_state);
+    }
+
+    #[cfg(feature = "parity-scale-codec")]
+    #[test]
+    fn tm_states_parity_scale_codec_roundtrip() {
+        use ibc::clients::tendermint::types::ConsensusState;
+        use parity_scale_codec::{Decode, Encode};
+
+        let header = dummy_tendermint_header();
+        let client_state: ClientStateType = dummy_tm_client_state_from_header(header.clone())
+            .inner()
+            .clone();
+
+        let bytes = client_state.encode();
+        assert_eq!(
+            ClientStateType::decode(&mut bytes.as_slice()).unwrap(),
+            client_state
+        );
+
+        let consensus_state = ConsensusState::from(header);
+
+        let bytes = consensus_state.encode();
+        assert_eq!(
+            ConsensusState::decode(&mut bytes.as_slice()).unwrap(),
+            consensus_state
+        );
+    }
 }
@@ -193,4 +193,15 @@ mod tests {
             }
         }
     }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn channel_end_borsh_roundtrip() {
+        let channel_end = ChannelEnd::try_from(dummy_raw_channel_end(3, Some(0))).unwrap();
+
+        let bytes = borsh::to_vec(&channel_end).unwrap();
+        let decoded = <ChannelEnd as borsh::BorshDeserialize>::try_from_slice(&bytes).unwrap();
+
+        assert_eq!(decoded, channel_end);
+    }
 }
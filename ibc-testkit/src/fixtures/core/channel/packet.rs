@@ -269,4 +269,15 @@ mod tests {
         ));
         let _ = tendermint::abci::Event::try_from(ibc_event);
     }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn packet_borsh_roundtrip() {
+        let packet = Packet::try_from(dummy_raw_packet(15, 1)).unwrap();
+
+        let bytes = borsh::to_vec(&packet).unwrap();
+        let decoded = <Packet as borsh::BorshDeserialize>::try_from_slice(&bytes).unwrap();
+
+        assert_eq!(decoded, packet);
+    }
 }
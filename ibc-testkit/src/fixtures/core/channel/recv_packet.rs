@@ -7,10 +7,11 @@ use ibc::core::channel::types::proto::v1::MsgRecvPacket as RawMsgRecvPacket;
 use ibc::core::client::types::proto::v1::Height as RawHeight;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentProofBytes;
-use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::primitives::Signer;
 
 use super::{dummy_proof, dummy_raw_packet};
 use crate::fixtures::core::signer::dummy_bech32_account;
+use crate::utils::year_2024;
 
 pub fn dummy_msg_recv_packet(
     packet: Packet,
@@ -29,7 +30,7 @@ pub fn dummy_msg_recv_packet(
 /// Returns a dummy `RawMsgRecvPacket`, for testing purposes only! The `height`
 /// parametrizes both the proof height as well as the timeout height.
 pub fn dummy_raw_msg_recv_packet(height: u64) -> RawMsgRecvPacket {
-    let timestamp = Timestamp::now().add(Duration::from_secs(9));
+    let timestamp = year_2024().add(Duration::from_secs(9));
     RawMsgRecvPacket {
         packet: Some(dummy_raw_packet(
             height,
@@ -1,7 +1,10 @@
 use alloc::vec::Vec;
+use core::fmt::Debug;
 
+use basecoin_store::context::ProvableStore;
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::ChainId;
+use ibc::core::host::types::path::Path;
 use ibc::core::primitives::Timestamp;
 use typed_builder::TypedBuilder;
 
@@ -9,8 +12,9 @@ use super::{TestBlock, TestHeader, TestHost};
 use crate::testapp::ibc::clients::mock::client_state::MockClientState;
 use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use crate::testapp::ibc::clients::mock::header::MockHeader;
+use crate::testapp::ibc::core::types::MockIbcStore;
 
-#[derive(TypedBuilder, Debug)]
+#[derive(TypedBuilder, Clone, Debug)]
 pub struct MockHost {
     /// Unique identifier for the chain.
     #[builder(default = ChainId::new("mock-0").expect("Never fails"))]
@@ -40,9 +44,36 @@ impl TestHost for MockHost {
         self.history.push(block);
     }
 
+    /// Commits to the root of the [`MockCommitmentTree`] over the IBC state,
+    /// against which the mock clients of the host verify proofs.
+    ///
+    /// [`MockCommitmentTree`]: crate::testapp::ibc::clients::mock::commitment::MockCommitmentTree
+    fn commitment_root<S>(&self, ibc_store: &MockIbcStore<S>, _multi_store_root: Vec<u8>) -> Vec<u8>
+    where
+        S: ProvableStore + Debug,
+    {
+        ibc_store.mock_commitment_tree().root().into_vec()
+    }
+
+    fn commitment_proof<S>(
+        &self,
+        ibc_store: &MockIbcStore<S>,
+        height: &Height,
+        path: &Path,
+    ) -> Option<Vec<u8>>
+    where
+        S: ProvableStore + Debug,
+    {
+        let tree = ibc_store.mock_commitment_tree_at(height);
+
+        tree.prove_membership(path)
+            .or_else(|| tree.prove_non_membership(path))
+            .map(Into::into)
+    }
+
     fn generate_block(
         &self,
-        _commitment_root: Vec<u8>,
+        commitment_root: Vec<u8>,
         height: u64,
         timestamp: Timestamp,
         _params: &Self::BlockParams,
@@ -50,6 +81,9 @@ impl TestHost for MockHost {
         MockHeader {
             height: Height::new(self.chain_id.revision_number(), height).expect("Never fails"),
             timestamp,
+            root: commitment_root
+                .try_into()
+                .expect("mock commitment roots are 32-byte hashes"),
         }
     }
 
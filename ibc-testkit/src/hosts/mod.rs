@@ -5,15 +5,19 @@ use core::fmt::Debug;
 use core::ops::Add;
 use core::time::Duration;
 
+use basecoin_store::context::ProvableStore;
 use ibc::core::client::context::consensus_state::ConsensusState;
 use ibc::core::client::types::Height;
+use ibc::core::host::types::path::Path;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::Any;
+use ibc_query::core::context::ProvableContext;
 
 pub use self::mock::MockHost;
 pub use self::tendermint::TendermintHost;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+use crate::testapp::ibc::core::types::MockIbcStore;
 
 pub type HostClientState<H> = <H as TestHost>::ClientState;
 pub type HostBlock<H> = <H as TestHost>::Block;
@@ -86,6 +90,29 @@ pub trait TestHost: Default + Debug + Sized {
         self.push_block(new_block);
     }
 
+    /// Returns the root committed to by the next block of the host, given its
+    /// IBC store and the commitment of its multi store.
+    fn commitment_root<S>(&self, _ibc_store: &MockIbcStore<S>, multi_store_root: Vec<u8>) -> Vec<u8>
+    where
+        S: ProvableStore + Debug,
+    {
+        multi_store_root
+    }
+
+    /// Returns a proof of the IBC state of the host at the given height, in
+    /// the format verified by the light clients of the host.
+    fn commitment_proof<S>(
+        &self,
+        ibc_store: &MockIbcStore<S>,
+        height: &Height,
+        path: &Path,
+    ) -> Option<Vec<u8>>
+    where
+        S: ProvableStore + Debug,
+    {
+        ibc_store.get_proof(*height, path)
+    }
+
     /// Generate a block at the given height and timestamp, using the provided parameters.
     fn generate_block(
         &self,
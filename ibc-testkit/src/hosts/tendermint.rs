@@ -23,7 +23,7 @@ use crate::fixtures::clients::tendermint::ClientStateConfig;
 use crate::hosts::{TestBlock, TestHeader, TestHost};
 
 /// A host that produces Tendermint blocks and interfaces with Tendermint light clients.
-#[derive(TypedBuilder, Debug)]
+#[derive(TypedBuilder, Clone, Debug)]
 pub struct TendermintHost {
     /// Unique identifier for the chain.
     #[builder(default = ChainId::new("mock-0").expect("Never fails"))]
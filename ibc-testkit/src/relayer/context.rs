@@ -505,8 +505,11 @@ where
         };
 
         // module creates the send_packet
+        let ctx_a = self.get_ctx_a_mut();
+
         send_transfer(
-            self.get_ctx_a_mut().ibc_store_mut(),
+            &mut ctx_a.ibc_store,
+            &ctx_a.ibc_router,
             &mut DummyTransferModule,
             msg,
         )
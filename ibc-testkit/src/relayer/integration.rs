@@ -1,3 +1,4 @@
+use basecoin_store::context::ProvableStore;
 use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 
@@ -14,6 +15,64 @@ use crate::testapp::ibc::core::types::DefaultIbcStore;
 /// [`DummyTransferModule`](crate::testapp::ibc::applications::transfer::types::DummyTransferModule)
 /// to simulate the transfer of tokens between two contexts.
 pub fn ibc_integration_test<A, B>()
+where
+    A: TestHost,
+    B: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+{
+    run_ibc_integration::<A, B>();
+}
+
+/// Determinism test for IBC implementation. This runs the
+/// [`ibc_integration_test`] message sequence on two fresh pairs of
+/// [`TestHost`]s, and asserts that both pairs end up with identical events,
+/// logs and committed state.
+///
+/// This catches handlers depending on anything else than the messages and the
+/// host context, such as the system time or the iteration order of hash maps.
+pub fn ibc_determinism_test<A, B>()
+where
+    A: TestHost,
+    B: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+{
+    let first = run_ibc_integration::<A, B>();
+    let second = run_ibc_integration::<A, B>();
+
+    assert_identical_contexts(first.get_ctx_a(), second.get_ctx_a());
+    assert_identical_contexts(first.get_ctx_b(), second.get_ctx_b());
+}
+
+fn assert_identical_contexts<H>(first: &TestContext<H>, second: &TestContext<H>)
+where
+    H: TestHost,
+    HostClientState<H>: ClientStateValidation<DefaultIbcStore>,
+{
+    assert_eq!(first.get_events(), second.get_events(), "events diverged");
+    assert_eq!(first.get_logs(), second.get_logs(), "logs diverged");
+
+    assert_eq!(
+        first.latest_height(),
+        second.latest_height(),
+        "heights diverged"
+    );
+    assert_eq!(
+        first.latest_timestamp(),
+        second.latest_timestamp(),
+        "timestamps diverged"
+    );
+    // The mock host consensus states carry a placeholder root, so compare
+    // the root of the committed store itself.
+    assert_eq!(
+        first.ibc_store().store.root_hash(),
+        second.ibc_store().store.root_hash(),
+        "committed states diverged"
+    );
+}
+
+fn run_ibc_integration<A, B>() -> RelayerContext<A, B>
 where
     A: TestHost,
     B: TestHost,
@@ -160,6 +219,8 @@ where
             };
         }
     }
+
+    relayer
 }
 
 #[cfg(test)]
@@ -175,4 +236,12 @@ mod tests {
         ibc_integration_test::<TendermintHost, MockHost>();
         ibc_integration_test::<TendermintHost, TendermintHost>();
     }
+
+    #[test]
+    fn ibc_determinism_test_for_all_pairs() {
+        ibc_determinism_test::<MockHost, MockHost>();
+        ibc_determinism_test::<MockHost, TendermintHost>();
+        ibc_determinism_test::<TendermintHost, MockHost>();
+        ibc_determinism_test::<TendermintHost, TendermintHost>();
+    }
 }
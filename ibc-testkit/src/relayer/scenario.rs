@@ -0,0 +1,461 @@
+//! A builder expressing multi-step IBC scenarios between two [`TestContext`]s
+//! in a few chained calls, while recording the events they emit.
+//!
+//! ```rust,ignore
+//! let scenario = Scenario::<MockHost, MockHost>::new()
+//!     .create_clients()
+//!     .open_connection()
+//!     .open_channel(PortId::transfer())
+//!     .send(msg_transfer)
+//!     .relay_all();
+//!
+//! assert!(scenario
+//!     .events_on(Chain::A)
+//!     .any(|event| matches!(event, IbcEvent::AcknowledgePacket(_))));
+//! ```
+
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::primitives::Signer;
+
+use crate::context::TestContext;
+use crate::fixtures::core::signer::dummy_account_id;
+use crate::hosts::{HostClientState, TestHost};
+use crate::relayer::context::RelayerContext;
+use crate::testapp::ibc::core::types::DefaultIbcStore;
+
+/// One of the two chains of a [`Scenario`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Chain {
+    A,
+    B,
+}
+
+/// The steps a [`Scenario`] is made of.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Step {
+    CreateClients,
+    OpenConnection,
+    OpenChannel { port_id: PortId },
+    Send { packet: Packet },
+    Relay { packet: Packet },
+}
+
+/// An event emitted on a chain during a step of a [`Scenario`].
+#[derive(Clone, Debug)]
+pub struct TranscriptEntry {
+    pub step: Step,
+    pub chain: Chain,
+    pub event: IbcEvent,
+}
+
+/// A deterministic scenario between two chains, where chain A initiates the
+/// handshakes and sends the packets.
+///
+/// Each step runs the handlers of both chains through a [`RelayerContext`]
+/// and panics if any of them fails, so that a scenario reads as a sequence of
+/// expected successes. The events emitted by every step are recorded in the
+/// transcript for assertions.
+pub struct Scenario<A, B>
+where
+    A: TestHost,
+    B: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+{
+    relayer: RelayerContext<A, B>,
+    signer: Signer,
+    client_ids: Option<(ClientId, ClientId)>,
+    connection_ids: Option<(ConnectionId, ConnectionId)>,
+    channel_ids: Vec<(PortId, ChannelId, ChannelId)>,
+    pending_packets: Vec<Packet>,
+    transcript: Vec<TranscriptEntry>,
+}
+
+impl<A, B> Default for Scenario<A, B>
+where
+    A: TestHost,
+    B: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, B> Scenario<A, B>
+where
+    A: TestHost,
+    B: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+{
+    /// Creates a scenario between two default contexts.
+    pub fn new() -> Self {
+        Self::with_contexts(TestContext::default(), TestContext::default())
+    }
+
+    /// Creates a scenario between the given contexts.
+    pub fn with_contexts(ctx_a: TestContext<A>, ctx_b: TestContext<B>) -> Self {
+        Self {
+            relayer: RelayerContext::new(ctx_a, ctx_b),
+            signer: dummy_account_id(),
+            client_ids: None,
+            connection_ids: None,
+            channel_ids: Vec::new(),
+            pending_packets: Vec::new(),
+            transcript: Vec::new(),
+        }
+    }
+
+    /// Sets the signer of the messages submitted by the scenario.
+    pub fn with_signer(self, signer: Signer) -> Self {
+        Self { signer, ..self }
+    }
+
+    /// Creates a client of each chain on the other one.
+    pub fn create_clients(mut self) -> Self {
+        let signer = self.signer.clone();
+
+        let client_ids = self.record(Step::CreateClients, |relayer| {
+            (
+                relayer.create_client_on_a(signer.clone()),
+                relayer.create_client_on_b(signer),
+            )
+        });
+
+        self.client_ids = Some(client_ids);
+        self
+    }
+
+    /// Opens a connection from chain A to chain B on top of the clients.
+    pub fn open_connection(mut self) -> Self {
+        let (client_id_on_a, client_id_on_b) = self
+            .client_ids
+            .clone()
+            .expect("clients must be created before opening a connection");
+        let signer = self.signer.clone();
+
+        let connection_ids = self.record(Step::OpenConnection, |relayer| {
+            relayer.create_connection_on_a(client_id_on_a, client_id_on_b, signer)
+        });
+
+        self.connection_ids = Some(connection_ids);
+        self
+    }
+
+    /// Opens a channel from chain A to chain B between the given port on both
+    /// chains, on top of the connection.
+    pub fn open_channel(mut self, port_id: PortId) -> Self {
+        let (conn_id_on_a, conn_id_on_b) = self
+            .connection_ids
+            .clone()
+            .expect("a connection must be opened before opening a channel");
+        let signer = self.signer.clone();
+
+        let (chan_id_on_a, chan_id_on_b) = self.record(
+            Step::OpenChannel {
+                port_id: port_id.clone(),
+            },
+            |relayer| {
+                relayer.create_channel_on_a(
+                    conn_id_on_a,
+                    port_id.clone(),
+                    conn_id_on_b,
+                    port_id.clone(),
+                    signer,
+                )
+            },
+        );
+
+        self.channel_ids.push((port_id, chan_id_on_a, chan_id_on_b));
+        self
+    }
+
+    /// Sends a token transfer from chain A, using the
+    /// [`DummyTransferModule`](crate::testapp::ibc::applications::transfer::types::DummyTransferModule).
+    /// The packet is relayed by the next call to [`relay_all`](Self::relay_all).
+    ///
+    /// Requires `serde` feature because of [`ibc::apps::transfer::handler::send_transfer`].
+    #[cfg(feature = "serde")]
+    pub fn send(mut self, msg: ibc::apps::transfer::types::msgs::transfer::MsgTransfer) -> Self {
+        use ibc::apps::transfer::handler::send_transfer;
+
+        use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+        let events_before = self.relayer.get_ctx_a().ibc_store().events.lock().len();
+
+        let ctx_a = self.relayer.get_ctx_a_mut();
+
+        send_transfer(
+            &mut ctx_a.ibc_store,
+            &ctx_a.ibc_router,
+            &mut DummyTransferModule,
+            msg,
+        )
+        .expect("successfully sent the transfer");
+
+        // send_packet wasn't committed, hence produce a block
+        self.relayer.get_ctx_a_mut().advance_block_height();
+
+        let events: Vec<IbcEvent> =
+            self.relayer.get_ctx_a().ibc_store().events.lock()[events_before..].to_vec();
+
+        let packet = events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::SendPacket(event) => Some(Packet {
+                    port_id_on_a: event.port_id_on_a().clone(),
+                    chan_id_on_a: event.chan_id_on_a().clone(),
+                    seq_on_a: *event.seq_on_a(),
+                    data: event.packet_data().to_vec(),
+                    timeout_height_on_b: *event.timeout_height_on_b(),
+                    timeout_timestamp_on_b: *event.timeout_timestamp_on_b(),
+                    port_id_on_b: event.port_id_on_b().clone(),
+                    chan_id_on_b: event.chan_id_on_b().clone(),
+                }),
+                _ => None,
+            })
+            .expect("send_packet event is emitted");
+
+        let step = Step::Send {
+            packet: packet.clone(),
+        };
+        self.transcript
+            .extend(events.into_iter().map(|event| TranscriptEntry {
+                step: step.clone(),
+                chain: Chain::A,
+                event,
+            }));
+
+        self.pending_packets.push(packet);
+        self
+    }
+
+    /// Relays all the packets sent so far to chain B, and their
+    /// acknowledgements back to chain A.
+    pub fn relay_all(mut self) -> Self {
+        let signer = self.signer.clone();
+
+        for packet in core::mem::take(&mut self.pending_packets) {
+            self.record(
+                Step::Relay {
+                    packet: packet.clone(),
+                },
+                |relayer| relayer.submit_packet_on_b(packet, signer.clone()),
+            );
+        }
+
+        self
+    }
+
+    /// Returns the identifiers of the clients on chain A and B.
+    pub fn client_ids(&self) -> Option<&(ClientId, ClientId)> {
+        self.client_ids.as_ref()
+    }
+
+    /// Returns the identifiers of the connection ends on chain A and B.
+    pub fn connection_ids(&self) -> Option<&(ConnectionId, ConnectionId)> {
+        self.connection_ids.as_ref()
+    }
+
+    /// Returns the port and the identifiers of the channel ends on chain A and
+    /// B of the opened channels, in opening order.
+    pub fn channel_ids(&self) -> &[(PortId, ChannelId, ChannelId)] {
+        &self.channel_ids
+    }
+
+    /// Returns the packets sent but not relayed yet.
+    pub fn pending_packets(&self) -> &[Packet] {
+        &self.pending_packets
+    }
+
+    /// Returns the events emitted so far, in emission order per chain.
+    pub fn transcript(&self) -> &[TranscriptEntry] {
+        &self.transcript
+    }
+
+    /// Returns the events emitted so far on the given chain.
+    pub fn events_on(&self, chain: Chain) -> impl Iterator<Item = &IbcEvent> {
+        self.transcript
+            .iter()
+            .filter(move |entry| entry.chain == chain)
+            .map(|entry| &entry.event)
+    }
+
+    /// Returns the underlying relayer context.
+    pub fn relayer(&self) -> &RelayerContext<A, B> {
+        &self.relayer
+    }
+
+    /// Returns a mutable reference to the underlying relayer context, e.g. to
+    /// run steps not covered by the scenario.
+    pub fn relayer_mut(&mut self) -> &mut RelayerContext<A, B> {
+        &mut self.relayer
+    }
+
+    /// Consumes the scenario and returns the underlying relayer context.
+    pub fn into_relayer(self) -> RelayerContext<A, B> {
+        self.relayer
+    }
+
+    /// Runs a step and records the events it emitted on both chains.
+    fn record<T>(&mut self, step: Step, f: impl FnOnce(&mut RelayerContext<A, B>) -> T) -> T {
+        let events_before_a = self.relayer.get_ctx_a().ibc_store().events.lock().len();
+        let events_before_b = self.relayer.get_ctx_b().ibc_store().events.lock().len();
+
+        let output = f(&mut self.relayer);
+
+        for (chain, events_before) in [(Chain::A, events_before_a), (Chain::B, events_before_b)] {
+            let events = match chain {
+                Chain::A => {
+                    self.relayer.get_ctx_a().ibc_store().events.lock()[events_before..].to_vec()
+                }
+                Chain::B => {
+                    self.relayer.get_ctx_b().ibc_store().events.lock()[events_before..].to_vec()
+                }
+            };
+
+            self.transcript
+                .extend(events.into_iter().map(|event| TranscriptEntry {
+                    step: step.clone(),
+                    chain,
+                    event,
+                }));
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hosts::MockHost;
+
+    #[test]
+    fn test_scenario_handshakes() {
+        let scenario = Scenario::<MockHost, MockHost>::new()
+            .create_clients()
+            .open_connection()
+            .open_channel(PortId::transfer());
+
+        assert_eq!(
+            scenario.channel_ids(),
+            &[(PortId::transfer(), ChannelId::new(0), ChannelId::new(0))]
+        );
+        assert!(scenario
+            .events_on(Chain::B)
+            .any(|event| matches!(event, IbcEvent::OpenConfirmChannel(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    fn dummy_msg_transfer() -> ibc::apps::transfer::types::msgs::transfer::MsgTransfer {
+        use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
+        use ibc::apps::transfer::types::packet::PacketData;
+        use ibc::primitives::Timestamp;
+
+        let signer = dummy_account_id();
+
+        MsgTransfer {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            packet_data: PacketData {
+                token: "1000uibc".parse().expect("valid prefixed coin"),
+                sender: signer.clone(),
+                receiver: signer,
+                memo: "sample memo".into(),
+            },
+            timeout_height_on_b: ibc::core::channel::types::timeout::TimeoutHeight::At(
+                ibc::core::client::types::Height::new(0, 100).expect("valid height"),
+            ),
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_scenario_transfer() {
+        let scenario = Scenario::<MockHost, MockHost>::new()
+            .create_clients()
+            .open_connection()
+            .open_channel(PortId::transfer())
+            .send(dummy_msg_transfer())
+            .relay_all();
+
+        assert!(scenario.pending_packets().is_empty());
+        assert!(scenario
+            .events_on(Chain::B)
+            .any(|event| matches!(event, IbcEvent::ReceivePacket(_))));
+        assert!(scenario
+            .events_on(Chain::A)
+            .any(|event| matches!(event, IbcEvent::AcknowledgePacket(_))));
+    }
+
+    /// Round-trips a packet between two chains committing to it with the host
+    /// hash function, which the CI also runs with
+    /// `--cfg ibc_host_hasher="blake2s"`.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_scenario_transfer_with_host_hasher() {
+        use ibc::core::channel::types::commitment::compute_counterparty_packet_commitment;
+        use ibc::core::client::context::prelude::*;
+        use ibc::core::host::types::path::CommitmentPath;
+        use ibc::core::host::ValidationContext;
+        use ibc::core::primitives::CommitmentHasher;
+
+        let scenario = Scenario::<MockHost, MockHost>::new()
+            .create_clients()
+            .open_connection()
+            .open_channel(PortId::transfer())
+            .send(dummy_msg_transfer());
+
+        let (_, client_id_on_b) = scenario.client_ids().expect("clients are created");
+        let commitment_hasher = scenario
+            .relayer()
+            .get_ctx_b()
+            .ibc_store()
+            .client_state(client_id_on_b)
+            .expect("client state exists")
+            .commitment_hasher()
+            .expect("supported hasher");
+
+        assert_eq!(commitment_hasher, CommitmentHasher::host());
+
+        // The client on B expects the packet commitment A actually stores.
+        let packet = scenario.pending_packets()[0].clone();
+        let commitment_on_a = scenario
+            .relayer()
+            .get_ctx_a()
+            .ibc_store()
+            .get_packet_commitment(&CommitmentPath::new(
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                packet.seq_on_a,
+            ))
+            .expect("packet is committed");
+
+        assert_eq!(
+            commitment_on_a,
+            compute_counterparty_packet_commitment(
+                commitment_hasher,
+                &packet.data,
+                &packet.timeout_height_on_b,
+                &packet.timeout_timestamp_on_b,
+            )
+        );
+
+        let scenario = scenario.relay_all();
+
+        assert!(scenario
+            .events_on(Chain::B)
+            .any(|event| matches!(event, IbcEvent::ReceivePacket(_))));
+        assert!(scenario
+            .events_on(Chain::A)
+            .any(|event| matches!(event, IbcEvent::AcknowledgePacket(_))));
+    }
+}
@@ -30,7 +30,6 @@ use ibc::core::host::types::path::{
 };
 use ibc::core::host::ValidationContext;
 use ibc::primitives::Signer;
-use ibc_query::core::context::ProvableContext;
 
 use crate::context::TestContext;
 use crate::hosts::{HostClientState, TestBlock, TestHost};
@@ -220,9 +219,8 @@ where
         );
 
         let proof_conn_end_on_a = ctx_a
-            .ibc_store()
-            .get_proof(
-                proofs_height_on_a,
+            .commitment_proof(
+                &proofs_height_on_a,
                 &ConnectionPath::new(&conn_id_on_a).into(),
             )
             .expect("connection end exists")
@@ -230,9 +228,8 @@ where
             .expect("value merkle proof");
 
         let proof_client_state_of_b_on_a = ctx_a
-            .ibc_store()
-            .get_proof(
-                proofs_height_on_a,
+            .commitment_proof(
+                &proofs_height_on_a,
                 &ClientStatePath::new(client_id_on_a.clone()).into(),
             )
             .expect("client state exists")
@@ -240,9 +237,8 @@ where
             .expect("value merkle proof");
 
         let proof_consensus_state_of_b_on_a = ctx_a
-            .ibc_store()
-            .get_proof(
-                proofs_height_on_a,
+            .commitment_proof(
+                &proofs_height_on_a,
                 &ClientConsensusStatePath::new(
                     client_id_on_a.clone(),
                     consensus_height_of_b_on_a.revision_number(),
@@ -303,9 +299,8 @@ where
         let consensus_height_of_a_on_b = client_state_of_a_on_b.latest_height();
 
         let proof_conn_end_on_b = ctx_b
-            .ibc_store()
-            .get_proof(
-                proofs_height_on_b,
+            .commitment_proof(
+                &proofs_height_on_b,
                 &ConnectionPath::new(&conn_id_on_b).into(),
             )
             .expect("connection end exists")
@@ -313,9 +308,8 @@ where
             .expect("value merkle proof");
 
         let proof_client_state_of_a_on_b = ctx_b
-            .ibc_store()
-            .get_proof(
-                proofs_height_on_b,
+            .commitment_proof(
+                &proofs_height_on_b,
                 &ClientStatePath::new(client_id_on_b.clone()).into(),
             )
             .expect("client state exists")
@@ -323,9 +317,8 @@ where
             .expect("value merkle proof");
 
         let proof_consensus_state_of_a_on_b = ctx_b
-            .ibc_store()
-            .get_proof(
-                proofs_height_on_b,
+            .commitment_proof(
+                &proofs_height_on_b,
                 &ClientConsensusStatePath::new(
                     client_id_on_b.clone(),
                     consensus_height_of_a_on_b.revision_number(),
@@ -371,9 +364,8 @@ where
         let proof_height_on_a = ctx_a.latest_height();
 
         let proof_conn_end_on_a = ctx_a
-            .ibc_store()
-            .get_proof(
-                proof_height_on_a,
+            .commitment_proof(
+                &proof_height_on_a,
                 &ConnectionPath::new(&conn_id_on_a).into(),
             )
             .expect("connection end exists")
@@ -507,9 +499,8 @@ where
         let proof_height_on_a = ctx_a.latest_height();
 
         let proof_chan_end_on_a = ctx_a
-            .ibc_store()
-            .get_proof(
-                proof_height_on_a,
+            .commitment_proof(
+                &proof_height_on_a,
                 &ChannelEndPath::new(&port_id_on_a, &chan_id_on_a).into(),
             )
             .expect("connection end exists")
@@ -556,9 +547,8 @@ where
         let proof_height_on_b = ctx_b.latest_height();
 
         let proof_chan_end_on_b = ctx_b
-            .ibc_store()
-            .get_proof(
-                proof_height_on_b,
+            .commitment_proof(
+                &proof_height_on_b,
                 &ChannelEndPath::new(&port_id_on_b, &chan_id_on_b).into(),
             )
             .expect("connection end exists")
@@ -596,9 +586,8 @@ where
         let proof_height_on_a = ctx_a.latest_height();
 
         let proof_chan_end_on_a = ctx_a
-            .ibc_store()
-            .get_proof(
-                proof_height_on_a,
+            .commitment_proof(
+                &proof_height_on_a,
                 &ChannelEndPath::new(&PortId::transfer(), &chan_id_on_a).into(),
             )
             .expect("connection end exists")
@@ -655,9 +644,8 @@ where
         let proof_height_on_a = ctx_a.latest_height();
 
         let proof_chan_end_on_a = ctx_a
-            .ibc_store()
-            .get_proof(
-                proof_height_on_a,
+            .commitment_proof(
+                &proof_height_on_a,
                 &ChannelEndPath::new(&PortId::transfer(), &chan_id_on_b).into(),
             )
             .expect("connection end exists")
@@ -806,9 +794,8 @@ where
         let proof_height_on_a = ctx_a.latest_height();
 
         let proof_commitment_on_a = ctx_a
-            .ibc_store()
-            .get_proof(
-                proof_height_on_a,
+            .commitment_proof(
+                &proof_height_on_a,
                 &CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a)
                     .into(),
             )
@@ -845,9 +832,8 @@ where
         let proof_height_on_b = ctx_b.latest_height();
 
         let proof_acked_on_b = ctx_b
-            .ibc_store()
-            .get_proof(
-                proof_height_on_b,
+            .commitment_proof(
+                &proof_height_on_b,
                 &AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a).into(),
             )
             .expect("acknowledgement proof exists")
@@ -881,9 +867,8 @@ where
         let proof_height_on_b = ctx_b.latest_height();
 
         let proof_unreceived_on_b = ctx_b
-            .ibc_store()
-            .get_proof(
-                proof_height_on_b,
+            .commitment_proof(
+                &proof_height_on_b,
                 &ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a)
                     .into(),
             )
@@ -920,9 +905,8 @@ where
         let proof_height_on_b = ctx_b.latest_height();
 
         let proof_unreceived_on_b = ctx_b
-            .ibc_store()
-            .get_proof(
-                proof_height_on_b,
+            .commitment_proof(
+                &proof_height_on_b,
                 &ReceiptPath::new(&port_id_on_b, &chan_id_on_b, packet.seq_on_a).into(),
             )
             .expect("non-membership receipt proof")
@@ -930,9 +914,8 @@ where
             .expect("value merkle proof");
 
         let proof_close_on_b = ctx_b
-            .ibc_store()
-            .get_proof(
-                proof_height_on_b,
+            .commitment_proof(
+                &proof_height_on_b,
                 &ChannelEndPath::new(&port_id_on_b, &chan_id_on_b).into(),
             )
             .expect("channel end data exists")
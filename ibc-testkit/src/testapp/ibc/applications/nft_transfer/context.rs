@@ -1,10 +1,13 @@
+use ibc::apps::nft_transfer::audit::NftEscrowAuditContext;
 use ibc::apps::nft_transfer::context::{
     NftClassContext, NftContext, NftTransferExecutionContext, NftTransferValidationContext,
 };
 use ibc::apps::nft_transfer::types::error::NftTransferError;
+use ibc::apps::nft_transfer::types::version::NftTransferVersion;
 use ibc::apps::nft_transfer::types::{
     ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenUri,
 };
+use ibc::core::channel::types::Version;
 use ibc::core::host::types::identifiers::{ChannelId, PortId};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
@@ -60,6 +63,18 @@ impl NftTransferValidationContext for DummyNftTransferModule {
         Ok(())
     }
 
+    fn channel_version(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Version, NftTransferError> {
+        Ok(self
+            .channel_versions
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .unwrap_or_else(|| NftTransferVersion::V1.into()))
+    }
+
     fn create_or_update_class_validate(
         &self,
         _class_id: &PrefixedClassId,
@@ -69,6 +84,24 @@ impl NftTransferValidationContext for DummyNftTransferModule {
         Ok(())
     }
 
+    fn transfer_policy_validate(
+        &self,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<(), NftTransferError> {
+        if self
+            .non_transferable_nfts
+            .contains(&(class_id.clone(), token_id.clone()))
+        {
+            return Err(NftTransferError::NonTransferableNft {
+                class_id: class_id.clone(),
+                token_id: token_id.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
     fn escrow_nft_validate(
         &self,
         _from_account: &Self::AccountId,
@@ -127,6 +160,47 @@ impl NftTransferValidationContext for DummyNftTransferModule {
     ) -> Result<Self::NftClass, NftTransferError> {
         Ok(DummyNftClass::default())
     }
+
+    fn escrowed_nfts(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<(PrefixedClassId, TokenId)>, NftTransferError> {
+        Ok(self
+            .escrowed_nfts
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn traced_nft_classes(&self) -> Result<Vec<PrefixedClassId>, NftTransferError> {
+        Ok(self.traced_nft_classes.clone())
+    }
+}
+
+impl NftEscrowAuditContext for DummyNftTransferModule {
+    fn escrow_account(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Self::AccountId, NftTransferError> {
+        Ok(Self::channel_escrow_account(port_id, channel_id))
+    }
+
+    fn nft_owner(
+        &self,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<Self::AccountId, NftTransferError> {
+        self.nft_owners
+            .get(&(class_id.clone(), token_id.clone()))
+            .cloned()
+            .ok_or(NftTransferError::NftNotFound)
+    }
+
+    fn minted_vouchers(&self) -> Result<Vec<(PrefixedClassId, TokenId)>, NftTransferError> {
+        Ok(self.minted_vouchers.clone())
+    }
 }
 
 impl NftTransferExecutionContext for DummyNftTransferModule {
@@ -142,44 +216,70 @@ impl NftTransferExecutionContext for DummyNftTransferModule {
     fn escrow_nft_execute(
         &mut self,
         _from_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
         _memo: &Memo,
     ) -> Result<(), NftTransferError> {
+        let nft = (class_id.clone(), token_id.clone());
+        let escrowed = self
+            .escrowed_nfts
+            .entry((port_id.clone(), channel_id.clone()))
+            .or_default();
+        if !escrowed.contains(&nft) {
+            escrowed.push(nft.clone());
+        }
+        self.nft_owners
+            .insert(nft, Self::channel_escrow_account(port_id, channel_id));
         Ok(())
     }
 
     fn unescrow_nft_execute(
         &mut self,
-        _to_account: &Self::AccountId,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
     ) -> Result<(), NftTransferError> {
+        let nft = (class_id.clone(), token_id.clone());
+        if let Some(escrowed) = self
+            .escrowed_nfts
+            .get_mut(&(port_id.clone(), channel_id.clone()))
+        {
+            escrowed.retain(|escrowed_nft| escrowed_nft != &nft);
+        }
+        self.nft_owners.insert(nft, to_account.clone());
         Ok(())
     }
 
     fn mint_nft_execute(
         &mut self,
-        _account: &Self::AccountId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        account: &Self::AccountId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
         _token_uri: Option<&TokenUri>,
         _token_data: Option<&TokenData>,
     ) -> Result<(), NftTransferError> {
+        let nft = (class_id.clone(), token_id.clone());
+        if !self.minted_vouchers.contains(&nft) {
+            self.minted_vouchers.push(nft.clone());
+        }
+        self.nft_owners.insert(nft, account.clone());
         Ok(())
     }
 
     fn burn_nft_execute(
         &mut self,
         _account: &Self::AccountId,
-        _class_id: &PrefixedClassId,
-        _token_id: &TokenId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
         _memo: &Memo,
     ) -> Result<(), NftTransferError> {
+        let nft = (class_id.clone(), token_id.clone());
+        self.minted_vouchers.retain(|voucher| voucher != &nft);
+        self.nft_owners.remove(&nft);
         Ok(())
     }
 }
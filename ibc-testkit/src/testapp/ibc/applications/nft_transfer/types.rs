@@ -1,7 +1,31 @@
-use ibc::apps::nft_transfer::types::{ClassData, ClassId, ClassUri, TokenData, TokenId, TokenUri};
+use alloc::collections::BTreeSet;
 
-#[derive(Debug)]
-pub struct DummyNftTransferModule;
+use ibc::apps::nft_transfer::types::{
+    ClassData, ClassId, ClassUri, PrefixedClassId, TokenData, TokenId, TokenUri,
+};
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+/// A dummy NFT transfer module keeping track of the NFTs it escrows and mints,
+/// so that tests can audit its escrow.
+#[derive(Debug, Default)]
+pub struct DummyNftTransferModule {
+    /// The NFTs escrowed on each channel.
+    pub escrowed_nfts: BTreeMap<(PortId, ChannelId), Vec<(PrefixedClassId, TokenId)>>,
+    /// The current owner of each existing NFT.
+    pub nft_owners: BTreeMap<(PrefixedClassId, TokenId), Signer>,
+    /// The vouchers minted upon receiving NFTs from other chains.
+    pub minted_vouchers: Vec<(PrefixedClassId, TokenId)>,
+    /// The classes created from a trace.
+    pub traced_nft_classes: Vec<PrefixedClassId>,
+    /// The versions negotiated by the channels, which default to the original
+    /// ICS-721 version.
+    pub channel_versions: BTreeMap<(PortId, ChannelId), Version>,
+    /// The NFTs that the transfer policy of the host forbids to transfer.
+    pub non_transferable_nfts: BTreeSet<(PrefixedClassId, TokenId)>,
+}
 
 #[derive(Debug)]
 pub struct DummyNft {
@@ -50,12 +74,11 @@ impl Default for DummyNftClass {
 
 impl DummyNftTransferModule {
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
-}
 
-impl Default for DummyNftTransferModule {
-    fn default() -> Self {
-        Self::new()
+    /// Returns the account escrowing the NFTs sent over the given channel.
+    pub fn channel_escrow_account(port_id: &PortId, channel_id: &ChannelId) -> Signer {
+        Signer::from(format!("escrow/{port_id}/{channel_id}"))
     }
 }
@@ -1,4 +1,4 @@
-use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::acknowledgement::{Acknowledgement, RecvPacketResult};
 use ibc::core::channel::types::channel::{Counterparty, Order};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::packet::Packet;
@@ -60,14 +60,21 @@ impl Module for DummyTransferModule {
         Ok((ModuleExtras::empty(), counterparty_version.clone()))
     }
 
+    #[cfg(feature = "serde")]
+    fn packet_data_codec(
+        &self,
+    ) -> Option<&dyn ibc::core::channel::types::packet_data::DynPacketDataCodec> {
+        Some(&ibc::apps::transfer::codec::TransferPacketDataCodec)
+    }
+
     fn on_recv_packet_execute(
         &mut self,
         _packet: &Packet,
         _relayer: &Signer,
-    ) -> (ModuleExtras, Acknowledgement) {
+    ) -> (ModuleExtras, RecvPacketResult) {
         (
             ModuleExtras::empty(),
-            Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+            RecvPacketResult::Success(Acknowledgement::try_from(vec![1u8]).expect("Never fails")),
         )
     }
 
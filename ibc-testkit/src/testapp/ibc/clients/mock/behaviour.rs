@@ -0,0 +1,111 @@
+//! Failure modes that can be injected into the mock light client, so that the
+//! error paths of the core handlers can be exercised deterministically.
+
+use alloc::sync::Arc;
+
+use ibc::core::client::types::Status;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::primitives::prelude::*;
+use parking_lot::Mutex;
+
+/// The failure modes injected into a single mock client.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MockClientBehaviour {
+    /// Number of upcoming header updates to reject during verification.
+    pub rejected_updates: u64,
+    /// Status reported by the client instead of the one it computes.
+    pub forced_status: Option<Status>,
+    /// Whether headers are reported as conflicting with the stored consensus
+    /// states, i.e. as misbehaviour.
+    pub conflicting_headers: bool,
+}
+
+/// A handle controlling the behaviour of the mock clients of a context.
+///
+/// The handle is shared by all its clones, so that a test can keep a clone and
+/// change the behaviour of the clients while the handlers run.
+#[derive(Clone, Debug, Default)]
+pub struct MockClientHandle {
+    behaviours: Arc<Mutex<BTreeMap<ClientId, MockClientBehaviour>>>,
+}
+
+impl MockClientHandle {
+    /// Makes the client reject its next `count` header updates.
+    pub fn reject_next_updates(&self, client_id: &ClientId, count: u64) {
+        self.update(client_id, |behaviour| behaviour.rejected_updates = count);
+    }
+
+    /// Makes the client report the given status, e.g. `Frozen` or `Expired`,
+    /// regardless of its state.
+    pub fn force_status(&self, client_id: &ClientId, status: Status) {
+        self.update(client_id, |behaviour| {
+            behaviour.forced_status = Some(status)
+        });
+    }
+
+    /// Makes the client report the headers it receives as conflicting with its
+    /// consensus states, which the update handler treats as misbehaviour.
+    pub fn produce_conflicting_headers(&self, client_id: &ClientId, enabled: bool) {
+        self.update(client_id, |behaviour| {
+            behaviour.conflicting_headers = enabled
+        });
+    }
+
+    /// Restores the regular behaviour of the client.
+    pub fn reset(&self, client_id: &ClientId) {
+        self.behaviours.lock().remove(client_id);
+    }
+
+    /// Returns the failure modes currently injected into the client.
+    pub fn behaviour(&self, client_id: &ClientId) -> MockClientBehaviour {
+        self.behaviours
+            .lock()
+            .get(client_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the client must reject the current update, consuming
+    /// one of the pending rejections.
+    pub fn take_update_rejection(&self, client_id: &ClientId) -> bool {
+        let mut behaviours = self.behaviours.lock();
+
+        match behaviours.get_mut(client_id) {
+            Some(behaviour) if behaviour.rejected_updates > 0 => {
+                behaviour.rejected_updates -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn update(&self, client_id: &ClientId, f: impl FnOnce(&mut MockClientBehaviour)) {
+        f(self.behaviours.lock().entry(client_id.clone()).or_default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_rejections_are_consumed() {
+        let client_id = ClientId::new("9999-mock", 0).expect("no error");
+        let handle = MockClientHandle::default();
+
+        handle.reject_next_updates(&client_id, 2);
+
+        assert!(handle.clone().take_update_rejection(&client_id));
+        assert!(handle.take_update_rejection(&client_id));
+        assert!(!handle.take_update_rejection(&client_id));
+
+        handle.force_status(&client_id, Status::Frozen);
+        assert_eq!(
+            handle.behaviour(&client_id).forced_status,
+            Some(Status::Frozen)
+        );
+
+        handle.reset(&client_id);
+        assert_eq!(handle.behaviour(&client_id), MockClientBehaviour::default());
+    }
+}
@@ -3,7 +3,7 @@ use core::time::Duration;
 
 use ibc::core::client::context::prelude::*;
 use ibc::core::client::types::error::{ClientError, UpgradeClientError};
-use ibc::core::client::types::{Height, Status};
+use ibc::core::client::types::{FrozenHeight, Height, Status, UpdateMeta};
 use ibc::core::commitment_types::commitment::{
     CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
 };
@@ -11,11 +11,15 @@ use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::{ClientId, ClientType};
 use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath, Path};
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{CommitmentHasher, Timestamp};
 use ibc::primitives::proto::{Any, Protobuf};
 
+use crate::testapp::ibc::clients::mock::behaviour::MockClientHandle;
 use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
-use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use crate::testapp::ibc::clients::mock::commitment::{self, empty_root_hash};
+use crate::testapp::ibc::clients::mock::consensus_state::{
+    MockConsensusState, MOCK_UNVERIFIED_ROOT,
+};
 use crate::testapp::ibc::clients::mock::header::{MockHeader, MOCK_HEADER_TYPE_URL};
 use crate::testapp::ibc::clients::mock::misbehaviour::{Misbehaviour, MOCK_MISBEHAVIOUR_TYPE_URL};
 use crate::testapp::ibc::clients::mock::proto::ClientState as RawMockClientState;
@@ -34,7 +38,7 @@ pub fn client_type() -> ClientType {
 pub struct MockClientState {
     pub header: MockHeader,
     pub trusting_period: Duration,
-    pub frozen: bool,
+    pub frozen_height: FrozenHeight,
 }
 
 impl MockClientState {
@@ -43,9 +47,10 @@ impl MockClientState {
     /// needs to be changed, use the `with_trusting_period` method to override it.
     pub fn new(header: MockHeader) -> Self {
         Self {
-            header,
+            // the commitment root is tracked by the consensus states only
+            header: header.with_root(empty_root_hash()),
             trusting_period: Duration::from_secs(64000),
-            frozen: false,
+            frozen_height: FrozenHeight::UNFROZEN,
         }
     }
 
@@ -64,22 +69,26 @@ impl MockClientState {
         }
     }
 
-    pub fn frozen(self) -> Self {
-        Self {
-            frozen: true,
-            ..self
-        }
+    pub fn frozen(mut self) -> Self {
+        self.freeze(FrozenHeight::SENTINEL_HEIGHT);
+        self
     }
 
-    pub fn unfrozen(self) -> Self {
-        Self {
-            frozen: false,
-            ..self
-        }
+    pub fn unfrozen(mut self) -> Self {
+        self.unfreeze();
+        self
+    }
+
+    pub fn freeze(&mut self, height: Height) {
+        self.frozen_height.freeze(height);
+    }
+
+    pub fn unfreeze(&mut self) {
+        self.frozen_height.unfreeze();
     }
 
     pub fn is_frozen(&self) -> bool {
-        self.frozen
+        self.frozen_height.is_frozen()
     }
 
     fn expired(&self, elapsed: Duration) -> bool {
@@ -101,7 +110,12 @@ impl TryFrom<RawMockClientState> for MockClientState {
                 })?
                 .try_into()?,
             trusting_period: Duration::from_nanos(raw.trusting_period),
-            frozen: raw.frozen,
+            // The mock encoding only records whether the client is frozen.
+            frozen_height: if raw.frozen {
+                FrozenHeight::SENTINEL
+            } else {
+                FrozenHeight::UNFROZEN
+            },
         })
     }
 }
@@ -115,7 +129,7 @@ impl From<MockClientState> for RawMockClientState {
                 .as_nanos()
                 .try_into()
                 .expect("no overflow"),
-            frozen: value.frozen,
+            frozen: value.frozen_height.is_frozen(),
         }
     }
 }
@@ -157,6 +171,12 @@ pub trait MockClientContext {
 
     /// Returns the current height of the local chain.
     fn host_height(&self) -> Result<Height, ContextError>;
+
+    /// Returns the handle controlling the failure modes injected into the mock
+    /// clients, if the context supports them.
+    fn mock_client_handle(&self) -> Option<&MockClientHandle> {
+        None
+    }
 }
 
 impl ClientStateCommon for MockClientState {
@@ -184,6 +204,12 @@ impl ClientStateCommon for MockClientState {
         Ok(())
     }
 
+    /// Mock chains are built alongside the hosts tracking them, hence share
+    /// their hash function.
+    fn commitment_hasher(&self) -> Result<CommitmentHasher, ClientError> {
+        Ok(CommitmentHasher::host())
+    }
+
     fn verify_upgrade_client(
         &self,
         upgraded_client_state: Any,
@@ -192,6 +218,8 @@ impl ClientStateCommon for MockClientState {
         _proof_upgrade_consensus_state: CommitmentProofBytes,
         _root: &CommitmentRoot,
     ) -> Result<(), ClientError> {
+        self.frozen_height.verify_not_frozen()?;
+
         let upgraded_mock_client_state = Self::try_from(upgraded_client_state)?;
         MockConsensusState::try_from(upgraded_consensus_state)?;
         if self.latest_height() >= upgraded_mock_client_state.latest_height() {
@@ -203,25 +231,53 @@ impl ClientStateCommon for MockClientState {
         Ok(())
     }
 
+    /// Verifies proofs of a [`MockCommitmentTree`](commitment::MockCommitmentTree),
+    /// unless the consensus state opted out with
+    /// [`MockConsensusState::with_unverified_proofs`] or
+    /// [`MockHeader::with_unverified_proofs`].
     fn verify_membership(
         &self,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _root: &CommitmentRoot,
-        _path: Path,
-        _value: Vec<u8>,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
     ) -> Result<(), ClientError> {
-        Ok(())
+        self.frozen_height.verify_not_frozen()?;
+
+        if root.as_bytes() == MOCK_UNVERIFIED_ROOT {
+            return Ok(());
+        }
+
+        commitment::verify_membership(prefix, proof, root, &path, &value)
     }
 
     fn verify_non_membership(
         &self,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _root: &CommitmentRoot,
-        _path: Path,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
     ) -> Result<(), ClientError> {
-        Ok(())
+        self.frozen_height.verify_not_frozen()?;
+
+        if root.as_bytes() == MOCK_UNVERIFIED_ROOT {
+            return Ok(());
+        }
+
+        commitment::verify_non_membership(prefix, proof, root, &path)
+    }
+
+    /// Counts a mock header as carrying a single signature, and a mock
+    /// misbehaviour as carrying those of its two headers.
+    fn client_message_signature_count(&self, client_message: &Any) -> Result<usize, ClientError> {
+        match client_message.type_url.as_str() {
+            MOCK_HEADER_TYPE_URL => Ok(1),
+            MOCK_MISBEHAVIOUR_TYPE_URL => Ok(2),
+            header_type => Err(ClientError::UnknownHeaderType {
+                header_type: header_type.to_owned(),
+            }),
+        }
     }
 }
 
@@ -233,13 +289,24 @@ where
 {
     fn verify_client_message(
         &self,
-        _ctx: &V,
-        _client_id: &ClientId,
+        ctx: &V,
+        client_id: &ClientId,
         client_message: Any,
     ) -> Result<(), ClientError> {
+        self.frozen_height.verify_not_frozen()?;
+
         match client_message.type_url.as_str() {
             MOCK_HEADER_TYPE_URL => {
                 let _header = MockHeader::try_from(client_message)?;
+
+                if ctx
+                    .mock_client_handle()
+                    .is_some_and(|handle| handle.take_update_rejection(client_id))
+                {
+                    return Err(ClientError::HeaderVerificationFailure {
+                        reason: "update rejected by the mock client handle".to_string(),
+                    });
+                }
             }
             MOCK_MISBEHAVIOUR_TYPE_URL => {
                 let _misbehaviour = Misbehaviour::try_from(client_message)?;
@@ -252,12 +319,14 @@ where
 
     fn check_for_misbehaviour(
         &self,
-        _ctx: &V,
-        _client_id: &ClientId,
+        ctx: &V,
+        client_id: &ClientId,
         client_message: Any,
     ) -> Result<bool, ClientError> {
         match client_message.type_url.as_str() {
-            MOCK_HEADER_TYPE_URL => Ok(false),
+            MOCK_HEADER_TYPE_URL => Ok(ctx
+                .mock_client_handle()
+                .is_some_and(|handle| handle.behaviour(client_id).conflicting_headers)),
             MOCK_MISBEHAVIOUR_TYPE_URL => {
                 let misbehaviour = Misbehaviour::try_from(client_message)?;
                 let header_1 = misbehaviour.header1;
@@ -275,6 +344,13 @@ where
     }
 
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if let Some(status) = ctx
+            .mock_client_handle()
+            .and_then(|handle| handle.behaviour(client_id).forced_status)
+        {
+            return Ok(status);
+        }
+
         if self.is_frozen() {
             return Ok(Status::Frozen);
         }
@@ -338,8 +414,7 @@ where
         ctx.store_update_meta(
             client_id.clone(),
             self.latest_height(),
-            ctx.host_timestamp()?,
-            ctx.host_height()?,
+            UpdateMeta::new(ctx.host_timestamp()?, ctx.host_height()?),
         )?;
 
         Ok(())
@@ -372,8 +447,7 @@ where
         ctx.store_update_meta(
             client_id.clone(),
             header_height,
-            ctx.host_timestamp()?,
-            ctx.host_height()?,
+            UpdateMeta::new(ctx.host_timestamp()?, ctx.host_height()?),
         )?;
 
         Ok(vec![header_height])
@@ -426,8 +500,7 @@ where
         ctx.store_update_meta(
             client_id.clone(),
             latest_height,
-            host_timestamp,
-            host_height,
+            UpdateMeta::new(host_timestamp, host_height),
         )?;
 
         Ok(latest_height)
@@ -444,10 +517,7 @@ where
 
         let latest_height = substitute_client_state.latest_height();
 
-        let new_mock_client_state = MockClientState {
-            frozen: false,
-            ..substitute_client_state
-        };
+        let new_mock_client_state = substitute_client_state.unfrozen();
 
         let host_timestamp = ctx.host_timestamp()?;
         let host_height = ctx.host_height()?;
@@ -471,8 +541,7 @@ where
         ctx.store_update_meta(
             subject_client_id.clone(),
             latest_height,
-            host_timestamp,
-            host_height,
+            UpdateMeta::new(host_timestamp, host_height),
         )?;
 
         Ok(())
@@ -0,0 +1,574 @@
+//! A simple binary Merkle tree committing to the state of a mock chain, along
+//! with the (non-)membership proofs verified by the mock light client.
+//!
+//! The tree follows the construction of RFC 9162: leaves are sorted by key,
+//! leaf hashes are domain-separated from inner node hashes, and the root of an
+//! empty tree is the hash of the empty string. Keys are the ICS-24 paths
+//! prefixed by the commitment prefix of the store.
+
+use ibc::core::client::types::error::ClientError;
+use ibc::core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc::core::commitment_types::error::CommitmentError;
+use ibc::core::host::types::path::Path;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{HostHasher, Sha256Hasher};
+
+type Hash = [u8; 32];
+
+const LEAF_PREFIX: u8 = 0;
+const INNER_PREFIX: u8 = 1;
+
+const MEMBERSHIP_PROOF_TAG: u8 = 0;
+const NON_MEMBERSHIP_PROOF_TAG: u8 = 1;
+
+/// A binary Merkle tree over the `path -> value` entries of a mock chain.
+#[derive(Clone, Debug)]
+pub struct MockCommitmentTree {
+    prefix: CommitmentPrefix,
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl MockCommitmentTree {
+    /// Creates an empty tree whose keys are prefixed by the given
+    /// [`CommitmentPrefix`].
+    pub fn new(prefix: CommitmentPrefix) -> Self {
+        Self {
+            prefix,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Stores the value under the given path, replacing any previous value.
+    pub fn insert(&mut self, path: Path, value: Vec<u8>) {
+        let key = commitment_key(&self.prefix, &path);
+        self.entries.insert(key, value);
+    }
+
+    /// Removes the value stored under the given path, if any.
+    pub fn remove(&mut self, path: &Path) -> Option<Vec<u8>> {
+        let key = commitment_key(&self.prefix, path);
+        self.entries.remove(&key)
+    }
+
+    /// Returns the number of entries committed to by the tree.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the tree holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the root of the tree, to be stored in the mock consensus states
+    /// of the counterparty.
+    pub fn root(&self) -> CommitmentRoot {
+        let leaves = self.leaf_hashes();
+        CommitmentRoot::from(subtree_root(&leaves).to_vec())
+    }
+
+    /// Returns the root of an empty tree, to which mock headers commit unless
+    /// they are produced by a chain with some state.
+    pub fn empty_root() -> CommitmentRoot {
+        CommitmentRoot::from(empty_root_hash().to_vec())
+    }
+
+    /// Returns a proof that the given path is part of the tree, or `None` if
+    /// nothing is stored under it.
+    pub fn prove_membership(&self, path: &Path) -> Option<CommitmentProofBytes> {
+        let key = commitment_key(&self.prefix, path);
+        let index = self.entries.keys().position(|k| *k == key)?;
+
+        MockCommitmentProof::Membership(self.leaf_proof(index))
+            .encode()
+            .try_into()
+            .ok()
+    }
+
+    /// Returns a proof that the given path is absent from the tree, or `None`
+    /// if a value is stored under it.
+    pub fn prove_non_membership(&self, path: &Path) -> Option<CommitmentProofBytes> {
+        let key = commitment_key(&self.prefix, path);
+        if self.entries.contains_key(&key) {
+            return None;
+        }
+
+        // index of the first entry whose key is greater than the absent key
+        let right_index = self.entries.keys().take_while(|k| **k < key).count();
+
+        let left = right_index
+            .checked_sub(1)
+            .map(|index| self.leaf_proof(index));
+        let right = (right_index < self.entries.len()).then(|| self.leaf_proof(right_index));
+
+        MockCommitmentProof::NonMembership {
+            size: self.entries.len() as u64,
+            left,
+            right,
+        }
+        .encode()
+        .try_into()
+        .ok()
+    }
+
+    fn leaf_hashes(&self) -> Vec<Hash> {
+        self.entries
+            .iter()
+            .map(|(key, value)| leaf_hash(key, &Sha256Hasher::hash(value)))
+            .collect()
+    }
+
+    fn leaf_proof(&self, index: usize) -> MockLeafProof {
+        let (key, value) = self
+            .entries
+            .iter()
+            .nth(index)
+            .expect("index is within the tree");
+
+        MockLeafProof {
+            index: index as u64,
+            size: self.entries.len() as u64,
+            key: key.clone(),
+            value_hash: Sha256Hasher::hash(value),
+            path: audit_path(index, &self.leaf_hashes()),
+        }
+    }
+}
+
+/// Verifies that `value` is stored under `path` in the tree with the given
+/// root.
+pub fn verify_membership(
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: &Path,
+    value: &[u8],
+) -> Result<(), ClientError> {
+    let MockCommitmentProof::Membership(leaf) = MockCommitmentProof::decode(proof.as_ref())? else {
+        return Err(verification_failure());
+    };
+
+    let key = commitment_key(prefix, path);
+    if leaf.key != key || leaf.value_hash != Sha256Hasher::hash(value) {
+        return Err(verification_failure());
+    }
+
+    leaf.verify(root)
+}
+
+/// Verifies that nothing is stored under `path` in the tree with the given
+/// root.
+pub fn verify_non_membership(
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: &Path,
+) -> Result<(), ClientError> {
+    let MockCommitmentProof::NonMembership { size, left, right } =
+        MockCommitmentProof::decode(proof.as_ref())?
+    else {
+        return Err(verification_failure());
+    };
+
+    let key = commitment_key(prefix, path);
+
+    match (left, right) {
+        (None, None) if size == 0 => {
+            if root.as_bytes() != subtree_root(&[]) {
+                return Err(verification_failure());
+            }
+        }
+        (Some(left), None) => {
+            if left.size != size || left.index + 1 != size || left.key >= key {
+                return Err(verification_failure());
+            }
+            left.verify(root)?;
+        }
+        (None, Some(right)) => {
+            if right.size != size || right.index != 0 || right.key <= key {
+                return Err(verification_failure());
+            }
+            right.verify(root)?;
+        }
+        (Some(left), Some(right)) => {
+            if left.size != size
+                || right.size != size
+                || left.index + 1 != right.index
+                || left.key >= key
+                || right.key <= key
+            {
+                return Err(verification_failure());
+            }
+            left.verify(root)?;
+            right.verify(root)?;
+        }
+        _ => return Err(verification_failure()),
+    }
+
+    Ok(())
+}
+
+/// A proof of (non-)membership in a [`MockCommitmentTree`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum MockCommitmentProof {
+    Membership(MockLeafProof),
+    /// Proves the absence of a key with the adjacent leaves surrounding it.
+    NonMembership {
+        size: u64,
+        left: Option<MockLeafProof>,
+        right: Option<MockLeafProof>,
+    },
+}
+
+impl MockCommitmentProof {
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        match self {
+            Self::Membership(leaf) => {
+                bytes.push(MEMBERSHIP_PROOF_TAG);
+                leaf.encode_into(&mut bytes);
+            }
+            Self::NonMembership { size, left, right } => {
+                bytes.push(NON_MEMBERSHIP_PROOF_TAG);
+                bytes.extend(size.to_be_bytes());
+                for leaf in [left, right] {
+                    match leaf {
+                        Some(leaf) => {
+                            bytes.push(1);
+                            leaf.encode_into(&mut bytes);
+                        }
+                        None => bytes.push(0),
+                    }
+                }
+            }
+        }
+
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, ClientError> {
+        let mut reader = Reader(bytes);
+
+        let proof = match reader.read_u8()? {
+            MEMBERSHIP_PROOF_TAG => Self::Membership(MockLeafProof::decode_from(&mut reader)?),
+            NON_MEMBERSHIP_PROOF_TAG => {
+                let size = reader.read_u64()?;
+                let left = reader.read_optional_leaf()?;
+                let right = reader.read_optional_leaf()?;
+                Self::NonMembership { size, left, right }
+            }
+            tag => return Err(decoding_failure(format!("unknown proof tag {tag}"))),
+        };
+
+        if !reader.0.is_empty() {
+            return Err(decoding_failure("trailing bytes"));
+        }
+
+        Ok(proof)
+    }
+}
+
+/// The audit path of a leaf, along with its position in the tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MockLeafProof {
+    index: u64,
+    size: u64,
+    key: Vec<u8>,
+    value_hash: Hash,
+    path: Vec<Hash>,
+}
+
+impl MockLeafProof {
+    /// Recomputes the root from the audit path, following RFC 9162 (2.1.3.2).
+    fn verify(&self, root: &CommitmentRoot) -> Result<(), ClientError> {
+        if self.index >= self.size {
+            return Err(verification_failure());
+        }
+
+        let mut fn_ = self.index;
+        let mut sn = self.size - 1;
+        let mut r = leaf_hash(&self.key, &self.value_hash);
+
+        for p in &self.path {
+            if sn == 0 {
+                return Err(verification_failure());
+            }
+
+            if fn_ & 1 == 1 || fn_ == sn {
+                r = inner_hash(p, &r);
+                while fn_ & 1 == 0 && fn_ != 0 {
+                    fn_ >>= 1;
+                    sn >>= 1;
+                }
+            } else {
+                r = inner_hash(&r, p);
+            }
+
+            fn_ >>= 1;
+            sn >>= 1;
+        }
+
+        if sn != 0 || root.as_bytes() != r {
+            return Err(verification_failure());
+        }
+
+        Ok(())
+    }
+
+    fn encode_into(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.index.to_be_bytes());
+        bytes.extend(self.size.to_be_bytes());
+        bytes.extend((self.key.len() as u32).to_be_bytes());
+        bytes.extend(&self.key);
+        bytes.extend(self.value_hash);
+        bytes.extend((self.path.len() as u32).to_be_bytes());
+        for hash in &self.path {
+            bytes.extend(hash);
+        }
+    }
+
+    fn decode_from(reader: &mut Reader<'_>) -> Result<Self, ClientError> {
+        let index = reader.read_u64()?;
+        let size = reader.read_u64()?;
+        let key_len = reader.read_u32()? as usize;
+        let key = reader.read_bytes(key_len)?.to_vec();
+        let value_hash = reader.read_hash()?;
+        let path_len = reader.read_u32()?;
+        let path = (0..path_len)
+            .map(|_| reader.read_hash())
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            index,
+            size,
+            key,
+            value_hash,
+            path,
+        })
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], ClientError> {
+        if self.0.len() < len {
+            return Err(decoding_failure("unexpected end of proof"));
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, ClientError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32, ClientError> {
+        let bytes = self.read_bytes(4)?;
+        Ok(u32::from_be_bytes(bytes.try_into().expect("4 bytes")))
+    }
+
+    fn read_u64(&mut self) -> Result<u64, ClientError> {
+        let bytes = self.read_bytes(8)?;
+        Ok(u64::from_be_bytes(bytes.try_into().expect("8 bytes")))
+    }
+
+    fn read_hash(&mut self) -> Result<Hash, ClientError> {
+        let bytes = self.read_bytes(32)?;
+        Ok(bytes.try_into().expect("32 bytes"))
+    }
+
+    fn read_optional_leaf(&mut self) -> Result<Option<MockLeafProof>, ClientError> {
+        match self.read_u8()? {
+            0 => Ok(None),
+            1 => MockLeafProof::decode_from(self).map(Some),
+            flag => Err(decoding_failure(format!("invalid presence flag {flag}"))),
+        }
+    }
+}
+
+fn commitment_key(prefix: &CommitmentPrefix, path: &Path) -> Vec<u8> {
+    let mut key = prefix.as_bytes().to_vec();
+    key.push(b'/');
+    key.extend(path.to_string().as_bytes());
+    key
+}
+
+fn leaf_hash(key: &[u8], value_hash: &Hash) -> Hash {
+    let mut data = vec![LEAF_PREFIX];
+    data.extend((key.len() as u64).to_be_bytes());
+    data.extend(key);
+    data.extend(value_hash);
+    Sha256Hasher::hash(&data)
+}
+
+fn inner_hash(left: &Hash, right: &Hash) -> Hash {
+    let mut data = vec![INNER_PREFIX];
+    data.extend(left);
+    data.extend(right);
+    Sha256Hasher::hash(&data)
+}
+
+/// Returns the largest power of two strictly smaller than `n`, with `n > 1`.
+fn split_point(n: usize) -> usize {
+    let mut k = 1;
+    while k << 1 < n {
+        k <<= 1;
+    }
+    k
+}
+
+pub(crate) fn empty_root_hash() -> [u8; 32] {
+    subtree_root(&[])
+}
+
+fn subtree_root(leaves: &[Hash]) -> Hash {
+    match leaves.len() {
+        0 => Sha256Hasher::hash(&[]),
+        1 => leaves[0],
+        n => {
+            let k = split_point(n);
+            inner_hash(&subtree_root(&leaves[..k]), &subtree_root(&leaves[k..]))
+        }
+    }
+}
+
+fn audit_path(index: usize, leaves: &[Hash]) -> Vec<Hash> {
+    if leaves.len() <= 1 {
+        return Vec::new();
+    }
+
+    let k = split_point(leaves.len());
+    if index < k {
+        let mut path = audit_path(index, &leaves[..k]);
+        path.push(subtree_root(&leaves[k..]));
+        path
+    } else {
+        let mut path = audit_path(index - k, &leaves[k..]);
+        path.push(subtree_root(&leaves[..k]));
+        path
+    }
+}
+
+fn verification_failure() -> ClientError {
+    ClientError::InvalidCommitmentProof(CommitmentError::VerificationFailure)
+}
+
+fn decoding_failure(reason: impl ToString) -> ClientError {
+    ClientError::InvalidCommitmentProof(CommitmentError::DecodingFailure(reason.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+    use ibc::core::host::types::path::{CommitmentPath, ReceiptPath};
+
+    use super::*;
+
+    fn commitment_path(seq: u64) -> Path {
+        Path::Commitment(CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            Sequence::from(seq),
+        ))
+    }
+
+    fn tree(size: u64) -> MockCommitmentTree {
+        let mut tree = MockCommitmentTree::new(CommitmentPrefix::cosmos());
+        for seq in 1..=size {
+            tree.insert(commitment_path(2 * seq), vec![seq as u8]);
+        }
+        tree
+    }
+
+    #[test]
+    fn test_membership_proofs() {
+        let prefix = CommitmentPrefix::cosmos();
+
+        for size in 1..=9 {
+            let tree = tree(size);
+            let root = tree.root();
+
+            for seq in 1..=size {
+                let path = commitment_path(2 * seq);
+                let proof = tree.prove_membership(&path).expect("path is stored");
+
+                assert!(verify_membership(&prefix, &proof, &root, &path, &[seq as u8]).is_ok());
+                assert!(verify_membership(&prefix, &proof, &root, &path, &[0xff]).is_err());
+                assert!(verify_membership(
+                    &prefix,
+                    &proof,
+                    &root,
+                    &commitment_path(1),
+                    &[seq as u8]
+                )
+                .is_err());
+                assert!(verify_non_membership(&prefix, &proof, &root, &path).is_err());
+            }
+
+            assert!(tree.prove_membership(&commitment_path(1)).is_none());
+        }
+    }
+
+    #[test]
+    fn test_non_membership_proofs() {
+        let prefix = CommitmentPrefix::cosmos();
+
+        for size in 0..=9 {
+            let tree = tree(size);
+            let root = tree.root();
+
+            // absent paths before, between and after the stored ones
+            for seq in (1..=2 * size + 1).step_by(2) {
+                let path = commitment_path(seq);
+                let proof = tree.prove_non_membership(&path).expect("path is absent");
+
+                assert!(verify_non_membership(&prefix, &proof, &root, &path).is_ok());
+            }
+
+            if size > 0 {
+                let stored = commitment_path(2);
+                assert!(tree.prove_non_membership(&stored).is_none());
+
+                // a proof for another absent path cannot be reused
+                let proof = tree.prove_non_membership(&commitment_path(1)).unwrap();
+                assert!(verify_non_membership(&prefix, &proof, &root, &stored).is_err());
+            }
+        }
+
+        let receipt = Path::Receipt(ReceiptPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            Sequence::from(1),
+        ));
+        let proof = tree(3).prove_non_membership(&receipt).unwrap();
+        assert!(verify_non_membership(&prefix, &proof, &tree(4).root(), &receipt).is_err());
+    }
+
+    #[test]
+    fn test_malformed_proofs_are_rejected() {
+        let tree = tree(3);
+        let path = commitment_path(2);
+        let proof: Vec<u8> = tree.prove_membership(&path).unwrap().into();
+
+        for bytes in [
+            proof[..proof.len() - 1].to_vec(),
+            [&proof[..], &[0u8][..]].concat(),
+            [&[2u8][..], &proof[1..]].concat(),
+        ] {
+            let proof = CommitmentProofBytes::try_from(bytes).unwrap();
+            assert!(verify_membership(
+                &CommitmentPrefix::cosmos(),
+                &proof,
+                &tree.root(),
+                &path,
+                &[1]
+            )
+            .is_err());
+        }
+    }
+}
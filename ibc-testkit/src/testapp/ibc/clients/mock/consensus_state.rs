@@ -5,10 +5,24 @@ use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::{Any, Protobuf};
 
+use crate::testapp::ibc::clients::mock::commitment::empty_root_hash;
+use crate::testapp::ibc::clients::mock::fields::{append_bytes_field, find_bytes_field};
 use crate::testapp::ibc::clients::mock::header::MockHeader;
 use crate::testapp::ibc::clients::mock::proto::ConsensusState as RawMockConsensusState;
 pub const MOCK_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.mock.ConsensusState";
 
+/// The root of mock headers and consensus states that opted out of proof
+/// verification, which no [`MockCommitmentTree`] hashes to.
+///
+/// [`MockCommitmentTree`]: crate::testapp::ibc::clients::mock::commitment::MockCommitmentTree
+pub const MOCK_UNVERIFIED_ROOT: [u8; 32] = [0; 32];
+
+/// The protobuf field numbers under which the commitment roots of the
+/// consensus state and of its header are appended to the encoding of
+/// [`RawMockConsensusState`], which has no such fields.
+const ROOT_FIELD_NUMBER: u64 = 2;
+const HEADER_ROOT_FIELD_NUMBER: u64 = 3;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MockConsensusState {
@@ -17,13 +31,29 @@ pub struct MockConsensusState {
 }
 
 impl MockConsensusState {
+    /// Creates the consensus state of the given header, which commits to the
+    /// root of the header.
     pub fn new(header: MockHeader) -> Self {
         Self {
             header,
-            root: CommitmentRoot::from(vec![0]),
+            root: CommitmentRoot::from_bytes(&header.root),
         }
     }
 
+    /// Commits the consensus state to the given root, e.g. the root of a
+    /// [`MockCommitmentTree`](super::commitment::MockCommitmentTree), so that
+    /// the mock client verifies proofs against it.
+    pub fn with_root(self, root: CommitmentRoot) -> Self {
+        Self { root, ..self }
+    }
+
+    /// Makes the mock client accept any proof against the consensus state,
+    /// for tests of the handler logic beyond proof verification that do not
+    /// build the state of the counterparty.
+    pub fn with_unverified_proofs(self) -> Self {
+        self.with_root(CommitmentRoot::from_bytes(&MOCK_UNVERIFIED_ROOT))
+    }
+
     pub fn timestamp(&self) -> Timestamp {
         self.header.timestamp
     }
@@ -37,10 +67,7 @@ impl TryFrom<RawMockConsensusState> for MockConsensusState {
     fn try_from(raw: RawMockConsensusState) -> Result<Self, Self::Error> {
         let raw_header = raw.header.ok_or(ClientError::MissingRawConsensusState)?;
 
-        Ok(Self {
-            header: raw_header.try_into()?,
-            root: CommitmentRoot::from(vec![0]),
-        })
+        Ok(Self::new(raw_header.try_into()?))
     }
 }
 
@@ -59,12 +86,21 @@ impl TryFrom<Any> for MockConsensusState {
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         fn decode_consensus_state(value: &[u8]) -> Result<MockConsensusState, ClientError> {
-            let mock_consensus_state =
-                Protobuf::<RawMockConsensusState>::decode(value).map_err(|e| {
-                    ClientError::Other {
-                        description: e.to_string(),
-                    }
+            let mut mock_consensus_state = Protobuf::<RawMockConsensusState>::decode(value)
+                .map_err(|e| ClientError::Other {
+                    description: e.to_string(),
                 })?;
+            if let Some(header_root) = find_bytes_field(value, HEADER_ROOT_FIELD_NUMBER)? {
+                let header_root: [u8; 32] =
+                    header_root.try_into().map_err(|_| ClientError::Other {
+                        description: "header commitment root is not 32 bytes long".into(),
+                    })?;
+                mock_consensus_state =
+                    MockConsensusState::new(mock_consensus_state.header.with_root(header_root));
+            }
+            if let Some(root) = find_bytes_field(value, ROOT_FIELD_NUMBER)? {
+                mock_consensus_state = mock_consensus_state.with_root(root.into());
+            }
             Ok(mock_consensus_state)
         }
         match raw.type_url.as_str() {
@@ -78,9 +114,21 @@ impl TryFrom<Any> for MockConsensusState {
 
 impl From<MockConsensusState> for Any {
     fn from(consensus_state: MockConsensusState) -> Self {
+        let header_root = consensus_state.header.root;
+        let root = (consensus_state.root.as_bytes() != header_root)
+            .then(|| consensus_state.root.clone().into_vec());
+
+        let mut value = Protobuf::<RawMockConsensusState>::encode_vec(consensus_state);
+        if header_root != empty_root_hash() {
+            append_bytes_field(&mut value, HEADER_ROOT_FIELD_NUMBER, &header_root);
+        }
+        if let Some(root) = root {
+            append_bytes_field(&mut value, ROOT_FIELD_NUMBER, &root);
+        }
+
         Self {
             type_url: MOCK_CONSENSUS_STATE_TYPE_URL.to_string(),
-            value: Protobuf::<RawMockConsensusState>::encode_vec(consensus_state),
+            value,
         }
     }
 }
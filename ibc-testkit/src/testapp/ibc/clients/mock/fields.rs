@@ -0,0 +1,84 @@
+//! Helpers to extend the protobuf encodings of the mock types, whose messages
+//! are defined by `ibc-proto`, with fields of their own. The appended fields
+//! are skipped by the decoders of the raw messages.
+
+use ibc::core::client::types::error::ClientError;
+use ibc::core::primitives::prelude::*;
+
+const WIRE_TYPE_VARINT: u64 = 0;
+const WIRE_TYPE_FIXED64: u64 = 1;
+const WIRE_TYPE_LEN: u64 = 2;
+const WIRE_TYPE_FIXED32: u64 = 5;
+
+/// Appends a length-delimited field to an encoded message.
+pub(super) fn append_bytes_field(buf: &mut Vec<u8>, field_number: u64, bytes: &[u8]) {
+    encode_varint((field_number << 3) | WIRE_TYPE_LEN, buf);
+    encode_varint(bytes.len() as u64, buf);
+    buf.extend(bytes);
+}
+
+/// Scans the top-level fields of an encoded message for the last
+/// length-delimited field with the given number.
+pub(super) fn find_bytes_field(
+    mut buf: &[u8],
+    field_number: u64,
+) -> Result<Option<Vec<u8>>, ClientError> {
+    let mut found = None;
+
+    while !buf.is_empty() {
+        let key = decode_varint(&mut buf)?;
+        let len = match key & 0x7 {
+            WIRE_TYPE_VARINT => {
+                decode_varint(&mut buf)?;
+                continue;
+            }
+            WIRE_TYPE_FIXED64 => 8,
+            WIRE_TYPE_LEN => decode_varint(&mut buf)? as usize,
+            WIRE_TYPE_FIXED32 => 4,
+            wire_type => {
+                return Err(ClientError::Other {
+                    description: format!("unsupported wire type {wire_type}"),
+                })
+            }
+        };
+
+        if buf.len() < len {
+            return Err(ClientError::Other {
+                description: "truncated field".into(),
+            });
+        }
+        let (field, rest) = buf.split_at(len);
+        buf = rest;
+
+        if key >> 3 == field_number && key & 0x7 == WIRE_TYPE_LEN {
+            found = Some(field.to_vec());
+        }
+    }
+
+    Ok(found)
+}
+
+fn encode_varint(mut value: u64, buf: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn decode_varint(buf: &mut &[u8]) -> Result<u64, ClientError> {
+    let mut value = 0;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = buf.split_first().ok_or(ClientError::Other {
+            description: "truncated varint".into(),
+        })?;
+        *buf = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte < 0x80 {
+            return Ok(value);
+        }
+    }
+    Err(ClientError::Other {
+        description: "varint overflow".into(),
+    })
+}
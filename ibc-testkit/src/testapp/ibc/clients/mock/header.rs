@@ -6,15 +6,25 @@ use ibc::core::client::types::Height;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::{Any, Protobuf};
 
+use crate::testapp::ibc::clients::mock::commitment::empty_root_hash;
+use crate::testapp::ibc::clients::mock::consensus_state::MOCK_UNVERIFIED_ROOT;
+use crate::testapp::ibc::clients::mock::fields::{append_bytes_field, find_bytes_field};
 use crate::testapp::ibc::clients::mock::proto::Header as RawMockHeader;
 
 pub const MOCK_HEADER_TYPE_URL: &str = "/ibc.mock.Header";
 
+/// The protobuf field number under which the commitment root is appended to
+/// the encoding of [`RawMockHeader`], which has no such field.
+const ROOT_FIELD_NUMBER: u64 = 3;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct MockHeader {
     pub height: Height,
     pub timestamp: Timestamp,
+    /// The root of the [`MockCommitmentTree`](super::commitment::MockCommitmentTree)
+    /// over the state of the chain at this height.
+    pub root: [u8; 32],
 }
 
 impl Default for MockHeader {
@@ -22,6 +32,7 @@ impl Default for MockHeader {
         Self {
             height: Height::min(0),
             timestamp: Timestamp::none(),
+            root: empty_root_hash(),
         }
     }
 }
@@ -54,6 +65,7 @@ impl TryFrom<RawMockHeader> for MockHeader {
                     description: err.to_string(),
                 }
             })?,
+            root: empty_root_hash(),
         })
     }
 }
@@ -76,19 +88,25 @@ impl MockHeader {
         Self {
             height,
             timestamp: Timestamp::none(),
-        }
-    }
-
-    pub fn with_current_timestamp(self) -> Self {
-        Self {
-            timestamp: Timestamp::now(),
-            ..self
+            root: empty_root_hash(),
         }
     }
 
     pub fn with_timestamp(self, timestamp: Timestamp) -> Self {
         Self { timestamp, ..self }
     }
+
+    /// Commits the header to the root of the state of the chain, against
+    /// which the mock client verifies proofs once it is updated with it.
+    pub fn with_root(self, root: [u8; 32]) -> Self {
+        Self { root, ..self }
+    }
+
+    /// Makes the mock client accept any proof against the consensus state of
+    /// the header, for tests driving a client with headers of no actual chain.
+    pub fn with_unverified_proofs(self) -> Self {
+        self.with_root(MOCK_UNVERIFIED_ROOT)
+    }
 }
 
 impl Protobuf<Any> for MockHeader {}
@@ -98,11 +116,21 @@ impl TryFrom<Any> for MockHeader {
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         match raw.type_url.as_str() {
-            MOCK_HEADER_TYPE_URL => Ok(Protobuf::<RawMockHeader>::decode_vec(&raw.value).map_err(
-                |e| ClientError::InvalidRawHeader {
-                    reason: e.to_string(),
-                },
-            )?),
+            MOCK_HEADER_TYPE_URL => {
+                let header = Protobuf::<RawMockHeader>::decode_vec(&raw.value).map_err(|e| {
+                    ClientError::InvalidRawHeader {
+                        reason: e.to_string(),
+                    }
+                })?;
+                match find_bytes_field(&raw.value, ROOT_FIELD_NUMBER)? {
+                    Some(root) => Ok(header.with_root(root.try_into().map_err(|_| {
+                        ClientError::InvalidRawHeader {
+                            reason: "commitment root is not 32 bytes long".into(),
+                        }
+                    })?)),
+                    None => Ok(header),
+                }
+            }
             _ => Err(ClientError::UnknownHeaderType {
                 header_type: raw.type_url,
             }),
@@ -112,9 +140,14 @@ impl TryFrom<Any> for MockHeader {
 
 impl From<MockHeader> for Any {
     fn from(header: MockHeader) -> Self {
+        let mut value = Protobuf::<RawMockHeader>::encode_vec(header);
+        if header.root != empty_root_hash() {
+            append_bytes_field(&mut value, ROOT_FIELD_NUMBER, &header.root);
+        }
+
         Self {
             type_url: MOCK_HEADER_TYPE_URL.to_string(),
-            value: Protobuf::<RawMockHeader>::encode_vec(header),
+            value,
         }
     }
 }
@@ -138,4 +171,12 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn any_round_trip_keeps_root() {
+        let header = MockHeader::new(Height::new(1, 10).expect("Never fails")).with_root([7; 32]);
+        let decoded = MockHeader::try_from(Any::from(header)).expect("Never fails");
+
+        assert_eq!(decoded, header);
+    }
 }
@@ -1,6 +1,9 @@
 //! Definitions of ibc mock types used in testing.
+pub mod behaviour;
 pub mod client_state;
+pub mod commitment;
 pub mod consensus_state;
+mod fields;
 pub mod header;
 pub mod misbehaviour;
 
@@ -5,18 +5,24 @@ use basecoin_store::types::Height as StoreHeight;
 use ibc::core::client::context::{
     ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
 };
-use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::error::{ClientError, UpgradeClientError};
+use ibc::core::client::types::{Height, UpdateMeta};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId};
 use ibc::core::host::types::path::{
     ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath, ClientUpdateTimePath, Path,
+    UpgradeClientPath,
 };
 use ibc::core::host::ValidationContext;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::cosmos_host::upgrade_proposal::{
+    Plan, UpgradeValidationContext, UpgradedClientStateRef, UpgradedConsensusStateRef,
+};
 use ibc::primitives::prelude::*;
 
 use super::types::MockIbcStore;
+use crate::fixtures::core::signer::dummy_account_id;
+use crate::testapp::ibc::clients::mock::behaviour::MockClientHandle;
 use crate::testapp::ibc::clients::mock::client_state::MockClientContext;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 
@@ -44,6 +50,10 @@ where
     fn host_height(&self) -> Result<Height, ContextError> {
         ValidationContext::host_height(self)
     }
+
+    fn mock_client_handle(&self) -> Option<&MockClientHandle> {
+        Some(&self.mock_client_handle)
+    }
 }
 
 impl<S> ExtClientValidationContext for MockIbcStore<S>
@@ -150,6 +160,19 @@ where
 
         Ok(consensus_state)
     }
+
+    /// Only the dummy account is the recovery authority of the mock host, so
+    /// that tests can recover clients as well as be denied doing so.
+    fn validate_client_recovery_authority(&self, signer: &Signer) -> Result<(), ContextError> {
+        if signer != &dummy_account_id() {
+            return Err(ClientError::UnauthorizedClientRecovery {
+                signer: signer.clone(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
 }
 
 impl<S> ClientValidationContext for MockIbcStore<S>
@@ -194,7 +217,7 @@ where
         &self,
         client_id: &ClientId,
         height: &Height,
-    ) -> Result<(Timestamp, Height), ContextError> {
+    ) -> Result<UpdateMeta, ContextError> {
         let client_update_time_path = ClientUpdateTimePath::new(
             client_id.clone(),
             height.revision_number(),
@@ -220,7 +243,7 @@ where
                 height: *height,
             })?;
 
-        Ok((processed_timestamp, processed_height))
+        Ok(UpdateMeta::new(processed_timestamp, processed_height))
     }
 }
 
@@ -267,6 +290,22 @@ where
         Ok(())
     }
 
+    fn unindex_consensus_state_processed_time(
+        &mut self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(), ContextError> {
+        self.secondary_indexes
+            .lock()
+            .consensus_states_by_processed_time
+            .retain(|_, states| {
+                states.remove(&(client_id.clone(), *height));
+                !states.is_empty()
+            });
+
+        Ok(())
+    }
+
     /// Delete the update metadata associated with the client at the specified
     /// height.
     fn delete_update_meta(
@@ -297,8 +336,7 @@ where
         &mut self,
         client_id: ClientId,
         height: Height,
-        host_timestamp: Timestamp,
-        host_height: Height,
+        update_meta: UpdateMeta,
     ) -> Result<(), ContextError> {
         let client_update_time_path = ClientUpdateTimePath::new(
             client_id.clone(),
@@ -306,7 +344,7 @@ where
             height.revision_height(),
         );
         self.client_processed_times
-            .set(client_update_time_path, host_timestamp)
+            .set(client_update_time_path, update_meta.processed_time)
             .map_err(|_| ClientError::Other {
                 description: "store update error".into(),
             })?;
@@ -316,10 +354,43 @@ where
             height.revision_height(),
         );
         self.client_processed_heights
-            .set(client_update_height_path, host_height)
+            .set(client_update_height_path, update_meta.processed_height)
             .map_err(|_| ClientError::Other {
                 description: "store update error".into(),
             })?;
         Ok(())
     }
 }
+
+/// The mock host never schedules an upgrade, so that it has neither an upgrade
+/// plan nor upgraded states.
+impl<S> UpgradeValidationContext for MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    type V = Self;
+
+    fn upgrade_plan(&self) -> Result<Plan, UpgradeClientError> {
+        Err(UpgradeClientError::InvalidUpgradePlan {
+            reason: "the mock host schedules no upgrade".into(),
+        })
+    }
+
+    fn upgraded_client_state(
+        &self,
+        upgrade_path: &UpgradeClientPath,
+    ) -> Result<UpgradedClientStateRef<Self>, UpgradeClientError> {
+        Err(UpgradeClientError::Other {
+            reason: format!("no upgraded client state at `{upgrade_path}`"),
+        })
+    }
+
+    fn upgraded_consensus_state(
+        &self,
+        upgrade_path: &UpgradeClientPath,
+    ) -> Result<UpgradedConsensusStateRef<Self>, UpgradeClientError> {
+        Err(UpgradeClientError::Other {
+            reason: format!("no upgraded consensus state at `{upgrade_path}`"),
+        })
+    }
+}
@@ -1,16 +1,22 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
+use alloc::collections::BTreeSet;
 use core::fmt::Debug;
 use core::time::Duration;
 
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::types::Height as StoreHeight;
+use ibc::core::channel::context::RelayerPayeeExecutionContext;
 use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
+use ibc::core::channel::types::limits::PacketSizeLimits;
 use ibc::core::channel::types::packet::{PacketState, Receipt};
+use ibc::core::channel::types::packet_data::DynPacketDataCodec;
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::client::context::consensus_state::ConsensusState;
 use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::params::ClientParams;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
 use ibc::core::commitment_types::merkle::MerkleProof;
@@ -18,15 +24,23 @@ use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::{ConnectionEnd, IdentifiedConnectionEnd};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::handler::types::log::HandlerLog;
+use ibc::core::handler::types::mode::HostMode;
+use ibc::core::host::types::identifiers::{
+    ClientId, ConnectionId, PortId, Sequence, ValidationProfile,
+};
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath,
-    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, Path, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    CounterpartyPayeePath, NextChannelSequencePath, NextClientSequencePath,
+    NextConnectionSequencePath, Path, PayeePath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc::core::host::{
+    ClientStateRef, ConsensusStateRef, ExecutionContext, IbcPerBlockHooks, ResourceMeter,
+    ValidationContext,
 };
-use ibc::core::host::{ClientStateRef, ConsensusStateRef, ExecutionContext, ValidationContext};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::types::module::ModuleId;
 use ibc::primitives::ToVec;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
 use ibc_query::core::context::{ProvableContext, QueryContext};
@@ -157,6 +171,30 @@ where
             .ok_or(ChannelError::MissingChannel)?)
     }
 
+    fn channel_owner(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Option<ModuleId>, ContextError> {
+        Ok(self.channel_owners.lock().get(channel_end_path).cloned())
+    }
+
+    fn payee(&self, payee_path: &PayeePath) -> Result<Option<Signer>, ContextError> {
+        Ok(self
+            .payee_store
+            .get(StoreHeight::Pending, payee_path)
+            .map(Signer::from))
+    }
+
+    fn counterparty_payee(
+        &self,
+        counterparty_payee_path: &CounterpartyPayeePath,
+    ) -> Result<Option<Signer>, ContextError> {
+        Ok(self
+            .counterparty_payee_store
+            .get(StoreHeight::Pending, counterparty_payee_path)
+            .map(Signer::from))
+    }
+
     fn get_next_sequence_send(
         &self,
         seq_send_path: &SeqSendPath,
@@ -263,6 +301,34 @@ where
         Ok(())
     }
 
+    fn identifier_validation_profile(&self) -> ValidationProfile {
+        self.identifier_validation_profile
+    }
+
+    fn resource_meter(&self) -> &dyn ResourceMeter {
+        &self.resource_meter
+    }
+
+    fn host_mode(&self) -> HostMode {
+        self.host_mode
+    }
+
+    fn packet_size_limits(&self) -> PacketSizeLimits {
+        self.packet_size_limits
+    }
+
+    fn packet_data_codec(&self, port_id: &PortId) -> Option<&dyn DynPacketDataCodec> {
+        self.packet_data_codecs.get(port_id).map(|codec| &**codec)
+    }
+
+    fn client_params(&self) -> Result<ClientParams, ContextError> {
+        Ok(self.client_params.clone())
+    }
+
+    fn validate_client_params_authority(&self, _signer: &Signer) -> Result<(), ContextError> {
+        Ok(())
+    }
+
     fn get_client_validation_context(&self) -> &Self::V {
         self
     }
@@ -391,6 +457,15 @@ where
             .collect::<Result<Vec<_>, _>>()
     }
 
+    /// Returns the client types allowed to be created, as overridden for the
+    /// client params query if set.
+    fn allowed_clients(&self) -> Result<Vec<String>, ContextError> {
+        match &self.queried_allowed_clients {
+            Some(allowed_clients) => Ok(allowed_clients.clone()),
+            None => Ok(self.client_params()?.allowed_clients),
+        }
+    }
+
     /// Returns all the IBC connection ends of a chain.
     fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, ContextError> {
         let path = "connections".to_owned().into();
@@ -562,6 +637,40 @@ where
             .collect::<Result<Vec<_>, _>>()
     }
 
+    /// Returns the sequences of the packets received on a channel.
+    fn packet_receipts(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let receipt_path_prefix = format!(
+            "receipts/ports/{}/channels/{}/sequences",
+            channel_end_path.0, channel_end_path.1
+        )
+        .try_into()
+        .map_err(|_| PacketError::Other {
+            description: "Invalid receipt path".into(),
+        })?;
+
+        Ok(self
+            .packet_receipt_store
+            .get_keys(&receipt_path_prefix)
+            .into_iter()
+            .filter_map(|path| {
+                if let Ok(Path::Receipt(receipt_path)) = path.try_into() {
+                    Some(receipt_path)
+                } else {
+                    None
+                }
+            })
+            .filter(|receipt_path| {
+                self.packet_receipt_store
+                    .get(StoreHeight::Pending, receipt_path)
+                    .is_some()
+            })
+            .map(|receipt_path| receipt_path.sequence)
+            .collect())
+    }
+
     /// Returns the unreceived IBC packets associated with a channel and sequences.
     ///
     /// Takes a sequence list as an argument.
@@ -776,6 +885,17 @@ where
         Ok(())
     }
 
+    fn store_channel_owner(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        module_id: ModuleId,
+    ) -> Result<(), ContextError> {
+        self.channel_owners
+            .lock()
+            .insert(channel_end_path.clone(), module_id);
+        Ok(())
+    }
+
     fn store_next_sequence_send(
         &mut self,
         seq_send_path: &SeqSendPath,
@@ -831,8 +951,171 @@ where
         Ok(())
     }
 
-    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
-        self.logs.lock().push(message);
+    fn log(&mut self, log: HandlerLog) -> Result<(), ContextError> {
+        self.logs.lock().push(log);
+        Ok(())
+    }
+
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.client_params = params;
+        Ok(())
+    }
+
+    fn index_packet_timeout(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        timeout_height: &TimeoutHeight,
+        timeout_timestamp: &Timestamp,
+    ) -> Result<(), ContextError> {
+        let mut indexes = self.secondary_indexes.lock();
+
+        if let TimeoutHeight::At(height) = timeout_height {
+            indexes
+                .packets_by_timeout_height
+                .entry(*height)
+                .or_default()
+                .insert(commitment_path.clone());
+        }
+
+        if timeout_timestamp.is_set() {
+            indexes
+                .packets_by_timeout_timestamp
+                .entry(timeout_timestamp.nanoseconds())
+                .or_default()
+                .insert(commitment_path.clone());
+        }
+
+        Ok(())
+    }
+
+    fn unindex_packet_timeout(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        let mut indexes = self.secondary_indexes.lock();
+
+        indexes.packets_by_timeout_height.retain(|_, paths| {
+            paths.remove(commitment_path);
+            !paths.is_empty()
+        });
+        indexes.packets_by_timeout_timestamp.retain(|_, paths| {
+            paths.remove(commitment_path);
+            !paths.is_empty()
+        });
+
+        Ok(())
+    }
+
+    fn index_consensus_state_processed_time(
+        &mut self,
+        client_id: &ClientId,
+        height: &Height,
+        processed_time: &Timestamp,
+    ) -> Result<(), ContextError> {
+        self.secondary_indexes
+            .lock()
+            .consensus_states_by_processed_time
+            .entry(processed_time.nanoseconds())
+            .or_default()
+            .insert((client_id.clone(), *height));
+
+        Ok(())
+    }
+
+    fn index_client_update(
+        &mut self,
+        client_id: &ClientId,
+        consensus_height: &Height,
+        host_height: &Height,
+    ) -> Result<(), ContextError> {
+        self.secondary_indexes
+            .lock()
+            .client_updates_by_height
+            .entry(*host_height)
+            .or_default()
+            .insert((client_id.clone(), *consensus_height));
+
+        Ok(())
+    }
+}
+
+/// Checks all the clients for expiry and the pending packets found in the
+/// secondary indexes for timeouts, logging the packets whose timeout elapsed.
+impl<S> IbcPerBlockHooks for MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    fn clients_to_check_for_expiry(&self) -> Result<Vec<ClientId>, ContextError> {
+        Ok(self
+            .client_states()?
+            .into_iter()
+            .map(|(client_id, _)| client_id)
+            .collect())
+    }
+
+    fn channels_with_pending_packets(&self) -> Result<Vec<ChannelEndPath>, ContextError> {
+        let indexes = self.secondary_indexes.lock();
+
+        let channels = indexes
+            .packets_by_timeout_height
+            .values()
+            .chain(indexes.packets_by_timeout_timestamp.values())
+            .flatten()
+            .map(|path| ChannelEndPath::new(&path.port_id, &path.channel_id))
+            .collect::<BTreeSet<_>>();
+
+        Ok(channels.into_iter().collect())
+    }
+
+    fn timed_out_packets(
+        &self,
+        chan_end_path: &ChannelEndPath,
+        counterparty_height: &Height,
+        counterparty_timestamp: &Timestamp,
+    ) -> Result<Vec<CommitmentPath>, ContextError> {
+        Ok(self
+            .secondary_indexes
+            .lock()
+            .timed_out_packets(counterparty_height, counterparty_timestamp)
+            .into_iter()
+            .filter(|path| path.port_id == chan_end_path.0 && path.channel_id == chan_end_path.1)
+            .collect())
+    }
+
+    fn on_packet_timeout_elapsed(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.log_message(format!("packet timeout elapsed: {commitment_path}"))
+    }
+}
+
+impl<S> RelayerPayeeExecutionContext for MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    fn store_payee(&mut self, payee_path: &PayeePath, payee: Signer) -> Result<(), ContextError> {
+        self.payee_store
+            .set(payee_path.clone(), payee.to_string())
+            .map_err(|e| ChannelError::Other {
+                description: format!("payee storage failed: {e:?}"),
+            })?;
+        Ok(())
+    }
+
+    fn store_counterparty_payee(
+        &mut self,
+        counterparty_payee_path: &CounterpartyPayeePath,
+        counterparty_payee: Signer,
+    ) -> Result<(), ContextError> {
+        self.counterparty_payee_store
+            .set(
+                counterparty_payee_path.clone(),
+                counterparty_payee.to_string(),
+            )
+            .map_err(|e| ChannelError::Other {
+                description: format!("counterparty payee storage failed: {e:?}"),
+            })?;
         Ok(())
     }
 }
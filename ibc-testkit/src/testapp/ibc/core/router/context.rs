@@ -2,11 +2,28 @@ use alloc::sync::Arc;
 
 use ibc::core::host::types::identifiers::PortId;
 use ibc::core::router::module::Module;
+use ibc::core::router::port::PortKeeper;
 use ibc::core::router::router::Router;
+use ibc::core::router::types::error::RouterError;
 use ibc::core::router::types::module::ModuleId;
 
 use super::types::MockRouter;
 
+impl PortKeeper for MockRouter {
+    fn port_owner(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.port_to_module.get(port_id).cloned()
+    }
+
+    fn store_port_owner(
+        &mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+    ) -> Result<(), RouterError> {
+        self.port_to_module.insert(port_id, module_id);
+        Ok(())
+    }
+}
+
 impl Router for MockRouter {
     fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
         self.router.get(module_id).map(Arc::as_ref)
@@ -26,8 +43,4 @@ impl Router for MockRouter {
             None => None,
         }
     }
-
-    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
-        self.port_to_module.get(port_id).cloned()
-    }
 }
@@ -4,6 +4,7 @@ use ibc::apps::transfer::types::MODULE_ID_STR;
 use ibc::core::host::types::identifiers::PortId;
 use ibc::core::primitives::prelude::*;
 use ibc::core::router::module::Module;
+use ibc::core::router::port::bind_port;
 use ibc::core::router::types::module::ModuleId;
 
 use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
@@ -22,7 +23,7 @@ impl MockRouter {
 
         let module_id = ModuleId::new(MODULE_ID_STR.to_string());
 
-        router.scope_port_to_module(PortId::transfer(), module_id.clone());
+        bind_port(&mut router, PortId::transfer(), module_id.clone()).expect("Never fails");
 
         let transfer_mod = DummyTransferModule::new();
 
@@ -44,6 +45,9 @@ impl MockRouter {
         }
     }
 
+    /// Binds the given port to the module, overriding any previous owner.
+    ///
+    /// Use [`bind_port`] to enforce the ICS-05 ownership semantics instead.
     pub fn scope_port_to_module(&mut self, port_id: PortId, module_id: ModuleId) {
         self.port_to_module.insert(port_id, module_id);
     }
@@ -1,26 +1,41 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use core::fmt::Debug;
 
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::impls::SharedStore;
-use basecoin_store::types::{BinStore, JsonStore, ProtobufStore, TypedSet, TypedStore};
+use basecoin_store::types::{
+    BinStore, Height as StoreHeight, JsonStore, Path as StorePath, ProtobufStore, TypedSet,
+    TypedStore,
+};
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::limits::PacketSizeLimits;
+use ibc::core::channel::types::packet_data::DynPacketDataCodec;
 use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::types::params::ClientParams;
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentRoot;
 use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::handler::types::error::ResourceError;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc::core::handler::types::log::HandlerLog;
+use ibc::core::handler::types::mode::HostMode;
+use ibc::core::host::types::identifiers::{
+    ClientId, ConnectionId, PortId, Sequence, ValidationProfile,
+};
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
     ClientUpdateHeightPath, ClientUpdateTimePath, CommitmentPath, ConnectionPath,
-    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    CounterpartyPayeePath, NextChannelSequencePath, NextClientSequencePath,
+    NextConnectionSequencePath, Path, PayeePath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
+use ibc::core::host::{ResourceMeter, ValidationContext};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
+use ibc::core::router::types::module::ModuleId;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::channel::v1::Channel as RawChannelEnd;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
@@ -31,7 +46,9 @@ use typed_builder::TypedBuilder;
 
 use crate::context::{MockStore, TestContext};
 use crate::fixtures::core::context::TestContextConfig;
-use crate::hosts::{HostClientState, TestBlock, TestHeader, TestHost};
+use crate::hosts::{HostClientState, MockHost, TestBlock, TestHeader, TestHost};
+use crate::testapp::ibc::clients::mock::behaviour::MockClientHandle;
+use crate::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use crate::testapp::ibc::clients::mock::header::MockHeader;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 pub const DEFAULT_BLOCK_TIME_SECS: u64 = 3;
@@ -85,6 +102,10 @@ where
     pub packet_receipt_store: TypedSet<SharedStore<S>, ReceiptPath>,
     /// A typed-store for packet ack
     pub packet_ack_store: BinStore<SharedStore<S>, AckPath, AcknowledgementCommitment>,
+    /// A typed-store for the payees registered by the relayers
+    pub payee_store: JsonStore<SharedStore<S>, PayeePath, String>,
+    /// A typed-store for the counterparty payees registered by the relayers
+    pub counterparty_payee_store: JsonStore<SharedStore<S>, CounterpartyPayeePath, String>,
     /// Map of host consensus states
     pub host_consensus_states: Arc<Mutex<BTreeMap<u64, AnyConsensusState>>>,
     /// Map of older ibc commitment proofs
@@ -92,7 +113,28 @@ where
     /// IBC Events
     pub events: Arc<Mutex<Vec<IbcEvent>>>,
     /// message logs
-    pub logs: Arc<Mutex<Vec<String>>>,
+    pub logs: Arc<Mutex<Vec<HandlerLog>>>,
+    /// Handle to the failure modes injected into the mock clients
+    pub mock_client_handle: MockClientHandle,
+    /// Rules that the identifiers generated by the host must follow
+    pub identifier_validation_profile: ValidationProfile,
+    /// Meter charged by the handlers for the work they perform
+    pub resource_meter: MockResourceMeter,
+    /// Mode in which the host operates IBC
+    pub host_mode: HostMode,
+    /// Upper bounds on the size of the packets handled by the host
+    pub packet_size_limits: PacketSizeLimits,
+    /// Codecs of the packet data of the applications, by port
+    pub packet_data_codecs: BTreeMap<PortId, Arc<dyn DynPacketDataCodec + Send + Sync>>,
+    /// Parameters of the client module
+    pub client_params: ClientParams,
+    /// Client types reported as allowed by the client params query, in place
+    /// of those of `client_params`, when set
+    pub queried_allowed_clients: Option<Vec<String>>,
+    /// Secondary indexes maintained by the handlers
+    pub secondary_indexes: Arc<Mutex<MockSecondaryIndexes>>,
+    /// Modules owning the channels, as claimed by the channel handshakes
+    pub channel_owners: Arc<Mutex<BTreeMap<ChannelEndPath, ModuleId>>>,
 }
 
 impl<S> MockIbcStore<S>
@@ -138,8 +180,20 @@ where
             packet_commitment_store: TypedStore::new(shared_store.clone()),
             packet_receipt_store: TypedStore::new(shared_store.clone()),
             packet_ack_store: TypedStore::new(shared_store.clone()),
+            payee_store: TypedStore::new(shared_store.clone()),
+            counterparty_payee_store: TypedStore::new(shared_store.clone()),
             events: Arc::new(Mutex::new(Vec::new())),
             logs: Arc::new(Mutex::new(Vec::new())),
+            mock_client_handle: MockClientHandle::default(),
+            identifier_validation_profile: ValidationProfile::default(),
+            resource_meter: MockResourceMeter::default(),
+            host_mode: HostMode::default(),
+            packet_size_limits: PacketSizeLimits::default(),
+            packet_data_codecs: BTreeMap::new(),
+            client_params: ClientParams::default(),
+            queried_allowed_clients: None,
+            secondary_indexes: Arc::new(Mutex::new(Default::default())),
+            channel_owners: Arc::new(Mutex::new(Default::default())),
             store: shared_store,
         }
     }
@@ -169,6 +223,47 @@ where
         self.store.commit()
     }
 
+    /// Returns a [`MockCommitmentTree`] over the pending IBC state of the
+    /// store, whose root is committed to by the next block of a mock chain.
+    ///
+    /// Values are committed in the encoding of the store, which matches the
+    /// one expected by the handlers for client and consensus states,
+    /// connection and channel ends, and packet and acknowledgement
+    /// commitments, but not for sequences, which are stored as JSON.
+    pub fn mock_commitment_tree(&self) -> MockCommitmentTree {
+        self.collect_mock_commitment_tree(|key| self.store.get(StoreHeight::Pending, key))
+    }
+
+    /// Returns the [`MockCommitmentTree`] committed to by the block of a mock
+    /// chain at the given height, whose proofs are verified by the mock
+    /// clients of the chain.
+    ///
+    /// Keys are listed from the pending state, so entries deleted since the
+    /// given height are left out of the tree.
+    pub fn mock_commitment_tree_at(&self, height: &Height) -> MockCommitmentTree {
+        self.collect_mock_commitment_tree(|key| {
+            self.store.get(height.revision_height().into(), key)
+        })
+    }
+
+    fn collect_mock_commitment_tree(
+        &self,
+        get: impl Fn(&StorePath) -> Option<Vec<u8>>,
+    ) -> MockCommitmentTree {
+        let mut tree = MockCommitmentTree::new(self.commitment_prefix());
+
+        for key in self.store.get_keys(&String::new().into()) {
+            let Some(value) = get(&key) else {
+                continue;
+            };
+            if let Ok(path) = Path::try_from(key) {
+                tree.insert(path, value);
+            }
+        }
+
+        tree
+    }
+
     pub fn prune_host_consensus_states_till(&self, height: &Height) {
         assert!(height.revision_number() == *self.revision_number.lock());
         let mut history = self.host_consensus_states.lock();
@@ -178,6 +273,248 @@ where
     }
 }
 
+/// Clones share the underlying store and the shared state of the original, so
+/// that the writes of one clone are readable from the others, as expected from
+/// the contexts of the query services.
+impl<S> Clone for MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    fn clone(&self) -> Self {
+        let shared_store = self.store.clone();
+
+        Self {
+            revision_number: self.revision_number.clone(),
+            client_counter: TypedStore::new(shared_store.clone()),
+            conn_counter: TypedStore::new(shared_store.clone()),
+            channel_counter: TypedStore::new(shared_store.clone()),
+            client_processed_times: TypedStore::new(shared_store.clone()),
+            client_processed_heights: TypedStore::new(shared_store.clone()),
+            host_consensus_states: self.host_consensus_states.clone(),
+            ibc_commiment_proofs: self.ibc_commiment_proofs.clone(),
+            client_state_store: TypedStore::new(shared_store.clone()),
+            consensus_state_store: TypedStore::new(shared_store.clone()),
+            connection_end_store: TypedStore::new(shared_store.clone()),
+            connection_ids_store: TypedStore::new(shared_store.clone()),
+            channel_end_store: TypedStore::new(shared_store.clone()),
+            send_sequence_store: TypedStore::new(shared_store.clone()),
+            recv_sequence_store: TypedStore::new(shared_store.clone()),
+            ack_sequence_store: TypedStore::new(shared_store.clone()),
+            packet_commitment_store: TypedStore::new(shared_store.clone()),
+            packet_receipt_store: TypedStore::new(shared_store.clone()),
+            packet_ack_store: TypedStore::new(shared_store.clone()),
+            payee_store: TypedStore::new(shared_store.clone()),
+            counterparty_payee_store: TypedStore::new(shared_store.clone()),
+            events: self.events.clone(),
+            logs: self.logs.clone(),
+            mock_client_handle: self.mock_client_handle.clone(),
+            identifier_validation_profile: self.identifier_validation_profile,
+            resource_meter: self.resource_meter.clone(),
+            host_mode: self.host_mode,
+            packet_size_limits: self.packet_size_limits,
+            packet_data_codecs: self.packet_data_codecs.clone(),
+            client_params: self.client_params.clone(),
+            queried_allowed_clients: self.queried_allowed_clients.clone(),
+            secondary_indexes: self.secondary_indexes.clone(),
+            channel_owners: self.channel_owners.clone(),
+            store: shared_store,
+        }
+    }
+}
+
+/// Secondary indexes over the IBC state of a [`MockIbcStore`], maintained by
+/// the handlers through the index methods of the `ExecutionContext`.
+///
+/// The indexes are kept outside of the provable store, as a host would keep
+/// them outside of its consensus state.
+#[derive(Clone, Debug, Default)]
+pub struct MockSecondaryIndexes {
+    /// Commitments of the pending packets, by the timeout height of the packet
+    pub packets_by_timeout_height: BTreeMap<Height, BTreeSet<CommitmentPath>>,
+    /// Commitments of the pending packets, by the timeout timestamp of the
+    /// packet in nanoseconds
+    pub packets_by_timeout_timestamp: BTreeMap<u64, BTreeSet<CommitmentPath>>,
+    /// Consensus states, by the host time at which they were processed in
+    /// nanoseconds
+    pub consensus_states_by_processed_time: BTreeMap<u64, BTreeSet<(ClientId, Height)>>,
+    /// Client updates to a consensus height, by the host height at which
+    /// they were processed
+    pub client_updates_by_height: BTreeMap<Height, BTreeSet<(ClientId, Height)>>,
+}
+
+impl MockSecondaryIndexes {
+    /// Returns the commitments of the pending packets which timed out at the
+    /// given height and timestamp of the counterparty chain.
+    pub fn timed_out_packets(&self, height: &Height, timestamp: &Timestamp) -> Vec<CommitmentPath> {
+        let by_height = self
+            .packets_by_timeout_height
+            .range(..=*height)
+            .flat_map(|(_, paths)| paths);
+        let by_timestamp = self
+            .packets_by_timeout_timestamp
+            .range(..=timestamp.nanoseconds())
+            .flat_map(|(_, paths)| paths);
+
+        by_height
+            .chain(by_timestamp)
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the consensus states processed strictly before the given host
+    /// time, e.g. the ones to prune.
+    pub fn consensus_states_processed_before(&self, time: &Timestamp) -> Vec<(ClientId, Height)> {
+        self.consensus_states_by_processed_time
+            .range(..time.nanoseconds())
+            .flat_map(|(_, states)| states.iter().cloned())
+            .collect()
+    }
+
+    /// Returns the client updates processed at the given host height.
+    pub fn client_updates_at(&self, host_height: &Height) -> Vec<(ClientId, Height)> {
+        self.client_updates_by_height
+            .get(host_height)
+            .map(|updates| updates.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// A [`ResourceMeter`] charging one unit of gas per byte of proof, header or
+/// packet data and per verified signature, optionally up to a limit.
+///
+/// Clones share the gas consumed.
+#[derive(Clone, Debug, Default)]
+pub struct MockResourceMeter {
+    limit: Option<u64>,
+    consumed: Arc<Mutex<u64>>,
+}
+
+impl MockResourceMeter {
+    /// Creates a meter that runs out of gas once more than `limit` units are
+    /// consumed.
+    pub fn with_limit(limit: u64) -> Self {
+        Self {
+            limit: Some(limit),
+            consumed: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    /// Returns the units of gas consumed so far.
+    pub fn consumed(&self) -> u64 {
+        *self.consumed.lock()
+    }
+
+    fn charge(&self, len: usize) -> Result<(), ResourceError> {
+        let mut consumed = self.consumed.lock();
+        *consumed = consumed.saturating_add(u64::try_from(len).unwrap_or(u64::MAX));
+
+        match self.limit {
+            Some(limit) if *consumed > limit => Err(ResourceError::OutOfGas {
+                consumed: *consumed,
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl ResourceMeter for MockResourceMeter {
+    fn charge_proof_verification(&self, proof_len: usize) -> Result<(), ResourceError> {
+        self.charge(proof_len)
+    }
+
+    fn charge_signature_verification(&self, signatures: usize) -> Result<(), ResourceError> {
+        self.charge(signatures)
+    }
+
+    fn charge_header_decoding(&self, message_len: usize) -> Result<(), ResourceError> {
+        self.charge(message_len)
+    }
+
+    fn charge_packet_data(&self, data_len: usize) -> Result<(), ResourceError> {
+        self.charge(data_len)
+    }
+}
+
+/// A copy of the state of a [`MockIbcStore`], taken with
+/// [`MockIbcStore::snapshot`].
+#[derive(Clone, Debug)]
+pub struct MockIbcStoreSnapshot<S> {
+    revision_number: u64,
+    store: S,
+    host_consensus_states: BTreeMap<u64, AnyConsensusState>,
+    ibc_commitment_proofs: BTreeMap<u64, CommitmentProof>,
+    events: Vec<IbcEvent>,
+    logs: Vec<HandlerLog>,
+    secondary_indexes: MockSecondaryIndexes,
+    channel_owners: BTreeMap<ChannelEndPath, ModuleId>,
+}
+
+impl<S> MockIbcStore<S>
+where
+    S: ProvableStore + Debug + Clone,
+{
+    /// Takes a snapshot of the store, including its committed history, the
+    /// host consensus states, the emitted events and logs, the secondary
+    /// indexes and the channel owners.
+    ///
+    /// The behaviours of the mock clients are not part of the snapshot.
+    pub fn snapshot(&self) -> MockIbcStoreSnapshot<S> {
+        MockIbcStoreSnapshot {
+            revision_number: *self.revision_number.lock(),
+            store: self.store.read().expect("store lock is poisoned").clone(),
+            host_consensus_states: self.host_consensus_states.lock().clone(),
+            ibc_commitment_proofs: self.ibc_commiment_proofs.lock().clone(),
+            events: self.events.lock().clone(),
+            logs: self.logs.lock().clone(),
+            secondary_indexes: self.secondary_indexes.lock().clone(),
+            channel_owners: self.channel_owners.lock().clone(),
+        }
+    }
+
+    /// Restores the store to the given snapshot, in place, so that all the
+    /// clones of the store observe the restored state.
+    pub fn restore(&mut self, snapshot: &MockIbcStoreSnapshot<S>) {
+        *self.revision_number.lock() = snapshot.revision_number;
+        *self.store.write().expect("store lock is poisoned") = snapshot.store.clone();
+        *self.host_consensus_states.lock() = snapshot.host_consensus_states.clone();
+        *self.ibc_commiment_proofs.lock() = snapshot.ibc_commitment_proofs.clone();
+        *self.events.lock() = snapshot.events.clone();
+        *self.logs.lock() = snapshot.logs.clone();
+        *self.secondary_indexes.lock() = snapshot.secondary_indexes.clone();
+        *self.channel_owners.lock() = snapshot.channel_owners.clone();
+    }
+
+    /// Returns a branch of the store, i.e. an independent copy of its state
+    /// and configuration, which the writes made to the store do not affect
+    /// and vice versa.
+    ///
+    /// The branch shares the behaviours of the mock clients with the store,
+    /// and starts with a meter consuming from the gas consumed so far.
+    pub fn branch(&self) -> Self {
+        let snapshot = self.snapshot();
+
+        let mut branch = Self::new(snapshot.revision_number, snapshot.store.clone());
+        branch.restore(&snapshot);
+
+        branch.mock_client_handle = self.mock_client_handle.clone();
+        branch.identifier_validation_profile = self.identifier_validation_profile;
+        branch.resource_meter = MockResourceMeter {
+            limit: self.resource_meter.limit,
+            consumed: Arc::new(Mutex::new(self.resource_meter.consumed())),
+        };
+        branch.host_mode = self.host_mode;
+        branch.packet_size_limits = self.packet_size_limits;
+        branch.packet_data_codecs = self.packet_data_codecs.clone();
+        branch.client_params = self.client_params.clone();
+        branch.queried_allowed_clients = self.queried_allowed_clients.clone();
+
+        branch
+    }
+}
+
 impl<S> Default for MockIbcStore<S>
 where
     S: ProvableStore + Debug + Default,
@@ -200,7 +537,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use ibc::core::channel::types::acknowledgement::Acknowledgement;
+    use ibc::core::channel::types::acknowledgement::{Acknowledgement, RecvPacketResult};
     use ibc::core::channel::types::channel::{Counterparty, Order};
     use ibc::core::channel::types::error::{ChannelError, PacketError};
     use ibc::core::channel::types::packet::Packet;
@@ -276,12 +613,14 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
+            ) -> (ModuleExtras, RecvPacketResult) {
                 self.counter += 1;
 
                 (
                     ModuleExtras::empty(),
-                    Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    RecvPacketResult::Success(
+                        Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    ),
                 )
             }
 
@@ -376,10 +715,12 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
+            ) -> (ModuleExtras, RecvPacketResult) {
                 (
                     ModuleExtras::empty(),
-                    Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    RecvPacketResult::Success(
+                        Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    ),
                 )
             }
 
@@ -473,6 +814,27 @@ where
     }
 }
 
+impl LightClientState<MockHost> {
+    /// Commits the consensus states of the light client to the given root,
+    /// e.g. the root of a [`MockCommitmentTree`] over the state that a test
+    /// proves to the client.
+    pub fn with_commitment_root(mut self, root: CommitmentRoot) -> Self {
+        for consensus_state in self.consensus_states.values_mut() {
+            *consensus_state = consensus_state.clone().with_root(root.clone());
+        }
+        self
+    }
+
+    /// Makes the light client accept any proof, see
+    /// [`MockConsensusState::with_unverified_proofs`](crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState::with_unverified_proofs).
+    pub fn with_unverified_proofs(mut self) -> Self {
+        for consensus_state in self.consensus_states.values_mut() {
+            *consensus_state = consensus_state.clone().with_unverified_proofs();
+        }
+        self
+    }
+}
+
 #[derive(TypedBuilder)]
 #[builder(builder_method(name = init), build_method(into))]
 pub struct LightClientBuilder<'a, H>
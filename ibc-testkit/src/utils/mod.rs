@@ -15,3 +15,16 @@ pub fn year_2023() -> Timestamp {
         .expect("should be a valid time")
         .into()
 }
+
+/// Returns a `Timestamp` representation of beginning of year 2024.
+///
+/// This is a year ahead of the default latest timestamp of the
+/// [`StoreGenericTestContext`](crate::context::StoreGenericTestContext)s, and
+/// stands for the current time in fixtures and tests, which must not depend on
+/// the system time.
+pub fn year_2024() -> Timestamp {
+    // Mon Jan 01 2024 00:00:00 GMT+0000
+    Time::from_unix_timestamp(1_704_067_200, 0)
+        .expect("should be a valid time")
+        .into()
+}
@@ -1,4 +1,8 @@
 #[cfg(feature = "serde")]
 pub mod nft_transfer;
 #[cfg(feature = "serde")]
+pub mod rate_limit;
+#[cfg(feature = "serde")]
+pub mod receive_fee;
+#[cfg(feature = "serde")]
 pub mod transfer;
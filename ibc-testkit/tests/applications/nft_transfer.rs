@@ -1,13 +1,41 @@
+use ibc::apps::nft_transfer::audit::{
+    apply_repair_operation, audit_nft_escrow, repair_operations, EscrowViolation, RepairOperation,
+};
+use ibc::apps::nft_transfer::context::NftTransferExecutionContext;
+use ibc::apps::nft_transfer::handler::send_nft_transfer_validate;
 use ibc::apps::nft_transfer::module::{
     on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_chan_open_try_validate, on_recv_packet_execute,
+};
+use ibc::apps::nft_transfer::types::error::NftTransferError;
+use ibc::apps::nft_transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::nft_transfer::types::packet::{MetadataUpdate, PacketData};
+use ibc::apps::nft_transfer::types::{
+    Memo, PrefixedClassId, TokenId, TokenIds, MODULE_ID_STR, VERSION, VERSION_V2,
 };
-use ibc::apps::nft_transfer::types::VERSION;
-use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::acknowledgement::RecvPacketResult;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::module::Module;
+use ibc::core::router::port::bind_port;
+use ibc::core::router::types::module::ModuleId;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::applications::nft_transfer::types::DummyNftTransferModule;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
 
 fn get_defaults() -> (
     DummyNftTransferModule,
@@ -24,7 +52,7 @@ fn get_defaults() -> (
     let counterparty = Counterparty::new(port_id.clone(), Some(channel_id.clone()));
 
     (
-        DummyNftTransferModule,
+        DummyNftTransferModule::new(),
         order,
         connection_hops,
         port_id,
@@ -114,6 +142,38 @@ fn test_on_chan_open_try_counterparty_correct_version() {
     assert_eq!(out_version, Version::new(VERSION.to_string()));
 }
 
+/// If the counterparty proposes ics721-2, then negotiate ics721-2
+#[test]
+fn test_on_chan_open_try_counterparty_v2_version() {
+    let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    let counterparty_version = Version::new(VERSION_V2.to_string());
+
+    on_chan_open_try_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &counterparty_version,
+    )
+    .unwrap();
+
+    let (_, out_version) = on_chan_open_try_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &counterparty_version,
+    )
+    .unwrap();
+
+    assert_eq!(out_version, Version::new(VERSION_V2.to_string()));
+}
+
 /// If the counterparty doesn't support ics721, then fail
 #[test]
 fn test_on_chan_open_try_counterparty_incorrect_version() {
@@ -133,3 +193,296 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+/// Users may never close NFT transfer channels
+#[test]
+fn test_chan_close_init_not_allowed() {
+    let (ctx, _, _, port_id, channel_id, _) = get_defaults();
+
+    assert!(!ctx.chan_close_init_allowed(
+        &port_id,
+        &channel_id,
+        &Signer::from("owner".to_string())
+    ));
+}
+
+/// Metadata updates are only accepted over channels negotiated with a version
+/// supporting them
+#[test]
+fn test_on_recv_packet_metadata_update_not_negotiated() {
+    let (mut ctx, ..) = get_defaults();
+
+    let packet_data = PacketData::new(
+        "class".parse().unwrap(),
+        None,
+        None,
+        TokenIds::try_from(vec!["token_0".to_string()]).unwrap(),
+        vec![],
+        vec![],
+        Signer::from("sender".to_string()),
+        Signer::from("receiver".to_string()),
+        Memo::from(""),
+    )
+    .unwrap()
+    .with_metadata_update(MetadataUpdate {
+        class: true,
+        tokens: true,
+    });
+    let packet: Packet = PacketConfig::builder()
+        .data(serde_json::to_vec(&packet_data).expect("valid packet data"))
+        .build();
+
+    let (_, result) = on_recv_packet_execute(&mut ctx, &packet);
+
+    assert!(matches!(result, RecvPacketResult::Error { .. }));
+    assert!(ctx.minted_vouchers.is_empty());
+
+    ctx.channel_versions.insert(
+        (packet.port_id_on_b.clone(), packet.chan_id_on_b.clone()),
+        Version::new(VERSION_V2.to_string()),
+    );
+
+    let (_, result) = on_recv_packet_execute(&mut ctx, &packet);
+
+    assert!(matches!(result, RecvPacketResult::Success(_)));
+    assert_eq!(ctx.minted_vouchers.len(), 1);
+}
+
+/// NFTs marked as non-transferable by the policy of the host cannot be sent,
+/// while the other NFTs still can
+#[test]
+fn test_send_nft_transfer_policy() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        core::time::Duration::ZERO,
+    )
+    .unwrap();
+
+    let ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let mut router = MockRouter::default();
+    bind_port(
+        &mut router,
+        PortId::transfer(),
+        ModuleId::new(MODULE_ID_STR.to_string()),
+    )
+    .unwrap();
+
+    let class_id: PrefixedClassId = "class".parse().unwrap();
+    let soulbound: TokenId = "soulbound".parse().unwrap();
+
+    let mut transfer_ctx = DummyNftTransferModule::new();
+    transfer_ctx
+        .non_transferable_nfts
+        .insert((class_id.clone(), soulbound.clone()));
+
+    let msg = |token_id: &str| MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        packet_data: PacketData::new(
+            class_id.clone(),
+            None,
+            None,
+            TokenIds::try_from(vec![token_id.to_string()]).unwrap(),
+            vec![],
+            vec![],
+            Signer::from("sender".to_string()),
+            Signer::from("receiver".to_string()),
+            Memo::from(""),
+        )
+        .unwrap(),
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 35).unwrap()),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let res = send_nft_transfer_validate(&ctx.ibc_store, &router, &transfer_ctx, msg("soulbound"));
+    assert!(matches!(
+        res,
+        Err(NftTransferError::NonTransferableNft { class_id: c, token_id: t })
+            if c == class_id && t == soulbound
+    ));
+
+    send_nft_transfer_validate(&ctx.ibc_store, &router, &transfer_ctx, msg("token_0"))
+        .expect("the NFT is transferable");
+}
+
+#[test]
+fn test_audit_nft_escrow_consistent() {
+    let (ctx, _, _, port_id, channel_id, _) = get_defaults();
+
+    let violations = audit_nft_escrow(&ctx, [(&port_id, &channel_id)]).unwrap();
+
+    assert!(violations.is_empty());
+}
+
+#[test]
+fn test_audit_nft_escrow_violations() {
+    let (mut ctx, _, _, port_id, channel_id, _) = get_defaults();
+    let sender = Signer::from("sender".to_string());
+    let owner = Signer::from("owner".to_string());
+    let class_id: PrefixedClassId = "class".parse().unwrap();
+    let voucher_class_id: PrefixedClassId = "nft-transfer/channel-1/class".parse().unwrap();
+    let traced_class_id: PrefixedClassId = "nft-transfer/channel-1/traced".parse().unwrap();
+    let token_ids: Vec<TokenId> = (0..5)
+        .map(|i| format!("token_{i}").parse().unwrap())
+        .collect();
+
+    // The first NFT is escrowed and then moved out of the escrow account.
+    for token_id in &token_ids[..2] {
+        ctx.escrow_nft_execute(
+            &sender,
+            &port_id,
+            &channel_id,
+            &class_id,
+            token_id,
+            &Memo::from(""),
+        )
+        .unwrap();
+    }
+    ctx.nft_owners
+        .insert((class_id.clone(), token_ids[0].clone()), owner.clone());
+    // The second NFT is escrowed and then disappears.
+    ctx.nft_owners
+        .remove(&(class_id.clone(), token_ids[1].clone()));
+
+    // Vouchers minted without the trace of their class, with and without a
+    // trace path, and a voucher whose class is traced.
+    for (class_id, token_id) in [
+        (&voucher_class_id, &token_ids[2]),
+        (&class_id, &token_ids[3]),
+        (&traced_class_id, &token_ids[4]),
+    ] {
+        ctx.mint_nft_execute(&sender, class_id, token_id, None, None)
+            .unwrap();
+    }
+    ctx.traced_nft_classes.push(traced_class_id);
+
+    let violations = audit_nft_escrow(&ctx, [(&port_id, &channel_id)]).unwrap();
+
+    assert_eq!(
+        violations,
+        vec![
+            EscrowViolation::MisplacedEscrowedNft {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                class_id: class_id.clone(),
+                token_id: token_ids[0].clone(),
+                owner: owner.clone(),
+            },
+            EscrowViolation::MissingEscrowedNft {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                class_id: class_id.clone(),
+                token_id: token_ids[1].clone(),
+            },
+            EscrowViolation::MissingClassTrace {
+                class_id: voucher_class_id.clone(),
+                token_id: token_ids[2].clone(),
+            },
+            EscrowViolation::MissingClassTrace {
+                class_id: class_id.clone(),
+                token_id: token_ids[3].clone(),
+            },
+        ]
+    );
+
+    let operations = repair_operations(violations);
+
+    assert_eq!(
+        operations,
+        vec![
+            RepairOperation::ReturnToEscrow {
+                owner,
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                class_id: class_id.clone(),
+                token_id: token_ids[0].clone(),
+            },
+            RepairOperation::RestoreClassTrace {
+                class_id: voucher_class_id,
+            },
+        ]
+    );
+
+    for operation in &operations {
+        apply_repair_operation(&mut ctx, operation).unwrap();
+    }
+
+    assert_eq!(
+        ctx.nft_owners.get(&(class_id, token_ids[0].clone())),
+        Some(&DummyNftTransferModule::channel_escrow_account(
+            &port_id,
+            &channel_id
+        ))
+    );
+}
+
+#[test]
+fn test_repair_operations() {
+    let (_, _, _, port_id, channel_id, _) = get_defaults();
+    let class_id = "nft-transfer/channel-1/class".parse().unwrap();
+    let owner = Signer::from("owner".to_string());
+
+    let violations = vec![
+        EscrowViolation::MisplacedEscrowedNft {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            class_id: "class".parse().unwrap(),
+            token_id: "token_0".parse().unwrap(),
+            owner: owner.clone(),
+        },
+        EscrowViolation::MissingEscrowedNft {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            class_id: "class".parse().unwrap(),
+            token_id: "token_1".parse().unwrap(),
+        },
+        EscrowViolation::MissingClassTrace {
+            class_id: class_id.clone(),
+            token_id: "token_2".parse().unwrap(),
+        },
+        EscrowViolation::MissingClassTrace {
+            class_id: class_id.clone(),
+            token_id: "token_3".parse().unwrap(),
+        },
+    ];
+
+    assert_eq!(
+        repair_operations(violations),
+        vec![
+            RepairOperation::ReturnToEscrow {
+                owner,
+                port_id,
+                channel_id,
+                class_id: "class".parse().unwrap(),
+                token_id: "token_0".parse().unwrap(),
+            },
+            RepairOperation::RestoreClassTrace { class_id },
+        ]
+    );
+}
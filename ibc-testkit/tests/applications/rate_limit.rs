@@ -0,0 +1,346 @@
+use core::time::Duration;
+
+use ibc::apps::transfer::rate_limit::{
+    rate_limit_outflow_execute, rate_limit_outflow_validate, RateLimitContext, RateLimitMiddleware,
+};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::rate_limit::{Flow, FlowDirection, Quota};
+use ibc::apps::transfer::types::{ack_success_b64, Amount, PrefixedCoin, PrefixedDenom};
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::host::types::identifiers::{ChannelId, Sequence};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::middleware::Middleware;
+use ibc_testkit::fixtures::applications::transfer::PacketDataConfig;
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+const EPOCH: Duration = Duration::from_secs(3600);
+
+/// A rate limiter context keeping its quotas and flows in memory, whose clock
+/// can be advanced and which can be made to fail when storing flows.
+#[derive(Debug)]
+struct MockRateLimitContext {
+    quotas: BTreeMap<(ChannelId, String), Quota>,
+    flows: BTreeMap<(ChannelId, String), Flow>,
+    outflow_epochs: BTreeMap<(ChannelId, Sequence), Timestamp>,
+    now: Timestamp,
+    fail_store: bool,
+}
+
+impl Default for MockRateLimitContext {
+    fn default() -> Self {
+        Self {
+            quotas: BTreeMap::new(),
+            flows: BTreeMap::new(),
+            outflow_epochs: BTreeMap::new(),
+            now: Timestamp::from_nanoseconds(1_000_000_000).expect("valid timestamp"),
+            fail_store: false,
+        }
+    }
+}
+
+impl MockRateLimitContext {
+    fn with_quota(mut self, channel_id: ChannelId, denom: &str, quota: Quota) -> Self {
+        self.quotas.insert((channel_id, denom.to_string()), quota);
+        self
+    }
+
+    fn amount(&self, channel_id: &ChannelId, denom: &str, direction: FlowDirection) -> Amount {
+        self.flows
+            .get(&(channel_id.clone(), denom.to_string()))
+            .map(|flow| flow.amount(direction))
+            .unwrap_or_else(|| Amount::from(0))
+    }
+
+    fn advance_time(&mut self, duration: Duration) {
+        self.now = (self.now + duration).expect("valid timestamp");
+    }
+}
+
+impl RateLimitContext for MockRateLimitContext {
+    fn quota(&self, channel_id: &ChannelId, denom: &PrefixedDenom) -> Option<Quota> {
+        self.quotas
+            .get(&(channel_id.clone(), denom.to_string()))
+            .copied()
+    }
+
+    fn flow(&self, channel_id: &ChannelId, denom: &PrefixedDenom) -> Option<Flow> {
+        self.flows
+            .get(&(channel_id.clone(), denom.to_string()))
+            .copied()
+    }
+
+    fn store_flow(
+        &mut self,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        flow: Flow,
+    ) -> Result<(), TokenTransferError> {
+        if self.fail_store {
+            return Err(TokenTransferError::Other("flow storage failed".to_string()));
+        }
+
+        self.flows
+            .insert((channel_id.clone(), denom.to_string()), flow);
+
+        Ok(())
+    }
+
+    fn outflow_epoch(&self, channel_id: &ChannelId, sequence: Sequence) -> Option<Timestamp> {
+        self.outflow_epochs
+            .get(&(channel_id.clone(), sequence))
+            .copied()
+    }
+
+    fn store_outflow_epoch(
+        &mut self,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        epoch_start: Timestamp,
+    ) -> Result<(), TokenTransferError> {
+        self.outflow_epochs
+            .insert((channel_id.clone(), sequence), epoch_start);
+
+        Ok(())
+    }
+
+    fn delete_outflow_epoch(
+        &mut self,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), TokenTransferError> {
+        self.outflow_epochs.remove(&(channel_id.clone(), sequence));
+
+        Ok(())
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, TokenTransferError> {
+        Ok(self.now)
+    }
+}
+
+fn quota(max: u64) -> Quota {
+    Quota {
+        max_inflow: Amount::from(max),
+        max_outflow: Amount::from(max),
+        epoch_duration: EPOCH,
+    }
+}
+
+fn transfer_packet(token: &str) -> Packet {
+    sent_packet(0, token)
+}
+
+/// Returns the packet of the given sequence sending `token` over channel-0.
+fn sent_packet(sequence: u64, token: &str) -> Packet {
+    let packet_data: PacketData = PacketDataConfig::builder()
+        .token(token.parse().expect("valid coin"))
+        .build();
+
+    PacketConfig::builder()
+        .seq_on_a(Sequence::from(sequence))
+        .chan_id_on_b(ChannelId::new(1))
+        .data(serde_json::to_vec(&packet_data).expect("valid packet data"))
+        .build()
+}
+
+fn error_ack() -> Acknowledgement {
+    AcknowledgementStatus::error(StatusValue::new("failed").expect("non-empty")).into()
+}
+
+/// Sends `token` over channel-0 of the host in the packet of the given
+/// sequence, recording its outflow.
+fn record_outflow(ctx: &mut MockRateLimitContext, sequence: u64, token: &str) {
+    let coin: PrefixedCoin = token.parse().expect("valid coin");
+
+    rate_limit_outflow_validate(ctx, &ChannelId::zero(), &coin).expect("within the quota");
+    rate_limit_outflow_execute(ctx, &ChannelId::zero(), Sequence::from(sequence), &coin)
+        .expect("no error");
+}
+
+#[test]
+fn test_rate_limit_inflow() {
+    // The tokens are received with the trace of the receiving channel.
+    let denom = "transfer/channel-1/uatom";
+
+    let ctx = MockRateLimitContext::default().with_quota(ChannelId::new(1), denom, quota(1500));
+    let mut middleware = RateLimitMiddleware::new(ctx);
+    let relayer: Signer = dummy_account_id();
+
+    let (_, result) = middleware.on_recv_packet_execute(
+        &mut DummyTransferModule,
+        &transfer_packet("1000uatom"),
+        &relayer,
+    );
+    assert!(result.is_successful());
+    assert_eq!(
+        middleware
+            .ctx()
+            .amount(&ChannelId::new(1), denom, FlowDirection::Inflow),
+        Amount::from(1000)
+    );
+
+    let (extras, result) = middleware.on_recv_packet_execute(
+        &mut DummyTransferModule,
+        &transfer_packet("1000uatom"),
+        &relayer,
+    );
+    assert!(!result.is_successful());
+    assert!(extras
+        .events
+        .iter()
+        .any(|event| event.kind == "rate_limit_exceeded"));
+    assert_eq!(
+        middleware
+            .ctx()
+            .amount(&ChannelId::new(1), denom, FlowDirection::Inflow),
+        Amount::from(1000)
+    );
+}
+
+#[test]
+fn test_rate_limit_reverts_refunded_outflow() {
+    let mut ctx =
+        MockRateLimitContext::default().with_quota(ChannelId::zero(), "uatom", quota(5000));
+    record_outflow(&mut ctx, 0, "1000uatom");
+    record_outflow(&mut ctx, 1, "1000uatom");
+    record_outflow(&mut ctx, 2, "1000uatom");
+
+    let mut middleware = RateLimitMiddleware::new(ctx);
+    let relayer: Signer = dummy_account_id();
+
+    let (_, result) = middleware.on_timeout_packet_execute(
+        &mut DummyTransferModule,
+        &sent_packet(0, "1000uatom"),
+        &relayer,
+    );
+    assert!(result.is_ok());
+    assert_eq!(
+        middleware
+            .ctx()
+            .amount(&ChannelId::zero(), "uatom", FlowDirection::Outflow),
+        Amount::from(2000)
+    );
+
+    let (_, result) = middleware.on_acknowledgement_packet_execute(
+        &mut DummyTransferModule,
+        &sent_packet(1, "1000uatom"),
+        &error_ack(),
+        &relayer,
+    );
+    assert!(result.is_ok());
+    assert_eq!(
+        middleware
+            .ctx()
+            .amount(&ChannelId::zero(), "uatom", FlowDirection::Outflow),
+        Amount::from(1000)
+    );
+
+    let success_ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+    let (_, result) = middleware.on_acknowledgement_packet_execute(
+        &mut DummyTransferModule,
+        &sent_packet(2, "1000uatom"),
+        &success_ack,
+        &relayer,
+    );
+    assert!(result.is_ok());
+    assert_eq!(
+        middleware
+            .ctx()
+            .amount(&ChannelId::zero(), "uatom", FlowDirection::Outflow),
+        Amount::from(1000)
+    );
+
+    // Each outflow is reverted at most once.
+    let (_, result) = middleware.on_timeout_packet_execute(
+        &mut DummyTransferModule,
+        &sent_packet(1, "1000uatom"),
+        &relayer,
+    );
+    assert!(result.is_ok());
+    assert_eq!(
+        middleware
+            .ctx()
+            .amount(&ChannelId::zero(), "uatom", FlowDirection::Outflow),
+        Amount::from(1000)
+    );
+}
+
+/// A refund arriving after the epoch of its transfer was reset must not
+/// release quota of the new epoch.
+#[test]
+fn test_rate_limit_ignores_refund_after_epoch_rollover() {
+    let mut ctx =
+        MockRateLimitContext::default().with_quota(ChannelId::zero(), "uatom", quota(5000));
+    record_outflow(&mut ctx, 0, "1000uatom");
+
+    ctx.advance_time(EPOCH);
+    record_outflow(&mut ctx, 1, "3000uatom");
+
+    let mut middleware = RateLimitMiddleware::new(ctx);
+    let relayer: Signer = dummy_account_id();
+
+    let (_, result) = middleware.on_timeout_packet_execute(
+        &mut DummyTransferModule,
+        &sent_packet(0, "1000uatom"),
+        &relayer,
+    );
+    assert!(result.is_ok());
+    assert_eq!(
+        middleware
+            .ctx()
+            .amount(&ChannelId::zero(), "uatom", FlowDirection::Outflow),
+        Amount::from(3000)
+    );
+
+    // The outflow of the new epoch is still reverted on refund.
+    let (_, result) = middleware.on_timeout_packet_execute(
+        &mut DummyTransferModule,
+        &sent_packet(1, "3000uatom"),
+        &relayer,
+    );
+    assert!(result.is_ok());
+    assert_eq!(
+        middleware
+            .ctx()
+            .amount(&ChannelId::zero(), "uatom", FlowDirection::Outflow),
+        Amount::from(0)
+    );
+}
+
+/// The wrapped application refunded the tokens before the outflow is
+/// reverted, so failing to revert it must not fail the packet.
+#[test]
+fn test_rate_limit_revert_failure_is_logged() {
+    let mut ctx =
+        MockRateLimitContext::default().with_quota(ChannelId::zero(), "uatom", quota(5000));
+    record_outflow(&mut ctx, 0, "1000uatom");
+    ctx.fail_store = true;
+
+    let mut middleware = RateLimitMiddleware::new(ctx);
+    let relayer: Signer = dummy_account_id();
+
+    let (extras, result) = middleware.on_timeout_packet_execute(
+        &mut DummyTransferModule,
+        &transfer_packet("1000uatom"),
+        &relayer,
+    );
+
+    assert!(result.is_ok());
+    assert!(extras
+        .log
+        .iter()
+        .any(|log| log.starts_with("failed to revert rate limit outflow")));
+    assert_eq!(
+        middleware
+            .ctx()
+            .amount(&ChannelId::zero(), "uatom", FlowDirection::Outflow),
+        Amount::from(1000)
+    );
+}
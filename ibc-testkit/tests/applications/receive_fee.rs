@@ -0,0 +1,199 @@
+use ibc::apps::transfer::receive_fee::{ReceiveFeeContext, ReceiveFeeMiddleware};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::receive_fee::ReceiveFee;
+use ibc::apps::transfer::types::{PrefixedCoin, PrefixedDenom};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::host::types::identifiers::ChannelId;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::middleware::Middleware;
+use ibc_testkit::fixtures::applications::transfer::PacketDataConfig;
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+/// A receive fee context recording the deducted fees and the reverted
+/// credits, whose deductions can be made to fail validation or execution.
+#[derive(Debug, Default)]
+struct MockReceiveFeeContext {
+    fee: Option<ReceiveFee>,
+    reject_deductions: bool,
+    fail_deductions: bool,
+    deducted: Vec<(Signer, Signer, PrefixedCoin)>,
+    reverted: Vec<(Signer, PrefixedCoin)>,
+}
+
+impl ReceiveFeeContext for MockReceiveFeeContext {
+    fn receive_fee(&self, _channel_id: &ChannelId, _denom: &PrefixedDenom) -> Option<ReceiveFee> {
+        self.fee.clone()
+    }
+
+    fn deduct_receive_fee_validate(
+        &self,
+        _receiver: &Signer,
+        _fee_account: &Signer,
+        _fee: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        if self.reject_deductions {
+            return Err(TokenTransferError::Other(
+                "fee account is blocked".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn deduct_receive_fee_execute(
+        &mut self,
+        receiver: &Signer,
+        fee_account: &Signer,
+        fee: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        if self.fail_deductions {
+            return Err(TokenTransferError::Other(
+                "insufficient receiver balance".to_string(),
+            ));
+        }
+
+        self.deducted
+            .push((receiver.clone(), fee_account.clone(), fee.clone()));
+
+        Ok(())
+    }
+
+    fn revert_receive_execute(
+        &mut self,
+        receiver: &Signer,
+        coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.reverted.push((receiver.clone(), coin.clone()));
+
+        Ok(())
+    }
+}
+
+fn fee_account() -> Signer {
+    Signer::from("fee_account".to_string())
+}
+
+fn transfer_packet(token: &str) -> (Packet, PacketData) {
+    let packet_data: PacketData = PacketDataConfig::builder()
+        .token(token.parse().expect("valid coin"))
+        .build();
+
+    let packet = PacketConfig::builder()
+        .chan_id_on_b(ChannelId::new(1))
+        .data(serde_json::to_vec(&packet_data).expect("valid packet data"))
+        .build();
+
+    (packet, packet_data)
+}
+
+#[test]
+fn test_receive_fee_deducted() {
+    let ctx = MockReceiveFeeContext {
+        fee: Some(ReceiveFee::new(25, fee_account()).expect("valid fee")),
+        ..Default::default()
+    };
+    let mut middleware = ReceiveFeeMiddleware::new(ctx);
+    let (packet, packet_data) = transfer_packet("100000uatom");
+    let relayer = dummy_account_id();
+
+    let (extras, result) =
+        middleware.on_recv_packet_execute(&mut DummyTransferModule, &packet, &relayer);
+
+    assert!(result.is_successful());
+    assert!(extras
+        .events
+        .iter()
+        .any(|event| event.kind == "receive_fee"));
+
+    // The tokens are received with the trace of the receiving channel.
+    let fee: PrefixedCoin = "250transfer/channel-1/uatom".parse().expect("valid coin");
+    assert_eq!(
+        middleware.ctx().deducted,
+        vec![(packet_data.receiver, fee_account(), fee)]
+    );
+}
+
+#[test]
+fn test_receive_fee_rejected_before_crediting() {
+    let ctx = MockReceiveFeeContext {
+        fee: Some(ReceiveFee::new(25, fee_account()).expect("valid fee")),
+        reject_deductions: true,
+        ..Default::default()
+    };
+    let mut middleware = ReceiveFeeMiddleware::new(ctx);
+    let (packet, _) = transfer_packet("100000uatom");
+    let relayer = dummy_account_id();
+
+    let (extras, result) =
+        middleware.on_recv_packet_execute(&mut DummyTransferModule, &packet, &relayer);
+
+    assert!(!result.is_successful());
+    assert!(extras.events.is_empty());
+    assert!(middleware.ctx().deducted.is_empty());
+    assert!(middleware.ctx().reverted.is_empty());
+}
+
+/// A deduction failing once the receiver is credited reverts the credit, so
+/// that the error acknowledgement refunds the whole transfer.
+#[test]
+fn test_receive_fee_failed_deduction_reverts_credit() {
+    let ctx = MockReceiveFeeContext {
+        fee: Some(ReceiveFee::new(25, fee_account()).expect("valid fee")),
+        fail_deductions: true,
+        ..Default::default()
+    };
+    let mut middleware = ReceiveFeeMiddleware::new(ctx);
+    let (packet, packet_data) = transfer_packet("100000uatom");
+    let relayer = dummy_account_id();
+
+    let (extras, result) =
+        middleware.on_recv_packet_execute(&mut DummyTransferModule, &packet, &relayer);
+
+    assert!(!result.is_successful());
+    assert!(!extras
+        .events
+        .iter()
+        .any(|event| event.kind == "receive_fee"));
+    assert!(middleware.ctx().deducted.is_empty());
+
+    let received: PrefixedCoin = "100000transfer/channel-1/uatom"
+        .parse()
+        .expect("valid coin");
+    assert_eq!(
+        middleware.ctx().reverted,
+        vec![(packet_data.receiver, received)]
+    );
+}
+
+#[test]
+fn test_receive_fee_skipped() {
+    let relayer = dummy_account_id();
+
+    // No fee applies
+    let mut middleware = ReceiveFeeMiddleware::new(MockReceiveFeeContext::default());
+    let (packet, _) = transfer_packet("100000uatom");
+
+    let (_, result) =
+        middleware.on_recv_packet_execute(&mut DummyTransferModule, &packet, &relayer);
+
+    assert!(result.is_successful());
+    assert!(middleware.ctx().deducted.is_empty());
+
+    // The fee rounds down to zero, so the deduction is not even validated
+    let mut middleware = ReceiveFeeMiddleware::new(MockReceiveFeeContext {
+        fee: Some(ReceiveFee::new(25, fee_account()).expect("valid fee")),
+        reject_deductions: true,
+        ..Default::default()
+    });
+    let (packet, _) = transfer_packet("399uatom");
+
+    let (_, result) =
+        middleware.on_recv_packet_execute(&mut DummyTransferModule, &packet, &relayer);
+
+    assert!(result.is_successful());
+    assert!(middleware.ctx().deducted.is_empty());
+}
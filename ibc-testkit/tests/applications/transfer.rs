@@ -1,13 +1,29 @@
+use ibc::apps::transfer::handler::{query_params, update_params};
 use ibc::apps::transfer::module::{
-    on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_acknowledgement_packet_execute, on_chan_open_init_execute, on_chan_open_init_validate,
+    on_chan_open_try_execute, on_chan_open_try_validate, on_timeout_packet_execute,
+};
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::msgs::update_params::MsgUpdateParams;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::params::TransferParams;
+use ibc::apps::transfer::types::{ack_success_b64, VERSION};
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
 };
-use ibc::apps::transfer::types::VERSION;
 use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::Version;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::middleware::{Middleware, MiddlewareStack};
+use ibc::core::router::module::Module;
+use ibc::core::router::types::event::ModuleEvent;
 use ibc::cosmos_host::utils::cosmos_adr028_escrow_address;
+use ibc_testkit::fixtures::applications::transfer::PacketDataConfig;
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
 use subtle_encoding::bech32;
 
@@ -166,3 +182,99 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+fn dummy_transfer_packet() -> Packet {
+    let packet_data: PacketData = PacketDataConfig::builder()
+        .token("1000uatom".parse().expect("valid coin"))
+        .build();
+
+    PacketConfig::builder()
+        .data(serde_json::to_vec(&packet_data).expect("valid packet data"))
+        .build()
+}
+
+fn refund_reason(events: &[ModuleEvent]) -> Option<String> {
+    events
+        .iter()
+        .find(|event| event.kind == "transfer_refund")
+        .and_then(|event| event.attributes.iter().find(|attr| attr.key == "reason"))
+        .map(|attr| attr.value.clone())
+}
+
+#[test]
+fn test_refund_events() {
+    let mut ctx = DummyTransferModule;
+    let packet = dummy_transfer_packet();
+    let relayer: Signer = dummy_account_id();
+
+    let (extras, res) = on_timeout_packet_execute(&mut ctx, &packet, &relayer);
+    assert!(res.is_ok());
+    assert_eq!(refund_reason(&extras.events).as_deref(), Some("timeout"));
+
+    let error_ack: Acknowledgement =
+        AcknowledgementStatus::error(StatusValue::new("failed").expect("non-empty")).into();
+    let (extras, res) = on_acknowledgement_packet_execute(&mut ctx, &packet, &error_ack, &relayer);
+    assert!(res.is_ok());
+    assert_eq!(
+        refund_reason(&extras.events).as_deref(),
+        Some("error_acknowledgement")
+    );
+
+    let success_ack: Acknowledgement = AcknowledgementStatus::success(ack_success_b64()).into();
+    let (extras, res) =
+        on_acknowledgement_packet_execute(&mut ctx, &packet, &success_ack, &relayer);
+    assert!(res.is_ok());
+    assert_eq!(refund_reason(&extras.events), None);
+}
+
+#[test]
+fn test_packet_data_codec_introspection() {
+    #[derive(Debug)]
+    struct PassthroughMiddleware;
+
+    impl Middleware for PassthroughMiddleware {}
+
+    let stack = MiddlewareStack::new(PassthroughMiddleware, DummyTransferModule);
+    let packet = dummy_transfer_packet();
+
+    let codec = stack
+        .packet_data_codec()
+        .expect("the transfer packet data is introspectable");
+
+    codec
+        .validate_bytes(&packet.data)
+        .expect("valid packet data");
+
+    let info = codec.decode_info(&packet.data).expect("valid packet data");
+    assert_eq!(info.sender(), Some(dummy_account_id().as_ref()));
+    assert_eq!(info.memo(), Some(""));
+
+    let zero_amount: PacketData = PacketDataConfig::builder()
+        .token("0uatom".parse().expect("valid coin"))
+        .build();
+    let zero_amount = serde_json::to_vec(&zero_amount).expect("valid packet data");
+
+    assert!(codec.validate_bytes(&zero_amount).is_err());
+    assert!(codec.validate_bytes(b"not json").is_err());
+}
+
+#[test]
+fn test_transfer_params_defaults() {
+    let mut ctx = DummyTransferModule;
+
+    let res = query_params(&ctx).expect("params are queryable");
+    assert_eq!(
+        res.params.map(TransferParams::from),
+        Some(TransferParams::default())
+    );
+
+    let msg = MsgUpdateParams {
+        signer: dummy_account_id(),
+        params: TransferParams::new(false, false),
+    };
+
+    assert!(matches!(
+        update_params(&mut ctx, msg),
+        Err(TokenTransferError::UnauthorizedParamsUpdate { .. })
+    ));
+}
@@ -0,0 +1,89 @@
+//! Snapshots of the JSON representation of the IBC events, which must match
+//! the events found in the transaction results of ibc-go chains.
+
+use ibc::core::channel::types::channel::Order;
+use ibc::core::channel::types::events::SendPacket;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::client::types::events::CreateClient;
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::events::{EventIndexPolicy, IbcEvent, JsonEvent};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ClientType, ConnectionId, PortId};
+use ibc::core::primitives::Timestamp;
+
+fn to_json(event: IbcEvent, policy: &EventIndexPolicy) -> String {
+    let json_event = event.try_into_json_event(policy).expect("valid event");
+
+    serde_json::to_string(&json_event).expect("serializable event")
+}
+
+#[test]
+fn test_create_client_event_json() {
+    let event = CreateClient::new(
+        ClientId::new("07-tendermint", 0).expect("valid client id"),
+        ClientType::new("07-tendermint").expect("valid client type"),
+        Height::new(0, 10).expect("valid height"),
+    );
+
+    assert_eq!(
+        to_json(
+            IbcEvent::CreateClient(event),
+            &EventIndexPolicy::index_all()
+        ),
+        concat!(
+            r#"{"type":"create_client","attributes":["#,
+            r#"{"key":"client_id","value":"07-tendermint-0","index":true},"#,
+            r#"{"key":"client_type","value":"07-tendermint","index":true},"#,
+            r#"{"key":"consensus_height","value":"0-10","index":true}"#,
+            r#"]}"#,
+        )
+    );
+}
+
+#[test]
+fn test_send_packet_event_json() {
+    let packet = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: br#"{"amount":"1000"}"#.to_vec(),
+        timeout_height_on_b: TimeoutHeight::At(Height::new(1, 1000).expect("valid height")),
+        timeout_timestamp_on_b: Timestamp::from_nanoseconds(1_700_000_000_000_000_000)
+            .expect("valid timestamp"),
+    };
+    let event = SendPacket::new(packet, Order::Unordered, ConnectionId::new(0));
+
+    assert_eq!(
+        to_json(IbcEvent::SendPacket(event), &EventIndexPolicy::index_none()),
+        concat!(
+            r#"{"type":"send_packet","attributes":["#,
+            r#"{"key":"packet_data","value":"{\"amount\":\"1000\"}","index":false},"#,
+            r#"{"key":"packet_data_hex","value":"7b22616d6f756e74223a2231303030227d","index":false},"#,
+            r#"{"key":"packet_timeout_height","value":"1-1000","index":false},"#,
+            r#"{"key":"packet_timeout_timestamp","value":"1700000000000000000","index":false},"#,
+            r#"{"key":"packet_sequence","value":"1","index":false},"#,
+            r#"{"key":"packet_src_port","value":"transfer","index":false},"#,
+            r#"{"key":"packet_src_channel","value":"channel-0","index":false},"#,
+            r#"{"key":"packet_dst_port","value":"transfer","index":false},"#,
+            r#"{"key":"packet_dst_channel","value":"channel-1","index":false},"#,
+            r#"{"key":"packet_channel_ordering","value":"ORDER_UNORDERED","index":false},"#,
+            r#"{"key":"packet_connection","value":"connection-0","index":false}"#,
+            r#"]}"#,
+        )
+    );
+}
+
+#[test]
+fn test_event_json_roundtrip() {
+    let json = r#"{"type":"create_client","attributes":[{"key":"client_id","value":"07-tendermint-0","index":true}]}"#;
+
+    let event: JsonEvent = serde_json::from_str(json).expect("valid json");
+
+    assert_eq!(event.kind, "create_client");
+    assert_eq!(
+        serde_json::to_string(&event).expect("serializable event"),
+        json
+    );
+}
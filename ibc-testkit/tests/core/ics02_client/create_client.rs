@@ -3,17 +3,27 @@ use ibc::clients::tendermint::types::{
     client_type as tm_client_type, ConsensusState as TmConsensusState,
 };
 use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::context::verify::MsgProof;
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::handler::update_params;
 use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient};
-use ibc::core::client::types::Height;
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClientParams};
+use ibc::core::client::types::params::{ClientParams, ALLOW_ALL_CLIENTS};
+use ibc::core::client::types::{Height, UpdateMeta};
 use ibc::core::commitment_types::error::CommitmentError;
-use ibc::core::entrypoint::{execute, validate};
+use ibc::core::commitment_types::merkle::DecodedProof;
+use ibc::core::entrypoint::{create_client, execute, validate};
 use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::handler::types::output::HandlerResult;
 use ibc::core::host::types::identifiers::ClientId;
 use ibc::core::host::types::path::{ClientConsensusStatePath, NextClientSequencePath};
 use ibc::core::host::{ClientStateRef, ValidationContext};
+use ibc_query::core::client::{
+    query_client_params, query_client_update_meta, query_client_update_metas,
+    QueryClientParamsRequest, QueryClientUpdateMetaRequest, QueryClientUpdateMetasRequest,
+};
 use ibc_query::core::context::ProvableContext;
 use ibc_testkit::context::{MockContext, TendermintContext};
 use ibc_testkit::fixtures::clients::tendermint::{
@@ -53,9 +63,18 @@ fn test_create_client_ok() {
 
     assert!(res.is_ok(), "validation happy path");
 
-    let res = execute(&mut ctx, &mut router, msg_envelope);
+    let output = execute(&mut ctx, &mut router, msg_envelope).expect("execution happy path");
 
-    assert!(res.is_ok(), "execution happy path");
+    assert_eq!(
+        output.result,
+        Some(HandlerResult::ClientCreated(client_id.clone()))
+    );
+    assert_eq!(output.result.unwrap().to_bytes(), client_id.as_bytes());
+    assert!(matches!(
+        output.events.as_slice(),
+        [IbcEvent::Message(_), IbcEvent::CreateClient(_)]
+    ));
+    assert_eq!(output.logs.len(), 1);
 
     let expected_client_state =
         ClientStateRef::<DefaultIbcStore>::try_from(msg.client_state).unwrap();
@@ -63,6 +82,162 @@ fn test_create_client_ok() {
     assert_eq!(ctx.client_state(&client_id).unwrap(), expected_client_state);
 }
 
+#[test]
+fn test_create_client_records_update_meta() {
+    let mut ctx = DefaultIbcStore::default();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    let client_id = mock_client_type().build_client_id(ctx.client_counter().unwrap());
+
+    execute(
+        &mut ctx,
+        &mut router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    )
+    .expect("execution happy path");
+
+    let expected_meta = UpdateMeta::new(ctx.host_timestamp().unwrap(), ctx.host_height().unwrap());
+
+    let response = query_client_update_meta(
+        &ctx,
+        &QueryClientUpdateMetaRequest {
+            client_id: client_id.clone(),
+            consensus_height: height,
+        },
+    )
+    .expect("update meta is recorded");
+    assert_eq!(response.update_meta, expected_meta);
+
+    let response = query_client_update_metas(&ctx, &QueryClientUpdateMetasRequest { client_id })
+        .expect("update metas are recorded");
+    assert_eq!(response.update_metas, vec![(height, expected_meta)]);
+}
+
+#[test]
+fn test_create_client_per_message_entrypoints() {
+    let mut ctx = DefaultIbcStore::default();
+    let signer = dummy_account_id();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        signer,
+    );
+
+    let client_id = mock_client_type().build_client_id(ctx.client_counter().unwrap());
+
+    let res = create_client::validate(&ctx, msg.clone());
+
+    assert!(res.is_ok(), "validation happy path");
+
+    let res = create_client::execute(&mut ctx, msg);
+
+    assert_eq!(
+        res.expect("execution happy path").client_id,
+        client_id,
+        "the response carries the identifier of the created client"
+    );
+
+    assert!(ctx.client_state(&client_id).is_ok());
+}
+
+#[test]
+fn test_create_client_not_allowed() {
+    let mut ctx = DefaultIbcStore::default();
+    let signer = dummy_account_id();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        signer.clone(),
+    );
+
+    let msg_update_params = MsgUpdateClientParams {
+        signer,
+        params: ClientParams::new(vec![tm_client_type().as_str().to_string()]),
+    };
+
+    assert!(update_params::validate(&ctx, msg_update_params.clone()).is_ok());
+    assert!(update_params::execute(&mut ctx, msg_update_params).is_ok());
+    assert!(matches!(
+        ctx.events.lock().last(),
+        Some(IbcEvent::Message(MessageEvent::Client))
+    ));
+
+    let res = create_client::validate(&ctx, msg.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(
+                ClientError::ClientTypeNotAllowed { .. }
+            ))
+        ),
+        "validation fails since the mock client type is not allowed: {res:?}"
+    );
+
+    ctx.client_params = ClientParams::new(vec![ALLOW_ALL_CLIENTS.to_string()]);
+
+    assert!(create_client::validate(&ctx, msg).is_ok());
+}
+
+/// The client params query reports the allowed clients of the client params
+/// by default.
+#[test]
+fn test_query_client_params_default() {
+    let mut ctx = DefaultIbcStore::default();
+    let request = QueryClientParamsRequest { query_height: None };
+
+    let response = query_client_params(&ctx, &request).expect("no error");
+    assert_eq!(
+        response.allowed_clients,
+        vec![ALLOW_ALL_CLIENTS.to_string()]
+    );
+
+    ctx.client_params = ClientParams::new(vec![tm_client_type().as_str().to_string()]);
+
+    let response = query_client_params(&ctx, &request).expect("no error");
+    assert_eq!(
+        response.allowed_clients,
+        vec![tm_client_type().as_str().to_string()]
+    );
+}
+
+/// Hosts overriding the allowed clients of the query report them instead of
+/// those of the client params.
+#[test]
+fn test_query_client_params_overridden() {
+    let mut ctx = DefaultIbcStore::default();
+    ctx.client_params = ClientParams::new(vec![tm_client_type().as_str().to_string()]);
+    let request = QueryClientParamsRequest { query_height: None };
+
+    ctx.queried_allowed_clients = Some(vec![mock_client_type().as_str().to_string()]);
+
+    let response = query_client_params(&ctx, &request).expect("no error");
+    assert_eq!(
+        response.allowed_clients,
+        vec![mock_client_type().as_str().to_string()]
+    );
+
+    ctx.queried_allowed_clients = Some(vec![ALLOW_ALL_CLIENTS.to_string()]);
+
+    let response = query_client_params(&ctx, &request).expect("no error");
+    assert_eq!(
+        response.allowed_clients,
+        vec![ALLOW_ALL_CLIENTS.to_string()]
+    );
+    assert!(ClientParams::new(response.allowed_clients).is_allowed(&mock_client_type()));
+}
+
 #[test]
 fn test_tm_create_client_ok() {
     let signer = dummy_account_id();
@@ -211,4 +386,49 @@ fn test_tm_create_client_proof_verification_ok() {
             .expect_err("proof verification fails"),
         ClientError::Ics23Verification(CommitmentError::VerificationFailure)
     ));
+
+    // the proof decoded once is reused across verifications
+    let decoded_proof = DecodedProof::try_from(&proof).expect("valid merkle proof");
+
+    tm_client_state
+        .verify_membership_decoded(
+            &ctx_tm.ibc_store().commitment_prefix(),
+            &decoded_proof,
+            &root,
+            next_client_seq_path.clone().into(),
+            serde_json::to_vec(&next_client_seq_value).expect("valid json serialization"),
+        )
+        .expect("successful proof verification");
+
+    assert!(matches!(
+        tm_client_state
+            .verify_membership_decoded(
+                &ctx_tm.ibc_store().commitment_prefix(),
+                &decoded_proof,
+                &root,
+                next_client_seq_path.clone().into(),
+                serde_json::to_vec(&(next_client_seq_value + 1)).expect("valid json serialization"),
+            )
+            .expect_err("proof verification fails"),
+        ClientError::Ics23Verification(CommitmentError::VerificationFailure)
+    ));
+
+    // handlers decode the proofs of messages once to verify them and report
+    // the value they prove
+    let msg_proof = MsgProof::from(&proof);
+
+    assert!(matches!(msg_proof, MsgProof::Merkle(_)));
+    assert_eq!(
+        msg_proof.proved_value(),
+        Some(serde_json::to_vec(&next_client_seq_value).expect("valid json serialization"))
+    );
+    msg_proof
+        .verify_membership(
+            &tm_client_state,
+            &ctx_tm.ibc_store().commitment_prefix(),
+            &root,
+            next_client_seq_path.into(),
+            serde_json::to_vec(&next_client_seq_value).expect("valid json serialization"),
+        )
+        .expect("successful proof verification");
 }
@@ -4,3 +4,4 @@ pub mod recover_client;
 pub mod update_client;
 #[cfg(feature = "serde")]
 pub mod upgrade_client;
+pub mod verify;
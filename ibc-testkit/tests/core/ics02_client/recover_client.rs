@@ -5,6 +5,7 @@ use ibc::core::client::context::ClientValidationContext;
 use ibc::core::client::handler::recover_client;
 use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgRecoverClient};
 use ibc::core::client::types::{Height, Status as ClientStatus};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ClientId;
 use ibc::core::host::types::path::ClientConsensusStatePath;
@@ -164,6 +165,22 @@ fn test_recover_client_ok() {
     recover_client::execute(ctx.ibc_store_mut(), msg.clone())
         .expect("client recovery execution happy path");
 
+    {
+        let ibc_events = ctx.ibc_store().events.lock();
+        let [.., message_event, recover_event] = ibc_events.as_slice() else {
+            panic!("recover client events are expected");
+        };
+        assert!(matches!(
+            message_event,
+            IbcEvent::Message(MessageEvent::Client)
+        ));
+        let IbcEvent::RecoverClient(recover_event) = recover_event else {
+            panic!("RecoverClient event is expected");
+        };
+        assert_eq!(recover_event.subject_client_id(), &msg.subject_client_id);
+        assert_eq!(recover_event.client_type(), &mock_client_type());
+    }
+
     // client state is copied.
     assert_eq!(
         ctx.ibc_store()
@@ -174,6 +191,19 @@ fn test_recover_client_ok() {
             .unwrap(),
     );
 
+    // latest consensus state is indexed by its processed time.
+    let host_timestamp = ctx.ibc_store().host_timestamp().unwrap();
+    assert!(
+        ctx.ibc_store()
+            .secondary_indexes
+            .lock()
+            .consensus_states_by_processed_time
+            .get(&host_timestamp.nanoseconds())
+            .is_some_and(
+                |states| states.contains(&(msg.subject_client_id.clone(), substitute_height))
+            )
+    );
+
     // latest consensus state is copied.
     assert_eq!(
         ctx.ibc_store()
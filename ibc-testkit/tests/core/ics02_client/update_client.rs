@@ -3,25 +3,29 @@ use core::str::FromStr;
 use core::time::Duration;
 
 use basecoin_store::context::ProvableStore;
-use ibc::clients::tendermint::client_state::ClientState;
+use ibc::clients::tendermint::client_state::{
+    reset_to_header, update_trust_params, verify_header_with_trust_level, ClientState,
+};
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
 use ibc::clients::tendermint::types::{
     client_type as tm_client_type, ClientState as TmClientState, Header as TmHeader,
-    Misbehaviour as TmMisbehaviour,
+    Misbehaviour as TmMisbehaviour, TrustParamsUpdate, TrustThreshold,
 };
 use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
 use ibc::core::client::types::proto::v1::Height as RawHeight;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{Height, Status};
 use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChainId, ClientId, ClientType};
 use ibc::core::host::types::path::ClientConsensusStatePath;
 use ibc::core::host::ValidationContext;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, Timestamp};
 use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
 use ibc_testkit::context::{MockContext, TendermintContext, TestContext};
@@ -38,12 +42,16 @@ use ibc_testkit::testapp::ibc::clients::mock::client_state::{
 };
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{
+use ibc_testkit::utils::year_2024;
     DefaultIbcStore, LightClientBuilder, LightClientState, MockIbcStore,
 };
 use rstest::*;
+use tendermint::block::CommitSig;
+use tendermint::crypto::default::Sha256;
+use tendermint_light_client_verifier::ProdVerifier;
 use tendermint_testgen::Validator as TestgenValidator;
 use tracing::debug;
 
@@ -68,7 +76,7 @@ fn fixture() -> Fixture {
 
 /// Returns a `MsgEnvelope` with the `client_message` field set to a `MockMisbehaviour` report.
 fn msg_update_client(client_id: &ClientId) -> MsgEnvelope {
-    let timestamp = Timestamp::now();
+    let timestamp = year_2024();
     let height = Height::new(0, 46).unwrap();
     let msg = MsgUpdateClient {
         client_id: client_id.clone(),
@@ -93,7 +101,7 @@ fn test_update_client_ok(fixture: Fixture) {
 
     let client_id = ClientId::new("07-tendermint", 0).expect("no error");
     let signer = dummy_account_id();
-    let timestamp = Timestamp::now();
+    let timestamp = year_2024();
 
     let height = Height::new(0, 46).unwrap();
     let msg = MsgUpdateClient {
@@ -116,6 +124,116 @@ fn test_update_client_ok(fixture: Fixture) {
         ctx.ibc_store.client_state(&msg.client_id).unwrap(),
         MockClientState::new(MockHeader::new(height).with_timestamp(timestamp)).into()
     );
+
+    let host_height = ctx.ibc_store.host_height().unwrap();
+
+    assert_eq!(
+        ctx.ibc_store
+            .secondary_indexes
+            .lock()
+            .client_updates_at(&host_height),
+        vec![(msg.client_id, height)]
+    );
+}
+
+/// Returns a `MsgEnvelope` updating the client with a `MockHeader` at the given height.
+fn msg_update_client_with_header(client_id: &ClientId, height: Height) -> MsgEnvelope {
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: MockHeader::new(height)
+            .with_timestamp(year_2024())
+            .into(),
+        signer: dummy_account_id(),
+    };
+
+    MsgEnvelope::from(ClientMsg::from(msg))
+}
+
+#[rstest]
+fn test_update_client_rejected_by_mock_client(fixture: Fixture) {
+    let Fixture { ctx, router } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let msg_envelope = msg_update_client_with_header(&client_id, Height::new(0, 46).unwrap());
+
+    ctx.ibc_store
+        .mock_client_handle
+        .reject_next_updates(&client_id, 1);
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(
+                ClientError::HeaderVerificationFailure { .. }
+            ))
+        ),
+        "{res:?}"
+    );
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(res.is_ok(), "only the next update is rejected: {res:?}");
+}
+
+#[rstest]
+#[case(Status::Frozen)]
+#[case(Status::Expired)]
+fn test_update_client_on_inactive_mock_client(fixture: Fixture, #[case] status: Status) {
+    let Fixture { ctx, router } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let msg_envelope = msg_update_client_with_header(&client_id, Height::new(0, 46).unwrap());
+
+    ctx.ibc_store
+        .mock_client_handle
+        .force_status(&client_id, status);
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::ClientNotActive { status: s })) if s == status
+        ),
+        "{res:?}"
+    );
+}
+
+#[rstest]
+fn test_update_client_with_conflicting_mock_header(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let msg_envelope = msg_update_client_with_header(&client_id, Height::new(0, 46).unwrap());
+
+    ctx.ibc_store
+        .mock_client_handle
+        .produce_conflicting_headers(&client_id, true);
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "validation happy path: {res:?}");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "execution happy path: {res:?}");
+
+    let ibc_events = ctx.get_events();
+    assert!(matches!(
+        ibc_events.last(),
+        Some(IbcEvent::ClientMisbehaviour(_))
+    ));
+
+    ctx.ibc_store.mock_client_handle.reset(&client_id);
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+    let status = client_state
+        .status(ctx.ibc_store.get_client_validation_context(), &client_id)
+        .unwrap();
+    assert_eq!(status, Status::Frozen);
 }
 
 #[rstest]
@@ -159,7 +277,7 @@ fn test_update_client_with_prev_header() {
             .generate_block(
                 Vec::new(),
                 target_height.revision_height(),
-                Timestamp::now(),
+                year_2024(),
                 &Default::default(),
             )
             .into_header();
@@ -234,7 +352,7 @@ fn test_consensus_state_pruning() {
     let mut ctx = TestContextConfig::builder()
         .host(TendermintHost::builder().chain_id(chain_id).build())
         .latest_height(client_height)
-        .latest_timestamp(Timestamp::now())
+        .latest_timestamp(year_2024())
         .build::<TendermintContext>()
         .with_light_client(
             &client_id,
@@ -252,6 +370,16 @@ fn test_consensus_state_pruning() {
 
     let start_host_timestamp = ctx.ibc_store.host_timestamp().unwrap();
 
+    // The initial consensus state is indexed as if the client was created
+    // through the handler.
+    ctx.ibc_store
+        .secondary_indexes
+        .lock()
+        .consensus_states_by_processed_time
+        .entry(start_host_timestamp.nanoseconds())
+        .or_default()
+        .insert((client_id.clone(), client_height));
+
     // Move the chain forward by 2 blocks to pass the trusting period.
     for _ in 1..=2 {
         let signer = dummy_account_id();
@@ -316,6 +444,20 @@ fn test_consensus_state_pruning() {
         end_host_timestamp,
         (start_host_timestamp + Duration::from_secs(6)).unwrap()
     );
+
+    // Check that only the pruned consensus state is removed from the index.
+    let indexed_states: Vec<_> = ctx
+        .ibc_store
+        .secondary_indexes
+        .lock()
+        .consensus_states_by_processed_time
+        .values()
+        .flatten()
+        .cloned()
+        .collect();
+
+    assert!(!indexed_states.contains(&(client_id.clone(), expired_height)));
+    assert!(indexed_states.contains(&(client_id, earliest_valid_height)));
 }
 
 #[rstest]
@@ -690,6 +832,358 @@ fn test_update_synthetic_tendermint_client_validator_change_fail() {
     assert!(res.is_err());
 }
 
+/// Sets up a Tendermint client of chain B on a mock chain A, trusted at
+/// height 20 of chain B and with the given trust level, along with a header
+/// of chain B skipping from height 20 to the last height of the given
+/// validator history.
+fn validator_handoff_fixture(
+    val_history: Vec<Vec<TestgenValidator>>,
+    trust_level: TrustThreshold,
+) -> (MockContext, ClientId, TmHeader) {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let block_params = BlockParams::from_validator_history(val_history);
+
+    let update_height = client_height.add(block_params.len() as u64 - 1);
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .block_params_history(block_params)
+        .build::<TendermintContext>();
+
+    let ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .params(
+                    ClientStateConfig::builder()
+                        .trust_level(trust_level)
+                        .build(),
+                )
+                .build(),
+        );
+
+    let trusted_next_validator_set = ctx_b
+        .host_block(&client_height)
+        .expect("no error")
+        .next_validators
+        .clone();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+
+    block.set_trusted_height(client_height);
+    block.set_trusted_next_validators_set(trusted_next_validator_set);
+
+    (ctx_a, client_id, block.into())
+}
+
+fn tm_client_state(ctx: &MockContext, client_id: &ClientId) -> TmClientState {
+    match ctx.ibc_store.client_state(client_id).unwrap() {
+        AnyClientState::Tendermint(client_state) => client_state.inner().clone(),
+        AnyClientState::Mock(_) => panic!("expected a Tendermint client"),
+    }
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_trust_level_override() {
+    let ctx_b_val_history = vec![
+        // validator set of height-20
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // next validator set of height-20
+        // validator set of height-21
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // next validator set of height-21
+        // validator set of height-22
+        // half of the power is handed off to a new validator
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("3").voting_power(50),
+        ],
+        // validator set of height-23
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("3").voting_power(50),
+        ],
+    ];
+
+    let (ctx_a, client_id, header) =
+        validator_handoff_fixture(ctx_b_val_history, TrustThreshold::TWO_THIRDS);
+
+    let router_a = MockRouter::new_with_transfer();
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: header.clone().into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "less than two thirds of the trusted power signed the header"
+    );
+
+    let client_state = tm_client_state(&ctx_a, &client_id);
+
+    let res = verify_header_with_trust_level::<_, Sha256>(
+        &client_state,
+        &ctx_a.ibc_store,
+        &client_id,
+        &header,
+        TrustThreshold::ONE_THIRD,
+        &ProdVerifier::default(),
+    );
+
+    assert!(res.is_ok(), "handoff with overridden trust level: {res:?}");
+
+    // the override does not weaken the client itself
+    assert_eq!(
+        tm_client_state(&ctx_a, &client_id).trust_level,
+        TrustThreshold::TWO_THIRDS
+    );
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_trust_params() {
+    let ctx_b_val_history = vec![
+        // validator set of height-20
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // next validator set of height-20
+        // validator set of height-21
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // next validator set of height-21
+        // validator set of height-22
+        // half of the power is handed off to a new validator
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("3").voting_power(50),
+        ],
+        // validator set of height-23
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("3").voting_power(50),
+        ],
+    ];
+
+    let (mut ctx_a, client_id, header) =
+        validator_handoff_fixture(ctx_b_val_history, TrustThreshold::TWO_THIRDS);
+
+    let router_a = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: header.into(),
+        signer: dummy_account_id(),
+    }));
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
+
+    assert!(
+        res.is_err(),
+        "less than two thirds of the trusted power signed the header"
+    );
+
+    let client_state = tm_client_state(&ctx_a, &client_id);
+    let params = TrustParamsUpdate {
+        trust_level: Some(TrustThreshold::ONE_THIRD),
+        ..Default::default()
+    };
+
+    let res = update_trust_params(
+        &client_state,
+        &mut ctx_a.ibc_store,
+        &client_id,
+        params.clone(),
+        &Signer::from("governance".to_string()),
+    );
+
+    assert!(
+        res.is_err(),
+        "only the recovery authority can update the trust parameters"
+    );
+    assert_eq!(
+        tm_client_state(&ctx_a, &client_id).trust_level,
+        TrustThreshold::TWO_THIRDS
+    );
+
+    update_trust_params(
+        &client_state,
+        &mut ctx_a.ibc_store,
+        &client_id,
+        params,
+        &dummy_account_id(),
+    )
+    .expect("trust parameters are updated");
+
+    assert_eq!(
+        tm_client_state(&ctx_a, &client_id).trust_level,
+        TrustThreshold::ONE_THIRD
+    );
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
+
+    assert!(res.is_ok(), "handoff with lowered trust level: {res:?}");
+}
+
+#[rstest]
+fn test_reset_synthetic_tendermint_client_to_header() {
+    // more than two thirds of the power changes at once, such that not even
+    // the lowest trust level allows verifying the header
+    let ctx_b_val_history = vec![
+        // validator set of height-20
+        vec![
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ],
+        // next validator set of height-20
+        // validator set of height-21
+        vec![
+            TestgenValidator::new("1").voting_power(90),
+            TestgenValidator::new("2").voting_power(10),
+        ],
+        // next validator set of height-21
+        // validator set of height-22
+        vec![
+            TestgenValidator::new("4").voting_power(90),
+            TestgenValidator::new("2").voting_power(10),
+        ],
+        // validator set of height-23
+        vec![
+            TestgenValidator::new("4").voting_power(90),
+            TestgenValidator::new("2").voting_power(10),
+        ],
+    ];
+
+    let (mut ctx_a, client_id, header) =
+        validator_handoff_fixture(ctx_b_val_history, TrustThreshold::ONE_THIRD);
+
+    let client_state = tm_client_state(&ctx_a, &client_id);
+
+    let res = verify_header_with_trust_level::<_, Sha256>(
+        &client_state,
+        &ctx_a.ibc_store,
+        &client_id,
+        &header,
+        TrustThreshold::ONE_THIRD,
+        &ProdVerifier::default(),
+    );
+
+    assert!(
+        res.is_err(),
+        "less than a third of the trusted power signed"
+    );
+
+    let header_height = header.height().unwrap();
+
+    let res = reset_to_header::<_, Sha256>(
+        &client_state,
+        &mut ctx_a.ibc_store,
+        &client_id,
+        header.clone(),
+        &Signer::from("governance".to_string()),
+        &ProdVerifier::default(),
+    );
+
+    assert!(
+        res.is_err(),
+        "only the recovery authority can reset the client"
+    );
+
+    let mut unsigned_header = header.clone();
+    unsigned_header.signed_header.commit.signatures = unsigned_header
+        .signed_header
+        .commit
+        .signatures
+        .iter()
+        .map(|_| CommitSig::BlockIdFlagAbsent)
+        .collect();
+
+    let res = reset_to_header::<_, Sha256>(
+        &client_state,
+        &mut ctx_a.ibc_store,
+        &client_id,
+        unsigned_header,
+        &dummy_account_id(),
+        &ProdVerifier::default(),
+    );
+
+    assert!(
+        res.is_err(),
+        "cannot reset to a header not signed by its validator set"
+    );
+    assert_eq!(
+        tm_client_state(&ctx_a, &client_id).latest_height,
+        client_state.latest_height
+    );
+
+    let reset_height = reset_to_header::<_, Sha256>(
+        &client_state,
+        &mut ctx_a.ibc_store,
+        &client_id,
+        header.clone(),
+        &dummy_account_id(),
+        &ProdVerifier::default(),
+    )
+    .expect("reset to header");
+
+    assert_eq!(reset_height, header_height);
+    assert_eq!(
+        tm_client_state(&ctx_a, &client_id).latest_height,
+        header_height
+    );
+
+    let consensus_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header_height.revision_number(),
+        header_height.revision_height(),
+    );
+    assert!(ctx_a
+        .ibc_store
+        .consensus_state(&consensus_state_path)
+        .is_ok());
+
+    let client_state = tm_client_state(&ctx_a, &client_id);
+    let res = reset_to_header::<_, Sha256>(
+        &client_state,
+        &mut ctx_a.ibc_store,
+        &client_id,
+        header,
+        &dummy_account_id(),
+        &ProdVerifier::default(),
+    );
+
+    assert!(
+        res.is_err(),
+        "cannot reset to a header not above the latest height"
+    );
+}
+
 #[rstest]
 fn test_update_synthetic_tendermint_client_malicious_validator_change_pass() {
     let client_id = tm_client_type().build_client_id(0);
@@ -1120,7 +1614,7 @@ fn test_update_client_events(fixture: Fixture) {
 
     let client_id = ClientId::new("07-tendermint", 0).expect("no error");
     let signer = dummy_account_id();
-    let timestamp = Timestamp::now();
+    let timestamp = year_2024();
 
     let height = Height::new(0, 46).unwrap();
     let header: Any = MockHeader::new(height).with_timestamp(timestamp).into();
@@ -1285,7 +1779,7 @@ fn test_misbehaviour_synthetic_tendermint_equivocation() {
             .generate_block(
                 Vec::new(),
                 misbehaviour_height.revision_height(),
-                Timestamp::now(),
+                year_2024(),
                 &Default::default(),
             )
             .into_header();
@@ -1347,7 +1841,7 @@ fn test_misbehaviour_synthetic_tendermint_bft_time() {
             .generate_block(
                 Vec::new(),
                 misbehaviour_height.revision_height(),
-                Timestamp::now(),
+                year_2024(),
                 &Default::default(),
             )
             .into_header();
@@ -1359,7 +1853,7 @@ fn test_misbehaviour_synthetic_tendermint_bft_time() {
     // timestamp
     let header2 = {
         let timestamp =
-            Timestamp::from_nanoseconds(Timestamp::now().nanoseconds() + 1_000_000_000).unwrap();
+            Timestamp::from_nanoseconds(year_2024().nanoseconds() + 1_000_000_000).unwrap();
         let mut tm_block = TendermintHost::builder()
             .chain_id(chain_id_b)
             .build()
@@ -1399,7 +1893,7 @@ fn test_expired_client() {
 
     let client_id = tm_client_type().build_client_id(0);
 
-    let timestamp = Timestamp::now();
+    let timestamp = year_2024();
 
     let trusting_period = Duration::from_secs(64);
 
@@ -1452,7 +1946,7 @@ fn test_client_update_max_clock_drift() {
 
     let client_id = tm_client_type().build_client_id(0);
 
-    let timestamp = Timestamp::now();
+    let timestamp = year_2024();
 
     let max_clock_drift = Duration::from_secs(64);
 
@@ -0,0 +1,379 @@
+use ibc::core::client::context::verify::{
+    batch_membership_at_height, membership_at_height, merkle_membership_at_height,
+    non_membership_at_height,
+};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::commitment_types::error::CommitmentError;
+use ibc::core::commitment_types::merkle::{try_apply_prefix, MerkleProof};
+use ibc::core::commitment_types::proto::ics23::commitment_proof::Proof;
+use ibc::core::commitment_types::proto::ics23::{
+    batch_entry, BatchEntry, BatchProof, CommitmentProof,
+};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    CommitmentPath, NextClientSequencePath, NextConnectionSequencePath, Path, ReceiptPath,
+};
+use ibc::core::host::ValidationContext;
+use ibc_query::core::context::ProvableContext;
+use ibc_testkit::context::{MockContext, TendermintContext};
+use ibc_testkit::fixtures::core::context::TestContextConfig;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
+use ibc_testkit::testapp::ibc::core::types::{LightClientBuilder, LightClientState};
+use test_log::test;
+
+struct Fixture {
+    context: MockContext,
+    client_id: ClientId,
+    client_height: Height,
+    prefix: CommitmentPrefix,
+    /// Proves the commitment of the first packet on the transfer channel.
+    proof: CommitmentProofBytes,
+    /// Proves the absence of the receipt of that packet.
+    non_membership_proof: CommitmentProofBytes,
+}
+
+fn fixture() -> Fixture {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let client_height = Height::new(0, 5).expect("valid height");
+    let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("no error");
+
+    let commitment_path: Path =
+        CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1)).into();
+    let receipt_path: Path =
+        ReceiptPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1)).into();
+
+    let mut tree = MockCommitmentTree::new(prefix.clone());
+    tree.insert(commitment_path.clone(), vec![1]);
+
+    let context = MockContext::default().with_light_client(
+        &client_id,
+        LightClientState::<MockHost>::with_latest_height(client_height)
+            .with_commitment_root(tree.root()),
+    );
+
+    Fixture {
+        context,
+        client_id,
+        client_height,
+        prefix,
+        proof: tree.prove_membership(&commitment_path).expect("no error"),
+        non_membership_proof: tree.prove_non_membership(&receipt_path).expect("no error"),
+    }
+}
+
+#[test]
+fn verify_membership_at_height_happy_path() {
+    let Fixture {
+        context,
+        client_id,
+        client_height,
+        prefix,
+        proof,
+        non_membership_proof,
+    } = fixture();
+
+    let commitment_path =
+        CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1));
+
+    let res = membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        client_height,
+        &prefix,
+        &proof,
+        commitment_path.clone(),
+        vec![1],
+    );
+    assert!(res.is_ok(), "verification happy path: {res:?}");
+
+    let res = membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        client_height,
+        &prefix,
+        &proof,
+        commitment_path,
+        vec![2],
+    );
+    assert!(res.is_err(), "verification fails for a wrong value");
+
+    let receipt_path = ReceiptPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1));
+
+    let res = non_membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        client_height,
+        &prefix,
+        &non_membership_proof,
+        receipt_path,
+    );
+    assert!(res.is_ok(), "verification happy path: {res:?}");
+}
+
+#[test]
+fn verify_membership_at_height_fail_proof_height_too_high() {
+    let Fixture {
+        context,
+        client_id,
+        client_height,
+        prefix,
+        proof,
+        ..
+    } = fixture();
+
+    let res = membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        client_height.increment(),
+        &prefix,
+        &proof,
+        CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1)),
+        vec![1],
+    );
+
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(
+            ClientError::InvalidProofHeight { .. }
+        ))
+    ));
+}
+
+#[test]
+fn verify_membership_at_height_fail_unknown_client() {
+    let Fixture {
+        context,
+        client_height,
+        prefix,
+        proof,
+        ..
+    } = fixture();
+
+    let res = membership_at_height(
+        context.ibc_store(),
+        &ClientId::new("07-tendermint", 1).expect("no error"),
+        client_height,
+        &prefix,
+        &proof,
+        CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1)),
+        vec![1],
+    );
+
+    assert!(res.is_err(), "verification fails for an unknown client");
+}
+
+/// A batch proof of several values of the counterparty store is verified at
+/// once by the Tendermint client, while a proof of a single value does not
+/// prove the others.
+#[test]
+fn verify_batch_membership_at_height() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let client_height = Height::new(0, 10).expect("no error");
+
+    let ctx_tm = TestContextConfig::builder()
+        .latest_height(client_height)
+        .build::<TendermintContext>();
+
+    let context = MockContext::default().with_light_client(
+        &client_id,
+        LightClientBuilder::init().context(&ctx_tm).build(),
+    );
+
+    let prefix = ctx_tm.ibc_store().commitment_prefix();
+    let proof_height = ctx_tm.latest_height();
+
+    let paths: Vec<Path> = vec![
+        NextClientSequencePath.into(),
+        NextConnectionSequencePath.into(),
+    ];
+
+    let proofs: Vec<MerkleProof> = paths
+        .iter()
+        .map(|path| {
+            let proof: CommitmentProofBytes = ctx_tm
+                .ibc_store()
+                .get_proof(proof_height, path)
+                .expect("proof exists")
+                .try_into()
+                .expect("no error");
+
+            MerkleProof::try_from(&proof).expect("valid merkle proof")
+        })
+        .collect();
+
+    // The proofs of the paths only differ in the proof of the IBC store.
+    let entries = proofs
+        .iter()
+        .map(|proof| match &proof.proofs[0].proof {
+            Some(Proof::Exist(existence_proof)) => BatchEntry {
+                proof: Some(batch_entry::Proof::Exist(existence_proof.clone())),
+            },
+            _ => panic!("not an existence proof"),
+        })
+        .collect();
+
+    let batch_proof = CommitmentProofBytes::try_from(MerkleProof {
+        proofs: vec![
+            CommitmentProof {
+                proof: Some(Proof::Batch(BatchProof { entries })),
+            },
+            proofs[0].proofs[1].clone(),
+        ],
+    })
+    .expect("no error");
+
+    let counter = serde_json::to_vec(&0u64).expect("valid json serialization");
+    let items: Vec<(Path, Vec<u8>)> = paths
+        .iter()
+        .map(|path| (path.clone(), counter.clone()))
+        .collect();
+
+    let res = batch_membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        proof_height,
+        &prefix,
+        &batch_proof,
+        items.clone(),
+    );
+    assert!(res.is_ok(), "batch verification happy path: {res:?}");
+
+    let mut wrong_items = items.clone();
+    wrong_items[1].1 = serde_json::to_vec(&1u64).expect("valid json serialization");
+
+    let res = batch_membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        proof_height,
+        &prefix,
+        &batch_proof,
+        wrong_items,
+    );
+    assert!(res.is_err(), "batch verification fails for a wrong value");
+
+    let single_proof = CommitmentProofBytes::try_from(proofs[0].clone()).expect("no error");
+
+    let res = batch_membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        proof_height,
+        &prefix,
+        &single_proof,
+        items.clone(),
+    );
+    assert!(
+        res.is_err(),
+        "batch verification fails for a proof of a single value"
+    );
+
+    let res = batch_membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        proof_height.increment(),
+        &prefix,
+        &batch_proof,
+        items,
+    );
+    assert!(
+        res.is_err(),
+        "batch verification fails beyond the client height"
+    );
+}
+
+#[test]
+fn verify_merkle_membership_at_height() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let client_height = Height::new(0, 10).expect("no error");
+
+    let ctx_tm = TestContextConfig::builder()
+        .latest_height(client_height)
+        .build::<TendermintContext>();
+
+    let context = MockContext::default().with_light_client(
+        &client_id,
+        LightClientBuilder::init().context(&ctx_tm).build(),
+    );
+
+    let prefix = ctx_tm.ibc_store().commitment_prefix();
+    let proof_height = ctx_tm.latest_height();
+    let path: Path = NextClientSequencePath.into();
+
+    let proof: CommitmentProofBytes = ctx_tm
+        .ibc_store()
+        .get_proof(proof_height, &path)
+        .expect("proof exists")
+        .try_into()
+        .expect("no error");
+
+    let merkle_path = try_apply_prefix(&prefix, [path.to_string()]).expect("no error");
+    let counter = serde_json::to_vec(&0u64).expect("valid json serialization");
+
+    let res = merkle_membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        proof_height,
+        &proof,
+        merkle_path.clone(),
+        counter,
+    );
+    assert!(res.is_ok(), "merkle verification happy path: {res:?}");
+
+    let res = merkle_membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        proof_height,
+        &proof,
+        merkle_path,
+        serde_json::to_vec(&1u64).expect("valid json serialization"),
+    );
+    assert!(res.is_err(), "merkle verification fails for a wrong value");
+}
+
+/// Clients only verifying IBC paths reject merkle paths made of other keys.
+#[test]
+fn verify_merkle_membership_at_height_fail_not_ibc_path() {
+    let Fixture {
+        context,
+        client_id,
+        client_height,
+        prefix,
+        proof,
+        ..
+    } = fixture();
+
+    let commitment_path =
+        CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1));
+
+    let merkle_path = try_apply_prefix(&prefix, [commitment_path.to_string()]).expect("no error");
+
+    let res = merkle_membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        client_height,
+        &proof,
+        merkle_path,
+        vec![1],
+    );
+    assert!(res.is_ok(), "merkle verification of an IBC path: {res:?}");
+
+    let merkle_path = try_apply_prefix(&prefix, ["bank", "balances"]).expect("no error");
+
+    let res = merkle_membership_at_height(
+        context.ibc_store(),
+        &client_id,
+        client_height,
+        &proof,
+        merkle_path,
+        vec![1],
+    );
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(
+            ClientError::InvalidCommitmentProof(CommitmentError::InvalidMerklePath { .. })
+        ))
+    ));
+}
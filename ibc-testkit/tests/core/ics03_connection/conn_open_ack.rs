@@ -2,6 +2,7 @@ use core::str::FromStr;
 
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::commitment::compute_connection_commitment;
 use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenAck};
 use ibc::core::connection::types::{ConnectionEnd, Counterparty, State};
@@ -10,14 +11,20 @@ use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use ibc::core::host::types::path::{
+    ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
+};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::ZERO_DURATION;
+use ibc::primitives::proto::Any;
+use ibc::primitives::ToVec;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_ack;
 use ibc_testkit::fixtures::core::context::TestContextConfig;
 use ibc_testkit::fixtures::{Expect, Fixture};
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{DefaultIbcStore, LightClientState};
 use test_log::test;
@@ -30,7 +37,7 @@ enum Ctx {
 }
 
 fn conn_open_ack_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenAck> {
-    let msg = dummy_msg_conn_open_ack(10, 10);
+    let mut msg = dummy_msg_conn_open_ack(10, 10);
 
     // Client parameters -- identifier and correct height (matching the proof height)
     let client_id = ClientId::from_str("mock_clientid").unwrap();
@@ -73,10 +80,12 @@ fn conn_open_ack_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenAck> {
     let ctx = match ctx {
         Ctx::New => ctx_new.ibc_store,
         Ctx::NewWithConnection => {
+            let tree = commit_on_b(&ctx_new, &client_id, &mut msg);
             ctx_new
                 .with_light_client(
                     &client_id,
-                    LightClientState::<MockHost>::with_latest_height(proof_height),
+                    LightClientState::<MockHost>::with_latest_height(proof_height)
+                        .with_commitment_root(tree.root()),
                 )
                 .with_connection(conn_id, default_conn_end)
                 .ibc_store
@@ -104,6 +113,61 @@ fn conn_open_ack_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenAck> {
     Fixture { ctx, msg }
 }
 
+/// Commits chain B to the state claimed by the message, and proves it in the
+/// message.
+fn commit_on_b(
+    ctx_a: &MockContext,
+    client_id_on_b: &ClientId,
+    msg: &mut MsgConnectionOpenAck,
+) -> MockCommitmentTree {
+    let consensus_height = msg.consensus_height_of_a_on_b;
+
+    let conn_end_on_b = ConnectionEnd::new(
+        State::TryOpen,
+        client_id_on_b.clone(),
+        Counterparty::new(
+            client_id_on_b.clone(),
+            Some(msg.conn_id_on_a.clone()),
+            ctx_a.ibc_store.commitment_prefix(),
+        ),
+        vec![msg.version.clone()],
+        ZERO_DURATION,
+    )
+    .unwrap();
+    let consensus_state_of_a_on_b = ctx_a
+        .ibc_store
+        .host_consensus_state(&consensus_height)
+        .unwrap();
+
+    let conn_path = Path::Connection(ConnectionPath::new(&msg.conn_id_on_b));
+    let client_state_path = Path::ClientState(ClientStatePath::new(client_id_on_b.clone()));
+    let consensus_state_path = Path::ClientConsensusState(ClientConsensusStatePath::new(
+        client_id_on_b.clone(),
+        consensus_height.revision_number(),
+        consensus_height.revision_height(),
+    ));
+
+    let mut tree = MockCommitmentTree::new(CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap());
+    tree.insert(
+        conn_path.clone(),
+        compute_connection_commitment(&conn_end_on_b),
+    );
+    tree.insert(
+        client_state_path.clone(),
+        msg.client_state_of_a_on_b.to_vec(),
+    );
+    tree.insert(
+        consensus_state_path.clone(),
+        Any::from(consensus_state_of_a_on_b).to_vec(),
+    );
+
+    msg.proof_conn_end_on_b = tree.prove_membership(&conn_path).unwrap();
+    msg.proof_client_state_of_a_on_b = tree.prove_membership(&client_state_path).unwrap();
+    msg.proof_consensus_state_of_a_on_b = tree.prove_membership(&consensus_state_path).unwrap();
+
+    tree
+}
+
 fn conn_open_ack_validate(fxt: &Fixture<MsgConnectionOpenAck>, expect: Expect) {
     let router = MockRouter::new_with_transfer();
     let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
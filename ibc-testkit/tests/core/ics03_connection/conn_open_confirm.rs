@@ -1,22 +1,31 @@
 use core::str::FromStr;
+use core::time::Duration;
 
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::commitment::compute_connection_commitment;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenConfirm};
 use ibc::core::connection::types::{ConnectionEnd, Counterparty, State};
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::{ConnectionPath, Path};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::ZERO_DURATION;
-use ibc_testkit::context::MockContext;
+use ibc_query::core::context::ProvableContext;
+use ibc_testkit::context::{MockContext, TendermintContext};
 use ibc_testkit::fixtures::core::connection::dummy_conn_open_confirm;
+use ibc_testkit::fixtures::core::context::TestContextConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::fixtures::{Expect, Fixture};
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::testapp::ibc::core::types::{LightClientBuilder, LightClientState};
 use test_log::test;
 
 enum Ctx {
@@ -27,7 +36,7 @@ enum Ctx {
 
 fn conn_open_confirm_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenConfirm> {
     let client_id = ClientId::from_str("mock_clientid").unwrap();
-    let msg = dummy_conn_open_confirm();
+    let mut msg = dummy_conn_open_confirm();
     let counterparty = Counterparty::new(
         client_id.clone(),
         Some(msg.conn_id_on_b.clone()),
@@ -60,10 +69,34 @@ fn conn_open_confirm_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenConfirm> {
                 .ibc_store
         }
         Ctx::CorrectConnection => {
+            // Chain A has opened its end of the connection.
+            let conn_end_on_a = ConnectionEnd::new(
+                State::Open,
+                client_id.clone(),
+                Counterparty::new(
+                    client_id.clone(),
+                    Some(msg.conn_id_on_b.clone()),
+                    ctx_default.ibc_store.commitment_prefix(),
+                ),
+                correct_conn_end.versions().to_vec(),
+                ZERO_DURATION,
+            )
+            .unwrap();
+            let conn_path = Path::Connection(ConnectionPath::new(&msg.conn_id_on_b));
+
+            let mut tree =
+                MockCommitmentTree::new(correct_conn_end.counterparty().prefix().clone());
+            tree.insert(
+                conn_path.clone(),
+                compute_connection_commitment(&conn_end_on_a),
+            );
+            msg.proof_conn_end_on_a = tree.prove_membership(&conn_path).unwrap();
+
             ctx_default
                 .with_light_client(
                     &client_id,
-                    LightClientState::<MockHost>::with_latest_height(Height::new(0, 10).unwrap()),
+                    LightClientState::<MockHost>::with_latest_height(Height::new(0, 10).unwrap())
+                        .with_commitment_root(tree.root()),
                 )
                 .with_connection(msg.conn_id_on_b.clone(), correct_conn_end)
                 .ibc_store
@@ -138,3 +171,98 @@ fn conn_open_confirm_connection_mismatch() {
     let fxt = conn_open_confirm_fixture(Ctx::IncorrectConnection);
     conn_open_confirm_validate(&fxt, Expect::Failure(None));
 }
+
+/// The connection end claimed by a failing proof is reported field by field.
+#[test]
+fn conn_open_confirm_reports_claimed_connection_mismatch() {
+    let client_id_on_a = ClientId::new("07-tendermint", 1).unwrap();
+    let client_id_on_b = ClientId::new("07-tendermint", 0).unwrap();
+    let conn_id_on_a = ConnectionId::new(1);
+    let conn_id_on_b = ConnectionId::new(2);
+
+    let ctx_b = MockContext::default();
+    let versions = ValidationContext::get_compatible_versions(&ctx_b.ibc_store);
+
+    // Chain A committed its end with another delay period than chain B
+    // expects.
+    let conn_end_on_a = ConnectionEnd::new(
+        State::Open,
+        client_id_on_a.clone(),
+        Counterparty::new(
+            client_id_on_b.clone(),
+            Some(conn_id_on_b.clone()),
+            ctx_b.ibc_store.commitment_prefix(),
+        ),
+        versions.clone(),
+        Duration::from_secs(10),
+    )
+    .unwrap();
+
+    let mut ctx_a = TestContextConfig::builder()
+        .latest_height(Height::new(0, 5).unwrap())
+        .build::<TendermintContext>()
+        .with_connection(conn_id_on_a.clone(), conn_end_on_a);
+    ctx_a.advance_block_height();
+
+    let proof_height_on_a = ctx_a.latest_height();
+    let proof_conn_end_on_a = ctx_a
+        .ibc_store()
+        .get_proof(
+            proof_height_on_a,
+            &ConnectionPath::new(&conn_id_on_a).into(),
+        )
+        .expect("proof exists")
+        .try_into()
+        .unwrap();
+
+    let conn_end_on_b = ConnectionEnd::new(
+        State::TryOpen,
+        client_id_on_b.clone(),
+        Counterparty::new(
+            client_id_on_a,
+            Some(conn_id_on_a.clone()),
+            ctx_a.ibc_store().commitment_prefix(),
+        ),
+        versions,
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let ctx_b = ctx_b
+        .with_light_client(
+            &client_id_on_b,
+            LightClientBuilder::init().context(&ctx_a).build(),
+        )
+        .with_connection(conn_id_on_b.clone(), conn_end_on_b);
+
+    let msg = MsgConnectionOpenConfirm {
+        conn_id_on_b,
+        proof_conn_end_on_a,
+        proof_height_on_a,
+        signer: dummy_account_id(),
+    };
+
+    let res = validate(
+        &ctx_b.ibc_store,
+        &MockRouter::new_with_transfer(),
+        MsgEnvelope::from(ConnectionMsg::from(msg)),
+    );
+
+    match res {
+        Err(ContextError::ConnectionError(ConnectionError::CounterpartyConnectionMismatch {
+            connection_id,
+            diff,
+            ..
+        })) => {
+            assert_eq!(connection_id, conn_id_on_a);
+            assert_eq!(
+                diff.mismatches()
+                    .iter()
+                    .map(|mismatch| mismatch.field)
+                    .collect::<Vec<_>>(),
+                vec!["delay_period"]
+            );
+        }
+        _ => panic!("expected a counterparty connection mismatch, got {res:?}"),
+    }
+}
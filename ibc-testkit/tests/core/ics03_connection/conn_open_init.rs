@@ -1,16 +1,18 @@
 use ibc::core::client::types::Height;
+use ibc::core::connection::localhost::connection_end;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenInit};
 use ibc::core::connection::types::version::Version;
 use ibc::core::connection::types::State;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::connection::{
-    dummy_msg_conn_open_init, msg_conn_open_init_with_counterparty_conn_id,
-    msg_conn_open_with_version,
+    dummy_msg_conn_open_init, dummy_msg_conn_open_init_with_client_id,
+    msg_conn_open_init_with_counterparty_conn_id, msg_conn_open_with_version,
 };
 use ibc_testkit::fixtures::{Expect, Fixture};
 use ibc_testkit::hosts::MockHost;
@@ -28,6 +30,7 @@ enum Msg {
     NoVersion,
     BadVersion,
     WithCounterpartyConnId,
+    LocalhostClient,
 }
 
 fn conn_open_init_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<MsgConnectionOpenInit> {
@@ -39,6 +42,9 @@ fn conn_open_init_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<MsgConn
             msg_conn_open_with_version(msg_default, Some("random identifier 424242"))
         }
         Msg::WithCounterpartyConnId => msg_conn_open_init_with_counterparty_conn_id(msg_default, 2),
+        Msg::LocalhostClient => {
+            dummy_msg_conn_open_init_with_client_id(msg_default, ClientId::localhost())
+        }
     };
 
     let ctx_default = MockContext::default();
@@ -147,3 +153,25 @@ fn conn_open_init_with_counterparty_conn_id() {
     let expected_version = vec![fxt.msg.version.clone().unwrap()];
     conn_open_init_execute(&mut fxt, Expect::Success, expected_version);
 }
+
+#[test]
+fn conn_open_init_localhost_client() {
+    let fxt = conn_open_init_fixture(Ctx::WithClient, Msg::LocalhostClient);
+    conn_open_init_validate(&fxt, Expect::Failure(None));
+}
+
+#[test]
+fn localhost_connection_served_by_core() {
+    let ctx = MockContext::default().ibc_store;
+
+    assert!(ValidationContext::connection_end(&ctx, &ConnectionId::localhost()).is_err());
+
+    let conn_end = connection_end(&ctx, &ConnectionId::localhost()).unwrap();
+    assert!(conn_end.is_open());
+    assert!(conn_end.is_localhost());
+    assert_eq!(
+        conn_end.counterparty().connection_id(),
+        Some(&ConnectionId::localhost())
+    );
+    assert_eq!(ctx.connection_counter().unwrap(), 0);
+}
@@ -1,16 +1,23 @@
 use ibc::core::client::types::Height;
+use ibc::core::connection::types::commitment::compute_connection_commitment;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenTry};
-use ibc::core::connection::types::State;
+use ibc::core::connection::types::{ConnectionEnd, Counterparty, State};
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::path::{
+    ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
+};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
+use ibc::primitives::proto::Any;
+use ibc::primitives::ToVec;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_try;
 use ibc_testkit::fixtures::core::context::TestContextConfig;
 use ibc_testkit::fixtures::{Expect, Fixture};
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{DefaultIbcStore, LightClientState};
 use test_log::test;
@@ -33,7 +40,7 @@ fn conn_open_try_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<MsgConne
     let host_chain_height = Height::new(0, 35).unwrap();
     let pruned_height = host_chain_height.sub(retained_history_size + 1).unwrap();
 
-    let msg = match msg_variant {
+    let mut msg = match msg_variant {
         Msg::Default => dummy_msg_conn_open_try(
             client_cons_state_height,
             host_chain_height.revision_height(),
@@ -57,12 +64,14 @@ fn conn_open_try_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<MsgConne
     let ctx = match ctx_variant {
         Ctx::Default => DefaultIbcStore::default(),
         Ctx::WithClient => {
+            let tree = commit_on_a(&ctx_new, &mut msg);
             ctx_new
                 .with_light_client(
                     &msg.client_id_on_b,
                     LightClientState::<MockHost>::with_latest_height(
                         Height::new(0, client_cons_state_height).unwrap(),
-                    ),
+                    )
+                    .with_commitment_root(tree.root()),
                 )
                 .ibc_store
         }
@@ -72,6 +81,59 @@ fn conn_open_try_fixture(ctx_variant: Ctx, msg_variant: Msg) -> Fixture<MsgConne
     Fixture { ctx, msg }
 }
 
+/// Commits chain A to the state claimed by the message, and proves it in the
+/// message.
+fn commit_on_a(ctx_b: &MockContext, msg: &mut MsgConnectionOpenTry) -> MockCommitmentTree {
+    let client_id_on_a = msg.counterparty.client_id().clone();
+    let consensus_height = msg.consensus_height_of_b_on_a;
+
+    let conn_end_on_a = ConnectionEnd::new(
+        State::Init,
+        client_id_on_a.clone(),
+        Counterparty::new(
+            msg.client_id_on_b.clone(),
+            None,
+            ctx_b.ibc_store.commitment_prefix(),
+        ),
+        msg.versions_on_a.clone(),
+        msg.delay_period,
+    )
+    .unwrap();
+
+    let conn_path = Path::Connection(ConnectionPath::new(
+        msg.counterparty.connection_id().unwrap(),
+    ));
+    let client_state_path = Path::ClientState(ClientStatePath::new(client_id_on_a.clone()));
+    let consensus_state_path = Path::ClientConsensusState(ClientConsensusStatePath::new(
+        client_id_on_a,
+        consensus_height.revision_number(),
+        consensus_height.revision_height(),
+    ));
+
+    let mut tree = MockCommitmentTree::new(msg.counterparty.prefix().clone());
+    tree.insert(
+        conn_path.clone(),
+        compute_connection_commitment(&conn_end_on_a),
+    );
+    tree.insert(
+        client_state_path.clone(),
+        msg.client_state_of_b_on_a.to_vec(),
+    );
+    // chain B may not have reached or may have pruned the consensus height
+    if let Ok(consensus_state) = ctx_b.ibc_store.host_consensus_state(&consensus_height) {
+        tree.insert(
+            consensus_state_path.clone(),
+            Any::from(consensus_state).to_vec(),
+        );
+        msg.proof_consensus_state_of_b_on_a = tree.prove_membership(&consensus_state_path).unwrap();
+    }
+
+    msg.proof_conn_end_on_a = tree.prove_membership(&conn_path).unwrap();
+    msg.proof_client_state_of_b_on_a = tree.prove_membership(&client_state_path).unwrap();
+
+    tree
+}
+
 fn conn_open_try_validate(fxt: &Fixture<MsgConnectionOpenTry>, expect: Expect) {
     let router = MockRouter::new_with_transfer();
     let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
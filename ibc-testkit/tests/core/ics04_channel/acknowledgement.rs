@@ -1,21 +1,27 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
-use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
-use ibc::core::channel::types::msgs::{MsgAcknowledgement, PacketMsg};
+use ibc::core::channel::types::commitment::{
+    compute_counterparty_ack_commitment, compute_packet_commitment, PacketCommitment,
+};
+use ibc::core::channel::types::error::PacketError;
+use ibc::core::channel::types::msgs::{MsgAcknowledgement, MsgTimeout, PacketMsg};
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
-use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentRoot};
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{AckPath, Path};
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_acknowledgement;
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::LightClientState;
 use rstest::*;
@@ -30,6 +36,7 @@ struct Fixture {
     conn_end_on_a: ConnectionEnd,
     chan_end_on_a_ordered: ChannelEnd,
     chan_end_on_a_unordered: ChannelEnd,
+    commitment_root: CommitmentRoot,
 }
 
 #[fixture]
@@ -37,14 +44,10 @@ fn fixture() -> Fixture {
     let default_client_id = ClientId::new("07-tendermint", 0).expect("no error");
 
     let client_height = Height::new(0, 2).unwrap();
-    let ctx = MockContext::default().with_light_client(
-        &ClientId::new("07-tendermint", 0).expect("no error"),
-        LightClientState::<MockHost>::with_latest_height(client_height),
-    );
 
     let router = MockRouter::new_with_transfer();
 
-    let msg = MsgAcknowledgement::try_from(dummy_raw_msg_acknowledgement(
+    let mut msg = MsgAcknowledgement::try_from(dummy_raw_msg_acknowledgement(
         client_height.revision_height(),
     ))
     .unwrap();
@@ -60,7 +63,10 @@ fn fixture() -> Fixture {
     let chan_end_on_a_unordered = ChannelEnd::new(
         State::Open,
         Order::Unordered,
-        Counterparty::new(packet.port_id_on_b, Some(packet.chan_id_on_b)),
+        Counterparty::new(
+            packet.port_id_on_b.clone(),
+            Some(packet.chan_id_on_b.clone()),
+        ),
         vec![ConnectionId::zero()],
         Version::new("ics20-1".to_string()),
     )
@@ -82,6 +88,26 @@ fn fixture() -> Fixture {
     )
     .unwrap();
 
+    // The counterparty has acknowledged the packet.
+    let ack_path = Path::Ack(AckPath::new(
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+        packet.seq_on_a,
+    ));
+    let mut tree = MockCommitmentTree::new(conn_end_on_a.counterparty().prefix().clone());
+    tree.insert(
+        ack_path.clone(),
+        compute_counterparty_ack_commitment(CommitmentHasher::host(), &msg.acknowledgement)
+            .into_vec(),
+    );
+    msg.proof_acked_on_b = tree.prove_membership(&ack_path).unwrap();
+
+    let ctx = MockContext::default().with_light_client(
+        &ClientId::new("07-tendermint", 0).expect("no error"),
+        LightClientState::<MockHost>::with_latest_height(client_height)
+            .with_commitment_root(tree.root()),
+    );
+
     Fixture {
         ctx,
         router,
@@ -91,6 +117,7 @@ fn fixture() -> Fixture {
         conn_end_on_a,
         chan_end_on_a_ordered,
         chan_end_on_a_unordered,
+        commitment_root: tree.root(),
     }
 }
 
@@ -110,9 +137,9 @@ fn ack_fail_no_channel(fixture: Fixture) {
     )
 }
 
-/// NO-OP case
+/// Acknowledging a packet that is not pending, e.g. acknowledged twice, fails
 #[rstest]
-fn ack_success_no_packet_commitment(fixture: Fixture) {
+fn ack_fail_no_packet_commitment(fixture: Fixture) {
     let Fixture {
         ctx,
         router,
@@ -120,12 +147,14 @@ fn ack_success_no_packet_commitment(fixture: Fixture) {
         conn_end_on_a,
         chan_end_on_a_unordered,
         client_height,
+        commitment_root,
         ..
     } = fixture;
     let ctx = ctx
         .with_light_client(
             &ClientId::new("07-tendermint", 0).expect("no error"),
-            LightClientState::<MockHost>::with_latest_height(client_height),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
         )
         .with_channel(
             PortId::transfer(),
@@ -139,8 +168,13 @@ fn ack_success_no_packet_commitment(fixture: Fixture) {
     let res = validate(&ctx.ibc_store, &router, msg_envelope);
 
     assert!(
-        res.is_ok(),
-        "Validation should succeed when no packet commitment is present"
+        matches!(
+            res,
+            Err(ContextError::PacketError(
+                PacketError::PacketNotPending { .. }
+            ))
+        ),
+        "Validation fails when no packet commitment is present"
     )
 }
 
@@ -154,12 +188,14 @@ fn ack_success_happy_path(fixture: Fixture) {
         conn_end_on_a,
         chan_end_on_a_unordered,
         client_height,
+        commitment_root,
         ..
     } = fixture;
     let ctx = ctx
         .with_light_client(
             &ClientId::new("07-tendermint", 0).expect("no error"),
-            LightClientState::<MockHost>::with_latest_height(client_height),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
         )
         .with_channel(
             PortId::transfer(),
@@ -225,6 +261,74 @@ fn ack_unordered_chan_execute(fixture: Fixture) {
     assert!(matches!(ibc_events[1], IbcEvent::AcknowledgePacket(_)));
 }
 
+/// Once acknowledged, a packet can neither be acknowledged again nor timed
+/// out, whatever the ordering of the channel.
+#[rstest]
+#[case::unordered(Order::Unordered)]
+#[case::ordered(Order::Ordered)]
+fn ack_twice_and_timeout_after_ack_fail(fixture: Fixture, #[case] order: Order) {
+    let Fixture {
+        ctx,
+        mut router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_ordered,
+        chan_end_on_a_unordered,
+        ..
+    } = fixture;
+
+    let chan_end_on_a = match order {
+        Order::Ordered => chan_end_on_a_ordered,
+        _ => chan_end_on_a_unordered,
+    };
+
+    let mut ctx = ctx
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let timeout_msg = MsgTimeout {
+        packet: msg.packet.clone(),
+        next_seq_recv_on_b: msg.packet.seq_on_a,
+        proof_unreceived_on_b: msg.proof_acked_on_b.clone(),
+        proof_height_on_b: msg.proof_height_on_b,
+        signer: msg.signer.clone(),
+    };
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    execute(&mut ctx.ibc_store, &mut router, msg_envelope.clone())
+        .expect("first acknowledgement succeeds");
+
+    let is_not_pending = |res: Result<(), ContextError>| {
+        matches!(
+            res,
+            Err(ContextError::PacketError(
+                PacketError::PacketNotPending { .. }
+            ))
+        )
+    };
+
+    assert!(is_not_pending(validate(
+        &ctx.ibc_store,
+        &router,
+        msg_envelope.clone()
+    )));
+    assert!(is_not_pending(
+        execute(&mut ctx.ibc_store, &mut router, msg_envelope).map(|_| ())
+    ));
+    assert!(is_not_pending(validate(
+        &ctx.ibc_store,
+        &router,
+        MsgEnvelope::from(PacketMsg::from(timeout_msg))
+    )));
+}
+
 #[rstest]
 fn ack_ordered_chan_execute(fixture: Fixture) {
     let Fixture {
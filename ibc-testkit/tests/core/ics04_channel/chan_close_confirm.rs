@@ -1,6 +1,8 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::commitment::compute_channel_commitment;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelCloseConfirm};
 use ibc::core::channel::types::Version;
+use ibc::core::commitment_types::commitment::CommitmentRoot;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
@@ -9,6 +11,7 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::path::{ChannelEndPath, Path};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
@@ -16,9 +19,38 @@ use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_close_confirm;
 use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::LightClientState;
 
+/// Commits chain A to its closed end of the channel, and proves it in the
+/// message.
+fn commit_on_a(msg: &mut MsgChannelCloseConfirm, conn_end_on_b: &ConnectionEnd) -> CommitmentRoot {
+    let chan_end_on_a = ChannelEnd::new(
+        ChannelState::Closed,
+        Order::Unordered,
+        Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+        vec![conn_end_on_b
+            .counterparty()
+            .connection_id()
+            .unwrap()
+            .clone()],
+        Version::empty(),
+    )
+    .unwrap();
+    let chan_end_path_on_a =
+        Path::ChannelEnd(ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b));
+
+    let mut tree = MockCommitmentTree::new(conn_end_on_b.counterparty().prefix().clone());
+    tree.insert(
+        chan_end_path_on_a.clone(),
+        compute_channel_commitment(&chan_end_on_a),
+    );
+    msg.proof_chan_end_on_a = tree.prove_membership(&chan_end_path_on_a).unwrap();
+
+    tree.root()
+}
+
 #[test]
 fn test_chan_close_confirm_validate() {
     let client_id = mock_client_type().build_client_id(24);
@@ -35,10 +67,11 @@ fn test_chan_close_confirm_validate() {
     )
     .unwrap();
 
-    let msg_chan_close_confirm = MsgChannelCloseConfirm::try_from(
+    let mut msg_chan_close_confirm = MsgChannelCloseConfirm::try_from(
         dummy_raw_msg_chan_close_confirm(client_consensus_state_height.revision_height()),
     )
     .unwrap();
+    let commitment_root = commit_on_a(&mut msg_chan_close_confirm, &conn_end);
 
     let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_confirm.clone()));
 
@@ -57,7 +90,8 @@ fn test_chan_close_confirm_validate() {
     let context = default_context
         .with_light_client(
             &client_id,
-            LightClientState::<MockHost>::with_latest_height(client_consensus_state_height),
+            LightClientState::<MockHost>::with_latest_height(client_consensus_state_height)
+                .with_commitment_root(commitment_root),
         )
         .with_connection(conn_id, conn_end)
         .with_channel(
@@ -92,10 +126,11 @@ fn test_chan_close_confirm_execute() {
     )
     .unwrap();
 
-    let msg_chan_close_confirm = MsgChannelCloseConfirm::try_from(
+    let mut msg_chan_close_confirm = MsgChannelCloseConfirm::try_from(
         dummy_raw_msg_chan_close_confirm(client_consensus_state_height.revision_height()),
     )
     .unwrap();
+    let commitment_root = commit_on_a(&mut msg_chan_close_confirm, &conn_end);
 
     let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_confirm.clone()));
 
@@ -114,7 +149,8 @@ fn test_chan_close_confirm_execute() {
     let mut context = default_context
         .with_light_client(
             &client_id,
-            LightClientState::<MockHost>::with_latest_height(client_consensus_state_height),
+            LightClientState::<MockHost>::with_latest_height(client_consensus_state_height)
+                .with_commitment_root(commitment_root),
         )
         .with_connection(conn_id, conn_end)
         .with_channel(
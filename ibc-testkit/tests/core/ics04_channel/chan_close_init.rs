@@ -1,20 +1,28 @@
+use ibc::apps::transfer::types::MODULE_ID_STR;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelCloseInit};
 use ibc::core::channel::types::Version;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
-use ibc::core::entrypoint::{execute, validate};
+use ibc::core::entrypoint::{dispatch, execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::ChannelEndPath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
+use ibc::core::router::middleware::{Middleware, MiddlewareStack};
+use ibc::core::router::module::Module;
+use ibc::core::router::types::module::ModuleId;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_close_init;
 use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::LightClientState;
@@ -142,3 +150,106 @@ fn test_chan_close_init_execute() {
 
     assert!(matches!(ibc_events[1], IbcEvent::CloseInitChannel(_)));
 }
+
+#[test]
+fn test_chan_close_init_rejected_by_policy() {
+    /// Forbids closing any channel of the wrapped module.
+    #[derive(Debug)]
+    struct NoCloseMiddleware;
+
+    impl Middleware for NoCloseMiddleware {
+        fn chan_close_init_allowed(
+            &self,
+            _next: &dyn Module,
+            _port_id: &PortId,
+            _channel_id: &ChannelId,
+            _signer: &Signer,
+        ) -> bool {
+            false
+        }
+    }
+
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg_chan_close_init =
+        MsgChannelCloseInit::try_from(dummy_raw_msg_chan_close_init()).unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_init.clone()));
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(
+            msg_chan_close_init.port_id_on_a.clone(),
+            Some(msg_chan_close_init.chan_id_on_a.clone()),
+        ),
+        vec![conn_id.clone()],
+        Version::empty(),
+    )
+    .unwrap();
+
+    let mut context = {
+        let default_context = MockContext::default();
+        let client_consensus_state_height = default_context.ibc_store.host_height().unwrap();
+
+        default_context
+            .with_light_client(
+                &client_id,
+                LightClientState::<MockHost>::with_latest_height(client_consensus_state_height),
+            )
+            .with_connection(conn_id, conn_end)
+            .with_channel(
+                msg_chan_close_init.port_id_on_a.clone(),
+                msg_chan_close_init.chan_id_on_a.clone(),
+                chan_end,
+            )
+    };
+
+    let mut router = MockRouter::default();
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    router.scope_port_to_module(PortId::transfer(), module_id.clone());
+    router
+        .add_route(
+            module_id,
+            MiddlewareStack::new(NoCloseMiddleware, DummyTransferModule::new()),
+        )
+        .unwrap();
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::ChannelCloseNotAllowed { .. }
+            ))
+        ),
+        "Validation expected to fail. Result: {res:?}"
+    );
+
+    let res = dispatch(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_err(), "Dispatch expected to fail");
+    assert!(context.get_events().is_empty());
+
+    // The channel is left open.
+    let chan_end_path = ChannelEndPath::new(
+        &msg_chan_close_init.port_id_on_a,
+        &msg_chan_close_init.chan_id_on_a,
+    );
+    assert!(context
+        .ibc_store
+        .channel_end(&chan_end_path)
+        .unwrap()
+        .is_open());
+}
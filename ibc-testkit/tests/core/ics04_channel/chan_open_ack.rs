@@ -1,7 +1,9 @@
 use ibc::apps::transfer::types::MODULE_ID_STR;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_channel_commitment;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenAck};
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentRoot;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
@@ -10,6 +12,7 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::{ChannelEndPath, Path};
 use ibc::core::primitives::*;
 use ibc::core::router::types::module::ModuleId;
 use ibc_testkit::context::MockContext;
@@ -17,6 +20,7 @@ use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_ack;
 use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::LightClientState;
 use rstest::*;
@@ -32,6 +36,7 @@ pub struct Fixture {
     pub conn_end_on_a: ConnectionEnd,
     pub chan_end_on_a: ChannelEnd,
     pub proof_height: u64,
+    pub commitment_root: CommitmentRoot,
 }
 
 #[fixture]
@@ -53,7 +58,7 @@ fn fixture() -> Fixture {
     )
     .unwrap();
 
-    let msg = MsgChannelOpenAck::try_from(dummy_raw_msg_chan_open_ack(proof_height)).unwrap();
+    let mut msg = MsgChannelOpenAck::try_from(dummy_raw_msg_chan_open_ack(proof_height)).unwrap();
 
     let chan_end_on_a = ChannelEnd::new(
         State::Init,
@@ -64,6 +69,31 @@ fn fixture() -> Fixture {
     )
     .unwrap();
 
+    // Chain B has answered with its end of the channel.
+    let chan_end_on_b = ChannelEnd::new(
+        State::TryOpen,
+        Order::Unordered,
+        Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+        vec![conn_end_on_a
+            .counterparty()
+            .connection_id()
+            .unwrap()
+            .clone()],
+        msg.version_on_b.clone(),
+    )
+    .unwrap();
+    let chan_end_path_on_b = Path::ChannelEnd(ChannelEndPath::new(
+        &chan_end_on_a.counterparty().port_id,
+        &msg.chan_id_on_b,
+    ));
+
+    let mut tree = MockCommitmentTree::new(conn_end_on_a.counterparty().prefix().clone());
+    tree.insert(
+        chan_end_path_on_b.clone(),
+        compute_channel_commitment(&chan_end_on_b),
+    );
+    msg.proof_chan_end_on_b = tree.prove_membership(&chan_end_path_on_b).unwrap();
+
     Fixture {
         context,
         router,
@@ -74,6 +104,7 @@ fn fixture() -> Fixture {
         conn_end_on_a,
         chan_end_on_a,
         proof_height,
+        commitment_root: tree.root(),
     }
 }
 
@@ -88,13 +119,15 @@ fn chan_open_ack_happy_path(fixture: Fixture) {
         conn_end_on_a,
         chan_end_on_a,
         proof_height,
+        commitment_root,
         ..
     } = fixture;
 
     let context = context
         .with_light_client(
             &client_id_on_a,
-            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap())
+                .with_commitment_root(commitment_root),
         )
         .with_connection(conn_id_on_a, conn_end_on_a)
         .with_channel(
@@ -121,13 +154,15 @@ fn chan_open_ack_execute_happy_path(fixture: Fixture) {
         conn_end_on_a,
         chan_end_on_a,
         proof_height,
+        commitment_root,
         ..
     } = fixture;
 
     let mut context = context
         .with_light_client(
             &client_id_on_a,
-            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap())
+                .with_commitment_root(commitment_root),
         )
         .with_connection(conn_id_on_a, conn_end_on_a)
         .with_channel(
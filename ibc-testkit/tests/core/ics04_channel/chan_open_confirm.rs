@@ -1,7 +1,9 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_channel_commitment;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenConfirm};
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentRoot;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
@@ -10,12 +12,14 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId};
+use ibc::core::host::types::path::{ChannelEndPath, Path};
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_confirm;
 use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::LightClientState;
 use rstest::*;
@@ -30,6 +34,7 @@ pub struct Fixture {
     pub conn_end_on_b: ConnectionEnd,
     pub chan_end_on_b: ChannelEnd,
     pub proof_height: u64,
+    pub commitment_root: CommitmentRoot,
 }
 
 #[fixture]
@@ -50,7 +55,7 @@ fn fixture() -> Fixture {
     )
     .unwrap();
 
-    let msg =
+    let mut msg =
         MsgChannelOpenConfirm::try_from(dummy_raw_msg_chan_open_confirm(proof_height)).unwrap();
 
     let chan_end_on_b = ChannelEnd::new(
@@ -62,6 +67,31 @@ fn fixture() -> Fixture {
     )
     .unwrap();
 
+    // Chain A has opened its end of the channel.
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+        vec![conn_end_on_b
+            .counterparty()
+            .connection_id()
+            .unwrap()
+            .clone()],
+        Version::empty(),
+    )
+    .unwrap();
+    let chan_end_path_on_a = Path::ChannelEnd(ChannelEndPath::new(
+        &chan_end_on_b.counterparty().port_id,
+        &ChannelId::zero(),
+    ));
+
+    let mut tree = MockCommitmentTree::new(conn_end_on_b.counterparty().prefix().clone());
+    tree.insert(
+        chan_end_path_on_a.clone(),
+        compute_channel_commitment(&chan_end_on_a),
+    );
+    msg.proof_chan_end_on_a = tree.prove_membership(&chan_end_path_on_a).unwrap();
+
     Fixture {
         context,
         router,
@@ -71,6 +101,7 @@ fn fixture() -> Fixture {
         conn_end_on_b,
         chan_end_on_b,
         proof_height,
+        commitment_root: tree.root(),
     }
 }
 
@@ -85,13 +116,15 @@ fn chan_open_confirm_validate_happy_path(fixture: Fixture) {
         conn_end_on_b,
         chan_end_on_b,
         proof_height,
+        commitment_root,
         ..
     } = fixture;
 
     let context = context
         .with_light_client(
             &client_id_on_b,
-            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap())
+                .with_commitment_root(commitment_root),
         )
         .with_connection(conn_id_on_b, conn_end_on_b)
         .with_channel(msg.port_id_on_b.clone(), ChannelId::zero(), chan_end_on_b);
@@ -114,13 +147,15 @@ fn chan_open_confirm_execute_happy_path(fixture: Fixture) {
         conn_end_on_b,
         chan_end_on_b,
         proof_height,
+        commitment_root,
         ..
     } = fixture;
 
     let mut context = context
         .with_light_client(
             &client_id_on_b,
-            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap())
+                .with_commitment_root(commitment_root),
         )
         .with_connection(conn_id_on_b, conn_end_on_b)
         .with_channel(msg.port_id_on_b.clone(), ChannelId::zero(), chan_end_on_b);
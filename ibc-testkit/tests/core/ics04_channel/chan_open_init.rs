@@ -6,7 +6,8 @@ use ibc::core::connection::types::{ConnectionEnd, State as ConnectionState};
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::handler::types::output::HandlerResult;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, ValidationProfile};
 use ibc::core::host::ValidationContext;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_init;
@@ -95,7 +96,10 @@ fn chan_open_init_execute_happy_path(fixture: Fixture) {
 
     let res = execute(&mut ctx.ibc_store, &mut router, msg);
 
-    assert!(res.is_ok(), "Execution succeeds; good parameters");
+    assert_eq!(
+        res.expect("Execution succeeds; good parameters").result,
+        Some(HandlerResult::ChannelCreated(ChannelId::new(0)))
+    );
 
     assert_eq!(ctx.ibc_store.channel_counter().unwrap(), 1);
 
@@ -121,3 +125,26 @@ fn chan_open_init_fail_no_connection(fixture: Fixture) {
         "Validation fails because no connection exists in the context"
     )
 }
+
+#[rstest]
+#[case::channel_id_too_long(ValidationProfile::ICS24.with_channel_id_length(8, 8))]
+#[case::port_id_too_long(ValidationProfile::ICS24.with_port_id_length(2, 4))]
+fn chan_open_init_fail_identifier_validation_profile(
+    fixture: Fixture,
+    #[case] profile: ValidationProfile,
+) {
+    let Fixture {
+        mut ctx,
+        router,
+        msg,
+    } = fixture;
+
+    ctx.ibc_store.identifier_validation_profile = profile;
+
+    let res = validate(&ctx.ibc_store, &router, msg);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the host doesn't accept the `transfer` port or `channel-0`"
+    )
+}
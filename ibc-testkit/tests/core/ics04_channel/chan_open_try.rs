@@ -1,5 +1,8 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, State};
+use ibc::core::channel::types::commitment::compute_channel_commitment;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenTry};
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentRoot;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
@@ -8,6 +11,7 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::{ChannelEndPath, Path};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
@@ -15,6 +19,7 @@ use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_try;
 use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::LightClientState;
 use rstest::*;
@@ -28,6 +33,7 @@ pub struct Fixture {
     pub conn_id_on_b: ConnectionId,
     pub conn_end_on_b: ConnectionEnd,
     pub proof_height: u64,
+    pub commitment_root: CommitmentRoot,
 }
 
 #[fixture]
@@ -54,6 +60,31 @@ fn fixture() -> Fixture {
     let hops = vec![conn_id_on_b.clone()];
     msg_chan_open_try.connection_hops_on_b = hops;
 
+    // Chain A has initialized its end of the channel.
+    let chan_end_on_a = ChannelEnd::new(
+        State::Init,
+        msg_chan_open_try.ordering,
+        Counterparty::new(msg_chan_open_try.port_id_on_b.clone(), None),
+        vec![conn_end_on_b
+            .counterparty()
+            .connection_id()
+            .unwrap()
+            .clone()],
+        msg_chan_open_try.version_supported_on_a.clone(),
+    )
+    .unwrap();
+    let chan_end_path_on_a = Path::ChannelEnd(ChannelEndPath::new(
+        &msg_chan_open_try.port_id_on_a,
+        &msg_chan_open_try.chan_id_on_a,
+    ));
+
+    let mut tree = MockCommitmentTree::new(conn_end_on_b.counterparty().prefix().clone());
+    tree.insert(
+        chan_end_path_on_a.clone(),
+        compute_channel_commitment(&chan_end_on_a),
+    );
+    msg_chan_open_try.proof_chan_end_on_a = tree.prove_membership(&chan_end_path_on_a).unwrap();
+
     let msg = MsgEnvelope::from(ChannelMsg::from(msg_chan_open_try));
 
     let ctx = MockContext::default();
@@ -68,6 +99,7 @@ fn fixture() -> Fixture {
         conn_id_on_b,
         conn_end_on_b,
         proof_height,
+        commitment_root: tree.root(),
     }
 }
 
@@ -81,13 +113,15 @@ fn chan_open_try_validate_happy_path(fixture: Fixture) {
         conn_id_on_b,
         conn_end_on_b,
         proof_height,
+        commitment_root,
         ..
     } = fixture;
 
     let ctx = ctx
         .with_light_client(
             &client_id_on_b,
-            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap())
+                .with_commitment_root(commitment_root),
         )
         .with_connection(conn_id_on_b, conn_end_on_b);
 
@@ -106,13 +140,15 @@ fn chan_open_try_execute_happy_path(fixture: Fixture) {
         conn_id_on_b,
         conn_end_on_b,
         proof_height,
+        commitment_root,
         ..
     } = fixture;
 
     let mut ctx = ctx
         .with_light_client(
             &client_id_on_b,
-            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap())
+                .with_commitment_root(commitment_root),
         )
         .with_connection(conn_id_on_b, conn_end_on_b);
 
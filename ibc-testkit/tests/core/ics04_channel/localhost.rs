@@ -0,0 +1,149 @@
+use ibc::core::channel::types::channel::State;
+use ibc::core::channel::types::msgs::{
+    ChannelMsg, MsgChannelOpenAck, MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry,
+};
+use ibc::core::client::types::Height;
+use ibc::core::entrypoint::dispatch;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::ChannelEndPath;
+use ibc::core::host::ValidationContext;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_chan_open_ack, dummy_raw_msg_chan_open_confirm, dummy_raw_msg_chan_open_init,
+    dummy_raw_msg_chan_open_try,
+};
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use test_log::test;
+
+fn msg_chan_open_init() -> MsgChannelOpenInit {
+    let mut msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+    msg.connection_hops_on_a = vec![ConnectionId::localhost()];
+    msg.port_id_on_b = PortId::transfer();
+    msg
+}
+
+fn msg_chan_open_try(chan_id_on_a: ChannelId, proof_height: Height) -> MsgChannelOpenTry {
+    let mut msg = MsgChannelOpenTry::try_from(dummy_raw_msg_chan_open_try(1)).unwrap();
+    msg.connection_hops_on_b = vec![ConnectionId::localhost()];
+    msg.port_id_on_a = PortId::transfer();
+    msg.chan_id_on_a = chan_id_on_a;
+    msg.proof_height_on_a = proof_height;
+    msg
+}
+
+fn channel_state(ctx: &MockContext, chan_id: &ChannelId) -> State {
+    *ctx.ibc_store
+        .channel_end(&ChannelEndPath::new(&PortId::transfer(), chan_id))
+        .unwrap()
+        .state()
+}
+
+/// Opens a channel from the host to itself over the `connection-localhost`
+/// sentinel, which the host never stored, the proofs of each step being
+/// verified against the channel ends of the host.
+#[test]
+fn chan_open_handshake_over_localhost() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let chan_id_on_a = ChannelId::new(0);
+    let chan_id_on_b = ChannelId::new(1);
+
+    dispatch(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::from(ChannelMsg::from(msg_chan_open_init())),
+    )
+    .unwrap();
+    assert_eq!(channel_state(&ctx, &chan_id_on_a), State::Init);
+
+    let proof_height = ctx.ibc_store.host_height().unwrap();
+
+    dispatch(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::from(ChannelMsg::from(msg_chan_open_try(
+            chan_id_on_a.clone(),
+            proof_height,
+        ))),
+    )
+    .unwrap();
+    assert_eq!(channel_state(&ctx, &chan_id_on_b), State::TryOpen);
+
+    let mut msg_ack = MsgChannelOpenAck::try_from(dummy_raw_msg_chan_open_ack(1)).unwrap();
+    msg_ack.chan_id_on_a = chan_id_on_a.clone();
+    msg_ack.chan_id_on_b = chan_id_on_b.clone();
+    msg_ack.proof_height_on_b = proof_height;
+
+    dispatch(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::from(ChannelMsg::from(msg_ack)),
+    )
+    .unwrap();
+    assert_eq!(channel_state(&ctx, &chan_id_on_a), State::Open);
+
+    let mut msg_confirm =
+        MsgChannelOpenConfirm::try_from(dummy_raw_msg_chan_open_confirm(1)).unwrap();
+    msg_confirm.chan_id_on_b = chan_id_on_b.clone();
+    msg_confirm.proof_height_on_a = proof_height;
+
+    dispatch(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::from(ChannelMsg::from(msg_confirm)),
+    )
+    .unwrap();
+    assert_eq!(channel_state(&ctx, &chan_id_on_b), State::Open);
+
+    assert!(ValidationContext::connection_end(&ctx.ibc_store, &ConnectionId::localhost()).is_err());
+}
+
+#[test]
+fn chan_open_try_over_localhost_rejects_missing_counterparty_channel() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let proof_height = ctx.ibc_store.host_height().unwrap();
+
+    let res = dispatch(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::from(ChannelMsg::from(msg_chan_open_try(
+            ChannelId::new(0),
+            proof_height,
+        ))),
+    );
+
+    assert!(res.is_err(), "no channel end to verify against on the host");
+}
+
+#[test]
+fn chan_open_try_over_localhost_rejects_future_proof_height() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    dispatch(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::from(ChannelMsg::from(msg_chan_open_init())),
+    )
+    .unwrap();
+
+    let proof_height = ctx.ibc_store.host_height().unwrap().increment();
+
+    let res = dispatch(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::from(ChannelMsg::from(msg_chan_open_try(
+            ChannelId::new(0),
+            proof_height,
+        ))),
+    );
+
+    assert!(
+        res.is_err(),
+        "the localhost client is not past the host height"
+    );
+}
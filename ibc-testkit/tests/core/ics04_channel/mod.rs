@@ -5,7 +5,9 @@ pub mod chan_open_ack;
 pub mod chan_open_confirm;
 pub mod chan_open_init;
 pub mod chan_open_try;
+pub mod localhost;
 pub mod recv_packet;
+pub mod register_payee;
 pub mod send_packet;
 pub mod timeout;
 pub mod timeout_on_close;
@@ -1,24 +1,49 @@
+use ibc::apps::transfer::types::MODULE_ID_STR;
+use ibc::core::channel::context::RelayerPayeeExecutionContext;
+use ibc::core::channel::handler::write_acknowledgement;
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, RecvPacketResult, StatusValue,
+};
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::{
+    compute_ack_commitment, compute_counterparty_packet_commitment,
+};
+use ibc::core::channel::types::error::PacketError;
+use ibc::core::channel::types::limits::PacketSizeLimits;
 use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
-use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentRoot};
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
-use ibc::core::entrypoint::{execute, validate};
+use ibc::core::entrypoint::{self, execute, validate};
+use ibc::core::handler::types::error::{ContextError, HostModeError, ResourceError};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::log::HandlerLog;
+use ibc::core::handler::types::mode::HostMode;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{
+    AckPath, CommitmentPath, CounterpartyPayeePath, Path, PayeePath,
+};
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
+use ibc::core::router::middleware::{Middleware, MiddlewareStack};
+use ibc::core::router::module::Module;
+use ibc::core::router::port::bind_port;
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::{dummy_msg_recv_packet, dummy_raw_msg_recv_packet};
+use ibc_testkit::fixtures::core::commitment::dummy_commitment_proof_bytes;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::testapp::ibc::core::types::{LightClientState, MockResourceMeter};
 use rstest::*;
 use test_log::test;
 
@@ -31,6 +56,7 @@ pub struct Fixture {
     pub conn_end_on_b: ConnectionEnd,
     pub chan_end_on_b: ChannelEnd,
     pub client_id: ClientId,
+    pub commitment_root: CommitmentRoot,
 }
 
 #[fixture]
@@ -45,15 +71,19 @@ fn fixture() -> Fixture {
 
     let client_height = host_height.increment();
 
-    let msg = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(client_height.revision_height()))
-        .unwrap();
+    let mut msg =
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(client_height.revision_height()))
+            .unwrap();
 
     let packet = msg.packet.clone();
 
     let chan_end_on_b = ChannelEnd::new(
         State::Open,
         Order::Unordered,
-        Counterparty::new(packet.port_id_on_a, Some(packet.chan_id_on_a)),
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
         vec![ConnectionId::zero()],
         Version::new("ics20-1".to_string()),
     )
@@ -72,6 +102,25 @@ fn fixture() -> Fixture {
     )
     .unwrap();
 
+    // The counterparty has committed to the packet.
+    let commitment_path = Path::Commitment(CommitmentPath::new(
+        &packet.port_id_on_a,
+        &packet.chan_id_on_a,
+        packet.seq_on_a,
+    ));
+    let mut tree = MockCommitmentTree::new(conn_end_on_b.counterparty().prefix().clone());
+    tree.insert(
+        commitment_path.clone(),
+        compute_counterparty_packet_commitment(
+            CommitmentHasher::host(),
+            &packet.data,
+            &packet.timeout_height_on_b,
+            &packet.timeout_timestamp_on_b,
+        )
+        .into_vec(),
+    );
+    msg.proof_commitment_on_a = tree.prove_membership(&commitment_path).unwrap();
+
     Fixture {
         context,
         router,
@@ -81,6 +130,7 @@ fn fixture() -> Fixture {
         conn_end_on_b,
         chan_end_on_b,
         client_id,
+        commitment_root: tree.root(),
     }
 }
 
@@ -103,6 +153,64 @@ fn recv_packet_fail_no_channel(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn recv_packet_fail_send_only_host_mode(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        router,
+        msg,
+        ..
+    } = fixture;
+
+    context.ibc_store.host_mode = HostMode::SendOnly;
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::HostModeError(
+                HostModeError::MessageNotAllowed {
+                    mode: HostMode::SendOnly,
+                    ..
+                }
+            ))
+        ),
+        "Validation fails because send-only hosts do not receive packets"
+    )
+}
+
+#[rstest]
+fn recv_packet_fail_packet_data_too_long(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        router,
+        msg,
+        ..
+    } = fixture;
+
+    context.ibc_store.packet_size_limits = PacketSizeLimits {
+        max_data_length: msg.packet.data.len() - 1,
+        ..Default::default()
+    };
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(
+                PacketError::PacketDataTooLong { .. }
+            ))
+        ),
+        "Validation fails because the packet data exceeds the host limit"
+    )
+}
+
 #[rstest]
 fn recv_packet_validate_happy_path(fixture: Fixture) {
     let Fixture {
@@ -113,6 +221,7 @@ fn recv_packet_validate_happy_path(fixture: Fixture) {
         chan_end_on_b,
         client_height,
         host_height,
+        commitment_root,
         ..
     } = fixture;
 
@@ -120,7 +229,8 @@ fn recv_packet_validate_happy_path(fixture: Fixture) {
     let context = context
         .with_light_client(
             &ClientId::new("07-tendermint", 0).expect("no error"),
-            LightClientState::<MockHost>::with_latest_height(client_height),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
         )
         .with_connection(ConnectionId::zero(), conn_end_on_b)
         .with_channel(
@@ -151,6 +261,59 @@ fn recv_packet_validate_happy_path(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn recv_packet_validate_charges_resource_meter(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        commitment_root,
+        ..
+    } = fixture;
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .advance_block_up_to_height(host_height);
+
+    let expected_charge = packet.data.len() + msg.proof_commitment_on_a.as_bytes().len();
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "validation should succeed. err: {res:?}");
+    assert_eq!(
+        context.ibc_store.resource_meter.consumed(),
+        expected_charge as u64
+    );
+
+    context.ibc_store.resource_meter = MockResourceMeter::with_limit(expected_charge as u64 - 1);
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ResourceError(ResourceError::OutOfGas { .. }))
+        ),
+        "validation aborts once the meter runs out of gas. res: {res:?}"
+    );
+}
+
 #[rstest]
 fn recv_packet_timeout_expired(fixture: Fixture) {
     let Fixture {
@@ -161,6 +324,7 @@ fn recv_packet_timeout_expired(fixture: Fixture) {
         chan_end_on_b,
         client_height,
         host_height,
+        commitment_root,
         ..
     } = fixture;
 
@@ -187,7 +351,8 @@ fn recv_packet_timeout_expired(fixture: Fixture) {
     let context = context
         .with_light_client(
             &ClientId::new("07-tendermint", 0).expect("no error"),
-            LightClientState::<MockHost>::with_latest_height(client_height),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
         )
         .with_connection(ConnectionId::zero(), conn_end_on_b)
         .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
@@ -202,6 +367,77 @@ fn recv_packet_timeout_expired(fixture: Fixture) {
     )
 }
 
+/// Receiving the same packet twice is a no-op leaving the stored
+/// acknowledgement untouched, whatever the ordering of the channel.
+#[rstest]
+#[case::unordered(Order::Unordered)]
+#[case::ordered(Order::Ordered)]
+fn recv_packet_twice_is_no_op(fixture: Fixture, #[case] order: Order) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        mut chan_end_on_b,
+        client_height,
+        host_height,
+        commitment_root,
+        ..
+    } = fixture;
+
+    chan_end_on_b.ordering = order;
+
+    let packet = msg.packet.clone();
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_send_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            1.into(),
+        )
+        .advance_block_up_to_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    validate(&ctx.ibc_store, &router, msg_envelope.clone()).expect("first receive is valid");
+    execute(&mut ctx.ibc_store, &mut router, msg_envelope.clone()).expect("first receive succeeds");
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    let stored_ack = ctx
+        .ibc_store
+        .get_packet_acknowledgement(&ack_path_on_b)
+        .expect("the acknowledgement is written");
+    let event_count = ctx.get_events().len();
+
+    validate(&ctx.ibc_store, &router, msg_envelope.clone())
+        .expect("receiving the packet again is a valid no-op");
+    execute(&mut ctx.ibc_store, &mut router, msg_envelope)
+        .expect("receiving the packet again is a no-op");
+
+    assert_eq!(
+        ctx.ibc_store
+            .get_packet_acknowledgement(&ack_path_on_b)
+            .expect("the acknowledgement is kept"),
+        stored_ack
+    );
+    assert_eq!(ctx.get_events().len(), event_count);
+}
+
 #[rstest]
 fn recv_packet_execute_happy_path(fixture: Fixture) {
     let Fixture {
@@ -211,22 +447,39 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
         conn_end_on_b,
         chan_end_on_b,
         client_height,
+        commitment_root,
         ..
     } = fixture;
     let mut ctx = context
         .with_light_client(
             &ClientId::new("07-tendermint", 0).expect("no error"),
-            LightClientState::<MockHost>::with_latest_height(client_height),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
         )
         .with_connection(ConnectionId::zero(), conn_end_on_b)
         .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
 
+    let packet = msg.packet.clone();
+
     let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
 
     let res = execute(&mut ctx.ibc_store, &mut router, msg_env);
 
     assert!(res.is_ok());
 
+    let logs = ctx.get_logs();
+
+    assert!(logs.contains(&HandlerLog::PacketReceived {
+        port_id_on_b: packet.port_id_on_b.clone(),
+        chan_id_on_b: packet.chan_id_on_b.clone(),
+        sequence: packet.seq_on_a,
+    }));
+    assert!(logs.contains(&HandlerLog::AcknowledgementWritten {
+        port_id_on_b: packet.port_id_on_b,
+        chan_id_on_b: packet.chan_id_on_b,
+        sequence: packet.seq_on_a,
+    }));
+
     let ibc_events = ctx.get_events();
 
     assert_eq!(ibc_events.len(), 4);
@@ -234,10 +487,391 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
         &ibc_events[0],
         &IbcEvent::Message(MessageEvent::Channel)
     ));
-    assert!(matches!(&ibc_events[1], &IbcEvent::ReceivePacket(_)));
+    assert!(matches!(
+        &ibc_events[1],
+        IbcEvent::ReceivePacket(event) if event.relayer().is_none()
+    ));
     assert!(matches!(
         &ibc_events[2],
         &IbcEvent::Message(MessageEvent::Channel)
     ));
-    assert!(matches!(&ibc_events[3], &IbcEvent::WriteAcknowledgement(_)));
+    assert!(matches!(
+        &ibc_events[3],
+        IbcEvent::WriteAcknowledgement(event) if event.relayer().is_none()
+    ));
+}
+
+#[rstest]
+fn recv_packet_execute_attributes_registered_relayer(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        commitment_root,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    let relayer = msg.signer.clone();
+    let chan_id_on_b = msg.packet.chan_id_on_b.clone();
+    let payee = Signer::from("payee".to_string());
+    let counterparty_payee = Signer::from("counterparty_payee".to_string());
+
+    ctx.ibc_store
+        .store_payee(&PayeePath::new(&relayer, &chan_id_on_b), payee.clone())
+        .unwrap();
+    ctx.ibc_store
+        .store_counterparty_payee(
+            &CounterpartyPayeePath::new(&relayer, &chan_id_on_b),
+            counterparty_payee.clone(),
+        )
+        .unwrap();
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    execute(&mut ctx.ibc_store, &mut router, msg_env).expect("packet is received");
+
+    let ibc_events = ctx.get_events();
+
+    let [_, IbcEvent::ReceivePacket(recv_event), _, IbcEvent::WriteAcknowledgement(ack_event)] =
+        ibc_events.as_slice()
+    else {
+        panic!("unexpected events: {ibc_events:?}");
+    };
+
+    for (event_relayer, event_fee_recipient, event_counterparty_payee) in [
+        (
+            recv_event.relayer(),
+            recv_event.fee_recipient(),
+            recv_event.counterparty_payee(),
+        ),
+        (
+            ack_event.relayer(),
+            ack_event.fee_recipient(),
+            ack_event.counterparty_payee(),
+        ),
+    ] {
+        assert_eq!(event_relayer, Some(&relayer));
+        assert_eq!(event_fee_recipient, Some(&payee));
+        assert_eq!(event_counterparty_payee, Some(&counterparty_payee));
+    }
+}
+
+#[rstest]
+fn recv_packet_execute_replaces_acknowledgement_too_long(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        commitment_root,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    // The dummy transfer module acknowledges with a single byte.
+    ctx.ibc_store.packet_size_limits = PacketSizeLimits {
+        max_ack_length: 0,
+        ..Default::default()
+    };
+
+    let packet = msg.packet.clone();
+
+    let res = execute(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::from(PacketMsg::from(msg)),
+    );
+
+    assert!(res.is_ok(), "the packet is still received. err: {res:?}");
+
+    let expected_ack: Acknowledgement = AcknowledgementStatus::error(
+        StatusValue::new(PacketError::AcknowledgementTooLong {
+            length: 1,
+            max_length: 0,
+        })
+        .unwrap(),
+    )
+    .into();
+
+    assert!(ctx.get_events().iter().any(|event| matches!(
+        event,
+        IbcEvent::WriteAcknowledgement(e) if e.acknowledgement() == &expected_ack
+    )));
+    assert_eq!(
+        ctx.ibc_store
+            .get_packet_acknowledgement(&AckPath::new(
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                packet.seq_on_a
+            ))
+            .unwrap(),
+        compute_ack_commitment(&expected_ack)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[rstest]
+fn recv_packet_execute_rejects_memo_too_long(fixture: Fixture) {
+    use std::sync::Arc;
+
+    use ibc::apps::transfer::codec::TransferPacketDataCodec;
+
+    let Fixture {
+        context,
+        mut router,
+        mut msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        commitment_root,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    ctx.ibc_store
+        .packet_data_codecs
+        .insert(PortId::transfer(), Arc::new(TransferPacketDataCodec));
+    ctx.ibc_store.packet_size_limits = PacketSizeLimits {
+        max_memo_length: 4,
+        ..Default::default()
+    };
+
+    msg.packet.data =
+        br#"{"denom":"uatom","amount":"10","sender":"alice","receiver":"bob","memo":"hello"}"#
+            .to_vec();
+
+    let packet = msg.packet.clone();
+
+    let res = execute(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::from(PacketMsg::from(msg)),
+    );
+
+    assert!(res.is_ok(), "the packet is still received. err: {res:?}");
+
+    let expected_ack: Acknowledgement = AcknowledgementStatus::error(
+        StatusValue::new(PacketError::MemoTooLong {
+            length: 5,
+            max_length: 4,
+        })
+        .unwrap(),
+    )
+    .into();
+
+    assert_eq!(
+        ctx.ibc_store
+            .get_packet_acknowledgement(&AckPath::new(
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                packet.seq_on_a
+            ))
+            .unwrap(),
+        compute_ack_commitment(&expected_ack)
+    );
+}
+
+#[rstest]
+fn write_acknowledgement_requires_unacknowledged_received_packet(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        commitment_root,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    let packet = msg.packet.clone();
+    let ack = Acknowledgement::try_from(vec![1u8]).expect("no error");
+
+    // The packet was not received yet.
+    let res = write_acknowledgement(&mut ctx.ibc_store, packet.clone(), ack.clone());
+    assert!(matches!(
+        res,
+        Err(ContextError::PacketError(
+            PacketError::PacketReceiptNotFound { .. }
+        ))
+    ));
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+    execute(&mut ctx.ibc_store, &mut router, msg_env).expect("no error");
+
+    // The transfer module acknowledges synchronously.
+    let res = write_acknowledgement(&mut ctx.ibc_store, packet, ack);
+    assert!(matches!(
+        res,
+        Err(ContextError::PacketError(
+            PacketError::AcknowledgementExists { .. }
+        ))
+    ));
+}
+
+/// Acknowledgements written asynchronously are wrapped by the middlewares of
+/// the module owning the channel, as synchronous ones are.
+#[rstest]
+fn write_acknowledgement_wraps_async_acknowledgement(fixture: Fixture) {
+    /// Acknowledges packets asynchronously, tagging their acknowledgements.
+    #[derive(Debug)]
+    struct AsyncMiddleware;
+
+    impl Middleware for AsyncMiddleware {
+        fn wrap_acknowledgement(&self, app_ack: Acknowledgement) -> Acknowledgement {
+            let mut ack = b"async".to_vec();
+            ack.extend(app_ack.as_bytes());
+            ack.try_into().expect("non-empty acknowledgement")
+        }
+
+        fn on_recv_packet_execute(
+            &mut self,
+            _next: &mut dyn Module,
+            _packet: &Packet,
+            _relayer: &Signer,
+        ) -> (ModuleExtras, RecvPacketResult) {
+            (ModuleExtras::empty(), RecvPacketResult::Async)
+        }
+    }
+
+    let Fixture {
+        context,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        commitment_root,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    let module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    let mut router = MockRouter::default();
+    bind_port(&mut router, PortId::transfer(), module_id.clone()).expect("no error");
+    router
+        .add_route(
+            module_id,
+            MiddlewareStack::new(AsyncMiddleware, DummyTransferModule::new()),
+        )
+        .expect("no error");
+
+    let packet = msg.packet.clone();
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+    execute(&mut ctx.ibc_store, &mut router, msg_env).expect("no error");
+
+    let ack_path = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    assert!(ctx.ibc_store.get_packet_acknowledgement(&ack_path).is_err());
+
+    let app_ack = Acknowledgement::try_from(vec![1u8]).expect("no error");
+    entrypoint::write_acknowledgement::validate(&ctx.ibc_store, &router, &packet, app_ack.clone())
+        .expect("no error");
+    entrypoint::write_acknowledgement::execute(&mut ctx.ibc_store, &router, packet, app_ack)
+        .expect("no error");
+
+    let expected_ack = Acknowledgement::try_from(b"async\x01".to_vec()).expect("no error");
+    assert_eq!(
+        ctx.ibc_store.get_packet_acknowledgement(&ack_path).unwrap(),
+        compute_ack_commitment(&expected_ack)
+    );
+}
+
+#[rstest]
+fn recv_packet_verifies_mock_commitment_proofs(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        client_id,
+        commitment_root,
+    } = fixture;
+
+    let packet = msg.packet.clone();
+    let ctx_b = context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .advance_block_up_to_height(host_height);
+
+    let res = validate(
+        &ctx_b.ibc_store,
+        &router,
+        MsgEnvelope::from(PacketMsg::from(msg.clone())),
+    );
+    assert!(
+        res.is_ok(),
+        "Happy path: validation should succeed. err: {res:?}"
+    );
+
+    // The proof does not hold for another packet.
+    let mut tampered_msg = msg.clone();
+    tampered_msg.packet.data = b"tampered".to_vec();
+    let res = validate(
+        &ctx_b.ibc_store,
+        &router,
+        MsgEnvelope::from(PacketMsg::from(tampered_msg)),
+    );
+    assert!(res.is_err(), "proof of another commitment must not verify");
+
+    // The dummy proof is rejected.
+    msg.proof_commitment_on_a = dummy_commitment_proof_bytes();
+    let res = validate(
+        &ctx_b.ibc_store,
+        &router,
+        MsgEnvelope::from(PacketMsg::from(msg)),
+    );
+    assert!(res.is_err(), "dummy proofs must not verify");
 }
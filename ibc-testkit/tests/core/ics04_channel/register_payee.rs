@@ -0,0 +1,136 @@
+use ibc::core::channel::handler::{
+    register_counterparty_payee_execute, register_counterparty_payee_validate,
+    register_payee_execute, register_payee_validate,
+};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::msgs::{MsgRegisterCounterpartyPayee, MsgRegisterPayee};
+use ibc::core::channel::types::Version;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::Signer;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use rstest::*;
+
+pub struct Fixture {
+    pub ctx: MockContext,
+    pub msg_payee: MsgRegisterPayee,
+    pub msg_counterparty_payee: MsgRegisterCounterpartyPayee,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::new(0);
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::new(0))),
+        vec![ConnectionId::new(0)],
+        Version::empty(),
+    )
+    .unwrap();
+
+    let ctx = MockContext::default().with_channel(port_id.clone(), channel_id.clone(), chan_end);
+
+    let msg_payee = MsgRegisterPayee {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        relayer: dummy_account_id(),
+        payee: Signer::from("payee".to_string()),
+    };
+
+    let msg_counterparty_payee = MsgRegisterCounterpartyPayee {
+        port_id,
+        channel_id,
+        relayer: dummy_account_id(),
+        counterparty_payee: Signer::from("counterparty_payee".to_string()),
+    };
+
+    Fixture {
+        ctx,
+        msg_payee,
+        msg_counterparty_payee,
+    }
+}
+
+#[rstest]
+fn register_payee_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut ctx, msg_payee, ..
+    } = fixture;
+
+    let relayer = msg_payee.relayer.clone();
+    let channel_id = msg_payee.channel_id.clone();
+
+    // Without registration, the fees are paid out to the relayer itself.
+    let recipient = ctx.ibc_store.fee_recipient(&relayer, &channel_id).unwrap();
+    assert_eq!(recipient, relayer);
+
+    register_payee_validate(&ctx.ibc_store, &msg_payee).unwrap();
+    register_payee_execute(&mut ctx.ibc_store, msg_payee.clone()).unwrap();
+
+    let recipient = ctx.ibc_store.fee_recipient(&relayer, &channel_id).unwrap();
+    assert_eq!(recipient, msg_payee.payee);
+
+    let events = ctx.get_events();
+    assert!(matches!(
+        events.as_slice(),
+        [
+            ..,
+            IbcEvent::Message(MessageEvent::Channel),
+            IbcEvent::Module(event),
+        ] if event.kind == "register_payee"
+    ));
+}
+
+#[rstest]
+fn register_counterparty_payee_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        msg_counterparty_payee,
+        ..
+    } = fixture;
+
+    let relayer = msg_counterparty_payee.relayer.clone();
+    let channel_id = msg_counterparty_payee.channel_id.clone();
+
+    let attribution = ctx
+        .ibc_store
+        .recv_relayer_attribution(&relayer, &channel_id)
+        .unwrap();
+    assert!(attribution.is_none());
+
+    register_counterparty_payee_validate(&ctx.ibc_store, &msg_counterparty_payee).unwrap();
+    register_counterparty_payee_execute(&mut ctx.ibc_store, msg_counterparty_payee.clone())
+        .unwrap();
+
+    let attribution = ctx
+        .ibc_store
+        .recv_relayer_attribution(&relayer, &channel_id)
+        .unwrap();
+    assert_eq!(attribution, Some(msg_counterparty_payee.counterparty_payee));
+
+    let events = ctx.get_events();
+    assert!(matches!(
+        events.as_slice(),
+        [
+            ..,
+            IbcEvent::Message(MessageEvent::Channel),
+            IbcEvent::Module(event),
+        ] if event.kind == "register_counterparty_payee"
+    ));
+}
+
+#[rstest]
+fn register_payee_fail_no_channel(fixture: Fixture) {
+    let Fixture { msg_payee, .. } = fixture;
+
+    let ctx = MockContext::default();
+
+    let res = register_payee_validate(&ctx.ibc_store, &msg_payee);
+
+    assert!(res.is_err(), "registration on a missing channel must fail");
+}
@@ -1,10 +1,13 @@
 use core::ops::Add;
 use core::time::Duration;
 
-use ibc::core::channel::handler::send_packet;
+use ibc::core::channel::handler::{compute_packet_timeouts, send_packet};
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::PacketError;
+use ibc::core::channel::types::limits::PacketSizeLimits;
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::timeout_policy::TimeoutPolicy;
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
@@ -12,13 +15,18 @@ use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
+use ibc::core::handler::types::error::{ContextError, HostModeError};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::mode::HostMode;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::CommitmentPath;
 use ibc::core::primitives::*;
+use ibc_query::core::channel::{query_channel_packet_stats, QueryChannelPacketStatsRequest};
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::utils::year_2024;
 use test_log::test;
 
 #[test]
@@ -54,7 +62,7 @@ fn send_packet_processing() {
     )
     .unwrap();
 
-    let timestamp_future = Timestamp::now().add(Duration::from_secs(10)).unwrap();
+    let timestamp_future = year_2024().add(Duration::from_secs(10)).unwrap();
     let timestamp_ns_past = 1;
 
     let timeout_height_future = 10;
@@ -206,3 +214,248 @@ fn send_packet_processing() {
         }
     }
 }
+
+#[test]
+fn send_packet_fail_receive_only_host_mode() {
+    let mut ctx = MockContext::default();
+    ctx.ibc_store.host_mode = HostMode::ReceiveOnly;
+
+    let packet: Packet = dummy_raw_packet(10, 1).try_into().unwrap();
+
+    let res = send_packet(&mut ctx.ibc_store, packet);
+
+    assert!(matches!(
+        res,
+        Err(ContextError::HostModeError(
+            HostModeError::SendPacketNotAllowed {
+                mode: HostMode::ReceiveOnly
+            }
+        ))
+    ));
+}
+
+#[test]
+fn send_packet_fail_packet_data_too_long() {
+    let mut ctx = MockContext::default();
+    ctx.ibc_store.packet_size_limits = PacketSizeLimits {
+        max_data_length: 0,
+        ..Default::default()
+    };
+
+    let packet: Packet = dummy_raw_packet(10, 1).try_into().unwrap();
+
+    let res = send_packet(&mut ctx.ibc_store, packet);
+
+    assert!(matches!(
+        res,
+        Err(ContextError::PacketError(PacketError::PacketDataTooLong {
+            max_length: 0,
+            ..
+        }))
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn send_packet_fail_malformed_packet_data() {
+    use std::sync::Arc;
+
+    use ibc::apps::transfer::codec::TransferPacketDataCodec;
+
+    let mut ctx = MockContext::default();
+    ctx.ibc_store
+        .packet_data_codecs
+        .insert(PortId::transfer(), Arc::new(TransferPacketDataCodec));
+
+    let packet: Packet = dummy_raw_packet(10, 1).try_into().unwrap();
+
+    let res = send_packet(&mut ctx.ibc_store, packet);
+
+    assert!(matches!(
+        res,
+        Err(ContextError::PacketError(
+            PacketError::InvalidPacketData { .. }
+        ))
+    ));
+}
+
+#[test]
+fn channel_packet_stats_track_sent_packets() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let client_height = Height::new(0, 5).unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into())
+        .with_recv_sequence(PortId::transfer(), ChannelId::zero(), 1.into())
+        .with_ack_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let timestamp_future = year_2024().add(Duration::from_secs(10)).unwrap();
+    let mut packet: Packet = dummy_raw_packet(10, timestamp_future.nanoseconds())
+        .try_into()
+        .unwrap();
+    packet.seq_on_a = 1.into();
+
+    send_packet(&mut ctx.ibc_store, packet).expect("packet is sent");
+
+    let stats = query_channel_packet_stats(
+        &ctx.ibc_store,
+        &QueryChannelPacketStatsRequest {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+        },
+    )
+    .expect("channel exists");
+
+    assert_eq!(stats.packet_commitments, 1);
+    assert_eq!(stats.packet_receipts, 0);
+    assert_eq!(stats.packet_acknowledgements, 0);
+    assert_eq!(stats.next_sequence_send, 2.into());
+    assert_eq!(stats.next_sequence_receive, 1.into());
+    assert_eq!(stats.next_sequence_ack, 1.into());
+}
+
+#[test]
+fn send_packet_indexes_packet_timeout() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let client_height = Height::new(0, 5).unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let timestamp_future = year_2024().add(Duration::from_secs(10)).unwrap();
+    let mut packet: Packet = dummy_raw_packet(10, timestamp_future.nanoseconds())
+        .try_into()
+        .unwrap();
+    packet.seq_on_a = 1.into();
+
+    send_packet(&mut ctx.ibc_store, packet).expect("packet is sent");
+
+    let commitment_path = CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), 1.into());
+    let indexes = ctx.ibc_store.secondary_indexes.lock();
+
+    assert!(indexes
+        .timed_out_packets(&Height::new(0, 9).unwrap(), &year_2024())
+        .is_empty());
+    assert_eq!(
+        indexes.timed_out_packets(&Height::new(0, 10).unwrap(), &year_2024()),
+        vec![commitment_path.clone()]
+    );
+    assert_eq!(
+        indexes.timed_out_packets(&Height::new(0, 9).unwrap(), &timestamp_future),
+        vec![commitment_path]
+    );
+}
+
+#[test]
+fn send_packet_with_timeout_policy() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let client_height = Height::new(0, 5).unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let policy = TimeoutPolicy::new(10, Duration::from_secs(60));
+
+    let (timeout_height_on_b, timeout_timestamp_on_b) = compute_packet_timeouts(
+        &ctx.ibc_store,
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &policy,
+    )
+    .expect("channel exists");
+
+    assert_eq!(
+        timeout_height_on_b,
+        TimeoutHeight::At(Height::new(0, 15).unwrap())
+    );
+
+    let mut packet: Packet = dummy_raw_packet(10, 1).try_into().unwrap();
+    packet.timeout_height_on_b = timeout_height_on_b;
+    packet.timeout_timestamp_on_b = timeout_timestamp_on_b;
+
+    send_packet(&mut ctx.ibc_store, packet).expect("timeouts are in the future");
+}
@@ -1,23 +1,30 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
-use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::channel::types::commitment::{
+    compute_packet_commitment, compute_sequence_commitment, PacketCommitment,
+};
+use ibc::core::channel::types::error::PacketError;
 use ibc::core::channel::types::msgs::{MsgTimeout, PacketMsg};
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
-use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::host::types::path::{ClientConsensusStatePath, Path, ReceiptPath, SeqRecvPath};
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::LightClientState;
 use rstest::*;
@@ -32,6 +39,10 @@ struct Fixture {
     conn_end_on_a: ConnectionEnd,
     chan_end_on_a_ordered: ChannelEnd,
     chan_end_on_a_unordered: ChannelEnd,
+    /// Proves the next sequence to receive on an ordered channel, while the
+    /// message proves the absence of a receipt on an unordered one.
+    proof_next_seq_recv_on_b: CommitmentProofBytes,
+    commitment_root: CommitmentRoot,
 }
 
 #[fixture]
@@ -39,12 +50,7 @@ fn fixture() -> Fixture {
     let client_id = ClientId::new("07-tendermint", 0).expect("no error");
 
     let client_height = Height::new(0, 2).unwrap();
-    let ctx = MockContext::default().with_light_client(
-        &ClientId::new("07-tendermint", 0).expect("no error"),
-        LightClientState::<MockHost>::with_latest_height(client_height),
-    );
-
-    let client_height = Height::new(0, 2).unwrap();
+    let ctx = MockContext::default();
 
     let router = MockRouter::new_with_transfer();
 
@@ -53,7 +59,7 @@ fn fixture() -> Fixture {
     let msg_proof_height = 2;
     let msg_timeout_height = 5;
 
-    let msg = MsgTimeout::try_from(dummy_raw_msg_timeout(
+    let mut msg = MsgTimeout::try_from(dummy_raw_msg_timeout(
         msg_proof_height,
         msg_timeout_height,
         timeout_timestamp,
@@ -71,7 +77,10 @@ fn fixture() -> Fixture {
     let chan_end_on_a_unordered = ChannelEnd::new(
         State::Open,
         Order::Unordered,
-        Counterparty::new(packet.port_id_on_b.clone(), Some(packet.chan_id_on_b)),
+        Counterparty::new(
+            packet.port_id_on_b.clone(),
+            Some(packet.chan_id_on_b.clone()),
+        ),
         vec![ConnectionId::zero()],
         Version::new("ics20-1".to_string()),
     )
@@ -93,6 +102,27 @@ fn fixture() -> Fixture {
     )
     .unwrap();
 
+    // The counterparty has not received the packet.
+    let seq_recv_path = Path::SeqRecv(SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b));
+    let receipt_path = Path::Receipt(ReceiptPath::new(
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+        packet.seq_on_a,
+    ));
+    let mut tree = MockCommitmentTree::new(conn_end_on_a.counterparty().prefix().clone());
+    tree.insert(
+        seq_recv_path.clone(),
+        compute_sequence_commitment(&packet.seq_on_a),
+    );
+    msg.proof_unreceived_on_b = tree.prove_non_membership(&receipt_path).unwrap();
+    let proof_next_seq_recv_on_b = tree.prove_membership(&seq_recv_path).unwrap();
+
+    let ctx = ctx.with_light_client(
+        &client_id,
+        LightClientState::<MockHost>::with_latest_height(client_height)
+            .with_commitment_root(tree.root()),
+    );
+
     Fixture {
         ctx,
         router,
@@ -103,6 +133,8 @@ fn fixture() -> Fixture {
         conn_end_on_a,
         chan_end_on_a_ordered,
         chan_end_on_a_unordered,
+        proof_next_seq_recv_on_b,
+        commitment_root: tree.root(),
     }
 }
 
@@ -113,11 +145,13 @@ fn timeout_fail_no_channel(fixture: Fixture) {
         router,
         msg,
         client_height,
+        commitment_root,
         ..
     } = fixture;
     let ctx = ctx.with_light_client(
         &ClientId::new("07-tendermint", 0).expect("no error"),
-        LightClientState::<MockHost>::with_latest_height(client_height),
+        LightClientState::<MockHost>::with_latest_height(client_height)
+            .with_commitment_root(commitment_root),
     );
     let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
     let res = validate(&ctx.ibc_store, &router, msg_envelope);
@@ -186,6 +220,7 @@ fn timeout_fail_proof_timeout_not_reached(fixture: Fixture) {
         chan_end_on_a_unordered,
         conn_end_on_a,
         client_height,
+        commitment_root,
         ..
     } = fixture;
 
@@ -204,7 +239,8 @@ fn timeout_fail_proof_timeout_not_reached(fixture: Fixture) {
     let ctx = ctx
         .with_light_client(
             &ClientId::new("07-tendermint", 0).expect("no error"),
-            LightClientState::<MockHost>::with_latest_height(client_height),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
         )
         .with_connection(ConnectionId::zero(), conn_end_on_a)
         .with_channel(
@@ -229,9 +265,9 @@ fn timeout_fail_proof_timeout_not_reached(fixture: Fixture) {
         )
 }
 
-/// NO-OP case
+/// Timing out a packet that is not pending, e.g. already acknowledged, fails
 #[rstest]
-fn timeout_success_no_packet_commitment(fixture: Fixture) {
+fn timeout_fail_no_packet_commitment(fixture: Fixture) {
     let Fixture {
         ctx,
         router,
@@ -253,8 +289,13 @@ fn timeout_success_no_packet_commitment(fixture: Fixture) {
     let res = validate(&ctx.ibc_store, &router, msg_envelope);
 
     assert!(
-        res.is_ok(),
-        "Validation should succeed when no packet commitment is present"
+        matches!(
+            res,
+            Err(ContextError::PacketError(
+                PacketError::PacketNotPending { .. }
+            ))
+        ),
+        "Validation fails when no packet commitment is present"
     )
 }
 
@@ -268,6 +309,7 @@ fn timeout_unordered_channel_validate(fixture: Fixture) {
         conn_end_on_a,
         packet_commitment,
         client_height,
+        commitment_root,
         ..
     } = fixture;
 
@@ -276,7 +318,8 @@ fn timeout_unordered_channel_validate(fixture: Fixture) {
     let ctx = ctx
         .with_light_client(
             &ClientId::new("07-tendermint", 0).expect("no error"),
-            LightClientState::<MockHost>::with_latest_height(client_height),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
         )
         .with_connection(ConnectionId::zero(), conn_end_on_a)
         .with_channel(
@@ -303,20 +346,25 @@ fn timeout_ordered_channel_validate(fixture: Fixture) {
     let Fixture {
         ctx,
         router,
-        msg,
+        mut msg,
         chan_end_on_a_ordered,
         conn_end_on_a,
         packet_commitment,
         client_height,
+        proof_next_seq_recv_on_b,
+        commitment_root,
         ..
     } = fixture;
 
+    msg.proof_unreceived_on_b = proof_next_seq_recv_on_b;
+
     let packet = msg.packet.clone();
 
     let ctx = ctx
         .with_light_client(
             &ClientId::new("07-tendermint", 0).expect("no error"),
-            LightClientState::<MockHost>::with_latest_height(client_height),
+            LightClientState::<MockHost>::with_latest_height(client_height)
+                .with_commitment_root(commitment_root),
         )
         .with_connection(ConnectionId::zero(), conn_end_on_a)
         .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a_ordered)
@@ -1,22 +1,29 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
-use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::channel::types::commitment::{
+    compute_channel_commitment, compute_packet_commitment, compute_sequence_commitment,
+    PacketCommitment,
+};
+use ibc::core::channel::types::error::PacketError;
 use ibc::core::channel::types::msgs::{MsgTimeoutOnClose, PacketMsg};
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
-use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::validate;
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, Path, SeqRecvPath};
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout_on_close;
 use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::commitment::MockCommitmentTree;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::testapp::ibc::core::types::{LightClientState, MockResourceMeter};
 use rstest::*;
 
 pub struct Fixture {
@@ -33,16 +40,12 @@ fn fixture() -> Fixture {
     let default_client_id = ClientId::new("07-tendermint", 0).expect("no error");
 
     let client_height = Height::new(0, 2).unwrap();
-    let context = MockContext::default().with_light_client(
-        &ClientId::new("07-tendermint", 0).expect("no error"),
-        LightClientState::<MockHost>::with_latest_height(client_height),
-    );
     let router = MockRouter::new_with_transfer();
 
     let height = 2;
     let timeout_timestamp = 5;
 
-    let msg =
+    let mut msg =
         MsgTimeoutOnClose::try_from(dummy_raw_msg_timeout_on_close(height, timeout_timestamp))
             .unwrap();
 
@@ -57,7 +60,10 @@ fn fixture() -> Fixture {
     let chan_end_on_a = ChannelEnd::new(
         State::Open,
         Order::Ordered,
-        Counterparty::new(packet.port_id_on_b.clone(), Some(packet.chan_id_on_b)),
+        Counterparty::new(
+            packet.port_id_on_b.clone(),
+            Some(packet.chan_id_on_b.clone()),
+        ),
         vec![ConnectionId::zero()],
         Version::new("ics20-1".to_string()),
     )
@@ -76,6 +82,43 @@ fn fixture() -> Fixture {
     )
     .unwrap();
 
+    // The counterparty has closed the channel before receiving the packet.
+    let chan_end_on_b = ChannelEnd::new(
+        State::Closed,
+        Order::Ordered,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+    let chan_end_path_on_b = Path::ChannelEnd(ChannelEndPath::new(
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+    ));
+    let seq_recv_path_on_b =
+        Path::SeqRecv(SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b));
+
+    let mut tree = MockCommitmentTree::new(conn_end_on_a.counterparty().prefix().clone());
+    tree.insert(
+        chan_end_path_on_b.clone(),
+        compute_channel_commitment(&chan_end_on_b),
+    );
+    tree.insert(
+        seq_recv_path_on_b.clone(),
+        compute_sequence_commitment(&packet.seq_on_a),
+    );
+    msg.proof_close_on_b = tree.prove_membership(&chan_end_path_on_b).unwrap();
+    msg.proof_unreceived_on_b = tree.prove_membership(&seq_recv_path_on_b).unwrap();
+
+    let context = MockContext::default().with_light_client(
+        &ClientId::new("07-tendermint", 0).expect("no error"),
+        LightClientState::<MockHost>::with_latest_height(client_height)
+            .with_commitment_root(tree.root()),
+    );
+
     Fixture {
         context,
         router,
@@ -105,9 +148,9 @@ fn timeout_on_close_fail_no_channel(fixture: Fixture) {
     )
 }
 
-/// NO-OP case
+/// Timing out a packet that is not pending, e.g. already acknowledged, fails
 #[rstest]
-fn timeout_on_close_success_no_packet_commitment(fixture: Fixture) {
+fn timeout_on_close_fail_no_packet_commitment(fixture: Fixture) {
     let Fixture {
         context,
         router,
@@ -125,8 +168,13 @@ fn timeout_on_close_success_no_packet_commitment(fixture: Fixture) {
     let res = validate(&context.ibc_store, &router, msg_envelope);
 
     assert!(
-        res.is_ok(),
-        "Validation should succeed when no packet commitment is present"
+        matches!(
+            res,
+            Err(ContextError::PacketError(
+                PacketError::PacketNotPending { .. }
+            ))
+        ),
+        "Validation fails when no packet commitment is present"
     )
 }
 
@@ -160,3 +208,68 @@ fn timeout_on_close_success_happy_path(fixture: Fixture) {
         "Happy path: validation should succeed. err: {res:?}"
     )
 }
+
+/// On ordered channels, a single proof of both the closed channel end and the
+/// next sequence to receive is verified, and charged, once.
+#[rstest]
+fn timeout_on_close_verifies_batch_proof_once(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a,
+        ..
+    } = fixture;
+
+    // The mock client has no batch proofs, so the shared proof is not
+    // verified but only metered.
+    msg.proof_unreceived_on_b = msg.proof_close_on_b.clone();
+
+    let mut context = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(msg.proof_height_on_b)
+                .with_unverified_proofs(),
+        )
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let packet_charge = msg.packet.data.len() as u64;
+    let proof_charge = msg.proof_close_on_b.as_bytes().len() as u64;
+
+    let res = validate(
+        &context.ibc_store,
+        &router,
+        MsgEnvelope::from(PacketMsg::from(msg.clone())),
+    );
+
+    assert!(res.is_ok(), "validation should succeed. err: {res:?}");
+    assert_eq!(
+        context.ibc_store.resource_meter.consumed(),
+        packet_charge + proof_charge
+    );
+
+    // Distinct proofs are verified, and charged, separately.
+    msg.proof_unreceived_on_b = CommitmentProofBytes::try_from(vec![1; 16]).expect("no error");
+    context.ibc_store.resource_meter = MockResourceMeter::default();
+
+    let res = validate(
+        &context.ibc_store,
+        &router,
+        MsgEnvelope::from(PacketMsg::from(msg)),
+    );
+
+    assert!(res.is_ok(), "validation should succeed. err: {res:?}");
+    assert_eq!(
+        context.ibc_store.resource_meter.consumed(),
+        packet_charge + proof_charge + 16
+    );
+}
@@ -0,0 +1,170 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::primitives::*;
+use ibc_query::invariants::{
+    check_channel_connections, check_consensus_state_update_meta, check_core_invariants,
+    check_packet_commitment_sequences, InvariantViolation,
+};
+use ibc_testkit::context::MockContext;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::utils::year_2023;
+use test_log::test;
+
+fn client_id() -> ClientId {
+    ClientId::new("07-tendermint", 0).expect("no error")
+}
+
+fn connection_end(state: ConnectionState) -> ConnectionEnd {
+    ConnectionEnd::new(
+        state,
+        client_id(),
+        ConnectionCounterparty::new(
+            client_id(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .expect("no error")
+}
+
+fn channel_end(state: State) -> ChannelEnd {
+    ChannelEnd::new(
+        state,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .expect("no error")
+}
+
+/// Returns a context with a light client, an open connection and an open
+/// channel whose next send sequence is 2.
+fn consistent_context() -> MockContext {
+    MockContext::default()
+        .with_light_client(
+            &client_id(),
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 2).expect("no error")),
+        )
+        .with_connection(ConnectionId::zero(), connection_end(ConnectionState::Open))
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            channel_end(State::Open),
+        )
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 2.into())
+        .with_packet_commitment(
+            PortId::transfer(),
+            ChannelId::zero(),
+            1.into(),
+            PacketCommitment::from(vec![0; 32]),
+        )
+}
+
+#[test]
+fn test_core_invariants_hold() {
+    let ctx = consistent_context();
+
+    assert_eq!(
+        check_core_invariants(&ctx.ibc_store).expect("no error"),
+        vec![]
+    );
+}
+
+#[test]
+fn test_channel_with_missing_connection() {
+    let ctx = MockContext::default()
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            channel_end(State::Open),
+        )
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    assert_eq!(
+        check_channel_connections(&ctx.ibc_store).expect("no error"),
+        vec![InvariantViolation::MissingConnection {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+            connection_id: ConnectionId::zero(),
+        }]
+    );
+}
+
+#[test]
+fn test_open_channel_with_connection_not_open() {
+    let ctx = consistent_context()
+        .with_connection(ConnectionId::zero(), connection_end(ConnectionState::Init));
+
+    assert_eq!(
+        check_core_invariants(&ctx.ibc_store).expect("no error"),
+        vec![InvariantViolation::ConnectionNotOpen {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+            connection_id: ConnectionId::zero(),
+        }]
+    );
+
+    // only open channels require an open connection
+    let ctx = ctx.with_channel(
+        PortId::transfer(),
+        ChannelId::zero(),
+        channel_end(State::Init),
+    );
+
+    assert_eq!(
+        check_channel_connections(&ctx.ibc_store).expect("no error"),
+        vec![]
+    );
+}
+
+#[test]
+fn test_packet_commitment_ahead_of_send_sequence() {
+    let ctx = consistent_context().with_packet_commitment(
+        PortId::transfer(),
+        ChannelId::zero(),
+        2.into(),
+        PacketCommitment::from(vec![0; 32]),
+    );
+
+    assert_eq!(
+        check_packet_commitment_sequences(&ctx.ibc_store).expect("no error"),
+        vec![InvariantViolation::CommitmentAheadOfSendSequence {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+            sequence: Sequence::from(2),
+            next_sequence_send: Sequence::from(2),
+        }]
+    );
+}
+
+#[test]
+fn test_consensus_state_without_update_meta() {
+    let height = Height::new(0, 3).expect("no error");
+    let consensus_state =
+        MockConsensusState::new(MockHeader::new(height).with_timestamp(year_2023()));
+
+    let ctx =
+        consistent_context().with_consensus_state(&client_id(), height, consensus_state.into());
+
+    assert_eq!(
+        check_consensus_state_update_meta(&ctx.ibc_store).expect("no error"),
+        vec![InvariantViolation::MissingUpdateMeta {
+            client_id: client_id(),
+            height,
+        }]
+    );
+}
@@ -0,0 +1,119 @@
+use ibc::core::primitives::prelude::*;
+use ibc::core::router::memo::{
+    DestCallback, Forward, MemoError, MemoLimits, MemoRegistry, MemoSchema, ParsedMemo,
+    SrcCallback, WasmHook,
+};
+use rstest::rstest;
+use serde::Deserialize;
+
+#[test]
+fn test_parse_known_schemas() {
+    let memo = r#"{
+        "forward": {
+            "receiver": "cosmos1receiver",
+            "port": "transfer",
+            "channel": "channel-1",
+            "retries": 2,
+            "next": {"wasm": {"contract": "osmo1contract", "msg": {"swap": {}}}}
+        },
+        "src_callback": {"address": "cosmos1callback", "gas_limit": "100000"},
+        "wasm": {"contract": "cosmos1contract", "msg": {"execute": {"amount": [1, "2", null, true]}}}
+    }"#;
+
+    let parsed = MemoRegistry::with_known_schemas()
+        .parse(memo, &MemoLimits::default())
+        .expect("valid memo");
+
+    let forward = parsed.get::<Forward>().unwrap().expect("has forward");
+    assert_eq!(forward.channel, "channel-1");
+    assert_eq!(forward.retries, Some(2));
+
+    let next = ParsedMemo::parse(
+        forward.next.expect("has next").as_str(),
+        &MemoLimits::default(),
+    )
+    .unwrap();
+    let next_wasm = next.get::<WasmHook>().unwrap().expect("has wasm");
+    assert_eq!(next_wasm.msg.as_str(), r#"{"swap":{}}"#);
+
+    let callback = parsed.get::<SrcCallback>().unwrap().expect("has callback");
+    assert_eq!(callback.gas_limit.as_deref(), Some("100000"));
+    assert!(parsed.get::<DestCallback>().unwrap().is_none());
+
+    let wasm = parsed.get::<WasmHook>().unwrap().expect("has wasm");
+    assert_eq!(
+        wasm.msg.as_str(),
+        r#"{"execute":{"amount":[1,"2",null,true]}}"#
+    );
+}
+
+#[rstest]
+#[case::empty("")]
+#[case::plain_text("thanks for the tokens")]
+#[case::unknown_key(r#"{"custom": {"foo": "bar"}}"#)]
+fn test_parse_memo_without_known_payload(#[case] memo: &str) {
+    let parsed = MemoRegistry::with_known_schemas()
+        .parse(memo, &MemoLimits::default())
+        .expect("valid memo");
+
+    assert!(parsed.get::<Forward>().unwrap().is_none());
+}
+
+#[rstest]
+#[case::invalid_json(r#"{"forward": "#)]
+#[case::invalid_payload(r#"{"forward": {"receiver": "cosmos1receiver"}}"#)]
+#[case::invalid_callback(r#"{"dest_callback": {"gas_limit": "1"}}"#)]
+#[case::float(r#"{"wasm": {"contract": "cosmos1contract", "msg": {"amount": 1.0}}}"#)]
+#[case::exponent(r#"{"custom": {"amount": 1e3}}"#)]
+fn test_parse_invalid_memo(#[case] memo: &str) {
+    let res = MemoRegistry::with_known_schemas().parse(memo, &MemoLimits::default());
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_parse_memo_too_long() {
+    let memo = "a".repeat(11);
+
+    let res = ParsedMemo::parse(&memo, &MemoLimits { max_length: 10 });
+
+    assert!(matches!(
+        res,
+        Err(MemoError::TooLong {
+            length: 11,
+            max_length: 10
+        })
+    ));
+}
+
+#[test]
+fn test_register_custom_schema() {
+    struct Custom;
+
+    #[derive(Deserialize)]
+    struct CustomPayload {
+        foo: String,
+    }
+
+    impl MemoSchema for Custom {
+        const KEY: &'static str = "custom";
+
+        type Payload = CustomPayload;
+    }
+
+    let mut registry = MemoRegistry::with_known_schemas();
+    registry.register::<Custom>().expect("new schema");
+    assert!(matches!(
+        registry.register::<Custom>(),
+        Err(MemoError::DuplicateSchema { .. })
+    ));
+
+    let parsed = registry
+        .parse(r#"{"custom": {"foo": "bar"}}"#, &MemoLimits::default())
+        .expect("valid memo");
+    let payload = parsed.get::<Custom>().unwrap().expect("has payload");
+    assert_eq!(payload.foo, "bar");
+
+    let res = registry.parse(r#"{"custom": {"foo": 1}}"#, &MemoLimits::default());
+    assert!(res.is_err());
+}
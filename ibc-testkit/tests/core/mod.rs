@@ -1,5 +1,15 @@
+pub mod encoding;
+#[cfg(feature = "serde")]
+pub mod event_json;
 pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
+pub mod invariants;
+#[cfg(feature = "serde")]
+pub mod memo;
+pub mod per_block;
+#[cfg(feature = "serde")]
+pub mod query_services;
 #[cfg(feature = "serde")]
 pub mod router;
+pub mod simulate;
@@ -0,0 +1,126 @@
+use core::time::Duration;
+
+use ibc::core::channel::handler::send_packet;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::events::ClientExpired;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::core::host::IbcPerBlockHooks;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use test_log::test;
+
+fn log_messages(ctx: &MockContext) -> Vec<String> {
+    ctx.get_logs()
+        .iter()
+        .filter_map(|log| log.as_message().map(ToString::to_string))
+        .collect()
+}
+
+fn client_expired_events(ctx: &MockContext) -> Vec<IbcEvent> {
+    ctx.get_events()
+        .into_iter()
+        .filter(|event| matches!(event, IbcEvent::ClientExpired(_)))
+        .collect()
+}
+
+#[test]
+fn end_block_reports_expired_clients() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let mut ctx = MockContext::default().with_light_client(
+        &client_id,
+        LightClientState::<MockHost>::with_latest_height(Height::new(0, 1).unwrap()),
+    );
+
+    IbcPerBlockHooks::end_block(&mut ctx.ibc_store).expect("no error");
+    assert!(client_expired_events(&ctx).is_empty());
+
+    // past the trusting period of the mock client
+    ctx.advance_block_after(Duration::from_secs(64001));
+
+    IbcPerBlockHooks::end_block(&mut ctx.ibc_store).expect("no error");
+    assert_eq!(
+        client_expired_events(&ctx),
+        vec![IbcEvent::ClientExpired(ClientExpired::new(
+            client_id,
+            mock_client_type()
+        ))]
+    );
+}
+
+/// The timeouts of the packets are checked against the latest height known to
+/// the counterparty client, which lags behind the host height.
+#[test]
+fn end_block_reports_packets_timed_out_on_counterparty() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        Duration::ZERO,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .advance_block_up_to_height(Height::new(0, 20).unwrap())
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let mut packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+    packet.seq_on_a = 1.into();
+
+    send_packet(&mut ctx.ibc_store, packet).expect("packet is sent");
+
+    IbcPerBlockHooks::end_block(&mut ctx.ibc_store).expect("no error");
+    assert!(
+        log_messages(&ctx).is_empty(),
+        "the host height is past the timeout, but not the counterparty height"
+    );
+
+    ctx = ctx.with_light_client(
+        &client_id,
+        LightClientState::<MockHost>::with_latest_height(Height::new(0, 10).unwrap()),
+    );
+
+    IbcPerBlockHooks::end_block(&mut ctx.ibc_store).expect("no error");
+
+    let commitment_path = CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), 1.into());
+
+    assert_eq!(
+        log_messages(&ctx),
+        vec![format!("packet timeout elapsed: {commitment_path}")]
+    );
+}
@@ -0,0 +1,15 @@
+use ibc_query::core::services::IbcQueryServices;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::testapp::ibc::applications::nft_transfer::types::DummyNftTransferModule;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use test_log::test;
+
+#[test]
+fn test_ibc_query_services_construction() {
+    let ctx = MockContext::default();
+
+    let _router = IbcQueryServices::new(ctx.ibc_store.clone())
+        .with_transfer_service(DummyTransferModule::new())
+        .with_nft_transfer_service(DummyNftTransferModule::default())
+        .into_router();
+}
@@ -3,25 +3,44 @@ use core::ops::Add;
 use ibc::apps::transfer::handler::send_transfer;
 use ibc::apps::transfer::types::error::TokenTransferError;
 use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
-use ibc::apps::transfer::types::{BaseCoin, U256};
+use ibc::apps::transfer::types::version::TransferVersion;
+use ibc::apps::transfer::types::{BaseCoin, MODULE_ID_STR, U256};
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
 use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{
     ChannelMsg, MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
     MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeoutOnClose, PacketMsg,
 };
 use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version;
 use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
 use ibc::core::client::types::Height;
-use ibc::core::connection::types::msgs::ConnectionMsg;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenInit};
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
 use ibc::core::entrypoint::dispatch;
+use ibc::core::entrypoint::ownership::{
+    authenticate_channel_owner, channel_owner, claim_channel, migrate_channel_owners,
+};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
-use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::handler::types::msgs::{Msg, MsgEnvelope};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::host::types::path::CommitmentPath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
+use ibc::core::router::middleware::{Middleware, MiddlewareStack};
+use ibc::core::router::module::Module;
+use ibc::core::router::port::{authenticate_port_owner, bind_port};
+use ibc::core::router::router::{DynamicRouter, InMemoryRouter, Router};
+use ibc::core::router::types::error::RouterError;
+use ibc::core::router::types::module::ModuleId;
+use ibc::core::router::version::{decode_json_version, encode_json_version, AppVersion};
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::applications::transfer::{
     extract_transfer_packet, MsgTransferConfig, PacketDataConfig,
@@ -29,7 +48,7 @@ use ibc_testkit::fixtures::applications::transfer::{
 use ibc_testkit::fixtures::core::channel::{
     dummy_raw_msg_ack_with_packet, dummy_raw_msg_chan_close_confirm, dummy_raw_msg_chan_close_init,
     dummy_raw_msg_chan_open_ack, dummy_raw_msg_chan_open_init, dummy_raw_msg_chan_open_try,
-    dummy_raw_msg_recv_packet, dummy_raw_msg_timeout_on_close,
+    dummy_raw_msg_recv_packet, dummy_raw_msg_timeout_on_close, PacketConfig,
 };
 use ibc_testkit::fixtures::core::client::dummy_msg_upgrade_client;
 use ibc_testkit::fixtures::core::connection::{
@@ -38,11 +57,14 @@ use ibc_testkit::fixtures::core::connection::{
 };
 use ibc_testkit::fixtures::core::context::TestContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
 use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::utils::year_2024;
 use test_log::test;
 
 #[test]
@@ -93,16 +115,16 @@ fn routing_module_and_keepers() {
     let mut ctx = TestContextConfig::builder()
         // a future timestamp, so that submitted packets are considered from past
         // not more than 5 secs, as later dummy_raw_msg_timeout_on_close(*, 5) is used
-        .latest_timestamp(
-            Timestamp::now()
-                .add(core::time::Duration::from_secs(4))
-                .unwrap(),
-        )
+        .latest_timestamp(year_2024().add(core::time::Duration::from_secs(4)).unwrap())
         .build::<MockContext>();
 
     let mut router = MockRouter::new_with_transfer();
 
-    let header = MockHeader::new(start_client_height).with_current_timestamp();
+    // The dummy messages carry no proofs of an actual counterparty, so the
+    // client is driven with headers opting out of proof verification.
+    let header = MockHeader::new(start_client_height)
+        .with_timestamp(year_2024())
+        .with_unverified_proofs();
 
     let create_client_msg = MsgCreateClient::new(
         MockClientState::new(header).into(),
@@ -227,7 +249,8 @@ fn routing_module_and_keepers() {
             msg: MsgEnvelope::Client(ClientMsg::UpdateClient(MsgUpdateClient {
                 client_id: client_id.clone(),
                 client_message: MockHeader::new(update_client_height)
-                    .with_current_timestamp()
+                    .with_timestamp(year_2024())
+                    .with_unverified_proofs()
                     .into(),
                 signer: default_signer.clone(),
             }))
@@ -304,7 +327,8 @@ fn routing_module_and_keepers() {
             msg: MsgEnvelope::Client(ClientMsg::UpdateClient(MsgUpdateClient {
                 client_id: client_id.clone(),
                 client_message: MockHeader::new(update_client_height_after_send)
-                    .with_current_timestamp()
+                    .with_timestamp(year_2024())
+                    .with_unverified_proofs()
                     .into(),
                 signer: default_signer.clone(),
             }))
@@ -350,7 +374,8 @@ fn routing_module_and_keepers() {
             msg: MsgEnvelope::Client(ClientMsg::UpdateClient(MsgUpdateClient {
                 client_id: client_id.clone(),
                 client_message: MockHeader::new(update_client_height_after_second_send)
-                    .with_current_timestamp()
+                    .with_timestamp(year_2024())
+                    .with_unverified_proofs()
                     .into(),
                 signer: default_signer,
             }))
@@ -417,12 +442,14 @@ fn routing_module_and_keepers() {
 
     for test in tests {
         let res = match test.msg.clone() {
-            TestMsg::Ics26(msg) => dispatch(&mut ctx.ibc_store, &mut router, msg),
-            TestMsg::Ics20(msg) => send_transfer(&mut ctx.ibc_store, &mut DummyTransferModule, msg)
-                .map_err(|e: TokenTransferError| ChannelError::AppModule {
-                    description: e.to_string(),
-                })
-                .map_err(ContextError::from),
+            TestMsg::Ics26(msg) => dispatch(&mut ctx.ibc_store, &mut router, msg).map(|_| ()),
+            TestMsg::Ics20(msg) => {
+                send_transfer(&mut ctx.ibc_store, &router, &mut DummyTransferModule, msg)
+                    .map_err(|e: TokenTransferError| ChannelError::AppModule {
+                        description: e.to_string(),
+                    })
+                    .map_err(ContextError::from)
+            }
         };
 
         assert_eq!(
@@ -446,3 +473,430 @@ fn routing_module_and_keepers() {
         }
     }
 }
+
+#[test]
+fn port_binding_ownership() {
+    let mut router = MockRouter::new_with_transfer();
+
+    let transfer_module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    let other_module_id = ModuleId::new("othermodule".to_string());
+
+    // The transfer port is already bound to the transfer module.
+    assert!(matches!(
+        bind_port(&mut router, PortId::transfer(), other_module_id.clone()),
+        Err(RouterError::PortAlreadyBound { .. })
+    ));
+    assert!(authenticate_port_owner(&router, &PortId::transfer(), &transfer_module_id).is_ok());
+    assert!(matches!(
+        authenticate_port_owner(&router, &PortId::transfer(), &other_module_id),
+        Err(RouterError::UnauthorizedPortOwner { .. })
+    ));
+
+    let other_port_id = PortId::new("other".to_string()).unwrap();
+
+    assert!(matches!(
+        authenticate_port_owner(&router, &other_port_id, &other_module_id),
+        Err(RouterError::PortNotBound { .. })
+    ));
+
+    bind_port(&mut router, other_port_id.clone(), other_module_id.clone()).unwrap();
+
+    assert!(authenticate_port_owner(&router, &other_port_id, &other_module_id).is_ok());
+    assert_eq!(router.lookup_module(&other_port_id), Some(other_module_id));
+}
+
+#[test]
+fn send_transfer_requires_port_ownership() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        core::time::Duration::ZERO,
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let msg: MsgTransfer = MsgTransferConfig::builder()
+        .packet_data(
+            PacketDataConfig::builder()
+                .token(
+                    BaseCoin {
+                        denom: "uatom".parse().expect("parse denom"),
+                        amount: U256::from(10).into(),
+                    }
+                    .into(),
+                )
+                .build(),
+        )
+        .timeout_height_on_b(TimeoutHeight::At(Height::new(0, 35).unwrap()))
+        .build();
+
+    // The transfer port is bound to another module.
+    let mut router = MockRouter::default();
+    bind_port(
+        &mut router,
+        PortId::transfer(),
+        ModuleId::new("othermodule".to_string()),
+    )
+    .unwrap();
+
+    assert!(matches!(
+        send_transfer(
+            &mut ctx.ibc_store,
+            &router,
+            &mut DummyTransferModule,
+            msg.clone()
+        ),
+        Err(TokenTransferError::ContextError(ContextError::RouterError(
+            RouterError::UnauthorizedPortOwner { .. }
+        )))
+    ));
+
+    // The transfer port is not bound.
+    assert!(matches!(
+        send_transfer(
+            &mut ctx.ibc_store,
+            &MockRouter::default(),
+            &mut DummyTransferModule,
+            msg.clone()
+        ),
+        Err(TokenTransferError::ContextError(ContextError::RouterError(
+            RouterError::PortNotBound { .. }
+        )))
+    ));
+
+    send_transfer(
+        &mut ctx.ibc_store,
+        &MockRouter::new_with_transfer(),
+        &mut DummyTransferModule,
+        msg,
+    )
+    .expect("the transfer module owns the transfer port");
+}
+
+#[test]
+fn in_memory_router_routing_table() {
+    let mut router = InMemoryRouter::new();
+
+    let transfer_module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    let other_module_id = ModuleId::new("othermodule".to_string());
+
+    router
+        .add_module(
+            transfer_module_id.clone(),
+            PortId::transfer(),
+            DummyTransferModule,
+        )
+        .unwrap();
+
+    // The same module cannot be registered twice.
+    assert!(matches!(
+        router.add_route(transfer_module_id.clone(), Box::new(DummyTransferModule)),
+        Err(RouterError::DuplicateModule { .. })
+    ));
+
+    // Ports cannot be bound to unregistered modules.
+    assert!(matches!(
+        router.rebind_port(&PortId::transfer(), other_module_id.clone()),
+        Err(RouterError::ModuleNotFound)
+    ));
+
+    router
+        .add_route(other_module_id.clone(), Box::new(DummyTransferModule))
+        .unwrap();
+    router
+        .rebind_port(&PortId::transfer(), other_module_id.clone())
+        .unwrap();
+
+    assert_eq!(
+        router.lookup_module(&PortId::transfer()),
+        Some(other_module_id.clone())
+    );
+    assert_eq!(
+        router.module_ids(),
+        vec![other_module_id.clone(), transfer_module_id.clone()]
+    );
+    assert_eq!(
+        router.bound_ports(),
+        vec![(PortId::transfer(), other_module_id.clone())]
+    );
+
+    // Removing a module releases all of its ports.
+    router.remove_route(&other_module_id).unwrap();
+
+    assert!(router.lookup_module(&PortId::transfer()).is_none());
+    assert!(router.get_route(&other_module_id).is_none());
+    assert!(router.get_route(&transfer_module_id).is_some());
+}
+
+#[test]
+fn channel_ownership_survives_port_rebinding() {
+    let mut ctx = MockContext::default();
+    let mut router = InMemoryRouter::new();
+
+    let transfer_module_id = ModuleId::new(MODULE_ID_STR.to_string());
+    let other_module_id = ModuleId::new("othermodule".to_string());
+
+    router
+        .add_module(
+            transfer_module_id.clone(),
+            PortId::transfer(),
+            DummyTransferModule,
+        )
+        .unwrap();
+    router
+        .add_route(other_module_id.clone(), Box::new(DummyTransferModule))
+        .unwrap();
+
+    claim_channel(
+        ctx.ibc_store_mut(),
+        &PortId::transfer(),
+        &ChannelId::new(0),
+        transfer_module_id.clone(),
+    )
+    .unwrap();
+
+    // A channel cannot be claimed twice.
+    assert!(matches!(
+        claim_channel(
+            ctx.ibc_store_mut(),
+            &PortId::transfer(),
+            &ChannelId::new(0),
+            other_module_id.clone(),
+        ),
+        Err(ContextError::RouterError(
+            RouterError::ChannelAlreadyOwned { .. }
+        ))
+    ));
+
+    router
+        .rebind_port(&PortId::transfer(), other_module_id.clone())
+        .unwrap();
+
+    // The new owner of the port cannot act on the channels of the previous one.
+    assert!(matches!(
+        authenticate_channel_owner(
+            ctx.ibc_store(),
+            &router,
+            &PortId::transfer(),
+            &ChannelId::new(0),
+            &other_module_id,
+        ),
+        Err(ContextError::RouterError(
+            RouterError::UnauthorizedChannelOwner { .. }
+        ))
+    ));
+    assert!(authenticate_channel_owner(
+        ctx.ibc_store(),
+        &router,
+        &PortId::transfer(),
+        &ChannelId::new(0),
+        &transfer_module_id,
+    )
+    .is_ok());
+
+    // Channel 1 has no recorded owner, so it is owned by the owner of its
+    // port until migrated.
+    assert!(authenticate_channel_owner(
+        ctx.ibc_store(),
+        &router,
+        &PortId::transfer(),
+        &ChannelId::new(1),
+        &other_module_id,
+    )
+    .is_ok());
+
+    let claimed = migrate_channel_owners(
+        ctx.ibc_store_mut(),
+        &router,
+        [
+            (PortId::transfer(), ChannelId::new(0)),
+            (PortId::transfer(), ChannelId::new(1)),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(claimed, 1);
+
+    // Rebinding the port again does not move the migrated channel.
+    router
+        .rebind_port(&PortId::transfer(), transfer_module_id.clone())
+        .unwrap();
+
+    assert_eq!(
+        channel_owner(
+            ctx.ibc_store(),
+            &router,
+            &PortId::transfer(),
+            &ChannelId::new(1)
+        )
+        .unwrap(),
+        other_module_id
+    );
+    assert_eq!(
+        channel_owner(
+            ctx.ibc_store(),
+            &router,
+            &PortId::transfer(),
+            &ChannelId::new(0)
+        )
+        .unwrap(),
+        transfer_module_id
+    );
+}
+
+#[test]
+fn middleware_stack_composes_versions_and_acks() {
+    /// Prefixes the wrapped version and acknowledgement with its own tag.
+    #[derive(Debug)]
+    struct TagMiddleware(&'static str);
+
+    impl Middleware for TagMiddleware {
+        fn wrap_version(&self, app_version: Version) -> Version {
+            Version::new(format!("{}|{app_version}", self.0))
+        }
+
+        fn unwrap_version(&self, version: &Version) -> Result<Version, ChannelError> {
+            version
+                .as_str()
+                .strip_prefix(&format!("{}|", self.0))
+                .map(|app_version| Version::new(app_version.to_string()))
+                .ok_or_else(|| ChannelError::AppModule {
+                    description: format!("missing `{}` version tag", self.0),
+                })
+        }
+
+        fn wrap_acknowledgement(&self, app_ack: Acknowledgement) -> Acknowledgement {
+            let mut ack = self.0.as_bytes().to_vec();
+            ack.extend(app_ack.as_bytes());
+            ack.try_into().expect("non-empty acknowledgement")
+        }
+    }
+
+    // `outer` is placed on top of `inner`, which wraps the transfer module.
+    let mut stack = MiddlewareStack::new(TagMiddleware("inner"), DummyTransferModule)
+        .wrap(TagMiddleware("outer"));
+
+    let counterparty = Counterparty::new(PortId::transfer(), Some(ChannelId::zero()));
+
+    let version = stack
+        .on_chan_open_try_validate(
+            Order::Unordered,
+            &[ConnectionId::zero()],
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            &counterparty,
+            &Version::new("outer|inner|ics20-1".to_string()),
+        )
+        .unwrap();
+    assert_eq!(version.as_str(), "outer|inner|ics20-1");
+
+    // The layers must be unwrapped in order.
+    assert!(stack
+        .on_chan_open_try_validate(
+            Order::Unordered,
+            &[ConnectionId::zero()],
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            &counterparty,
+            &Version::new("inner|outer|ics20-1".to_string()),
+        )
+        .is_err());
+
+    let packet = PacketConfig::builder().build();
+    let (_, result) = stack.on_recv_packet_execute(&packet, &dummy_account_id());
+    assert!(result.is_successful());
+    assert!(result
+        .acknowledgement()
+        .expect("synchronous acknowledgement")
+        .as_bytes()
+        .starts_with(b"outerinner"));
+}
+
+#[test]
+fn app_version_negotiation_and_json_metadata() {
+    // An empty version lets the application propose its own.
+    let version = TransferVersion::on_chan_open_init(&Version::empty()).unwrap();
+    assert_eq!(version.to_version().as_str(), "ics20-1");
+
+    assert!(TransferVersion::on_chan_open_try(&Version::new("ics20-1".to_string())).is_ok());
+    assert!(TransferVersion::on_chan_open_ack(&Version::new("ics20-2".to_string())).is_err());
+    assert!(version
+        .upgrade(&Version::new("ics721-1".to_string()))
+        .is_err());
+
+    /// The version metadata of a fee middleware.
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct FeeMetadata {
+        fee_version: String,
+        app_version: String,
+    }
+
+    let metadata = FeeMetadata {
+        fee_version: "ics29-1".to_string(),
+        app_version: version.to_version().to_string(),
+    };
+    let version = encode_json_version(&metadata).unwrap();
+    assert_eq!(
+        version.as_str(),
+        r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#
+    );
+    assert_eq!(
+        decode_json_version::<FeeMetadata>(&version).unwrap(),
+        metadata
+    );
+    assert!(decode_json_version::<FeeMetadata>(&Version::new("ics20-1".to_string())).is_err());
+}
+
+#[test]
+fn msg_any_roundtrip_and_type_url_registry() {
+    let msg = dummy_msg_conn_open_init();
+
+    let any = msg.clone().to_any();
+    assert_eq!(any.type_url, MsgConnectionOpenInit::TYPE_URL);
+    assert!(MsgEnvelope::is_supported_type_url(&any.type_url));
+
+    let decoded = MsgConnectionOpenInit::try_from_any(any.clone()).expect("roundtrip succeeds");
+    assert_eq!(decoded, msg);
+
+    let envelope = MsgEnvelope::try_from(any.clone()).expect("envelope decodes");
+    assert_eq!(
+        envelope,
+        MsgEnvelope::Connection(ConnectionMsg::OpenInit(msg))
+    );
+
+    // A message with a mismatching type URL is rejected.
+    let res = MsgCreateClient::try_from_any(any);
+    assert!(matches!(
+        res,
+        Err(RouterError::UnknownMessageTypeUrl { .. })
+    ));
+
+    // Application messages expose their type URL, which is not routed by the
+    // core envelope.
+    assert!(!MsgEnvelope::is_supported_type_url(MsgTransfer::TYPE_URL));
+}
@@ -0,0 +1,106 @@
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::entrypoint::simulate;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::handler::types::simulation::StateWrite;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::utils::year_2024;
+use test_log::test;
+
+fn msg_update_client(client_id: &ClientId, height: Height) -> MsgEnvelope {
+    MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: MockHeader::new(height).with_timestamp(year_2024()).into(),
+        signer: dummy_account_id(),
+    }))
+}
+
+#[test]
+fn test_simulate_update_client() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let mut ctx = MockContext::default().with_light_client(
+        &client_id,
+        LightClientState::<MockHost>::with_latest_height(Height::new(0, 42).unwrap()),
+    );
+
+    let update_height = Height::new(0, 46).unwrap();
+
+    let report = simulate(&ctx, msg_update_client(&client_id, update_height));
+
+    assert!(report.is_ok(), "simulation happy path: {:?}", report.result);
+    assert!(matches!(
+        report.events.last(),
+        Some(IbcEvent::UpdateClient(_))
+    ));
+    assert!(report.writes.contains(&StateWrite::Set(
+        ClientStatePath::new(client_id.clone()).into()
+    )));
+    assert!(report.writes.contains(&StateWrite::Set(
+        ClientConsensusStatePath::new(client_id.clone(), 0, 46).into()
+    )));
+    assert!(report.charges.header_decoding > 0);
+    assert!(report.charges.signature_verification > 0);
+
+    // None of the writes reached the context: neither those of the light
+    // client, nor the events, nor the secondary indexes.
+    assert!(ctx.get_events().is_empty());
+    assert_eq!(
+        ctx.ibc_store
+            .client_state(&client_id)
+            .unwrap()
+            .latest_height(),
+        Height::new(0, 42).unwrap()
+    );
+    assert!(ctx
+        .ibc_store
+        .consensus_state(&ClientConsensusStatePath::new(client_id.clone(), 0, 46))
+        .is_err());
+    assert!(ctx
+        .ibc_store
+        .client_update_meta(&client_id, &update_height)
+        .is_err());
+    assert!(ctx
+        .ibc_store
+        .secondary_indexes
+        .lock()
+        .consensus_states_by_processed_time
+        .values()
+        .all(|states| !states.contains(&(client_id.clone(), update_height))));
+
+    // The same message is then handled for real.
+    ctx.dispatch(msg_update_client(&client_id, update_height))
+        .expect("update happy path");
+
+    assert_eq!(
+        ctx.ibc_store
+            .client_state(&client_id)
+            .unwrap()
+            .latest_height(),
+        update_height
+    );
+}
+
+#[test]
+fn test_simulate_unknown_client() {
+    let ctx = MockContext::default();
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let report = simulate(
+        &ctx,
+        msg_update_client(&client_id, Height::new(0, 46).unwrap()),
+    );
+
+    assert!(!report.is_ok());
+    assert!(report.events.is_empty());
+    assert!(report.writes.is_empty());
+}
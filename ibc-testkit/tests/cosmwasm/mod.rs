@@ -1,3 +1,6 @@
+// The client expiry is exercised against the system time.
+#![allow(clippy::disallowed_methods)]
+
 pub mod fixture;
 pub mod helper;
 
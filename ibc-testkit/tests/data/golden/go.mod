@@ -0,0 +1,5 @@
+module github.com/cosmos/ibc-rs/ibc-testkit/tests/data/golden
+
+go 1.21
+
+require github.com/cosmos/ibc-go/v8 v8.4.0
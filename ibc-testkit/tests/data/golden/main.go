@@ -0,0 +1,230 @@
+// Prints the golden vectors of `ibc-testkit/tests/core/encoding.rs`, as
+// marshalled by ibc-go, one `<test name> <hex>` line per vector.
+//
+// Run it with `go mod tidy && go run .` from this directory, and update the
+// vectors of the tests whenever ibc-go is bumped in `go.mod`.
+package main
+
+import (
+	"bytes"
+	"encoding/hex"
+	"fmt"
+	"time"
+
+	sdkmath "cosmossdk.io/math"
+	codectypes "github.com/cosmos/cosmos-sdk/codec/types"
+	sdk "github.com/cosmos/cosmos-sdk/types"
+	"github.com/cosmos/gogoproto/proto"
+
+	transfertypes "github.com/cosmos/ibc-go/v8/modules/apps/transfer/types"
+	clienttypes "github.com/cosmos/ibc-go/v8/modules/core/02-client/types"
+	connectiontypes "github.com/cosmos/ibc-go/v8/modules/core/03-connection/types"
+	channeltypes "github.com/cosmos/ibc-go/v8/modules/core/04-channel/types"
+	commitmenttypes "github.com/cosmos/ibc-go/v8/modules/core/23-commitment/types"
+	ibctm "github.com/cosmos/ibc-go/v8/modules/light-clients/07-tendermint"
+)
+
+type marshaler interface {
+	Marshal() ([]byte, error)
+}
+
+func emit(name string, msg marshaler) {
+	bz, err := msg.Marshal()
+	if err != nil {
+		panic(err)
+	}
+	fmt.Println(name, hex.EncodeToString(bz))
+}
+
+func toAny(msg proto.Message) *codectypes.Any {
+	value, err := codectypes.NewAnyWithValue(msg)
+	if err != nil {
+		panic(err)
+	}
+	return value
+}
+
+const signer = "signer"
+
+var (
+	height = clienttypes.NewHeight(1, 42)
+	proof  = []byte("proof")
+	prefix = commitmenttypes.NewMerklePrefix([]byte("ibc"))
+)
+
+func main() {
+	channel := channeltypes.Channel{
+		State:          channeltypes.OPEN,
+		Ordering:       channeltypes.UNORDERED,
+		Counterparty:   channeltypes.NewCounterparty("transfer", "channel-1"),
+		ConnectionHops: []string{"connection-0"},
+		Version:        "ics20-1",
+	}
+
+	connection := connectiontypes.ConnectionEnd{
+		ClientId: "07-tendermint-0",
+		Versions: []*connectiontypes.Version{connectiontypes.DefaultIBCVersion},
+		State:    connectiontypes.OPEN,
+		Counterparty: connectiontypes.NewCounterparty(
+			"07-tendermint-1", "connection-1", prefix,
+		),
+	}
+
+	packet := channeltypes.Packet{
+		Sequence:           1,
+		SourcePort:         "transfer",
+		SourceChannel:      "channel-0",
+		DestinationPort:    "transfer",
+		DestinationChannel: "channel-1",
+		Data:               []byte("data"),
+		TimeoutHeight:      clienttypes.ZeroHeight(),
+		TimeoutTimestamp:   1_000_000_000,
+	}
+
+	clientState := &ibctm.ClientState{
+		ChainId:         "ibc-1",
+		TrustLevel:      ibctm.Fraction{Numerator: 1, Denominator: 3},
+		TrustingPeriod:  14 * 24 * time.Hour,
+		UnbondingPeriod: 21 * 24 * time.Hour,
+		MaxClockDrift:   3 * time.Second,
+		FrozenHeight:    clienttypes.ZeroHeight(),
+		LatestHeight:    height,
+		ProofSpecs:      commitmenttypes.GetSDKSpecs(),
+		UpgradePath:     []string{"upgrade", "upgradedIBCState"},
+	}
+
+	consensusState := &ibctm.ConsensusState{
+		Timestamp:          time.Unix(1_700_000_000, 0).UTC(),
+		Root:               commitmenttypes.NewMerkleRoot(bytes.Repeat([]byte{1}, 32)),
+		NextValidatorsHash: bytes.Repeat([]byte{2}, 32),
+	}
+
+	emit("test_height_golden_vector", &height)
+	emit("test_channel_end_golden_vector", &channel)
+	emit("test_connection_end_golden_vector", &connection)
+	emit("test_packet_golden_vector", &packet)
+	emit("test_tm_client_state_golden_vector", clientState)
+	emit("test_tm_client_state_any_golden_vector", toAny(clientState))
+	emit("test_tm_consensus_state_golden_vector", consensusState)
+	emit("test_tm_consensus_state_any_golden_vector", toAny(consensusState))
+
+	emit("test_msg_transfer_golden_vector", &transfertypes.MsgTransfer{
+		SourcePort:       "transfer",
+		SourceChannel:    "channel-0",
+		Token:            sdk.NewCoin("uatom", sdkmath.NewInt(100)),
+		Sender:           "sender",
+		Receiver:         "receiver",
+		TimeoutHeight:    clienttypes.ZeroHeight(),
+		TimeoutTimestamp: 1_000_000_000,
+	})
+
+	emit("test_msg_recv_packet_golden_vector", &channeltypes.MsgRecvPacket{
+		Packet:          packet,
+		ProofCommitment: proof,
+		ProofHeight:     height,
+		Signer:          signer,
+	})
+
+	emit("test_msg_acknowledgement_golden_vector", &channeltypes.MsgAcknowledgement{
+		Packet:          packet,
+		Acknowledgement: channeltypes.NewResultAcknowledgement([]byte{1}).Acknowledgement(),
+		ProofAcked:      proof,
+		ProofHeight:     height,
+		Signer:          signer,
+	})
+
+	emit("test_msg_update_client_golden_vector", &clienttypes.MsgUpdateClient{
+		ClientId: "07-tendermint-0",
+		ClientMessage: &codectypes.Any{
+			TypeUrl: "/ibc.lightclients.tendermint.v1.Header",
+			Value:   []byte("header"),
+		},
+		Signer: signer,
+	})
+
+	emit("test_msg_conn_open_init_golden_vector", &connectiontypes.MsgConnectionOpenInit{
+		ClientId:     "07-tendermint-0",
+		Counterparty: connectiontypes.NewCounterparty("07-tendermint-1", "", prefix),
+		Version:      connectiontypes.DefaultIBCVersion,
+		Signer:       signer,
+	})
+
+	emit("test_msg_conn_open_try_golden_vector", &connectiontypes.MsgConnectionOpenTry{
+		ClientId:     "07-tendermint-1",
+		ClientState:  toAny(clientState),
+		Counterparty: connectiontypes.NewCounterparty("07-tendermint-0", "connection-0", prefix),
+		CounterpartyVersions: []*connectiontypes.Version{
+			connectiontypes.DefaultIBCVersion,
+		},
+		ProofHeight:     height,
+		ProofInit:       proof,
+		ProofClient:     proof,
+		ProofConsensus:  proof,
+		ConsensusHeight: height,
+		Signer:          signer,
+	})
+
+	emit("test_msg_conn_open_ack_golden_vector", &connectiontypes.MsgConnectionOpenAck{
+		ConnectionId:             "connection-0",
+		CounterpartyConnectionId: "connection-1",
+		Version:                  connectiontypes.DefaultIBCVersion,
+		ClientState:              toAny(clientState),
+		ProofHeight:              height,
+		ProofTry:                 proof,
+		ProofClient:              proof,
+		ProofConsensus:           proof,
+		ConsensusHeight:          height,
+		Signer:                   signer,
+	})
+
+	emit("test_msg_conn_open_confirm_golden_vector", &connectiontypes.MsgConnectionOpenConfirm{
+		ConnectionId: "connection-1",
+		ProofAck:     proof,
+		ProofHeight:  height,
+		Signer:       signer,
+	})
+
+	emit("test_msg_chan_open_init_golden_vector", &channeltypes.MsgChannelOpenInit{
+		PortId: "transfer",
+		Channel: channeltypes.Channel{
+			State:          channeltypes.INIT,
+			Ordering:       channeltypes.UNORDERED,
+			Counterparty:   channeltypes.NewCounterparty("transfer", ""),
+			ConnectionHops: []string{"connection-0"},
+			Version:        "ics20-1",
+		},
+		Signer: signer,
+	})
+
+	emit("test_msg_chan_open_try_golden_vector", &channeltypes.MsgChannelOpenTry{
+		PortId: "transfer",
+		Channel: channeltypes.Channel{
+			State:          channeltypes.TRYOPEN,
+			Ordering:       channeltypes.UNORDERED,
+			Counterparty:   channeltypes.NewCounterparty("transfer", "channel-0"),
+			ConnectionHops: []string{"connection-1"},
+		},
+		CounterpartyVersion: "ics20-1",
+		ProofInit:           proof,
+		ProofHeight:         height,
+		Signer:              signer,
+	})
+
+	emit("test_msg_chan_open_ack_golden_vector", &channeltypes.MsgChannelOpenAck{
+		PortId:                "transfer",
+		ChannelId:             "channel-0",
+		CounterpartyChannelId: "channel-1",
+		CounterpartyVersion:   "ics20-1",
+		ProofTry:              proof,
+		ProofHeight:           height,
+		Signer:                signer,
+	})
+
+	emit("test_msg_chan_open_confirm_golden_vector", &channeltypes.MsgChannelOpenConfirm{
+		PortId:      "transfer",
+		ChannelId:   "channel-1",
+		ProofAck:    proof,
+		ProofHeight: height,
+		Signer:      signer,
+	})
+}
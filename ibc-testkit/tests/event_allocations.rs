@@ -0,0 +1,178 @@
+//! Bounds the allocations made when converting the IBC events into ABCI
+//! events.
+//!
+//! Kept in its own test target, so that its counting global allocator does
+//! not serve the other tests of the testkit.
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::Order;
+use ibc::core::channel::types::events::{SendPacket, WriteAcknowledgement};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::client::types::events::UpdateClient;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::{ClientId, ClientType, ConnectionId};
+use ibc::core::primitives::Timestamp;
+use ibc_testkit::fixtures::core::channel::PacketConfig;
+use tendermint::abci;
+
+/// Counts the allocations of the current thread while a measurement is
+/// running, so that tests running in parallel don't interfere.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+fn record_allocation() {
+    // The thread local is gone while the thread shuts down.
+    let _ = ALLOCATIONS.try_with(|count| {
+        if let Some(n) = count.get() {
+            count.set(Some(n + 1));
+        }
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs `f` and returns its output along with the number of allocations and
+/// reallocations it made.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    ALLOCATIONS.with(|count| count.set(Some(0)));
+    let output = f();
+    let count = ALLOCATIONS.with(|count| count.replace(None));
+    (output, count.unwrap_or_default())
+}
+
+/// The `abci::Event` owns its kind, the attribute vector, and the key and
+/// value of each attribute, which is the least an event conversion allocates.
+fn max_allocations(event: &abci::Event) -> usize {
+    2 + 2 * event.attributes.len()
+}
+
+fn attribute_value<'a>(event: &'a abci::Event, key: &str) -> &'a str {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key_str().expect("valid key") == key)
+        .expect("attribute exists")
+        .value_str()
+        .expect("valid value")
+}
+
+fn dummy_packet() -> Packet {
+    PacketConfig::builder()
+        .data(br#"{"amount":"1000","denom":"uatom"}"#.to_vec())
+        .timeout_height_on_b(TimeoutHeight::At(
+            Height::new(1, 1000).expect("valid height"),
+        ))
+        .timeout_timestamp_on_b(
+            Timestamp::from_nanoseconds(1_700_000_000_000_000_000).expect("valid timestamp"),
+        )
+        .build()
+}
+
+#[test]
+fn test_send_packet_event_allocations() {
+    let packet = dummy_packet();
+    let event = SendPacket::new(packet.clone(), Order::Unordered, ConnectionId::zero());
+
+    let (abci_event, allocations) = count_allocations(|| abci::Event::try_from(event));
+    let abci_event = abci_event.expect("valid event");
+
+    assert!(
+        allocations <= max_allocations(&abci_event),
+        "{allocations} allocations for {} attributes",
+        abci_event.attributes.len()
+    );
+    assert_eq!(
+        attribute_value(&abci_event, "packet_data"),
+        r#"{"amount":"1000","denom":"uatom"}"#
+    );
+    assert_eq!(
+        attribute_value(&abci_event, "packet_data_hex"),
+        hex::encode(&packet.data)
+    );
+    assert_eq!(
+        attribute_value(&abci_event, "packet_timeout_height"),
+        "1-1000"
+    );
+}
+
+#[test]
+fn test_write_acknowledgement_event_allocations() {
+    let ack = Acknowledgement::try_from(br#"{"result":"AQ=="}"#.to_vec()).expect("valid ack");
+    let event = WriteAcknowledgement::new(dummy_packet(), ack.clone(), ConnectionId::zero());
+
+    let (abci_event, allocations) = count_allocations(|| abci::Event::try_from(event));
+    let abci_event = abci_event.expect("valid event");
+
+    assert!(
+        allocations <= max_allocations(&abci_event),
+        "{allocations} allocations for {} attributes",
+        abci_event.attributes.len()
+    );
+    assert_eq!(
+        attribute_value(&abci_event, "packet_ack"),
+        r#"{"result":"AQ=="}"#
+    );
+    assert_eq!(
+        attribute_value(&abci_event, "packet_ack_hex"),
+        hex::encode(ack.as_bytes())
+    );
+}
+
+#[test]
+fn test_update_client_event_allocations() {
+    let header = vec![0xab; 4096];
+    let event = UpdateClient::new(
+        ClientId::new("07-tendermint", 0).expect("valid client id"),
+        ClientType::new("07-tendermint").expect("valid client type"),
+        Height::new(1, 1000).expect("valid height"),
+        (1000..1010)
+            .map(|height| Height::new(1, height).expect("valid height"))
+            .collect(),
+        header.clone(),
+    );
+
+    let (abci_event, allocations) = count_allocations(|| abci::Event::from(event));
+
+    assert!(
+        allocations <= max_allocations(&abci_event),
+        "{allocations} allocations for {} attributes",
+        abci_event.attributes.len()
+    );
+    assert_eq!(attribute_value(&abci_event, "header"), hex::encode(header));
+    assert_eq!(
+        attribute_value(&abci_event, "consensus_heights"),
+        "1-1000,1-1001,1-1002,1-1003,1-1004,1-1005,1-1006,1-1007,1-1008,1-1009"
+    );
+}
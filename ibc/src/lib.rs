@@ -30,6 +30,23 @@
 //! This is the part of the protocol that abstracts away the core protocol and
 //! focuses solely on business logic.
 //!
+//! The [prelude](prelude) gathers the types and traits most hosts need, and
+//! [proto](proto) the protobuf types they exchange with the rest of the
+//! Cosmos ecosystem, so that downstream code doesn't depend on the layout of
+//! the underlying crates:
+//!
+//! ```
+//! use ibc::prelude::*;
+//! use ibc::proto::client::v1::Height as RawHeight;
+//!
+//! let client_id = ClientId::new("07-tendermint", 0).expect("valid client id");
+//! let height = Height::new(1, 10).expect("valid height");
+//! let raw_height: RawHeight = height.into();
+//!
+//! assert_eq!(client_id.as_str(), "07-tendermint-0");
+//! assert_eq!(raw_height.revision_height, 10);
+//! ```
+//!
 //! [ibc-standard]: https://github.com/cosmos/ibc
 //! [ibc-rs]: https://github.com/cosmos/ibc-rs
 
@@ -59,6 +76,72 @@ pub mod apps {
     pub use ibc_apps::*;
 }
 
+/// A curated set of the IBC types and traits most commonly needed to
+/// integrate ibc-rs into a host chain or to build an application, meant to be
+/// glob imported with `use ibc::prelude::*`.
+///
+/// The `alloc` types required in `no_std` environments are re-exported
+/// separately, by [`primitives::prelude`].
+pub mod prelude {
+    pub use ibc_core::channel::types::acknowledgement::Acknowledgement;
+    pub use ibc_core::channel::types::channel::ChannelEnd;
+    pub use ibc_core::channel::types::packet::Packet;
+    pub use ibc_core::client::context::prelude::*;
+    pub use ibc_core::client::types::Height;
+    pub use ibc_core::commitment_types::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    pub use ibc_core::connection::types::ConnectionEnd;
+    pub use ibc_core::handler::types::error::ContextError;
+    pub use ibc_core::handler::types::events::IbcEvent;
+    pub use ibc_core::handler::types::msgs::{Msg, MsgEnvelope};
+    pub use ibc_core::host::types::identifiers::{
+        ChannelId, ClientId, ClientType, ConnectionId, PortId, Sequence,
+    };
+    pub use ibc_core::host::types::path::Path;
+    pub use ibc_core::host::{ExecutionContext, ValidationContext};
+    pub use ibc_core::router::module::Module;
+    pub use ibc_core::router::router::Router;
+    pub use ibc_primitives::proto::{Any, Protobuf};
+    pub use ibc_primitives::{Signer, Timestamp, ToProto, ToVec};
+}
+
+/// Re-exports the protobuf types of the IBC messages and data structures from
+/// the `ibc-proto` crate, grouped by specification.
+pub mod proto {
+    pub use ibc_primitives::proto::*;
+
+    /// ICS-02 client protobuf types.
+    pub mod client {
+        pub use ibc_core::client::types::proto::*;
+    }
+
+    /// ICS-03 connection protobuf types.
+    pub mod connection {
+        pub use ibc_core::connection::types::proto::*;
+    }
+
+    /// ICS-04 channel protobuf types.
+    pub mod channel {
+        pub use ibc_core::channel::types::proto::*;
+    }
+
+    /// ICS-23 commitment protobuf types, including the `ics23` proofs.
+    pub mod commitment {
+        pub use ibc_core::commitment_types::proto::*;
+    }
+
+    /// ICS-07 Tendermint light client protobuf types.
+    pub mod tendermint {
+        pub use ibc_clients::tendermint::types::proto::*;
+    }
+
+    /// ICS-08 Wasm light client protobuf types.
+    pub mod wasm {
+        pub use ibc_clients::wasm_types::proto::*;
+    }
+}
+
 /// Re-exports Cosmos-specific utility types, traits, and implementations.
 pub mod cosmos_host {
     pub use ibc_core_host_cosmos::*;